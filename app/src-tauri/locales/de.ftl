@@ -0,0 +1,67 @@
+## Vom Backend ausgegebene Meldungen. Die Ids entsprechen locales/en-US.ftl.
+
+## Desktop-Benachrichtigungen
+
+notify-transcript-copied = Transkript kopiert
+notify-transcript-copied-body = { $words } Wörter liegen in der Zwischenablage.
+notify-nothing-to-copy = Nichts zu kopieren
+notify-nothing-to-copy-body = Es wurde noch kein Transkript ausgegeben.
+notify-copy-failed = Kopieren fehlgeschlagen
+notify-transcript-pasted = Eingefügt
+notify-type-out-pasted = Mehrzeiliger Text wurde ins Terminal eingefügt statt getippt
+notify-language-switched = Diktiersprache: { $language }
+notify-buffer-overflow = Diktat länger als { $seconds } s: der Anfang wird verworfen
+notify-command-executed = Befehl: { $command }
+notify-command-failed = Befehl fehlgeschlagen: { $command }
+notify-command-unmatched = Kein passender Sprachbefehl
+notify-continuous-on = Dauerdiktat an
+notify-continuous-off = Dauerdiktat aus
+notify-settings-recovered = Einstellungen aus einer Sicherung wiederhergestellt
+notify-settings-reset = Einstellungen auf Standardwerte zurückgesetzt
+notify-settings-recovered-body = config.json war nicht lesbar und wurde als { $path } aufbewahrt.
+notify-model-install-failed = Modellinstallation fehlgeschlagen
+notify-model-update-failed = Modellaktualisierung fehlgeschlagen
+
+## Namen der Diktiersprachen
+
+language-auto = Automatisch erkennen
+language-en = Englisch
+language-de = Deutsch
+language-es = Spanisch
+language-fr = Französisch
+
+## Warum ein Diktat keine Ausgabe erzeugt hat
+
+no-output-no-audio = Kein Audio aufgenommen; Spracherkennung übersprungen
+no-output-no-speech = Keine Sprache erkannt; Spracherkennung übersprungen
+no-output-trim-rejected = Sprachzuschnitt verworfen; Spracherkennung übersprungen
+no-output-speaker-mismatch = Die Sprache passte nicht zum angelernten Sprecher
+no-output-empty-transcript = Die Spracherkennung lieferte ein leeres Transkript
+no-output-clean-empty = Die Bereinigung hat den gesamten Text entfernt
+
+## Warnungen zum Audioeingang
+
+audio-bluetooth-hands-free-rate = Das Mikrofon des Bluetooth-Headsets ({ $source }) läuft im Freisprechmodus mit { $khz } kHz mono; ein kabelgebundenes oder eingebautes Mikrofon wird besser erkannt
+audio-bluetooth-hands-free = Das Mikrofon des Bluetooth-Headsets ({ $source }) läuft im Freisprechmodus mit verminderter Qualität
+
+## Linux-Berechtigungen und Hilfsprogramme
+
+perm-not-wayland = Keine Wayland-Sitzung (XDG_SESSION_TYPE={ $session })
+perm-missing-xtest = XTEST fehlt (Einfügen unter X11 funktioniert eventuell nicht)
+perm-x11-connect-failed = Verbindung zu X11 fehlgeschlagen: { $error }
+perm-xtest-query-failed = Abfrage der XTEST-Erweiterung fehlgeschlagen: { $error }
+perm-xtest-reply-failed = Antwort der XTEST-Erweiterung nicht lesbar: { $error }
+perm-input-unreadable = /dev/input ist nicht lesbar: { $error }
+perm-no-readable-keyboards = Keine lesbaren Tastaturgeräte. Füge deinen Benutzer der Gruppe 'input' hinzu (danach ab- und wieder anmelden).
+perm-no-keyboards = Keine Tastaturgeräte unter /dev/input gefunden
+perm-input-group-pending = Zur Gruppe 'input' hinzugefügt, aber diese Anmeldung ist älter. Die Tastenkürzel starten automatisch, sobald der Zugriff funktioniert; sonst ab- und wieder anmelden.
+perm-uinput-missing = /dev/uinput nicht gefunden (Kernelmodul laden: modprobe uinput)
+perm-uinput-denied = /dev/uinput kann nicht geöffnet werden. Richte udev-Berechtigungen ein (und stelle sicher, dass ACLs die Gruppe 'input' nicht sperren), danach ab- und wieder anmelden.
+perm-uinput-open-failed = /dev/uinput kann nicht geöffnet werden: { $error }
+perm-uinput-acl-blocked = Die uinput-ACL sperrt die Gruppe 'input' (group::---). Häufige Ursache ist brltty; die Ein-Klick-Einrichtung behebt das mit setfacl.
+perm-missing-xdg-runtime-dir = XDG_RUNTIME_DIR fehlt (Wayland-Zwischenablage funktioniert eventuell nicht)
+perm-missing-wl-copy = wl-copy fehlt (wl-clipboard installieren)
+perm-missing-wl-paste = wl-paste fehlt (wl-clipboard installieren)
+perm-missing-xclip = xclip fehlt (xclip für die X11-Zwischenablage installieren)
+perm-missing-pkexec = pkexec fehlt (polkit installieren)
+perm-missing-setfacl = setfacl fehlt (acl installieren)
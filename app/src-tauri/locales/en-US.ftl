@@ -0,0 +1,68 @@
+## User-facing messages emitted by the backend. Message ids are stable codes;
+## every id here should also exist in the other catalogs.
+
+## Desktop notifications
+
+notify-transcript-copied = Transcript copied
+notify-transcript-copied-body = { $words } words are on the clipboard.
+notify-nothing-to-copy = Nothing to copy
+notify-nothing-to-copy-body = No transcript has been delivered yet.
+notify-copy-failed = Copy failed
+notify-transcript-pasted = Pasted
+notify-type-out-pasted = Multi-line text was pasted into the terminal instead of typed
+notify-language-switched = Dictation language: { $language }
+notify-buffer-overflow = Dictation longer than { $seconds } s: the start is being dropped
+notify-command-executed = Command: { $command }
+notify-command-failed = Command failed: { $command }
+notify-command-unmatched = No matching voice command
+notify-continuous-on = Continuous dictation on
+notify-continuous-off = Continuous dictation off
+notify-settings-recovered = Settings were restored from a backup
+notify-settings-reset = Settings were reset to defaults
+notify-settings-recovered-body = config.json could not be read and was kept as { $path }.
+notify-model-install-failed = Model install failed
+notify-model-update-failed = Model update failed
+
+## Dictation language names, by language code
+
+language-auto = Auto detect
+language-en = English
+language-de = German
+language-es = Spanish
+language-fr = French
+
+## Why a dictation produced no output, by transcription-skipped reason code
+
+no-output-no-audio = No audio captured; skipping ASR
+no-output-no-speech = No speech detected; skipping ASR
+no-output-trim-rejected = Speech trim rejected; skipping ASR
+no-output-speaker-mismatch = Speech did not match the enrolled speaker
+no-output-empty-transcript = ASR returned an empty transcript
+no-output-clean-empty = Cleanup removed all transcript text
+
+## Audio input warnings
+
+audio-bluetooth-hands-free-rate = Bluetooth headset mic ({ $source }) runs in hands-free mode at { $khz } kHz mono; a wired or built-in mic transcribes better
+audio-bluetooth-hands-free = Bluetooth headset mic ({ $source }) runs in hands-free mode with reduced quality
+
+## Linux permission and tooling checks
+
+perm-not-wayland = Not running under Wayland (XDG_SESSION_TYPE={ $session })
+perm-missing-xtest = Missing XTEST (X11 paste injection may not work)
+perm-x11-connect-failed = Failed to connect to X11: { $error }
+perm-xtest-query-failed = Failed to query XTEST extension: { $error }
+perm-xtest-reply-failed = Failed to read XTEST extension reply: { $error }
+perm-input-unreadable = /dev/input not readable: { $error }
+perm-no-readable-keyboards = No readable keyboard devices. Add your user to the 'input' group (then log out/in).
+perm-no-keyboards = No keyboard devices found under /dev/input
+perm-input-group-pending = Added to the 'input' group, but this login predates it. Hotkeys start automatically once access works; otherwise log out and back in.
+perm-uinput-missing = /dev/uinput not found (load the uinput kernel module: modprobe uinput)
+perm-uinput-denied = Cannot open /dev/uinput. Configure udev permissions (and ensure ACLs do not block the 'input' group) then log out/in.
+perm-uinput-open-failed = Cannot open /dev/uinput: { $error }
+perm-uinput-acl-blocked = uinput ACL blocks the 'input' group (group::---). This is commonly caused by brltty; the one-click setup uses setfacl to fix it.
+perm-missing-xdg-runtime-dir = Missing XDG_RUNTIME_DIR (Wayland clipboard may not work)
+perm-missing-wl-copy = Missing wl-copy (install wl-clipboard)
+perm-missing-wl-paste = Missing wl-paste (install wl-clipboard)
+perm-missing-xclip = Missing xclip (install xclip for X11 clipboard)
+perm-missing-pkexec = Missing pkexec (install polkit)
+perm-missing-setfacl = Missing setfacl (install acl)
@@ -1,7 +1,10 @@
+use std::ops::Range;
 use std::path::Path;
 
 use anyhow::{Context, Result};
-use tracing::warn;
+use tracing::{debug, warn};
+
+use super::language_id;
 
 pub fn load_whisper(
     model_dir: &Path,
@@ -54,6 +57,138 @@ pub fn transcribe(
     Ok(chunks.join("").trim().to_string())
 }
 
+/// Alternative transcripts for `samples`, produced by re-decoding with top-k sampling
+/// at rising temperatures. CT2's Whisper wrapper only returns the best beam, so this
+/// stands in for true N-best output. Duplicates of `best` and of each other are dropped.
+pub fn sample_alternatives(
+    recognizer: &mut ct2rs::Whisper,
+    samples: &[f32],
+    language: Option<&str>,
+    best: &str,
+    count: usize,
+) -> Result<Vec<String>> {
+    let language = match language {
+        Some(lang) if lang.trim().is_empty() => None,
+        Some("auto") => None,
+        other => other,
+    };
+
+    let mut alternatives: Vec<String> = Vec::new();
+    // Identical samples are common; give up after a few extra attempts.
+    for attempt in 0..count * 2 {
+        if alternatives.len() >= count {
+            break;
+        }
+        let options = ct2rs::WhisperOptions {
+            beam_size: 1,
+            sampling_topk: 4,
+            sampling_temperature: 0.5 + 0.2 * attempt as f32,
+            ..Default::default()
+        };
+        let text = recognizer
+            .generate(samples, language, false, &options)
+            .context("CT2 whisper sampled generate")?
+            .join("")
+            .trim()
+            .to_string();
+        if text.is_empty() || text == best || alternatives.contains(&text) {
+            continue;
+        }
+        alternatives.push(text);
+    }
+    Ok(alternatives)
+}
+
+/// Decodes each VAD speech segment as its own clip so long mid-dictation pauses don't
+/// derail the decoder. `on_segment` receives the transcript accumulated so far after
+/// every segment.
+pub fn transcribe_segments(
+    recognizer: &mut ct2rs::Whisper,
+    samples: &[f32],
+    segments: &[Range<usize>],
+    language: Option<&str>,
+    on_segment: &mut dyn FnMut(&str),
+) -> Result<String> {
+    if segments.len() <= 1 {
+        return transcribe(recognizer, samples, language);
+    }
+
+    let mut text = String::new();
+    for (index, segment) in segments.iter().enumerate() {
+        let clip = &samples[segment.start.min(samples.len())..segment.end.min(samples.len())];
+        if clip.is_empty() {
+            continue;
+        }
+        let piece = transcribe(recognizer, clip, language)
+            .with_context(|| format!("CT2 segment {index}"))?;
+        if piece.is_empty() {
+            continue;
+        }
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(&piece);
+        on_segment(&text);
+    }
+    Ok(text)
+}
+
+/// Code-switched decoding: every segment is decoded with language detection, so one
+/// dictation can move between `languages`. A segment that comes out in none of them is
+/// re-decoded with each one forced, keeping the best-matching result.
+pub fn transcribe_segments_code_switched(
+    recognizer: &mut ct2rs::Whisper,
+    samples: &[f32],
+    segments: &[Range<usize>],
+    languages: &[String],
+    on_segment: &mut dyn FnMut(&str),
+) -> Result<String> {
+    let whole = [0..samples.len()];
+    let segments = if segments.is_empty() {
+        &whole[..]
+    } else {
+        segments
+    };
+
+    let mut text = String::new();
+    for (index, segment) in segments.iter().enumerate() {
+        let clip = &samples[segment.start.min(samples.len())..segment.end.min(samples.len())];
+        if clip.is_empty() {
+            continue;
+        }
+        let mut piece =
+            transcribe(recognizer, clip, None).with_context(|| format!("CT2 segment {index}"))?;
+        if !language_id::plausible(&piece, languages) {
+            let mut best: Option<(usize, String)> = None;
+            for language in languages {
+                let forced = transcribe(recognizer, clip, Some(language))
+                    .with_context(|| format!("CT2 segment {index} ({language})"))?;
+                let score = language_id::score(&forced, language);
+                let better = match &best {
+                    Some((best_score, _)) => score > *best_score,
+                    None => true,
+                };
+                if better {
+                    best = Some((score, forced));
+                }
+            }
+            if let Some((_, forced)) = best {
+                debug!("code_switch_redecoded segment={index}");
+                piece = forced;
+            }
+        }
+        if piece.is_empty() {
+            continue;
+        }
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(&piece);
+        on_segment(&text);
+    }
+    Ok(text)
+}
+
 fn parse_device(spec: &str) -> (ct2rs::Device, Option<Vec<i32>>) {
     let raw = spec.trim();
     if raw.is_empty() {
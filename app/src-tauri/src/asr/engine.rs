@@ -1,4 +1,6 @@
+use std::ops::Range;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 
 use parking_lot::Mutex;
@@ -7,15 +9,29 @@ use tracing::{info, warn};
 
 #[cfg(feature = "asr-ct2")]
 use crate::asr::ct2_whisper;
+use crate::asr::mock::MockRecognizer;
+use crate::asr::sample_ring::SampleRing;
 #[cfg(feature = "asr-sherpa")]
 use crate::asr::sherpa;
+use crate::asr::vocabulary::VocabularyCorrector;
+use crate::asr::weights::{self, WeightLoadReport};
+#[cfg(feature = "asr-whispercpp")]
+use crate::asr::whisper_cpp;
+
+/// Default for `AsrConfig::max_session_secs`.
+pub const DEFAULT_MAX_SESSION_SECS: u32 = 120;
+const SESSION_SAMPLE_RATE: usize = 16_000;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub enum AsrBackend {
     WhisperOnnx,
     WhisperCt2,
+    /// ggml/GGUF Whisper models run by whisper.cpp.
+    WhisperCpp,
     Parakeet,
+    /// Canned transcripts, no model needed (see `asr::mock`).
+    Mock,
 }
 
 impl Default for AsrBackend {
@@ -35,6 +51,15 @@ pub struct AsrConfig {
     pub num_threads: Option<i32>,
     pub ct2_device: String,
     pub ct2_compute_type: String,
+    /// Prefetch weight files through a shared read-only mapping before loading.
+    pub mmap_weights: bool,
+    /// Languages a CT2 Whisper dictation may switch between, detected per segment;
+    /// empty decodes the whole dictation in one language.
+    pub code_switch_languages: Vec<String>,
+    /// Longest dictation kept in memory; older audio is dropped beyond this.
+    pub max_session_secs: u32,
+    /// Custom vocabulary: hotwords for Parakeet, post-correction for Whisper.
+    pub hotwords: Vec<String>,
 }
 
 impl Default for AsrConfig {
@@ -48,6 +73,10 @@ impl Default for AsrConfig {
             num_threads: None,
             ct2_device: "cpu".into(),
             ct2_compute_type: "int8".into(),
+            mmap_weights: true,
+            code_switch_languages: Vec::new(),
+            max_session_secs: DEFAULT_MAX_SESSION_SECS,
+            hotwords: Vec::new(),
         }
     }
 }
@@ -55,31 +84,71 @@ impl Default for AsrConfig {
 #[derive(Debug, Clone)]
 pub struct RecognitionResult {
     pub text: String,
+    /// Other plausible transcripts, best first; empty unless alternatives were requested
+    /// and the backend supports them.
+    pub alternatives: Vec<String>,
+    /// Transcript of each segment when they were decoded one by one for pause
+    /// punctuation; empty otherwise.
+    pub pieces: Vec<String>,
     pub latency: Duration,
 }
 
+/// A model meant for the GPU that was loaded on the CPU because GPU init failed.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GpuFallback {
+    /// The device that failed, e.g. "cuda".
+    pub device: String,
+    pub reason: String,
+}
+
 pub struct AsrEngine {
     config: AsrConfig,
-    buffer: Mutex<Vec<f32>>,
+    buffer: SampleRing,
+    alternatives: AtomicUsize,
+    /// Upper bound on decode threads while the CPU is throttling; 0 when uncapped.
+    thread_cap: AtomicUsize,
+    segment_pieces: AtomicBool,
+    load_report: Mutex<Option<WeightLoadReport>>,
+    /// Set when a GPU load failed and the model was loaded on the CPU instead.
+    gpu_fallback: Mutex<Option<GpuFallback>>,
+    vocabulary: VocabularyCorrector,
+    /// Parakeet was loaded with hotwords, so its output needs no post-correction.
+    hotwords_active: AtomicBool,
+    mock: MockRecognizer,
     #[cfg(feature = "asr-sherpa")]
     whisper: Mutex<Option<sherpa_rs::whisper::WhisperRecognizer>>,
     #[cfg(feature = "asr-sherpa")]
     parakeet: Mutex<Option<sherpa_rs::transducer::TransducerRecognizer>>,
     #[cfg(feature = "asr-ct2")]
     ct2_whisper: Mutex<Option<ct2rs::Whisper>>,
+    #[cfg(feature = "asr-whispercpp")]
+    whisper_cpp: Mutex<Option<whisper_rs::WhisperContext>>,
 }
 
 impl AsrEngine {
     pub fn new(config: AsrConfig) -> Self {
+        let capacity = SESSION_SAMPLE_RATE * config.max_session_secs.max(1) as usize;
+        let vocabulary = VocabularyCorrector::new(&config.hotwords);
         Self {
             config,
-            buffer: Mutex::new(Vec::new()),
+            buffer: SampleRing::new(capacity),
+            alternatives: AtomicUsize::new(0),
+            thread_cap: AtomicUsize::new(0),
+            segment_pieces: AtomicBool::new(false),
+            load_report: Mutex::new(None),
+            gpu_fallback: Mutex::new(None),
+            vocabulary,
+            hotwords_active: AtomicBool::new(false),
+            mock: MockRecognizer::from_env(),
             #[cfg(feature = "asr-sherpa")]
             whisper: Mutex::new(None),
             #[cfg(feature = "asr-sherpa")]
             parakeet: Mutex::new(None),
             #[cfg(feature = "asr-ct2")]
             ct2_whisper: Mutex::new(None),
+            #[cfg(feature = "asr-whispercpp")]
+            whisper_cpp: Mutex::new(None),
         }
     }
 
@@ -87,62 +156,222 @@ impl AsrEngine {
         &self.config
     }
 
+    /// How the current model's weights were loaded, once it has been.
+    pub fn load_report(&self) -> Option<WeightLoadReport> {
+        self.load_report.lock().clone()
+    }
+
+    /// The pending GPU fallback, if a load since the last call fell back to the CPU.
+    pub fn take_gpu_fallback(&self) -> Option<GpuFallback> {
+        self.gpu_fallback.lock().take()
+    }
+
+    /// Loads on `device`, retrying on the CPU when a GPU load fails.
+    #[cfg(any(
+        feature = "asr-ct2",
+        feature = "asr-sherpa",
+        feature = "asr-whispercpp"
+    ))]
+    fn load_on_device<T>(
+        &self,
+        model_dir: &std::path::Path,
+        device: &str,
+        load: impl Fn(&str) -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        match self.load_weights(model_dir, || load(device)) {
+            Err(error) if device != "cpu" => {
+                warn!("asr_gpu_fallback device={device} error={error:#}");
+                let loaded = self.load_weights(model_dir, || load("cpu"))?;
+                *self.gpu_fallback.lock() = Some(GpuFallback {
+                    device: device.to_string(),
+                    reason: format!("{error:#}"),
+                });
+                Ok(loaded)
+            }
+            result => result,
+        }
+    }
+
+    #[cfg(any(
+        feature = "asr-ct2",
+        feature = "asr-sherpa",
+        feature = "asr-whispercpp"
+    ))]
+    fn load_weights<T>(
+        &self,
+        model_dir: &std::path::Path,
+        load: impl FnOnce() -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        let (loaded, report) = weights::load_with_mode(
+            &self.config.backend,
+            model_dir,
+            self.config.mmap_weights,
+            load,
+        )?;
+        info!(
+            "asr_weights_loaded backend={:?} mode={:?} files={} bytes={} elapsed_ms={}",
+            report.backend,
+            report.mode,
+            report.weight_files,
+            report.weight_bytes,
+            report.elapsed_ms
+        );
+        *self.load_report.lock() = Some(report);
+        Ok(loaded)
+    }
+
+    /// How many alternative transcripts to produce next to the best one (CT2 only).
+    pub fn set_alternatives(&self, count: usize) {
+        self.alternatives.store(count, Ordering::Relaxed);
+    }
+
+    /// Caps decode threads. whisper.cpp picks this up on its next decode; the other
+    /// backends fix their thread count at load, see `reload_for_thread_cap`.
+    pub fn set_thread_cap(&self, cap: Option<usize>) {
+        self.thread_cap.store(cap.unwrap_or(0), Ordering::Relaxed);
+    }
+
+    fn decode_threads(&self) -> Option<i32> {
+        match self.thread_cap.load(Ordering::Relaxed) {
+            0 => self.config.num_threads,
+            cap => {
+                let cap = i32::try_from(cap).unwrap_or(i32::MAX);
+                Some(
+                    self.config
+                        .num_threads
+                        .filter(|threads| *threads > 0)
+                        .map_or(cap, |threads| threads.min(cap)),
+                )
+            }
+        }
+    }
+
+    /// Reloads models whose thread count was fixed at load so they pick up the current
+    /// cap. Waits for any decode in flight; does nothing when none is loaded.
+    pub fn reload_for_thread_cap(&self) -> anyhow::Result<()> {
+        #[allow(unused_mut)]
+        let mut unloaded = false;
+        #[cfg(feature = "asr-sherpa")]
+        {
+            unloaded |= self.whisper.lock().take().is_some();
+            unloaded |= self.parakeet.lock().take().is_some();
+        }
+        #[cfg(feature = "asr-ct2")]
+        {
+            unloaded |= self.ct2_whisper.lock().take().is_some();
+        }
+        if !unloaded {
+            return Ok(());
+        }
+        info!("asr_reload_for_threads threads={:?}", self.decode_threads());
+        self.warmup()
+    }
+
+    /// Decode Parakeet dictations segment by segment and report the pieces, so pauses
+    /// between segments can be punctuated.
+    pub fn set_segment_pieces(&self, enabled: bool) {
+        self.segment_pieces.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Buffers samples for the current session. Returns how many of the oldest samples
+    /// were dropped to stay within the session cap.
     pub fn push_samples(&self, samples: &[f32]) -> usize {
-        let mut buffer = self.buffer.lock();
-        buffer.extend_from_slice(samples);
-        Self::truncate_if_needed(&mut buffer)
+        self.buffer.push(samples)
     }
 
     pub fn take_samples(&self) -> Vec<f32> {
-        let mut buffer = self.buffer.lock();
-        std::mem::take(&mut *buffer)
+        self.buffer.take()
     }
 
     pub fn reset(&self) {
-        let mut buffer = self.buffer.lock();
-        buffer.clear();
+        self.buffer.reset();
     }
 
+    /// Transcribes one captured dictation. `segments` are speech spans within `samples`;
+    /// backends that decode per clip (CT2) report each finished segment through
+    /// `on_segment`, others decode the whole buffer and ignore both.
     pub fn finalize_samples(
         &self,
         sample_rate: u32,
         samples: &[f32],
+        segments: &[Range<usize>],
+        on_segment: &mut dyn FnMut(&str),
     ) -> anyhow::Result<Option<RecognitionResult>> {
         if samples.is_empty() {
             return Ok(None);
         }
 
         let started = Instant::now();
+        let mut pieces = Vec::new();
         let result = match self.config.backend {
+            AsrBackend::Mock => Ok(self.mock.transcribe(segments.len(), on_segment)),
             AsrBackend::WhisperCt2 => {
                 #[cfg(feature = "asr-ct2")]
                 {
-                    self.transcribe_with_ct2(sample_rate, samples)
+                    self.transcribe_with_ct2(sample_rate, samples, segments, on_segment)
                 }
 
                 #[cfg(not(feature = "asr-ct2"))]
                 {
+                    let _ = (segments, on_segment);
                     Err(anyhow::anyhow!("CT2 ASR disabled"))
                 }
             }
+            AsrBackend::WhisperCpp => {
+                #[cfg(feature = "asr-whispercpp")]
+                {
+                    self.transcribe_with_whisper_cpp(sample_rate, samples)
+                }
+
+                #[cfg(not(feature = "asr-whispercpp"))]
+                {
+                    Err(anyhow::anyhow!("whisper.cpp ASR disabled"))
+                }
+            }
             _ => {
                 #[cfg(feature = "asr-sherpa")]
                 {
-                    self.transcribe_with_sherpa(sample_rate, samples)
+                    if self.config.backend == AsrBackend::Parakeet
+                        && segments.len() > 1
+                        && self.segment_pieces.load(Ordering::Relaxed)
+                    {
+                        self.transcribe_pieces_with_sherpa(sample_rate, samples, segments)
+                            .map(|decoded| {
+                                pieces = decoded;
+                                join_pieces(&pieces)
+                            })
+                    } else {
+                        self.transcribe_with_sherpa(sample_rate, samples)
+                    }
                 }
 
                 #[cfg(not(feature = "asr-sherpa"))]
                 {
+                    let _ = &mut pieces;
                     Err(anyhow::anyhow!("local ASR disabled"))
                 }
             }
         };
 
         match result {
-            Ok(text) => Ok(Some(RecognitionResult {
-                text,
-                latency: started.elapsed(),
-            })),
+            Ok(text) => {
+                let text = self.apply_vocabulary(text);
+                let alternatives = self
+                    .alternatives_for(samples, &text)
+                    .into_iter()
+                    .map(|alternative| self.apply_vocabulary(alternative))
+                    .collect();
+                let pieces = pieces
+                    .into_iter()
+                    .map(|piece| self.apply_vocabulary(piece))
+                    .collect();
+                Ok(Some(RecognitionResult {
+                    text,
+                    alternatives,
+                    pieces,
+                    latency: started.elapsed(),
+                }))
+            }
             Err(error) => {
                 warn!("ASR transcription failed: {error:?}");
                 Err(error)
@@ -150,12 +379,54 @@ impl AsrEngine {
         }
     }
 
+    fn apply_vocabulary(&self, text: String) -> String {
+        if self.vocabulary.is_empty() || self.hotwords_active.load(Ordering::Relaxed) {
+            return text;
+        }
+        self.vocabulary.apply(&text)
+    }
+
+    fn alternatives_for(&self, samples: &[f32], best: &str) -> Vec<String> {
+        let count = self.alternatives.load(Ordering::Relaxed);
+        if count == 0 || best.trim().is_empty() {
+            return Vec::new();
+        }
+
+        match self.config.backend {
+            #[cfg(feature = "asr-ct2")]
+            AsrBackend::WhisperCt2 => {
+                let mut guard = self.ct2_whisper.lock();
+                let Some(recognizer) = guard.as_mut() else {
+                    return Vec::new();
+                };
+                let language = if self.config.auto_language_detect
+                    || !self.config.code_switch_languages.is_empty()
+                {
+                    None
+                } else {
+                    Some(self.config.language.as_str())
+                };
+                ct2_whisper::sample_alternatives(recognizer, samples, language, best, count)
+                    .unwrap_or_else(|error| {
+                        warn!("ASR alternatives failed: {error:?}");
+                        Vec::new()
+                    })
+            }
+            // sherpa-rs exposes only the best hypothesis.
+            _ => {
+                let _ = samples;
+                Vec::new()
+            }
+        }
+    }
+
     /// Eagerly load the configured ASR model into memory.
     ///
     /// This is used for startup warmup so the first real transcription does not
     /// pay the model initialization cost.
     pub fn warmup(&self) -> anyhow::Result<()> {
         match self.config.backend {
+            AsrBackend::Mock => Ok(()),
             AsrBackend::WhisperCt2 => {
                 #[cfg(feature = "asr-ct2")]
                 {
@@ -168,11 +439,17 @@ impl AsrEngine {
                     let mut guard = self.ct2_whisper.lock();
                     if guard.is_none() {
                         info!("Warming CT2 Whisper model from {}", model_dir.display());
-                        *guard = Some(ct2_whisper::load_whisper(
+                        *guard = Some(self.load_on_device(
                             model_dir,
                             &self.config.ct2_device,
-                            &self.config.ct2_compute_type,
-                            self.config.num_threads,
+                            |device| {
+                                ct2_whisper::load_whisper(
+                                    model_dir,
+                                    device,
+                                    &self.config.ct2_compute_type,
+                                    self.decode_threads(),
+                                )
+                            },
                         )?);
                         info!("CT2 Whisper warmup complete");
                     }
@@ -184,6 +461,33 @@ impl AsrEngine {
                     anyhow::bail!("CT2 ASR disabled")
                 }
             }
+            AsrBackend::WhisperCpp => {
+                #[cfg(feature = "asr-whispercpp")]
+                {
+                    let model_dir = self
+                        .config
+                        .model_dir
+                        .as_ref()
+                        .ok_or_else(|| anyhow::anyhow!("ASR model not installed"))?;
+
+                    let mut guard = self.whisper_cpp.lock();
+                    if guard.is_none() {
+                        info!("Warming whisper.cpp model from {}", model_dir.display());
+                        *guard = Some(self.load_on_device(
+                            model_dir,
+                            &self.config.provider,
+                            |device| whisper_cpp::load_whisper(model_dir, device),
+                        )?);
+                        info!("whisper.cpp warmup complete");
+                    }
+                    Ok(())
+                }
+
+                #[cfg(not(feature = "asr-whispercpp"))]
+                {
+                    anyhow::bail!("whisper.cpp ASR disabled")
+                }
+            }
             AsrBackend::WhisperOnnx => {
                 #[cfg(feature = "asr-sherpa")]
                 {
@@ -205,11 +509,17 @@ impl AsrEngine {
                             "Warming Whisper (sherpa) model from {}",
                             model_dir.display()
                         );
-                        *guard = Some(sherpa::load_whisper(
+                        *guard = Some(self.load_on_device(
                             model_dir,
-                            &language,
                             &self.config.provider,
-                            self.config.num_threads,
+                            |provider| {
+                                sherpa::load_whisper(
+                                    model_dir,
+                                    &language,
+                                    provider,
+                                    self.decode_threads(),
+                                )
+                            },
                         )?);
                         info!("Whisper (sherpa) warmup complete");
                     }
@@ -236,11 +546,7 @@ impl AsrEngine {
                             "Warming Parakeet (sherpa) model from {}",
                             model_dir.display()
                         );
-                        *guard = Some(sherpa::load_parakeet(
-                            model_dir,
-                            &self.config.provider,
-                            self.config.num_threads,
-                        )?);
+                        *guard = Some(self.load_parakeet(model_dir)?);
                         info!("Parakeet warmup complete");
                     }
                     Ok(())
@@ -254,6 +560,49 @@ impl AsrEngine {
         }
     }
 
+    #[cfg(feature = "asr-sherpa")]
+    fn load_parakeet(
+        &self,
+        model_dir: &std::path::Path,
+    ) -> anyhow::Result<sherpa_rs::transducer::TransducerRecognizer> {
+        let (recognizer, hotwords_active) =
+            self.load_on_device(model_dir, &self.config.provider, |provider| {
+                sherpa::load_parakeet(
+                    model_dir,
+                    provider,
+                    self.decode_threads(),
+                    &self.config.hotwords,
+                )
+            })?;
+        if hotwords_active {
+            info!("parakeet_hotwords terms={}", self.config.hotwords.len());
+        }
+        self.hotwords_active
+            .store(hotwords_active, Ordering::Relaxed);
+        Ok(recognizer)
+    }
+
+    #[cfg(feature = "asr-sherpa")]
+    fn transcribe_pieces_with_sherpa(
+        &self,
+        sample_rate: u32,
+        samples: &[f32],
+        segments: &[Range<usize>],
+    ) -> anyhow::Result<Vec<String>> {
+        segments
+            .iter()
+            .map(|segment| {
+                let clip =
+                    &samples[segment.start.min(samples.len())..segment.end.min(samples.len())];
+                if clip.is_empty() {
+                    return Ok(String::new());
+                }
+                self.transcribe_with_sherpa(sample_rate, clip)
+                    .map(|text| text.trim().to_string())
+            })
+            .collect()
+    }
+
     #[cfg(feature = "asr-sherpa")]
     fn transcribe_with_sherpa(&self, sample_rate: u32, samples: &[f32]) -> anyhow::Result<String> {
         if sample_rate != 16_000 {
@@ -276,11 +625,17 @@ impl AsrEngine {
                         self.config.language.clone()
                     };
                     info!("Loading Whisper ASR model from {}", model_dir.display());
-                    *guard = Some(sherpa::load_whisper(
+                    *guard = Some(self.load_on_device(
                         model_dir,
-                        &language,
                         &self.config.provider,
-                        self.config.num_threads,
+                        |provider| {
+                            sherpa::load_whisper(
+                                model_dir,
+                                &language,
+                                provider,
+                                self.decode_threads(),
+                            )
+                        },
                     )?);
                     info!("Whisper ASR model loaded");
                 }
@@ -294,11 +649,7 @@ impl AsrEngine {
                 let mut guard = self.parakeet.lock();
                 if guard.is_none() {
                     info!("Loading Parakeet ASR model from {}", model_dir.display());
-                    *guard = Some(sherpa::load_parakeet(
-                        model_dir,
-                        &self.config.provider,
-                        self.config.num_threads,
-                    )?);
+                    *guard = Some(self.load_parakeet(model_dir)?);
                     info!("Parakeet ASR model loaded");
                 }
                 let recognizer = guard
@@ -307,11 +658,19 @@ impl AsrEngine {
                 Ok(recognizer.transcribe(sample_rate, samples))
             }
             AsrBackend::WhisperCt2 => anyhow::bail!("CT2 ASR is not handled by sherpa"),
+            AsrBackend::WhisperCpp => anyhow::bail!("whisper.cpp ASR is not handled by sherpa"),
+            AsrBackend::Mock => anyhow::bail!("mock ASR is not handled by sherpa"),
         }
     }
 
     #[cfg(feature = "asr-ct2")]
-    fn transcribe_with_ct2(&self, sample_rate: u32, samples: &[f32]) -> anyhow::Result<String> {
+    fn transcribe_with_ct2(
+        &self,
+        sample_rate: u32,
+        samples: &[f32],
+        segments: &[Range<usize>],
+        on_segment: &mut dyn FnMut(&str),
+    ) -> anyhow::Result<String> {
         if sample_rate != 16_000 {
             anyhow::bail!("ASR requires 16kHz audio (got {sample_rate}Hz)");
         }
@@ -325,12 +684,16 @@ impl AsrEngine {
         let mut guard = self.ct2_whisper.lock();
         if guard.is_none() {
             info!("Loading CT2 Whisper model from {}", model_dir.display());
-            *guard = Some(ct2_whisper::load_whisper(
-                model_dir,
-                &self.config.ct2_device,
-                &self.config.ct2_compute_type,
-                self.config.num_threads,
-            )?);
+            *guard = Some(
+                self.load_on_device(model_dir, &self.config.ct2_device, |device| {
+                    ct2_whisper::load_whisper(
+                        model_dir,
+                        device,
+                        &self.config.ct2_compute_type,
+                        self.decode_threads(),
+                    )
+                })?,
+            );
             info!("CT2 Whisper model loaded");
         }
 
@@ -338,23 +701,70 @@ impl AsrEngine {
             .as_mut()
             .ok_or_else(|| anyhow::anyhow!("CT2 whisper recognizer unavailable"))?;
 
+        if !self.config.code_switch_languages.is_empty() {
+            return ct2_whisper::transcribe_segments_code_switched(
+                recognizer,
+                samples,
+                segments,
+                &self.config.code_switch_languages,
+                on_segment,
+            );
+        }
+
         let language = if self.config.auto_language_detect {
             None
         } else {
             Some(self.config.language.as_str())
         };
 
-        let result = ct2_whisper::transcribe(recognizer, samples, language)?;
-        Ok(result)
+        ct2_whisper::transcribe_segments(recognizer, samples, segments, language, on_segment)
     }
 
-    fn truncate_if_needed(buffer: &mut Vec<f32>) -> usize {
-        const MAX_SAMPLES: usize = 16_000 * 120;
-        if buffer.len() > MAX_SAMPLES {
-            let overflow = buffer.len() - MAX_SAMPLES;
-            buffer.drain(..overflow);
-            return overflow;
+    #[cfg(feature = "asr-whispercpp")]
+    fn transcribe_with_whisper_cpp(
+        &self,
+        sample_rate: u32,
+        samples: &[f32],
+    ) -> anyhow::Result<String> {
+        if sample_rate != 16_000 {
+            anyhow::bail!("ASR requires 16kHz audio (got {sample_rate}Hz)");
+        }
+
+        let model_dir = self
+            .config
+            .model_dir
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("ASR model not installed"))?;
+
+        let mut guard = self.whisper_cpp.lock();
+        if guard.is_none() {
+            info!("Loading whisper.cpp model from {}", model_dir.display());
+            *guard = Some(
+                self.load_on_device(model_dir, &self.config.provider, |device| {
+                    whisper_cpp::load_whisper(model_dir, device)
+                })?,
+            );
+            info!("whisper.cpp model loaded");
         }
-        0
+
+        let context = guard
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("whisper.cpp context unavailable"))?;
+        let language = if self.config.auto_language_detect {
+            None
+        } else {
+            Some(self.config.language.as_str())
+        };
+        whisper_cpp::transcribe(context, samples, language, self.decode_threads())
     }
 }
+
+#[cfg(feature = "asr-sherpa")]
+fn join_pieces(pieces: &[String]) -> String {
+    pieces
+        .iter()
+        .filter(|piece| !piece.is_empty())
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
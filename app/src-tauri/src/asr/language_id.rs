@@ -0,0 +1,62 @@
+//! Rough text-side language check for code-switched dictation: counts common function
+//! words. Only good enough to tell whether a decoded segment belongs to one of the
+//! languages the user dictates in.
+
+fn function_words(language: &str) -> &'static [&'static str] {
+    match language {
+        "en" => &[
+            "the", "and", "is", "to", "of", "a", "in", "that", "it", "for", "you", "with", "this",
+            "on", "are", "be", "i", "we", "have", "not",
+        ],
+        "de" => &[
+            "der", "die", "das", "und", "ist", "nicht", "ich", "wir", "sie", "es", "ein", "eine",
+            "zu", "mit", "auf", "für", "den", "dem", "von", "auch",
+        ],
+        "es" => &[
+            "el", "la", "los", "las", "y", "es", "que", "de", "en", "un", "una", "no", "por",
+            "con", "para", "se", "lo", "yo", "muy", "pero",
+        ],
+        "fr" => &[
+            "le", "la", "les", "et", "est", "que", "de", "des", "un", "une", "je", "nous", "vous",
+            "pas", "ne", "pour", "avec", "dans", "sur", "ce",
+        ],
+        _ => &[],
+    }
+}
+
+/// Function-word hits of `text` in `language`; 0 for languages without a word list.
+pub fn score(text: &str, language: &str) -> usize {
+    let words = function_words(language);
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .filter(|word| words.contains(&word.to_lowercase().as_str()))
+        .count()
+}
+
+/// False when `text` is long enough to judge and shows none of `languages`' function
+/// words, i.e. the decoder likely drifted into another language.
+pub fn plausible(text: &str, languages: &[String]) -> bool {
+    let word_count = text.split_whitespace().count();
+    if word_count < 4 {
+        return true;
+    }
+    let known: Vec<&String> = languages
+        .iter()
+        .filter(|language| !function_words(language).is_empty())
+        .collect();
+    known.is_empty() || known.iter().any(|language| score(text, language) > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed_segments_are_plausible_and_foreign_ones_are_not() {
+        let languages = vec!["en".to_string(), "de".to_string()];
+        assert!(plausible("Ich schicke dir die Datei morgen", &languages));
+        assert!(plausible("Send me the report by Friday", &languages));
+        assert!(!plausible("Je vous envoie le rapport demain", &languages));
+        assert!(plausible("Okay danke", &languages));
+    }
+}
@@ -0,0 +1,119 @@
+//! Canned-transcript recognizer for frontend and integration work on machines
+//! without models or a working microphone.
+//!
+//! Selected instead of the configured backend when built with the `asr-mock`
+//! feature or run with `OPENFLOW_TEST_MODE`; `OPENFLOW_MOCK_ASR` set to a boolean
+//! overrides both. Transcripts come from `OPENFLOW_MOCK_ASR_TRANSCRIPTS`
+//! (`|`-separated, used in turn) and every decode takes
+//! `OPENFLOW_MOCK_ASR_LATENCY_MS` (300 ms by default).
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+const DEFAULT_TRANSCRIPTS: &[&str] = &[
+    "This is a mock transcript from OpenFlow.",
+    "The quick brown fox jumps over the lazy dog.",
+    "Testing one two three, with commas, periods and a question mark?",
+];
+const DEFAULT_LATENCY: Duration = Duration::from_millis(300);
+
+/// Whether the mock replaces the configured ASR backend.
+pub fn enabled() -> bool {
+    match env_flag("OPENFLOW_MOCK_ASR") {
+        Some(enabled) => enabled,
+        None => cfg!(feature = "asr-mock") || env_flag("OPENFLOW_TEST_MODE").unwrap_or(false),
+    }
+}
+
+fn env_flag(key: &str) -> Option<bool> {
+    let value = std::env::var(key).ok()?;
+    Some(matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "1" | "true" | "yes" | "y" | "on"
+    ))
+}
+
+pub struct MockRecognizer {
+    transcripts: Vec<String>,
+    latency: Duration,
+    next: AtomicUsize,
+}
+
+impl MockRecognizer {
+    pub fn new(transcripts: Vec<String>, latency: Duration) -> Self {
+        let transcripts = if transcripts.is_empty() {
+            DEFAULT_TRANSCRIPTS
+                .iter()
+                .map(|text| text.to_string())
+                .collect()
+        } else {
+            transcripts
+        };
+        Self {
+            transcripts,
+            latency,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let transcripts = std::env::var("OPENFLOW_MOCK_ASR_TRANSCRIPTS")
+            .map(|value| {
+                value
+                    .split('|')
+                    .map(str::trim)
+                    .filter(|text| !text.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let latency = std::env::var("OPENFLOW_MOCK_ASR_LATENCY_MS")
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map_or(DEFAULT_LATENCY, Duration::from_millis);
+        Self::new(transcripts, latency)
+    }
+
+    /// Returns the next canned transcript after the configured latency. With several
+    /// segments its words are revealed segment by segment through `on_segment`, the
+    /// way CT2 reports partial results.
+    pub fn transcribe(&self, segments: usize, on_segment: &mut dyn FnMut(&str)) -> String {
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.transcripts.len();
+        let text = self.transcripts[index].clone();
+        if segments <= 1 {
+            std::thread::sleep(self.latency);
+            return text;
+        }
+
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let per_segment = words.len().div_ceil(segments).max(1);
+        let pause = self.latency / segments as u32;
+        for revealed in (per_segment..words.len()).step_by(per_segment) {
+            std::thread::sleep(pause);
+            on_segment(&words[..revealed].join(" "));
+        }
+        std::thread::sleep(pause);
+        on_segment(&text);
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycles_transcripts_and_reports_cumulative_partials() {
+        let recognizer = MockRecognizer::new(
+            vec!["one two three four".into(), "five".into()],
+            Duration::ZERO,
+        );
+        let mut partials = Vec::new();
+        let text = recognizer.transcribe(2, &mut |partial| partials.push(partial.to_string()));
+        assert_eq!(text, "one two three four");
+        assert_eq!(partials, ["one two", "one two three four"]);
+
+        assert_eq!(recognizer.transcribe(1, &mut |_| {}), "five");
+        assert_eq!(recognizer.transcribe(1, &mut |_| {}), "one two three four");
+    }
+}
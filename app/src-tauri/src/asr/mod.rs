@@ -1,8 +1,19 @@
 #[cfg(feature = "asr-ct2")]
 mod ct2_whisper;
 mod engine;
+#[cfg(feature = "asr-ct2")]
+mod language_id;
+pub mod mock;
+mod sample_ring;
 #[cfg(feature = "asr-sherpa")]
 mod sherpa;
+mod vocabulary;
+mod weights;
+#[cfg(feature = "asr-whispercpp")]
+mod whisper_cpp;
 
 #[allow(unused_imports)]
-pub use engine::{AsrBackend, AsrConfig, AsrEngine, RecognitionResult};
+pub use engine::{
+    AsrBackend, AsrConfig, AsrEngine, GpuFallback, RecognitionResult, DEFAULT_MAX_SESSION_SECS,
+};
+pub use weights::{WeightLoadMode, WeightLoadReport};
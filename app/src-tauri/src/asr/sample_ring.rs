@@ -0,0 +1,139 @@
+use std::sync::atomic::{fence, AtomicU32, AtomicUsize, Ordering};
+
+/// Fixed-capacity sample buffer for one dictation, written by the audio thread and
+/// drained once at finalize.
+///
+/// Single producer, single consumer: only the audio path pushes, only the pipeline
+/// takes/resets. Samples are stored as `f32` bits in atomics so neither side locks;
+/// when a session outgrows the capacity the oldest samples are overwritten.
+pub struct SampleRing {
+    slots: Box<[AtomicU32]>,
+    /// Total samples ever pushed. Only the producer advances it.
+    written: AtomicUsize,
+    /// End of the push in progress, published before its slots are overwritten.
+    reserved: AtomicUsize,
+    /// Logical index of the first sample of the current session.
+    start: AtomicUsize,
+}
+
+impl SampleRing {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            slots: (0..capacity).map(|_| AtomicU32::new(0)).collect(),
+            written: AtomicUsize::new(0),
+            reserved: AtomicUsize::new(0),
+            start: AtomicUsize::new(0),
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Appends samples and returns how many old samples were overwritten to fit them.
+    pub fn push(&self, samples: &[f32]) -> usize {
+        let capacity = self.capacity();
+        let written = self.written.load(Ordering::Relaxed);
+        let end = written + samples.len();
+        self.reserved.store(end, Ordering::Relaxed);
+        // Pairs with the fence in `take`: a reader that sees any slot below overwritten
+        // also sees `reserved` covering it.
+        fence(Ordering::Release);
+        for (offset, sample) in samples.iter().enumerate() {
+            self.slots[(written + offset) % capacity].store(sample.to_bits(), Ordering::Relaxed);
+        }
+        self.written.store(end, Ordering::Release);
+
+        let start = self.start.load(Ordering::Acquire);
+        let overflow_before = written.saturating_sub(start).saturating_sub(capacity);
+        let overflow_after = end.saturating_sub(start).saturating_sub(capacity);
+        overflow_after - overflow_before
+    }
+
+    /// Copies out the current session's samples and starts a new, empty session.
+    pub fn take(&self) -> Vec<f32> {
+        let capacity = self.capacity();
+        let end = self.written.load(Ordering::Acquire);
+        let start = self.start.load(Ordering::Acquire);
+        let begin = start.max(end.saturating_sub(capacity));
+
+        let mut out = Vec::with_capacity(end - begin);
+        out.extend(
+            (begin..end)
+                .map(|index| f32::from_bits(self.slots[index % capacity].load(Ordering::Relaxed))),
+        );
+        // The producer may have lapped the oldest copied slots while we read them; those
+        // now hold newer samples, so drop that prefix.
+        fence(Ordering::Acquire);
+        let reserved = self.reserved.load(Ordering::Relaxed);
+        let overwritten = reserved
+            .saturating_sub(capacity)
+            .saturating_sub(begin)
+            .min(out.len());
+        out.drain(..overwritten);
+        self.start.store(end, Ordering::Release);
+        out
+    }
+
+    /// Discards the current session's samples without copying them.
+    pub fn reset(&self) {
+        let end = self.written.load(Ordering::Acquire);
+        self.start.store(end, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_returns_session_samples_and_reports_overflow() {
+        let ring = SampleRing::new(4);
+        assert_eq!(ring.push(&[1.0, 2.0, 3.0]), 0);
+        assert_eq!(ring.push(&[4.0, 5.0]), 1);
+        assert_eq!(ring.take(), vec![2.0, 3.0, 4.0, 5.0]);
+        assert!(ring.take().is_empty());
+
+        ring.push(&[6.0]);
+        ring.reset();
+        ring.push(&[7.0]);
+        assert_eq!(ring.take(), vec![7.0]);
+    }
+
+    #[test]
+    fn take_never_returns_samples_overwritten_mid_copy() {
+        const TOTAL: usize = 200_000;
+        let ring = std::sync::Arc::new(SampleRing::new(256));
+        let producer = {
+            let ring = std::sync::Arc::clone(&ring);
+            std::thread::spawn(move || {
+                let mut next = 0usize;
+                while next < TOTAL {
+                    let chunk: Vec<f32> =
+                        (next..(next + 64).min(TOTAL)).map(|n| n as f32).collect();
+                    next += chunk.len();
+                    ring.push(&chunk);
+                }
+            })
+        };
+
+        let mut last_seen = -1.0f32;
+        loop {
+            let done = producer.is_finished();
+            let taken = ring.take();
+            for pair in taken.windows(2) {
+                assert_eq!(pair[1], pair[0] + 1.0, "torn copy: {pair:?}");
+            }
+            if let Some(&first) = taken.first() {
+                assert!(first > last_seen, "{first} repeats {last_seen}");
+                last_seen = *taken.last().unwrap_or(&first);
+            }
+            if done {
+                break;
+            }
+        }
+        producer.join().unwrap();
+        assert_eq!(last_seen, (TOTAL - 1) as f32);
+    }
+}
@@ -2,8 +2,13 @@ use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
+use directories::ProjectDirs;
 use sherpa_rs::transducer::{TransducerConfig, TransducerRecognizer};
 use sherpa_rs::whisper::{WhisperConfig, WhisperRecognizer};
+use tracing::info;
+
+/// Log-probability bonus per hotword token; sherpa's examples use 1.5–2.0.
+const HOTWORDS_SCORE: f32 = 1.5;
 
 pub fn load_whisper(
     model_dir: &Path,
@@ -28,12 +33,27 @@ pub fn load_whisper(
     WhisperRecognizer::new(config).map_err(|err| anyhow::anyhow!("init whisper model: {err}"))
 }
 
+/// Loads Parakeet, boosting `hotwords` with modified beam search when the model ships a
+/// BPE vocabulary to tokenize them with. Returns whether hotwords are active.
 pub fn load_parakeet(
     model_dir: &Path,
     provider: &str,
     num_threads: Option<i32>,
-) -> Result<TransducerRecognizer> {
-    let config = TransducerConfig {
+    hotwords: &[String],
+) -> Result<(TransducerRecognizer, bool)> {
+    let hotword_setup = if hotwords.is_empty() {
+        None
+    } else {
+        match find_bpe_vocab(model_dir) {
+            Some(vocab) => Some((write_hotwords_file(hotwords)?, vocab)),
+            None => {
+                info!("parakeet_hotwords_unavailable reason=no-bpe-vocab");
+                None
+            }
+        }
+    };
+
+    let mut config = TransducerConfig {
         encoder: find_component(model_dir, "encoder")?
             .to_string_lossy()
             .into_owned(),
@@ -53,7 +73,30 @@ pub fn load_parakeet(
         provider: Some(provider.to_string()),
         ..Default::default()
     };
-    TransducerRecognizer::new(config).map_err(|err| anyhow::anyhow!("init parakeet model: {err}"))
+    let boosted = hotword_setup.is_some();
+    if let Some((hotwords_file, vocab)) = hotword_setup {
+        config.decoding_method = "modified_beam_search".to_string();
+        config.hotwords_file = hotwords_file.to_string_lossy().into_owned();
+        config.hotwords_score = HOTWORDS_SCORE;
+        config.modeling_unit = "bpe".to_string();
+        config.bpe_vocab = vocab.to_string_lossy().into_owned();
+    }
+    let recognizer = TransducerRecognizer::new(config)
+        .map_err(|err| anyhow::anyhow!("init parakeet model: {err}"))?;
+    Ok((recognizer, boosted))
+}
+
+/// sherpa reads hotwords from a file, one term per line.
+fn write_hotwords_file(hotwords: &[String]) -> Result<PathBuf> {
+    let dir = ProjectDirs::from("com", "OpenFlow", "OpenFlow")
+        .map(|dirs| dirs.cache_dir().to_path_buf())
+        .unwrap_or_else(std::env::temp_dir);
+    std::fs::create_dir_all(&dir).with_context(|| format!("create {}", dir.display()))?;
+    let path = dir.join("hotwords.txt");
+    let mut body = hotwords.join("\n");
+    body.push('\n');
+    std::fs::write(&path, body).with_context(|| format!("write {}", path.display()))?;
+    Ok(path)
 }
 
 fn find_component(model_dir: &Path, component: &str) -> Result<PathBuf> {
@@ -106,6 +149,14 @@ fn find_vocab(model_dir: &Path) -> Option<PathBuf> {
     .ok()
 }
 
+/// `bpe.vocab` as exported by sentencepiece; sherpa tokenizes hotwords with it.
+fn find_bpe_vocab(model_dir: &Path) -> Option<PathBuf> {
+    find_one_level_deep(model_dir, |path| {
+        path.extension() == Some(OsStr::new("vocab"))
+    })
+    .ok()
+}
+
 fn find_one_level_deep<F>(dir: &Path, predicate: F) -> Result<PathBuf>
 where
     F: Fn(&PathBuf) -> bool,
@@ -0,0 +1,155 @@
+//! Custom vocabulary (names, jargon, acronyms) for backends that can't bias decoding.
+//! Parakeet takes the terms as sherpa hotwords; Whisper transcripts are post-corrected
+//! instead: a run of words that nearly spells a term is replaced by the term.
+
+/// Terms shorter than this (once spaces and punctuation are dropped) must match exactly;
+/// fuzzy matching on them would rewrite ordinary short words.
+const MIN_FUZZY_CHARS: usize = 4;
+
+#[derive(Debug, Clone)]
+struct Term {
+    spelling: String,
+    /// Lowercased alphanumerics of the whole term, without spaces.
+    key: Vec<char>,
+    words: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct VocabularyCorrector {
+    /// Longest terms first so "Visual Studio Code" wins over "Visual Studio".
+    terms: Vec<Term>,
+}
+
+impl VocabularyCorrector {
+    pub fn new(terms: &[String]) -> Self {
+        let mut terms: Vec<Term> = terms
+            .iter()
+            .map(|term| term.trim())
+            .filter(|term| !term.is_empty())
+            .map(|term| Term {
+                spelling: term.to_string(),
+                key: normalize(term).chars().collect(),
+                words: term.split_whitespace().count(),
+            })
+            .filter(|term| !term.key.is_empty())
+            .collect();
+        terms.sort_by(|a, b| b.words.cmp(&a.words).then(b.key.len().cmp(&a.key.len())));
+        Self { terms }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn apply(&self, text: &str) -> String {
+        if self.terms.is_empty() {
+            return text.to_string();
+        }
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        let mut out: Vec<String> = Vec::with_capacity(tokens.len());
+        let mut index = 0;
+        while index < tokens.len() {
+            match self.match_at(&tokens[index..]) {
+                Some((term, span)) => {
+                    let first = tokens[index];
+                    let last = tokens[index + span - 1];
+                    let lead = &first[..first.len() - first.trim_start_matches(is_edge).len()];
+                    let trail = &last[last.trim_end_matches(is_edge).len()..];
+                    out.push(format!("{lead}{}{trail}", term.spelling));
+                    index += span;
+                }
+                None => {
+                    out.push(tokens[index].to_string());
+                    index += 1;
+                }
+            }
+        }
+        out.join(" ")
+    }
+
+    /// The first term matching a prefix of `tokens`, with the number of tokens it covers.
+    /// A term may be split into one more word than it has (e.g. "cooper netties").
+    fn match_at(&self, tokens: &[&str]) -> Option<(&Term, usize)> {
+        for term in &self.terms {
+            for span in (1..=(term.words + 1).min(tokens.len())).rev() {
+                let candidate: Vec<char> = tokens[..span]
+                    .iter()
+                    .map(|token| normalize(token))
+                    .collect::<String>()
+                    .chars()
+                    .collect();
+                if candidate.is_empty() || !matches(&term.key, &candidate) {
+                    continue;
+                }
+                let spelled = tokens[..span]
+                    .iter()
+                    .map(|token| token.trim_matches(is_edge))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                if spelled == term.spelling {
+                    // Already right; keep the original tokens.
+                    return None;
+                }
+                return Some((term, span));
+            }
+        }
+        None
+    }
+}
+
+fn is_edge(c: char) -> bool {
+    !c.is_alphanumeric()
+}
+
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn matches(key: &[char], candidate: &[char]) -> bool {
+    if key == candidate {
+        return true;
+    }
+    if key.len() < MIN_FUZZY_CHARS {
+        return false;
+    }
+    let allowed = (key.len() / 4).max(1);
+    key.len().abs_diff(candidate.len()) <= allowed && edit_distance(key, candidate) <= allowed
+}
+
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snaps_near_misses_to_the_term_spelling() {
+        let corrector = VocabularyCorrector::new(&[
+            "Kubernetes".to_string(),
+            "OpenFlow".to_string(),
+            "API".to_string(),
+        ]);
+        assert_eq!(
+            corrector.apply("Deploy open flow to kubernetis, then call the a.p.i."),
+            "Deploy OpenFlow to Kubernetes, then call the API."
+        );
+        // Short words are only replaced on an exact match.
+        assert_eq!(corrector.apply("a pie"), "a pie");
+        assert_eq!(corrector.apply("OpenFlow works"), "OpenFlow works");
+    }
+}
@@ -0,0 +1,171 @@
+//! Model weight loading modes.
+//!
+//! Neither ct2rs nor sherpa-rs can hand the backend a memory-mapped model: both
+//! read the weight files into their own heap buffers. What we can do is map the
+//! files read-only and prefetch them through the page cache before the backend
+//! reads them. The pages are shared between processes and survive restarts, so a
+//! second instance or a fast relaunch loads from memory instead of disk. The
+//! backend's private copy is still resident once it has loaded.
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use memmap2::{Advice, Mmap};
+use serde::Serialize;
+
+use super::engine::AsrBackend;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum WeightLoadMode {
+    /// Weights were mapped and prefetched through the shared page cache first.
+    MappedPrefetch,
+    /// The backend read the files itself.
+    Direct,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeightLoadReport {
+    pub backend: AsrBackend,
+    pub mode: WeightLoadMode,
+    pub weight_files: usize,
+    pub weight_bytes: u64,
+    pub elapsed_ms: u64,
+}
+
+/// Read-only mappings kept alive while the backend loads.
+struct PrefetchedWeights {
+    _maps: Vec<Mmap>,
+    files: usize,
+    bytes: u64,
+}
+
+/// Runs `load`, prefetching the weight files first when `mmap` is set. Falls back
+/// to a direct load if mapping fails.
+pub fn load_with_mode<T>(
+    backend: &AsrBackend,
+    model_dir: &Path,
+    mmap: bool,
+    load: impl FnOnce() -> Result<T>,
+) -> Result<(T, WeightLoadReport)> {
+    let started = Instant::now();
+    let files = weight_files(backend, model_dir);
+    let prefetched = if mmap {
+        match prefetch(&files) {
+            Ok(prefetched) => Some(prefetched),
+            Err(error) => {
+                tracing::warn!("Weight prefetch failed, loading directly: {error:?}");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let loaded = load()?;
+    let (mode, weight_files, weight_bytes) = match &prefetched {
+        Some(prefetched) => (
+            WeightLoadMode::MappedPrefetch,
+            prefetched.files,
+            prefetched.bytes,
+        ),
+        None => (
+            WeightLoadMode::Direct,
+            files.len(),
+            files
+                .iter()
+                .filter_map(|path| fs::metadata(path).ok())
+                .map(|meta| meta.len())
+                .sum(),
+        ),
+    };
+    drop(prefetched);
+
+    Ok((
+        loaded,
+        WeightLoadReport {
+            backend: backend.clone(),
+            mode,
+            weight_files,
+            weight_bytes,
+            elapsed_ms: started.elapsed().as_millis() as u64,
+        },
+    ))
+}
+
+fn prefetch(files: &[PathBuf]) -> Result<PrefetchedWeights> {
+    let mut maps = Vec::with_capacity(files.len());
+    let mut bytes = 0u64;
+    for path in files {
+        let file = File::open(path).with_context(|| format!("open {path:?}"))?;
+        // Safety: the mapping is read-only and only lives until the backend has
+        // read the same file; model revisions are never modified in place.
+        let map = unsafe { Mmap::map(&file) }.with_context(|| format!("mmap {path:?}"))?;
+        if let Err(error) = map.advise(Advice::WillNeed) {
+            tracing::debug!("madvise(WILLNEED) failed for {path:?}: {error}");
+        }
+        bytes += map.len() as u64;
+        maps.push(map);
+    }
+    Ok(PrefetchedWeights {
+        files: maps.len(),
+        _maps: maps,
+        bytes,
+    })
+}
+
+/// Files holding the bulk of the weights: `model.bin` for CT2, the ONNX graphs
+/// (up to one directory deep, as sherpa looks them up) otherwise.
+fn weight_files(backend: &AsrBackend, model_dir: &Path) -> Vec<PathBuf> {
+    if *backend == AsrBackend::WhisperCpp {
+        // A single ggml/GGUF file holds the whole model.
+        return fs::read_dir(model_dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| {
+                        path.is_file()
+                            && path
+                                .extension()
+                                .is_some_and(|ext| ext == "bin" || ext == "gguf")
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+    }
+    if *backend == AsrBackend::WhisperCt2 {
+        let model = model_dir.join("model.bin");
+        return if model.is_file() {
+            vec![model]
+        } else {
+            Vec::new()
+        };
+    }
+
+    let mut files = Vec::new();
+    let mut dirs = vec![model_dir.to_path_buf()];
+    if let Ok(entries) = fs::read_dir(model_dir) {
+        dirs.extend(
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir()),
+        );
+    }
+    for dir in dirs {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        files.extend(
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "onnx")),
+        );
+    }
+    files
+}
@@ -0,0 +1,74 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
+
+/// Model file extensions whisper.cpp can load.
+const MODEL_EXTENSIONS: [&str; 2] = ["bin", "gguf"];
+
+pub fn load_whisper(model_dir: &Path, device: &str) -> Result<WhisperContext> {
+    let model = find_model_file(model_dir)
+        .ok_or_else(|| anyhow::anyhow!("no whisper.cpp model file in {}", model_dir.display()))?;
+    let path = model
+        .to_str()
+        .ok_or_else(|| anyhow::anyhow!("model path is not UTF-8: {}", model.display()))?;
+
+    let mut params = WhisperContextParameters::default();
+    params.use_gpu = device != "cpu";
+    WhisperContext::new_with_params(path, params).context("init whisper.cpp")
+}
+
+pub fn transcribe(
+    context: &WhisperContext,
+    samples: &[f32],
+    language: Option<&str>,
+    num_threads: Option<i32>,
+) -> Result<String> {
+    let mut state = context.create_state().context("create whisper.cpp state")?;
+
+    let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+    // whisper.cpp detects the language itself when given "auto".
+    params.set_language(Some(match language {
+        Some(lang) if !lang.trim().is_empty() => lang,
+        _ => "auto",
+    }));
+    if let Some(threads) = num_threads.filter(|threads| *threads > 0) {
+        params.set_n_threads(threads);
+    }
+    params.set_no_context(true);
+    params.set_suppress_blank(true);
+    params.set_print_special(false);
+    params.set_print_progress(false);
+    params.set_print_realtime(false);
+    params.set_print_timestamps(false);
+
+    state.full(params, samples).context("whisper.cpp decode")?;
+    let segments = state.full_n_segments().context("whisper.cpp segments")?;
+    let mut text = String::new();
+    for index in 0..segments {
+        text.push_str(
+            &state
+                .full_get_segment_text(index)
+                .context("whisper.cpp segment text")?,
+        );
+    }
+    Ok(text.trim().to_string())
+}
+
+/// The model file in `model_dir`: a single ggml `.bin` or `.gguf` file.
+fn find_model_file(model_dir: &Path) -> Option<PathBuf> {
+    let mut candidates: Vec<PathBuf> = std::fs::read_dir(model_dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| MODEL_EXTENSIONS.contains(&ext))
+        })
+        .collect();
+    candidates.sort();
+    candidates.into_iter().next()
+}
@@ -0,0 +1,266 @@
+//! Bluetooth headsets through PipeWire's PulseAudio interface (`pactl`).
+//!
+//! A headset's mic only exists in its hands-free profile (HFP/HSP), which caps capture
+//! at 8 kHz (CVSD) or 16 kHz (mSBC) mono; A2DP plays back well but has no mic.
+
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde_json::Value;
+use tracing::{info, warn};
+
+use crate::audio::preferred_device_index;
+use crate::core::i18n;
+use crate::core::linux_setup::binary_in_path;
+
+const PACTL: &str = "pactl";
+/// Hands-free profiles in order of preference; wideband mSBC first.
+const HANDS_FREE_PROFILES: &[&str] = &[
+    "headset-head-unit-msbc",
+    "headset-head-unit",
+    "headset-head-unit-cvsd",
+    "handsfree_head_unit",
+];
+
+/// Last detected Bluetooth input; refreshed off-thread at session start so diagnostics
+/// don't spawn `pactl`.
+static LAST_INPUT: Lazy<Mutex<Option<BluetoothInput>>> = Lazy::new(|| Mutex::new(None));
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BluetoothInput {
+    pub source: String,
+    pub sample_rate: Option<u32>,
+}
+
+impl BluetoothInput {
+    /// Diagnostics warning for the degraded hands-free capture.
+    pub fn warning(&self) -> String {
+        match self.sample_rate {
+            Some(rate) => i18n::message(
+                "audio-bluetooth-hands-free-rate",
+                &[
+                    ("source", &self.source),
+                    ("khz", &(rate / 1000).to_string()),
+                ],
+            ),
+            None => i18n::message("audio-bluetooth-hands-free", &[("source", &self.source)]),
+        }
+    }
+}
+
+/// A card moved to its hands-free profile for a session; `restore` moves it back.
+#[derive(Debug)]
+pub struct ProfileSwitch {
+    card: String,
+    previous: String,
+}
+
+impl ProfileSwitch {
+    pub fn restore(self) -> Result<()> {
+        set_card_profile(&self.card, &self.previous)?;
+        info!(
+            "bluetooth_profile_restored card={} profile={}",
+            self.card, self.previous
+        );
+        Ok(())
+    }
+}
+
+pub fn available() -> bool {
+    binary_in_path(PACTL)
+}
+
+pub fn last_input() -> Option<BluetoothInput> {
+    LAST_INPUT.lock().clone()
+}
+
+/// Re-detects the default source on a background thread and warns once per change to
+/// a degraded Bluetooth input.
+pub fn refresh_in_background() {
+    if !available() {
+        return;
+    }
+    let spawned = std::thread::Builder::new()
+        .name("bluetooth-detect".into())
+        .spawn(|| {
+            let detected = match default_input() {
+                Ok(detected) => detected,
+                Err(error) => {
+                    warn!("bluetooth_detect_failed error={error:#}");
+                    None
+                }
+            };
+            let mut guard = LAST_INPUT.lock();
+            if detected != *guard {
+                if let Some(input) = &detected {
+                    warn!(
+                        "bluetooth_input_degraded source={} sample_rate={:?}",
+                        input.source, input.sample_rate
+                    );
+                }
+                *guard = detected;
+            }
+        });
+    if let Err(error) = spawned {
+        warn!("failed to spawn bluetooth detection: {error}");
+    }
+}
+
+/// The default source when it is a Bluetooth headset mic.
+pub fn default_input() -> Result<Option<BluetoothInput>> {
+    let source = pactl(&["get-default-source"])?.trim().to_string();
+    if !source.starts_with("bluez_") {
+        return Ok(None);
+    }
+    let sources: Value = serde_json::from_str(&pactl(&["--format=json", "list", "sources"])?)
+        .context("parse pactl sources")?;
+    let sample_rate = sources
+        .as_array()
+        .and_then(|sources| {
+            sources
+                .iter()
+                .find(|entry| entry.get("name").and_then(Value::as_str) == Some(&source))
+        })
+        .and_then(|entry| entry.get("sample_specification").and_then(Value::as_str))
+        .and_then(parse_sample_rate);
+    Ok(Some(BluetoothInput {
+        source,
+        sample_rate,
+    }))
+}
+
+/// Moves the Bluetooth card of the selected input to its best hands-free profile when
+/// it is in a mic-less profile (A2DP). The card must match one of the input device
+/// `preferences`, so other headphones keep their playback quality. `None` when there is
+/// nothing to switch.
+pub fn switch_to_hands_free(preferences: &[String]) -> Result<Option<ProfileSwitch>> {
+    if preferences.is_empty() {
+        return Ok(None);
+    }
+    let cards: Value = serde_json::from_str(&pactl(&["--format=json", "list", "cards"])?)
+        .context("parse pactl cards")?;
+    let Some(cards) = cards.as_array() else {
+        return Ok(None);
+    };
+    for card in cards {
+        let Some(name) = card.get("name").and_then(Value::as_str) else {
+            continue;
+        };
+        if !name.starts_with("bluez_card.") || !card_matches(card, preferences) {
+            continue;
+        }
+        let active = card
+            .get("active_profile")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        if !active.starts_with("a2dp") {
+            continue;
+        }
+        let Some(profile) = hands_free_profile(card) else {
+            continue;
+        };
+        set_card_profile(name, &profile)?;
+        info!("bluetooth_profile_switched card={name} from={active} to={profile}");
+        return Ok(Some(ProfileSwitch {
+            card: name.to_string(),
+            previous: active.to_string(),
+        }));
+    }
+    Ok(None)
+}
+
+/// Whether a preferred input device names this card, by its description or alias.
+fn card_matches(card: &Value, preferences: &[String]) -> bool {
+    let Some(properties) = card.get("properties") else {
+        return false;
+    };
+    let names: Vec<String> = ["device.description", "device.alias", "bluez.alias"]
+        .iter()
+        .filter_map(|key| properties.get(*key).and_then(Value::as_str))
+        .filter(|name| !name.trim().is_empty())
+        .map(str::to_string)
+        .collect();
+    preferred_device_index(preferences, &names).is_some()
+}
+
+fn hands_free_profile(card: &Value) -> Option<String> {
+    let profiles = card.get("profiles")?.as_object()?;
+    HANDS_FREE_PROFILES
+        .iter()
+        .find(|name| {
+            profiles.get(**name).is_some_and(|profile| {
+                profile
+                    .get("available")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(true)
+            })
+        })
+        .map(|name| name.to_string())
+}
+
+/// "s16le 1ch 16000Hz" -> 16000.
+fn parse_sample_rate(spec: &str) -> Option<u32> {
+    spec.split_whitespace()
+        .find_map(|part| part.strip_suffix("Hz"))
+        .and_then(|rate| rate.parse().ok())
+}
+
+fn set_card_profile(card: &str, profile: &str) -> Result<()> {
+    pactl(&["set-card-profile", card, profile]).map(|_| ())
+}
+
+fn pactl(args: &[&str]) -> Result<String> {
+    let output = Command::new(PACTL)
+        .args(args)
+        .env("LC_ALL", "C")
+        .output()
+        .context("spawn pactl")?;
+    if !output.status.success() {
+        bail!(
+            "pactl {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefers_wideband_hands_free_profile() {
+        let card = serde_json::json!({
+            "name": "bluez_card.00_11_22_33_44_55",
+            "active_profile": "a2dp-sink",
+            "profiles": {
+                "a2dp-sink": { "available": true },
+                "headset-head-unit-cvsd": { "available": true },
+                "headset-head-unit-msbc": { "available": true },
+                "off": { "available": true }
+            }
+        });
+        assert_eq!(
+            hands_free_profile(&card).as_deref(),
+            Some("headset-head-unit-msbc")
+        );
+        assert_eq!(parse_sample_rate("s16le 1ch 16000Hz"), Some(16_000));
+    }
+
+    #[test]
+    fn only_switches_the_card_of_a_preferred_input() {
+        let card = serde_json::json!({
+            "name": "bluez_card.00_11_22_33_44_55",
+            "properties": { "device.description": "Jabra Evolve2 65" }
+        });
+        assert!(card_matches(&card, &["jabra".to_string()]));
+        assert!(!card_matches(&card, &["Sony WH-1000XM4".to_string()]));
+        assert!(!card_matches(
+            &serde_json::json!({ "name": "bluez_card.x" }),
+            &["jabra".to_string()]
+        ));
+    }
+}
@@ -0,0 +1,64 @@
+use crossbeam_channel::{bounded, Receiver, Sender};
+
+/// Frames kept for reuse; enough for the capture channel plus the standby pre-roll.
+const POOL_CAPACITY: usize = 128;
+
+/// Recycles audio frame buffers between the capture callback and the consumer so the
+/// 20 ms hot path doesn't allocate once the pool is warm.
+///
+/// Consumers hand frames back with [`FramePool::release`] when done; frames that are
+/// dropped instead are simply reallocated later.
+#[derive(Clone)]
+pub struct FramePool {
+    free_tx: Sender<Vec<f32>>,
+    free_rx: Receiver<Vec<f32>>,
+}
+
+impl FramePool {
+    pub fn new() -> Self {
+        let (free_tx, free_rx) = bounded(POOL_CAPACITY);
+        Self { free_tx, free_rx }
+    }
+
+    /// Empty buffer with at least `capacity` samples reserved.
+    pub fn acquire(&self, capacity: usize) -> Vec<f32> {
+        match self.free_rx.try_recv() {
+            Ok(mut frame) => {
+                frame.clear();
+                frame.reserve(capacity);
+                frame
+            }
+            Err(_) => Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn release(&self, frame: Vec<f32>) {
+        if frame.capacity() > 0 {
+            let _ = self.free_tx.try_send(frame);
+        }
+    }
+}
+
+impl Default for FramePool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reuses_released_buffers() {
+        let pool = FramePool::new();
+        let mut frame = pool.acquire(320);
+        frame.extend_from_slice(&[0.5; 320]);
+        let ptr = frame.as_ptr();
+        pool.release(frame);
+
+        let reused = pool.acquire(320);
+        assert!(reused.is_empty());
+        assert_eq!(reused.as_ptr(), ptr);
+    }
+}
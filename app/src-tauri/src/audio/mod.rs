@@ -1,7 +1,11 @@
+pub mod bluetooth;
+mod frame_pool;
 mod pipeline;
 mod preprocess;
 
+pub use frame_pool::FramePool;
 pub use pipeline::{
-    list_input_devices, AudioDeviceInfo, AudioEvent, AudioPipeline, AudioPipelineConfig,
+    list_input_devices, preferred_device_index, AudioDeviceInfo, AudioEvent, AudioPipeline,
+    AudioPipelineConfig,
 };
 pub use preprocess::AudioPreprocessor;
@@ -1,3 +1,5 @@
+#[cfg(feature = "real-audio")]
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -9,19 +11,32 @@ use tauri::async_runtime::JoinHandle;
 use tracing::warn;
 use tracing::{debug, info};
 
+use super::FramePool;
+
 const DEFAULT_SAMPLE_RATE: u32 = 16_000;
 const DEFAULT_FRAME_LEN: usize = 320;
 const DEFAULT_FRAME_INTERVAL: Duration = Duration::from_millis(20);
+/// How long an on-demand device stays open after a session, so back-to-back
+/// dictations skip the device open latency and keep a pre-roll buffer warm.
+#[cfg(feature = "real-audio")]
+const ON_DEMAND_STANDBY: Duration = Duration::from_secs(3);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
 pub struct AudioPipelineConfig {
-    pub device_id: Option<String>,
+    /// Input device names in priority order; the first one present is opened, else the
+    /// system default.
+    pub device_preferences: Vec<String>,
+    /// Open the input device only while dictating instead of capturing continuously.
+    pub on_demand: bool,
 }
 
 impl Default for AudioPipelineConfig {
     fn default() -> Self {
-        Self { device_id: None }
+        Self {
+            device_preferences: Vec::new(),
+            on_demand: false,
+        }
     }
 }
 
@@ -34,11 +49,14 @@ pub enum AudioEvent {
 pub struct AudioPipeline {
     #[cfg(feature = "real-audio")]
     real_audio: Arc<Mutex<Option<RealAudioHandle>>>,
-    _worker: JoinHandle<()>,
+    /// Bumped on every open/close request so a stale standby close is ignored.
+    #[cfg(feature = "real-audio")]
+    capture_generation: Arc<AtomicU64>,
+    worker: JoinHandle<()>,
     receiver: Receiver<AudioEvent>,
     sender: Sender<AudioEvent>,
+    pool: FramePool,
     config: Arc<AudioPipelineConfig>,
-    device_id: Option<String>,
     sample_rate: u32,
     synthetic: bool,
 }
@@ -56,12 +74,16 @@ impl AudioPipeline {
         let (tx, rx) = bounded(16);
         let (out_tx, out_rx) = bounded(64);
         let config = Arc::new(config);
+        let pool = FramePool::new();
         #[cfg(feature = "real-audio")]
         let (real_audio, sample_rate) =
-            match RealAudioHandle::spawn(Arc::clone(&config), tx.clone()) {
+            match RealAudioHandle::spawn(Arc::clone(&config), tx.clone(), pool.clone()) {
                 Ok(handle) => {
                     let rate = handle.sample_rate();
-                    info!("real audio capture started (sample_rate={rate}Hz)");
+                    info!(
+                        "real audio capture started (device={}, sample_rate={rate}Hz)",
+                        handle.device_name
+                    );
                     (Some(handle), rate)
                 }
                 Err(error) => {
@@ -76,12 +98,24 @@ impl AudioPipeline {
         let sample_rate: u32 = DEFAULT_SAMPLE_RATE;
 
         let use_synthetic = real_audio.is_none();
+        // On-demand capture only needed the device to learn its sample rate; close it
+        // until the first session.
+        #[cfg(feature = "real-audio")]
+        let real_audio = if config.on_demand {
+            if real_audio.is_some() {
+                info!("audio capture on-demand; closing device until dictation starts");
+            }
+            drop(real_audio);
+            None
+        } else {
+            real_audio
+        };
         #[cfg(feature = "real-audio")]
         let real_audio = Arc::new(Mutex::new(real_audio));
+        let worker_pool = pool.clone();
         let worker = tauri::async_runtime::spawn(async move {
             info!("audio pipeline worker started (synthetic={use_synthetic})");
             let mut phase = 0.0f32;
-            let mut frame = Vec::with_capacity(DEFAULT_FRAME_LEN);
             let mut tick = tokio::time::interval(DEFAULT_FRAME_INTERVAL);
 
             loop {
@@ -91,14 +125,17 @@ impl AudioPipeline {
 
                 if use_synthetic {
                     tick.tick().await;
-                    frame.clear();
+                    let mut frame = worker_pool.acquire(DEFAULT_FRAME_LEN);
                     for _ in 0..DEFAULT_FRAME_LEN {
                         let sample = (phase * 2.0 * std::f32::consts::PI).sin() * 0.03;
                         frame.push(sample);
                         phase = (phase + 0.01) % 1.0;
                     }
-                    if out_tx.try_send(AudioEvent::Frame(frame.clone())).is_err() {
+                    if let Err(error) = out_tx.try_send(AudioEvent::Frame(frame)) {
                         debug!("audio frame dropped (backpressure)");
+                        if let AudioEvent::Frame(frame) = error.into_inner() {
+                            worker_pool.release(frame);
+                        }
                     }
                 } else {
                     tokio::time::sleep(Duration::from_millis(5)).await;
@@ -109,11 +146,13 @@ impl AudioPipeline {
         Self {
             #[cfg(feature = "real-audio")]
             real_audio,
-            _worker: worker,
+            #[cfg(feature = "real-audio")]
+            capture_generation: Arc::new(AtomicU64::new(0)),
+            worker,
             receiver: out_rx,
             sender: tx,
-            config: Arc::clone(&config),
-            device_id: config.device_id.clone(),
+            pool,
+            config,
             sample_rate,
             synthetic: use_synthetic,
         }
@@ -123,8 +162,30 @@ impl AudioPipeline {
         self.receiver.clone()
     }
 
-    pub fn device_id(&self) -> Option<String> {
-        self.device_id.clone()
+    /// Pool that subscribers should return finished frames to.
+    pub fn frame_pool(&self) -> FramePool {
+        self.pool.clone()
+    }
+
+    pub fn device_preferences(&self) -> Vec<String> {
+        self.config.device_preferences.clone()
+    }
+
+    /// Name of the input device currently open, if any.
+    pub fn active_device(&self) -> Option<String> {
+        #[cfg(feature = "real-audio")]
+        {
+            return self
+                .real_audio
+                .lock()
+                .as_ref()
+                .map(|handle| handle.device_name.clone());
+        }
+
+        #[cfg(not(feature = "real-audio"))]
+        {
+            None
+        }
     }
 
     pub fn sample_rate(&self) -> u32 {
@@ -135,6 +196,139 @@ impl AudioPipeline {
         self.synthetic
     }
 
+    pub fn on_demand(&self) -> bool {
+        self.config.on_demand
+    }
+
+    /// Whether the input device is currently open. Always true for continuous and
+    /// synthetic capture.
+    pub fn capture_open(&self) -> bool {
+        #[cfg(feature = "real-audio")]
+        {
+            if self.config.on_demand && !self.synthetic {
+                return self.real_audio.lock().is_some();
+            }
+        }
+        true
+    }
+
+    /// Opens the input device for a session when capturing on demand, cancelling any
+    /// pending standby close. Continuous capture instead moves to a higher-priority
+    /// device that appeared since it was opened.
+    pub fn begin_capture(&self) -> anyhow::Result<()> {
+        #[cfg(feature = "real-audio")]
+        {
+            if self.synthetic {
+                return Ok(());
+            }
+            if !self.config.on_demand {
+                self.reselect_device();
+                return Ok(());
+            }
+            self.capture_generation.fetch_add(1, Ordering::SeqCst);
+            let mut guard = self.real_audio.lock();
+            if guard.is_none() {
+                let handle = RealAudioHandle::spawn(
+                    Arc::clone(&self.config),
+                    self.sender.clone(),
+                    self.pool.clone(),
+                )?;
+                if handle.sample_rate() != self.sample_rate {
+                    warn!(
+                        "on-demand capture opened at {}Hz, expected {}Hz",
+                        handle.sample_rate(),
+                        self.sample_rate
+                    );
+                }
+                info!(
+                    "audio capture opened (on-demand, device={})",
+                    handle.device_name
+                );
+                *guard = Some(handle);
+            }
+        }
+        Ok(())
+    }
+
+    /// Closes the input device and stops forwarding frames, which ends subscribers'
+    /// receive loops. Used on app exit.
+    pub fn shutdown(&self) {
+        #[cfg(feature = "real-audio")]
+        {
+            self.capture_generation.fetch_add(1, Ordering::SeqCst);
+            // Dropping the handle joins the capture thread.
+            drop(self.real_audio.lock().take());
+        }
+        self.worker.abort();
+        info!("audio pipeline stopped");
+    }
+
+    /// Closes an on-demand device once it has stayed unused for the standby period.
+    pub fn end_capture(&self) {
+        #[cfg(feature = "real-audio")]
+        {
+            if !self.config.on_demand || self.synthetic {
+                return;
+            }
+            let generation = self.capture_generation.fetch_add(1, Ordering::SeqCst) + 1;
+            let real_audio = Arc::clone(&self.real_audio);
+            let capture_generation = Arc::clone(&self.capture_generation);
+            let spawned = std::thread::Builder::new()
+                .name("audio-standby".into())
+                .spawn(move || {
+                    std::thread::sleep(ON_DEMAND_STANDBY);
+                    // Check under the lock so a concurrent begin_capture can't be undone.
+                    let mut guard = real_audio.lock();
+                    if capture_generation.load(Ordering::SeqCst) != generation {
+                        return;
+                    }
+                    if guard.take().is_some() {
+                        info!("audio capture closed (on-demand standby elapsed)");
+                    }
+                });
+            if let Err(error) = spawned {
+                warn!("failed to schedule audio capture close: {error}");
+            }
+        }
+    }
+
+    #[cfg(feature = "real-audio")]
+    fn reselect_device(&self) {
+        if self.config.device_preferences.is_empty() {
+            return;
+        }
+        let mut guard = self.real_audio.lock();
+        let Some(current) = guard.as_ref().map(|handle| handle.device_name.clone()) else {
+            return;
+        };
+        let names: Vec<String> = list_input_devices()
+            .into_iter()
+            .map(|device| device.name)
+            .collect();
+        let Some(index) = preferred_device_index(&self.config.device_preferences, &names) else {
+            return;
+        };
+        if names[index] == current {
+            return;
+        }
+        match RealAudioHandle::spawn(
+            Arc::clone(&self.config),
+            self.sender.clone(),
+            self.pool.clone(),
+        ) {
+            Ok(handle) => {
+                info!(
+                    "audio_device_switched from={current:?} to={:?}",
+                    handle.device_name
+                );
+                *guard = Some(handle);
+            }
+            Err(error) => warn!("audio_device_switch_failed error={error:#}"),
+        }
+    }
+
+    /// Reopens the input device, picking the highest-priority device that is still
+    /// present; used by the watchdog when frames stop arriving.
     pub fn restart_capture(&self) -> anyhow::Result<bool> {
         #[cfg(feature = "real-audio")]
         {
@@ -143,8 +337,11 @@ impl AudioPipeline {
                 return Ok(false);
             }
 
-            let replacement =
-                RealAudioHandle::spawn(Arc::clone(&self.config), self.sender.clone())?;
+            let replacement = RealAudioHandle::spawn(
+                Arc::clone(&self.config),
+                self.sender.clone(),
+                self.pool.clone(),
+            )?;
             *guard = Some(replacement);
             return Ok(true);
         }
@@ -191,6 +388,23 @@ pub fn list_input_devices() -> Vec<AudioDeviceInfo> {
     }
 }
 
+/// Index into `names` of the first preference that is present. An exact name wins;
+/// otherwise a preference matches a device whose name contains it, ignoring case, so
+/// "jabra" survives the device's name changing with its port or profile.
+pub fn preferred_device_index(preferences: &[String], names: &[String]) -> Option<usize> {
+    preferences.iter().find_map(|preference| {
+        names
+            .iter()
+            .position(|name| name == preference)
+            .or_else(|| {
+                let needle = preference.to_lowercase();
+                names
+                    .iter()
+                    .position(|name| name.to_lowercase().contains(&needle))
+            })
+    })
+}
+
 /// Get the preferred audio host, avoiding JACK on Linux to reduce startup noise
 #[cfg(feature = "real-audio")]
 fn get_preferred_host() -> cpal::Host {
@@ -209,32 +423,46 @@ struct RealAudioHandle {
     stop: Sender<()>,
     thread: Option<std::thread::JoinHandle<()>>,
     sample_rate: u32,
+    device_name: String,
 }
 
 #[cfg(feature = "real-audio")]
 impl RealAudioHandle {
-    fn spawn(config: Arc<AudioPipelineConfig>, sender: Sender<AudioEvent>) -> anyhow::Result<Self> {
+    fn spawn(
+        config: Arc<AudioPipelineConfig>,
+        sender: Sender<AudioEvent>,
+        pool: FramePool,
+    ) -> anyhow::Result<Self> {
         use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 
         let (stop_tx, stop_rx) = bounded::<()>(1);
-        let (ready_tx, ready_rx) = bounded::<Result<u32, anyhow::Error>>(1);
+        let (ready_tx, ready_rx) = bounded::<Result<(u32, String), anyhow::Error>>(1);
 
         let thread = std::thread::spawn(move || {
             let startup = || -> anyhow::Result<()> {
                 let host = get_preferred_host();
-                let device = if let Some(device_id) = &config.device_id {
-                    host.input_devices()
-                        .ok()
-                        .and_then(|devices| {
-                            devices
-                                .into_iter()
-                                .find(|d| d.name().ok().as_ref() == Some(device_id))
-                        })
-                        .or_else(|| host.default_input_device())
-                } else {
-                    host.default_input_device()
+                let mut devices: Vec<cpal::Device> = host
+                    .input_devices()
+                    .map(|devices| devices.collect())
+                    .unwrap_or_default();
+                let names: Vec<String> = devices
+                    .iter()
+                    .map(|device| device.name().unwrap_or_default())
+                    .collect();
+                let device = match preferred_device_index(&config.device_preferences, &names) {
+                    Some(index) => Some(devices.swap_remove(index)),
+                    None => {
+                        if !config.device_preferences.is_empty() {
+                            warn!(
+                                "audio_device_fallback preferences={:?} reason=none_present",
+                                config.device_preferences
+                            );
+                        }
+                        host.default_input_device()
+                    }
                 }
                 .ok_or_else(|| anyhow::anyhow!("no input device available"))?;
+                let device_name = device.name().unwrap_or_default();
 
                 let desired_sample_rate = DEFAULT_SAMPLE_RATE;
                 let stream_config = device
@@ -260,21 +488,22 @@ impl RealAudioHandle {
 
                 let channels = stream_config.channels as usize;
                 let frame_samples = ((stream_config.sample_rate.0 as usize) * 20) / 1000;
-                let mut buffer = Vec::with_capacity(frame_samples);
+                let mut current = pool.acquire(frame_samples);
                 let sender_clone = sender.clone();
 
                 let stream = device.build_input_stream(
                     &stream_config,
                     move |data: &[f32], _| {
+                        // Fill pooled frames directly; a full frame is handed off as-is.
                         for frame in data.chunks(channels) {
-                            let sample = frame.get(0).copied().unwrap_or(0.0);
-                            buffer.push(sample);
-                            if buffer.len() >= frame_samples {
-                                let mut out = Vec::with_capacity(frame_samples);
-                                out.extend_from_slice(&buffer[..frame_samples]);
-                                buffer.drain(..frame_samples);
-                                if sender_clone.try_send(AudioEvent::Frame(out)).is_err() {
-                                    buffer.clear();
+                            current.push(frame.first().copied().unwrap_or(0.0));
+                            if current.len() >= frame_samples {
+                                let out =
+                                    std::mem::replace(&mut current, pool.acquire(frame_samples));
+                                if let Err(error) = sender_clone.try_send(AudioEvent::Frame(out)) {
+                                    if let AudioEvent::Frame(out) = error.into_inner() {
+                                        pool.release(out);
+                                    }
                                 }
                             }
                         }
@@ -284,7 +513,7 @@ impl RealAudioHandle {
                 )?;
 
                 stream.play()?;
-                let _ = ready_tx.send(Ok(stream_config.sample_rate.0));
+                let _ = ready_tx.send(Ok((stream_config.sample_rate.0, device_name)));
 
                 while stop_rx.recv_timeout(Duration::from_millis(200)).is_err() {}
 
@@ -299,10 +528,11 @@ impl RealAudioHandle {
         });
 
         match ready_rx.recv() {
-            Ok(Ok(sample_rate)) => Ok(Self {
+            Ok(Ok((sample_rate, device_name))) => Ok(Self {
                 stop: stop_tx,
                 thread: Some(thread),
                 sample_rate,
+                device_name,
             }),
             Ok(Err(error)) => {
                 let _ = stop_tx.send(());
@@ -333,3 +563,21 @@ impl Drop for RealAudioHandle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_present_preference_wins() {
+        let names = vec![
+            "HD Pro Webcam C920".to_string(),
+            "Jabra Evolve2 65 Mono".to_string(),
+            "default".to_string(),
+        ];
+        let preferences = vec!["jabra".to_string(), "HD Pro Webcam C920".to_string()];
+        assert_eq!(preferred_device_index(&preferences, &names), Some(1));
+        assert_eq!(preferred_device_index(&preferences, &names[..1]), Some(0));
+        assert_eq!(preferred_device_index(&preferences, &names[2..]), None);
+    }
+}
@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex as StdMutex};
 use std::time::Instant;
@@ -5,23 +6,55 @@ use std::time::Instant;
 use anyhow::{anyhow, Result};
 use parking_lot::Mutex;
 
-use crate::asr::{AsrBackend, AsrConfig};
+use crate::asr::{AsrBackend, AsrConfig, WeightLoadReport};
 use crate::audio::AudioPipelineConfig;
+use crate::core::debug_capture;
 use crate::core::events;
-use crate::llm::AutocleanMode;
+use crate::core::failed_audio;
+use crate::core::fullscreen;
+use crate::core::history::{ExportFormat, ExportRange, ExportSummary, HistoryEntry, HistoryStore};
+use crate::core::i18n;
+use crate::core::performance;
+use crate::core::privacy;
+use crate::core::simulation;
+use crate::core::startup_warmup::{self, StartupTrigger};
+use crate::llm::{
+    AutocleanMode, CorrectionStore, CorrectionSummary, FormattingLocale, PolishApi, PolishConfig,
+    RedactionRules,
+};
 use crate::models::{
-    sync_runtime_environment, ModelDownloadJob, ModelDownloadService, ModelKind, ModelManager,
-    ModelStatus,
+    check_model_updates, rollback_model_update, sync_runtime_environment, DownloadPriority,
+    ModelAsset, ModelDownloadJob, ModelDownloadService, ModelKind, ModelManager, ModelStatus,
+    QueuedDownload, QueuedDownloadKey,
 };
+use crate::output::notify;
+use crate::output::sound::{self, SoundCue, SoundCueConfig, SoundTheme};
+use crate::output::tray;
 use crate::output::PasteShortcut;
-use crate::vad::VadConfig;
+use crate::vad::{SpeakerGate, SpeakerProfileSummary, VadConfig, SPEAKER_MODEL_ENV};
 use tauri::window::Color;
 use tauri::WebviewUrl;
 use tauri::{AppHandle, Manager, PhysicalPosition, WebviewWindowBuilder};
 use tracing::{debug, warn};
 
-use super::pipeline::{OutputMode, SpeechPipeline};
+use super::pipeline::{OutputMode, SessionKind, SpeechPipeline};
 use super::settings::{AsrSelection, SettingsManager};
+use super::settings_journal::ChangeSource;
+use super::settings_validation::{self, FieldError};
+use super::voice_commands::CommandRegistry;
+
+const PENDING_CONFIRM_FOCUS_DELAY: std::time::Duration = std::time::Duration::from_millis(180);
+/// Dictations that may wait for finalization while another one is captured.
+const MAX_QUEUED_SESSIONS: usize = 2;
+/// How long exit waits for a dictation being finalized, paste included.
+const SHUTDOWN_SESSION_GRACE: std::time::Duration = std::time::Duration::from_secs(3);
+/// Exit animation allowance when settings cannot be read.
+const DEFAULT_OVERLAY_HIDE_DELAY: std::time::Duration = std::time::Duration::from_millis(260);
+const DEFAULT_HUD_TOAST: std::time::Duration = std::time::Duration::from_millis(1_800);
+/// Longest a simulated dictation may take to finalize, including the model loading.
+const SIMULATION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(120);
+const SPEAKER_ENROLLMENT_MIN_SECS: u32 = 5;
+const SPEAKER_ENROLLMENT_MAX_SECS: u32 = 30;
 
 fn env_flag_enabled(key: &str) -> bool {
     let value = match std::env::var(key) {
@@ -73,6 +106,9 @@ pub enum SessionState {
     Idle,
     Listening,
     Processing,
+    /// Always listening: the pipeline cuts and delivers each utterance on its own
+    /// until continuous dictation is toggled off.
+    Continuous,
 }
 
 pub struct AppState {
@@ -82,12 +118,25 @@ pub struct AppState {
     models: Arc<StdMutex<ModelManager>>,
     downloads: Arc<Mutex<Option<ModelDownloadService>>>,
     hud_state: Arc<Mutex<String>>,
+    /// When the latest HUD toast expires; the idle overlay stays up until then.
+    hud_toast_until: Arc<Mutex<Option<Instant>>>,
     asr_warmup: Arc<Mutex<AsrWarmupTracker>>,
     asr_warmup_generation: Arc<AtomicU64>,
     overlay_generation: Arc<AtomicU64>,
     hotkey_down: Arc<AtomicBool>,
     hold_to_ready_armed: Arc<AtomicBool>,
     hold_to_ready_waiter_running: Arc<AtomicBool>,
+    /// Selection in use before the last model downshift, for one-click revert.
+    downshift_revert: Arc<Mutex<Option<AsrSelection>>>,
+    /// Selection that failed warmup and was reverted, for "retry original".
+    warmup_revert: Arc<Mutex<Option<AsrSelection>>>,
+    corrections: Arc<CorrectionStore>,
+    speaker_gate: Arc<SpeakerGate>,
+    history: Arc<HistoryStore>,
+    /// Set once exit begins so nothing rebuilds the pipeline behind the shutdown.
+    shutting_down: Arc<AtomicBool>,
+    /// Launch-time warmup and downloads are waiting for their `StartupTrigger`.
+    startup_pending: Arc<AtomicBool>,
 }
 
 impl AppState {
@@ -99,12 +148,15 @@ impl AppState {
             AsrWarmupState::Warming
         };
         Self {
+            shutting_down: Arc::new(AtomicBool::new(false)),
+            startup_pending: Arc::new(AtomicBool::new(false)),
             settings: Arc::new(SettingsManager::new()),
             pipeline: Arc::new(Mutex::new(None)),
             session: Arc::new(Mutex::new(SessionState::Idle)),
             models: Arc::new(StdMutex::new(models)),
             downloads: Arc::new(Mutex::new(None)),
             hud_state: Arc::new(Mutex::new("idle".to_string())),
+            hud_toast_until: Arc::new(Mutex::new(None)),
             asr_warmup: Arc::new(Mutex::new(AsrWarmupTracker {
                 state: warmup_state,
                 warmed_selection: None,
@@ -116,6 +168,11 @@ impl AppState {
             hotkey_down: Arc::new(AtomicBool::new(false)),
             hold_to_ready_armed: Arc::new(AtomicBool::new(false)),
             hold_to_ready_waiter_running: Arc::new(AtomicBool::new(false)),
+            downshift_revert: Arc::new(Mutex::new(None)),
+            warmup_revert: Arc::new(Mutex::new(None)),
+            corrections: Arc::new(CorrectionStore::new()),
+            speaker_gate: Arc::new(SpeakerGate::new()),
+            history: Arc::new(HistoryStore::new()),
         }
     }
 
@@ -144,24 +201,51 @@ impl AppState {
 
         publish_hud_runtime_state(self, state);
         events::emit_hud_state(app, state);
+        tray::set_status(app, state);
+
+        match state {
+            "listening" => sound::play(SoundCue::ListenStart),
+            "processing" => sound::play(SoundCue::ListenStop),
+            "asr-error" => sound::play(SoundCue::Error),
+            _ => {}
+        }
+    }
+
+    /// Whether the window overlay should be used: enabled in settings and not suppressed
+    /// by a fullscreen window. Sound cues and notifications still report the state.
+    fn overlay_enabled(&self) -> bool {
+        let Ok(settings) = self.settings_manager().read_frontend() else {
+            return false;
+        };
+        if !settings.show_hud_overlay {
+            return false;
+        }
+        if settings.suppress_overlay_in_fullscreen && fullscreen::focused_window_fullscreen() {
+            debug!("overlay_suppressed reason=fullscreen");
+            return false;
+        }
+        true
     }
 
     pub fn sync_hud_overlay_mode(&self, app: &AppHandle) {
         let hud_state = { self.hud_state.lock().clone() };
         publish_hud_runtime_state(self, &hud_state);
+        if let Ok(settings) = self.settings_manager().read_frontend() {
+            events::emit_hud_appearance(
+                app,
+                events::HudAppearancePayload {
+                    theme: settings.hud_theme,
+                    accent: settings.hud_accent_color,
+                },
+            );
+        }
 
         if !window_overlay_supported() {
             hide_status_overlay(app);
             return;
         }
 
-        let show_overlay = self
-            .settings_manager()
-            .read_frontend()
-            .map(|settings| settings.show_hud_overlay)
-            .unwrap_or(false);
-
-        if !show_overlay || hud_state == "idle" {
+        if !self.overlay_enabled() || hud_state == "idle" {
             hide_status_overlay(app);
             return;
         }
@@ -169,6 +253,21 @@ impl AppState {
         show_status_overlay(app, overlay_monitor_target_from_cursor(app));
     }
 
+    /// Drops the runtime HUD state file (possibly left by a crashed instance) and
+    /// republishes the live state.
+    pub fn reset_hud_runtime_state(&self, app: &AppHandle) {
+        if let Some(path) = hud_runtime_state_path() {
+            for stale in [path.with_extension("json.tmp"), path] {
+                if let Err(error) = std::fs::remove_file(&stale) {
+                    if error.kind() != std::io::ErrorKind::NotFound {
+                        warn!("failed removing {stale:?}: {error}");
+                    }
+                }
+            }
+        }
+        self.sync_hud_overlay_mode(app);
+    }
+
     pub fn replay_hud_state(&self, app: &AppHandle) {
         let state = { self.hud_state.lock().clone() };
         events::emit_hud_state(app, &state);
@@ -178,6 +277,24 @@ impl AppState {
         self.asr_warmup.lock().state
     }
 
+    pub fn asr_warmup_error(&self) -> Option<String> {
+        self.asr_warmup.lock().last_error.clone()
+    }
+
+    pub fn pipeline_handle(&self) -> Option<SpeechPipeline> {
+        self.pipeline.lock().as_ref().cloned()
+    }
+
+    /// Returns the asset backing the selected ASR model and its install status.
+    pub fn selected_asr_asset_status(&self) -> Option<(String, ModelStatus)> {
+        let settings = self.settings.read_frontend().ok()?;
+        let backend = parse_asr_backend(&settings);
+        let asset_name = self.required_asr_asset_name(&settings, &backend)?;
+        let guard = self.models.lock().ok()?;
+        let status = guard.asset_by_name(&asset_name)?.status.clone();
+        Some((asset_name, status))
+    }
+
     pub fn kickoff_asr_warmup(&self, app: &AppHandle) {
         if disable_asr_warmup() {
             let selection = self
@@ -262,16 +379,23 @@ impl AppState {
     }
 
     pub fn start_session(&self, app: &AppHandle) {
-        let show_overlay = self
-            .settings_manager()
-            .read_frontend()
-            .map(|settings| settings.show_hud_overlay)
-            .unwrap_or(false);
-
+        let show_overlay = self.overlay_enabled();
         self.start_session_with_overlay(app, show_overlay);
     }
 
     pub fn start_session_with_overlay(&self, app: &AppHandle, show_overlay: bool) {
+        self.begin_session(app, show_overlay, SessionKind::Dictation);
+    }
+
+    /// Starts a session whose utterance runs a voice command instead of being pasted.
+    pub fn start_command_session(&self, app: &AppHandle) {
+        let show_overlay = self.overlay_enabled();
+        self.begin_session(app, show_overlay, SessionKind::Command);
+    }
+
+    fn begin_session(&self, app: &AppHandle, show_overlay: bool, kind: SessionKind) {
+        // Dictation was asked for before the startup trigger fired; don't wait for it.
+        self.run_startup_work(app);
         let use_window_overlay = show_overlay && window_overlay_supported();
         let target_monitor = if use_window_overlay {
             overlay_monitor_target_from_cursor(app)
@@ -281,13 +405,20 @@ impl AppState {
 
         match self.operational_readiness() {
             OperationalReadiness::AsrWarming => {
-                tracing::info!("backend_readiness waiting=asr-warming");
                 if use_window_overlay {
                     show_status_overlay(app, target_monitor);
                 } else {
                     hide_status_overlay(app);
                 }
-                self.set_hud_state(app, "warming");
+                if let Some((asset_name, progress)) = self.pending_asr_download() {
+                    tracing::info!(
+                        "backend_readiness waiting=model-download asset={asset_name} progress={progress:.2}"
+                    );
+                    self.set_download_hud_state(app, &asset_name, progress);
+                } else {
+                    tracing::info!("backend_readiness waiting=asr-warming");
+                    self.set_hud_state(app, "warming");
+                }
                 self.arm_hold_to_ready(app);
                 return;
             }
@@ -326,26 +457,39 @@ impl AppState {
             OperationalReadiness::Ready => {}
         }
 
+        // Don't hold the pipeline mutex while toggling listening.
+        let pipeline = { self.pipeline.lock().as_ref().cloned() };
+        let queued = pipeline
+            .as_ref()
+            .map(|pipeline| pipeline.queued_sessions())
+            .unwrap_or(0);
+
         let should_start = {
             let mut guard = self.session.lock();
-            // Only start a new session from Idle. If we're already listening or
-            // processing, ignore the request.
-            if *guard != SessionState::Idle {
-                false
-            } else {
-                *guard = SessionState::Listening;
-                true
+            // Start from Idle, or from Processing so a new dictation captures into its
+            // own buffer while earlier ones finalize. Ignore the request when already
+            // listening or when the finalize queue is full.
+            match *guard {
+                SessionState::Idle => {
+                    *guard = SessionState::Listening;
+                    true
+                }
+                SessionState::Processing if queued < MAX_QUEUED_SESSIONS => {
+                    *guard = SessionState::Listening;
+                    true
+                }
+                _ => false,
             }
         };
         if !should_start {
             return;
         }
 
-        // Don't hold the pipeline mutex while toggling listening.
-        let pipeline = { self.pipeline.lock().as_ref().cloned() };
         if let Some(pipeline) = pipeline {
+            pipeline.set_session_kind(kind);
             pipeline.set_listening(true);
         }
+        tracing::debug!("session_started kind={kind:?}");
 
         if use_window_overlay {
             show_status_overlay(app, target_monitor);
@@ -358,6 +502,75 @@ impl AppState {
         self.set_hud_state(app, "listening");
     }
 
+    /// One-shot toggle for callers without press/release semantics (e.g. the GNOME
+    /// panel indicator): confirms a held transcript, stops a running session, or starts one.
+    pub fn toggle_session(&self, app: &AppHandle) {
+        if self.is_continuous() {
+            self.toggle_continuous(app);
+            return;
+        }
+        if self.has_pending_transcript() {
+            if let Err(error) = self.confirm_pending_transcript(app, None) {
+                warn!("confirm_pending_transcript failed: {error:?}");
+            }
+            return;
+        }
+
+        if self.is_listening() {
+            self.mark_processing(app);
+            self.complete_session(app);
+        } else {
+            self.start_session(app);
+        }
+    }
+
+    /// Starts or stops continuous dictation. While on, push-to-talk is ignored and the
+    /// pipeline finalizes each utterance once the speaker pauses.
+    pub fn toggle_continuous(&self, app: &AppHandle) {
+        let pipeline = { self.pipeline.lock().as_ref().cloned() };
+        let stopping = {
+            let mut guard = self.session.lock();
+            if *guard == SessionState::Continuous {
+                // complete_session finishes the trailing utterance like a PTT release.
+                *guard = SessionState::Listening;
+                true
+            } else {
+                false
+            }
+        };
+        if stopping {
+            if let Some(pipeline) = pipeline.as_ref() {
+                pipeline.set_continuous(false);
+            }
+            self.complete_session(app);
+            tracing::info!("continuous_dictation active=false");
+            events::emit_continuous_dictation(app, false);
+            self.show_hud_toast(app, &i18n::message("notify-continuous-off", &[]));
+            return;
+        }
+
+        self.start_session(app);
+        let started = {
+            let mut guard = self.session.lock();
+            if *guard == SessionState::Listening {
+                *guard = SessionState::Continuous;
+                true
+            } else {
+                false
+            }
+        };
+        if !started {
+            debug!("continuous dictation not started: backend not ready or busy");
+            return;
+        }
+        if let Some(pipeline) = pipeline.as_ref() {
+            pipeline.set_continuous(true);
+        }
+        tracing::info!("continuous_dictation active=true");
+        events::emit_continuous_dictation(app, true);
+        self.show_hud_toast(app, &i18n::message("notify-continuous-on", &[]));
+    }
+
     pub fn set_hotkey_down(&self, app: &AppHandle, is_down: bool) {
         self.hotkey_down.store(is_down, Ordering::SeqCst);
         if !is_down {
@@ -385,6 +598,10 @@ impl AppState {
             let prev = *guard;
 
             match prev {
+                SessionState::Continuous => {
+                    // Only toggle_continuous or cancel ends continuous dictation.
+                    return;
+                }
                 SessionState::Idle => {
                     // Ensure we still hide overlay + stop any lingering audio capture.
                 }
@@ -416,34 +633,54 @@ impl AppState {
             self.set_hud_state(app, "idle");
         }
 
-        let should_finalize = !matches!(previous, SessionState::Idle);
+        // Stopping capture is cheap; the captured audio is finalized on the pipeline's
+        // queue so a new session can start capturing right away.
+        let finalized = if matches!(previous, SessionState::Idle) {
+            None
+        } else if let Some(pipeline) = pipeline.as_ref() {
+            pipeline.end_session()
+        } else {
+            debug!("complete_session: pipeline not initialized");
+            None
+        };
 
         tauri::async_runtime::spawn(async move {
-            if should_finalize {
-                if let Some(pipeline) = pipeline {
-                    if let Err(error) = tokio::task::spawn_blocking(move || {
-                        pipeline.set_listening(false);
-                    })
-                    .await
-                    {
-                        warn!("failed to finalize dictation: {error:?}");
-                    }
-                } else {
-                    debug!("complete_session: pipeline not initialized");
+            if let Some(done) = finalized {
+                if let Err(error) = tokio::task::spawn_blocking(move || {
+                    let _ = done.recv();
+                })
+                .await
+                {
+                    warn!("failed to finalize dictation: {error:?}");
                 }
             }
 
-            {
+            // Earlier-queued sessions, or one started since, keep the state busy.
+            let still_queued = pipeline
+                .as_ref()
+                .map(|pipeline| pipeline.queued_sessions() > 0)
+                .unwrap_or(false);
+            let became_idle = {
                 let mut guard = session.lock();
-                *guard = SessionState::Idle;
+                match *guard {
+                    SessionState::Listening | SessionState::Continuous => false,
+                    SessionState::Processing if still_queued => false,
+                    _ => {
+                        *guard = SessionState::Idle;
+                        true
+                    }
+                }
+            };
+            if !became_idle {
+                return;
             }
 
             if let Some(state) = app_handle.try_state::<AppState>() {
                 state.set_hud_state(&app_handle, "idle");
 
-                // Let the frontend play a short exit animation before hiding the
-                // overlay window. Guard against races with a new dictation start.
-                tokio::time::sleep(std::time::Duration::from_millis(260)).await;
+                // Let the frontend play its exit animation, and any toast run out, before
+                // hiding the overlay window. Guard against races with a new dictation start.
+                tokio::time::sleep(state.overlay_hide_delay()).await;
                 let still_idle = {
                     let hud = state.hud_state.lock();
                     hud.as_str() == "idle"
@@ -453,12 +690,55 @@ impl AppState {
                 }
             } else {
                 events::emit_hud_state(&app_handle, "idle");
-                tokio::time::sleep(std::time::Duration::from_millis(260)).await;
+                tokio::time::sleep(DEFAULT_OVERLAY_HIDE_DELAY).await;
                 hide_status_overlay(&app_handle);
             }
         });
     }
 
+    /// Ends the running dictation without transcribing it. Sessions already queued for
+    /// finalization still deliver.
+    pub fn cancel_session(&self, app: &AppHandle) {
+        let pipeline = { self.pipeline.lock().as_ref().cloned() };
+        let (cancelled, idle, was_continuous) = {
+            let mut guard = self.session.lock();
+            let was_continuous = *guard == SessionState::Continuous;
+            if *guard != SessionState::Listening && !was_continuous {
+                return;
+            }
+            if was_continuous {
+                if let Some(pipeline) = pipeline.as_ref() {
+                    pipeline.set_continuous(false);
+                }
+            }
+            let cancelled = pipeline
+                .as_ref()
+                .map(|pipeline| pipeline.cancel_session())
+                .unwrap_or(false);
+            let still_queued = pipeline
+                .as_ref()
+                .map(|pipeline| pipeline.queued_sessions() > 0)
+                .unwrap_or(false);
+            *guard = if still_queued {
+                SessionState::Processing
+            } else {
+                SessionState::Idle
+            };
+            (cancelled, !still_queued, was_continuous)
+        };
+        tracing::info!("dictation_cancelled captured={cancelled}");
+        if was_continuous {
+            events::emit_continuous_dictation(app, false);
+        }
+
+        if idle {
+            self.set_hud_state(app, "idle");
+            hide_status_overlay(app);
+        } else {
+            self.set_hud_state(app, "processing");
+        }
+    }
+
     pub fn secure_blocked(&self, app: &AppHandle) {
         events::emit_secure_blocked(app);
         self.complete_session(app);
@@ -473,10 +753,507 @@ impl AppState {
         Ok(())
     }
 
+    pub fn has_pending_transcript(&self) -> bool {
+        self.pipeline
+            .lock()
+            .as_ref()
+            .map(|pipeline| pipeline.has_pending_transcript())
+            .unwrap_or(false)
+    }
+
+    /// Stores a user edit of a transcript. Once the same substitution has been made
+    /// often enough it is promoted to a rule and the live pipeline picks it up. Nothing
+    /// is stored under strict privacy.
+    pub fn submit_correction(&self, original: &str, corrected: &str) -> Result<bool> {
+        if privacy::strict() {
+            tracing::info!("correction_skipped reason=strict_privacy");
+            return Ok(false);
+        }
+        let (original, corrected) = match self.pipeline.lock().as_ref() {
+            Some(pipeline) => (
+                pipeline.redact_for_storage(original),
+                pipeline.redact_for_storage(corrected),
+            ),
+            None => (original.to_string(), corrected.to_string()),
+        };
+        let promoted = self.corrections.submit(&original, &corrected)?;
+        if promoted {
+            tracing::info!("correction promoted to replacement rule");
+            self.refresh_correction_rules();
+        }
+        Ok(promoted)
+    }
+
+    pub fn correction_summary(&self) -> CorrectionSummary {
+        self.corrections.summary()
+    }
+
+    pub fn forget_correction(&self, from: &str, to: &str) -> Result<()> {
+        self.corrections.forget(from, to)?;
+        self.refresh_correction_rules();
+        Ok(())
+    }
+
+    pub fn clear_corrections(&self) -> Result<()> {
+        self.corrections.clear()?;
+        self.refresh_correction_rules();
+        Ok(())
+    }
+
+    fn refresh_correction_rules(&self) {
+        if let Some(pipeline) = self.pipeline.lock().as_ref() {
+            pipeline.set_correction_rules(self.corrections.rules());
+            pipeline.set_speaker_gate(Some(self.speaker_gate.clone()));
+        }
+    }
+
+    /// Records `seconds` of the user's voice and stores it as the speaker-gate profile.
+    /// Blocks for the whole recording.
+    pub fn enroll_speaker(&self, seconds: u32) -> Result<SpeakerProfileSummary> {
+        self.sync_model_environment();
+        if std::env::var_os(SPEAKER_MODEL_ENV).is_none() {
+            return Err(anyhow!("install the speaker model before enrolling"));
+        }
+        let pipeline = { self.pipeline.lock().as_ref().cloned() }
+            .ok_or_else(|| anyhow!("pipeline not initialized"))?;
+
+        let seconds = seconds.clamp(SPEAKER_ENROLLMENT_MIN_SECS, SPEAKER_ENROLLMENT_MAX_SECS);
+        tracing::info!("speaker_enrollment_start seconds={seconds}");
+        let (sample_rate, samples) =
+            pipeline.record_enrollment(std::time::Duration::from_secs(u64::from(seconds)))?;
+        let summary = self.speaker_gate.enroll(sample_rate, &samples)?;
+        tracing::info!(
+            "speaker_enrollment_done seconds={:.1}",
+            summary.enrollment_seconds
+        );
+        Ok(summary)
+    }
+
+    pub fn speaker_profile(&self) -> Option<SpeakerProfileSummary> {
+        self.speaker_gate.profile_summary()
+    }
+
+    pub fn clear_speaker_profile(&self) -> Result<()> {
+        self.speaker_gate.clear_profile()
+    }
+
+    /// Pastes the transcript held by confirm-before-paste. Blocking: the main window is
+    /// hidden first and we wait briefly so focus returns to the paste target.
+    pub fn confirm_pending_transcript(
+        &self,
+        app: &AppHandle,
+        text: Option<String>,
+    ) -> Result<bool> {
+        let pipeline = { self.pipeline.lock().as_ref().cloned() }
+            .ok_or_else(|| anyhow!("pipeline not initialized"))?;
+        if !pipeline.has_pending_transcript() {
+            return Ok(false);
+        }
+
+        if let Some(window) = app.get_webview_window("main") {
+            if window.is_focused().unwrap_or(false) {
+                let _ = window.hide();
+                std::thread::sleep(PENDING_CONFIRM_FOCUS_DELAY);
+            }
+        }
+        Ok(pipeline.confirm_pending_transcript(text))
+    }
+
+    pub fn list_history(&self, offset: usize, limit: usize) -> Vec<HistoryEntry> {
+        self.history.list(offset, limit)
+    }
+
+    pub fn search_history(&self, query: &str, limit: usize) -> Vec<HistoryEntry> {
+        self.history.search(query, limit)
+    }
+
+    pub fn clear_history(&self) -> Result<()> {
+        self.history.clear()
+    }
+
+    pub fn export_history(
+        &self,
+        format: ExportFormat,
+        range: ExportRange,
+        path: &Path,
+    ) -> Result<ExportSummary> {
+        let summary = self.history.export(format, range, path)?;
+        tracing::info!(
+            "history_exported format={format:?} entries={} path={}",
+            summary.written,
+            path.display()
+        );
+        Ok(summary)
+    }
+
+    /// Pastes a history entry into the window that had focus before ours. Blocking, like
+    /// `confirm_pending_transcript`.
+    pub fn repaste_history_entry(&self, app: &AppHandle, id: u64) -> Result<()> {
+        let entry = self
+            .history
+            .get(id)
+            .ok_or_else(|| anyhow!("history entry {id} not found"))?;
+        let pipeline = { self.pipeline.lock().as_ref().cloned() }
+            .ok_or_else(|| anyhow!("pipeline not initialized"))?;
+
+        if let Some(window) = app.get_webview_window("main") {
+            if window.is_focused().unwrap_or(false) {
+                let _ = window.hide();
+                std::thread::sleep(PENDING_CONFIRM_FOCUS_DELAY);
+            }
+        }
+        pipeline.repaste(&entry.text);
+        Ok(())
+    }
+
+    /// Runs a simulated dictation from a WAV path or transcript text and returns the
+    /// events it emitted, in order.
+    pub fn simulate_dictation(
+        &self,
+        app: &AppHandle,
+        input: &str,
+    ) -> Result<Vec<simulation::SimulatedEvent>> {
+        let pipeline = { self.pipeline.lock().as_ref().cloned() }
+            .ok_or_else(|| anyhow!("pipeline not initialized"))?;
+        let input = simulation::SimulatedInput::parse(input, pipeline.sample_rate())?;
+
+        let recorder = simulation::EventRecorder::start(app);
+        let finished = pipeline.simulate_session(input).and_then(|done| {
+            done.recv_timeout(SIMULATION_TIMEOUT)
+                .map_err(|_| anyhow!("simulated dictation did not finish in time"))
+        });
+        let events = recorder.finish();
+        finished?;
+        tracing::info!("dictation_simulation_finished events={}", events.len());
+        Ok(events)
+    }
+
+    /// Runs ASR again over the newest kept clip of a failed dictation.
+    pub fn replay_last_audio(&self) -> Result<failed_audio::ReplayedAudio> {
+        let pipeline = { self.pipeline.lock().as_ref().cloned() }
+            .ok_or_else(|| anyhow!("pipeline not initialized"))?;
+        let path = failed_audio::latest()?.ok_or_else(|| anyhow!("no failed audio kept"))?;
+        let samples = simulation::read_wav(&path, pipeline.sample_rate())?;
+        let recognized = pipeline.transcribe_clip(&samples)?;
+        let (transcript, latency) = recognized
+            .map(|result| (result.text, result.latency))
+            .unwrap_or_default();
+        tracing::info!(
+            "failed_audio_replayed chars={} latency_ms={}",
+            transcript.len(),
+            latency.as_millis()
+        );
+        Ok(failed_audio::ReplayedAudio {
+            path: path.display().to_string(),
+            transcript,
+            latency_ms: latency.as_millis() as u64,
+        })
+    }
+
+    /// Re-copies the last delivered transcript and tells the user via toast and a
+    /// desktop notification, so a misdirected paste can be redone by hand.
+    pub fn recopy_last_transcript(&self, app: &AppHandle) {
+        let pipeline = { self.pipeline.lock().as_ref().cloned() };
+        let result = match pipeline {
+            Some(pipeline) => pipeline.recopy_last_transcript(),
+            None => Ok(None),
+        };
+
+        match result {
+            Ok(Some(text)) => {
+                tracing::info!("transcript_recopied chars={}", text.len());
+                events::emit_transcript_recopied(app, Some(&text));
+                // Notifications can end up in the daemon's history, so strict privacy
+                // leaves the transcript out of the body.
+                let body = if privacy::strict() {
+                    let words = text.split_whitespace().count().to_string();
+                    i18n::message("notify-transcript-copied-body", &[("words", &words)])
+                } else {
+                    notify::preview(&text)
+                };
+                notify::show(&i18n::message("notify-transcript-copied", &[]), &body);
+            }
+            Ok(None) => {
+                events::emit_transcript_recopied(app, None);
+                notify::show(
+                    &i18n::message("notify-nothing-to-copy", &[]),
+                    &i18n::message("notify-nothing-to-copy-body", &[]),
+                );
+            }
+            Err(error) => {
+                warn!("transcript re-copy failed: {error}");
+                sound::play(SoundCue::Error);
+                notify::show(
+                    &i18n::message("notify-copy-failed", &[]),
+                    &error.to_string(),
+                );
+            }
+        }
+    }
+
+    /// Moves the dictation language to the next entry of `language_cycle`, switching
+    /// to the ASR selection pinned for it, and announces the new language on the HUD.
+    pub fn cycle_language(&self, app: &AppHandle) -> Result<()> {
+        let mut settings = self.settings.read_frontend()?;
+        let Some(next) = next_cycle_language(&settings.language_cycle, &settings.language) else {
+            debug!("language cycle skipped: no languages configured");
+            return Ok(());
+        };
+        // Swapping the model rebuilds the pipeline, which would drop a dictation in flight.
+        if !matches!(*self.session.lock(), SessionState::Idle) {
+            debug!("language cycle skipped: dictation in progress");
+            return Ok(());
+        }
+
+        let previous_selection = AsrSelection::from_frontend(&settings);
+        let pinned = settings.language_models.get(&next).cloned();
+        settings.auto_detect_language = next == "auto";
+        settings.language = next.clone();
+        if let Some(selection) = &pinned {
+            selection.apply_to_frontend(&mut settings);
+        }
+        self.settings
+            .write_frontend(settings, ChangeSource::LanguageCycle)?;
+        let fresh = self.settings.read_frontend()?;
+        self.configure_pipeline(Some(app), &fresh)?;
+        if pinned
+            .as_ref()
+            .is_some_and(|selection| *selection != previous_selection)
+        {
+            self.kickoff_asr_warmup(app);
+        }
+
+        let label = i18n::lookup(&format!("language-{next}"), &[])
+            .unwrap_or_else(|| next.to_ascii_uppercase());
+        tracing::info!(
+            "language_cycled language={next} model_switched={}",
+            pinned.is_some()
+        );
+        events::emit_language_cycled(
+            app,
+            events::LanguageCycledPayload {
+                language: next,
+                label: label.clone(),
+                asr_selection: pinned,
+            },
+        );
+        self.show_hud_toast(
+            app,
+            &i18n::message("notify-language-switched", &[("language", &label)]),
+        );
+        Ok(())
+    }
+
+    /// Shows `message` under the HUD orb for `hud_toast_ms`, bringing up the overlay
+    /// when idle. Without an overlay the message goes to a desktop notification instead.
+    pub fn show_hud_toast(&self, app: &AppHandle, message: &str) {
+        events::emit_hud_toast(app, message);
+        self.present_toast(app, message, "");
+    }
+
+    /// Shows what was just pasted on the HUD; the overlay can copy it again on click.
+    pub fn show_transcript_toast(&self, app: &AppHandle, payload: events::TranscriptToastPayload) {
+        let body = payload.text.clone();
+        events::emit_transcript_toast(app, payload);
+        self.present_toast(app, &i18n::message("notify-transcript-pasted", &[]), &body);
+    }
+
+    /// Keeps the overlay up while a toast is showing, or falls back to a desktop
+    /// notification when the overlay is off.
+    fn present_toast(&self, app: &AppHandle, summary: &str, body: &str) {
+        if !self.overlay_enabled() || !window_overlay_supported() {
+            notify::show(summary, body);
+            return;
+        }
+        let duration = self
+            .settings
+            .read_frontend()
+            .map(|settings| std::time::Duration::from_millis(u64::from(settings.hud_toast_ms)))
+            .unwrap_or(DEFAULT_HUD_TOAST);
+        *self.hud_toast_until.lock() = Some(Instant::now() + duration);
+        if self.hud_state.lock().as_str() != "idle" {
+            // The session's own idle transition keeps the overlay up until the toast ends.
+            return;
+        }
+
+        show_status_overlay(app, overlay_monitor_target_from_cursor(app));
+        let app_handle = app.clone();
+        tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(duration).await;
+            if let Some(state) = app_handle.try_state::<AppState>() {
+                let superseded = state
+                    .hud_toast_until
+                    .lock()
+                    .is_some_and(|until| until > Instant::now());
+                if !superseded {
+                    // Hides the overlay again unless a session started meanwhile.
+                    state.sync_hud_overlay_mode(&app_handle);
+                }
+            }
+        });
+    }
+
+    /// How long an overlay that just went idle stays up: the configured exit animation,
+    /// or longer while a toast is still showing.
+    fn overlay_hide_delay(&self) -> std::time::Duration {
+        let exit = self
+            .settings
+            .read_frontend()
+            .map(|settings| {
+                std::time::Duration::from_millis(u64::from(settings.overlay_hide_delay_ms))
+            })
+            .unwrap_or(DEFAULT_OVERLAY_HIDE_DELAY);
+        let toast = self
+            .hud_toast_until
+            .lock()
+            .map(|until| until.saturating_duration_since(Instant::now()))
+            .unwrap_or_default();
+        exit.max(toast)
+    }
+
+    /// Called once performance mode has lasted `downshift_after_sessions`; offers or
+    /// applies the next lighter ASR selection depending on the `model_downshift` setting.
+    pub fn handle_sustained_slowness(&self, app: &AppHandle) {
+        let settings = match self.settings.read_frontend() {
+            Ok(settings) => settings,
+            Err(error) => {
+                warn!("model downshift skipped: {error}");
+                return;
+            }
+        };
+        if settings.model_downshift == "off" {
+            return;
+        }
+
+        let current = AsrSelection::from_frontend(&settings);
+        let Some(target) = performance::downshift_selection(&current) else {
+            debug!("model downshift skipped: already on the lightest selection");
+            return;
+        };
+
+        // Swapping the model rebuilds the pipeline, which would drop a dictation in flight.
+        let idle = matches!(*self.session.lock(), SessionState::Idle);
+        if settings.model_downshift == "auto" && idle {
+            if let Err(error) = self.apply_model_downshift(app) {
+                warn!("automatic model downshift failed: {error}");
+            }
+            return;
+        }
+
+        events::emit_model_downshift(
+            app,
+            events::ModelDownshiftPayload::new(&current, &target, false),
+        );
+    }
+
+    /// Switches to the next lighter ASR selection and remembers the current one for revert.
+    pub fn apply_model_downshift(&self, app: &AppHandle) -> Result<()> {
+        let settings = self.settings.read_frontend()?;
+        let current = AsrSelection::from_frontend(&settings);
+        let target = performance::downshift_selection(&current)
+            .ok_or_else(|| anyhow!("no lighter model available"))?;
+
+        tracing::info!(
+            "model_downshift from={} to={}",
+            format_asr_selection_label(&settings),
+            target.whisper_model
+        );
+        self.switch_asr_selection(app, &target, ChangeSource::ModelDownshift)?;
+        *self.downshift_revert.lock() = Some(current.clone());
+        events::emit_model_downshift(
+            app,
+            events::ModelDownshiftPayload::new(&current, &target, true),
+        );
+        Ok(())
+    }
+
+    /// Restores the selection replaced by the last downshift. Returns false when there
+    /// is nothing to revert.
+    pub fn revert_model_downshift(&self, app: &AppHandle) -> Result<bool> {
+        let Some(previous) = self.downshift_revert.lock().take() else {
+            return Ok(false);
+        };
+        tracing::info!("model_downshift_reverted to={}", previous.whisper_model);
+        self.switch_asr_selection(app, &previous, ChangeSource::ModelDownshift)?;
+        Ok(true)
+    }
+
+    /// Selects the model again that failed warmup and was reverted. Returns false when
+    /// nothing was reverted since the last retry.
+    pub fn retry_reverted_asr_selection(&self, app: &AppHandle) -> Result<bool> {
+        let Some(original) = self.warmup_revert.lock().take() else {
+            return Ok(false);
+        };
+        tracing::info!("asr_revert_retry to={}", original.whisper_model);
+        self.switch_asr_selection(app, &original, ChangeSource::Ui)?;
+        Ok(true)
+    }
+
+    fn switch_asr_selection(
+        &self,
+        app: &AppHandle,
+        selection: &AsrSelection,
+        source: ChangeSource,
+    ) -> Result<()> {
+        let mut settings = self.settings.read_frontend()?;
+        selection.apply_to_frontend(&mut settings);
+        self.settings.write_frontend(settings, source)?;
+        let fresh = self.settings.read_frontend()?;
+        self.configure_pipeline(Some(app), &fresh)?;
+        self.kickoff_asr_warmup(app);
+        Ok(())
+    }
+
+    pub fn discard_pending_transcript(&self) -> bool {
+        self.pipeline
+            .lock()
+            .as_ref()
+            .map(|pipeline| pipeline.discard_pending_transcript())
+            .unwrap_or(false)
+    }
+
+    pub fn asr_load_report(&self) -> Option<WeightLoadReport> {
+        self.pipeline
+            .lock()
+            .as_ref()
+            .and_then(|pipeline| pipeline.asr_load_report())
+    }
+
     pub fn is_listening(&self) -> bool {
         matches!(*self.session.lock(), SessionState::Listening)
     }
 
+    /// Field errors for `settings`, including a Whisper selection the model catalog
+    /// has no asset for.
+    pub fn validate_settings(
+        &self,
+        settings: &crate::core::settings::FrontendSettings,
+    ) -> Vec<FieldError> {
+        let mut errors = settings_validation::validate(settings);
+        if !errors.is_empty() {
+            return errors;
+        }
+        let backend = parse_asr_backend(settings);
+        if let Some(name) = resolve_whisper_asset_name(settings, &backend) {
+            let known = self
+                .models
+                .lock()
+                .map(|guard| guard.asset_by_name(&name).is_some())
+                .unwrap_or(true);
+            if !known {
+                errors.push(FieldError::new(
+                    "whisperPrecision",
+                    format!("no {name} model is available; choose another size or precision"),
+                ));
+            }
+        }
+        errors
+    }
+
+    pub fn is_continuous(&self) -> bool {
+        matches!(*self.session.lock(), SessionState::Continuous)
+    }
+
     pub fn hotkey_mode(&self) -> String {
         self.settings
             .read_frontend()
@@ -484,6 +1261,16 @@ impl AppState {
             .unwrap_or_else(|_| "hold".into())
     }
 
+    /// How long a hybrid-mode press must be held to act as push-to-talk.
+    pub fn hybrid_hold_threshold(&self) -> std::time::Duration {
+        let millis = self
+            .settings
+            .read_frontend()
+            .map(|settings| settings.hybrid_hold_threshold_ms)
+            .unwrap_or(350);
+        std::time::Duration::from_millis(u64::from(millis))
+    }
+
     fn operational_readiness(&self) -> OperationalReadiness {
         match self.asr_warmup_state() {
             AsrWarmupState::Warming => return OperationalReadiness::AsrWarming,
@@ -503,6 +1290,32 @@ impl AppState {
         }
     }
 
+    /// Returns the selected ASR asset and its progress while it is still downloading.
+    fn pending_asr_download(&self) -> Option<(String, f32)> {
+        let settings = self.settings.read_frontend().ok()?;
+        let backend = parse_asr_backend(&settings);
+        if self.resolve_asr_model_dir(&settings, &backend).is_some() {
+            return None;
+        }
+        let asset_name = self.required_asr_asset_name(&settings, &backend)?;
+        let guard = self.models.lock().ok()?;
+        match guard.asset_by_name(&asset_name)?.status {
+            ModelStatus::Downloading { progress, .. } => Some((asset_name, progress)),
+            _ => None,
+        }
+    }
+
+    fn set_download_hud_state(&self, app: &AppHandle, asset_name: &str, progress: f32) {
+        self.set_hud_state(app, "downloading");
+        events::emit_hud_download_progress(
+            app,
+            events::HudDownloadProgressPayload {
+                asset_name: asset_name.to_string(),
+                progress: progress.clamp(0.0, 1.0),
+            },
+        );
+    }
+
     fn arm_hold_to_ready(&self, app: &AppHandle) {
         self.hold_to_ready_armed.store(true, Ordering::SeqCst);
         if self.hotkey_down.load(Ordering::SeqCst) {
@@ -561,16 +1374,66 @@ impl AppState {
         app: Option<&AppHandle>,
         settings: &crate::core::settings::FrontendSettings,
     ) -> Result<()> {
+        if self.shutting_down.load(Ordering::SeqCst) {
+            return Ok(());
+        }
         let desired_asr_config = self.build_asr_config(settings);
         let desired_paste_shortcut = parse_paste_shortcut(&settings.paste_shortcut);
+        let app_paste_shortcuts = parse_app_paste_shortcuts(&settings.app_paste_shortcuts);
+        i18n::set_locale(&settings.message_locale);
+        if privacy::set_strict(settings.strict_privacy) {
+            tracing::info!("strict_privacy_changed enabled={}", settings.strict_privacy);
+        }
+        if settings.strict_privacy {
+            if let Err(error) = self.history.clear() {
+                warn!("history_purge_failed error={error:#}");
+            }
+        }
+        let history = settings.history_enabled.then(|| self.history.clone());
+        if let Some(app) = app {
+            events::emit_privacy_posture(app, privacy::posture(settings));
+        }
+        debug_capture::configure(
+            settings
+                .debug_transcripts
+                .then(|| self.settings_manager().debug_transcripts_until())
+                .flatten(),
+        );
+        failed_audio::configure(settings.keep_failed_audio.then(|| {
+            std::time::Duration::from_secs(
+                u64::from(settings.failed_audio_retention_days) * 24 * 60 * 60,
+            )
+        }));
+        events::configure_emission_limits(events::EmissionLimits::from_settings(
+            &settings.event_rate_profile,
+            &settings.event_min_interval_ms,
+        ));
+        sound::configure(SoundCueConfig {
+            enabled: settings.sound_cues_enabled,
+            theme: SoundTheme::parse(&settings.sound_cue_theme),
+            listen_start: settings.sound_cue_start,
+            listen_stop: settings.sound_cue_stop,
+            paste_success: settings.sound_cue_paste,
+            error: settings.sound_cue_error,
+        });
+        self.speaker_gate
+            .configure(settings.speaker_gate, settings.speaker_gate_threshold);
         let mut guard = self.pipeline.lock();
         if let Some(existing) = guard.as_ref() {
-            let desired_device = settings.audio_device_id.clone();
-            if existing.audio_device_id() != desired_device
-                || existing.asr_config() != desired_asr_config
+            let asr_changed = existing.asr_config() != desired_asr_config;
+            if existing.audio_device_preferences() != settings.audio_device_preferences
+                || existing.audio_on_demand() != settings.on_demand_capture
+                || asr_changed
             {
                 *guard = None;
             }
+            if asr_changed {
+                // The rebuilt pipeline starts cold even when the model selection is the
+                // same (e.g. only the vocabulary changed), so the next kickoff must re-warm.
+                let mut tracker = self.asr_warmup.lock();
+                tracker.warmed_selection = None;
+                tracker.target_selection = None;
+            }
         }
 
         let vad_config = VadConfig {
@@ -580,8 +1443,38 @@ impl AppState {
 
         if let Some(pipeline) = guard.as_mut() {
             pipeline.set_mode(parse_autoclean_mode(&settings.autoclean_mode));
+            pipeline.set_polish_config(polish_config(settings));
+            pipeline
+                .set_formatting_locale(FormattingLocale::from_setting(&settings.formatting_locale));
             pipeline.set_vad_config(vad_config.clone());
             pipeline.set_paste_shortcut(desired_paste_shortcut);
+            pipeline.set_app_paste_shortcuts(app_paste_shortcuts);
+            pipeline.set_paste_fallback_shift_insert(settings.paste_fallback_shift_insert);
+            pipeline.set_type_out(
+                settings.type_out,
+                settings.type_out_char_delay_ms,
+                settings.type_out_terminal_paste,
+            );
+            pipeline.set_always_transcribe(settings.always_transcribe);
+            pipeline.set_continuous_end_silence(std::time::Duration::from_millis(u64::from(
+                settings.continuous_end_silence_ms,
+            )));
+            pipeline.set_transcript_toast(settings.transcript_toast);
+            pipeline.set_redaction(redaction_rules(settings), settings.redact_before_paste);
+            pipeline.set_bluetooth_auto_profile(settings.bluetooth_auto_profile);
+            pipeline.set_voice_commands(CommandRegistry::from_settings(&settings.voice_commands));
+            pipeline.set_history(history);
+            pipeline.set_confirm_before_paste(settings.confirm_before_paste);
+            pipeline.set_transcript_alternatives(transcript_alternatives(settings));
+            pipeline.set_pause_punctuation(
+                settings
+                    .pause_punctuation
+                    .then(|| settings.pause_thresholds_for_language()),
+            );
+            pipeline.set_correction_rules(self.corrections.rules());
+            pipeline.set_diagnostics_interval(diagnostics_interval(settings));
+            pipeline.set_trace_export_dir(trace_export_dir(settings));
+            pipeline.set_performance_policy(settings.performance_policy.clone());
             if let Some(app) = app {
                 events::emit_autoclean_mode(app, parse_autoclean_mode(&settings.autoclean_mode));
             }
@@ -591,7 +1484,8 @@ impl AppState {
         let app = app.ok_or_else(|| anyhow!("app handle required to construct pipeline"))?;
         self.sync_model_environment();
         let audio_config = AudioPipelineConfig {
-            device_id: settings.audio_device_id.clone(),
+            device_preferences: settings.audio_device_preferences.clone(),
+            on_demand: settings.on_demand_capture,
         };
         let pipeline = SpeechPipeline::new(
             app.clone(),
@@ -600,8 +1494,38 @@ impl AppState {
             desired_asr_config,
         );
         pipeline.set_mode(parse_autoclean_mode(&settings.autoclean_mode));
+        pipeline.set_polish_config(polish_config(settings));
+        pipeline.set_formatting_locale(FormattingLocale::from_setting(&settings.formatting_locale));
         pipeline.set_vad_config(vad_config);
         pipeline.set_paste_shortcut(desired_paste_shortcut);
+        pipeline.set_app_paste_shortcuts(app_paste_shortcuts);
+        pipeline.set_paste_fallback_shift_insert(settings.paste_fallback_shift_insert);
+        pipeline.set_type_out(
+            settings.type_out,
+            settings.type_out_char_delay_ms,
+            settings.type_out_terminal_paste,
+        );
+        pipeline.set_always_transcribe(settings.always_transcribe);
+        pipeline.set_continuous_end_silence(std::time::Duration::from_millis(u64::from(
+            settings.continuous_end_silence_ms,
+        )));
+        pipeline.set_transcript_toast(settings.transcript_toast);
+        pipeline.set_redaction(redaction_rules(settings), settings.redact_before_paste);
+        pipeline.set_bluetooth_auto_profile(settings.bluetooth_auto_profile);
+        pipeline.set_voice_commands(CommandRegistry::from_settings(&settings.voice_commands));
+        pipeline.set_history(history);
+        pipeline.set_confirm_before_paste(settings.confirm_before_paste);
+        pipeline.set_transcript_alternatives(transcript_alternatives(settings));
+        pipeline.set_pause_punctuation(
+            settings
+                .pause_punctuation
+                .then(|| settings.pause_thresholds_for_language()),
+        );
+        pipeline.set_correction_rules(self.corrections.rules());
+        pipeline.set_speaker_gate(Some(self.speaker_gate.clone()));
+        pipeline.set_diagnostics_interval(diagnostics_interval(settings));
+        pipeline.set_trace_export_dir(trace_export_dir(settings));
+        pipeline.set_performance_policy(settings.performance_policy.clone());
         *guard = Some(pipeline);
         events::emit_autoclean_mode(app, parse_autoclean_mode(&settings.autoclean_mode));
         Ok(())
@@ -613,12 +1537,48 @@ impl AppState {
 
         self.repair_installed_ct2_models(app);
 
+        Ok(())
+    }
+
+    /// Starts the ASR warmup and default-model downloads once the configured
+    /// `StartupTrigger` fires, so they don't compete with the desktop's startup.
+    pub fn schedule_startup_work(&self, app: &AppHandle) {
+        let trigger = self
+            .settings
+            .read_frontend()
+            .map(|settings| StartupTrigger::from_settings(&settings))
+            .unwrap_or(StartupTrigger::Immediate);
+        tracing::info!("startup_work_scheduled trigger={trigger:?}");
+        self.startup_pending.store(true, Ordering::SeqCst);
+
+        match trigger {
+            StartupTrigger::Immediate => self.run_startup_work(app),
+            StartupTrigger::Hotkey => {}
+            StartupTrigger::Delay(_) | StartupTrigger::Idle(_) => {
+                let app_handle = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    match trigger {
+                        StartupTrigger::Delay(delay) => tokio::time::sleep(delay).await,
+                        StartupTrigger::Idle(quiet) => startup_warmup::wait_for_idle(quiet).await,
+                        StartupTrigger::Immediate | StartupTrigger::Hotkey => {}
+                    }
+                    app_handle.state::<AppState>().run_startup_work(&app_handle);
+                });
+            }
+        }
+    }
+
+    /// Runs the deferred startup work the first time it is called after
+    /// `schedule_startup_work`; later calls do nothing.
+    fn run_startup_work(&self, app: &AppHandle) {
+        if !self.startup_pending.swap(false, Ordering::SeqCst) {
+            return;
+        }
+        tracing::info!("startup_work_start");
         if !disable_model_autodownload() {
-            // Auto-download default models if they're not installed
             self.auto_download_default_models(app);
         }
-
-        Ok(())
+        self.kickoff_asr_warmup(app);
     }
 
     fn repair_installed_ct2_models(&self, app: &AppHandle) {
@@ -634,7 +1594,8 @@ impl AppState {
                 if asset.kind != ModelKind::WhisperCt2 {
                     continue;
                 }
-                if !matches!(asset.status, ModelStatus::Installed) {
+                // System-provided models are read-only; admins own their layout.
+                if !matches!(asset.status, ModelStatus::Installed) || asset.system_provided {
                     continue;
                 }
 
@@ -690,7 +1651,7 @@ impl AppState {
         if parakeet_missing {
             tracing::info!("Parakeet ASR not installed, auto-downloading...");
             if let Some(name) = parakeet_asset {
-                if let Err(e) = self.queue_model_download(app, &name) {
+                if let Err(e) = self.queue_model_download(app, &name, DownloadPriority::Low) {
                     tracing::warn!("Failed to queue Parakeet download: {e:?}");
                 }
             }
@@ -699,14 +1660,19 @@ impl AppState {
         if vad_missing {
             tracing::info!("Silero VAD not installed, auto-downloading...");
             if let Some(name) = vad_asset {
-                if let Err(e) = self.queue_model_download(app, &name) {
+                if let Err(e) = self.queue_model_download(app, &name, DownloadPriority::Low) {
                     tracing::warn!("Failed to queue VAD download: {e:?}");
                 }
             }
         }
     }
 
-    pub fn queue_model_download(&self, app: &AppHandle, asset_name: &str) -> Result<()> {
+    pub fn queue_model_download(
+        &self,
+        app: &AppHandle,
+        asset_name: &str,
+        priority: DownloadPriority,
+    ) -> Result<()> {
         self.ensure_download_service(app)?;
         let service = self
             .downloads
@@ -716,9 +1682,120 @@ impl AppState {
             .ok_or_else(|| anyhow!("download service unavailable"))?;
         service.queue(ModelDownloadJob {
             asset_name: asset_name.to_string(),
+            update: false,
+            priority,
         })
     }
 
+    /// Downloads waiting behind the running one, in the order they would start.
+    pub fn download_queue(&self) -> Vec<QueuedDownload> {
+        self.downloads
+            .lock()
+            .as_ref()
+            .map(|service| service.pending())
+            .unwrap_or_default()
+    }
+
+    pub fn reorder_download_queue(&self, order: &[QueuedDownloadKey]) {
+        if let Some(service) = self.downloads.lock().as_ref() {
+            service.reorder(order);
+        }
+    }
+
+    pub fn set_download_priority(&self, asset_name: &str, priority: DownloadPriority) {
+        if let Some(service) = self.downloads.lock().as_ref() {
+            service.set_priority(asset_name, priority);
+        }
+    }
+
+    /// Stops the download of `asset_name`, whether it is running or still queued.
+    pub fn cancel_model_download(&self, asset_name: &str) -> Result<()> {
+        let service = self.downloads.lock().as_ref().cloned();
+        let cancelled = service.is_some_and(|service| service.cancel(&self.models, asset_name));
+        if !cancelled {
+            return Err(anyhow!("{asset_name} is not downloading"));
+        }
+        Ok(())
+    }
+
+    /// Re-downloads an installed asset beside the current copy; the old copy is
+    /// kept for `rollback_model_asset`.
+    pub fn update_model_asset(&self, app: &AppHandle, asset_name: &str) -> Result<()> {
+        self.ensure_download_service(app)?;
+        let service = self
+            .downloads
+            .lock()
+            .as_ref()
+            .cloned()
+            .ok_or_else(|| anyhow!("download service unavailable"))?;
+        service.queue(ModelDownloadJob {
+            asset_name: asset_name.to_string(),
+            update: true,
+            priority: DownloadPriority::Normal,
+        })
+    }
+
+    pub fn rollback_model_asset(&self, app: &AppHandle, asset_name: &str) -> Result<()> {
+        let snapshot = rollback_model_update(&self.models, asset_name)?;
+        events::emit_model_status(app, snapshot);
+        self.reload_pipeline(app)
+    }
+
+    /// Points an asset at an existing model directory instead of downloading it.
+    pub fn link_model_asset(&self, app: &AppHandle, asset_name: &str, path: &Path) -> Result<()> {
+        let snapshot = {
+            let mut guard = self.models.lock().map_err(|err| anyhow!(err.to_string()))?;
+            let snapshot = guard.link_external(asset_name, path)?;
+            sync_runtime_environment(&guard)?;
+            snapshot
+        };
+        events::emit_model_status(app, snapshot);
+        self.reload_pipeline(app)
+    }
+
+    /// Checks upstream revisions now; returns every asset with an update available.
+    pub fn check_model_updates(&self, app: &AppHandle) -> Result<Vec<ModelAsset>> {
+        for asset in check_model_updates(&self.models) {
+            events::emit_model_status(app, asset);
+        }
+        let guard = self.models.lock().map_err(|err| anyhow!(err.to_string()))?;
+        Ok(guard
+            .assets()
+            .into_iter()
+            .filter(|asset| asset.revisions.update_available())
+            .cloned()
+            .collect())
+    }
+
+    /// Ordered teardown on app exit: hotkey listeners first so no new session starts,
+    /// then downloads, then the pipeline, whose in-flight paste gets to restore the
+    /// clipboard before the audio threads are joined. Runs once.
+    pub fn shutdown(&self, app: &AppHandle) {
+        if self.shutting_down.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        tracing::info!("shutdown_started");
+
+        crate::core::hotkeys::stop_listeners();
+        crate::core::hud_control::remove_socket();
+
+        let downloads = { self.downloads.lock().take() };
+        if let Some(service) = downloads {
+            for asset in service.shutdown(&self.models) {
+                events::emit_model_status(app, asset);
+            }
+        }
+
+        let pipeline = { self.pipeline.lock().take() };
+        if let Some(pipeline) = pipeline {
+            pipeline.shutdown(SHUTDOWN_SESSION_GRACE);
+        }
+
+        tracing::info!("shutdown_complete");
+        let _ = std::io::Write::flush(&mut std::io::stdout());
+        let _ = std::io::Write::flush(&mut std::io::stderr());
+    }
+
     pub fn reload_pipeline(&self, app: &AppHandle) -> Result<()> {
         let settings = self.settings.read_frontend()?;
         {
@@ -750,13 +1827,15 @@ impl AppState {
         let backend = parse_asr_backend(settings);
         let model_dir = self.resolve_asr_model_dir(settings, &backend);
 
-        let provider = std::env::var("SHERPA_PROVIDER").unwrap_or_else(|_| "cpu".into());
+        let (provider, ct2_device) =
+            crate::core::gpu::resolve(&settings.asr_device, crate::core::gpu::inventory());
+        let provider = std::env::var("SHERPA_PROVIDER").unwrap_or(provider);
         let num_threads = std::env::var("SHERPA_THREADS")
             .ok()
             .and_then(|value| value.parse::<i32>().ok())
             .filter(|value| *value > 0);
 
-        let ct2_device = std::env::var("CT2_DEVICE").unwrap_or_else(|_| "cpu".into());
+        let ct2_device = std::env::var("CT2_DEVICE").unwrap_or(ct2_device);
         let ct2_compute_type = match settings.whisper_precision.as_str() {
             "float" => "float16".to_string(),
             _ => "int8".to_string(),
@@ -769,6 +1848,17 @@ impl AppState {
                 (settings.language.clone(), settings.auto_detect_language)
             };
 
+        // Code-switching needs a multilingual Whisper model and a fixed primary language.
+        let code_switch_languages = if matches!(backend, AsrBackend::WhisperCt2)
+            && settings.whisper_model_language != "en"
+            && language != "auto"
+            && !settings.secondary_language.is_empty()
+        {
+            vec![language.clone(), settings.secondary_language.clone()]
+        } else {
+            Vec::new()
+        };
+
         AsrConfig {
             backend,
             language,
@@ -778,6 +1868,10 @@ impl AppState {
             num_threads,
             ct2_device,
             ct2_compute_type,
+            mmap_weights: settings.mmap_model_weights,
+            code_switch_languages,
+            max_session_secs: settings.max_dictation_secs,
+            hotwords: settings.custom_vocabulary.clone(),
         }
     }
 
@@ -795,7 +1889,12 @@ impl AppState {
                 ModelKind::WhisperCt2,
                 resolve_whisper_asset_name(settings, backend),
             ),
+            AsrBackend::WhisperCpp => (
+                ModelKind::WhisperCpp,
+                resolve_whisper_asset_name(settings, backend),
+            ),
             AsrBackend::Parakeet => (ModelKind::Parakeet, None),
+            AsrBackend::Mock => return None,
         };
 
         self.models.lock().ok().and_then(|guard| {
@@ -807,7 +1906,7 @@ impl AppState {
 
             asset.and_then(|asset| {
                 if matches!(asset.status, ModelStatus::Installed) {
-                    Some(asset.path(guard.root()))
+                    Some(guard.asset_path(asset))
                 } else {
                     None
                 }
@@ -833,16 +1932,33 @@ impl AppState {
 fn parse_autoclean_mode(value: &str) -> AutocleanMode {
     match value {
         "off" => AutocleanMode::Off,
+        "polish" => AutocleanMode::Polish,
         _ => AutocleanMode::Fast,
     }
 }
 
+fn polish_config(settings: &crate::core::settings::FrontendSettings) -> PolishConfig {
+    PolishConfig {
+        endpoint: settings.polish_endpoint.clone(),
+        api: match settings.polish_api.as_str() {
+            "openai" => PolishApi::OpenAi,
+            _ => PolishApi::Ollama,
+        },
+        model: settings.polish_model.clone(),
+        prompt: settings.polish_prompt.clone(),
+        timeout: std::time::Duration::from_millis(u64::from(settings.polish_timeout_ms)),
+        allow_remote: settings.polish_allow_remote && !settings.strict_privacy,
+    }
+}
+
 fn parse_asr_backend(settings: &crate::core::settings::FrontendSettings) -> AsrBackend {
-    if settings.asr_family == "whisper" {
-        if settings.whisper_backend == "onnx" {
-            AsrBackend::WhisperOnnx
-        } else {
-            AsrBackend::WhisperCt2
+    if crate::asr::mock::enabled() {
+        AsrBackend::Mock
+    } else if settings.asr_family == "whisper" {
+        match settings.whisper_backend.as_str() {
+            "onnx" => AsrBackend::WhisperOnnx,
+            "cpp" => AsrBackend::WhisperCpp,
+            _ => AsrBackend::WhisperCt2,
         }
     } else {
         AsrBackend::Parakeet
@@ -882,25 +1998,76 @@ fn resolve_whisper_asset_name(
             let lang_suffix = if language == "en" { "-en" } else { "" };
             Some(format!("whisper-onnx-{size}{lang_suffix}-{precision}"))
         }
+        AsrBackend::WhisperCpp => {
+            // whisper.cpp publishes 5-bit quantizations rather than int8.
+            let precision = match settings.whisper_precision.as_str() {
+                "float" => "float",
+                _ => "q5",
+            };
+            let lang_suffix = if language == "en" { "-en" } else { "" };
+            Some(format!("whisper-cpp-{size}{lang_suffix}-{precision}"))
+        }
         _ => None,
     }
 }
 
-fn parse_paste_shortcut(value: &str) -> PasteShortcut {
-    match value {
-        "ctrl-v" => PasteShortcut::CtrlV,
-        "ctrl-shift-v" => PasteShortcut::CtrlShiftV,
-        _ => PasteShortcut::CtrlShiftV,
+fn trace_export_dir(
+    settings: &crate::core::settings::FrontendSettings,
+) -> Option<std::path::PathBuf> {
+    if !settings.export_session_traces || privacy::strict() {
+        return None;
+    }
+    match crate::core::timing::traces_dir() {
+        Ok(dir) => Some(dir),
+        Err(error) => {
+            tracing::warn!("Session trace export unavailable: {error:?}");
+            None
+        }
     }
 }
 
+fn diagnostics_interval(
+    settings: &crate::core::settings::FrontendSettings,
+) -> Option<std::time::Duration> {
+    match settings.diagnostics_interval_ms {
+        0 => None,
+        ms => Some(std::time::Duration::from_millis(u64::from(ms))),
+    }
+}
+
+fn parse_paste_shortcut(value: &str) -> PasteShortcut {
+    PasteShortcut::parse(value).unwrap_or_else(|error| {
+        warn!("invalid paste shortcut {value:?}: {error}; using Ctrl+Shift+V");
+        PasteShortcut::default()
+    })
+}
+
+fn parse_app_paste_shortcuts(
+    overrides: &std::collections::BTreeMap<String, String>,
+) -> Vec<(String, PasteShortcut)> {
+    overrides
+        .iter()
+        .filter(|(app, _)| !app.trim().is_empty())
+        .filter_map(|(app, value)| match PasteShortcut::parse(value) {
+            Ok(shortcut) => Some((app.trim().to_string(), shortcut)),
+            Err(error) => {
+                warn!("ignoring paste shortcut for {app}: {value:?} ({error})");
+                None
+            }
+        })
+        .collect()
+}
+
 fn publish_hud_runtime_state(state: &AppState, hud_state: &str) {
-    let overlay_enabled = state
-        .settings_manager()
-        .read_frontend()
+    let settings = state.settings_manager().read_frontend().ok();
+    let overlay_enabled = settings
+        .as_ref()
         .map(|settings| settings.show_hud_overlay)
         .unwrap_or(false)
         && is_gnome_wayland_session();
+    let (theme, accent) = settings
+        .map(|settings| (settings.hud_theme, settings.hud_accent_color))
+        .unwrap_or_else(|| ("system".into(), None));
 
     let path = match hud_runtime_state_path() {
         Some(path) => path,
@@ -919,9 +2086,15 @@ fn publish_hud_runtime_state(state: &AppState, hud_state: &str) {
         "state": hud_state,
         "pid": std::process::id(),
         "session_id": std::env::var("XDG_SESSION_ID").ok(),
+        "theme": theme,
+        "accent": accent,
     });
 
     let body = payload.to_string();
+    events::govern_hud_runtime_state(move || write_hud_runtime_state(&path, body));
+}
+
+fn write_hud_runtime_state(path: &Path, body: String) {
     let temp_path = path.with_extension("json.tmp");
 
     if let Err(error) = std::fs::write(&temp_path, body) {
@@ -929,7 +2102,7 @@ fn publish_hud_runtime_state(state: &AppState, hud_state: &str) {
         return;
     }
 
-    if let Err(error) = std::fs::rename(&temp_path, &path) {
+    if let Err(error) = std::fs::rename(&temp_path, path) {
         tracing::debug!("failed promoting runtime hud state file: {error}");
         let _ = std::fs::remove_file(&temp_path);
     }
@@ -941,7 +2114,17 @@ fn hud_runtime_state_path() -> Option<std::path::PathBuf> {
         .map(|base| base.join("openflow").join("hud-state.json"))
 }
 
-fn window_overlay_supported() -> bool {
+/// Brings the main window forward and opens a settings route in it (see `events::ROUTE_*`).
+pub fn open_settings_page(app: &AppHandle, route: &str) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+    events::emit_navigate(app, route);
+}
+
+pub fn window_overlay_supported() -> bool {
     !is_gnome_wayland_session()
 }
 
@@ -1146,13 +2329,20 @@ fn position_overlay_deferred(
             return;
         }
 
-        // Keep the overlay non-interactive (click-through + never focusable).
+        // Keep the overlay never focusable; it is click-through unless the interactive
+        // variant (Cancel/Done buttons) is enabled. A non-focusable window still receives
+        // button presses, so an explicit click does not move keyboard focus either.
         // NOTE: tao's CursorIgnoreEvents handler unwraps the underlying GdkWindow, so calling
         // this before the window is realized will panic. Only do this after show() + delay.
+        let interactive = app_handle
+            .try_state::<AppState>()
+            .and_then(|state| state.settings_manager().read_frontend().ok())
+            .map(|settings| settings.interactive_overlay)
+            .unwrap_or(false);
         let _ = window.set_focusable(false);
         let _ = window.set_visible_on_all_workspaces(true);
         let _ = window.set_always_on_top(true);
-        let _ = window.set_ignore_cursor_events(true);
+        let _ = window.set_ignore_cursor_events(!interactive);
 
         // Some Wayland compositors can still focus the overlay even after we mark it
         // non-focusable. On X11 this can be a transient map-time state, so only force-hide
@@ -1181,6 +2371,35 @@ fn hide_status_overlay(app: &AppHandle) {
     }
 }
 
+/// The entry after `current` in `cycle`, wrapping around; the first entry when
+/// `current` is not part of the cycle.
+fn next_cycle_language(cycle: &[String], current: &str) -> Option<String> {
+    let next = match cycle.iter().position(|language| language == current) {
+        Some(index) => (index + 1) % cycle.len(),
+        None => 0,
+    };
+    cycle.get(next).cloned()
+}
+
+/// Alternatives only matter when there is a review step to pick them from.
+fn transcript_alternatives(settings: &crate::core::settings::FrontendSettings) -> usize {
+    if settings.confirm_before_paste {
+        settings.transcript_alternatives as usize
+    } else {
+        0
+    }
+}
+
+/// Compiled redaction rules; invalid custom patterns are logged and skipped.
+fn redaction_rules(settings: &crate::core::settings::FrontendSettings) -> RedactionRules {
+    let (rules, invalid) =
+        RedactionRules::compile(&settings.redaction_rules, &settings.custom_redactions);
+    for entry in invalid {
+        warn!("redaction_pattern_invalid {entry}");
+    }
+    rules
+}
+
 fn format_asr_selection_label(settings: &crate::core::settings::FrontendSettings) -> String {
     if settings.asr_family == "whisper" {
         format!(
@@ -1219,7 +2438,7 @@ async fn warmup_current_asr(app: &AppHandle, generation: u64) -> Result<()> {
     }
 
     // If the selection failed, fall back to last known-good.
-    let (fallback, current) = {
+    let (fallback, current, auto_revert) = {
         let state = app.state::<AppState>();
         let current_settings = state.settings_manager().read_frontend()?;
         let current = AsrSelection::from_frontend(&current_settings);
@@ -1227,10 +2446,10 @@ async fn warmup_current_asr(app: &AppHandle, generation: u64) -> Result<()> {
             .settings_manager()
             .read_last_known_good_asr()
             .unwrap_or_else(default_asr_selection);
-        (fallback, current)
+        (fallback, current, current_settings.auto_revert_asr)
     };
 
-    if fallback == current {
+    if fallback == current || !auto_revert {
         let error = match &attempt {
             Ok(()) => "unknown warmup failure".to_string(),
             Err(err) => err.to_string(),
@@ -1249,7 +2468,9 @@ async fn warmup_current_asr(app: &AppHandle, generation: u64) -> Result<()> {
         let state = app.state::<AppState>();
         let mut settings = state.settings_manager().read_frontend()?;
         fallback.apply_to_frontend(&mut settings);
-        state.settings_manager().write_frontend(settings)?;
+        state
+            .settings_manager()
+            .write_frontend(settings, ChangeSource::WarmupFallback)?;
         if let Err(error) = state.reload_pipeline(app) {
             tracing::warn!("Failed to reload pipeline for fallback ASR selection: {error:?}");
         }
@@ -1258,6 +2479,21 @@ async fn warmup_current_asr(app: &AppHandle, generation: u64) -> Result<()> {
             let mut tracker = state.asr_warmup.lock();
             tracker.target_selection = Some(fallback.clone());
         }
+
+        let error = match &attempt {
+            Ok(()) => "unknown warmup failure".to_string(),
+            Err(err) => format!("{err:#}"),
+        };
+        tracing::warn!(
+            "asr_selection_reverted from={} to={} error={error}",
+            current.whisper_model,
+            fallback.whisper_model
+        );
+        *state.warmup_revert.lock() = Some(current.clone());
+        events::emit_asr_selection_reverted(
+            app,
+            events::AsrSelectionRevertedPayload::new(&current, &fallback, error),
+        );
     }
 
     // Warm the fallback selection.
@@ -1309,7 +2545,8 @@ async fn warmup_selected_asr(app: &AppHandle, generation: u64) -> Result<()> {
             };
 
             if let Some(pipeline) = pipeline {
-                if pipeline.asr_config().model_dir.is_some() {
+                let config = pipeline.asr_config();
+                if config.model_dir.is_some() || config.backend == AsrBackend::Mock {
                     break pipeline;
                 }
             }
@@ -1327,6 +2564,9 @@ async fn warmup_selected_asr(app: &AppHandle, generation: u64) -> Result<()> {
     tokio::task::spawn_blocking(move || pipeline_clone.warmup_asr())
         .await
         .map_err(|err| anyhow::anyhow!(err.to_string()))??;
+    if let Some(report) = pipeline.asr_load_report() {
+        events::emit_asr_weights_loaded(app, report);
+    }
 
     if !is_current(app) {
         return Ok(());
@@ -1359,7 +2599,10 @@ async fn ensure_asr_assets_ready(
 
     let backend = parse_asr_backend(settings);
 
-    // If already installed, we're done.
+    // The mock needs no assets; otherwise, if already installed, we're done.
+    if backend == AsrBackend::Mock {
+        return Ok(());
+    }
     {
         let state = app.state::<AppState>();
         if state.resolve_asr_model_dir(settings, &backend).is_some() {
@@ -1407,15 +2650,26 @@ async fn ensure_asr_assets_ready(
             Some(ModelStatus::NotInstalled) => {
                 if !queued {
                     let state = app.state::<AppState>();
-                    if let Err(error) = state.queue_model_download(app, &asset_name) {
+                    // Dictation is waiting on this model, so it goes ahead of other downloads.
+                    if let Err(error) =
+                        state.queue_model_download(app, &asset_name, DownloadPriority::High)
+                    {
                         tracing::warn!("Failed to queue ASR model download: {error:?}");
                     } else {
                         queued = true;
                     }
                 }
             }
-            Some(ModelStatus::Downloading { .. }) => {
-                // Wait.
+            Some(ModelStatus::Downloading { progress, .. }) => {
+                // Only surface progress while a dictation attempt is blocked on it.
+                let state = app.state::<AppState>();
+                let blocked = {
+                    let hud = state.hud_state.lock();
+                    matches!(hud.as_str(), "warming" | "downloading")
+                };
+                if blocked {
+                    state.set_download_hud_state(app, &asset_name, progress);
+                }
             }
             None => {
                 // Asset might not exist in manifest; nothing we can do.
@@ -1434,7 +2688,7 @@ impl AppState {
         backend: &AsrBackend,
     ) -> Option<String> {
         match *backend {
-            AsrBackend::WhisperOnnx | AsrBackend::WhisperCt2 => {
+            AsrBackend::WhisperOnnx | AsrBackend::WhisperCt2 | AsrBackend::WhisperCpp => {
                 resolve_whisper_asset_name(settings, backend)
             }
             AsrBackend::Parakeet => {
@@ -1443,6 +2697,7 @@ impl AppState {
                     .primary_asset(&ModelKind::Parakeet)
                     .map(|asset| asset.name.clone())
             }
+            AsrBackend::Mock => None,
         }
     }
 }
@@ -0,0 +1,197 @@
+//! Wayland compositor identification and protocol probing, so overlay, injection and
+//! hotkey backends can be picked per compositor instead of assuming GNOME.
+
+use std::process::Command;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use tracing::{info, warn};
+use wayland_client::protocol::wl_registry;
+use wayland_client::{Connection, Dispatch, QueueHandle};
+
+use crate::core::linux_setup::binary_in_path;
+
+const LAYER_SHELL: &str = "zwlr_layer_shell_v1";
+const DATA_CONTROL: &[&str] = &[
+    "ext_data_control_manager_v1",
+    "zwlr_data_control_manager_v1",
+];
+const VIRTUAL_KEYBOARD: &str = "zwp_virtual_keyboard_manager_v1";
+
+/// Probed once per run; the compositor doesn't change under a running app.
+static CAPABILITIES: Lazy<Option<CompositorCapabilities>> = Lazy::new(probe);
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CompositorCapabilities {
+    /// "gnome", "kde", "wlroots" (sway, Hyprland, river, labwc, Wayfire) or "other".
+    pub family: String,
+    /// `XDG_CURRENT_DESKTOP` as reported, for display.
+    pub desktop: String,
+    pub layer_shell: bool,
+    pub data_control: bool,
+    pub virtual_keyboard: bool,
+    /// `org.freedesktop.portal.GlobalShortcuts` is exported on the session bus.
+    pub global_shortcuts_portal: bool,
+    /// Best available strategies given the above.
+    pub overlay: String,
+    pub injection: String,
+    pub hotkeys: String,
+}
+
+/// Capabilities of the running Wayland compositor; `None` outside Wayland or when the
+/// display can't be reached.
+pub fn capabilities() -> Option<CompositorCapabilities> {
+    (*CAPABILITIES).clone()
+}
+
+fn probe() -> Option<CompositorCapabilities> {
+    if std::env::var_os("WAYLAND_DISPLAY").is_none() {
+        return None;
+    }
+    let globals = match registry_globals() {
+        Ok(globals) => globals,
+        Err(error) => {
+            warn!("compositor_probe_failed error={error:#}");
+            return None;
+        }
+    };
+    let desktop = std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default();
+    let capabilities = classify(&desktop, &globals, global_shortcuts_portal());
+    info!(
+        "compositor_probed family={} layer_shell={} data_control={} virtual_keyboard={} global_shortcuts_portal={}",
+        capabilities.family,
+        capabilities.layer_shell,
+        capabilities.data_control,
+        capabilities.virtual_keyboard,
+        capabilities.global_shortcuts_portal
+    );
+    Some(capabilities)
+}
+
+fn classify(desktop: &str, globals: &[String], portal: bool) -> CompositorCapabilities {
+    let has = |interface: &str| globals.iter().any(|global| global == interface);
+    let layer_shell = has(LAYER_SHELL);
+    let data_control = DATA_CONTROL.iter().any(|interface| has(interface));
+    let virtual_keyboard = has(VIRTUAL_KEYBOARD);
+
+    let desktops: Vec<String> = desktop
+        .split(':')
+        .map(|segment| segment.trim().to_ascii_lowercase())
+        .collect();
+    let is = |name: &str| desktops.iter().any(|segment| segment == name);
+    let family = if is("gnome") {
+        "gnome"
+    } else if is("kde") {
+        "kde"
+    } else if ["sway", "hyprland", "river", "labwc", "wayfire"]
+        .iter()
+        .any(|name| is(name))
+        || (layer_shell && virtual_keyboard)
+    {
+        "wlroots"
+    } else {
+        "other"
+    };
+
+    let overlay = if family == "gnome" {
+        "gnome-extension"
+    } else if layer_shell {
+        "layer-shell"
+    } else {
+        "window"
+    };
+    // The virtual keyboard protocol needs no /dev/uinput permission, but only
+    // wlroots-style compositors expose it.
+    let injection = if virtual_keyboard {
+        "virtual-keyboard"
+    } else {
+        "uinput"
+    };
+    let hotkeys = if portal { "portal" } else { "evdev" };
+
+    CompositorCapabilities {
+        family: family.to_string(),
+        desktop: desktop.to_string(),
+        layer_shell,
+        data_control,
+        virtual_keyboard,
+        global_shortcuts_portal: portal,
+        overlay: overlay.to_string(),
+        injection: injection.to_string(),
+        hotkeys: hotkeys.to_string(),
+    }
+}
+
+struct Globals(Vec<String>);
+
+impl Dispatch<wl_registry::WlRegistry, ()> for Globals {
+    fn event(
+        state: &mut Self,
+        _registry: &wl_registry::WlRegistry,
+        event: wl_registry::Event,
+        _data: &(),
+        _conn: &Connection,
+        _queue: &QueueHandle<Self>,
+    ) {
+        if let wl_registry::Event::Global { interface, .. } = event {
+            state.0.push(interface);
+        }
+    }
+}
+
+/// Interface names the compositor advertises on `wl_registry`.
+fn registry_globals() -> anyhow::Result<Vec<String>> {
+    let connection = Connection::connect_to_env()?;
+    let mut queue = connection.new_event_queue();
+    let _registry = connection.display().get_registry(&queue.handle(), ());
+    let mut globals = Globals(Vec::new());
+    queue.roundtrip(&mut globals)?;
+    Ok(globals.0)
+}
+
+fn global_shortcuts_portal() -> bool {
+    if !binary_in_path("busctl") {
+        return false;
+    }
+    Command::new("busctl")
+        .args([
+            "--user",
+            "get-property",
+            "org.freedesktop.portal.Desktop",
+            "/org/freedesktop/portal/desktop",
+            "org.freedesktop.portal.GlobalShortcuts",
+            "version",
+        ])
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_strategies_per_compositor() {
+        let sway: Vec<String> = [
+            LAYER_SHELL,
+            VIRTUAL_KEYBOARD,
+            "zwlr_data_control_manager_v1",
+        ]
+        .iter()
+        .map(|interface| interface.to_string())
+        .collect();
+        let capabilities = classify("sway", &sway, false);
+        assert_eq!(capabilities.family, "wlroots");
+        assert_eq!(capabilities.overlay, "layer-shell");
+        assert_eq!(capabilities.injection, "virtual-keyboard");
+        assert_eq!(capabilities.hotkeys, "evdev");
+
+        let gnome = classify("ubuntu:GNOME", &[], true);
+        assert_eq!(gnome.family, "gnome");
+        assert_eq!(gnome.overlay, "gnome-extension");
+        assert_eq!(gnome.injection, "uinput");
+        assert_eq!(gnome.hotkeys, "portal");
+    }
+}
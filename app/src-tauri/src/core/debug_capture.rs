@@ -0,0 +1,287 @@
+//! Debug transcript capture: while `debug_transcripts` is on, each session's transcripts,
+//! stage timings and recent log lines are written encrypted to the cache dir, and
+//! everything is deleted once the capture window closes.
+//!
+//! The key lives in the system keyring. If a new key can't be stored there an in-memory
+//! key is used, so the files become unreadable (and are purged) after a restart. While
+//! the keyring can't be read (e.g. it is locked) nothing is captured, read or purged.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Context, Result};
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use directories::ProjectDirs;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tracing::{debug, warn};
+
+use crate::core::secrets;
+use crate::core::settings::DEBUG_TRANSCRIPT_TTL;
+use crate::core::timing::SessionTimeline;
+use crate::output::logs;
+
+const CAPTURE_EXTENSION: &str = "capture";
+const NONCE_LEN: usize = 12;
+/// Log lines kept with each captured session.
+const LOG_TAIL_LINES: usize = 50;
+
+/// End of the current capture window; `None` while capture is off.
+static CAPTURE_UNTIL: Lazy<Mutex<Option<OffsetDateTime>>> = Lazy::new(|| Mutex::new(None));
+static KEY: Lazy<Mutex<Option<Key>>> = Lazy::new(|| Mutex::new(None));
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureEntry {
+    pub session_id: u64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub recorded_at: OffsetDateTime,
+    pub raw_transcript: String,
+    pub transcript: String,
+    pub timeline: serde_json::Value,
+    pub log_tail: Vec<String>,
+}
+
+/// Opens or closes the capture window. Closing it purges everything captured; otherwise
+/// files older than the window are dropped.
+pub fn configure(until: Option<OffsetDateTime>) {
+    let active = until.is_some_and(|until| OffsetDateTime::now_utc() < until);
+    *CAPTURE_UNTIL.lock() = until.filter(|_| active);
+    let result = if active { purge_stale() } else { purge_all() };
+    match result {
+        Ok(0) => {}
+        Ok(removed) => debug!("debug_capture_purged removed={removed} active={active}"),
+        Err(error) => warn!("debug_capture_purge_failed error={error:#}"),
+    }
+}
+
+pub fn active() -> bool {
+    let mut guard = CAPTURE_UNTIL.lock();
+    match *guard {
+        Some(until) if OffsetDateTime::now_utc() < until => true,
+        Some(_) => {
+            // The window ran out mid-run; nothing written under it may outlive it.
+            *guard = None;
+            drop(guard);
+            if let Err(error) = purge_all() {
+                warn!("debug_capture_purge_failed error={error:#}");
+            }
+            false
+        }
+        None => false,
+    }
+}
+
+/// Writes one session; a no-op when capture is off.
+pub fn record(
+    session_id: u64,
+    raw_transcript: &str,
+    transcript: &str,
+    timeline: &SessionTimeline,
+) -> Result<()> {
+    if !active() {
+        return Ok(());
+    }
+    let mut log_tail = logs::snapshot();
+    let skip = log_tail.len().saturating_sub(LOG_TAIL_LINES);
+    log_tail.drain(..skip);
+    let entry = CaptureEntry {
+        session_id,
+        recorded_at: OffsetDateTime::now_utc(),
+        raw_transcript: raw_transcript.to_string(),
+        transcript: transcript.to_string(),
+        timeline: serde_json::to_value(timeline).context("serialize timeline")?,
+        log_tail,
+    };
+
+    let plaintext = serde_json::to_vec(&entry).context("serialize capture entry")?;
+    let cipher = ChaCha20Poly1305::new(&capture_key()?);
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let mut bytes = nonce.to_vec();
+    bytes.extend(
+        cipher
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|_| anyhow!("encrypt capture entry"))?,
+    );
+
+    let dir = capture_dir()?;
+    fs::create_dir_all(&dir).context("create debug capture directory")?;
+    let stamp = entry.recorded_at.unix_timestamp_nanos() / 1_000_000;
+    let path = dir.join(format!("session-{stamp}-{session_id}.{CAPTURE_EXTENSION}"));
+    fs::write(&path, bytes).with_context(|| format!("write {}", path.display()))?;
+    debug!("debug_capture_recorded session={session_id}");
+    Ok(())
+}
+
+/// Decrypts every captured session, oldest first. Files that no longer decrypt (key
+/// rotated or lost) are deleted. Fails without touching them if the keyring can't be read.
+pub fn entries() -> Result<Vec<CaptureEntry>> {
+    let files = capture_files()?;
+    if files.is_empty() {
+        return Ok(Vec::new());
+    }
+    let cipher = ChaCha20Poly1305::new(&capture_key()?);
+    let mut entries = Vec::new();
+    for path in files {
+        let bytes = fs::read(&path).with_context(|| format!("read {}", path.display()))?;
+        match decrypt(&cipher, &bytes) {
+            Ok(entry) => entries.push(entry),
+            Err(error) => {
+                warn!(
+                    "debug_capture_unreadable path={} error={error:#}",
+                    path.display()
+                );
+                let _ = fs::remove_file(&path);
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// Deletes every captured session; returns how many were removed.
+pub fn purge_all() -> Result<usize> {
+    let files = capture_files()?;
+    for path in &files {
+        fs::remove_file(path).with_context(|| format!("remove {}", path.display()))?;
+    }
+    Ok(files.len())
+}
+
+/// Drops sessions captured longer ago than the debug transcript TTL.
+fn purge_stale() -> Result<usize> {
+    let max_age = DEBUG_TRANSCRIPT_TTL.whole_seconds().unsigned_abs();
+    let mut removed = 0;
+    for path in capture_files()? {
+        let age = fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok());
+        let stale = match age {
+            Some(age) => age.as_secs() > max_age,
+            None => true,
+        };
+        if stale {
+            fs::remove_file(&path).with_context(|| format!("remove {}", path.display()))?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+fn decrypt(cipher: &ChaCha20Poly1305, bytes: &[u8]) -> Result<CaptureEntry> {
+    if bytes.len() <= NONCE_LEN {
+        bail!("capture file truncated");
+    }
+    let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow!("decrypt capture entry"))?;
+    serde_json::from_slice(&plaintext).context("parse capture entry")
+}
+
+/// Loads the key from the keyring, creating it on first use. A failed lookup is an
+/// error rather than a missing key: replacing the stored key would orphan every capture.
+fn capture_key() -> Result<Key> {
+    let mut guard = KEY.lock();
+    if let Some(key) = *guard {
+        return Ok(key);
+    }
+
+    let stored =
+        secrets::get(secrets::SECRET_DEBUG_CAPTURE_KEY).context("read debug capture key")?;
+    let key = match parse_stored_key(stored) {
+        Some(key) => key,
+        None => {
+            let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+            if let Err(error) = secrets::set(secrets::SECRET_DEBUG_CAPTURE_KEY, &encode_hex(&key)) {
+                warn!("debug_capture_key_ephemeral error={error:#}");
+            }
+            key
+        }
+    };
+    *guard = Some(key);
+    Ok(key)
+}
+
+/// The stored key, or None when there is none (or it is garbled) and a new one is due.
+fn parse_stored_key(stored: Option<String>) -> Option<Key> {
+    let bytes = decode_hex(&stored?)?;
+    (bytes.len() == 32).then(|| *Key::from_slice(&bytes))
+}
+
+fn capture_dir() -> Result<PathBuf> {
+    let dirs =
+        ProjectDirs::from("com", "OpenFlow", "OpenFlow").context("missing project directories")?;
+    Ok(dirs.cache_dir().join("debug-capture"))
+}
+
+fn capture_files() -> Result<Vec<PathBuf>> {
+    let dir = capture_dir()?;
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Ok(Vec::new());
+    };
+    let mut files: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == CAPTURE_EXTENSION))
+        .collect();
+    // Names start with a millisecond stamp, so they sort by age.
+    files.sort();
+    Ok(files)
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    if value.len() % 2 != 0 {
+        return None;
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(value.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_round_trip_and_reject_a_foreign_key() {
+        let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+        assert_eq!(
+            decode_hex(&encode_hex(&key)).as_deref(),
+            Some(key.as_slice())
+        );
+
+        let cipher = ChaCha20Poly1305::new(&key);
+        let entry = CaptureEntry {
+            session_id: 7,
+            recorded_at: OffsetDateTime::now_utc(),
+            raw_transcript: "um hello".into(),
+            transcript: "Hello.".into(),
+            timeline: serde_json::Value::Null,
+            log_tail: Vec::new(),
+        };
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let mut bytes = nonce.to_vec();
+        bytes.extend(
+            cipher
+                .encrypt(&nonce, serde_json::to_vec(&entry).unwrap().as_slice())
+                .unwrap(),
+        );
+
+        assert_eq!(decrypt(&cipher, &bytes).unwrap().transcript, "Hello.");
+        let other = ChaCha20Poly1305::new(&ChaCha20Poly1305::generate_key(&mut OsRng));
+        assert!(decrypt(&other, &bytes).is_err());
+
+        assert_eq!(parse_stored_key(Some(encode_hex(&key))), Some(key));
+        assert_eq!(parse_stored_key(None), None);
+        assert_eq!(parse_stored_key(Some("abc".into())), None);
+    }
+}
@@ -1,30 +1,233 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use parking_lot::{Mutex, RwLock};
 use serde::Serialize;
 use tauri::{AppHandle, Emitter};
+use tracing::debug;
 
 use crate::core::linux_setup::LinuxPermissionsStatus;
-use crate::core::pipeline::EngineMetrics;
+use crate::core::performance::{PerformancePolicy, PerformanceReason};
+use crate::core::pipeline::{EngineMetrics, OutputMode};
+use crate::core::settings::AsrSelection;
+use crate::core::thermal::ThermalSample;
+use crate::core::timing::SessionTimeline;
 use crate::llm::AutocleanMode;
 
 pub const EVENT_HUD_STATE: &str = "hud-state";
+pub const EVENT_HUD_DOWNLOAD_PROGRESS: &str = "hud-download-progress";
+pub const EVENT_HUD_APPEARANCE: &str = "hud-appearance";
+pub const EVENT_HUD_TOAST: &str = "hud-toast";
+/// Rolling mic level history for the overlay waveform while listening.
+pub const EVENT_HUD_LEVELS: &str = "hud-levels";
+pub const EVENT_TRANSCRIPT_TOAST: &str = "transcript-toast";
+pub const EVENT_LANGUAGE_CYCLED: &str = "language-cycled";
+pub const EVENT_PRIVACY_POSTURE: &str = "privacy-posture";
 pub const EVENT_PERFORMANCE_WARNING: &str = "performance-warning";
 pub const EVENT_PERFORMANCE_RECOVERED: &str = "performance-recovered";
+/// The CPU started or stopped thermal-throttling; decode threads follow.
+pub const EVENT_THERMAL_THROTTLE: &str = "thermal-throttle";
+pub const EVENT_MODEL_DOWNSHIFT: &str = "model-downshift";
 pub const EVENT_SECURE_BLOCKED: &str = "secure-field-blocked";
+pub const EVENT_CONTINUOUS_DICTATION: &str = "continuous-dictation";
+pub const EVENT_SETTINGS_RECOVERED: &str = "settings-recovered";
+/// Warmup of the selected model failed and the selection was reverted to a working one.
+pub const EVENT_ASR_SELECTION_REVERTED: &str = "asr-selection-reverted";
+/// Device access changed without a relogin; views should re-read the permission status.
+pub const EVENT_LINUX_PERMISSIONS_CHANGED: &str = "linux-permissions-changed";
 
 pub const EVENT_TRANSCRIPTION_OUTPUT: &str = "transcription-output";
 pub const EVENT_TRANSCRIPTION_ERROR: &str = "transcription-error";
+pub const EVENT_TRANSCRIPTION_PARTIAL: &str = "transcription-partial";
+pub const EVENT_TRANSCRIPTION_ALTERNATIVES: &str = "transcription-alternatives";
 pub const EVENT_TRANSCRIPTION_SKIPPED: &str = "transcription-skipped";
+pub const EVENT_BUFFER_OVERFLOW: &str = "buffer-overflow";
 pub const EVENT_PERFORMANCE_METRICS: &str = "performance-metrics";
+pub const EVENT_SESSION_TIMING: &str = "session-timing";
+pub const EVENT_LATENCY_BUDGET_EXCEEDED: &str = "latency-budget-exceeded";
 pub const EVENT_MODEL_STATUS: &str = "model-status";
+pub const EVENT_MODEL_DOWNLOAD_QUEUE: &str = "model-download-queue";
+pub const EVENT_ASR_WEIGHTS_LOADED: &str = "asr-weights-loaded";
+pub const EVENT_ASR_GPU_FALLBACK: &str = "asr-gpu-fallback";
 
 pub const EVENT_PASTE_FAILED: &str = "paste-failed";
 pub const EVENT_PASTE_UNCONFIRMED: &str = "paste-unconfirmed";
 pub const EVENT_PASTE_SUCCEEDED: &str = "paste-succeeded";
+pub const EVENT_PENDING_TRANSCRIPT: &str = "pending-transcript";
+pub const EVENT_TRANSCRIPT_RECOPIED: &str = "transcript-recopied";
 
 pub const EVENT_AUDIO_DIAGNOSTICS: &str = "audio-diagnostics";
 pub const EVENT_VAD_DIAGNOSTICS: &str = "vad-diagnostics";
+/// Emitted once per finalized dictation with the speech trim decisions.
+pub const EVENT_TRIM_REPORT: &str = "trim-report";
+
+/// Asks the main window to open a settings route (see `ROUTE_*`).
+pub const EVENT_NAVIGATE: &str = "navigate";
+/// A problem the user can fix from a specific settings route.
+pub const EVENT_ATTENTION_ROUTE: &str = "attention-route";
+
+pub const ROUTE_SETTINGS: &str = "settings";
+pub const ROUTE_GENERAL: &str = "settings/general";
+pub const ROUTE_MODELS: &str = "settings/models";
+pub const ROUTE_PERMISSIONS: &str = "settings/permissions";
+
+pub fn model_route(asset_name: &str) -> String {
+    format!("models/{asset_name}")
+}
 
 pub const EVENT_UPDATE_DOWNLOAD_PROGRESS: &str = "update-download-progress";
 pub const EVENT_UPDATE_APPLY_PROGRESS: &str = "update-apply-progress";
+pub const EVENT_UPDATE_AVAILABLE: &str = "update-available";
+
+/// Not a webview event: key under which writes of the GNOME HUD runtime state file are governed.
+pub const HUD_RUNTIME_STATE: &str = "hud-runtime-state";
+
+/// Minimum spacing between deliveries of chatty events. Events without an interval are
+/// emitted as-is; state changes, results and errors are never limited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmissionLimits {
+    intervals: HashMap<String, Duration>,
+}
+
+impl EmissionLimits {
+    /// Built-in intervals for `profile` ("normal" or "low-power"), with per-event
+    /// millisecond overrides on top; an override of 0 removes the limit.
+    pub fn from_settings(profile: &str, overrides: &BTreeMap<String, u32>) -> Self {
+        let low_power = profile == "low-power";
+        let defaults: [(&str, u64, u64); 10] = [
+            (EVENT_PERFORMANCE_METRICS, 250, 1_000),
+            (EVENT_AUDIO_DIAGNOSTICS, 0, 500),
+            (EVENT_VAD_DIAGNOSTICS, 0, 500),
+            (EVENT_MODEL_STATUS, 100, 500),
+            (EVENT_HUD_DOWNLOAD_PROGRESS, 100, 500),
+            (EVENT_HUD_LEVELS, 0, 100),
+            (EVENT_UPDATE_DOWNLOAD_PROGRESS, 100, 500),
+            (EVENT_TRANSCRIPTION_PARTIAL, 0, 250),
+            (HUD_RUNTIME_STATE, 0, 250),
+            (EVENT_BUFFER_OVERFLOW, 1_000, 1_000),
+        ];
+        let mut intervals: HashMap<String, Duration> = defaults
+            .into_iter()
+            .map(|(event, normal, low)| {
+                let ms = if low_power { low } else { normal };
+                (event.to_string(), Duration::from_millis(ms))
+            })
+            .collect();
+        for (event, ms) in overrides {
+            intervals.insert(event.clone(), Duration::from_millis(u64::from(*ms)));
+        }
+        intervals.retain(|_, interval| !interval.is_zero());
+        Self { intervals }
+    }
+
+    pub fn interval(&self, event: &str) -> Duration {
+        self.intervals.get(event).copied().unwrap_or(Duration::ZERO)
+    }
+}
+
+impl Default for EmissionLimits {
+    fn default() -> Self {
+        Self::from_settings("normal", &BTreeMap::new())
+    }
+}
+
+type Delivery = Box<dyn FnOnce() + Send>;
+
+#[derive(Default)]
+struct EmissionSlot {
+    last_sent: Option<Instant>,
+    /// Newest undelivered payload; older ones are dropped when it is replaced.
+    pending: Option<Delivery>,
+    flush_scheduled: bool,
+}
+
+static EMISSION_LIMITS: Lazy<RwLock<EmissionLimits>> =
+    Lazy::new(|| RwLock::new(EmissionLimits::default()));
+static EMISSION_SLOTS: Lazy<Mutex<HashMap<(&'static str, String), EmissionSlot>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+pub fn configure_emission_limits(limits: EmissionLimits) {
+    let mut guard = EMISSION_LIMITS.write();
+    if *guard != limits {
+        debug!("emission_limits_configured limits={:?}", limits.intervals);
+        *guard = limits;
+    }
+}
+
+/// Delivers `send` now if `event`/`key` is outside its interval, otherwise keeps it as the
+/// pending payload and delivers the newest one when the interval ends (trailing edge), so
+/// the final value of a burst always arrives.
+fn govern(event: &'static str, key: &str, send: impl FnOnce() + Send + 'static) {
+    let interval = EMISSION_LIMITS.read().interval(event);
+    if interval.is_zero() {
+        send();
+        return;
+    }
+
+    let now = Instant::now();
+    let mut slots = EMISSION_SLOTS.lock();
+    let slot = slots.entry((event, key.to_string())).or_default();
+    let due = match slot.last_sent {
+        Some(last) => now.duration_since(last) >= interval,
+        None => true,
+    };
+    if due && !slot.flush_scheduled {
+        slot.last_sent = Some(now);
+        drop(slots);
+        send();
+        return;
+    }
+
+    slot.pending = Some(Box::new(send));
+    if slot.flush_scheduled {
+        return;
+    }
+    slot.flush_scheduled = true;
+    let wait = slot.last_sent.map_or(Duration::ZERO, |last| {
+        (last + interval).saturating_duration_since(now)
+    });
+    let key = key.to_string();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(wait).await;
+        flush_pending(event, &key);
+    });
+}
+
+fn flush_pending(event: &'static str, key: &str) {
+    let pending = {
+        let mut slots = EMISSION_SLOTS.lock();
+        let Some(slot) = slots.get_mut(&(event, key.to_string())) else {
+            return;
+        };
+        slot.flush_scheduled = false;
+        let pending = slot.pending.take();
+        if pending.is_some() {
+            slot.last_sent = Some(Instant::now());
+        }
+        pending
+    };
+    if let Some(send) = pending {
+        send();
+    }
+}
+
+/// Drops undelivered payloads for `event`, e.g. a partial transcript once the final one is out.
+fn discard_pending(event: &'static str) {
+    let mut slots = EMISSION_SLOTS.lock();
+    for ((slot_event, _), slot) in slots.iter_mut() {
+        if *slot_event == event {
+            slot.pending = None;
+        }
+    }
+}
+
+/// Rate-limited write of the GNOME HUD runtime state file; see `HUD_RUNTIME_STATE`.
+pub fn govern_hud_runtime_state(write: impl FnOnce() + Send + 'static) {
+    govern(HUD_RUNTIME_STATE, "", write);
+}
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -33,6 +236,10 @@ pub struct PasteFailedPayload {
     pub message: String,
     pub shortcut: String,
     pub transcript_on_clipboard: bool,
+    /// Read-back of a uinput chord: "echoed", "grabbed", "not-echoed", "no-handler" or
+    /// "unverified". Absent when no uinput chord was sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chord_delivery: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub linux: Option<LinuxPermissionsStatus>,
 }
@@ -48,30 +255,391 @@ pub fn emit_hud_state(app: &AppHandle, state: &str) {
     let _ = app.emit(EVENT_HUD_STATE, state.to_string());
 }
 
-pub fn emit_performance_warning(app: &AppHandle, metrics: &EngineMetrics) {
-    let _ = app.emit(EVENT_PERFORMANCE_WARNING, metrics.clone());
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HudAppearancePayload {
+    pub theme: String,
+    pub accent: Option<String>,
+}
+
+pub fn emit_hud_appearance(app: &AppHandle, payload: HudAppearancePayload) {
+    let _ = app.emit(EVENT_HUD_APPEARANCE, payload);
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HudDownloadProgressPayload {
+    pub asset_name: String,
+    pub progress: f32,
+}
+
+pub fn emit_hud_download_progress(app: &AppHandle, payload: HudDownloadProgressPayload) {
+    let app = app.clone();
+    let key = payload.asset_name.clone();
+    govern(EVENT_HUD_DOWNLOAD_PROGRESS, &key, move || {
+        let _ = app.emit(EVENT_HUD_DOWNLOAD_PROGRESS, payload);
+    });
+}
+
+/// Oldest first, up to 30 points each (about a second), in 0..=1.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HudLevelsPayload {
+    pub rms: Vec<f32>,
+    pub peak: Vec<f32>,
+}
+
+pub fn emit_hud_levels(app: &AppHandle, payload: HudLevelsPayload) {
+    let app = app.clone();
+    govern(EVENT_HUD_LEVELS, "", move || {
+        let _ = app.emit(EVENT_HUD_LEVELS, payload);
+    });
+}
+
+/// Short message the overlay shows under the orb, e.g. after a hotkey changed a setting.
+pub fn emit_hud_toast(app: &AppHandle, message: &str) {
+    let _ = app.emit(EVENT_HUD_TOAST, message.to_string());
+}
+
+/// What was just pasted, for the overlay's confirmation toast.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptToastPayload {
+    /// Single-line, truncated preview of the pasted text.
+    pub text: String,
+    pub chars: usize,
+    pub words: usize,
+    pub shortcut: String,
+    /// `WM_CLASS` of the window pasted into; X11 only.
+    pub window_class: Option<String>,
+}
+
+pub fn emit_transcript_toast(app: &AppHandle, payload: TranscriptToastPayload) {
+    let _ = app.emit(EVENT_TRANSCRIPT_TOAST, payload);
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguageCycledPayload {
+    pub language: String,
+    pub label: String,
+    /// The ASR selection switched to along with the language, if it has one pinned.
+    pub asr_selection: Option<AsrSelection>,
+}
+
+pub fn emit_language_cycled(app: &AppHandle, payload: LanguageCycledPayload) {
+    let _ = app.emit(EVENT_LANGUAGE_CYCLED, payload);
+}
+
+pub fn emit_privacy_posture(app: &AppHandle, payload: crate::core::privacy::PrivacyPosture) {
+    let _ = app.emit(EVENT_PRIVACY_POSTURE, payload);
+}
+
+/// Sent when performance mode turns on or off, with the numbers that triggered it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceModePayload {
+    pub performance_mode: bool,
+    pub reason: &'static str,
+    pub detail: String,
+    pub last_latency_ms: u64,
+    pub average_cpu_percent: f32,
+    pub consecutive_slow: u32,
+    pub consecutive_fast: u32,
+    /// Actions applied while the mode is on, e.g. "shorten-vad-hangover".
+    pub actions: Vec<&'static str>,
+    pub policy: PerformancePolicy,
+}
+
+impl PerformanceModePayload {
+    pub fn new(
+        metrics: &EngineMetrics,
+        policy: &PerformancePolicy,
+        reason: PerformanceReason,
+    ) -> Self {
+        let latency_ms = metrics.last_latency.as_millis() as u64;
+        let cpu_percent = metrics.average_cpu * 100.0;
+        let detail = match reason {
+            PerformanceReason::SlowSessions => format!(
+                "{} sessions slower than {} ms at over {:.0}% CPU (last {} ms, {:.0}%)",
+                metrics.consecutive_slow,
+                policy.slow_latency_ms,
+                policy.slow_cpu_percent,
+                latency_ms,
+                cpu_percent
+            ),
+            PerformanceReason::FastSessions => format!(
+                "{} sessions within {} ms (last {} ms)",
+                metrics.consecutive_fast, policy.slow_latency_ms, latency_ms
+            ),
+            PerformanceReason::CpuRecovered => format!(
+                "CPU {:.0}% below {:.0}%",
+                cpu_percent, policy.recover_cpu_percent
+            ),
+        };
+        Self {
+            performance_mode: metrics.performance_mode,
+            reason: reason.as_str(),
+            detail,
+            last_latency_ms: latency_ms,
+            average_cpu_percent: cpu_percent,
+            consecutive_slow: metrics.consecutive_slow,
+            consecutive_fast: metrics.consecutive_fast,
+            actions: if metrics.performance_mode {
+                policy.actions()
+            } else {
+                Vec::new()
+            },
+            policy: policy.clone(),
+        }
+    }
+}
+
+pub fn emit_performance_warning(app: &AppHandle, payload: PerformanceModePayload) {
+    let _ = app.emit(EVENT_PERFORMANCE_WARNING, payload);
+}
+
+pub fn emit_performance_recovered(app: &AppHandle, payload: PerformanceModePayload) {
+    let _ = app.emit(EVENT_PERFORMANCE_RECOVERED, payload);
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThermalThrottlePayload {
+    pub throttled: bool,
+    pub temp_c: Option<f32>,
+    pub trip_c: Option<f32>,
+    /// Decode thread cap while throttled; None once it is lifted.
+    pub decode_threads: Option<usize>,
+    pub detail: String,
+}
+
+impl ThermalThrottlePayload {
+    pub fn new(sample: &ThermalSample, decode_threads: Option<usize>) -> Self {
+        let temp = match (sample.temp_c, sample.trip_c) {
+            (Some(temp), Some(trip)) => format!("CPU at {temp:.0}°C (trip {trip:.0}°C)"),
+            _ => "CPU throttle counters rising".to_string(),
+        };
+        let detail = match (decode_threads, sample.temp_c) {
+            (Some(threads), _) => format!("{temp}; decoding with {threads} thread(s)"),
+            (None, Some(temp)) => format!("CPU cooled to {temp:.0}°C; decode threads restored"),
+            (None, None) => "CPU no longer throttling; decode threads restored".to_string(),
+        };
+        Self {
+            throttled: sample.throttled,
+            temp_c: sample.temp_c,
+            trip_c: sample.trip_c,
+            decode_threads,
+            detail,
+        }
+    }
+}
+
+pub fn emit_thermal_throttle(app: &AppHandle, payload: ThermalThrottlePayload) {
+    let _ = app.emit(EVENT_THERMAL_THROTTLE, payload);
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelDownshiftPayload {
+    /// False when the lighter model is only being offered.
+    pub applied: bool,
+    pub from_label: String,
+    pub to_label: String,
+}
+
+impl ModelDownshiftPayload {
+    pub fn new(from: &AsrSelection, to: &AsrSelection, applied: bool) -> Self {
+        Self {
+            applied,
+            from_label: asr_selection_label(from),
+            to_label: asr_selection_label(to),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AsrSelectionRevertedPayload {
+    pub original: AsrSelection,
+    pub original_label: String,
+    pub fallback: AsrSelection,
+    pub fallback_label: String,
+    pub error: String,
+}
+
+impl AsrSelectionRevertedPayload {
+    pub fn new(original: &AsrSelection, fallback: &AsrSelection, error: String) -> Self {
+        Self {
+            original: original.clone(),
+            original_label: asr_selection_label(original),
+            fallback: fallback.clone(),
+            fallback_label: asr_selection_label(fallback),
+            error,
+        }
+    }
+}
+
+pub fn emit_asr_selection_reverted(app: &AppHandle, payload: AsrSelectionRevertedPayload) {
+    let _ = app.emit(EVENT_ASR_SELECTION_REVERTED, payload);
+}
+
+fn asr_selection_label(selection: &AsrSelection) -> String {
+    if selection.asr_family == "whisper" {
+        format!(
+            "Whisper {} ({})",
+            selection.whisper_model, selection.whisper_precision
+        )
+    } else {
+        "Parakeet".to_string()
+    }
+}
+
+pub fn emit_model_downshift(app: &AppHandle, payload: ModelDownshiftPayload) {
+    let _ = app.emit(EVENT_MODEL_DOWNSHIFT, payload);
 }
 
-pub fn emit_performance_recovered(app: &AppHandle, metrics: &EngineMetrics) {
-    let _ = app.emit(EVENT_PERFORMANCE_RECOVERED, metrics.clone());
+pub fn emit_navigate(app: &AppHandle, route: &str) {
+    let _ = app.emit(EVENT_NAVIGATE, route.to_string());
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttentionRoutePayload {
+    pub route: String,
+    pub title: String,
+    pub message: String,
+}
+
+pub fn emit_attention_route(app: &AppHandle, route: &str, title: &str, message: &str) {
+    let _ = app.emit(
+        EVENT_ATTENTION_ROUTE,
+        AttentionRoutePayload {
+            route: route.to_string(),
+            title: title.to_string(),
+            message: message.to_string(),
+        },
+    );
+}
+
+pub fn emit_linux_permissions_changed(app: &AppHandle) {
+    let _ = app.emit(EVENT_LINUX_PERMISSIONS_CHANGED, ());
 }
 
 pub fn emit_secure_blocked(app: &AppHandle) {
     let _ = app.emit(EVENT_SECURE_BLOCKED, ());
 }
 
+pub fn emit_continuous_dictation(app: &AppHandle, active: bool) {
+    let _ = app.emit(EVENT_CONTINUOUS_DICTATION, active);
+}
+
+pub fn emit_settings_recovered(app: &AppHandle, recovery: crate::core::settings::SettingsRecovery) {
+    let _ = app.emit(EVENT_SETTINGS_RECOVERED, recovery);
+}
+
 pub fn emit_autoclean_mode(app: &AppHandle, mode: AutocleanMode) {
     let _ = app.emit("autoclean-mode", mode);
 }
 
-pub fn emit_transcription_output(app: &AppHandle, text: &str) {
-    let _ = app.emit(EVENT_TRANSCRIPTION_OUTPUT, text.to_string());
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptionOutputPayload {
+    pub text: String,
+    pub mode: OutputMode,
+    /// Set in emit-only mode so external consumers can decide where the text goes.
+    pub routing: Option<RoutingContext>,
+}
+
+/// Where the dictation was aimed when it finished.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoutingContext {
+    /// Focused window `WM_CLASS` class; X11 only, None on Wayland.
+    pub window_class: Option<String>,
+    /// Focused window title; X11 only, None on Wayland.
+    pub window_title: Option<String>,
+    pub session_id: u64,
+    /// Active dictation profile. Always None until profiles exist.
+    pub profile: Option<String>,
+}
+
+pub fn emit_transcription_output(
+    app: &AppHandle,
+    text: &str,
+    mode: OutputMode,
+    routing: Option<RoutingContext>,
+) {
+    discard_pending(EVENT_TRANSCRIPTION_PARTIAL);
+    let _ = app.emit(
+        EVENT_TRANSCRIPTION_OUTPUT,
+        TranscriptionOutputPayload {
+            text: text.to_string(),
+            mode,
+            routing,
+        },
+    );
+}
+
+/// Sent when confirm-before-paste holds a transcript; `None` once it is confirmed or discarded.
+pub fn emit_pending_transcript(app: &AppHandle, text: Option<&str>) {
+    let _ = app.emit(EVENT_PENDING_TRANSCRIPT, text.map(str::to_string));
+}
+
+/// Payload is the re-copied transcript, or None when there was nothing to re-copy.
+pub fn emit_transcript_recopied(app: &AppHandle, text: Option<&str>) {
+    let _ = app.emit(EVENT_TRANSCRIPT_RECOPIED, text.map(str::to_string));
+}
+
+/// Transcript accumulated so far while a segmented decode is still running.
+pub fn emit_transcription_partial(app: &AppHandle, text: &str) {
+    let app = app.clone();
+    let text = text.to_string();
+    govern(EVENT_TRANSCRIPTION_PARTIAL, "", move || {
+        let _ = app.emit(EVENT_TRANSCRIPTION_PARTIAL, text);
+    });
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptionAlternativesPayload {
+    /// The transcript that was delivered (or is pending review).
+    pub text: String,
+    pub alternatives: Vec<String>,
+}
+
+/// Sent before the transcript is delivered, so a review UI can offer the alternatives.
+pub fn emit_transcription_alternatives(app: &AppHandle, text: &str, alternatives: Vec<String>) {
+    let _ = app.emit(
+        EVENT_TRANSCRIPTION_ALTERNATIVES,
+        TranscriptionAlternativesPayload {
+            text: text.to_string(),
+            alternatives,
+        },
+    );
 }
 
 pub fn emit_transcription_error(app: &AppHandle, message: &str) {
     let _ = app.emit(EVENT_TRANSCRIPTION_ERROR, message.to_string());
 }
 
+/// Audio dropped from the start of the current dictation because it outgrew the cap.
+/// Totals are cumulative for the session.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BufferOverflowPayload {
+    pub dropped_samples: usize,
+    pub dropped_ms: u64,
+    pub cap_secs: u32,
+}
+
+pub fn emit_buffer_overflow(app: &AppHandle, payload: BufferOverflowPayload) {
+    let app = app.clone();
+    govern(EVENT_BUFFER_OVERFLOW, "", move || {
+        let _ = app.emit(EVENT_BUFFER_OVERFLOW, payload);
+    });
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TranscriptionSkippedPayload {
@@ -110,10 +678,34 @@ pub struct AudioDiagnosticsPayload {
     pub synthetic: bool,
     pub rms: f32,
     pub peak: f32,
+    /// Input quality problem worth surfacing, e.g. a Bluetooth headset in hands-free mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
+/// Open views listening for audio/VAD diagnostics. Diagnostics are only computed while
+/// at least one is subscribed.
+static DIAGNOSTICS_SUBSCRIBERS: AtomicUsize = AtomicUsize::new(0);
+
+pub fn set_diagnostics_subscribed(subscribed: bool) {
+    if subscribed {
+        DIAGNOSTICS_SUBSCRIBERS.fetch_add(1, Ordering::SeqCst);
+    } else {
+        let _ = DIAGNOSTICS_SUBSCRIBERS.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+            count.checked_sub(1)
+        });
+    }
+}
+
+pub fn diagnostics_subscribed() -> bool {
+    DIAGNOSTICS_SUBSCRIBERS.load(Ordering::Relaxed) > 0
 }
 
 pub fn emit_audio_diagnostics(app: &AppHandle, payload: AudioDiagnosticsPayload) {
-    let _ = app.emit(EVENT_AUDIO_DIAGNOSTICS, payload);
+    let app = app.clone();
+    govern(EVENT_AUDIO_DIAGNOSTICS, "", move || {
+        let _ = app.emit(EVENT_AUDIO_DIAGNOSTICS, payload);
+    });
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -127,7 +719,49 @@ pub struct VadDiagnosticsPayload {
 }
 
 pub fn emit_vad_diagnostics(app: &AppHandle, payload: VadDiagnosticsPayload) {
-    let _ = app.emit(EVENT_VAD_DIAGNOSTICS, payload);
+    let app = app.clone();
+    govern(EVENT_VAD_DIAGNOSTICS, "", move || {
+        let _ = app.emit(EVENT_VAD_DIAGNOSTICS, payload);
+    });
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrimSegmentPayload {
+    /// Offsets into the captured audio.
+    pub start_ms: u64,
+    pub end_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrimCheckPayload {
+    /// The no-output reason code the check rejects with.
+    pub code: String,
+    pub passed: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrimReportPayload {
+    pub session_id: u64,
+    pub sample_rate: u32,
+    pub total_samples: usize,
+    /// Samples the VAD judged as speech, and the minimum needed to run ASR at all.
+    pub active_samples: usize,
+    pub min_active_samples: usize,
+    /// Samples handed to ASR; the rest was trimmed, compacted or gated away.
+    pub kept_samples: usize,
+    pub dropped_samples: usize,
+    pub segments: Vec<TrimSegmentPayload>,
+    /// Checks in evaluation order; checks after a failed one are not listed.
+    pub checks: Vec<TrimCheckPayload>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rejected: Option<String>,
+}
+
+pub fn emit_trim_report(app: &AppHandle, payload: TrimReportPayload) {
+    let _ = app.emit(EVENT_TRIM_REPORT, payload);
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -137,6 +771,8 @@ struct MetricsPayload {
     average_cpu_percent: f32,
     consecutive_slow: u32,
     performance_mode: bool,
+    thermal_throttled: bool,
+    cpu_temp_c: Option<f32>,
 }
 
 pub fn emit_metrics(app: &AppHandle, metrics: &EngineMetrics) {
@@ -145,19 +781,97 @@ pub fn emit_metrics(app: &AppHandle, metrics: &EngineMetrics) {
         average_cpu_percent: metrics.average_cpu * 100.0,
         consecutive_slow: metrics.consecutive_slow,
         performance_mode: metrics.performance_mode,
+        thermal_throttled: metrics.thermal_throttled,
+        cpu_temp_c: metrics.cpu_temp_c,
+    };
+    let app = app.clone();
+    govern(EVENT_PERFORMANCE_METRICS, "", move || {
+        let _ = app.emit(EVENT_PERFORMANCE_METRICS, payload);
+    });
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionTimingPayload {
+    #[serde(flatten)]
+    pub timeline: SessionTimeline,
+    pub total_ms: u64,
+    /// Chrome trace written for this session, when export is on.
+    pub trace_path: Option<PathBuf>,
+}
+
+pub fn emit_session_timing(
+    app: &AppHandle,
+    timeline: SessionTimeline,
+    trace_path: Option<PathBuf>,
+) {
+    let payload = SessionTimingPayload {
+        total_ms: timeline.total().as_millis() as u64,
+        timeline,
+        trace_path,
     };
-    let _ = app.emit(EVENT_PERFORMANCE_METRICS, payload);
+    let _ = app.emit(EVENT_SESSION_TIMING, payload);
+}
+
+pub fn emit_latency_budget_exceeded(
+    app: &AppHandle,
+    payload: crate::core::performance::LatencyBudgetReport,
+) {
+    let _ = app.emit(EVENT_LATENCY_BUDGET_EXCEEDED, payload);
+}
+
+/// Coalesced per asset `name`; a payload without one (a full snapshot) supersedes
+/// every pending per-asset update.
+pub fn emit_model_status<T: Serialize + Clone + Send + 'static>(app: &AppHandle, payload: T) {
+    let key = serde_json::to_value(&payload)
+        .ok()
+        .and_then(|value| value.get("name")?.as_str().map(str::to_string));
+    let key = match key {
+        Some(name) => name,
+        None => {
+            discard_pending(EVENT_MODEL_STATUS);
+            String::new()
+        }
+    };
+    let app = app.clone();
+    govern(EVENT_MODEL_STATUS, &key, move || {
+        let _ = app.emit(EVENT_MODEL_STATUS, payload);
+    });
+}
+
+/// Downloads waiting behind the running one, after every queue change.
+pub fn emit_model_download_queue(app: &AppHandle, payload: Vec<crate::models::QueuedDownload>) {
+    let _ = app.emit(EVENT_MODEL_DOWNLOAD_QUEUE, payload);
 }
 
-pub fn emit_model_status<T: Serialize + Clone>(app: &AppHandle, payload: T) {
-    let _ = app.emit(EVENT_MODEL_STATUS, payload);
+pub fn emit_asr_weights_loaded(app: &AppHandle, payload: crate::asr::WeightLoadReport) {
+    let _ = app.emit(EVENT_ASR_WEIGHTS_LOADED, payload);
+}
+
+pub fn emit_asr_gpu_fallback(app: &AppHandle, payload: crate::asr::GpuFallback) {
+    let _ = app.emit(EVENT_ASR_GPU_FALLBACK, payload);
 }
 
 pub fn emit_update_download_progress(
     app: &AppHandle,
     payload: crate::core::updater::UpdateDownloadProgress,
 ) {
-    let _ = app.emit(EVENT_UPDATE_DOWNLOAD_PROGRESS, payload);
+    let app = app.clone();
+    govern(EVENT_UPDATE_DOWNLOAD_PROGRESS, "", move || {
+        let _ = app.emit(EVENT_UPDATE_DOWNLOAD_PROGRESS, payload);
+    });
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAvailablePayload {
+    pub check: crate::core::updater::UpdateCheckResult,
+    /// Set once a background pre-download has finished and verified.
+    pub downloaded: Option<crate::core::updater::DownloadedUpdate>,
+}
+
+pub fn emit_update_available(app: &AppHandle, payload: UpdateAvailablePayload) {
+    let _ = app.emit(EVENT_UPDATE_AVAILABLE, payload);
 }
 
 pub fn emit_update_apply_progress(
@@ -166,3 +880,33 @@ pub fn emit_update_apply_progress(
 ) {
     let _ = app.emit(EVENT_UPDATE_APPLY_PROGRESS, payload);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn low_power_profile_limits_diagnostics_and_overrides_apply() {
+        let normal = EmissionLimits::from_settings("normal", &BTreeMap::new());
+        assert_eq!(normal.interval(EVENT_AUDIO_DIAGNOSTICS), Duration::ZERO);
+        assert_eq!(normal.interval(EVENT_HUD_STATE), Duration::ZERO);
+
+        let overrides = BTreeMap::from([
+            (EVENT_PERFORMANCE_METRICS.to_string(), 0),
+            (EVENT_TRANSCRIPTION_PARTIAL.to_string(), 400),
+        ]);
+        let low_power = EmissionLimits::from_settings("low-power", &overrides);
+        assert_eq!(
+            low_power.interval(EVENT_AUDIO_DIAGNOSTICS),
+            Duration::from_millis(500)
+        );
+        assert_eq!(
+            low_power.interval(EVENT_PERFORMANCE_METRICS),
+            Duration::ZERO
+        );
+        assert_eq!(
+            low_power.interval(EVENT_TRANSCRIPTION_PARTIAL),
+            Duration::from_millis(400)
+        );
+    }
+}
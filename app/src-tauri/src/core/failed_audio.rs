@@ -0,0 +1,145 @@
+//! Audio of dictations that produced no transcript, kept as WAV files in the cache
+//! dir while `keep_failed_audio` is on so "it heard nothing" reports can be replayed.
+//! Clips older than the retention window, or beyond the newest few, are deleted.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use directories::ProjectDirs;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::Serialize;
+use time::OffsetDateTime;
+use tracing::{debug, warn};
+
+const CLIP_EXTENSION: &str = "wav";
+/// Clips kept regardless of age.
+const MAX_CLIPS: usize = 20;
+
+/// What ASR made of a kept clip on replay.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayedAudio {
+    pub path: String,
+    pub transcript: String,
+    pub latency_ms: u64,
+}
+
+/// How long clips are kept; `None` while retention is off.
+static RETENTION: Lazy<Mutex<Option<Duration>>> = Lazy::new(|| Mutex::new(None));
+
+/// Turns retention on for `retention`, or off (deleting every clip) with `None`.
+pub fn configure(retention: Option<Duration>) {
+    *RETENTION.lock() = retention;
+    let result = match retention {
+        Some(max_age) => purge_expired(max_age),
+        None => purge_all(),
+    };
+    match result {
+        Ok(0) => {}
+        Ok(removed) => debug!("failed_audio_purged removed={removed}"),
+        Err(error) => warn!("failed_audio_purge_failed error={error:#}"),
+    }
+}
+
+/// Writes `samples` as 16-bit mono WAV; a no-op while retention is off.
+pub fn record(session_id: u64, reason: &str, sample_rate: u32, samples: &[f32]) {
+    let Some(max_age) = *RETENTION.lock() else {
+        return;
+    };
+    if samples.is_empty() {
+        return;
+    }
+    match write_clip(session_id, reason, sample_rate, samples) {
+        Ok(path) => debug!(
+            "failed_audio_recorded session={session_id} reason={reason} path={}",
+            path.display()
+        ),
+        Err(error) => warn!("failed_audio_record_failed session={session_id} error={error:#}"),
+    }
+    if let Err(error) = purge_expired(max_age) {
+        warn!("failed_audio_purge_failed error={error:#}");
+    }
+}
+
+/// The most recently kept clip.
+pub fn latest() -> Result<Option<PathBuf>> {
+    Ok(clip_files()?.pop())
+}
+
+fn write_clip(session_id: u64, reason: &str, sample_rate: u32, samples: &[f32]) -> Result<PathBuf> {
+    let dir = clip_dir()?;
+    fs::create_dir_all(&dir).context("create failed audio directory")?;
+    let stamp = OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000;
+    let path = dir.join(format!(
+        "session-{stamp}-{session_id}-{reason}.{CLIP_EXTENSION}"
+    ));
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(&path, spec)
+        .with_context(|| format!("create {}", path.display()))?;
+    for sample in samples {
+        writer
+            .write_sample((sample.clamp(-1.0, 1.0) * f32::from(i16::MAX)) as i16)
+            .context("write sample")?;
+    }
+    writer.finalize().context("finalize wav")?;
+    Ok(path)
+}
+
+/// Drops clips older than `max_age` and all but the newest [`MAX_CLIPS`].
+fn purge_expired(max_age: Duration) -> Result<usize> {
+    let files = clip_files()?;
+    let excess = files.len().saturating_sub(MAX_CLIPS);
+    let mut removed = 0;
+    for (index, path) in files.iter().enumerate() {
+        let age = fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok());
+        let expired = match age {
+            Some(age) => index < excess || age > max_age,
+            None => true,
+        };
+        if expired {
+            fs::remove_file(path).with_context(|| format!("remove {}", path.display()))?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+fn purge_all() -> Result<usize> {
+    let files = clip_files()?;
+    for path in &files {
+        fs::remove_file(path).with_context(|| format!("remove {}", path.display()))?;
+    }
+    Ok(files.len())
+}
+
+fn clip_dir() -> Result<PathBuf> {
+    let dirs =
+        ProjectDirs::from("com", "OpenFlow", "OpenFlow").context("missing project directories")?;
+    Ok(dirs.cache_dir().join("failed-audio"))
+}
+
+fn clip_files() -> Result<Vec<PathBuf>> {
+    let dir = clip_dir()?;
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Ok(Vec::new());
+    };
+    let mut files: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == CLIP_EXTENSION))
+        .collect();
+    // Names start with a millisecond stamp, so they sort by age.
+    files.sort();
+    Ok(files)
+}
@@ -0,0 +1,32 @@
+//! Whether the focused window is fullscreen, so the overlay can stay out of games and
+//! presentations. X11 exposes `_NET_WM_STATE` directly; on GNOME Wayland the HUD
+//! extension reports changes over the control socket instead.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tracing::debug;
+
+use crate::output::x11;
+
+/// Last state reported by the GNOME HUD extension.
+static REPORTED: AtomicBool = AtomicBool::new(false);
+
+/// Records a fullscreen change reported by the HUD extension. Returns whether it
+/// differs from the previous report.
+pub fn set_reported(fullscreen: bool) -> bool {
+    REPORTED.swap(fullscreen, Ordering::SeqCst) != fullscreen
+}
+
+/// Best effort: false when neither X11 nor the extension can tell.
+pub fn focused_window_fullscreen() -> bool {
+    if REPORTED.load(Ordering::SeqCst) {
+        return true;
+    }
+    match x11::active_window_fullscreen() {
+        Ok(fullscreen) => fullscreen,
+        Err(error) => {
+            debug!("fullscreen state unavailable: {error}");
+            false
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! GPU detection for ASR inference and mapping of the device setting onto the
+//! sherpa provider and CT2 device.
+
+use std::fs;
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+/// Directories searched for the CUDA driver library.
+const CUDA_LIBRARY_DIRS: &[&str] = &[
+    "/usr/lib/x86_64-linux-gnu",
+    "/usr/lib64",
+    "/usr/lib",
+    "/usr/local/cuda/lib64",
+    "/usr/lib/wsl/lib",
+];
+
+const AMD_PCI_VENDOR: &str = "0x1002";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GpuDevice {
+    /// "nvidia" or "amd".
+    pub vendor: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GpuInventory {
+    pub devices: Vec<GpuDevice>,
+    /// The NVIDIA driver and libcuda are present.
+    pub cuda: bool,
+    /// The ROCm kernel driver and runtime are present.
+    pub rocm: bool,
+}
+
+static INVENTORY: Lazy<GpuInventory> = Lazy::new(|| {
+    let inventory = detect();
+    tracing::info!(
+        "gpu_inventory devices={} cuda={} rocm={}",
+        inventory.devices.len(),
+        inventory.cuda,
+        inventory.rocm
+    );
+    inventory
+});
+
+/// GPUs found on this machine; probed once per run.
+pub fn inventory() -> &'static GpuInventory {
+    &INVENTORY
+}
+
+fn detect() -> GpuInventory {
+    let mut devices = nvidia_devices();
+    let cuda = Path::new("/proc/driver/nvidia/version").exists()
+        && CUDA_LIBRARY_DIRS
+            .iter()
+            .any(|dir| Path::new(dir).join("libcuda.so.1").exists());
+    devices.extend(amd_devices());
+    let rocm = Path::new("/dev/kfd").exists() && Path::new("/opt/rocm").is_dir();
+    GpuInventory {
+        devices,
+        cuda,
+        rocm,
+    }
+}
+
+fn nvidia_devices() -> Vec<GpuDevice> {
+    let Ok(entries) = fs::read_dir("/proc/driver/nvidia/gpus") else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| fs::read_to_string(entry.path().join("information")).ok())
+        .map(|information| {
+            let name = information
+                .lines()
+                .find_map(|line| line.strip_prefix("Model:"))
+                .map(|model| model.trim().to_string())
+                .unwrap_or_else(|| "NVIDIA GPU".to_string());
+            GpuDevice {
+                vendor: "nvidia".into(),
+                name,
+            }
+        })
+        .collect()
+}
+
+fn amd_devices() -> Vec<GpuDevice> {
+    let Ok(entries) = fs::read_dir("/sys/class/drm") else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.starts_with("card") && !name.contains('-')
+        })
+        .filter(|entry| {
+            fs::read_to_string(entry.path().join("device/vendor"))
+                .is_ok_and(|vendor| vendor.trim() == AMD_PCI_VENDOR)
+        })
+        .map(|entry| {
+            let name = fs::read_to_string(entry.path().join("device/product_name"))
+                .ok()
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| "AMD GPU".to_string());
+            GpuDevice {
+                vendor: "amd".into(),
+                name,
+            }
+        })
+        .collect()
+}
+
+/// The sherpa provider and CT2 device for the `asrDevice` setting. ROCm builds of
+/// CTranslate2 register as "cuda"; sherpa has no ROCm provider and stays on CPU.
+pub fn resolve(setting: &str, inventory: &GpuInventory) -> (String, String) {
+    let setting = match setting {
+        "auto" if inventory.cuda => "cuda",
+        "auto" if inventory.rocm => "rocm",
+        other => other,
+    };
+    let (provider, ct2_device) = match setting {
+        "cuda" => ("cuda", "cuda"),
+        "rocm" => ("cpu", "cuda"),
+        _ => ("cpu", "cpu"),
+    };
+    (provider.to_string(), ct2_device.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_device_setting_against_inventory() {
+        let none = GpuInventory::default();
+        let nvidia = GpuInventory {
+            cuda: true,
+            ..GpuInventory::default()
+        };
+        let amd = GpuInventory {
+            rocm: true,
+            ..GpuInventory::default()
+        };
+        let pair = |provider: &str, device: &str| (provider.to_string(), device.to_string());
+
+        assert_eq!(resolve("auto", &none), pair("cpu", "cpu"));
+        assert_eq!(resolve("auto", &nvidia), pair("cuda", "cuda"));
+        assert_eq!(resolve("auto", &amd), pair("cpu", "cuda"));
+        assert_eq!(resolve("cuda", &none), pair("cuda", "cuda"));
+        assert_eq!(resolve("cpu", &nvidia), pair("cpu", "cpu"));
+    }
+}
@@ -0,0 +1,349 @@
+//! Local dictation history: every delivered transcript is appended to a JSON-lines file
+//! in the data dir, so text survives a failed or misdirected paste.
+//!
+//! Entries hold the storage-redacted text. Strict privacy turns history off and purges it.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use directories::ProjectDirs;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tracing::warn;
+
+const HISTORY_FILE: &str = "history.jsonl";
+const MAX_ENTRIES: usize = 2_000;
+/// Appends run this far past the cap before the file is rewritten without the oldest.
+const COMPACT_SLACK: usize = 200;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEntry {
+    pub id: u64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub recorded_at: OffsetDateTime,
+    pub text: String,
+    /// X11 window class of the focused window at delivery, when known.
+    pub app: Option<String>,
+    pub word_count: usize,
+    /// "pasted", "paste-failed", "paste-unconfirmed" or "emitted".
+    pub outcome: String,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExportFormat {
+    /// `## YYYY-MM-DD` headings with `- HH:MM — text` bullets, times in UTC.
+    Markdown,
+    /// One serialized entry per line, the same shape as the history file.
+    Jsonl,
+}
+
+/// Inclusive bounds on `recorded_at`; either side may be open.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportRange {
+    pub from_unix: Option<i64>,
+    pub to_unix: Option<i64>,
+}
+
+impl ExportRange {
+    fn contains(&self, entry: &HistoryEntry) -> bool {
+        let at = entry.recorded_at.unix_timestamp();
+        self.from_unix.into_iter().all(|from| at >= from)
+            && self.to_unix.into_iter().all(|to| at <= to)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSummary {
+    pub written: usize,
+}
+
+pub struct HistoryStore {
+    path: PathBuf,
+    entries: RwLock<Vec<HistoryEntry>>,
+}
+
+impl HistoryStore {
+    pub fn new() -> Self {
+        let path = resolve_history_path().unwrap_or_else(|_| PathBuf::from(HISTORY_FILE));
+        let entries = load_entries(&path).unwrap_or_else(|error| {
+            warn!("history_load_failed error={error:#}");
+            Vec::new()
+        });
+        Self {
+            path,
+            entries: RwLock::new(entries),
+        }
+    }
+
+    pub fn record(&self, text: &str, app: Option<String>, outcome: &str) -> Result<HistoryEntry> {
+        let mut entries = self.entries.write();
+        let entry = HistoryEntry {
+            id: entries.last().map_or(1, |last| last.id + 1),
+            recorded_at: OffsetDateTime::now_utc(),
+            text: text.to_string(),
+            app,
+            word_count: text.split_whitespace().count(),
+            outcome: outcome.to_string(),
+        };
+        entries.push(entry.clone());
+        if entries.len() > MAX_ENTRIES + COMPACT_SLACK {
+            let excess = entries.len() - MAX_ENTRIES;
+            entries.drain(..excess);
+            rewrite(&self.path, &entries)?;
+        } else {
+            append(&self.path, &entry)?;
+        }
+        Ok(entry)
+    }
+
+    /// Newest first, skipping `offset` entries.
+    pub fn list(&self, offset: usize, limit: usize) -> Vec<HistoryEntry> {
+        self.entries
+            .read()
+            .iter()
+            .rev()
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Newest first; every whitespace-separated term must appear in the text or app,
+    /// ignoring case.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<HistoryEntry> {
+        let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        self.entries
+            .read()
+            .iter()
+            .rev()
+            .filter(|entry| {
+                let haystack = format!(
+                    "{} {}",
+                    entry.text.to_lowercase(),
+                    entry.app.as_deref().unwrap_or_default().to_lowercase()
+                );
+                terms.iter().all(|term| haystack.contains(term.as_str()))
+            })
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    pub fn get(&self, id: u64) -> Option<HistoryEntry> {
+        self.entries
+            .read()
+            .iter()
+            .find(|entry| entry.id == id)
+            .cloned()
+    }
+
+    /// Writes the entries recorded within `range`, oldest first, to `path`. The export
+    /// goes to a temporary file first so a failure never leaves a partial one behind.
+    pub fn export(
+        &self,
+        format: ExportFormat,
+        range: ExportRange,
+        path: &Path,
+    ) -> Result<ExportSummary> {
+        let entries: Vec<HistoryEntry> = self
+            .entries
+            .read()
+            .iter()
+            .filter(|entry| range.contains(entry))
+            .cloned()
+            .collect();
+        let body = match format {
+            ExportFormat::Markdown => render_markdown(&entries),
+            ExportFormat::Jsonl => render_jsonl(&entries)?,
+        };
+        let mut temp_path = path.as_os_str().to_owned();
+        temp_path.push(".tmp");
+        let temp_path = PathBuf::from(temp_path);
+        fs::write(&temp_path, body).with_context(|| format!("write {}", temp_path.display()))?;
+        fs::rename(&temp_path, path).with_context(|| format!("replace {}", path.display()))?;
+        Ok(ExportSummary {
+            written: entries.len(),
+        })
+    }
+
+    /// Deletes every entry, including the file.
+    pub fn clear(&self) -> Result<()> {
+        let mut entries = self.entries.write();
+        entries.clear();
+        match fs::remove_file(&self.path) {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(error).with_context(|| format!("remove {}", self.path.display())),
+        }
+    }
+}
+
+impl Default for HistoryStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn resolve_history_path() -> Result<PathBuf> {
+    let dirs =
+        ProjectDirs::from("com", "OpenFlow", "OpenFlow").context("missing project directories")?;
+    Ok(dirs.data_dir().join(HISTORY_FILE))
+}
+
+/// Lines that don't parse (e.g. cut off by a crash mid-write) are skipped.
+fn load_entries(path: &Path) -> Result<Vec<HistoryEntry>> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error).with_context(|| format!("read {}", path.display())),
+    };
+    Ok(parse_lines(&text))
+}
+
+fn parse_lines(text: &str) -> Vec<HistoryEntry> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+fn append(path: &Path, entry: &HistoryEntry) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).with_context(|| format!("create {}", parent.display()))?;
+    }
+    let mut line = serde_json::to_string(entry).context("serialize history entry")?;
+    line.push('\n');
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+        .with_context(|| format!("append to {}", path.display()))
+}
+
+fn rewrite(path: &Path, entries: &[HistoryEntry]) -> Result<()> {
+    let body = render_jsonl(entries)?;
+    fs::write(path, body).with_context(|| format!("write {}", path.display()))
+}
+
+fn render_jsonl(entries: &[HistoryEntry]) -> Result<String> {
+    let mut body = String::new();
+    for entry in entries {
+        body.push_str(&serde_json::to_string(entry).context("serialize history entry")?);
+        body.push('\n');
+    }
+    Ok(body)
+}
+
+fn render_markdown(entries: &[HistoryEntry]) -> String {
+    let mut body = String::from("# Dictation history\n");
+    let mut current_day = None;
+    for entry in entries {
+        let day = entry.recorded_at.date();
+        if current_day != Some(day) {
+            body.push_str(&format!("\n## {day}\n\n"));
+            current_day = Some(day);
+        }
+        let time = entry.recorded_at.time();
+        // Keep multi-line dictations inside their bullet.
+        let text = entry.text.trim().replace('\n', "\n  ");
+        body.push_str(&format!(
+            "- {:02}:{:02} — {text}\n",
+            time.hour(),
+            time.minute()
+        ));
+    }
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_torn_lines_and_searches_all_terms() {
+        let entry = |id: u64, text: &str, app: Option<&str>| HistoryEntry {
+            id,
+            recorded_at: OffsetDateTime::UNIX_EPOCH,
+            text: text.into(),
+            app: app.map(str::to_string),
+            word_count: text.split_whitespace().count(),
+            outcome: "pasted".into(),
+        };
+        let mut file = String::new();
+        for entry in [
+            entry(1, "Send the report to Anna", Some("thunderbird")),
+            entry(2, "Meeting moved to Friday", Some("slack")),
+        ] {
+            file.push_str(&serde_json::to_string(&entry).unwrap());
+            file.push('\n');
+        }
+        file.push_str("{\"id\":3,\"recordedAt\":\"2026-");
+
+        let store = HistoryStore {
+            path: PathBuf::from("/nonexistent/history.jsonl"),
+            entries: RwLock::new(parse_lines(&file)),
+        };
+        assert_eq!(store.list(0, 10).len(), 2);
+        assert_eq!(store.list(0, 10)[0].id, 2);
+        let found = store.search("REPORT thunderbird", 10);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, 1);
+        assert!(store.search("report slack", 10).is_empty());
+    }
+
+    #[test]
+    fn exports_entries_in_range_as_markdown_and_jsonl() {
+        let entry = |id: u64, unix: i64, text: &str| HistoryEntry {
+            id,
+            recorded_at: OffsetDateTime::from_unix_timestamp(unix).unwrap(),
+            text: text.into(),
+            app: None,
+            word_count: text.split_whitespace().count(),
+            outcome: "pasted".into(),
+        };
+        let store = HistoryStore {
+            path: PathBuf::from("/nonexistent/history.jsonl"),
+            entries: RwLock::new(vec![
+                entry(1, 1_767_225_600, "Too early"),
+                // 2026-01-02 09:05 and 2026-01-03 18:30 UTC.
+                entry(2, 1_767_344_700, "First line\nsecond line"),
+                entry(3, 1_767_465_000, "Next day"),
+            ]),
+        };
+        let range = ExportRange {
+            from_unix: Some(1_767_300_000),
+            to_unix: None,
+        };
+        let dir = std::env::temp_dir().join(format!("openflow-export-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let markdown = dir.join("history.md");
+        let summary = store
+            .export(ExportFormat::Markdown, range, &markdown)
+            .unwrap();
+        assert_eq!(summary.written, 2);
+        assert_eq!(
+            fs::read_to_string(&markdown).unwrap(),
+            "# Dictation history\n\n## 2026-01-02\n\n- 09:05 — First line\n  second line\n\n\
+             ## 2026-01-03\n\n- 18:30 — Next day\n"
+        );
+
+        let jsonl = dir.join("history.jsonl");
+        store.export(ExportFormat::Jsonl, range, &jsonl).unwrap();
+        let exported = parse_lines(&fs::read_to_string(&jsonl).unwrap());
+        assert_eq!(
+            exported.iter().map(|entry| entry.id).collect::<Vec<_>>(),
+            [2, 3]
+        );
+        assert!(!dir.join("history.jsonl.tmp").exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
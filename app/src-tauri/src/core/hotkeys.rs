@@ -1,7 +1,9 @@
-use parking_lot::RwLock;
+use std::time::Instant;
+
+use parking_lot::{Mutex, RwLock};
 use tauri::Manager;
 use tauri::{AppHandle, Emitter};
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
 use crate::core::app_state::AppState;
 use crate::core::events;
@@ -13,8 +15,110 @@ enum HotkeyState {
     Released,
 }
 
+/// What a registered chord does. The dictation hotkey is always present; the others
+/// are optional and skipped if their chord fails to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotkeyAction {
+    Dictation,
+    RecopyLastTranscript,
+    CycleLanguage,
+    /// Held while speaking a voice command.
+    VoiceCommand,
+    /// Turns continuous dictation on or off.
+    ContinuousDictation,
+}
+
 /// Tracks the currently registered hotkey so we can unregister it when changing.
 static CURRENT_HOTKEY: RwLock<Option<String>> = RwLock::new(None);
+static CURRENT_BACKEND: RwLock<Option<&'static str>> = RwLock::new(None);
+/// Extra (non-dictation) bindings registered alongside the dictation hotkey.
+static CURRENT_EXTRA_BINDINGS: RwLock<Vec<(HotkeyAction, String)>> = RwLock::new(Vec::new());
+/// When the hybrid-mode press that started the current session went down; cleared on
+/// release, or when the press stops a latched session instead.
+static HYBRID_PRESSED_AT: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// A modifier + key chord as written in settings, e.g. `Ctrl+Shift+V` or `Shift+Insert`.
+///
+/// Shared by hotkey registration and paste chord injection so both accept the same syntax.
+/// Key names compare case-insensitively, so `Ctrl+v` equals `Ctrl+V`.
+#[derive(Debug, Clone, Eq)]
+pub struct KeyChord {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+    pub key: String,
+}
+
+impl KeyChord {
+    pub fn parse(input: &str) -> anyhow::Result<Self> {
+        let parts: Vec<&str> = input
+            .split('+')
+            .map(|p| p.trim())
+            .filter(|p| !p.is_empty())
+            .collect();
+
+        let Some((key, mods)) = parts.split_last() else {
+            anyhow::bail!("hotkey is empty");
+        };
+
+        let mut chord = KeyChord {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            meta: false,
+            key: key.to_string(),
+        };
+
+        for m in mods {
+            match m.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => chord.ctrl = true,
+                "alt" => chord.alt = true,
+                "shift" => chord.shift = true,
+                "meta" | "super" | "command" | "logo" => chord.meta = true,
+                _ => {}
+            }
+        }
+
+        Ok(chord)
+    }
+
+    pub fn evdev_key(&self) -> anyhow::Result<evdev::Key> {
+        linux_evdev::parse_key(&self.key)
+    }
+
+    pub fn x11_keycode<C: x11rb::connection::Connection>(&self, conn: &C) -> anyhow::Result<u8> {
+        linux_x11::keycode_for_key_string(conn, &self.key)
+    }
+}
+
+impl PartialEq for KeyChord {
+    fn eq(&self, other: &Self) -> bool {
+        (self.ctrl, self.alt, self.shift, self.meta)
+            == (other.ctrl, other.alt, other.shift, other.meta)
+            && self.key.trim().eq_ignore_ascii_case(other.key.trim())
+    }
+}
+
+impl std::fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (held, name) in [
+            (self.ctrl, "Ctrl"),
+            (self.alt, "Alt"),
+            (self.shift, "Shift"),
+            (self.meta, "Meta"),
+        ] {
+            if held {
+                write!(f, "{name}+")?;
+            }
+        }
+        if self.key.len() == 1 {
+            write!(f, "{}", self.key.to_ascii_uppercase())
+        } else {
+            write!(f, "{}", self.key)
+        }
+    }
+}
 
 fn is_wayland_session() -> bool {
     let xdg_session_type = std::env::var("XDG_SESSION_TYPE").unwrap_or_default();
@@ -44,6 +148,10 @@ pub async fn register(app: &AppHandle) -> tauri::Result<()> {
 pub async fn register_shortcut(app: &AppHandle, shortcut: &str) -> tauri::Result<()> {
     unregister_current(app).await?;
 
+    let extra = get_extra_bindings(app);
+    let mut bindings = vec![(HotkeyAction::Dictation, shortcut.to_string())];
+    bindings.extend(extra.iter().cloned());
+
     let session_type = std::env::var("XDG_SESSION_TYPE").unwrap_or_else(|_| "unknown".into());
     info!(
         "Registering hotkey: {} (session_type={}, display={})",
@@ -53,25 +161,47 @@ pub async fn register_shortcut(app: &AppHandle, shortcut: &str) -> tauri::Result
     );
 
     // Preferred backend selection:
-    // - Wayland: evdev (global hotkeys via /dev/input)
+    // - Mouse buttons and pedals: evdev, the only backend that sees them
+    // - Wayland: the GlobalShortcuts portal, else evdev (global hotkeys via /dev/input)
     // - X11: X11 grabs (no /dev/input needed; works in VNC/Xvfb)
-    if !is_wayland_session() && has_x11_display() {
-        match register_x11_shortcut(app, shortcut) {
+    if bindings
+        .iter()
+        .any(|(_, binding)| linux_evdev::is_button_binding(binding))
+    {
+        register_evdev_shortcut(app, &bindings)?;
+        set_current_hotkey(shortcut, &extra, "evdev");
+        let _ = app.emit("hotkey-backend", "evdev");
+    } else if !is_wayland_session() && has_x11_display() {
+        match register_x11_shortcut(app, &bindings) {
             Ok(()) => {
-                set_current_hotkey(shortcut);
+                set_current_hotkey(shortcut, &extra, "x11");
                 let _ = app.emit("hotkey-backend", "x11");
             }
             Err(error) => {
                 warn!("x11 hotkey registration failed: {error}");
-                register_evdev_shortcut(app, shortcut)?;
-                set_current_hotkey(shortcut);
+                register_evdev_shortcut(app, &bindings)?;
+                set_current_hotkey(shortcut, &extra, "evdev");
                 let _ = app.emit("hotkey-backend", "evdev");
             }
         }
     } else {
-        register_evdev_shortcut(app, shortcut)?;
-        set_current_hotkey(shortcut);
-        let _ = app.emit("hotkey-backend", "evdev");
+        let portal = if is_wayland_session() {
+            linux_portal::start(app, &bindings).await
+        } else {
+            Err(anyhow::anyhow!("not a Wayland session"))
+        };
+        match portal {
+            Ok(()) => {
+                set_current_hotkey(shortcut, &extra, "portal");
+                let _ = app.emit("hotkey-backend", "portal");
+            }
+            Err(error) => {
+                info!("global shortcuts portal unavailable, using evdev: {error}");
+                register_evdev_shortcut(app, &bindings)?;
+                set_current_hotkey(shortcut, &extra, "evdev");
+                let _ = app.emit("hotkey-backend", "evdev");
+            }
+        }
     }
     if let Some(state) = app.try_state::<AppState>() {
         state.set_hud_state(app, "idle");
@@ -82,6 +212,57 @@ pub async fn register_shortcut(app: &AppHandle, shortcut: &str) -> tauri::Result
     Ok(())
 }
 
+fn dispatch_hotkey(app: &AppHandle, action: HotkeyAction, state: HotkeyState) {
+    match action {
+        HotkeyAction::Dictation => handle_hotkey_state(app, state),
+        HotkeyAction::RecopyLastTranscript => {
+            if matches!(state, HotkeyState::Pressed) {
+                let app_handle = app.clone();
+                tauri::async_runtime::spawn_blocking(move || {
+                    let state = app_handle.state::<AppState>();
+                    state.recopy_last_transcript(&app_handle);
+                });
+            }
+        }
+        HotkeyAction::CycleLanguage => {
+            if matches!(state, HotkeyState::Pressed) {
+                let app_handle = app.clone();
+                tauri::async_runtime::spawn_blocking(move || {
+                    let state = app_handle.state::<AppState>();
+                    if let Err(error) = state.cycle_language(&app_handle) {
+                        warn!("language cycle failed: {error:?}");
+                    }
+                });
+            }
+        }
+        HotkeyAction::VoiceCommand => {
+            let state_handle = app.state::<AppState>();
+            match state {
+                HotkeyState::Pressed => {
+                    state_handle.set_hotkey_down(app, true);
+                    state_handle.start_command_session(app);
+                }
+                HotkeyState::Released => {
+                    state_handle.set_hotkey_down(app, false);
+                    if state_handle.is_listening() {
+                        state_handle.mark_processing(app);
+                        state_handle.complete_session(app);
+                    }
+                }
+            }
+        }
+        HotkeyAction::ContinuousDictation => {
+            if matches!(state, HotkeyState::Pressed) {
+                let app_handle = app.clone();
+                tauri::async_runtime::spawn_blocking(move || {
+                    let state = app_handle.state::<AppState>();
+                    state.toggle_continuous(&app_handle);
+                });
+            }
+        }
+    }
+}
+
 fn handle_hotkey_state(app: &AppHandle, state: HotkeyState) {
     let app_handle = app.clone();
     let state_handle = app_handle.state::<AppState>();
@@ -95,6 +276,19 @@ fn handle_hotkey_state(app: &AppHandle, state: HotkeyState) {
         },
     );
 
+    // With confirm-before-paste, the dictation hotkey pastes a held transcript
+    // instead of starting a new session.
+    if matches!(state, HotkeyState::Pressed) && state_handle.has_pending_transcript() {
+        let app_for_confirm = app_handle.clone();
+        tauri::async_runtime::spawn_blocking(move || {
+            let state = app_for_confirm.state::<AppState>();
+            if let Err(error) = state.confirm_pending_transcript(&app_for_confirm, None) {
+                warn!("confirm_pending_transcript failed: {error:?}");
+            }
+        });
+        return;
+    }
+
     match mode.as_str() {
         "toggle" => {
             if matches!(state, HotkeyState::Pressed) {
@@ -108,6 +302,39 @@ fn handle_hotkey_state(app: &AppHandle, state: HotkeyState) {
                 state_handle.set_hotkey_down(&app_handle, false);
             }
         }
+        "hybrid" => match state {
+            HotkeyState::Pressed => {
+                let mut pressed_at = HYBRID_PRESSED_AT.lock();
+                if pressed_at.is_some() {
+                    // Key repeat while held.
+                    return;
+                }
+                state_handle.set_hotkey_down(&app_handle, true);
+                if state_handle.is_listening() {
+                    // A session latched by an earlier tap; this press stops it.
+                    state_handle.mark_processing(&app_handle);
+                    state_handle.complete_session(&app_handle);
+                } else {
+                    *pressed_at = Some(Instant::now());
+                    state_handle.start_session(&app_handle);
+                }
+            }
+            HotkeyState::Released => {
+                state_handle.set_hotkey_down(&app_handle, false);
+                let Some(pressed_at) = HYBRID_PRESSED_AT.lock().take() else {
+                    return;
+                };
+                let held = pressed_at.elapsed();
+                if held < state_handle.hybrid_hold_threshold() {
+                    debug!("hybrid_hotkey_latched held_ms={}", held.as_millis());
+                    return;
+                }
+                if state_handle.is_listening() {
+                    state_handle.mark_processing(&app_handle);
+                }
+                state_handle.complete_session(&app_handle);
+            }
+        },
         _ => match state {
             HotkeyState::Pressed => {
                 state_handle.set_hotkey_down(&app_handle, true);
@@ -126,8 +353,15 @@ fn handle_hotkey_state(app: &AppHandle, state: HotkeyState) {
 
 /// Unregister the currently registered hotkey (if any).
 async fn unregister_current(_app: &AppHandle) -> tauri::Result<()> {
+    stop_listeners();
+    Ok(())
+}
+
+/// Stops the evdev/X11 listener threads, joining them, and forgets the registration.
+pub fn stop_listeners() {
     let current = { CURRENT_HOTKEY.read().clone() };
     if current.is_some() {
+        stop_portal_listener();
         stop_evdev_listener();
         stop_x11_listener();
     }
@@ -136,13 +370,29 @@ async fn unregister_current(_app: &AppHandle) -> tauri::Result<()> {
         let mut guard = CURRENT_HOTKEY.write();
         *guard = None;
     }
-
-    Ok(())
+    {
+        let mut guard = CURRENT_BACKEND.write();
+        *guard = None;
+    }
+    CURRENT_EXTRA_BINDINGS.write().clear();
+    // A release the listener never delivered must not swallow the next press.
+    *HYBRID_PRESSED_AT.lock() = None;
 }
 
-fn set_current_hotkey(shortcut: &str) {
+fn set_current_hotkey(shortcut: &str, extra: &[(HotkeyAction, String)], backend: &'static str) {
     let mut current = CURRENT_HOTKEY.write();
     *current = Some(shortcut.to_string());
+    *CURRENT_EXTRA_BINDINGS.write() = extra.to_vec();
+    let mut active = CURRENT_BACKEND.write();
+    *active = Some(backend);
+}
+
+/// Returns the registered hotkey and the backend ("x11", "portal" or "evdev") listening
+/// for it.
+pub fn active_registration() -> Option<(String, &'static str)> {
+    let hotkey = CURRENT_HOTKEY.read().clone()?;
+    let backend = (*CURRENT_BACKEND.read())?;
+    Some((hotkey, backend))
 }
 
 /// Get the current hotkey from settings based on the active mode.
@@ -154,6 +404,32 @@ fn get_current_hotkey(app: &AppHandle) -> String {
     }
 }
 
+/// Optional non-dictation bindings from settings; empty chords are skipped.
+fn get_extra_bindings(app: &AppHandle) -> Vec<(HotkeyAction, String)> {
+    let Some(state) = app.try_state::<AppState>() else {
+        return Vec::new();
+    };
+    let Ok(settings) = state.settings_manager().read_frontend() else {
+        return Vec::new();
+    };
+
+    [
+        (
+            HotkeyAction::RecopyLastTranscript,
+            settings.recopy_transcript_hotkey,
+        ),
+        (HotkeyAction::CycleLanguage, settings.language_cycle_hotkey),
+        (HotkeyAction::VoiceCommand, settings.command_hotkey),
+        (
+            HotkeyAction::ContinuousDictation,
+            settings.continuous_hotkey,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, chord)| !chord.trim().is_empty())
+    .collect()
+}
+
 /// Unregister all hotkeys.
 pub async fn unregister(app: &AppHandle) -> tauri::Result<()> {
     let current = { CURRENT_HOTKEY.read().clone() };
@@ -170,8 +446,9 @@ pub async fn unregister(app: &AppHandle) -> tauri::Result<()> {
 pub async fn reregister(app: &AppHandle) -> tauri::Result<()> {
     let new_shortcut = get_current_hotkey(app);
     let current = { CURRENT_HOTKEY.read().clone() };
+    let extra_changed = *CURRENT_EXTRA_BINDINGS.read() != get_extra_bindings(app);
 
-    if current.as_deref() != Some(new_shortcut.as_str()) {
+    if current.as_deref() != Some(new_shortcut.as_str()) || extra_changed {
         info!(
             "Hotkey changed from {:?} to {}, re-registering",
             current, new_shortcut
@@ -187,7 +464,7 @@ pub async fn reregister(app: &AppHandle) -> tauri::Result<()> {
 // -------------------------------------------------------------------------------------------------
 
 mod linux_evdev {
-    use super::{handle_hotkey_state, HotkeyState};
+    use super::{dispatch_hotkey, HotkeyAction, HotkeyState, KeyChord};
     use crate::output::uinput::VIRTUAL_KEYBOARD_NAME;
     use evdev::{Device, InputEventKind, Key};
     use inotify::{Inotify, WatchMask};
@@ -215,6 +492,7 @@ mod linux_evdev {
     struct HotkeySpec {
         key: Key,
         modifiers: Modifiers,
+        action: HotkeyAction,
     }
 
     pub(super) struct EvdevListener {
@@ -225,16 +503,28 @@ mod linux_evdev {
     static EVDEV_LISTENER: parking_lot::RwLock<Option<EvdevListener>> =
         parking_lot::RwLock::new(None);
 
-    pub(super) fn start(app: &AppHandle, shortcut: &str) -> anyhow::Result<()> {
+    pub(super) fn start(
+        app: &AppHandle,
+        bindings: &[(HotkeyAction, String)],
+    ) -> anyhow::Result<()> {
         stop();
-        let spec = parse_hotkey(shortcut)?;
+        let mut specs = Vec::with_capacity(bindings.len());
+        for (action, shortcut) in bindings {
+            match parse_hotkey(shortcut, *action) {
+                Ok(spec) => specs.push(spec),
+                Err(error) if *action != HotkeyAction::Dictation => {
+                    warn!("skipping {action:?} hotkey {shortcut:?}: {error}");
+                }
+                Err(error) => return Err(error),
+            }
+        }
         let app_handle = app.clone();
 
         let (stop_tx, stop_rx) = channel();
         let thread = thread::Builder::new()
             .name("evdev-hotkeys".to_string())
             .spawn(move || {
-                if let Err(error) = run_loop(app_handle, spec, stop_rx) {
+                if let Err(error) = run_loop(app_handle, specs, stop_rx) {
                     warn!("evdev hotkey listener stopped: {error:?}");
                 }
             })?;
@@ -255,45 +545,62 @@ mod linux_evdev {
         stop();
     }
 
-    fn parse_hotkey(input: &str) -> anyhow::Result<HotkeySpec> {
-        let parts: Vec<&str> = input
-            .split('+')
-            .map(|p| p.trim())
-            .filter(|p| !p.is_empty())
-            .collect();
-
-        if parts.is_empty() {
-            anyhow::bail!("hotkey is empty");
-        }
-
-        let (mods, key_str) = if parts.len() == 1 {
-            (Vec::new(), parts[0])
-        } else {
-            (parts[..parts.len() - 1].to_vec(), parts[parts.len() - 1])
-        };
+    fn parse_hotkey(input: &str, action: HotkeyAction) -> anyhow::Result<HotkeySpec> {
+        let chord = KeyChord::parse(input)?;
+        let key = parse_key(&chord.key)?;
+        Ok(HotkeySpec {
+            key,
+            modifiers: Modifiers {
+                ctrl: chord.ctrl,
+                alt: chord.alt,
+                shift: chord.shift,
+                meta: chord.meta,
+            },
+            action,
+        })
+    }
 
-        let mut modifiers = Modifiers {
-            ctrl: false,
-            alt: false,
-            shift: false,
-            meta: false,
-        };
+    /// Whether `binding` names a mouse or pedal button rather than a key.
+    pub(super) fn is_button_binding(binding: &str) -> bool {
+        KeyChord::parse(binding)
+            .ok()
+            .and_then(|chord| parse_button(&chord.key.trim().to_ascii_uppercase()).ok())
+            .is_some_and(|button| button.is_some())
+    }
 
-        for m in mods {
-            match m {
-                "Ctrl" | "Control" => modifiers.ctrl = true,
-                "Alt" => modifiers.alt = true,
-                "Shift" => modifiers.shift = true,
-                "Meta" | "Super" | "Command" | "Logo" => modifiers.meta = true,
-                _ => {}
+    /// BTN_* codes by evdev name, plus Mouse3-5 aliases. Foot switches that enumerate
+    /// as joysticks report the BTN_TRIGGER family or BTN_0 and up. Left and right
+    /// click are refused: the listener can't swallow them, so every click would fire.
+    fn parse_button(upper: &str) -> anyhow::Result<Option<Key>> {
+        let name = upper.replace([' ', '_'], "");
+        let button = match name.as_str() {
+            "BTNLEFT" | "BTNRIGHT" | "MOUSE1" | "MOUSE2" => {
+                anyhow::bail!("{upper} is a primary mouse button and can't be a hotkey")
             }
-        }
-
-        let key = parse_key(key_str)?;
-        Ok(HotkeySpec { key, modifiers })
+            "BTNMIDDLE" | "MOUSE3" | "MOUSEMIDDLE" => Key::BTN_MIDDLE,
+            "BTNSIDE" | "MOUSE4" | "MOUSEBACK" => Key::BTN_SIDE,
+            "BTNEXTRA" | "MOUSE5" | "MOUSEFORWARD" => Key::BTN_EXTRA,
+            "BTNFORWARD" => Key::BTN_FORWARD,
+            "BTNBACK" => Key::BTN_BACK,
+            "BTNTASK" => Key::BTN_TASK,
+            "BTNTRIGGER" => Key::BTN_TRIGGER,
+            "BTNTHUMB" => Key::BTN_THUMB,
+            "BTNTHUMB2" => Key::BTN_THUMB2,
+            "BTNTOP" => Key::BTN_TOP,
+            "BTNTOP2" => Key::BTN_TOP2,
+            "BTNPINKIE" => Key::BTN_PINKIE,
+            other => match other
+                .strip_prefix("BTN")
+                .and_then(|n| n.parse::<u16>().ok())
+            {
+                Some(n) if n <= 9 => Key::new(Key::BTN_0.code() + n),
+                _ => return Ok(None),
+            },
+        };
+        Ok(Some(button))
     }
 
-    fn parse_key(key: &str) -> anyhow::Result<Key> {
+    pub(super) fn parse_key(key: &str) -> anyhow::Result<Key> {
         let trimmed = key.trim();
         if trimmed.is_empty() {
             anyhow::bail!("missing hotkey key");
@@ -301,6 +608,9 @@ mod linux_evdev {
 
         let upper = trimmed.to_ascii_uppercase();
         let upper = upper.replace(' ', "");
+        if let Some(button) = parse_button(&upper)? {
+            return Ok(button);
+        }
 
         let mapped = match upper.as_str() {
             "SPACE" => Key::KEY_SPACE,
@@ -333,6 +643,19 @@ mod linux_evdev {
             "PAGEDOWN" => Key::KEY_PAGEDOWN,
             "DELETE" => Key::KEY_DELETE,
 
+            // Punctuation keys by name, as used when typing text out.
+            "MINUS" => Key::KEY_MINUS,
+            "EQUAL" => Key::KEY_EQUAL,
+            "LEFTBRACE" => Key::KEY_LEFTBRACE,
+            "RIGHTBRACE" => Key::KEY_RIGHTBRACE,
+            "SEMICOLON" => Key::KEY_SEMICOLON,
+            "APOSTROPHE" => Key::KEY_APOSTROPHE,
+            "GRAVE" => Key::KEY_GRAVE,
+            "BACKSLASH" => Key::KEY_BACKSLASH,
+            "COMMA" => Key::KEY_COMMA,
+            "DOT" => Key::KEY_DOT,
+            "SLASH" => Key::KEY_SLASH,
+
             _ => {
                 // Function keys
                 if let Some(num) = upper.strip_prefix('F') {
@@ -420,23 +743,35 @@ mod linux_evdev {
         Ok(mapped)
     }
 
-    fn run_loop(app: AppHandle, spec: HotkeySpec, stop_rx: Receiver<()>) -> anyhow::Result<()> {
-        let mut manager = DeviceManager::new()?;
-        info!(
-            "evdev hotkeys active: key={:?} ctrl={} alt={} shift={} meta={} devices={}",
-            spec.key,
-            spec.modifiers.ctrl,
-            spec.modifiers.alt,
-            spec.modifiers.shift,
-            spec.modifiers.meta,
-            manager.devices.len()
-        );
+    fn run_loop(
+        app: AppHandle,
+        specs: Vec<HotkeySpec>,
+        stop_rx: Receiver<()>,
+    ) -> anyhow::Result<()> {
+        let buttons: Vec<Key> = specs
+            .iter()
+            .map(|spec| spec.key)
+            .filter(|key| !is_keyboard_key(*key))
+            .collect();
+        let mut manager = DeviceManager::new(buttons)?;
+        for spec in &specs {
+            info!(
+                "evdev hotkeys active: action={:?} key={:?} ctrl={} alt={} shift={} meta={} devices={}",
+                spec.action,
+                spec.key,
+                spec.modifiers.ctrl,
+                spec.modifiers.alt,
+                spec.modifiers.shift,
+                spec.modifiers.meta,
+                manager.devices.len()
+            );
+        }
 
         let mut held_ctrl: HashSet<Key> = HashSet::new();
         let mut held_alt: HashSet<Key> = HashSet::new();
         let mut held_shift: HashSet<Key> = HashSet::new();
         let mut held_meta: HashSet<Key> = HashSet::new();
-        let mut is_pressed = false;
+        let mut pressed = vec![false; specs.len()];
         let mut last_validation = Instant::now();
         let mut warned_no_devices = false;
 
@@ -454,7 +789,7 @@ mod linux_evdev {
                 held_alt.clear();
                 held_shift.clear();
                 held_meta.clear();
-                is_pressed = false;
+                pressed.fill(false);
                 manager.handle_device_changes();
             }
 
@@ -485,33 +820,35 @@ mod linux_evdev {
                     &mut held_meta,
                 );
 
-                if key != spec.key {
-                    continue;
-                }
-
-                if !modifiers_satisfied(
-                    spec.modifiers,
-                    &held_ctrl,
-                    &held_alt,
-                    &held_shift,
-                    &held_meta,
-                ) {
-                    continue;
-                }
-
-                match value {
-                    1 if !is_pressed => {
-                        is_pressed = true;
-                        handle_hotkey_state(&app, HotkeyState::Pressed);
+                for (spec, is_pressed) in specs.iter().zip(pressed.iter_mut()) {
+                    if key != spec.key {
+                        continue;
                     }
-                    0 if is_pressed => {
-                        is_pressed = false;
-                        handle_hotkey_state(&app, HotkeyState::Released);
+
+                    if !modifiers_satisfied(
+                        spec.modifiers,
+                        &held_ctrl,
+                        &held_alt,
+                        &held_shift,
+                        &held_meta,
+                    ) {
+                        continue;
                     }
-                    2 => {
-                        // repeat - ignore
+
+                    match value {
+                        1 if !*is_pressed => {
+                            *is_pressed = true;
+                            dispatch_hotkey(&app, spec.action, HotkeyState::Pressed);
+                        }
+                        0 if *is_pressed => {
+                            *is_pressed = false;
+                            dispatch_hotkey(&app, spec.action, HotkeyState::Released);
+                        }
+                        2 => {
+                            // repeat - ignore
+                        }
+                        _ => {}
                     }
-                    _ => {}
                 }
             }
 
@@ -587,12 +924,14 @@ mod linux_evdev {
 
     struct DeviceManager {
         devices: HashMap<PathBuf, Device>,
+        /// Bound buttons; devices reporting any of them are read even if not keyboards.
+        buttons: Vec<Key>,
         inotify: Inotify,
         inotify_buffer: [u8; 1024],
     }
 
     impl DeviceManager {
-        fn new() -> anyhow::Result<Self> {
+        fn new(buttons: Vec<Key>) -> anyhow::Result<Self> {
             let inotify = Inotify::init().map_err(|err| anyhow::anyhow!(err))?;
             inotify
                 .watches()
@@ -604,6 +943,7 @@ mod linux_evdev {
 
             let mut manager = Self {
                 devices: HashMap::new(),
+                buttons,
                 inotify,
                 inotify_buffer: [0u8; 1024],
             };
@@ -630,7 +970,7 @@ mod linux_evdev {
 
                 match Device::open(&path) {
                     Ok(device) => {
-                        if is_keyboard(&device) {
+                        if is_keyboard(&device) || has_any_button(&device, &self.buttons) {
                             let device_name = device.name().unwrap_or("unknown");
                             if device_name == VIRTUAL_KEYBOARD_NAME {
                                 continue;
@@ -741,6 +1081,18 @@ mod linux_evdev {
             .unwrap_or(false)
     }
 
+    fn has_any_button(device: &Device, buttons: &[Key]) -> bool {
+        !buttons.is_empty()
+            && device
+                .supported_keys()
+                .is_some_and(|keys| buttons.iter().any(|button| keys.contains(*button)))
+    }
+
+    /// Codes below the BTN_* block, which starts at BTN_0, are ordinary keyboard keys.
+    fn is_keyboard_key(key: Key) -> bool {
+        key.code() < Key::BTN_0.code()
+    }
+
     fn set_nonblocking(device: &Device) {
         let fd = device.as_raw_fd();
         set_fd_nonblocking(fd);
@@ -761,7 +1113,7 @@ mod linux_evdev {
 // -------------------------------------------------------------------------------------------------
 
 mod linux_x11 {
-    use super::{handle_hotkey_state, HotkeyState};
+    use super::{dispatch_hotkey, HotkeyAction, HotkeyState, KeyChord};
     use crate::output::synthetic_paste_active;
     use anyhow::Context;
     use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
@@ -777,6 +1129,7 @@ mod linux_x11 {
     // Minimal X11 keysym constants we need.
     // Values from X11/keysymdef.h.
     const XK_SPACE: u32 = 0x0020;
+    const XK_BACKSPACE: u32 = 0xff08;
     const XK_TAB: u32 = 0xff09;
     const XK_RETURN: u32 = 0xff0d;
     const XK_ESCAPE: u32 = 0xff1b;
@@ -798,6 +1151,17 @@ mod linux_x11 {
 
     const XK_F1: u32 = 0xffbe;
 
+    const XK_HOME: u32 = 0xff50;
+    const XK_LEFT: u32 = 0xff51;
+    const XK_UP: u32 = 0xff52;
+    const XK_RIGHT: u32 = 0xff53;
+    const XK_DOWN: u32 = 0xff54;
+    const XK_PAGE_UP: u32 = 0xff55;
+    const XK_PAGE_DOWN: u32 = 0xff56;
+    const XK_END: u32 = 0xff57;
+    const XK_INSERT: u32 = 0xff63;
+    const XK_DELETE: u32 = 0xffff;
+
     pub(super) struct X11Listener {
         stop_tx: Sender<()>,
         thread: thread::JoinHandle<()>,
@@ -805,33 +1169,66 @@ mod linux_x11 {
 
     static X11_LISTENER: parking_lot::RwLock<Option<X11Listener>> = parking_lot::RwLock::new(None);
 
-    #[derive(Debug, Clone, Copy)]
-    struct Modifiers {
-        ctrl: bool,
-        alt: bool,
-        shift: bool,
-        meta: bool,
-    }
-
     #[derive(Debug, Clone, Copy)]
     struct HotkeySpec {
         keycode: u8,
         required: u16,
+        action: HotkeyAction,
     }
 
-    pub(super) fn start(app: &AppHandle, shortcut: &str) -> anyhow::Result<()> {
+    pub(super) fn start(
+        app: &AppHandle,
+        bindings: &[(HotkeyAction, String)],
+    ) -> anyhow::Result<()> {
         stop();
 
-        let (mods, key_str) = parse_hotkey(shortcut)?;
-
         let (conn, screen_num) = x11rb::connect(None).context("connect to X11")?;
         let root = conn.setup().roots[screen_num].root;
 
-        // Resolve trigger keycode.
-        let keycode = keycode_for_key_string(&conn, key_str)?;
-
         // Compute modifier masks from the server's modifier map so Alt/Meta work across layouts.
         let modifier_map = ModifierMap::new(&conn)?;
+        let variants = modifier_map.lock_variants();
+
+        let mut specs = Vec::with_capacity(bindings.len());
+        for (action, shortcut) in bindings {
+            match grab_binding(&conn, root, &modifier_map, &variants, shortcut, *action) {
+                Ok(spec) => specs.push(spec),
+                Err(error) if *action != HotkeyAction::Dictation => {
+                    tracing::warn!("skipping {action:?} hotkey {shortcut:?}: {error}");
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        conn.flush()?;
+
+        let app_handle = app.clone();
+        let (stop_tx, stop_rx) = channel();
+        let thread = thread::Builder::new()
+            .name("x11-hotkeys".to_string())
+            .spawn(move || {
+                if let Err(error) = run_loop(conn, app_handle, specs, stop_rx) {
+                    tracing::warn!("x11 hotkey listener stopped: {error:?}");
+                }
+            })?;
+
+        *X11_LISTENER.write() = Some(X11Listener { stop_tx, thread });
+        Ok(())
+    }
+
+    fn grab_binding<C: Connection>(
+        conn: &C,
+        root: u32,
+        modifier_map: &ModifierMap,
+        variants: &[u16],
+        shortcut: &str,
+        action: HotkeyAction,
+    ) -> anyhow::Result<HotkeySpec> {
+        let mods = KeyChord::parse(shortcut)?;
+
+        // Resolve trigger keycode.
+        let keycode = keycode_for_key_string(conn, &mods.key)?;
+
         let mut required_mask: u16 = 0;
         if mods.shift {
             required_mask |= u16::from(ModMask::SHIFT);
@@ -847,40 +1244,22 @@ mod linux_x11 {
         }
 
         // Grab the key. Include lock variants so the grab still works with CapsLock/NumLock.
-        let variants = modifier_map.lock_variants();
         for extra in variants {
             let mask_bits = required_mask | extra;
             let mask = ModMask::from(mask_bits);
             let _ = conn.grab_key(false, root, mask, keycode, GrabMode::ASYNC, GrabMode::ASYNC)?;
         }
 
-        conn.flush()?;
-
         info!(
-            "x11 hotkeys active: keycode={} required_mask=0x{:x}",
-            keycode, required_mask
+            "x11 hotkeys active: action={:?} keycode={} required_mask=0x{:x}",
+            action, keycode, required_mask
         );
 
-        let app_handle = app.clone();
-        let (stop_tx, stop_rx) = channel();
-        let thread = thread::Builder::new()
-            .name("x11-hotkeys".to_string())
-            .spawn(move || {
-                if let Err(error) = run_loop(
-                    conn,
-                    app_handle,
-                    HotkeySpec {
-                        keycode,
-                        required: required_mask,
-                    },
-                    stop_rx,
-                ) {
-                    tracing::warn!("x11 hotkey listener stopped: {error:?}");
-                }
-            })?;
-
-        *X11_LISTENER.write() = Some(X11Listener { stop_tx, thread });
-        Ok(())
+        Ok(HotkeySpec {
+            keycode,
+            required: required_mask,
+            action,
+        })
     }
 
     pub(super) fn stop() {
@@ -895,41 +1274,6 @@ mod linux_x11 {
         stop();
     }
 
-    fn parse_hotkey(input: &str) -> anyhow::Result<(Modifiers, &str)> {
-        let parts: Vec<&str> = input
-            .split('+')
-            .map(|p| p.trim())
-            .filter(|p| !p.is_empty())
-            .collect();
-        if parts.is_empty() {
-            anyhow::bail!("hotkey is empty");
-        }
-
-        let (mods, key_str) = if parts.len() == 1 {
-            (Vec::new(), parts[0])
-        } else {
-            (parts[..parts.len() - 1].to_vec(), parts[parts.len() - 1])
-        };
-
-        let mut modifiers = Modifiers {
-            ctrl: false,
-            alt: false,
-            shift: false,
-            meta: false,
-        };
-        for m in mods {
-            match m {
-                "Ctrl" | "Control" => modifiers.ctrl = true,
-                "Alt" => modifiers.alt = true,
-                "Shift" => modifiers.shift = true,
-                "Meta" | "Super" | "Command" | "Logo" => modifiers.meta = true,
-                _ => {}
-            }
-        }
-
-        Ok((modifiers, key_str))
-    }
-
     struct ModifierMap {
         alt: ModMask,
         meta: ModMask,
@@ -1017,7 +1361,7 @@ mod linux_x11 {
         }
     }
 
-    fn keycode_for_key_string<C: Connection>(conn: &C, key: &str) -> anyhow::Result<u8> {
+    pub(super) fn keycode_for_key_string<C: Connection>(conn: &C, key: &str) -> anyhow::Result<u8> {
         let trimmed = key.trim();
         if trimmed.is_empty() {
             anyhow::bail!("missing hotkey key");
@@ -1029,6 +1373,30 @@ mod linux_x11 {
             "ENTER" | "RETURN" => vec![XK_RETURN],
             "ESC" | "ESCAPE" => vec![XK_ESCAPE],
             "TAB" => vec![XK_TAB],
+            "BACKSPACE" => vec![XK_BACKSPACE],
+            "ARROWUP" | "UP" => vec![XK_UP],
+            "ARROWDOWN" | "DOWN" => vec![XK_DOWN],
+            "ARROWLEFT" | "LEFT" => vec![XK_LEFT],
+            "ARROWRIGHT" | "RIGHT" => vec![XK_RIGHT],
+            "INSERT" => vec![XK_INSERT],
+            "HOME" => vec![XK_HOME],
+            "END" => vec![XK_END],
+            "PAGEUP" => vec![XK_PAGE_UP],
+            "PAGEDOWN" => vec![XK_PAGE_DOWN],
+            "DELETE" => vec![XK_DELETE],
+
+            // Latin-1 keysyms equal the ASCII code of the unshifted character.
+            "MINUS" => vec![u32::from(b'-')],
+            "EQUAL" => vec![u32::from(b'=')],
+            "LEFTBRACE" => vec![u32::from(b'[')],
+            "RIGHTBRACE" => vec![u32::from(b']')],
+            "SEMICOLON" => vec![u32::from(b';')],
+            "APOSTROPHE" => vec![u32::from(b'\'')],
+            "GRAVE" => vec![u32::from(b'`')],
+            "BACKSLASH" => vec![u32::from(b'\\')],
+            "COMMA" => vec![u32::from(b',')],
+            "DOT" => vec![u32::from(b'.')],
+            "SLASH" => vec![u32::from(b'/')],
 
             "RIGHTALT" | "ALTRIGHT" => vec![XK_ALT_R, XK_ISO_LEVEL3_SHIFT, XK_MODE_SWITCH],
             "LEFTALT" | "ALTLEFT" => vec![XK_ALT_L],
@@ -1115,10 +1483,10 @@ mod linux_x11 {
     fn run_loop<C: Connection>(
         conn: C,
         app: AppHandle,
-        spec: HotkeySpec,
+        specs: Vec<HotkeySpec>,
         stop_rx: Receiver<()>,
     ) -> anyhow::Result<()> {
-        let mut is_pressed = false;
+        let mut pressed = vec![false; specs.len()];
         loop {
             match stop_rx.try_recv() {
                 Ok(_) | Err(TryRecvError::Disconnected) => return Ok(()),
@@ -1128,27 +1496,28 @@ mod linux_x11 {
             if let Some(event) = conn.poll_for_event()? {
                 match event {
                     Event::KeyPress(ev) => {
-                        if ev.detail == spec.keycode {
-                            if synthetic_paste_active() {
-                                continue;
-                            }
-                            let state_bits: u16 = ev.state.into();
-                            if (state_bits & spec.required) == spec.required {
-                                if !is_pressed {
-                                    is_pressed = true;
-                                    handle_hotkey_state(&app, HotkeyState::Pressed);
-                                }
+                        if synthetic_paste_active() {
+                            continue;
+                        }
+                        let state_bits: u16 = ev.state.into();
+                        for (spec, is_pressed) in specs.iter().zip(pressed.iter_mut()) {
+                            if ev.detail == spec.keycode
+                                && (state_bits & spec.required) == spec.required
+                                && !*is_pressed
+                            {
+                                *is_pressed = true;
+                                dispatch_hotkey(&app, spec.action, HotkeyState::Pressed);
                             }
                         }
                     }
                     Event::KeyRelease(ev) => {
-                        if ev.detail == spec.keycode {
-                            if synthetic_paste_active() {
-                                continue;
-                            }
-                            if is_pressed {
-                                is_pressed = false;
-                                handle_hotkey_state(&app, HotkeyState::Released);
+                        if synthetic_paste_active() {
+                            continue;
+                        }
+                        for (spec, is_pressed) in specs.iter().zip(pressed.iter_mut()) {
+                            if ev.detail == spec.keycode && *is_pressed {
+                                *is_pressed = false;
+                                dispatch_hotkey(&app, spec.action, HotkeyState::Released);
                             }
                         }
                     }
@@ -1161,8 +1530,282 @@ mod linux_x11 {
     }
 }
 
-fn register_evdev_shortcut(app: &AppHandle, shortcut: &str) -> tauri::Result<()> {
-    match linux_evdev::start(app, shortcut) {
+// -------------------------------------------------------------------------------------------------
+// XDG GlobalShortcuts portal backend (Wayland, no /dev/input access needed)
+// -------------------------------------------------------------------------------------------------
+
+mod linux_portal {
+    use super::{dispatch_hotkey, HotkeyAction, HotkeyState, KeyChord};
+    use ashpd::desktop::global_shortcuts::{GlobalShortcuts, NewShortcut};
+    use futures_util::StreamExt;
+    use std::thread;
+    use std::time::Duration;
+    use tauri::{AppHandle, Emitter};
+    use tokio::sync::oneshot;
+    use tracing::{debug, info, warn};
+
+    /// How long the portal gets to answer `CreateSession` before evdev is used instead.
+    const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+    pub(super) struct PortalListener {
+        stop_tx: oneshot::Sender<()>,
+        thread: thread::JoinHandle<()>,
+    }
+
+    static PORTAL_LISTENER: parking_lot::RwLock<Option<PortalListener>> =
+        parking_lot::RwLock::new(None);
+
+    /// Opens a portal session and binds `bindings` on a listener thread. Returns once the
+    /// portal accepted the session; binding may still show a compositor dialog, and if
+    /// it fails the listener falls back to evdev on its own.
+    pub(super) async fn start(
+        app: &AppHandle,
+        bindings: &[(HotkeyAction, String)],
+    ) -> anyhow::Result<()> {
+        stop();
+        let (ready_tx, ready_rx) = oneshot::channel();
+        let (stop_tx, stop_rx) = oneshot::channel();
+        let app_handle = app.clone();
+        let bindings = bindings.to_vec();
+        let thread = thread::Builder::new()
+            .name("portal-hotkeys".to_string())
+            .spawn(move || {
+                tauri::async_runtime::block_on(run(app_handle, bindings, ready_tx, stop_rx));
+            })?;
+
+        let ready = match tokio::time::timeout(PROBE_TIMEOUT, ready_rx).await {
+            Ok(Ok(ready)) => ready,
+            Ok(Err(_)) => Err(anyhow::anyhow!("portal listener exited")),
+            Err(_) => Err(anyhow::anyhow!("portal did not answer")),
+        };
+        let listener = PortalListener { stop_tx, thread };
+        match ready {
+            Ok(()) => {
+                *PORTAL_LISTENER.write() = Some(listener);
+                Ok(())
+            }
+            Err(error) => {
+                let _ = listener.stop_tx.send(());
+                let _ = listener.thread.join();
+                Err(error)
+            }
+        }
+    }
+
+    pub(super) fn stop() {
+        let listener = PORTAL_LISTENER.write().take();
+        if let Some(listener) = listener {
+            let _ = listener.stop_tx.send(());
+            let _ = listener.thread.join();
+        }
+    }
+
+    async fn run(
+        app: AppHandle,
+        bindings: Vec<(HotkeyAction, String)>,
+        ready_tx: oneshot::Sender<anyhow::Result<()>>,
+        mut stop_rx: oneshot::Receiver<()>,
+    ) {
+        let opened = async {
+            let proxy = GlobalShortcuts::new().await?;
+            let session = proxy.create_session().await?;
+            Ok::<_, ashpd::Error>((proxy, session))
+        }
+        .await;
+        let (proxy, session) = match opened {
+            Ok(opened) => {
+                let _ = ready_tx.send(Ok(()));
+                opened
+            }
+            Err(error) => {
+                let _ = ready_tx.send(Err(anyhow::anyhow!(error.to_string())));
+                return;
+            }
+        };
+
+        let shortcuts: Vec<NewShortcut> = bindings
+            .iter()
+            .map(|(action, chord)| {
+                let trigger = KeyChord::parse(chord)
+                    .ok()
+                    .and_then(|chord| trigger(&chord));
+                NewShortcut::new(shortcut_id(*action), description(*action))
+                    .preferred_trigger(trigger.as_deref())
+            })
+            .collect();
+        let bound = async {
+            proxy
+                .bind_shortcuts(&session, &shortcuts, None)
+                .await?
+                .response()
+        };
+        let bound = tokio::select! {
+            bound = bound => bound,
+            _ = &mut stop_rx => {
+                let _ = session.close().await;
+                return;
+            }
+        };
+        match bound {
+            Ok(response) if !response.shortcuts().is_empty() => {
+                for shortcut in response.shortcuts() {
+                    info!(
+                        "portal hotkeys active: id={} trigger={}",
+                        shortcut.id(),
+                        shortcut.trigger_description()
+                    );
+                }
+            }
+            outcome => {
+                let reason = match outcome {
+                    Ok(_) => "no shortcuts bound".to_string(),
+                    Err(error) => error.to_string(),
+                };
+                warn!("portal hotkey binding failed, falling back to evdev: {reason}");
+                let _ = session.close().await;
+                if stop_rx.try_recv().is_err() {
+                    fall_back_to_evdev(&app, &bindings);
+                }
+                return;
+            }
+        }
+
+        let streams = async {
+            let activated = proxy.receive_activated().await?;
+            let deactivated = proxy.receive_deactivated().await?;
+            Ok::<_, ashpd::Error>((activated, deactivated))
+        }
+        .await;
+        let (activated, deactivated) = match streams {
+            Ok(streams) => streams,
+            Err(error) => {
+                warn!("portal hotkey signals unavailable: {error}");
+                let _ = session.close().await;
+                return;
+            }
+        };
+        tokio::pin!(activated, deactivated);
+
+        loop {
+            tokio::select! {
+                Some(event) = activated.next() => {
+                    if let Some(action) = action_for(&bindings, event.shortcut_id()) {
+                        dispatch_hotkey(&app, action, HotkeyState::Pressed);
+                    }
+                }
+                Some(event) = deactivated.next() => {
+                    if let Some(action) = action_for(&bindings, event.shortcut_id()) {
+                        dispatch_hotkey(&app, action, HotkeyState::Released);
+                    }
+                }
+                _ = &mut stop_rx => break,
+                else => break,
+            }
+        }
+        debug!("portal hotkeys stopping");
+        let _ = session.close().await;
+    }
+
+    fn fall_back_to_evdev(app: &AppHandle, bindings: &[(HotkeyAction, String)]) {
+        let Some(((_, shortcut), extra)) = bindings.split_first() else {
+            return;
+        };
+        if super::register_evdev_shortcut(app, bindings).is_ok() {
+            super::set_current_hotkey(shortcut, extra, "evdev");
+            let _ = app.emit("hotkey-backend", "evdev");
+        }
+    }
+
+    fn action_for(bindings: &[(HotkeyAction, String)], id: &str) -> Option<HotkeyAction> {
+        bindings
+            .iter()
+            .map(|(action, _)| *action)
+            .find(|action| shortcut_id(*action) == id)
+    }
+
+    fn shortcut_id(action: HotkeyAction) -> &'static str {
+        match action {
+            HotkeyAction::Dictation => "dictation",
+            HotkeyAction::RecopyLastTranscript => "recopy-last-transcript",
+            HotkeyAction::CycleLanguage => "cycle-language",
+            HotkeyAction::VoiceCommand => "voice-command",
+            HotkeyAction::ContinuousDictation => "continuous-dictation",
+        }
+    }
+
+    fn description(action: HotkeyAction) -> &'static str {
+        match action {
+            HotkeyAction::Dictation => "Dictate (hold or toggle)",
+            HotkeyAction::RecopyLastTranscript => "Copy the last transcript again",
+            HotkeyAction::CycleLanguage => "Switch dictation language",
+            HotkeyAction::VoiceCommand => "Speak a voice command",
+            HotkeyAction::ContinuousDictation => "Start or stop continuous dictation",
+        }
+    }
+
+    /// The chord in the shortcuts spec syntax used for preferred triggers, e.g.
+    /// `CTRL+SHIFT+d`. None for keys without a known keysym name; the compositor then
+    /// asks the user for a trigger.
+    pub(super) fn trigger(chord: &KeyChord) -> Option<String> {
+        let upper = chord.key.trim().to_ascii_uppercase().replace(' ', "");
+        let key = match upper.as_str() {
+            "SPACE" => "space".to_string(),
+            "ENTER" | "RETURN" => "Return".to_string(),
+            "ESC" | "ESCAPE" => "Escape".to_string(),
+            "TAB" => "Tab".to_string(),
+            "BACKSPACE" => "BackSpace".to_string(),
+            "ARROWUP" | "UP" => "Up".to_string(),
+            "ARROWDOWN" | "DOWN" => "Down".to_string(),
+            "ARROWLEFT" | "LEFT" => "Left".to_string(),
+            "ARROWRIGHT" | "RIGHT" => "Right".to_string(),
+            "INSERT" => "Insert".to_string(),
+            "HOME" => "Home".to_string(),
+            "END" => "End".to_string(),
+            "PAGEUP" => "Page_Up".to_string(),
+            "PAGEDOWN" => "Page_Down".to_string(),
+            "DELETE" => "Delete".to_string(),
+            "PAUSE" => "Pause".to_string(),
+            "SCROLLLOCK" => "Scroll_Lock".to_string(),
+            "CAPSLOCK" => "Caps_Lock".to_string(),
+            "RIGHTALT" | "ALTRIGHT" => "Alt_R".to_string(),
+            "LEFTALT" | "ALTLEFT" => "Alt_L".to_string(),
+            "RIGHTCTRL" | "CTRLRIGHT" | "CONTROLRIGHT" => "Control_R".to_string(),
+            "LEFTCTRL" | "CTRLLEFT" | "CONTROLLEFT" => "Control_L".to_string(),
+            "RIGHTSHIFT" | "SHIFTRIGHT" => "Shift_R".to_string(),
+            "LEFTSHIFT" | "SHIFTLEFT" => "Shift_L".to_string(),
+            "RIGHTMETA" | "METARIGHT" | "SUPERRIGHT" => "Super_R".to_string(),
+            "LEFTMETA" | "METALEFT" | "SUPERLEFT" => "Super_L".to_string(),
+            key if key.len() == 1 && key.chars().all(|c| c.is_ascii_alphanumeric()) => {
+                key.to_ascii_lowercase()
+            }
+            key => match key.strip_prefix('F').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n) if (1..=24).contains(&n) => format!("F{n}"),
+                _ => return None,
+            },
+        };
+
+        let mut trigger = String::new();
+        for (held, name) in [
+            (chord.ctrl, "CTRL"),
+            (chord.alt, "ALT"),
+            (chord.shift, "SHIFT"),
+            (chord.meta, "LOGO"),
+        ] {
+            if held {
+                trigger.push_str(name);
+                trigger.push('+');
+            }
+        }
+        trigger.push_str(&key);
+        Some(trigger)
+    }
+}
+
+fn register_evdev_shortcut(
+    app: &AppHandle,
+    bindings: &[(HotkeyAction, String)],
+) -> tauri::Result<()> {
+    match linux_evdev::start(app, bindings) {
         Ok(()) => Ok(()),
         Err(error) => {
             warn!("evdev hotkey registration failed: {error}");
@@ -1177,8 +1820,11 @@ fn register_evdev_shortcut(app: &AppHandle, shortcut: &str) -> tauri::Result<()>
     }
 }
 
-fn register_x11_shortcut(app: &AppHandle, shortcut: &str) -> tauri::Result<()> {
-    match linux_x11::start(app, shortcut) {
+fn register_x11_shortcut(
+    app: &AppHandle,
+    bindings: &[(HotkeyAction, String)],
+) -> tauri::Result<()> {
+    match linux_x11::start(app, bindings) {
         Ok(()) => Ok(()),
         Err(error) => {
             warn!("x11 hotkey registration failed: {error}");
@@ -1193,6 +1839,10 @@ fn register_x11_shortcut(app: &AppHandle, shortcut: &str) -> tauri::Result<()> {
     }
 }
 
+fn stop_portal_listener() {
+    linux_portal::stop();
+}
+
 fn stop_evdev_listener() {
     linux_evdev::stop_from_parent();
 }
@@ -1200,3 +1850,54 @@ fn stop_evdev_listener() {
 fn stop_x11_listener() {
     linux_x11::stop_from_parent();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{linux_evdev, linux_portal, KeyChord};
+
+    #[test]
+    fn parses_chords_with_case_insensitive_modifiers() {
+        let chord = KeyChord::parse("shift+Insert").unwrap();
+        assert!(chord.shift && !chord.ctrl && !chord.alt && !chord.meta);
+        assert_eq!(chord.key, "Insert");
+        assert_eq!(chord.to_string(), "Shift+Insert");
+
+        let chord = KeyChord::parse("Ctrl + Shift + v").unwrap();
+        assert_eq!(chord.to_string(), "Ctrl+Shift+V");
+
+        assert!(KeyChord::parse(" + ").is_err());
+
+        assert_eq!(
+            KeyChord::parse("ctrl+v").unwrap(),
+            KeyChord::parse("Ctrl+V").unwrap()
+        );
+        assert_ne!(
+            KeyChord::parse("Ctrl+V").unwrap(),
+            KeyChord::parse("Ctrl+Shift+V").unwrap()
+        );
+    }
+
+    #[test]
+    fn parses_mouse_and_pedal_buttons() {
+        use evdev::Key;
+        assert_eq!(linux_evdev::parse_key("BTN_SIDE").unwrap(), Key::BTN_SIDE);
+        assert_eq!(linux_evdev::parse_key("mouse5").unwrap(), Key::BTN_EXTRA);
+        assert_eq!(linux_evdev::parse_key("BTN_3").unwrap(), Key::BTN_3);
+        assert_eq!(
+            linux_evdev::parse_key("BTN_TRIGGER").unwrap(),
+            Key::BTN_TRIGGER
+        );
+        assert!(linux_evdev::parse_key("BTN_LEFT").is_err());
+        assert!(linux_evdev::is_button_binding("Ctrl+Mouse4"));
+        assert!(!linux_evdev::is_button_binding("Ctrl+Shift+B"));
+    }
+
+    #[test]
+    fn converts_chords_to_portal_triggers() {
+        let trigger = |input: &str| linux_portal::trigger(&KeyChord::parse(input).unwrap());
+        assert_eq!(trigger("Ctrl+Shift+D").as_deref(), Some("CTRL+SHIFT+d"));
+        assert_eq!(trigger("Super+F9").as_deref(), Some("LOGO+F9"));
+        assert_eq!(trigger("RightAlt").as_deref(), Some("Alt_R"));
+        assert_eq!(trigger("Ctrl+MediaPlay"), None);
+    }
+}
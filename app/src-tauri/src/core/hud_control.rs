@@ -0,0 +1,197 @@
+//! Command channel from the GNOME HUD extension back into the app.
+//!
+//! The HUD state file is one-way (app → shell). The extension's panel indicator
+//! writes newline-terminated commands to a Unix socket next to it, so a click on
+//! the indicator can start or stop dictation where global hotkeys are unavailable.
+//! The extension also reports fullscreen changes, which Wayland hides from clients.
+
+use std::io::{BufRead, BufReader};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use tauri::{AppHandle, Manager};
+use tracing::{debug, warn};
+
+use super::app_state::AppState;
+use super::fullscreen;
+
+/// A client that connects but never finishes its line must not wedge the listener.
+const CLIENT_READ_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Set once this process bound the socket; only the owner removes it on exit.
+static OWNS_SOCKET: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ControlCommand {
+    Toggle,
+    Start,
+    Stop,
+    /// `fullscreen on` / `fullscreen off`: the focused window entered or left fullscreen.
+    Fullscreen(bool),
+}
+
+impl ControlCommand {
+    fn parse(line: &str) -> Option<Self> {
+        match line.trim() {
+            "toggle" => Some(Self::Toggle),
+            "start" => Some(Self::Start),
+            "stop" => Some(Self::Stop),
+            "fullscreen on" => Some(Self::Fullscreen(true)),
+            "fullscreen off" => Some(Self::Fullscreen(false)),
+            _ => None,
+        }
+    }
+}
+
+pub fn control_socket_path() -> Option<PathBuf> {
+    std::env::var_os("XDG_RUNTIME_DIR")
+        .map(PathBuf::from)
+        .map(|base| base.join("openflow").join("control.sock"))
+}
+
+/// Binds the control socket and serves it on a background thread. Best effort:
+/// failures are logged and the app keeps running without the channel.
+pub fn spawn(app: AppHandle) {
+    let listener = match bind() {
+        Ok(Some(listener)) => {
+            OWNS_SOCKET.store(true, Ordering::SeqCst);
+            listener
+        }
+        Ok(None) => return,
+        Err(error) => {
+            warn!("HUD control socket unavailable: {error:?}");
+            return;
+        }
+    };
+
+    let spawned = std::thread::Builder::new()
+        .name("hud-control".into())
+        .spawn(move || serve(app, listener));
+    if let Err(error) = spawned {
+        warn!("failed to start HUD control thread: {error}");
+    }
+}
+
+/// Removes the control socket so clients fail fast once the app is gone. A process
+/// that didn't bind it leaves it to the instance serving it.
+pub fn remove_socket() {
+    if !OWNS_SOCKET.swap(false, Ordering::SeqCst) {
+        return;
+    }
+    if let Some(path) = control_socket_path() {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+fn bind() -> Result<Option<UnixListener>> {
+    let Some(path) = control_socket_path() else {
+        return Ok(None);
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("create runtime dir")?;
+    }
+    bind_at(&path).map(Some)
+}
+
+/// Binds `path`, replacing a socket file only when nothing accepts connections on it.
+fn bind_at(path: &Path) -> Result<UnixListener> {
+    // A previous run that crashed leaves the socket file behind; a live instance
+    // still answers on it and keeps it.
+    if path.exists() {
+        match UnixStream::connect(path) {
+            Ok(_) => bail!("another instance is serving {}", path.display()),
+            Err(error) if error.kind() == std::io::ErrorKind::ConnectionRefused => {
+                std::fs::remove_file(path).context("remove stale control socket")?;
+            }
+            Err(error) => {
+                return Err(error).context("probe existing control socket");
+            }
+        }
+    }
+
+    let listener = UnixListener::bind(path).context("bind control socket")?;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+        .context("restrict control socket")?;
+    Ok(listener)
+}
+
+fn serve(app: AppHandle, listener: UnixListener) {
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                if let Some(command) = read_command(stream) {
+                    dispatch(&app, command);
+                }
+            }
+            Err(error) => debug!("HUD control accept failed: {error}"),
+        }
+    }
+}
+
+fn read_command(stream: UnixStream) -> Option<ControlCommand> {
+    let _ = stream.set_read_timeout(Some(CLIENT_READ_TIMEOUT));
+    let mut line = String::new();
+    if let Err(error) = BufReader::new(stream).read_line(&mut line) {
+        debug!("HUD control read failed: {error}");
+        return None;
+    }
+    let command = ControlCommand::parse(&line);
+    if command.is_none() {
+        debug!("ignoring unknown HUD control command {:?}", line.trim());
+    }
+    command
+}
+
+fn dispatch(app: &AppHandle, command: ControlCommand) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    if let ControlCommand::Fullscreen(fullscreen) = command {
+        if fullscreen::set_reported(fullscreen) {
+            debug!("hud_control fullscreen={fullscreen}");
+            state.sync_hud_overlay_mode(app);
+        }
+        return;
+    }
+    let listening = state.is_listening();
+    tracing::info!("hud_control command={command:?} listening={listening}");
+
+    let wanted = match command {
+        ControlCommand::Toggle => true,
+        ControlCommand::Start => !listening,
+        ControlCommand::Stop => listening,
+        ControlCommand::Fullscreen(_) => false,
+    };
+    if wanted {
+        state.toggle_session(app);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bind_keeps_a_live_socket_and_replaces_a_stale_one() {
+        let dir = std::env::temp_dir().join(format!("openflow-control-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("control.sock");
+        let _ = std::fs::remove_file(&path);
+
+        let live = bind_at(&path).unwrap();
+        let error = bind_at(&path).unwrap_err();
+        assert!(error.to_string().contains("another instance"));
+        assert!(path.exists());
+
+        // Closing the listener leaves the file behind, as a crash would.
+        drop(live);
+        assert!(path.exists());
+        let replaced = bind_at(&path).unwrap();
+        drop(replaced);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
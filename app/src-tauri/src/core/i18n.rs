@@ -0,0 +1,147 @@
+//! Message catalogs for user-facing text produced by the backend: notifications,
+//! permission hints and skipped-dictation reasons. Messages are Fluent entries keyed
+//! by stable codes (see `locales/*.ftl`); log lines stay in English.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use once_cell::sync::Lazy;
+use tracing::{debug, info, warn};
+use unic_langid::LanguageIdentifier;
+
+const FALLBACK_LOCALE: &str = "en-US";
+const CATALOGS: &[(&str, &str)] = &[
+    ("en-US", include_str!("../../locales/en-US.ftl")),
+    ("de", include_str!("../../locales/de.ftl")),
+];
+
+type Bundle = FluentBundle<FluentResource>;
+
+static BUNDLES: Lazy<Vec<(LanguageIdentifier, Bundle)>> = Lazy::new(|| {
+    CATALOGS
+        .iter()
+        .filter_map(|(tag, source)| {
+            let locale: LanguageIdentifier = tag.parse().ok()?;
+            Some((locale.clone(), build_bundle(locale, source)))
+        })
+        .collect()
+});
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+
+fn build_bundle(locale: LanguageIdentifier, source: &str) -> Bundle {
+    let resource =
+        FluentResource::try_new(source.to_string()).unwrap_or_else(|(resource, errors)| {
+            warn!("message catalog {locale} has syntax errors: {errors:?}");
+            resource
+        });
+    let mut bundle = FluentBundle::new_concurrent(vec![locale.clone()]);
+    // Isolation marks around arguments show up as stray glyphs in notifications.
+    bundle.set_use_isolating(false);
+    if let Err(errors) = bundle.add_resource(resource) {
+        warn!("message catalog {locale} has duplicate entries: {errors:?}");
+    }
+    bundle
+}
+
+/// Selects the catalog for the `messageLocale` setting: a tag such as "de-DE", or
+/// "system" for the locale in `LC_ALL`/`LC_MESSAGES`/`LANG`.
+pub fn set_locale(setting: &str) {
+    let tag = match setting.trim() {
+        "" | "system" => system_locale(),
+        tag => Some(tag.to_string()),
+    };
+    let index = tag.as_deref().map_or_else(fallback_index, resolve);
+    if ACTIVE.swap(index, Ordering::Relaxed) != index {
+        info!("message_locale_selected locale={}", BUNDLES[index].0);
+    }
+}
+
+fn system_locale() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|key| std::env::var(key).ok())
+        .find(|value| !value.trim().is_empty())
+}
+
+/// Best catalog for a POSIX or BCP 47 tag: exact match, then same language.
+fn resolve(tag: &str) -> usize {
+    let tag = tag
+        .split(['.', '@'])
+        .next()
+        .unwrap_or_default()
+        .replace('_', "-");
+    let Ok(wanted) = tag.parse::<LanguageIdentifier>() else {
+        return fallback_index();
+    };
+    BUNDLES
+        .iter()
+        .position(|(locale, _)| *locale == wanted)
+        .or_else(|| {
+            BUNDLES
+                .iter()
+                .position(|(locale, _)| locale.language == wanted.language)
+        })
+        .unwrap_or_else(fallback_index)
+}
+
+fn fallback_index() -> usize {
+    BUNDLES
+        .iter()
+        .position(|(locale, _)| locale.to_string() == FALLBACK_LOCALE)
+        .unwrap_or(0)
+}
+
+/// The message `id` in the selected locale, falling back to English, with `args`
+/// substituted for its `{ $name }` placeholders.
+pub fn lookup(id: &str, args: &[(&str, &str)]) -> Option<String> {
+    let active = ACTIVE.load(Ordering::Relaxed);
+    [active, fallback_index()]
+        .into_iter()
+        .find_map(|index| format_message(&BUNDLES.get(index)?.1, id, args))
+}
+
+/// Like [`lookup`], but returns `id` itself when no catalog has it.
+pub fn message(id: &str, args: &[(&str, &str)]) -> String {
+    lookup(id, args).unwrap_or_else(|| {
+        warn!("missing message {id}");
+        id.to_string()
+    })
+}
+
+fn format_message(bundle: &Bundle, id: &str, args: &[(&str, &str)]) -> Option<String> {
+    let pattern = bundle.get_message(id)?.value()?;
+    let mut fluent_args = FluentArgs::new();
+    for (name, value) in args {
+        fluent_args.set(*name, *value);
+    }
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
+    if !errors.is_empty() {
+        debug!("message {id} formatted with errors: {errors:?}");
+    }
+    Some(text.into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_posix_tags_and_formats_arguments() {
+        let german = resolve("de_AT.UTF-8");
+        assert_eq!(BUNDLES[german].0.to_string(), "de");
+        assert_eq!(resolve("fr_FR.UTF-8"), fallback_index());
+
+        let text = format_message(
+            &BUNDLES[german].1,
+            "perm-uinput-open-failed",
+            &[("error", "EBUSY")],
+        );
+        assert_eq!(
+            text.as_deref(),
+            Some("/dev/uinput kann nicht geöffnet werden: EBUSY")
+        );
+        assert!(format_message(&BUNDLES[german].1, "no-such-message", &[]).is_none());
+    }
+}
@@ -1,10 +1,90 @@
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
 use serde::Serialize;
 
+use crate::core::compositor::{self, CompositorCapabilities};
+use crate::core::i18n;
+use crate::output::virtual_keyboard;
+
 const GNOME_HUD_EXTENSION_UUID: &str = "openflow-hud@openflow";
 const GNOME_HUD_METADATA: &str =
     include_str!("../../../../gnome-extension/openflow-hud@openflow/metadata.json");
 const GNOME_HUD_EXTENSION_JS: &str =
     include_str!("../../../../gnome-extension/openflow-hud@openflow/extension.js");
+/// Root script behind both setup paths; takes the user name as `$1`. Keep heredoc
+/// terminators at column 0 (no indentation) so shells parse them correctly.
+const PERMISSIONS_SCRIPT: &str = r#"set -eu
+
+USER_NAME="$1"
+
+if ! command -v getent >/dev/null 2>&1; then
+  echo "getent not available" >&2
+  exit 1
+fi
+
+if ! getent group input >/dev/null 2>&1; then
+  groupadd input
+fi
+
+if [ ! -x /usr/bin/setfacl ]; then
+  echo "setfacl not available (install acl)" >&2
+  exit 1
+fi
+
+# Add user to input group (for /dev/input and /dev/uinput access).
+usermod -a -G input "$USER_NAME"
+
+# Group membership only applies to new logins. Grant the user direct ACLs on the
+# current device nodes so the running session works now; they last until the nodes
+# are recreated (replug or reboot), by which time the group has taken over.
+for NODE in /dev/input/event*; do
+  [ -e "$NODE" ] && /usr/bin/setfacl -m "u:$USER_NAME:r" "$NODE" || true
+done
+
+# Ensure uinput is available.
+if command -v modprobe >/dev/null 2>&1; then
+  modprobe uinput || true
+fi
+
+# Make /dev/uinput writable by the input group.
+RULE_FILE="/etc/udev/rules.d/99-openflow-uinput.rules"
+cat > "$RULE_FILE" <<'EOF'
+KERNEL=="uinput", ACTION=="add", MODE="0660", GROUP="input", TEST=="/usr/bin/setfacl", RUN+="/usr/bin/setfacl -m g::rw -m m::rw /dev/$name"
+EOF
+
+# Apply immediately for the current node (if present).
+if [ -e /dev/uinput ]; then
+  chgrp input /dev/uinput || true
+  chmod 0660 /dev/uinput || true
+  /usr/bin/setfacl -m g::rw -m m::rw /dev/uinput || true
+  /usr/bin/setfacl -m "u:$USER_NAME:rw" /dev/uinput || true
+fi
+
+if command -v udevadm >/dev/null 2>&1; then
+  udevadm control --reload-rules || true
+  udevadm trigger --action=add --name-match=uinput || true
+fi
+"#;
+/// Rule file the permissions script installs.
+const UINPUT_RULE_FILE: &str = "/etc/udev/rules.d/99-openflow-uinput.rules";
+/// How often, and for how long, device access is re-checked after setup.
+const ACCESS_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+const ACCESS_WATCH_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+static ACCESS_WATCH: Lazy<Mutex<AccessWatchState>> =
+    Lazy::new(|| Mutex::new(AccessWatchState::Idle));
+
+/// Progress of waiting for /dev/input access to become effective after setup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AccessWatchState {
+    Idle,
+    Waiting,
+    Granted,
+    TimedOut,
+}
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -18,12 +98,22 @@ pub struct LinuxPermissionsStatus {
     pub xdg_runtime_dir_available: bool,
     pub evdev_readable: bool,
     pub uinput_writable: bool,
+    /// Paste chords go through the compositor's virtual keyboard protocol, so
+    /// /dev/uinput access isn't needed.
+    pub virtual_keyboard_injection: bool,
     pub clipboard_backend: String,
     pub wl_copy_available: bool,
     pub wl_paste_available: bool,
     pub xclip_available: bool,
     pub pkexec_available: bool,
     pub setfacl_available: bool,
+    /// The user was added to the `input` group, but this login session predates it.
+    pub input_group_pending_relogin: bool,
+    pub access_watch: AccessWatchState,
+    /// Effects of the permissions script; Wayland sessions only.
+    pub setup_steps: Vec<SetupStep>,
+    /// Compositor family, protocol support and the strategies chosen from them.
+    pub compositor: Option<CompositorCapabilities>,
     pub details: Vec<String>,
 }
 
@@ -185,9 +275,7 @@ pub fn permissions_status() -> LinuxPermissionsStatus {
         } else {
             xdg_session_type.clone()
         };
-        details.push(format!(
-            "Not running under Wayland (XDG_SESSION_TYPE={session})"
-        ));
+        details.push(i18n::message("perm-not-wayland", &[("session", &session)]));
     }
 
     let xdg_runtime_dir_available = std::env::var_os("XDG_RUNTIME_DIR")
@@ -201,7 +289,7 @@ pub fn permissions_status() -> LinuxPermissionsStatus {
         match check_x11_capabilities() {
             Ok((display_ok, xtest_ok)) => {
                 if display_ok && !xtest_ok {
-                    details.push("Missing XTEST (X11 paste injection may not work)".to_string());
+                    details.push(i18n::message("perm-missing-xtest", &[]));
                 }
                 // Hotkeys use core X11 grabs (no /dev/input needed). If we can connect, we can at
                 // least attempt grabs.
@@ -251,26 +339,32 @@ pub fn permissions_status() -> LinuxPermissionsStatus {
 
     if wayland_session {
         if !xdg_runtime_dir_available {
-            details.push("Missing XDG_RUNTIME_DIR (Wayland clipboard may not work)".to_string());
+            details.push(i18n::message("perm-missing-xdg-runtime-dir", &[]));
         }
         if !wl_copy_available {
-            details.push("Missing wl-copy (install wl-clipboard)".to_string());
+            details.push(i18n::message("perm-missing-wl-copy", &[]));
         }
         if !wl_paste_available {
-            details.push("Missing wl-paste (install wl-clipboard)".to_string());
+            details.push(i18n::message("perm-missing-wl-paste", &[]));
         }
     } else if !xclip_available {
-        details.push("Missing xclip (install xclip for X11 clipboard)".to_string());
+        details.push(i18n::message("perm-missing-xclip", &[]));
     }
 
     let pkexec_available = binary_in_path("pkexec");
     if wayland_session && !pkexec_available {
-        details.push("Missing pkexec (install polkit)".to_string());
+        details.push(i18n::message("perm-missing-pkexec", &[]));
     }
 
     let setfacl_available = binary_in_path("setfacl");
     if wayland_session && !setfacl_available {
-        details.push("Missing setfacl (install acl)".to_string());
+        details.push(i18n::message("perm-missing-setfacl", &[]));
+    }
+
+    let input_group_pending_relogin =
+        wayland_session && !evdev_readable && input_group_pending_relogin();
+    if input_group_pending_relogin {
+        details.push(i18n::message("perm-input-group-pending", &[]));
     }
 
     LinuxPermissionsStatus {
@@ -289,10 +383,112 @@ pub fn permissions_status() -> LinuxPermissionsStatus {
         xclip_available,
         pkexec_available,
         setfacl_available,
+        input_group_pending_relogin,
+        access_watch: *ACCESS_WATCH.lock(),
+        setup_steps: if wayland_session {
+            setup_steps()
+        } else {
+            Vec::new()
+        },
+        virtual_keyboard_injection: virtual_keyboard::available(),
+        compositor: compositor::capabilities(),
         details,
     }
 }
 
+/// Re-checks /dev/input access in the background until it works, then calls
+/// `on_granted` once, so hotkeys can start without a logout. Access arrives through
+/// the per-user ACLs the setup script grants, or a relogin. No-op if already watching.
+pub fn watch_for_input_access(on_granted: impl FnOnce() + Send + 'static) {
+    {
+        let mut state = ACCESS_WATCH.lock();
+        if *state == AccessWatchState::Waiting {
+            return;
+        }
+        *state = AccessWatchState::Waiting;
+    }
+    let spawned = std::thread::Builder::new()
+        .name("input-access-watch".into())
+        .spawn(move || {
+            let started = Instant::now();
+            loop {
+                if check_evdev_keyboard_access().is_ok() {
+                    *ACCESS_WATCH.lock() = AccessWatchState::Granted;
+                    tracing::info!(
+                        "input_access_granted waited_ms={}",
+                        started.elapsed().as_millis()
+                    );
+                    on_granted();
+                    return;
+                }
+                if started.elapsed() >= ACCESS_WATCH_TIMEOUT {
+                    *ACCESS_WATCH.lock() = AccessWatchState::TimedOut;
+                    tracing::warn!("input_access_watch_timed_out");
+                    return;
+                }
+                std::thread::sleep(ACCESS_WATCH_INTERVAL);
+            }
+        });
+    if let Err(error) = spawned {
+        *ACCESS_WATCH.lock() = AccessWatchState::Idle;
+        tracing::warn!("failed to spawn input access watch: {error}");
+    }
+}
+
+/// Whether `/etc/group` lists the user in `input` while this process lacks the group:
+/// membership was added after the session started.
+pub fn input_group_pending_relogin() -> bool {
+    let Some(user) = current_username() else {
+        return false;
+    };
+    let Some((gid, members)) = input_group_entry() else {
+        return false;
+    };
+    members.iter().any(|member| member == &user) && !process_has_group(gid)
+}
+
+fn input_group_entry() -> Option<(libc::gid_t, Vec<String>)> {
+    let output = std::process::Command::new("getent")
+        .args(["group", "input"])
+        .output()
+        .ok()?;
+    parse_group_entry(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// "input:x:104:alice,bob" -> (104, ["alice", "bob"]).
+fn parse_group_entry(line: &str) -> Option<(libc::gid_t, Vec<String>)> {
+    let mut fields = line.trim().split(':');
+    let gid = fields.nth(2)?.parse().ok()?;
+    let members = fields
+        .next()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|member| !member.is_empty())
+        .map(str::to_string)
+        .collect();
+    Some((gid, members))
+}
+
+fn process_has_group(gid: libc::gid_t) -> bool {
+    if unsafe { libc::getegid() } == gid {
+        return true;
+    }
+    unsafe {
+        let count = libc::getgroups(0, std::ptr::null_mut());
+        if count <= 0 {
+            return false;
+        }
+        let mut groups = vec![0 as libc::gid_t; count as usize];
+        let count = libc::getgroups(count, groups.as_mut_ptr());
+        if count < 0 {
+            return false;
+        }
+        groups.truncate(count as usize);
+        groups.contains(&gid)
+    }
+}
+
 fn check_x11_capabilities() -> Result<(bool, bool), String> {
     use x11rb::protocol::xproto::ConnectionExt as _;
 
@@ -301,14 +497,14 @@ fn check_x11_capabilities() -> Result<(bool, bool), String> {
         return Ok((false, false));
     }
 
-    let (conn, _) =
-        x11rb::connect(None).map_err(|err| format!("Failed to connect to X11: {err}"))?;
+    let (conn, _) = x11rb::connect(None)
+        .map_err(|err| i18n::message("perm-x11-connect-failed", &[("error", &err.to_string())]))?;
 
     let xtest = conn
         .query_extension(b"XTEST")
-        .map_err(|err| format!("Failed to query XTEST extension: {err}"))?
+        .map_err(|err| i18n::message("perm-xtest-query-failed", &[("error", &err.to_string())]))?
         .reply()
-        .map_err(|err| format!("Failed to read XTEST extension reply: {err}"))?;
+        .map_err(|err| i18n::message("perm-xtest-reply-failed", &[("error", &err.to_string())]))?;
 
     Ok((true, xtest.present))
 }
@@ -339,79 +535,19 @@ fn diagnose_uinput_acl_hint() -> Option<String> {
 
     let stdout = String::from_utf8_lossy(&output.stdout);
     if stdout.contains("group::---") {
-        return Some(
-            "uinput ACL blocks the 'input' group (group::---). This is commonly caused by brltty; the one-click setup uses setfacl to fix it."
-                .to_string(),
-        );
+        return Some(i18n::message("perm-uinput-acl-blocked", &[]));
     }
 
     None
 }
 
 pub fn enable_permissions_for_current_user() -> anyhow::Result<()> {
-    let user = current_username().unwrap_or_default();
-    if user.is_empty() {
-        anyhow::bail!("Could not determine current user (unable to resolve username)");
-    }
-
-    // Restrict to typical Unix usernames to avoid passing unsafe values to a root shell.
-    if !user
-        .chars()
-        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.')
-    {
-        anyhow::bail!("Invalid username '{user}'");
-    }
+    let user = setup_user()?;
 
     if !binary_in_path("pkexec") {
         anyhow::bail!("pkexec not found (install polkit)");
     }
 
-    // Keep heredoc terminators at column 0 (no indentation) so shells parse them correctly.
-    let script = r#"set -eu
-
-USER_NAME="$1"
-
-if ! command -v getent >/dev/null 2>&1; then
-  echo "getent not available" >&2
-  exit 1
-fi
-
-if ! getent group input >/dev/null 2>&1; then
-  groupadd input
-fi
-
-if [ ! -x /usr/bin/setfacl ]; then
-  echo "setfacl not available (install acl)" >&2
-  exit 1
-fi
-
-# Add user to input group (for /dev/input and /dev/uinput access).
-usermod -a -G input "$USER_NAME"
-
-# Ensure uinput is available.
-if command -v modprobe >/dev/null 2>&1; then
-  modprobe uinput || true
-fi
-
-# Make /dev/uinput writable by the input group.
-RULE_FILE="/etc/udev/rules.d/99-openflow-uinput.rules"
-cat > "$RULE_FILE" <<'EOF'
-KERNEL=="uinput", ACTION=="add", MODE="0660", GROUP="input", TEST=="/usr/bin/setfacl", RUN+="/usr/bin/setfacl -m g::rw -m m::rw /dev/$name"
-EOF
-
-# Apply immediately for the current node (if present).
-if [ -e /dev/uinput ]; then
-  chgrp input /dev/uinput || true
-  chmod 0660 /dev/uinput || true
-  /usr/bin/setfacl -m g::rw -m m::rw /dev/uinput || true
-fi
-
-if command -v udevadm >/dev/null 2>&1; then
-  udevadm control --reload-rules || true
-  udevadm trigger --action=add --name-match=uinput || true
-fi
-"#;
-
     let pkexec = if std::path::Path::new("/usr/bin/pkexec").is_file() {
         "/usr/bin/pkexec"
     } else {
@@ -421,7 +557,7 @@ fi
     let status = std::process::Command::new(pkexec)
         .arg("sh")
         .arg("-c")
-        .arg(script)
+        .arg(PERMISSIONS_SCRIPT)
         .arg("_")
         .arg(&user)
         .status()?;
@@ -433,6 +569,79 @@ fi
     Ok(())
 }
 
+/// The permissions script as a standalone file for users who won't approve a pkexec
+/// prompt (or have no polkit agent): review it, then run it with `sudo sh`. Progress
+/// shows up step by step in `LinuxPermissionsStatus::setup_steps`.
+pub fn generate_setup_script() -> anyhow::Result<String> {
+    let user = setup_user()?;
+    Ok(format!(
+        r#"#!/bin/sh
+# OpenFlow input permissions for user '{user}'.
+#
+# Review before running, then: sudo sh openflow-setup.sh
+# It adds '{user}' to the 'input' group, grants ACLs on the current /dev/input and
+# /dev/uinput nodes, and installs {UINPUT_RULE_FILE}.
+# Undo: gpasswd -d {user} input && rm {UINPUT_RULE_FILE}
+
+if [ "$(id -u)" -ne 0 ]; then
+  echo "run as root: sudo sh $0" >&2
+  exit 1
+fi
+
+set -- '{user}'
+
+{PERMISSIONS_SCRIPT}"#
+    ))
+}
+
+/// One effect of the permissions script, checked on its own so a partial manual run
+/// shows what is left.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetupStep {
+    /// "input-group", "uinput-rule", "input-access" or "uinput-access".
+    pub id: String,
+    pub done: bool,
+}
+
+fn setup_steps() -> Vec<SetupStep> {
+    let in_group = match (current_username(), input_group_entry()) {
+        (Some(user), Some((_, members))) => members.contains(&user),
+        _ => false,
+    };
+    [
+        ("input-group", in_group),
+        (
+            "uinput-rule",
+            std::path::Path::new(UINPUT_RULE_FILE).is_file(),
+        ),
+        ("input-access", check_evdev_keyboard_access().is_ok()),
+        ("uinput-access", check_uinput_access().is_ok()),
+    ]
+    .into_iter()
+    .map(|(id, done)| SetupStep {
+        id: id.to_string(),
+        done,
+    })
+    .collect()
+}
+
+/// The current user, restricted to typical Unix names so it is safe to hand to a root
+/// shell.
+fn setup_user() -> anyhow::Result<String> {
+    let user = current_username().unwrap_or_default();
+    if user.is_empty() {
+        anyhow::bail!("Could not determine current user (unable to resolve username)");
+    }
+    if !user
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.')
+    {
+        anyhow::bail!("Invalid username '{user}'");
+    }
+    Ok(user)
+}
+
 fn current_username() -> Option<String> {
     // Avoid relying on $USER, which may be missing in clean/sandboxed environments.
     if let Ok(u) = std::env::var("USER") {
@@ -487,8 +696,8 @@ fn username_from_uid(uid: libc::uid_t) -> Option<String> {
 }
 
 fn check_evdev_keyboard_access() -> Result<(), String> {
-    let dir =
-        std::fs::read_dir("/dev/input").map_err(|err| format!("/dev/input not readable: {err}"))?;
+    let dir = std::fs::read_dir("/dev/input")
+        .map_err(|err| i18n::message("perm-input-unreadable", &[("error", &err.to_string())]))?;
 
     let mut permission_denied = false;
     for entry in dir.flatten() {
@@ -522,22 +731,17 @@ fn check_evdev_keyboard_access() -> Result<(), String> {
     }
 
     if permission_denied {
-        return Err(
-            "No readable keyboard devices. Add your user to the 'input' group (then log out/in)."
-                .to_string(),
-        );
+        return Err(i18n::message("perm-no-readable-keyboards", &[]));
     }
 
-    Err("No keyboard devices found under /dev/input".to_string())
+    Err(i18n::message("perm-no-keyboards", &[]))
 }
 
 fn check_uinput_access() -> Result<(), String> {
     use std::fs::OpenOptions;
 
     if !std::path::Path::new("/dev/uinput").exists() {
-        return Err(
-            "/dev/uinput not found (load the uinput kernel module: modprobe uinput)".to_string(),
-        );
+        return Err(i18n::message("perm-uinput-missing", &[]));
     }
 
     OpenOptions::new()
@@ -547,14 +751,14 @@ fn check_uinput_access() -> Result<(), String> {
         .map(|_| ())
         .map_err(|err| {
             if err.kind() == std::io::ErrorKind::PermissionDenied {
-                "Cannot open /dev/uinput. Configure udev permissions (and ensure ACLs do not block the 'input' group) then log out/in.".to_string()
+                i18n::message("perm-uinput-denied", &[])
             } else {
-                format!("Cannot open /dev/uinput: {err}")
+                i18n::message("perm-uinput-open-failed", &[("error", &err.to_string())])
             }
         })
 }
 
-fn binary_in_path(binary: &str) -> bool {
+pub fn binary_in_path(binary: &str) -> bool {
     if let Some(path) = std::env::var_os("PATH") {
         for dir in std::env::split_paths(&path) {
             let full = dir.join(binary);
@@ -1,7 +1,28 @@
 pub mod app_state;
+pub mod compositor;
+pub mod debug_capture;
 pub mod events;
+pub mod failed_audio;
+pub mod fullscreen;
+pub mod gpu;
+pub mod history;
 pub mod hotkeys;
+pub mod hud_control;
+pub mod i18n;
 pub mod linux_setup;
+pub mod performance;
 pub mod pipeline;
+pub mod preflight;
+pub mod privacy;
+pub mod recovery;
+pub mod restart;
+pub mod secrets;
 pub mod settings;
+pub mod settings_journal;
+pub mod settings_validation;
+pub mod simulation;
+pub mod startup_warmup;
+pub mod thermal;
+pub mod timing;
 pub mod updater;
+pub mod voice_commands;
@@ -0,0 +1,403 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::pipeline::EngineMetrics;
+use crate::core::settings::AsrSelection;
+use crate::core::timing::{SessionTimeline, Stage};
+
+/// Whisper sizes from heaviest to lightest; downshifting moves one step right.
+const WHISPER_DOWNSHIFT_ORDER: &[&str] = &[
+    "large-v3",
+    "large-v3-turbo",
+    "medium",
+    "small",
+    "base",
+    "tiny",
+];
+
+/// Thresholds and actions for performance mode. Defaults match the original
+/// hard-coded heuristics.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PerformancePolicy {
+    /// A session is slow when ASR latency exceeds this...
+    pub slow_latency_ms: u64,
+    /// ...while CPU load is above this percentage.
+    pub slow_cpu_percent: f32,
+    /// Consecutive slow sessions before performance mode turns on.
+    pub enter_after_slow_sessions: u32,
+    /// Consecutive normal sessions before it turns off again.
+    pub exit_after_fast_sessions: u32,
+    /// Leave performance mode early once sampled CPU drops below this; 0 disables.
+    pub recover_cpu_percent: f32,
+    /// Cap the VAD hangover while in performance mode.
+    pub shorten_vad_hangover: bool,
+    pub hangover_cap_ms: u64,
+    /// Ask the frontend to suggest a lighter ASR model.
+    pub suggest_model_downshift: bool,
+    /// Sessions finished while performance mode stays on before a model downshift is
+    /// offered or applied.
+    pub downshift_after_sessions: u32,
+    pub latency_budget: LatencyBudget,
+}
+
+impl Default for PerformancePolicy {
+    fn default() -> Self {
+        Self {
+            slow_latency_ms: 2_000,
+            slow_cpu_percent: 75.0,
+            enter_after_slow_sessions: 2,
+            exit_after_fast_sessions: 1,
+            recover_cpu_percent: 75.0,
+            shorten_vad_hangover: true,
+            hangover_cap_ms: 200,
+            suggest_model_downshift: false,
+            downshift_after_sessions: 3,
+            latency_budget: LatencyBudget::default(),
+        }
+    }
+}
+
+/// Target latency from key release to delivered text, and its split across stages.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LatencyBudget {
+    /// End-to-end target; 0 only checks the stages listed in `stage_ms`.
+    pub target_ms: u64,
+    /// Per-stage budgets. Stages not listed get their default share of the target.
+    pub stage_ms: BTreeMap<Stage, u64>,
+}
+
+/// Default split of the end-to-end target. Capture is the user speaking and VAD
+/// runs during it, so neither counts; `Inject` is covered by its two sub-stages.
+fn default_budget_share(stage: Stage) -> Option<f64> {
+    match stage {
+        Stage::Queue => Some(0.05),
+        Stage::Trim => Some(0.02),
+        Stage::SpeakerGate => Some(0.08),
+        Stage::Asr => Some(0.60),
+        Stage::Clean => Some(0.05),
+        Stage::Clipboard => Some(0.10),
+        Stage::KeyInject => Some(0.10),
+        Stage::Capture | Stage::Vad | Stage::Inject => None,
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StageOverrun {
+    pub stage: Stage,
+    pub actual_ms: u64,
+    pub budget_ms: u64,
+}
+
+/// A session that missed its latency budget.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatencyBudgetReport {
+    pub session_id: u64,
+    pub end_to_end_ms: u64,
+    pub target_ms: u64,
+    /// Stage furthest over its budget, or the slowest budgeted stage when none is.
+    pub culprit: Option<Stage>,
+    /// Stages over budget, worst first.
+    pub over_budget: Vec<StageOverrun>,
+}
+
+impl LatencyBudget {
+    pub fn is_enabled(&self) -> bool {
+        self.target_ms > 0 || !self.stage_ms.is_empty()
+    }
+
+    pub fn stage_budget_ms(&self, stage: Stage) -> Option<u64> {
+        if let Some(&budget) = self.stage_ms.get(&stage) {
+            return Some(budget);
+        }
+        if self.target_ms == 0 {
+            return None;
+        }
+        default_budget_share(stage).map(|share| (self.target_ms as f64 * share).round() as u64)
+    }
+
+    /// Compares a finished session against the budget. Returns a report when the
+    /// end-to-end target was missed or a stage with its own budget overran it.
+    pub fn check(&self, timeline: &SessionTimeline) -> Option<LatencyBudgetReport> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let mut over_budget = Vec::new();
+        let mut slowest: Option<(Stage, u64)> = None;
+        for timing in &timeline.stages {
+            let Some(budget_ms) = self.stage_budget_ms(timing.stage) else {
+                continue;
+            };
+            let actual_ms = timing.duration_us / 1_000;
+            if !matches!(slowest, Some((_, ms)) if ms >= actual_ms) {
+                slowest = Some((timing.stage, actual_ms));
+            }
+            if actual_ms > budget_ms {
+                over_budget.push(StageOverrun {
+                    stage: timing.stage,
+                    actual_ms,
+                    budget_ms,
+                });
+            }
+        }
+
+        let end_to_end_ms = timeline.end_to_end().as_millis() as u64;
+        let missed_target = self.target_ms > 0 && end_to_end_ms > self.target_ms;
+        let missed_stage = over_budget
+            .iter()
+            .any(|overrun| self.stage_ms.contains_key(&overrun.stage));
+        if !missed_target && !missed_stage {
+            return None;
+        }
+
+        over_budget.sort_by_key(|overrun| Reverse(overrun.actual_ms - overrun.budget_ms));
+        Some(LatencyBudgetReport {
+            session_id: timeline.session_id,
+            end_to_end_ms,
+            target_ms: self.target_ms,
+            culprit: over_budget
+                .first()
+                .map(|overrun| overrun.stage)
+                .or(slowest.map(|(stage, _)| stage)),
+            over_budget,
+        })
+    }
+}
+
+/// Why performance mode toggled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerformanceReason {
+    SlowSessions,
+    FastSessions,
+    CpuRecovered,
+}
+
+impl PerformanceReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PerformanceReason::SlowSessions => "slow-sessions",
+            PerformanceReason::FastSessions => "fast-sessions",
+            PerformanceReason::CpuRecovered => "cpu-recovered",
+        }
+    }
+}
+
+impl PerformancePolicy {
+    /// Normalizes out-of-range values read from settings.
+    pub fn sanitized(mut self) -> Self {
+        self.slow_cpu_percent = self.slow_cpu_percent.clamp(0.0, 100.0);
+        self.recover_cpu_percent = self.recover_cpu_percent.clamp(0.0, 100.0);
+        self.enter_after_slow_sessions = self.enter_after_slow_sessions.max(1);
+        self.exit_after_fast_sessions = self.exit_after_fast_sessions.max(1);
+        self.downshift_after_sessions = self.downshift_after_sessions.max(1);
+        self
+    }
+
+    pub fn hangover_cap(&self) -> Option<Duration> {
+        if self.shorten_vad_hangover {
+            Some(Duration::from_millis(self.hangover_cap_ms))
+        } else {
+            None
+        }
+    }
+
+    /// Names of the actions applied while performance mode is on.
+    pub fn actions(&self) -> Vec<&'static str> {
+        let mut actions = Vec::new();
+        if self.shorten_vad_hangover {
+            actions.push("shorten-vad-hangover");
+        }
+        if self.suggest_model_downshift {
+            actions.push("suggest-model-downshift");
+        }
+        actions
+    }
+
+    /// True exactly once per performance-mode episode, when it has lasted long enough
+    /// to warrant a lighter model.
+    pub fn wants_downshift(&self, metrics: &EngineMetrics) -> bool {
+        metrics.performance_mode
+            && metrics.sessions_in_performance_mode == self.downshift_after_sessions
+    }
+
+    /// Updates counters after a finished session. Returns the reason when the mode
+    /// toggled.
+    pub fn record_session(
+        &self,
+        metrics: &mut EngineMetrics,
+        latency: Duration,
+    ) -> Option<PerformanceReason> {
+        let reason = self.evaluate_session(metrics, latency);
+        if metrics.performance_mode {
+            metrics.sessions_in_performance_mode += 1;
+        } else {
+            metrics.sessions_in_performance_mode = 0;
+        }
+        reason
+    }
+
+    fn evaluate_session(
+        &self,
+        metrics: &mut EngineMetrics,
+        latency: Duration,
+    ) -> Option<PerformanceReason> {
+        metrics.last_latency = latency;
+        let slow = latency > Duration::from_millis(self.slow_latency_ms)
+            && metrics.average_cpu * 100.0 > self.slow_cpu_percent;
+
+        if slow {
+            metrics.consecutive_slow += 1;
+            metrics.consecutive_fast = 0;
+            if !metrics.performance_mode
+                && metrics.consecutive_slow >= self.enter_after_slow_sessions
+            {
+                metrics.performance_mode = true;
+                return Some(PerformanceReason::SlowSessions);
+            }
+        } else {
+            metrics.consecutive_slow = 0;
+            metrics.consecutive_fast += 1;
+            if metrics.performance_mode && metrics.consecutive_fast >= self.exit_after_fast_sessions
+            {
+                metrics.performance_mode = false;
+                return Some(PerformanceReason::FastSessions);
+            }
+        }
+        None
+    }
+
+    /// Updates CPU load from the background sampler; may end performance mode early.
+    pub fn record_cpu(
+        &self,
+        metrics: &mut EngineMetrics,
+        cpu_fraction: f32,
+    ) -> Option<PerformanceReason> {
+        metrics.average_cpu = cpu_fraction;
+        if metrics.performance_mode && metrics.average_cpu * 100.0 < self.recover_cpu_percent {
+            metrics.performance_mode = false;
+            metrics.consecutive_slow = 0;
+            metrics.consecutive_fast = 0;
+            metrics.sessions_in_performance_mode = 0;
+            return Some(PerformanceReason::CpuRecovered);
+        }
+        None
+    }
+}
+
+/// Next lighter ASR selection: float Whisper drops to int8 first, then the model
+/// size steps down. None when already at the lightest option or not on Whisper.
+pub fn downshift_selection(current: &AsrSelection) -> Option<AsrSelection> {
+    if current.asr_family != "whisper" {
+        return None;
+    }
+
+    let mut next = current.clone();
+    if current.whisper_precision == "float" {
+        next.whisper_precision = "int8".into();
+        return Some(next);
+    }
+
+    let position = WHISPER_DOWNSHIFT_ORDER
+        .iter()
+        .position(|model| *model == current.whisper_model)?;
+    next.whisper_model = (*WHISPER_DOWNSHIFT_ORDER.get(position + 1)?).into();
+    Some(next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enters_after_configured_slow_sessions_and_exits_with_hysteresis() {
+        let policy = PerformancePolicy {
+            enter_after_slow_sessions: 2,
+            exit_after_fast_sessions: 2,
+            recover_cpu_percent: 0.0,
+            ..PerformancePolicy::default()
+        };
+        let mut metrics = EngineMetrics {
+            average_cpu: 0.9,
+            ..EngineMetrics::default()
+        };
+        let slow = Duration::from_millis(2_500);
+        let fast = Duration::from_millis(300);
+
+        assert_eq!(policy.record_session(&mut metrics, slow), None);
+        assert_eq!(
+            policy.record_session(&mut metrics, slow),
+            Some(PerformanceReason::SlowSessions)
+        );
+        assert_eq!(policy.record_cpu(&mut metrics, 0.1), None);
+        assert_eq!(policy.record_session(&mut metrics, fast), None);
+        assert_eq!(
+            policy.record_session(&mut metrics, fast),
+            Some(PerformanceReason::FastSessions)
+        );
+        assert!(!metrics.performance_mode);
+    }
+
+    #[test]
+    fn latency_budget_names_the_stage_furthest_over() {
+        let origin = std::time::Instant::now();
+        let at = |ms: u64| origin + Duration::from_millis(ms);
+        let mut timeline = SessionTimeline::new(3, origin);
+        timeline.record(Stage::Capture, origin, Duration::from_millis(2_000));
+        timeline.record(Stage::Asr, at(2_000), Duration::from_millis(500));
+        timeline.record(Stage::Clipboard, at(2_500), Duration::from_millis(400));
+        timeline.record(Stage::KeyInject, at(2_900), Duration::from_millis(20));
+
+        let budget = LatencyBudget {
+            target_ms: 1_000,
+            ..LatencyBudget::default()
+        };
+        assert!(budget.check(&timeline).is_none());
+
+        let tight = LatencyBudget {
+            target_ms: 800,
+            ..LatencyBudget::default()
+        };
+        let report = tight.check(&timeline).expect("over budget");
+        assert_eq!(report.end_to_end_ms, 920);
+        assert_eq!(report.culprit, Some(Stage::Clipboard));
+        assert_eq!(report.over_budget.len(), 2);
+
+        let asr_only = LatencyBudget {
+            stage_ms: BTreeMap::from([(Stage::Asr, 300)]),
+            ..LatencyBudget::default()
+        };
+        let report = asr_only.check(&timeline).expect("asr over its own budget");
+        assert_eq!(report.culprit, Some(Stage::Asr));
+    }
+
+    #[test]
+    fn downshifts_precision_before_model_size() {
+        let selection = AsrSelection {
+            asr_family: "whisper".into(),
+            whisper_backend: "onnx".into(),
+            whisper_model: "small".into(),
+            whisper_model_language: "multi".into(),
+            whisper_precision: "float".into(),
+        };
+
+        let int8 = downshift_selection(&selection).expect("precision step");
+        assert_eq!(int8.whisper_precision, "int8");
+        assert_eq!(int8.whisper_model, "small");
+
+        let base = downshift_selection(&int8).expect("size step");
+        assert_eq!(base.whisper_model, "base");
+
+        let tiny = AsrSelection {
+            whisper_model: "tiny".into(),
+            ..base
+        };
+        assert!(downshift_selection(&tiny).is_none());
+    }
+}
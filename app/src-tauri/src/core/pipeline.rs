@@ -1,22 +1,44 @@
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use crossbeam_channel::{bounded, unbounded, Receiver, Sender};
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use sysinfo::System;
-use tauri::AppHandle;
-use tracing::{info, warn};
-
-use crate::asr::{AsrConfig, AsrEngine, RecognitionResult};
-use crate::audio::{AudioEvent, AudioPipeline, AudioPipelineConfig, AudioPreprocessor};
-use crate::core::events;
-use crate::llm::{AutocleanMode, AutocleanService};
+use tauri::{AppHandle, Manager};
+use tracing::{debug, info, warn};
+
+use crate::asr::{AsrBackend, AsrConfig, AsrEngine, RecognitionResult, WeightLoadReport};
+use crate::audio::bluetooth::{self, ProfileSwitch};
+use crate::audio::{AudioEvent, AudioPipeline, AudioPipelineConfig, AudioPreprocessor, FramePool};
+use crate::core::debug_capture;
+use crate::core::events::{self, TrimCheckPayload, TrimReportPayload, TrimSegmentPayload};
+use crate::core::failed_audio;
+use crate::core::history::HistoryStore;
+use crate::core::i18n;
+use crate::core::performance::{PerformancePolicy, PerformanceReason};
+use crate::core::simulation::SimulatedInput;
+use crate::core::thermal::{self, ThermalMonitor, ThermalSample};
+use crate::core::timing::{self, SessionTimeline, Stage};
+use crate::core::voice_commands::{self, CommandAction, CommandRegistry, RegisteredCommand};
+use crate::llm::{
+    AutocleanMode, AutocleanService, FormattingLocale, PauseThresholds, PolishConfig,
+    RedactionRules, ReplacementRules,
+};
 #[cfg(debug_assertions)]
 use crate::output::logs;
-use crate::output::{OutputAction, OutputInjector, PasteShortcut};
-use crate::vad::{VadBackend, VadConfig, VadDecision, VadObservation, VoiceActivityDetector};
+use crate::output::notify;
+use crate::output::sound::{self, SoundCue};
+use crate::output::tray::{self, TrayBadge};
+use crate::output::{x11, OutputAction, OutputInjector, PasteShortcut};
+use crate::vad::{
+    SpeakerGate, VadBackend, VadConfig, VadDecision, VadObservation, VoiceActivityDetector,
+};
 
 struct DiagnosticsState {
     last_emit: Instant,
@@ -26,6 +48,62 @@ struct DiagnosticsState {
     vad: Option<VadObservation>,
 }
 
+/// Rolling RMS/peak history behind the `hud-levels` event, one point per interval.
+#[derive(Debug)]
+struct HudLevelsState {
+    last_point: Instant,
+    last_frame: Instant,
+    frames: u32,
+    rms_sum: f32,
+    peak_max: f32,
+    rms: VecDeque<f32>,
+    peak: VecDeque<f32>,
+}
+
+impl HudLevelsState {
+    fn new(now: Instant) -> Self {
+        Self {
+            last_point: now,
+            last_frame: now,
+            frames: 0,
+            rms_sum: 0.0,
+            peak_max: 0.0,
+            rms: VecDeque::with_capacity(HUD_LEVELS_LEN),
+            peak: VecDeque::with_capacity(HUD_LEVELS_LEN),
+        }
+    }
+
+    /// Adds a frame's levels; returns the history (oldest first) when a point was added.
+    fn record(&mut self, rms: f32, peak: f32, now: Instant) -> Option<(Vec<f32>, Vec<f32>)> {
+        if now.duration_since(self.last_frame) >= HUD_LEVELS_RESET_GAP {
+            *self = Self::new(now);
+        }
+        self.last_frame = now;
+        self.frames += 1;
+        self.rms_sum += rms;
+        self.peak_max = self.peak_max.max(peak);
+        if now.duration_since(self.last_point) < HUD_LEVELS_INTERVAL {
+            return None;
+        }
+
+        if self.rms.len() == HUD_LEVELS_LEN {
+            self.rms.pop_front();
+            self.peak.pop_front();
+        }
+        self.rms
+            .push_back((self.rms_sum / self.frames as f32).clamp(0.0, 1.0));
+        self.peak.push_back(self.peak_max.clamp(0.0, 1.0));
+        self.frames = 0;
+        self.rms_sum = 0.0;
+        self.peak_max = 0.0;
+        self.last_point = now;
+        Some((
+            self.rms.iter().copied().collect(),
+            self.peak.iter().copied().collect(),
+        ))
+    }
+}
+
 #[derive(Debug)]
 struct AudioWatchdogState {
     last_frame_ingress: Instant,
@@ -36,7 +114,9 @@ struct AudioWatchdogState {
 
 #[derive(Debug, Clone, Copy)]
 struct NoOutputReason {
+    /// Stable reason code; the user-facing text is the `no-output-<code>` message.
     code: &'static str,
+    /// English text for logs and for codes without a catalog entry.
     message: &'static str,
 }
 
@@ -44,8 +124,26 @@ const VAD_MIN_SPEECH_MS: u64 = 350;
 const VAD_PRE_ROLL_MS: u64 = 200;
 const VAD_POST_ROLL_MS: u64 = 500;
 const VAD_MAX_TRAILING_SILENCE_MS: u64 = 600;
+/// Pauses at least this long split a dictation into separately decoded segments.
+const VAD_SEGMENT_SPLIT_GAP_MS: u64 = 700;
+/// Silence kept on each side of a split pause; the rest of the pause is not decoded.
+const VAD_SEGMENT_KEEP_GAP_MS: u64 = 150;
+const STANDBY_PREROLL_MS: u64 = 300;
+/// In continuous mode, silence buffered beyond this is dropped down to the standby pre-roll.
+const CONTINUOUS_IDLE_MAX_MS: u64 = 5_000;
+/// Frame length simulated audio is fed in, matching the capture callback.
+const SIMULATED_FRAME_MS: u64 = 20;
+const DEFAULT_DIAGNOSTICS_INTERVAL: Duration = Duration::from_millis(250);
+/// Points in the overlay's level waveform, and how often a new one is added (~30 Hz).
+const HUD_LEVELS_LEN: usize = 30;
+const HUD_LEVELS_INTERVAL: Duration = Duration::from_millis(33);
+/// A gap this long between frames means a new session; the waveform starts flat.
+const HUD_LEVELS_RESET_GAP: Duration = Duration::from_millis(500);
 const AUDIO_INGRESS_STALE_THRESHOLD: Duration = Duration::from_secs(2);
 const AUDIO_WATCHDOG_TICK: Duration = Duration::from_millis(500);
+const AUDIO_WATCHDOG_IDLE_TICK: Duration = Duration::from_secs(2);
+const CPU_SAMPLER_TICK: Duration = Duration::from_secs(2);
+const CPU_SAMPLER_IDLE_TICK: Duration = Duration::from_secs(10);
 
 #[derive(Debug, Default)]
 struct VadTrimState {
@@ -54,6 +152,10 @@ struct VadTrimState {
     first_active: Option<usize>,
     last_active: Option<usize>,
     active_samples: usize,
+    /// Contiguous runs of active frames, in absolute sample positions.
+    speech_runs: Vec<(usize, usize)>,
+    /// Time spent evaluating the VAD this session.
+    vad_time: Duration,
 }
 
 impl VadTrimState {
@@ -71,6 +173,10 @@ impl VadTrimState {
             }
             self.last_active = Some(end);
             self.active_samples = self.active_samples.saturating_add(frame_samples);
+            match self.speech_runs.last_mut() {
+                Some(run) if run.1 == start => run.1 = end,
+                _ => self.speech_runs.push((start, end)),
+            }
         }
 
         self.total_samples = end;
@@ -82,14 +188,97 @@ impl VadTrimState {
         }
         self.buffer_start = self.buffer_start.saturating_add(dropped);
     }
+
+    /// Where a continuous-mode capture stands after the latest frame. An utterance ends
+    /// once `end_silence` follows at least `min_speech` of speech; activity shorter
+    /// than that, and silence beyond `idle_max`, is dropped down to `idle_keep`.
+    fn utterance_boundary(
+        &self,
+        min_speech: usize,
+        end_silence: usize,
+        idle_keep: usize,
+        idle_max: usize,
+    ) -> UtteranceBoundary {
+        let buffered = self.total_samples.saturating_sub(self.buffer_start);
+        if let Some(last) = self.last_active {
+            if self.total_samples.saturating_sub(last) < end_silence {
+                return UtteranceBoundary::Pending;
+            }
+            if self.active_samples >= min_speech {
+                return UtteranceBoundary::Ended;
+            }
+            // A blip too short to be speech: drop it with the silence around it.
+            return UtteranceBoundary::Idle {
+                excess: buffered.saturating_sub(idle_keep),
+            };
+        }
+        if buffered > idle_max {
+            return UtteranceBoundary::Idle {
+                excess: buffered - idle_keep,
+            };
+        }
+        UtteranceBoundary::Pending
+    }
+
+    /// Forgets `dropped` leading samples of idle audio and any activity seen in them.
+    fn discard_idle(&mut self, dropped: usize) {
+        self.note_buffer_drop(dropped);
+        self.first_active = None;
+        self.last_active = None;
+        self.active_samples = 0;
+        self.speech_runs.clear();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UtteranceBoundary {
+    /// Speech may still be coming.
+    Pending,
+    /// Speech was followed by enough silence; finalize the capture.
+    Ended,
+    /// No speech in the buffer; drop this many leading samples.
+    Idle { excess: usize },
+}
+
+/// Audio and trim bookkeeping for one finished capture, owned by the finalize queue so
+/// the shared capture buffer is free for the next session immediately.
+struct CapturedSession {
+    id: u64,
+    sample_rate: u32,
+    samples: Vec<f32>,
+    trim: VadTrimState,
+    timeline: SessionTimeline,
+    /// Transcript supplied by a simulated dictation; trim and ASR are skipped.
+    transcript: Option<String>,
+    kind: SessionKind,
+}
+
+/// Whether a session's transcript is pasted or matched against voice commands.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SessionKind {
+    #[default]
+    Dictation,
+    Command,
+}
+
+struct FinalizeJob {
+    session: CapturedSession,
+    done: Sender<()>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct EngineMetrics {
     pub last_latency: Duration,
     pub consecutive_slow: u32,
+    /// Normal-speed sessions since the last slow one; drives the exit hysteresis.
+    pub consecutive_fast: u32,
+    /// Sessions finished while performance mode has stayed on.
+    pub sessions_in_performance_mode: u32,
     pub performance_mode: bool,
     pub average_cpu: f32,
+    /// The CPU is thermal-throttling and decode threads are capped.
+    pub thermal_throttled: bool,
+    pub cpu_temp_c: Option<f32>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -110,8 +299,12 @@ impl Default for EngineMetrics {
         Self {
             last_latency: Duration::from_millis(0),
             consecutive_slow: 0,
+            consecutive_fast: 0,
+            sessions_in_performance_mode: 0,
             performance_mode: false,
             average_cpu: 0.0,
+            thermal_throttled: false,
+            cpu_temp_c: None,
         }
     }
 }
@@ -131,13 +324,59 @@ struct SpeechPipelineInner {
     autoclean: AutocleanService,
     injector: OutputInjector,
     output_mode: Mutex<OutputMode>,
+    confirm_before_paste: AtomicBool,
+    paste_fallback_shift_insert: AtomicBool,
+    /// Type transcripts out key by key instead of pasting them.
+    type_out: AtomicBool,
+    /// Send the whole buffer to ASR when the VAD finds too little speech.
+    always_transcribe: AtomicBool,
+    /// Cut and deliver each utterance when the VAD hears it end, while listening.
+    continuous: AtomicBool,
+    /// Silence that ends an utterance in continuous mode.
+    continuous_end_silence: Mutex<Duration>,
+    /// Show the delivered text on the HUD after a successful paste.
+    transcript_toast: AtomicBool,
+    redaction: Mutex<RedactionRules>,
+    redact_before_paste: AtomicBool,
+    /// Move a Bluetooth headset to its hands-free profile for each session.
+    bluetooth_auto_profile: AtomicBool,
+    bluetooth_switch: Mutex<Option<ProfileSwitch>>,
+    /// Kind of the session being captured; reset to dictation when it ends.
+    session_kind: Mutex<SessionKind>,
+    voice_commands: Mutex<CommandRegistry>,
+    pending_transcript: Mutex<Option<String>>,
     metrics: Arc<Mutex<EngineMetrics>>,
+    performance_policy: Mutex<PerformancePolicy>,
     mode: Arc<Mutex<AutocleanMode>>,
     app: AppHandle,
     audio_thread: Mutex<Option<std::thread::JoinHandle<()>>>,
     listening: AtomicBool,
+    /// Held by whoever pushes into the ASR sample ring: the audio thread while
+    /// listening, or a simulated session. Keeps the ring single-producer.
+    producer: Mutex<()>,
+    capture_started: Mutex<Option<Instant>>,
+    /// Idle frames from an on-demand device, with when each arrived.
+    standby_frames: Mutex<VecDeque<(Instant, Vec<f32>)>>,
+    frame_pool: FramePool,
+    session_seq: AtomicU64,
+    /// Captured sessions are finalized one at a time, in capture order.
+    finalize_tx: Sender<FinalizeJob>,
+    queued_sessions: AtomicUsize,
+    /// Set on app exit; queued sessions are dropped and the watchdog stands down.
+    shutting_down: AtomicBool,
     diagnostics: Mutex<DiagnosticsState>,
+    hud_levels: Mutex<HudLevelsState>,
+    /// None disables audio/VAD diagnostics entirely.
+    diagnostics_interval: Mutex<Option<Duration>>,
+    /// Where to write a Chrome trace per session; None disables export.
+    trace_export_dir: Mutex<Option<PathBuf>>,
+    /// Raw and delivered transcript of the session being finalized, for the debug capture.
+    captured_transcripts: Mutex<Option<(String, String)>>,
     audio_watchdog: Mutex<AudioWatchdogState>,
+    speaker_gate: Mutex<Option<Arc<SpeakerGate>>>,
+    history: Mutex<Option<Arc<HistoryStore>>>,
+    /// Collects preprocessed audio outside of dictation while speaker enrollment runs.
+    enrollment: Mutex<Option<Vec<f32>>>,
 }
 
 impl SpeechPipeline {
@@ -149,9 +388,13 @@ impl SpeechPipeline {
     ) -> Self {
         let preprocessor = AudioPreprocessor::new();
         let audio = AudioPipeline::spawn(audio_config);
+        let frame_pool = audio.frame_pool();
         let vad = VoiceActivityDetector::new(vad_config.clone());
         let injector = OutputInjector::new();
         injector.prewarm();
+        let autoclean = AutocleanService::new();
+        autoclean.set_code_switching(!asr_config.code_switch_languages.is_empty());
+        let (finalize_tx, finalize_rx) = unbounded();
         let inner = Arc::new(SpeechPipelineInner {
             audio,
             preprocessor: Mutex::new(preprocessor),
@@ -159,14 +402,40 @@ impl SpeechPipeline {
             vad_default_hangover: Mutex::new(vad_config.hangover),
             vad_trim: Mutex::new(VadTrimState::default()),
             asr: AsrEngine::new(asr_config),
-            autoclean: AutocleanService::new(),
+            autoclean,
             injector,
             output_mode: Mutex::new(OutputMode::default()),
+            confirm_before_paste: AtomicBool::new(false),
+            paste_fallback_shift_insert: AtomicBool::new(false),
+            type_out: AtomicBool::new(false),
+            always_transcribe: AtomicBool::new(false),
+            continuous: AtomicBool::new(false),
+            continuous_end_silence: Mutex::new(Duration::from_millis(900)),
+            transcript_toast: AtomicBool::new(false),
+            redaction: Mutex::new(RedactionRules::default()),
+            redact_before_paste: AtomicBool::new(false),
+            bluetooth_auto_profile: AtomicBool::new(false),
+            bluetooth_switch: Mutex::new(None),
+            session_kind: Mutex::new(SessionKind::default()),
+            voice_commands: Mutex::new(CommandRegistry::default()),
+            pending_transcript: Mutex::new(None),
             metrics: Arc::new(Mutex::new(EngineMetrics::default())),
+            performance_policy: Mutex::new(PerformancePolicy::default()),
             mode: Arc::new(Mutex::new(AutocleanMode::Fast)),
             app,
             audio_thread: Mutex::new(None),
             listening: AtomicBool::new(false),
+            producer: Mutex::new(()),
+            capture_started: Mutex::new(None),
+            standby_frames: Mutex::new(VecDeque::new()),
+            frame_pool,
+            session_seq: AtomicU64::new(0),
+            finalize_tx,
+            queued_sessions: AtomicUsize::new(0),
+            shutting_down: AtomicBool::new(false),
+            diagnostics_interval: Mutex::new(Some(DEFAULT_DIAGNOSTICS_INTERVAL)),
+            trace_export_dir: Mutex::new(None),
+            captured_transcripts: Mutex::new(None),
             diagnostics: Mutex::new(DiagnosticsState {
                 last_emit: Instant::now(),
                 frames: 0,
@@ -174,6 +443,10 @@ impl SpeechPipeline {
                 peak_max: 0.0,
                 vad: None,
             }),
+            hud_levels: Mutex::new(HudLevelsState::new(Instant::now())),
+            speaker_gate: Mutex::new(None),
+            history: Mutex::new(None),
+            enrollment: Mutex::new(None),
             audio_watchdog: Mutex::new(AudioWatchdogState {
                 last_frame_ingress: Instant::now(),
                 seen_frame: false,
@@ -183,14 +456,23 @@ impl SpeechPipeline {
         });
 
         SpeechPipelineInner::start_audio_loop(&inner);
+        SpeechPipelineInner::start_finalize_worker(&inner, finalize_rx);
         SpeechPipelineInner::start_cpu_sampler(&inner);
         SpeechPipelineInner::start_audio_watchdog(&inner);
 
         Self { inner }
     }
 
-    pub fn audio_device_id(&self) -> Option<String> {
-        self.inner.audio.device_id()
+    pub fn audio_device_preferences(&self) -> Vec<String> {
+        self.inner.audio.device_preferences()
+    }
+
+    pub fn active_audio_device(&self) -> Option<String> {
+        self.inner.audio.active_device()
+    }
+
+    pub fn audio_on_demand(&self) -> bool {
+        self.inner.audio.on_demand()
     }
 
     pub fn set_mode(&self, mode: AutocleanMode) {
@@ -201,10 +483,26 @@ impl SpeechPipeline {
         self.inner.set_vad_config(config);
     }
 
+    pub fn set_polish_config(&self, config: PolishConfig) {
+        self.inner.autoclean.set_polish(config);
+    }
+
+    pub fn set_formatting_locale(&self, locale: Option<FormattingLocale>) {
+        self.inner.autoclean.set_locale(locale);
+    }
+
+    pub fn set_correction_rules(&self, rules: ReplacementRules) {
+        self.inner.autoclean.set_replacements(rules);
+    }
+
     pub fn set_paste_shortcut(&self, shortcut: PasteShortcut) {
         self.inner.set_paste_shortcut(shortcut);
     }
 
+    pub fn set_app_paste_shortcuts(&self, shortcuts: Vec<(String, PasteShortcut)>) {
+        self.inner.injector.set_app_paste_shortcuts(shortcuts);
+    }
+
     pub fn asr_config(&self) -> AsrConfig {
         self.inner.asr_config()
     }
@@ -213,21 +511,246 @@ impl SpeechPipeline {
         self.inner.set_listening(active);
     }
 
+    /// Sample rate of the capture stream in Hz.
+    pub fn sample_rate(&self) -> u32 {
+        self.inner.audio.sample_rate()
+    }
+
+    /// Stops capturing and queues the captured audio for finalization without waiting
+    /// for it. The returned receiver fires once this session's output was delivered;
+    /// None means nothing was being captured.
+    pub fn end_session(&self) -> Option<Receiver<()>> {
+        self.inner.end_session()
+    }
+
+    /// Runs a dictation from `input` instead of the microphone. The receiver fires once
+    /// it has been finalized.
+    pub fn simulate_session(&self, input: SimulatedInput) -> Result<Receiver<()>> {
+        self.inner.simulate_session(input)
+    }
+
+    /// Stops capturing and throws the captured audio away. Returns false when nothing
+    /// was being captured.
+    pub fn cancel_session(&self) -> bool {
+        self.inner.cancel_session()
+    }
+
+    /// Sessions captured but not yet finalized.
+    pub fn queued_sessions(&self) -> usize {
+        self.inner.queued_sessions.load(Ordering::SeqCst)
+    }
+
+    /// Stops the pipeline for app exit: discards a capture in progress, lets the
+    /// session being finalized finish within `grace` so a paste restores the
+    /// clipboard, drops the rest of the queue and joins the audio threads.
+    pub fn shutdown(&self, grace: Duration) {
+        self.inner.shutdown(grace)
+    }
+
     pub fn has_recent_audio_ingress(&self, max_age: Duration) -> bool {
         self.inner.has_recent_audio_ingress(max_age)
     }
 
+    pub fn audio_is_synthetic(&self) -> bool {
+        self.inner.audio.is_synthetic()
+    }
+
     pub fn set_output_mode(&self, mode: OutputMode) {
         self.inner.set_output_mode(mode);
     }
 
+    pub fn set_performance_policy(&self, policy: PerformancePolicy) {
+        *self.inner.performance_policy.lock() = policy;
+        let enabled = self.inner.metrics.lock().performance_mode;
+        self.inner.set_performance_override(enabled);
+    }
+
+    pub fn set_diagnostics_interval(&self, interval: Option<Duration>) {
+        *self.inner.diagnostics_interval.lock() = interval;
+    }
+
+    pub fn set_trace_export_dir(&self, dir: Option<PathBuf>) {
+        *self.inner.trace_export_dir.lock() = dir;
+    }
+
+    pub fn set_speaker_gate(&self, gate: Option<Arc<SpeakerGate>>) {
+        *self.inner.speaker_gate.lock() = gate;
+    }
+
+    /// None stops recording delivered transcripts.
+    pub fn set_history(&self, history: Option<Arc<HistoryStore>>) {
+        *self.inner.history.lock() = history;
+    }
+
+    /// Captures `duration` of microphone audio for speaker enrollment. Fails while a
+    /// dictation is in progress.
+    pub fn record_enrollment(&self, duration: Duration) -> Result<(u32, Vec<f32>)> {
+        if self.is_listening() {
+            return Err(anyhow!("finish the current dictation before enrolling"));
+        }
+        *self.inner.enrollment.lock() = Some(Vec::new());
+        if let Err(error) = self.inner.audio.begin_capture() {
+            *self.inner.enrollment.lock() = None;
+            return Err(error.context("open audio capture for enrollment"));
+        }
+        std::thread::sleep(duration);
+        let samples = self.inner.enrollment.lock().take().unwrap_or_default();
+        if !self.is_listening() {
+            self.inner.audio.end_capture();
+        }
+        Ok((self.inner.audio.sample_rate(), samples))
+    }
+
+    /// Punctuates pauses in Parakeet dictations, which come without punctuation.
+    pub fn set_pause_punctuation(&self, thresholds: Option<PauseThresholds>) {
+        self.inner.autoclean.set_pause_punctuation(thresholds);
+        self.inner.asr.set_segment_pieces(thresholds.is_some());
+    }
+
+    pub fn set_transcript_alternatives(&self, count: usize) {
+        self.inner.asr.set_alternatives(count);
+    }
+
+    pub fn set_confirm_before_paste(&self, enabled: bool) {
+        self.inner
+            .confirm_before_paste
+            .store(enabled, Ordering::SeqCst);
+    }
+
+    pub fn set_paste_fallback_shift_insert(&self, enabled: bool) {
+        self.inner
+            .paste_fallback_shift_insert
+            .store(enabled, Ordering::SeqCst);
+    }
+
+    pub fn set_type_out(&self, enabled: bool, char_delay_ms: u32, terminal_multiline_paste: bool) {
+        self.inner.type_out.store(enabled, Ordering::SeqCst);
+        self.inner.injector.set_type_char_delay(char_delay_ms);
+        self.inner
+            .injector
+            .set_terminal_multiline_paste(terminal_multiline_paste);
+    }
+
+    pub fn set_always_transcribe(&self, enabled: bool) {
+        self.inner
+            .always_transcribe
+            .store(enabled, Ordering::SeqCst);
+    }
+
+    /// Turns utterance cutting on or off for the session being captured.
+    pub fn set_continuous(&self, enabled: bool) {
+        self.inner.continuous.store(enabled, Ordering::SeqCst);
+    }
+
+    pub fn set_continuous_end_silence(&self, silence: Duration) {
+        *self.inner.continuous_end_silence.lock() = silence;
+    }
+
+    pub fn set_transcript_toast(&self, enabled: bool) {
+        self.inner.transcript_toast.store(enabled, Ordering::SeqCst);
+    }
+
+    pub fn set_redaction(&self, rules: RedactionRules, before_paste: bool) {
+        *self.inner.redaction.lock() = rules;
+        self.inner
+            .redact_before_paste
+            .store(before_paste, Ordering::SeqCst);
+    }
+
+    pub fn set_bluetooth_auto_profile(&self, enabled: bool) {
+        self.inner
+            .bluetooth_auto_profile
+            .store(enabled, Ordering::SeqCst);
+    }
+
+    pub fn set_voice_commands(&self, registry: CommandRegistry) {
+        *self.inner.voice_commands.lock() = registry;
+    }
+
+    /// Sets what the next captured session is for; call before `set_listening(true)`.
+    pub fn set_session_kind(&self, kind: SessionKind) {
+        *self.inner.session_kind.lock() = kind;
+    }
+
+    /// The transcript as it may be logged or stored: with redaction rules applied.
+    pub fn redact_for_storage(&self, text: &str) -> String {
+        self.inner.redact_for_storage(text)
+    }
+
+    pub fn has_pending_transcript(&self) -> bool {
+        self.inner.pending_transcript.lock().is_some()
+    }
+
+    /// Pastes the held transcript, replacing it with `edited` when the user changed it.
+    /// Returns false when nothing was pending.
+    pub fn confirm_pending_transcript(&self, edited: Option<String>) -> bool {
+        let Some(pending) = self.inner.pending_transcript.lock().take() else {
+            return false;
+        };
+        events::emit_pending_transcript(&self.inner.app, None);
+
+        let text = edited.unwrap_or(pending);
+        if text.trim().is_empty() {
+            return true;
+        }
+        let outcome = self.inner.paste_output(&text);
+        self.inner.record_history(&text, outcome);
+        true
+    }
+
+    /// Pastes a transcript from history into the focused window without recording it again.
+    pub fn repaste(&self, text: &str) {
+        self.inner.paste_output(text);
+    }
+
+    /// Copies the last pasted transcript back onto the clipboard. Returns the text copied,
+    /// or None when nothing has been delivered yet.
+    pub fn recopy_last_transcript(&self) -> Result<Option<String>> {
+        let Some(text) = self.inner.injector.last_delivered_text() else {
+            return Ok(None);
+        };
+        self.inner
+            .injector
+            .inject(&text, OutputAction::Copy, &PasteShortcut::default())
+            .map_err(|error| anyhow!(error.to_string()))?;
+        Ok(Some(text))
+    }
+
+    pub fn discard_pending_transcript(&self) -> bool {
+        let discarded = self.inner.pending_transcript.lock().take().is_some();
+        if discarded {
+            events::emit_pending_transcript(&self.inner.app, None);
+        }
+        discarded
+    }
+
     pub fn warmup_asr(&self) -> Result<()> {
-        self.inner.asr.warmup()?;
-        Ok(())
+        let warmed = self.inner.asr.warmup();
+        self.inner.report_gpu_fallback();
+        warmed
+    }
+
+    /// Runs ASR over a whole clip without cleaning or delivering the result.
+    pub fn transcribe_clip(&self, samples: &[f32]) -> Result<Option<RecognitionResult>> {
+        let segments = [0..samples.len()];
+        self.inner
+            .asr
+            .finalize_samples(self.sample_rate(), samples, &segments, &mut |_| {})
+    }
+
+    pub fn asr_load_report(&self) -> Option<WeightLoadReport> {
+        self.inner.asr.load_report()
     }
 }
 
 impl SpeechPipelineInner {
+    /// Tells the frontend when the model just loaded on the CPU instead of the GPU.
+    fn report_gpu_fallback(&self) {
+        if let Some(fallback) = self.asr.take_gpu_fallback() {
+            events::emit_asr_gpu_fallback(&self.app, fallback);
+        }
+    }
+
     fn start_audio_loop(this: &Arc<Self>) {
         let receiver = this.audio.subscribe();
         let weak = Arc::downgrade(this);
@@ -247,23 +770,59 @@ impl SpeechPipelineInner {
         *guard = Some(handle);
     }
 
+    fn start_finalize_worker(this: &Arc<Self>, receiver: Receiver<FinalizeJob>) {
+        let weak = Arc::downgrade(this);
+        let spawned = std::thread::Builder::new()
+            .name("dictation-finalize".into())
+            .spawn(move || {
+                for job in receiver.iter() {
+                    if let Some(inner) = weak.upgrade() {
+                        if inner.shutting_down.load(Ordering::SeqCst) {
+                            info!("dictation_session_dropped_on_exit id={}", job.session.id);
+                        } else {
+                            inner.finalize_session(job.session);
+                        }
+                        inner.queued_sessions.fetch_sub(1, Ordering::SeqCst);
+                    }
+                    let _ = job.done.send(());
+                }
+            });
+        if let Err(error) = spawned {
+            warn!("failed to start finalize worker: {error}");
+        }
+    }
+
     fn set_output_mode(&self, mode: OutputMode) {
         let mut guard = self.output_mode.lock();
         *guard = mode;
     }
 
+    /// A session is being captured or finalized. Background timers back off otherwise
+    /// to keep idle wakeups down.
+    fn is_busy(&self) -> bool {
+        self.listening.load(Ordering::Relaxed) || self.queued_sessions.load(Ordering::Relaxed) > 0
+    }
+
     fn start_cpu_sampler(this: &Arc<Self>) {
         let weak = Arc::downgrade(this);
         tauri::async_runtime::spawn(async move {
             let mut system = System::new();
             system.refresh_cpu_usage();
-            let mut interval = tokio::time::interval(Duration::from_secs(2));
+            let mut thermal = ThermalMonitor::discover();
             // The first measurement after refresh_cpu_usage is usually 0; wait a cycle.
-            interval.tick().await;
+            tokio::time::sleep(CPU_SAMPLER_TICK).await;
 
             loop {
-                interval.tick().await;
+                let tick = match weak.upgrade() {
+                    Some(inner) if inner.is_busy() => CPU_SAMPLER_TICK,
+                    Some(_) => CPU_SAMPLER_IDLE_TICK,
+                    None => break,
+                };
+                tokio::time::sleep(tick).await;
                 if let Some(inner) = weak.upgrade() {
+                    if thermal.is_available() {
+                        SpeechPipelineInner::record_thermal(&inner, thermal.sample());
+                    }
                     system.refresh_cpu_usage();
                     let usage = system.global_cpu_info().cpu_usage() / 100.0;
                     inner.record_cpu_load(usage.clamp(0.0, 1.0));
@@ -277,10 +836,14 @@ impl SpeechPipelineInner {
     fn start_audio_watchdog(this: &Arc<Self>) {
         let weak = Arc::downgrade(this);
         tauri::async_runtime::spawn(async move {
-            let mut interval = tokio::time::interval(AUDIO_WATCHDOG_TICK);
-            interval.tick().await;
+            tokio::time::sleep(AUDIO_WATCHDOG_TICK).await;
             loop {
-                interval.tick().await;
+                let tick = match weak.upgrade() {
+                    Some(inner) if inner.is_busy() => AUDIO_WATCHDOG_TICK,
+                    Some(_) => AUDIO_WATCHDOG_IDLE_TICK,
+                    None => break,
+                };
+                tokio::time::sleep(tick).await;
                 let Some(inner) = weak.upgrade() else {
                     break;
                 };
@@ -290,7 +853,10 @@ impl SpeechPipelineInner {
     }
 
     fn tick_audio_watchdog(&self) {
-        if self.audio.is_synthetic() {
+        if self.shutting_down.load(Ordering::SeqCst)
+            || self.audio.is_synthetic()
+            || !self.audio.capture_open()
+        {
             return;
         }
 
@@ -359,7 +925,8 @@ impl SpeechPipelineInner {
     }
 
     fn has_recent_audio_ingress(&self, max_age: Duration) -> bool {
-        if self.audio.is_synthetic() {
+        // A closed on-demand device is expected to be silent; it opens on session start.
+        if self.audio.is_synthetic() || !self.audio.capture_open() {
             return true;
         }
         let guard = self.audio_watchdog.lock();
@@ -371,33 +938,29 @@ impl SpeechPipelineInner {
             AudioEvent::Frame(mut samples) => {
                 self.note_audio_ingress();
                 if !self.listening.load(Ordering::Relaxed) {
+                    if let Some(buffer) = self.enrollment.lock().as_mut() {
+                        self.preprocessor.lock().process(&mut samples);
+                        buffer.extend_from_slice(&samples);
+                        self.frame_pool.release(samples);
+                        return Ok(());
+                    }
+                    if self.audio.on_demand() {
+                        self.record_standby_frame(samples);
+                    } else {
+                        self.frame_pool.release(samples);
+                    }
                     return Ok(());
                 }
 
-                {
-                    let mut preprocessor = self.preprocessor.lock();
-                    preprocessor.process(&mut samples);
-                }
-
-                let vad_observation = {
-                    let mut detector = self.vad.lock();
-                    detector.evaluate(&samples)
-                };
-
-                self.record_diagnostics(&samples, vad_observation);
-
-                {
-                    let mut trim = self.vad_trim.lock();
-                    trim.record(vad_observation.decision, samples.len());
-                }
-
-                // Always buffer audio while listening. VAD is used for diagnostics
-                // and trimming, but shouldn't block push-to-talk dictation.
-                let dropped = self.asr.push_samples(&samples);
-                if dropped > 0 {
-                    let mut trim = self.vad_trim.lock();
-                    trim.note_buffer_drop(dropped);
+                // A simulated session may have started since the session ended.
+                let _producer = self.producer.lock();
+                if !self.listening.load(Ordering::SeqCst) {
+                    self.frame_pool.release(samples);
+                    return Ok(());
                 }
+                self.ingest_preroll();
+                self.ingest_samples(&mut samples);
+                self.frame_pool.release(samples);
                 Ok(())
             }
             AudioEvent::Stopped => {
@@ -407,10 +970,168 @@ impl SpeechPipelineInner {
         }
     }
 
+    /// Keeps the last few hundred ms of idle audio while an on-demand device is open,
+    /// so speech that starts right at the hotkey press isn't clipped.
+    fn record_standby_frame(&self, samples: Vec<f32>) {
+        let max_samples = (STANDBY_PREROLL_MS * self.audio.sample_rate() as u64 / 1000) as usize;
+        let mut standby = self.standby_frames.lock();
+        standby.push_back((Instant::now(), samples));
+        let mut total: usize = standby.iter().map(|(_, frame)| frame.len()).sum();
+        while total > max_samples {
+            match standby.pop_front() {
+                Some((_, frame)) => {
+                    total -= frame.len();
+                    self.frame_pool.release(frame);
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Feeds the standby pre-roll in ahead of the first live frame of a session. Runs on
+    /// the audio thread, so the pre-roll can't interleave with live frames. Frames from
+    /// before the session's pre-roll window, e.g. left over from before the on-demand
+    /// device last closed, are dropped.
+    fn ingest_preroll(&self) {
+        let preroll: Vec<(Instant, Vec<f32>)> = {
+            let mut standby = self.standby_frames.lock();
+            if standby.is_empty() {
+                return;
+            }
+            standby.drain(..).collect()
+        };
+        let started = self.capture_started.lock().unwrap_or_else(Instant::now);
+        let window = Duration::from_millis(STANDBY_PREROLL_MS);
+        for (received, mut frame) in preroll {
+            if started.saturating_duration_since(received) <= window {
+                self.ingest_samples(&mut frame);
+            }
+            self.frame_pool.release(frame);
+        }
+    }
+
+    fn clear_standby_frames(&self) {
+        let stale: Vec<(Instant, Vec<f32>)> = self.standby_frames.lock().drain(..).collect();
+        for (_, frame) in stale {
+            self.frame_pool.release(frame);
+        }
+    }
+
+    fn ingest_samples(&self, samples: &mut [f32]) {
+        {
+            let mut preprocessor = self.preprocessor.lock();
+            preprocessor.process(samples);
+        }
+        let samples: &[f32] = samples;
+
+        let vad_started = Instant::now();
+        let vad_observation = {
+            let _span = Stage::Vad.span(0).entered();
+            let mut detector = self.vad.lock();
+            detector.evaluate(samples)
+        };
+        let vad_time = vad_started.elapsed();
+
+        self.record_diagnostics(samples, vad_observation);
+
+        {
+            let mut trim = self.vad_trim.lock();
+            trim.record(vad_observation.decision, samples.len());
+            trim.vad_time += vad_time;
+        }
+
+        // Always buffer audio while listening. VAD is used for diagnostics
+        // and trimming, but shouldn't block push-to-talk dictation.
+        let dropped = self.asr.push_samples(samples);
+        if dropped > 0 {
+            let (first, total) = {
+                let mut trim = self.vad_trim.lock();
+                let first = trim.buffer_start == 0;
+                trim.note_buffer_drop(dropped);
+                (first, trim.buffer_start)
+            };
+            self.report_buffer_overflow(first, total);
+        }
+
+        if self.continuous.load(Ordering::Relaxed) && self.listening.load(Ordering::Relaxed) {
+            self.cut_continuous_utterance();
+        }
+    }
+
+    /// Finalizes the buffered utterance once the speaker pauses, keeping capture open
+    /// for the next one, and keeps idle audio from piling up between utterances.
+    fn cut_continuous_utterance(&self) {
+        let sample_rate = u64::from(self.audio.sample_rate());
+        let samples_for =
+            |duration: Duration| (duration.as_millis() as u64 * sample_rate / 1000) as usize;
+        let end_silence = *self.continuous_end_silence.lock();
+        let boundary = self.vad_trim.lock().utterance_boundary(
+            samples_for(Duration::from_millis(VAD_MIN_SPEECH_MS)),
+            samples_for(end_silence),
+            samples_for(Duration::from_millis(STANDBY_PREROLL_MS)),
+            samples_for(Duration::from_millis(CONTINUOUS_IDLE_MAX_MS)),
+        );
+        match boundary {
+            UtteranceBoundary::Pending => {}
+            UtteranceBoundary::Ended => {
+                let session = self.take_capture();
+                self.reset_vad();
+                *self.capture_started.lock() = Some(Instant::now());
+                debug!("continuous_utterance_cut id={}", session.id);
+                // Delivery is reported through the usual finalize events.
+                let _ = self.enqueue_session(session);
+            }
+            UtteranceBoundary::Idle { excess } => {
+                let mut buffered = self.asr.take_samples();
+                let excess = excess.min(buffered.len());
+                buffered.drain(..excess);
+                self.asr.push_samples(&buffered);
+                self.vad_trim.lock().discard_idle(excess);
+                *self.capture_started.lock() = Some(Instant::now());
+            }
+        }
+    }
+
+    fn report_buffer_overflow(&self, first: bool, dropped_samples: usize) {
+        let cap_secs = self.asr.config().max_session_secs;
+        let sample_rate = u64::from(self.audio.sample_rate().max(1));
+        let dropped_ms = dropped_samples as u64 * 1_000 / sample_rate;
+        if first {
+            warn!("asr_buffer_overflow cap_secs={cap_secs}");
+            events::emit_hud_toast(
+                &self.app,
+                &i18n::message(
+                    "notify-buffer-overflow",
+                    &[("seconds", &cap_secs.to_string())],
+                ),
+            );
+        }
+        events::emit_buffer_overflow(
+            &self.app,
+            events::BufferOverflowPayload {
+                dropped_samples,
+                dropped_ms,
+                cap_secs,
+            },
+        );
+    }
+
     fn record_diagnostics(&self, samples: &[f32], vad: VadObservation) {
         let (rms, peak) = compute_rms_peak(samples);
         let now = Instant::now();
 
+        // The overlay waveform runs whether or not the debug panel is listening.
+        if let Some((rms, peak)) = self.hud_levels.lock().record(rms, peak, now) {
+            events::emit_hud_levels(&self.app, events::HudLevelsPayload { rms, peak });
+        }
+
+        let Some(interval) = *self.diagnostics_interval.lock() else {
+            return;
+        };
+        if !events::diagnostics_subscribed() {
+            return;
+        }
+
         let (avg_rms, peak_max, vad_snapshot, should_emit) = {
             let mut diag = self.diagnostics.lock();
             diag.frames = diag.frames.saturating_add(1);
@@ -418,7 +1139,7 @@ impl SpeechPipelineInner {
             diag.peak_max = diag.peak_max.max(peak);
             diag.vad = Some(vad);
 
-            let should_emit = now.duration_since(diag.last_emit) >= Duration::from_millis(250);
+            let should_emit = now.duration_since(diag.last_emit) >= interval;
             if !should_emit {
                 return;
             }
@@ -441,10 +1162,11 @@ impl SpeechPipelineInner {
                 &self.app,
                 events::AudioDiagnosticsPayload {
                     sample_rate: self.audio.sample_rate(),
-                    device_id: self.audio.device_id(),
+                    device_id: self.audio.active_device(),
                     synthetic: self.audio.is_synthetic(),
                     rms: avg_rms,
                     peak: peak_max,
+                    warning: bluetooth::last_input().map(|input| input.warning()),
                 },
             );
 
@@ -469,52 +1191,91 @@ impl SpeechPipelineInner {
     }
 
     fn update_metrics(&self, latency: Duration) {
+        let policy = self.performance_policy.lock().clone();
         let mut metrics = self.metrics.lock();
-        metrics.last_latency = latency;
-
-        if latency > Duration::from_secs(2) && metrics.average_cpu > 0.75 {
-            metrics.consecutive_slow += 1;
-            if metrics.consecutive_slow >= 2 && !metrics.performance_mode {
-                metrics.performance_mode = true;
-                self.set_performance_override(true);
-                warn!("Entering performance warning mode");
-                events::emit_performance_warning(&self.app, &*metrics);
-                #[cfg(debug_assertions)]
-                logs::push_log(format!(
-                    "Performance warning: latency={}ms cpu={:.1}%",
-                    latency.as_millis(),
-                    metrics.average_cpu * 100.0
-                ));
-            }
-        } else {
-            metrics.consecutive_slow = 0;
-            if metrics.performance_mode {
-                info!("recovering from performance warning");
-                metrics.performance_mode = false;
-                self.set_performance_override(false);
-                events::emit_performance_recovered(&self.app, &*metrics);
-                #[cfg(debug_assertions)]
-                logs::push_log("Performance recovered".to_string());
-            }
+        if let Some(reason) = policy.record_session(&mut metrics, latency) {
+            self.apply_performance_change(&policy, &metrics, reason);
+        }
+        if policy.wants_downshift(&metrics) {
+            let app = self.app.clone();
+            tauri::async_runtime::spawn_blocking(move || {
+                if let Some(state) = app.try_state::<crate::core::app_state::AppState>() {
+                    state.handle_sustained_slowness(&app);
+                }
+            });
         }
 
         events::emit_metrics(&self.app, &*metrics);
     }
 
     fn record_cpu_load(&self, cpu_fraction: f32) {
+        let policy = self.performance_policy.lock().clone();
         let mut metrics = self.metrics.lock();
-        metrics.average_cpu = cpu_fraction;
-        if metrics.average_cpu < 0.75 && metrics.performance_mode {
-            metrics.performance_mode = false;
-            metrics.consecutive_slow = 0;
-            info!("Performance warning cleared by CPU recovery");
-            self.set_performance_override(false);
-            events::emit_performance_recovered(&self.app, &*metrics);
+        if let Some(reason) = policy.record_cpu(&mut metrics, cpu_fraction) {
+            self.apply_performance_change(&policy, &metrics, reason);
         }
 
         events::emit_metrics(&self.app, &*metrics);
     }
 
+    /// Caps decode threads while the CPU throttles. Metrics go out with the next CPU
+    /// sample.
+    fn record_thermal(this: &Arc<Self>, sample: ThermalSample) {
+        {
+            let mut metrics = this.metrics.lock();
+            metrics.cpu_temp_c = sample.temp_c;
+            metrics.thermal_throttled = sample.throttled;
+        }
+        if !sample.changed {
+            return;
+        }
+
+        let threads = sample
+            .throttled
+            .then(|| thermal::throttled_threads(this.asr.config().num_threads));
+        this.asr.set_thread_cap(threads);
+        let payload = events::ThermalThrottlePayload::new(&sample, threads);
+        if sample.throttled {
+            warn!(
+                "thermal_throttle_start temp_c={:?} trip_c={:?} decode_threads={:?}",
+                sample.temp_c, sample.trip_c, threads
+            );
+        } else {
+            info!("thermal_throttle_end temp_c={:?}", sample.temp_c);
+        }
+        #[cfg(debug_assertions)]
+        logs::push_log(format!("Thermal: {}", payload.detail));
+        events::emit_thermal_throttle(&this.app, payload);
+
+        let inner = Arc::clone(this);
+        tauri::async_runtime::spawn_blocking(move || {
+            if let Err(error) = inner.asr.reload_for_thread_cap() {
+                warn!("thermal_reload_failed error={error:#}");
+            }
+        });
+    }
+
+    fn apply_performance_change(
+        &self,
+        policy: &PerformancePolicy,
+        metrics: &EngineMetrics,
+        reason: PerformanceReason,
+    ) {
+        let payload = events::PerformanceModePayload::new(metrics, policy, reason);
+        self.set_performance_override(metrics.performance_mode);
+        if metrics.performance_mode {
+            warn!("Entering performance warning mode: {}", payload.detail);
+            #[cfg(debug_assertions)]
+            logs::push_log(format!("Performance warning: {}", payload.detail));
+            events::emit_performance_warning(&self.app, payload);
+        } else {
+            info!("recovering from performance warning: {}", payload.detail);
+            #[cfg(debug_assertions)]
+            logs::push_log(format!("Performance recovered: {}", payload.detail));
+            events::emit_performance_recovered(&self.app, payload);
+        }
+    }
+
     fn set_mode(&self, mode: AutocleanMode) {
         let mut guard = self.mode.lock();
         *guard = mode;
@@ -529,14 +1290,12 @@ impl SpeechPipelineInner {
     }
 
     fn set_performance_override(&self, enabled: bool) {
-        {
-            let mut vad = self.vad.lock();
-            let default = *self.vad_default_hangover.lock();
-            if enabled {
-                vad.set_hangover(default.min(Duration::from_millis(200)));
-            } else {
-                vad.set_hangover(default);
-            }
+        let cap = self.performance_policy.lock().hangover_cap();
+        let mut vad = self.vad.lock();
+        let default = *self.vad_default_hangover.lock();
+        match cap {
+            Some(cap) if enabled => vad.set_hangover(default.min(cap)),
+            _ => vad.set_hangover(default),
         }
     }
 
@@ -567,13 +1326,15 @@ impl SpeechPipelineInner {
             "dictation_no_output reason={} message={}",
             reason.code, reason.message
         );
-        events::emit_transcription_skipped(&self.app, reason.code, reason.message);
+        let message = i18n::lookup(&format!("no-output-{}", reason.code), &[])
+            .unwrap_or_else(|| reason.message.to_string());
+        events::emit_transcription_skipped(&self.app, reason.code, &message);
         #[cfg(debug_assertions)]
         logs::push_log(format!("No output: {} ({})", reason.message, reason.code));
     }
 
     fn compute_trim_range(
-        &self,
+        trim: &VadTrimState,
         sample_rate: u32,
         buffer_len: usize,
     ) -> Result<(usize, usize), NoOutputReason> {
@@ -584,7 +1345,6 @@ impl SpeechPipelineInner {
             });
         }
 
-        let trim = self.vad_trim.lock();
         let min_samples = ((VAD_MIN_SPEECH_MS * sample_rate as u64) / 1000) as usize;
         if trim.first_active.is_none() || trim.active_samples < min_samples {
             return Err(NoOutputReason {
@@ -622,25 +1382,329 @@ impl SpeechPipelineInner {
         Ok((start - buffer_start, end - buffer_start))
     }
 
+    /// The checks `compute_trim_range` evaluated, given the code it rejected with.
+    fn trim_checks(failed: Option<&'static str>) -> Vec<TrimCheckPayload> {
+        let mut checks = Vec::new();
+        for code in ["no-audio", "no-speech", "trim-rejected"] {
+            let passed = failed != Some(code);
+            checks.push(TrimCheckPayload {
+                code: code.to_string(),
+                passed,
+            });
+            if !passed {
+                break;
+            }
+        }
+        checks
+    }
+
+    /// Splits the trimmed range at every pause of at least `split_gap_ms`. Ranges are
+    /// relative to the trimmed slice; of each split pause only `VAD_SEGMENT_KEEP_GAP_MS`
+    /// on either side stays in the neighbouring segments. Also returns the length of
+    /// each split pause in ms.
+    fn compute_segments(
+        trim: &VadTrimState,
+        sample_rate: u32,
+        (start, end): (usize, usize),
+        split_gap_ms: u64,
+    ) -> (Vec<Range<usize>>, Vec<u32>) {
+        let split_gap = ((split_gap_ms * sample_rate as u64) / 1000) as usize;
+        let keep = ((VAD_SEGMENT_KEEP_GAP_MS * sample_rate as u64) / 1000) as usize;
+        let relative = |position: usize| position.saturating_sub(trim.buffer_start);
+
+        let mut merged: Vec<(usize, usize)> = Vec::new();
+        for &(run_start, run_end) in &trim.speech_runs {
+            match merged.last_mut() {
+                Some(last) if run_start.saturating_sub(last.1) < split_gap => last.1 = run_end,
+                _ => merged.push((run_start, run_end)),
+            }
+        }
+
+        let mut segments = Vec::new();
+        let mut pauses_ms = Vec::new();
+        let mut segment_start = start;
+        for pair in merged.windows(2) {
+            let gap = pair[1].0 - pair[0].1;
+            let split = relative(pair[0].1 + gap / 2).clamp(segment_start, end);
+            if split > segment_start && split < end {
+                let segment_end = relative(pair[0].1 + keep).clamp(segment_start, split);
+                if segment_end > segment_start {
+                    segments.push(segment_start - start..segment_end - start);
+                    pauses_ms.push((gap as u64 * 1000 / sample_rate.max(1) as u64) as u32);
+                }
+                segment_start = relative(pair[1].0.saturating_sub(keep)).clamp(split, end);
+            }
+        }
+        if end > segment_start {
+            segments.push(segment_start - start..end - start);
+        }
+        (segments, pauses_ms)
+    }
+
+    /// Packs `segments` of `samples` back to back, dropping the pause audio between
+    /// them. Returns None when the segments already cover `samples` contiguously.
+    fn compact_segments(
+        samples: &[f32],
+        segments: &[Range<usize>],
+    ) -> Option<(Vec<f32>, Vec<Range<usize>>)> {
+        let contiguous = segments.first().map(|first| first.start) == Some(0)
+            && segments.last().map(|last| last.end) == Some(samples.len())
+            && segments.windows(2).all(|pair| pair[0].end == pair[1].start);
+        if contiguous {
+            return None;
+        }
+        let mut packed = Vec::with_capacity(segments.iter().map(|segment| segment.len()).sum());
+        let mut packed_segments = Vec::with_capacity(segments.len());
+        for segment in segments {
+            let start = packed.len();
+            packed.extend_from_slice(&samples[segment.clone()]);
+            packed_segments.push(start..packed.len());
+        }
+        Some((packed, packed_segments))
+    }
+
     fn set_listening(&self, active: bool) {
         if active {
-            self.listening.store(true, Ordering::SeqCst);
+            let Some(_producer) = self.producer.try_lock() else {
+                warn!("dictation_start_ignored reason=sample_ring_busy");
+                return;
+            };
+            self.switch_bluetooth_profile();
+            if let Err(error) = self.audio.begin_capture() {
+                warn!("failed to open audio capture: {error:?}");
+            } else if self.audio.on_demand() {
+                // A freshly opened device hasn't delivered frames yet; don't let the
+                // watchdog treat the gap as a stall.
+                self.note_audio_ingress();
+            }
             self.reset_recognizer();
             self.reset_vad();
             self.reset_trim_state();
+            *self.capture_started.lock() = Some(Instant::now());
+            // The audio thread feeds in the standby pre-roll with the first live frame.
+            self.listening.store(true, Ordering::SeqCst);
             return;
         }
 
+        match self.end_session() {
+            Some(done) => {
+                let _ = done.recv();
+            }
+            None => {
+                self.reset_recognizer();
+                self.reset_vad();
+                self.reset_trim_state();
+            }
+        }
+    }
+
+    fn end_session(&self) -> Option<Receiver<()>> {
         let was_listening = self.listening.swap(false, Ordering::SeqCst);
         if !was_listening {
-            self.reset_recognizer();
-            self.reset_vad();
-            self.reset_trim_state();
-            return;
+            return None;
+        }
+
+        let session = self.take_capture();
+        self.reset_vad();
+        self.audio.end_capture();
+        self.clear_standby_frames();
+        self.restore_bluetooth_profile();
+        Some(self.enqueue_session(session))
+    }
+
+    /// Best effort: the capture stream follows the headset source once PipeWire brings
+    /// it up, which can take a moment after the switch.
+    fn switch_bluetooth_profile(&self) {
+        if self.bluetooth_auto_profile.load(Ordering::SeqCst) && bluetooth::available() {
+            let mut guard = self.bluetooth_switch.lock();
+            if guard.is_none() {
+                match bluetooth::switch_to_hands_free(&self.audio.device_preferences()) {
+                    Ok(switch) => *guard = switch,
+                    Err(error) => warn!("bluetooth_profile_switch_failed error={error:#}"),
+                }
+            }
+        }
+        bluetooth::refresh_in_background();
+    }
+
+    fn restore_bluetooth_profile(&self) {
+        if let Some(switch) = self.bluetooth_switch.lock().take() {
+            if let Err(error) = switch.restore() {
+                warn!("bluetooth_profile_restore_failed error={error:#}");
+            }
+        }
+    }
+
+    /// Moves the buffered capture out into a session ready for finalizing.
+    fn take_capture(&self) -> CapturedSession {
+        let id = self.session_seq.fetch_add(1, Ordering::SeqCst) + 1;
+        let trim = std::mem::take(&mut *self.vad_trim.lock());
+        let captured_at = Instant::now();
+        let capture_started = self.capture_started.lock().take().unwrap_or(captured_at);
+        let mut timeline = SessionTimeline::new(id, capture_started);
+        timeline.record(
+            Stage::Capture,
+            capture_started,
+            captured_at.duration_since(capture_started),
+        );
+        timeline.record(Stage::Vad, capture_started, trim.vad_time);
+        CapturedSession {
+            id,
+            sample_rate: self.audio.sample_rate(),
+            samples: self.asr.take_samples(),
+            trim,
+            timeline,
+            transcript: None,
+            kind: std::mem::take(&mut *self.session_kind.lock()),
+        }
+    }
+
+    fn enqueue_session(&self, session: CapturedSession) -> Receiver<()> {
+        let queued = self.queued_sessions.fetch_add(1, Ordering::SeqCst) + 1;
+        info!(
+            "dictation_session_queued id={} samples={} queued={}",
+            session.id,
+            session.samples.len(),
+            queued
+        );
+
+        let (done_tx, done_rx) = bounded(1);
+        if self
+            .finalize_tx
+            .send(FinalizeJob {
+                session,
+                done: done_tx,
+            })
+            .is_err()
+        {
+            self.queued_sessions.fetch_sub(1, Ordering::SeqCst);
+            warn!("finalize worker unavailable; dropping captured session");
+        }
+        done_rx
+    }
+
+    /// Queues a dictation built from `input` instead of the microphone. Audio goes
+    /// through the same preprocessing and VAD as captured frames.
+    fn simulate_session(&self, input: SimulatedInput) -> Result<Receiver<()>> {
+        // Holding the producer lock keeps a hotkey session from starting, and the
+        // audio thread from feeding the ring, until the replay is queued.
+        let Some(_producer) = self.producer.try_lock() else {
+            return Err(anyhow!("a simulated dictation is already in progress"));
+        };
+        if self.listening.load(Ordering::SeqCst) {
+            return Err(anyhow!("a dictation is already in progress"));
         }
 
-        let sample_rate = self.audio.sample_rate();
-        let samples = self.asr.take_samples();
+        let session = match input {
+            SimulatedInput::Audio(samples) => {
+                self.reset_recognizer();
+                self.reset_vad();
+                self.reset_trim_state();
+                *self.capture_started.lock() = Some(Instant::now());
+                let frame_len =
+                    (SIMULATED_FRAME_MS * self.audio.sample_rate() as u64 / 1000).max(1) as usize;
+                for chunk in samples.chunks(frame_len) {
+                    let mut frame = chunk.to_vec();
+                    self.ingest_samples(&mut frame);
+                }
+                let session = self.take_capture();
+                self.reset_vad();
+                session
+            }
+            SimulatedInput::Text(text) => {
+                let id = self.session_seq.fetch_add(1, Ordering::SeqCst) + 1;
+                CapturedSession {
+                    id,
+                    sample_rate: self.audio.sample_rate(),
+                    samples: Vec::new(),
+                    trim: VadTrimState::default(),
+                    timeline: SessionTimeline::new(id, Instant::now()),
+                    transcript: Some(text),
+                    kind: SessionKind::Dictation,
+                }
+            }
+        };
+        info!("dictation_simulated id={}", session.id);
+        Ok(self.enqueue_session(session))
+    }
+
+    fn cancel_session(&self) -> bool {
+        let was_listening = self.listening.swap(false, Ordering::SeqCst);
+        if !was_listening {
+            return false;
+        }
+
+        let discarded = self.asr.take_samples().len();
+        self.capture_started.lock().take();
+        *self.session_kind.lock() = SessionKind::default();
+        self.reset_vad();
+        self.reset_trim_state();
+        self.audio.end_capture();
+        self.restore_bluetooth_profile();
+        info!("dictation_session_cancelled samples={discarded}");
+        true
+    }
+
+    fn shutdown(&self, grace: Duration) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+        self.cancel_session();
+
+        let deadline = Instant::now() + grace;
+        while self.queued_sessions.load(Ordering::SeqCst) > 0 && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        let unfinished = self.queued_sessions.load(Ordering::SeqCst);
+        if unfinished > 0 {
+            warn!("pipeline_shutdown_timeout unfinished_sessions={unfinished}");
+        }
+
+        // Stopping the frame source ends the audio loop's receive, so it can be joined.
+        self.audio.shutdown();
+        let handle = self.audio_thread.lock().take();
+        if let Some(handle) = handle {
+            let _ = handle.join();
+        }
+        info!("pipeline_shutdown_complete");
+    }
+
+    fn finalize_session(&self, session: CapturedSession) {
+        let CapturedSession {
+            id,
+            sample_rate,
+            samples,
+            trim,
+            mut timeline,
+            transcript,
+            kind,
+        } = session;
+        if let Some(text) = transcript {
+            let recognition = RecognitionResult {
+                text,
+                alternatives: Vec::new(),
+                pieces: Vec::new(),
+                latency: Duration::ZERO,
+            };
+            self.consume_result(id, kind, recognition, &[], &mut timeline);
+        } else {
+            if let Some(captured) = timeline.end_of(Stage::Capture) {
+                let released = timeline.origin() + captured;
+                timeline.record(Stage::Queue, released, released.elapsed());
+            }
+            self.finalize_audio(id, kind, sample_rate, &samples, &trim, &mut timeline);
+        }
+        self.publish_timeline(timeline);
+    }
+
+    fn finalize_audio(
+        &self,
+        id: u64,
+        kind: SessionKind,
+        sample_rate: u32,
+        samples: &[f32],
+        trim: &VadTrimState,
+        timeline: &mut SessionTimeline,
+    ) {
+        info!("dictation_finalize_start id={id}");
         #[cfg(debug_assertions)]
         {
             let pending = samples.len();
@@ -650,23 +1714,127 @@ impl SpeechPipelineInner {
             ));
         }
 
-        let trim_range = self.compute_trim_range(sample_rate, samples.len());
-        let (trim_start, trim_end) = match trim_range {
-            Ok(range) => range,
+        // Pause punctuation needs a piece per pause, so Parakeet splits at comma length.
+        let split_gap_ms = match self.autoclean.pause_punctuation() {
+            Some(thresholds) if self.asr.config().backend == AsrBackend::Parakeet => {
+                u64::from(thresholds.comma_ms)
+            }
+            _ => VAD_SEGMENT_SPLIT_GAP_MS,
+        };
+        let trimmed = timeline.measure(Stage::Trim, || {
+            Self::compute_trim_range(trim, sample_rate, samples.len()).map(|range| {
+                (
+                    range,
+                    Self::compute_segments(trim, sample_rate, range, split_gap_ms),
+                )
+            })
+        });
+        let mut report = TrimReportPayload {
+            session_id: id,
+            sample_rate,
+            total_samples: samples.len(),
+            active_samples: trim.active_samples,
+            min_active_samples: ((VAD_MIN_SPEECH_MS * sample_rate as u64) / 1000) as usize,
+            kept_samples: 0,
+            dropped_samples: samples.len(),
+            segments: Vec::new(),
+            checks: Self::trim_checks(trimmed.as_ref().err().map(|reason| reason.code)),
+            rejected: None,
+        };
+        let ((trim_start, trim_end), (segments, pauses_ms)) = match trimmed {
+            Ok(trimmed) => trimmed,
+            // The mock answers regardless of content, so silence or synthetic audio
+            // still produces a transcript.
+            Err(_) if self.asr.config().backend == AsrBackend::Mock => {
+                ((0, samples.len()), (vec![0..samples.len()], Vec::new()))
+            }
+            // Soft speech the VAD misses still reaches ASR when the user asked for it.
+            Err(reason)
+                if reason.code != "no-audio" && self.always_transcribe.load(Ordering::SeqCst) =>
+            {
+                info!("trim_overridden id={id} reason={}", reason.code);
+                ((0, samples.len()), (vec![0..samples.len()], Vec::new()))
+            }
             Err(reason) => {
+                report.rejected = Some(reason.code.to_string());
+                events::emit_trim_report(&self.app, report);
+                failed_audio::record(id, reason.code, sample_rate, samples);
                 self.emit_no_output_reason(reason);
-                self.reset_recognizer();
-                self.reset_vad();
-                self.reset_trim_state();
                 return;
             }
         };
+        let to_ms = |position: usize| position as u64 * 1000 / sample_rate.max(1) as u64;
+        report.segments = segments
+            .iter()
+            .map(|segment| TrimSegmentPayload {
+                start_ms: to_ms(trim_start + segment.start),
+                end_ms: to_ms(trim_start + segment.end),
+            })
+            .collect();
 
         let trimmed_samples = &samples[trim_start..trim_end];
-
-        match self.asr.finalize_samples(sample_rate, trimmed_samples) {
+        let compacted = Self::compact_segments(trimmed_samples, &segments);
+        let (trimmed_samples, segments) = match compacted.as_ref() {
+            Some((audio, packed)) => {
+                let dropped = trimmed_samples.len() - audio.len();
+                info!(
+                    "dictation_segments id={id} count={} dropped_silence_ms={}",
+                    packed.len(),
+                    dropped as u64 * 1000 / sample_rate.max(1) as u64
+                );
+                (audio.as_slice(), packed.clone())
+            }
+            None => {
+                if segments.len() > 1 {
+                    info!("dictation_segments id={id} count={}", segments.len());
+                }
+                (trimmed_samples, segments)
+            }
+        };
+        let gated = timeline.measure(Stage::SpeakerGate, || {
+            self.apply_speaker_gate(sample_rate, trimmed_samples, &segments)
+        });
+        let gate_active = self
+            .speaker_gate
+            .lock()
+            .as_ref()
+            .map(|gate| gate.is_active())
+            .unwrap_or(false);
+        if gate_active {
+            report.checks.push(TrimCheckPayload {
+                code: "speaker-mismatch".to_string(),
+                passed: !matches!(gated.as_ref(), Some((_, kept)) if kept.is_empty()),
+            });
+        }
+        let (trimmed_samples, segments, pauses_ms) = match gated.as_ref() {
+            Some((_, kept)) if kept.is_empty() => {
+                report.rejected = Some("speaker-mismatch".to_string());
+                events::emit_trim_report(&self.app, report);
+                self.emit_no_output_reason(NoOutputReason {
+                    code: "speaker-mismatch",
+                    message: "Speech did not match the enrolled speaker",
+                });
+                return;
+            }
+            // Dropped segments leave the remaining pauses meaningless.
+            Some((audio, kept)) => (audio.as_slice(), kept.clone(), Vec::new()),
+            None => (trimmed_samples, segments, pauses_ms),
+        };
+        report.kept_samples = trimmed_samples.len();
+        report.dropped_samples = samples.len().saturating_sub(trimmed_samples.len());
+        events::emit_trim_report(&self.app, report);
+        let app = self.app.clone();
+        let mut on_segment = |partial: &str| events::emit_transcription_partial(&app, partial);
+
+        let recognized = timeline.measure(Stage::Asr, || {
+            self.asr
+                .finalize_samples(sample_rate, trimmed_samples, &segments, &mut on_segment)
+        });
+        self.report_gpu_fallback();
+        match recognized {
             Ok(Some(result)) => {
                 if result.text.trim().is_empty() {
+                    failed_audio::record(id, "empty-transcript", sample_rate, trimmed_samples);
                     self.emit_no_output_reason(NoOutputReason {
                         code: "empty-transcript",
                         message: "ASR returned empty transcript",
@@ -675,7 +1843,8 @@ impl SpeechPipelineInner {
                     #[cfg(debug_assertions)]
                     logs::push_log("ASR returned empty transcript".to_string());
                 }
-                self.consume_result(result);
+                self.update_metrics(result.latency);
+                self.consume_result(id, kind, result, &pauses_ms, timeline);
             }
             Ok(None) => {
                 self.emit_no_output_reason(NoOutputReason {
@@ -684,19 +1853,67 @@ impl SpeechPipelineInner {
                 });
             }
             Err(error) => {
+                failed_audio::record(id, "asr-error", sample_rate, trimmed_samples);
                 events::emit_transcription_error(&self.app, &error.to_string());
                 #[cfg(debug_assertions)]
                 logs::push_log(format!("ASR error: {error}"));
             }
         }
-        self.reset_recognizer();
-        self.reset_vad();
-        self.reset_trim_state();
     }
 
-    fn consume_result(&self, recognition: RecognitionResult) {
-        self.update_metrics(recognition.latency);
+    /// Drops segments spoken by someone other than the enrolled speaker. Returns the
+    /// kept audio re-packed with matching ranges, or None when the gate is off or
+    /// every segment was kept.
+    fn apply_speaker_gate(
+        &self,
+        sample_rate: u32,
+        samples: &[f32],
+        segments: &[Range<usize>],
+    ) -> Option<(Vec<f32>, Vec<Range<usize>>)> {
+        let gate = self.speaker_gate.lock().clone()?;
+        if !gate.is_active() {
+            return None;
+        }
 
+        let mut kept = Vec::new();
+        let mut kept_segments = Vec::new();
+        let mut dropped = 0usize;
+        for segment in segments {
+            let audio = &samples[segment.clone()];
+            let accepted = match gate.accepts(sample_rate, audio) {
+                Ok((accepted, similarity)) => {
+                    info!(
+                        "speaker_gate samples={} similarity={similarity:?} accepted={accepted}",
+                        audio.len()
+                    );
+                    accepted
+                }
+                Err(error) => {
+                    // A broken model must not silently eat dictations.
+                    warn!("speaker gate unavailable; keeping audio: {error:?}");
+                    return None;
+                }
+            };
+            if accepted {
+                let start = kept.len();
+                kept.extend_from_slice(audio);
+                kept_segments.push(start..kept.len());
+            } else {
+                dropped += 1;
+            }
+        }
+
+        (dropped > 0).then_some((kept, kept_segments))
+    }
+
+    fn consume_result(
+        &self,
+        session_id: u64,
+        kind: SessionKind,
+        recognition: RecognitionResult,
+        pauses_ms: &[u32],
+        timeline: &mut SessionTimeline,
+    ) {
         let trimmed = recognition.text.trim();
         if trimmed.is_empty() {
             self.emit_no_output_reason(NoOutputReason {
@@ -705,14 +1922,233 @@ impl SpeechPipelineInner {
             });
             return;
         }
+        if kind == SessionKind::Command {
+            self.run_voice_command(trimmed, timeline);
+            return;
+        }
+
+        let cleaned = timeline.measure(Stage::Clean, || {
+            let active_mode = *self.mode.lock();
+            self.autoclean.set_mode(active_mode);
+            let cleaned = if recognition.pieces.len() > 1
+                && pauses_ms.len() + 1 == recognition.pieces.len()
+            {
+                self.autoclean.clean_timed(&recognition.pieces, pauses_ms)
+            } else {
+                self.autoclean.clean(trimmed)
+            };
+            let cleaned = self.autoclean.polish(&cleaned);
+            self.redact_for_paste(cleaned)
+        });
+        if debug_capture::active() {
+            *self.captured_transcripts.lock() = Some((
+                self.redact_for_storage(trimmed),
+                self.redact_for_storage(&cleaned),
+            ));
+        }
+        if !recognition.alternatives.is_empty() {
+            let mut alternatives: Vec<String> = Vec::new();
+            for alternative in &recognition.alternatives {
+                let alternative = self.redact_for_paste(self.autoclean.clean(alternative.trim()));
+                if !alternative.trim().is_empty()
+                    && alternative != cleaned
+                    && !alternatives.contains(&alternative)
+                {
+                    alternatives.push(alternative);
+                }
+            }
+            events::emit_transcription_alternatives(&self.app, &cleaned, alternatives);
+        }
+        // Drop timings of a paste made outside this session, e.g. a confirmed transcript.
+        self.injector.take_paste_timings();
+        timeline.measure(Stage::Inject, || self.deliver_output(session_id, &cleaned));
+        let paste = self.injector.take_paste_timings();
+        if let Some((started, duration)) = paste.clipboard {
+            timeline.record(Stage::Clipboard, started, duration);
+        }
+        if let Some((started, duration)) = paste.chord {
+            timeline.record(Stage::KeyInject, started, duration);
+        }
+    }
+
+    /// Executes the command the utterance names. Command sessions never paste: an
+    /// utterance that matches no command is only reported.
+    fn run_voice_command(&self, utterance: &str, timeline: &mut SessionTimeline) {
+        let command = self.voice_commands.lock().lookup(utterance).cloned();
+        let Some(command) = command else {
+            info!(
+                "voice_command_unmatched words={}",
+                utterance.split_whitespace().count()
+            );
+            sound::play(SoundCue::Error);
+            events::emit_hud_toast(&self.app, &i18n::message("notify-command-unmatched", &[]));
+            return;
+        };
+
+        let executed =
+            timeline.measure(Stage::Inject, || self.execute_command(&command, utterance));
+        match executed {
+            Ok(()) => {
+                info!("voice_command_executed phrase={:?}", command.phrase);
+                events::emit_hud_toast(
+                    &self.app,
+                    &i18n::message("notify-command-executed", &[("command", &command.phrase)]),
+                );
+            }
+            Err(error) => {
+                warn!(
+                    "voice_command_failed phrase={:?} error={error:#}",
+                    command.phrase
+                );
+                sound::play(SoundCue::Error);
+                events::emit_hud_toast(
+                    &self.app,
+                    &i18n::message("notify-command-failed", &[("command", &command.phrase)]),
+                );
+            }
+        }
+    }
+
+    fn execute_command(&self, command: &RegisteredCommand, utterance: &str) -> Result<()> {
+        match &command.action {
+            CommandAction::Keys(chord) => self.injector.press_chord(chord, 1),
+            CommandAction::Text(text) => self
+                .injector
+                .inject(
+                    text,
+                    OutputAction::Paste,
+                    &self.injector.effective_paste_shortcut(),
+                )
+                .map_err(|error| anyhow!(error.to_string())),
+            CommandAction::DeleteLastSentence => {
+                let delivered = self.last_utterance()?;
+                let (kept, removed) = voice_commands::split_last_sentence(&delivered);
+                if removed.is_empty() {
+                    return Err(anyhow!("last paste has no sentence left"));
+                }
+                self.injector.edit_last_utterance(
+                    removed.chars().count(),
+                    "",
+                    (!kept.is_empty()).then(|| kept.to_string()),
+                )
+            }
+            CommandAction::DeleteLastUtterance => {
+                let delivered = self.last_utterance()?;
+                self.injector
+                    .edit_last_utterance(delivered.chars().count(), "", None)
+            }
+            CommandAction::SelectLastSentence => {
+                let delivered = self.last_utterance()?;
+                let (_, sentence) = voice_commands::split_last_sentence(&delivered);
+                let length = sentence.trim_start().chars().count();
+                if length == 0 {
+                    return Err(anyhow!("last paste has no sentence left"));
+                }
+                let select_left = PasteShortcut::parse("Shift+Left")?;
+                self.injector.press_chord(&select_left, length)
+            }
+            CommandAction::Replace => {
+                let (target, replacement) =
+                    voice_commands::parse_replacement(&command.phrase, utterance)
+                        .ok_or_else(|| anyhow!("expected \"{} X with Y\"", command.phrase))?;
+                let delivered = self.last_utterance()?;
+                let edit = voice_commands::replace_last(&delivered, &target, &replacement)
+                    .ok_or_else(|| anyhow!("{target:?} is not in the last dictation"))?;
+                self.injector
+                    .edit_last_utterance(edit.erase, &edit.insert, Some(edit.result))
+            }
+            CommandAction::Launch(argv) => voice_commands::launch(argv),
+        }
+    }
+
+    /// Newest dictation delivered to the focused window, for the editing commands.
+    fn last_utterance(&self) -> Result<String> {
+        self.injector
+            .last_utterance()
+            .ok_or_else(|| anyhow!("nothing has been dictated into this window yet"))
+    }
+
+    /// Logs and emits the stage breakdown of a finished session, exporting it as a
+    /// Chrome trace when enabled.
+    fn publish_timeline(&self, timeline: SessionTimeline) {
+        info!(
+            "dictation_timing id={} total_ms={} {}",
+            timeline.session_id,
+            timeline.total().as_millis(),
+            timeline.summary()
+        );
+        if let Some((raw, cleaned)) = self.captured_transcripts.lock().take() {
+            if let Err(error) =
+                debug_capture::record(timeline.session_id, &raw, &cleaned, &timeline)
+            {
+                warn!("debug_capture_failed error={error:#}");
+            }
+        }
+        let export_dir = self.trace_export_dir.lock().clone();
+        let trace_path =
+            export_dir.and_then(|dir| match timing::export_chrome_trace(&dir, &timeline) {
+                Ok(path) => Some(path),
+                Err(error) => {
+                    warn!("failed to export session trace: {error:?}");
+                    None
+                }
+            });
+        let budget = self.performance_policy.lock().latency_budget.clone();
+        if let Some(report) = budget.check(&timeline) {
+            let culprit = report.over_budget.first().map_or_else(
+                || report.culprit.map_or("none", Stage::as_str).to_string(),
+                |overrun| {
+                    format!(
+                        "{} ({}ms > {}ms)",
+                        overrun.stage.as_str(),
+                        overrun.actual_ms,
+                        overrun.budget_ms
+                    )
+                },
+            );
+            warn!(
+                "latency_budget_exceeded id={} end_to_end_ms={} target_ms={} culprit={culprit}",
+                report.session_id, report.end_to_end_ms, report.target_ms
+            );
+            events::emit_latency_budget_exceeded(&self.app, report);
+        }
+        events::emit_session_timing(&self.app, timeline, trace_path);
+    }
+
+    fn redact_for_storage(&self, text: &str) -> String {
+        let rules = self.redaction.lock();
+        if rules.is_empty() {
+            text.to_string()
+        } else {
+            rules.apply(text)
+        }
+    }
+
+    fn redact_for_paste(&self, text: String) -> String {
+        if self.redact_before_paste.load(Ordering::SeqCst) {
+            self.redact_for_storage(&text)
+        } else {
+            text
+        }
+    }
 
-        let active_mode = *self.mode.lock();
-        self.autoclean.set_mode(active_mode);
-        let cleaned = self.autoclean.clean(trimmed);
-        self.deliver_output(&cleaned);
+    fn routing_context(session_id: u64) -> events::RoutingContext {
+        let window = match x11::active_window() {
+            Ok(window) => window.unwrap_or_default(),
+            Err(error) => {
+                debug!("routing window unavailable: {error}");
+                Default::default()
+            }
+        };
+        events::RoutingContext {
+            window_class: window.class,
+            window_title: window.title,
+            session_id,
+            profile: None,
+        }
     }
 
-    fn deliver_output(&self, cleaned: &str) {
+    fn deliver_output(&self, session_id: u64, cleaned: &str) {
         if cleaned.trim().is_empty() {
             self.emit_no_output_reason(NoOutputReason {
                 code: "clean-empty",
@@ -721,65 +2157,169 @@ impl SpeechPipelineInner {
             return;
         }
 
-        events::emit_transcription_output(&self.app, cleaned);
+        let mode = *self.output_mode.lock();
+        let routing =
+            matches!(mode, OutputMode::EmitOnly).then(|| Self::routing_context(session_id));
+        events::emit_transcription_output(&self.app, cleaned, mode, routing);
         #[cfg(debug_assertions)]
-        logs::push_log(format!("Transcription -> {}", cleaned));
+        logs::push_log(format!(
+            "Transcription -> {}",
+            crate::core::privacy::loggable(&self.redact_for_storage(cleaned))
+        ));
 
-        let mode = *self.output_mode.lock();
-        if matches!(mode, OutputMode::Paste) {
-            let configured_shortcut = self.injector.current_paste_shortcut();
-            let shortcut = match configured_shortcut {
-                PasteShortcut::CtrlV => "ctrl-v",
-                PasteShortcut::CtrlShiftV => "ctrl-shift-v",
+        if !matches!(mode, OutputMode::Paste) {
+            #[cfg(debug_assertions)]
+            logs::push_log("Output mode set to emit-only; skipping paste".to_string());
+            self.record_history(cleaned, "emitted");
+            return;
+        }
+
+        if self.confirm_before_paste.load(Ordering::SeqCst) {
+            // A queued dictation finishing before review appends to the held text.
+            let pending = {
+                let mut guard = self.pending_transcript.lock();
+                let text = match guard.take() {
+                    Some(previous) => format!("{previous} {cleaned}"),
+                    None => cleaned.to_string(),
+                };
+                *guard = Some(text.clone());
+                text
             };
+            events::emit_pending_transcript(&self.app, Some(&pending));
+            if let Some(window) = self.app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+            return;
+        }
 
-            match self.injector.inject(cleaned, OutputAction::Paste) {
-                Ok(()) => {
-                    events::emit_paste_succeeded(
-                        &self.app,
-                        events::PasteSucceededPayload {
-                            shortcut: shortcut.to_string(),
-                            chars: cleaned.len(),
-                        },
-                    );
+        let outcome = self.paste_output(cleaned);
+        self.record_history(cleaned, outcome);
+    }
+
+    fn record_history(&self, text: &str, outcome: &str) {
+        let Some(history) = self.history.lock().clone() else {
+            return;
+        };
+        let app = x11::active_window_class().ok().flatten();
+        if let Err(error) = history.record(&self.redact_for_storage(text), app, outcome) {
+            warn!("history_record_failed error={error:#}");
+        }
+    }
+
+    fn show_transcript_toast(&self, cleaned: &str, shortcut: &str) {
+        let window_class = x11::active_window_class().ok().flatten();
+        let payload = events::TranscriptToastPayload {
+            text: notify::preview(cleaned),
+            chars: cleaned.chars().count(),
+            words: cleaned.split_whitespace().count(),
+            shortcut: shortcut.to_string(),
+            window_class,
+        };
+        let app = self.app.clone();
+        // Bringing up the overlay window must not hold up the finalize worker.
+        tauri::async_runtime::spawn_blocking(move || {
+            if let Some(state) = app.try_state::<crate::core::app_state::AppState>() {
+                state.show_transcript_toast(&app, payload);
+            }
+        });
+    }
+
+    /// Returns the outcome recorded in history.
+    fn paste_output(&self, cleaned: &str) -> &'static str {
+        let effective_shortcut = self.injector.effective_paste_shortcut();
+        let type_out = self.type_out.load(Ordering::SeqCst);
+        // Multi-line text typed into a terminal would run line by line.
+        let terminal_paste = type_out && self.injector.type_out_pastes(cleaned);
+        if terminal_paste {
+            info!("type_out_fallback reason=terminal-multiline");
+            events::emit_hud_toast(&self.app, &i18n::message("notify-type-out-pasted", &[]));
+        }
+        let type_out = type_out && !terminal_paste;
+        let (action, mut shortcut) = if type_out {
+            (OutputAction::Type, "typed".to_string())
+        } else {
+            (OutputAction::Paste, effective_shortcut.label())
+        };
+
+        let mut result = self.injector.inject(cleaned, action, &effective_shortcut);
+        if let Err(crate::output::OutputInjectionError::Paste(failure)) = &result {
+            // An unconfirmed paste sent its chord, but nothing shows it landed; terminals
+            // that ignore the configured chord often still take Shift+Insert.
+            let unconfirmed = matches!(failure.kind, crate::output::PasteFailureKind::Unconfirmed);
+            let retry = self.paste_fallback_shift_insert.load(Ordering::SeqCst)
+                && !type_out
+                && (!failure.chord_sent || unconfirmed)
+                && effective_shortcut != PasteShortcut::shift_insert();
+            if retry {
+                // Keep the original failure for reporting if the fallback also fails.
+                if self.injector.inject_shift_insert_fallback(cleaned).is_ok() {
+                    shortcut = PasteShortcut::shift_insert().label();
+                    result = Ok(());
                 }
-                Err(error) => {
-                    let linux = Some(crate::core::linux_setup::permissions_status());
-
-                    match error {
-                        crate::output::OutputInjectionError::Paste(paste) => {
-                            let payload = events::PasteFailedPayload {
-                                step: paste.step.as_str().to_string(),
-                                message: paste.message,
-                                shortcut: shortcut.to_string(),
-                                transcript_on_clipboard: paste.transcript_on_clipboard,
-                                linux,
-                            };
+            }
+        }
 
-                            if matches!(paste.kind, crate::output::PasteFailureKind::Unconfirmed) {
-                                events::emit_paste_unconfirmed(&self.app, payload);
-                            } else {
-                                events::emit_paste_failed(&self.app, payload);
-                            }
-                        }
-                        crate::output::OutputInjectionError::Copy(message) => {
-                            events::emit_paste_failed(
-                                &self.app,
-                                events::PasteFailedPayload {
-                                    step: "clipboard".to_string(),
-                                    message,
-                                    shortcut: "unknown".to_string(),
-                                    transcript_on_clipboard: false,
-                                    linux,
-                                },
-                            );
+        match result {
+            Ok(()) => {
+                sound::play(SoundCue::PasteSuccess);
+                tray::set_badge(&self.app, TrayBadge::PasteFailed, false);
+                events::emit_paste_succeeded(
+                    &self.app,
+                    events::PasteSucceededPayload {
+                        shortcut: shortcut.to_string(),
+                        chars: cleaned.len(),
+                    },
+                );
+                if self.transcript_toast.load(Ordering::SeqCst) {
+                    self.show_transcript_toast(cleaned, &shortcut);
+                }
+                "pasted"
+            }
+            Err(error) => {
+                let linux = Some(crate::core::linux_setup::permissions_status());
+
+                match error {
+                    crate::output::OutputInjectionError::Paste(paste) => {
+                        let payload = events::PasteFailedPayload {
+                            step: paste.step.as_str().to_string(),
+                            message: paste.message,
+                            shortcut: shortcut.to_string(),
+                            transcript_on_clipboard: paste.transcript_on_clipboard,
+                            chord_delivery: paste
+                                .chord_delivery
+                                .map(|delivery| delivery.as_str().to_string()),
+                            linux,
+                        };
+
+                        if matches!(paste.kind, crate::output::PasteFailureKind::Unconfirmed) {
+                            events::emit_paste_unconfirmed(&self.app, payload);
+                            "paste-unconfirmed"
+                        } else {
+                            sound::play(SoundCue::Error);
+                            tray::set_badge(&self.app, TrayBadge::PasteFailed, true);
+                            events::emit_paste_failed(&self.app, payload);
+                            "paste-failed"
                         }
                     }
+                    crate::output::OutputInjectionError::Copy(message) => {
+                        sound::play(SoundCue::Error);
+                        tray::set_badge(&self.app, TrayBadge::PasteFailed, true);
+                        events::emit_paste_failed(
+                            &self.app,
+                            events::PasteFailedPayload {
+                                step: "clipboard".to_string(),
+                                message,
+                                shortcut: "unknown".to_string(),
+                                transcript_on_clipboard: false,
+                                chord_delivery: None,
+                                linux,
+                            },
+                        );
+                        "paste-failed"
+                    }
                 }
             }
-        } else {
-            #[cfg(debug_assertions)]
-            logs::push_log("Output mode set to emit-only; skipping paste".to_string());
         }
     }
 }
@@ -810,3 +2350,88 @@ impl Drop for SpeechPipelineInner {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_pauses_are_cut_out_between_segments() {
+        // 16 samples per ms: speech 0–1s, 3s pause, speech 4–5s.
+        let trim = VadTrimState {
+            total_samples: 80_000,
+            first_active: Some(0),
+            last_active: Some(80_000),
+            active_samples: 32_000,
+            speech_runs: vec![(0, 16_000), (64_000, 80_000)],
+            ..VadTrimState::default()
+        };
+        let (segments, pauses_ms) = SpeechPipelineInner::compute_segments(
+            &trim,
+            16_000,
+            (0, 80_000),
+            VAD_SEGMENT_SPLIT_GAP_MS,
+        );
+        assert_eq!(segments, vec![0..18_400, 61_600..80_000]);
+        assert_eq!(pauses_ms, vec![3_000]);
+
+        let samples = vec![0.0; 80_000];
+        let (packed, packed_segments) =
+            SpeechPipelineInner::compact_segments(&samples, &segments).expect("pause removed");
+        assert_eq!(packed.len(), 36_800);
+        assert_eq!(packed_segments, vec![0..18_400, 18_400..36_800]);
+        assert!(SpeechPipelineInner::compact_segments(&packed, &packed_segments).is_none());
+    }
+
+    #[test]
+    fn utterances_end_after_trailing_silence() {
+        // 1 sample per ms: min speech 350, end silence 900, keep 300, idle max 5000.
+        let boundary = |trim: &VadTrimState| trim.utterance_boundary(350, 900, 300, 5_000);
+        let mut trim = VadTrimState::default();
+        trim.record(VadDecision::Inactive, 1_000);
+        trim.record(VadDecision::Active, 600);
+        assert_eq!(boundary(&trim), UtteranceBoundary::Pending);
+        trim.record(VadDecision::Inactive, 899);
+        assert_eq!(boundary(&trim), UtteranceBoundary::Pending);
+        trim.record(VadDecision::Inactive, 1);
+        assert_eq!(boundary(&trim), UtteranceBoundary::Ended);
+
+        // A short blip is dropped along with the silence around it.
+        let mut blip = VadTrimState::default();
+        blip.record(VadDecision::Active, 100);
+        blip.record(VadDecision::Inactive, 1_000);
+        assert_eq!(boundary(&blip), UtteranceBoundary::Idle { excess: 800 });
+        blip.discard_idle(800);
+        assert_eq!(boundary(&blip), UtteranceBoundary::Pending);
+
+        let mut quiet = VadTrimState::default();
+        quiet.record(VadDecision::Inactive, 5_000);
+        assert_eq!(boundary(&quiet), UtteranceBoundary::Pending);
+        quiet.record(VadDecision::Inactive, 20);
+        assert_eq!(boundary(&quiet), UtteranceBoundary::Idle { excess: 4_720 });
+    }
+
+    #[test]
+    fn hud_levels_roll_and_restart_after_a_gap() {
+        let start = Instant::now();
+        let mut levels = HudLevelsState::new(start);
+        let frame = Duration::from_millis(20);
+        let mut last = None;
+        for index in 1..=200u32 {
+            if let Some(history) = levels.record(0.1, 0.5, start + frame * index) {
+                last = Some(history);
+            }
+        }
+        let (rms, peak) = last.expect("points were emitted");
+        assert_eq!(rms.len(), HUD_LEVELS_LEN);
+        assert!(rms.iter().all(|value| (*value - 0.1).abs() < 1e-6));
+        assert!(peak.iter().all(|value| *value == 0.5));
+
+        let resumed = start + frame * 200 + Duration::from_secs(2);
+        assert!(levels.record(0.3, 0.3, resumed).is_none());
+        let (rms, _) = levels
+            .record(0.3, 0.3, resumed + HUD_LEVELS_INTERVAL)
+            .expect("new point");
+        assert_eq!(rms.len(), 1);
+    }
+}
@@ -0,0 +1,568 @@
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+use time::OffsetDateTime;
+
+use crate::audio::{list_input_devices, preferred_device_index};
+use crate::core::app_state::{window_overlay_supported, AppState, AsrWarmupState};
+use crate::core::events;
+use crate::core::hotkeys;
+use crate::core::linux_setup;
+use crate::models::{ModelKind, ModelStatus};
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+pub enum PreflightSeverity {
+    Ok,
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreflightCheck {
+    pub id: String,
+    pub label: String,
+    pub severity: PreflightSeverity,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remedy: Option<String>,
+    /// Settings route where the remedy can be applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreflightReport {
+    pub generated_at_unix: i64,
+    pub app_version: String,
+    pub session_type: String,
+    pub overall: PreflightSeverity,
+    pub checks: Vec<PreflightCheck>,
+}
+
+impl PreflightCheck {
+    fn new(id: &str, label: &str, severity: PreflightSeverity, message: impl Into<String>) -> Self {
+        Self {
+            id: id.to_string(),
+            label: label.to_string(),
+            severity,
+            message: message.into(),
+            remedy: None,
+            route: None,
+        }
+    }
+
+    fn with_remedy(mut self, remedy: impl Into<String>) -> Self {
+        self.remedy = Some(remedy.into());
+        self
+    }
+
+    fn with_route(mut self, route: impl Into<String>) -> Self {
+        self.route = Some(route.into());
+        self
+    }
+}
+
+/// Aggregates readiness checks across subsystems into a single report.
+///
+/// Checks only observe state; nothing here installs models or requests permissions.
+pub fn run_preflight(app: &AppHandle) -> PreflightReport {
+    let state = app.state::<AppState>();
+    let linux = linux_setup::permissions_status();
+
+    let vad_status = state.model_manager().lock().ok().and_then(|guard| {
+        guard
+            .primary_asset(&ModelKind::Vad)
+            .map(|asset| asset.status.clone())
+    });
+
+    let checks = vec![
+        check_asr_model(state.selected_asr_asset_status()),
+        check_vad_model(vad_status),
+        check_pipeline(&state),
+        check_audio_device(&state),
+        check_clipboard(&linux),
+        check_key_injection(&linux),
+        check_hotkey_backend(hotkeys::active_registration(), &linux),
+        check_overlay(),
+    ];
+    let overall = overall_severity(&checks);
+
+    PreflightReport {
+        generated_at_unix: OffsetDateTime::now_utc().unix_timestamp(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        session_type: std::env::var("XDG_SESSION_TYPE").unwrap_or_else(|_| "unknown".into()),
+        overall,
+        checks,
+    }
+}
+
+/// The worst severity among `checks`.
+fn overall_severity(checks: &[PreflightCheck]) -> PreflightSeverity {
+    checks
+        .iter()
+        .map(|check| check.severity)
+        .max()
+        .unwrap_or(PreflightSeverity::Ok)
+}
+
+fn check_asr_model(selected: Option<(String, ModelStatus)>) -> PreflightCheck {
+    const ID: &str = "asr-model";
+    const LABEL: &str = "Speech model";
+
+    match selected {
+        Some((name, ModelStatus::Installed)) => PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Ok,
+            format!("{name} installed"),
+        ),
+        Some((name, ModelStatus::Downloading { progress, .. })) => PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Info,
+            format!("{name} downloading ({:.0}%)", progress * 100.0),
+        ),
+        Some((name, ModelStatus::NotInstalled)) => PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Error,
+            format!("{name} is not installed"),
+        )
+        .with_remedy("Install the selected model from Settings > Models.")
+        .with_route(events::model_route(&name)),
+        Some((name, ModelStatus::Error(message))) => PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Error,
+            format!("{name} failed to install: {message}"),
+        )
+        .with_remedy("Retry the download from Settings > Models.")
+        .with_route(events::model_route(&name)),
+        None => PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Error,
+            "No model asset matches the selected ASR settings",
+        )
+        .with_remedy("Pick a different model in Settings.")
+        .with_route(events::ROUTE_MODELS),
+    }
+}
+
+fn check_vad_model(status: Option<ModelStatus>) -> PreflightCheck {
+    const ID: &str = "vad-model";
+    const LABEL: &str = "Voice activity model";
+
+    match status {
+        Some(ModelStatus::Installed) => {
+            PreflightCheck::new(ID, LABEL, PreflightSeverity::Ok, "Silero VAD installed")
+        }
+        _ => PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Warning,
+            "Silero VAD not installed; using the energy detector",
+        )
+        .with_remedy("Install the VAD model from Settings > Models for better speech trimming."),
+    }
+}
+
+fn check_pipeline(state: &AppState) -> PreflightCheck {
+    const ID: &str = "pipeline";
+    const LABEL: &str = "Speech pipeline";
+
+    if state.pipeline_handle().is_none() {
+        return PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Error,
+            "Speech pipeline is not initialized",
+        )
+        .with_remedy("Restart OpenFlow; check the logs if this persists.");
+    }
+
+    match state.asr_warmup_state() {
+        AsrWarmupState::Ready => {
+            PreflightCheck::new(ID, LABEL, PreflightSeverity::Ok, "Pipeline ready")
+        }
+        AsrWarmupState::Warming => PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Info,
+            "Speech model is still loading",
+        ),
+        AsrWarmupState::Error => PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Error,
+            format!(
+                "Speech model failed to load: {}",
+                state
+                    .asr_warmup_error()
+                    .unwrap_or_else(|| "unknown error".to_string())
+            ),
+        )
+        .with_remedy("Reinstall the model or select a different one in Settings.")
+        .with_route(events::ROUTE_MODELS),
+    }
+}
+
+fn check_audio_device(state: &AppState) -> PreflightCheck {
+    const ID: &str = "audio-device";
+    const LABEL: &str = "Microphone";
+
+    let preferences = state
+        .settings_manager()
+        .read_frontend()
+        .map(|settings| settings.audio_device_preferences)
+        .unwrap_or_default();
+    let devices = list_input_devices();
+
+    if devices.is_empty() {
+        return PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Error,
+            "No input devices found",
+        )
+        .with_remedy("Connect a microphone and check PipeWire/PulseAudio settings.");
+    }
+
+    if !preferences.is_empty() {
+        let names: Vec<String> = devices.iter().map(|device| device.id.clone()).collect();
+        if preferred_device_index(&preferences, &names).is_none() {
+            return PreflightCheck::new(
+                ID,
+                LABEL,
+                PreflightSeverity::Warning,
+                "None of the preferred devices is connected; using the system default",
+            )
+            .with_remedy("Connect a preferred microphone or update the list in Settings.")
+            .with_route(events::ROUTE_GENERAL);
+        }
+    }
+
+    let Some(pipeline) = state.pipeline_handle() else {
+        return PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Warning,
+            "Audio capture not started",
+        );
+    };
+
+    if pipeline.audio_is_synthetic() {
+        return PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Error,
+            "Audio capture fell back to a synthetic source",
+        )
+        .with_remedy("Check that the microphone is not held exclusively by another app.");
+    }
+
+    if !pipeline.has_recent_audio_ingress(std::time::Duration::from_secs(2)) {
+        return PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Warning,
+            "No audio frames received in the last 2s",
+        );
+    }
+
+    match pipeline.active_audio_device() {
+        Some(device) => PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Ok,
+            format!("Receiving audio from {device}"),
+        ),
+        None => PreflightCheck::new(ID, LABEL, PreflightSeverity::Ok, "Receiving audio"),
+    }
+}
+
+fn check_clipboard(linux: &linux_setup::LinuxPermissionsStatus) -> PreflightCheck {
+    const ID: &str = "clipboard";
+    const LABEL: &str = "Clipboard tools";
+
+    if linux.wayland_session {
+        if linux.wl_copy_available && linux.wl_paste_available {
+            PreflightCheck::new(ID, LABEL, PreflightSeverity::Ok, "wl-clipboard available")
+        } else {
+            PreflightCheck::new(
+                ID,
+                LABEL,
+                PreflightSeverity::Error,
+                "wl-copy/wl-paste missing",
+            )
+            .with_remedy("Install the wl-clipboard package.")
+        }
+    } else if linux.xclip_available {
+        PreflightCheck::new(ID, LABEL, PreflightSeverity::Ok, "xclip available")
+    } else {
+        PreflightCheck::new(ID, LABEL, PreflightSeverity::Error, "xclip missing")
+            .with_remedy("Install the xclip package.")
+    }
+}
+
+fn check_key_injection(linux: &linux_setup::LinuxPermissionsStatus) -> PreflightCheck {
+    const ID: &str = "key-injection";
+    const LABEL: &str = "Paste injection";
+
+    if linux.virtual_keyboard_injection {
+        PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Ok,
+            "Wayland virtual keyboard protocol",
+        )
+    } else if linux.uinput_writable {
+        PreflightCheck::new(ID, LABEL, PreflightSeverity::Ok, "/dev/uinput writable")
+    } else if linux.x11_session && linux.x11_xtest_available {
+        PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Ok,
+            "XTEST available (uinput not writable)",
+        )
+    } else {
+        PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Error,
+            "/dev/uinput is not writable",
+        )
+        .with_remedy("Use \"Enable permissions\" in Settings, then log out and back in.")
+        .with_route(events::ROUTE_PERMISSIONS)
+    }
+}
+
+fn check_hotkey_backend(
+    registration: Option<(String, &'static str)>,
+    linux: &linux_setup::LinuxPermissionsStatus,
+) -> PreflightCheck {
+    const ID: &str = "hotkey-backend";
+    const LABEL: &str = "Global hotkey";
+
+    match registration {
+        Some((hotkey, backend)) => PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Ok,
+            format!("{hotkey} registered via {backend}"),
+        ),
+        None if !linux.evdev_readable && !linux.x11_hotkeys_available => PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Error,
+            "No hotkey registered and /dev/input is not readable",
+        )
+        .with_remedy("Use \"Enable permissions\" in Settings, then log out and back in.")
+        .with_route(events::ROUTE_PERMISSIONS),
+        None => PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Warning,
+            "No hotkey registered",
+        ),
+    }
+}
+
+fn check_overlay() -> PreflightCheck {
+    const ID: &str = "overlay";
+    const LABEL: &str = "HUD overlay";
+
+    if window_overlay_supported() {
+        return PreflightCheck::new(ID, LABEL, PreflightSeverity::Ok, "Window overlay supported");
+    }
+
+    let extension = linux_setup::gnome_hud_extension_status();
+    if extension.enabled {
+        PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Ok,
+            "GNOME HUD extension enabled",
+        )
+    } else {
+        PreflightCheck::new(
+            ID,
+            LABEL,
+            PreflightSeverity::Info,
+            "GNOME Wayland needs the HUD extension for an overlay",
+        )
+        .with_remedy("Install the GNOME HUD extension from Settings.")
+        .with_route(events::ROUTE_GENERAL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::linux_setup::{AccessWatchState, LinuxPermissionsStatus};
+
+    fn x11_ready() -> LinuxPermissionsStatus {
+        LinuxPermissionsStatus {
+            supported: true,
+            wayland_session: false,
+            x11_session: true,
+            x11_display_available: true,
+            x11_hotkeys_available: true,
+            x11_xtest_available: true,
+            xdg_runtime_dir_available: true,
+            evdev_readable: true,
+            uinput_writable: true,
+            virtual_keyboard_injection: false,
+            clipboard_backend: "xclip".into(),
+            wl_copy_available: false,
+            wl_paste_available: false,
+            xclip_available: true,
+            pkexec_available: true,
+            setfacl_available: true,
+            input_group_pending_relogin: false,
+            access_watch: AccessWatchState::Idle,
+            setup_steps: Vec::new(),
+            compositor: None,
+            details: Vec::new(),
+        }
+    }
+
+    fn wayland(linux: LinuxPermissionsStatus) -> LinuxPermissionsStatus {
+        LinuxPermissionsStatus {
+            wayland_session: true,
+            x11_session: false,
+            x11_hotkeys_available: false,
+            x11_xtest_available: false,
+            wl_copy_available: true,
+            wl_paste_available: true,
+            xclip_available: false,
+            ..linux
+        }
+    }
+
+    #[test]
+    fn passes_when_everything_is_ready() {
+        let linux = x11_ready();
+        let checks = [
+            check_asr_model(Some(("parakeet".into(), ModelStatus::Installed))),
+            check_vad_model(Some(ModelStatus::Installed)),
+            check_clipboard(&linux),
+            check_key_injection(&linux),
+            check_hotkey_backend(Some(("Ctrl+Space".into(), "x11")), &linux),
+        ];
+        assert!(checks
+            .iter()
+            .all(|check| check.severity == PreflightSeverity::Ok && check.remedy.is_none()));
+        assert_eq!(overall_severity(&checks), PreflightSeverity::Ok);
+        assert_eq!(
+            check_clipboard(&wayland(x11_ready())).severity,
+            PreflightSeverity::Ok
+        );
+    }
+
+    #[test]
+    fn reports_missing_or_failed_models() {
+        let downloading = check_asr_model(Some((
+            "parakeet".into(),
+            ModelStatus::Downloading {
+                progress: 0.5,
+                downloaded_bytes: 50,
+                total_bytes: Some(100),
+                bytes_per_sec: None,
+                eta_secs: None,
+            },
+        )));
+        assert_eq!(downloading.severity, PreflightSeverity::Info);
+        assert_eq!(downloading.message, "parakeet downloading (50%)");
+
+        let missing = check_asr_model(Some(("parakeet".into(), ModelStatus::NotInstalled)));
+        assert_eq!(missing.severity, PreflightSeverity::Error);
+        assert_eq!(missing.route.as_deref(), Some("models/parakeet"));
+
+        let failed = check_asr_model(Some((
+            "parakeet".into(),
+            ModelStatus::Error("checksum mismatch".into()),
+        )));
+        assert_eq!(failed.severity, PreflightSeverity::Error);
+        assert!(failed.message.contains("checksum mismatch"));
+
+        let unmatched = check_asr_model(None);
+        assert_eq!(unmatched.severity, PreflightSeverity::Error);
+        assert_eq!(unmatched.route.as_deref(), Some(events::ROUTE_MODELS));
+
+        assert_eq!(check_vad_model(None).severity, PreflightSeverity::Warning);
+        assert_eq!(
+            check_vad_model(Some(ModelStatus::NotInstalled)).severity,
+            PreflightSeverity::Warning
+        );
+    }
+
+    #[test]
+    fn reports_missing_clipboard_tools() {
+        let x11 = LinuxPermissionsStatus {
+            xclip_available: false,
+            ..x11_ready()
+        };
+        let check = check_clipboard(&x11);
+        assert_eq!(check.severity, PreflightSeverity::Error);
+        assert_eq!(check.message, "xclip missing");
+
+        let wayland = LinuxPermissionsStatus {
+            wl_paste_available: false,
+            ..wayland(x11_ready())
+        };
+        let check = check_clipboard(&wayland);
+        assert_eq!(check.severity, PreflightSeverity::Error);
+        assert!(check.remedy.is_some());
+    }
+
+    #[test]
+    fn falls_back_through_injection_backends() {
+        let xtest_only = LinuxPermissionsStatus {
+            uinput_writable: false,
+            ..x11_ready()
+        };
+        assert_eq!(
+            check_key_injection(&xtest_only).severity,
+            PreflightSeverity::Ok
+        );
+
+        let virtual_keyboard = LinuxPermissionsStatus {
+            uinput_writable: false,
+            virtual_keyboard_injection: true,
+            ..wayland(x11_ready())
+        };
+        assert_eq!(
+            check_key_injection(&virtual_keyboard).severity,
+            PreflightSeverity::Ok
+        );
+
+        let none = LinuxPermissionsStatus {
+            uinput_writable: false,
+            ..wayland(x11_ready())
+        };
+        let check = check_key_injection(&none);
+        assert_eq!(check.severity, PreflightSeverity::Error);
+        assert_eq!(check.route.as_deref(), Some(events::ROUTE_PERMISSIONS));
+    }
+
+    #[test]
+    fn reports_unregistered_hotkeys() {
+        let unregistered = check_hotkey_backend(None, &x11_ready());
+        assert_eq!(unregistered.severity, PreflightSeverity::Warning);
+
+        let no_access = LinuxPermissionsStatus {
+            evdev_readable: false,
+            ..wayland(x11_ready())
+        };
+        let check = check_hotkey_backend(None, &no_access);
+        assert_eq!(check.severity, PreflightSeverity::Error);
+        assert_eq!(check.route.as_deref(), Some(events::ROUTE_PERMISSIONS));
+
+        let checks = [unregistered, check];
+        assert_eq!(overall_severity(&checks), PreflightSeverity::Error);
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Serialize;
+
+use crate::core::settings::FrontendSettings;
+use crate::llm::is_local_endpoint;
+
+/// Mirrors `strict_privacy` for code paths that don't read settings (log lines, exports).
+static STRICT: AtomicBool = AtomicBool::new(false);
+
+/// Returns true when the value changed.
+pub fn set_strict(enabled: bool) -> bool {
+    STRICT.swap(enabled, Ordering::SeqCst) != enabled
+}
+
+pub fn strict() -> bool {
+    STRICT.load(Ordering::SeqCst)
+}
+
+/// Turns off every setting that keeps transcripts or audio beyond the session. Runs in
+/// settings migration, so a write can't re-enable them while strict privacy is on. New
+/// persistence or network features must be switched off here too. Corrections have no
+/// setting; `AppState::submit_correction` checks `strict()` instead.
+pub fn enforce(settings: &mut FrontendSettings) {
+    if !settings.strict_privacy {
+        return;
+    }
+    settings.debug_transcripts = false;
+    settings.keep_failed_audio = false;
+    settings.export_session_traces = false;
+    settings.history_enabled = false;
+    // Polish may only send transcripts to a server on this machine.
+    settings.polish_allow_remote = false;
+    if settings.autoclean_mode == "polish" && !is_local_endpoint(&settings.polish_endpoint) {
+        settings.autoclean_mode = "fast".into();
+    }
+}
+
+/// What currently leaves the session, sent as `privacy-posture` whenever settings apply.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivacyPosture {
+    pub strict: bool,
+    pub debug_transcripts: bool,
+    /// Audio of failed dictations kept for replay.
+    pub failed_audio: bool,
+    pub session_traces: bool,
+    pub history: bool,
+    /// Original/corrected transcript pairs saved from the review step.
+    pub corrections: bool,
+    /// Transcript text in the in-app log buffer.
+    pub transcript_logs: bool,
+}
+
+pub fn posture(settings: &FrontendSettings) -> PrivacyPosture {
+    PrivacyPosture {
+        strict: settings.strict_privacy,
+        debug_transcripts: settings.debug_transcripts,
+        failed_audio: settings.keep_failed_audio,
+        session_traces: settings.export_session_traces,
+        history: settings.history_enabled,
+        corrections: !settings.strict_privacy,
+        transcript_logs: !settings.strict_privacy,
+    }
+}
+
+/// Transcript text for the log buffer: only its length under strict privacy.
+pub fn loggable(text: &str) -> String {
+    if strict() {
+        format!("[{} chars redacted]", text.chars().count())
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strict_privacy_forces_persistence_off() {
+        let mut settings = FrontendSettings {
+            strict_privacy: true,
+            debug_transcripts: true,
+            keep_failed_audio: true,
+            export_session_traces: true,
+            history_enabled: true,
+            ..FrontendSettings::default()
+        };
+        enforce(&mut settings);
+        assert_eq!(
+            posture(&settings),
+            PrivacyPosture {
+                strict: true,
+                debug_transcripts: false,
+                failed_audio: false,
+                session_traces: false,
+                history: false,
+                corrections: false,
+                transcript_logs: false,
+            }
+        );
+    }
+}
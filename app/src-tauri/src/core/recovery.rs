@@ -0,0 +1,68 @@
+//! Clearing caches and stale runtime state when they wedge the app.
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use crate::core::app_state::AppState;
+use crate::core::updater;
+use crate::models;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ResetScope {
+    /// Cached latest.json, downloaded update payloads and release notes.
+    UpdateCache,
+    /// Leftovers of interrupted model downloads and statuses stuck on downloading.
+    ModelStaging,
+    /// The runtime HUD state file the GNOME extension reads.
+    HudState,
+    /// All user settings, back to defaults.
+    Settings,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetReport {
+    pub cleared: Vec<ResetScope>,
+    pub reclaimed_bytes: u64,
+    /// Models whose staging was kept because they are downloading.
+    pub skipped_models: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+/// Clears each scope independently; one failing scope does not stop the others.
+/// Re-applying reset settings is left to the caller.
+pub fn reset_app_state(app: &AppHandle, scopes: &[ResetScope]) -> ResetReport {
+    let mut report = ResetReport::default();
+    let state = app.state::<AppState>();
+    for &scope in scopes {
+        let result = match scope {
+            ResetScope::UpdateCache => updater::clear_update_cache().map(|reclaimed| {
+                report.reclaimed_bytes += reclaimed;
+            }),
+            ResetScope::ModelStaging => models::clear_download_staging(&state.model_manager(), app)
+                .map(|cleanup| {
+                    report.reclaimed_bytes += cleanup.reclaimed_bytes;
+                    report.skipped_models.extend(cleanup.skipped);
+                }),
+            ResetScope::HudState => {
+                state.reset_hud_runtime_state(app);
+                Ok(())
+            }
+            ResetScope::Settings => state.settings_manager().reset(),
+        };
+        match result {
+            Ok(()) => report.cleared.push(scope),
+            Err(error) => {
+                tracing::warn!("Reset of {scope:?} failed: {error:?}");
+                report.errors.push(format!("{scope:?}: {error}"));
+            }
+        }
+    }
+    tracing::info!(
+        "app_state_reset cleared={:?} reclaimed_bytes={}",
+        report.cleared,
+        report.reclaimed_bytes
+    );
+    report
+}
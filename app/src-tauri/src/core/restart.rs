@@ -0,0 +1,106 @@
+//! Relaunching the running install in place.
+//!
+//! The replacement is spawned from the same executable with the same arguments
+//! and environment, then waits for this process to exit before it starts, so it
+//! never races the old instance for the control socket or the models lock.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use tracing::{info, warn};
+
+/// Pid the relaunched process waits on. This is the only one-shot launch state: it
+/// is consumed at startup, so it is never passed on by a later restart.
+const RESTART_WAIT_PID_ENV: &str = "OPENFLOW_RESTART_WAIT_PID";
+const RESTART_WAIT_TIMEOUT: Duration = Duration::from_secs(10);
+const RESTART_WAIT_POLL: Duration = Duration::from_millis(50);
+
+/// Spawns a replacement for this process. The caller exits once this returns Ok.
+pub fn spawn_replacement() -> Result<()> {
+    let executable = current_executable()?;
+    let args: Vec<OsString> = std::env::args_os().skip(1).collect();
+    info!(
+        "Restarting via {executable:?} with {} argument(s)",
+        args.len()
+    );
+
+    Command::new(&executable)
+        .args(&args)
+        .env(RESTART_WAIT_PID_ENV, std::process::id().to_string())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("spawn {}", executable.display()))?;
+    Ok(())
+}
+
+/// Blocks startup until the instance that spawned us has exited. Call before
+/// anything claims per-user resources.
+pub fn wait_for_previous_instance() {
+    let Some(pid) = std::env::var_os(RESTART_WAIT_PID_ENV) else {
+        return;
+    };
+    std::env::remove_var(RESTART_WAIT_PID_ENV);
+    let Some(pid) = pid.to_str().and_then(|pid| pid.parse::<u32>().ok()) else {
+        return;
+    };
+
+    let proc_dir = PathBuf::from(format!("/proc/{pid}"));
+    let started = Instant::now();
+    while proc_dir.exists() {
+        if started.elapsed() >= RESTART_WAIT_TIMEOUT {
+            warn!("Previous instance (pid {pid}) still running; starting anyway");
+            return;
+        }
+        std::thread::sleep(RESTART_WAIT_POLL);
+    }
+}
+
+/// The file to re-exec. AppImages run from a mount that disappears with this
+/// process, so the image itself is relaunched; otherwise `/proc/self/exe`, with
+/// the on-disk path preferred when an update has replaced the binary since.
+fn current_executable() -> Result<PathBuf> {
+    if let Some(appimage) = std::env::var_os("APPIMAGE").map(PathBuf::from) {
+        if appimage.is_file() {
+            return Ok(appimage);
+        }
+    }
+
+    let proc_exe = Path::new("/proc/self/exe");
+    let target = std::fs::read_link(proc_exe).context("resolve /proc/self/exe")?;
+    match replaced_binary_path(&target) {
+        Some(path) if path.is_file() => Ok(path),
+        Some(_) => Ok(proc_exe.to_path_buf()),
+        None => Ok(target),
+    }
+}
+
+/// The original path of an executable that was replaced or removed while running,
+/// which the kernel reports as `<path> (deleted)`.
+fn replaced_binary_path(target: &Path) -> Option<PathBuf> {
+    target
+        .to_str()?
+        .strip_suffix(" (deleted)")
+        .map(PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replaced_binary_path_strips_deleted_marker() {
+        assert_eq!(
+            replaced_binary_path(Path::new("/opt/openflow/openflow (deleted)")),
+            Some(PathBuf::from("/opt/openflow/openflow"))
+        );
+        assert_eq!(
+            replaced_binary_path(Path::new("/opt/openflow/openflow")),
+            None
+        );
+    }
+}
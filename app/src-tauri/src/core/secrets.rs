@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{anyhow, bail, Context, Result};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use tracing::{debug, warn};
+
+use crate::core::linux_setup::binary_in_path;
+
+/// Hugging Face access token, sent with model metadata and file requests.
+pub const SECRET_HF_TOKEN: &str = "hf-token";
+pub const SECRET_REMOTE_LLM_API_KEY: &str = "remote-llm-api-key";
+/// Key for the encrypted debug capture; created by `core::debug_capture`, not the UI.
+pub const SECRET_DEBUG_CAPTURE_KEY: &str = "debug-capture-key";
+
+/// Secret ids that may be stored; settings reference secrets by these ids only.
+pub const KNOWN_SECRETS: &[&str] = &[
+    SECRET_HF_TOKEN,
+    SECRET_REMOTE_LLM_API_KEY,
+    SECRET_DEBUG_CAPTURE_KEY,
+];
+
+/// libsecret's CLI; talks to the Secret Service (GNOME Keyring, KWallet) over D-Bus.
+const SECRET_TOOL: &str = "secret-tool";
+const ATTR_SERVICE: &str = "service";
+const SERVICE: &str = "openflow";
+const ATTR_SECRET: &str = "secret";
+
+/// Looked-up values, so requests don't spawn `secret-tool` each time. `None` = not stored.
+/// Failed lookups (keyring locked, daemon missing) are never cached, so they're retried.
+static CACHE: Lazy<Mutex<HashMap<String, Option<String>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+pub fn is_known(id: &str) -> bool {
+    KNOWN_SECRETS.contains(&id)
+}
+
+/// Whether a Secret Service client is installed; the keyring daemon itself is only
+/// checked when a secret is used.
+pub fn available() -> bool {
+    binary_in_path(SECRET_TOOL)
+}
+
+/// Stores `value` in the keyring under `id`; an empty value clears it.
+pub fn set(id: &str, value: &str) -> Result<()> {
+    ensure_known(id)?;
+    let value = value.trim();
+    if value.is_empty() {
+        return clear(id);
+    }
+
+    let label = format!("OpenFlow {id}");
+    let mut child = Command::new(SECRET_TOOL)
+        .args([
+            "store",
+            "--label",
+            &label,
+            ATTR_SERVICE,
+            SERVICE,
+            ATTR_SECRET,
+            id,
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("spawn secret-tool (install libsecret-tools)")?;
+    // The value goes over stdin so it never shows up in the process list.
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| anyhow!("secret-tool stdin unavailable"))?
+        .write_all(value.as_bytes())
+        .context("write secret to secret-tool")?;
+    let output = child.wait_with_output().context("wait for secret-tool")?;
+    if !output.status.success() {
+        bail!(
+            "secret-tool store failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    CACHE.lock().insert(id.to_string(), Some(value.to_string()));
+    debug!("secret_stored id={id}");
+    Ok(())
+}
+
+pub fn clear(id: &str) -> Result<()> {
+    ensure_known(id)?;
+    let output = Command::new(SECRET_TOOL)
+        .args(["clear", ATTR_SERVICE, SERVICE, ATTR_SECRET, id])
+        .output()
+        .context("spawn secret-tool (install libsecret-tools)")?;
+    if !output.status.success() {
+        bail!(
+            "secret-tool clear failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    CACHE.lock().insert(id.to_string(), None);
+    debug!("secret_cleared id={id}");
+    Ok(())
+}
+
+pub fn get(id: &str) -> Result<Option<String>> {
+    ensure_known(id)?;
+    if let Some(cached) = CACHE.lock().get(id) {
+        return Ok(cached.clone());
+    }
+
+    let output = Command::new(SECRET_TOOL)
+        .args(["lookup", ATTR_SERVICE, SERVICE, ATTR_SECRET, id])
+        .output()
+        .context("spawn secret-tool (install libsecret-tools)")?;
+    // `lookup` exits non-zero without a message when nothing is stored.
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !output.status.success() && !stderr.trim().is_empty() {
+        bail!("secret-tool lookup failed: {}", stderr.trim());
+    }
+    let value = output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|value| !value.is_empty());
+
+    CACHE.lock().insert(id.to_string(), value.clone());
+    Ok(value)
+}
+
+/// `get` for callers that can proceed without the secret, e.g. anonymous downloads.
+pub fn lookup(id: &str) -> Option<String> {
+    match get(id) {
+        Ok(value) => value,
+        Err(error) => {
+            warn!("secret_lookup_failed id={id} error={error:#}");
+            None
+        }
+    }
+}
+
+fn ensure_known(id: &str) -> Result<()> {
+    if !is_known(id) {
+        bail!("unknown secret id: {id}");
+    }
+    Ok(())
+}
@@ -1,38 +1,204 @@
 use std::{
+    collections::BTreeMap,
     fs,
+    io::Write,
     path::{Path, PathBuf},
 };
 
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use serde::{Deserialize, Serialize};
 use time::{Duration, OffsetDateTime};
 
+use crate::asr::DEFAULT_MAX_SESSION_SECS;
+use crate::core::performance::PerformancePolicy;
+use crate::core::privacy;
+use crate::core::secrets;
+use crate::core::settings_journal::{ChangeSource, SettingsChange, SettingsJournal, JOURNAL_FILE};
+use crate::core::voice_commands::{self, VoiceCommand};
+use crate::llm::{
+    PauseThresholds, BUILTIN_REDACTIONS, DEFAULT_POLISH_ENDPOINT, DEFAULT_POLISH_MODEL,
+};
+use crate::vad::DEFAULT_SPEAKER_THRESHOLD;
+
 const CONFIG_FILE: &str = "config.json";
-const DEBUG_TRANSCRIPT_TTL: Duration = Duration::hours(24);
+/// Previous good configs kept as `config.json.bak.1` (newest) through `.bak.N`.
+const CONFIG_BACKUPS: usize = 3;
+pub const DEBUG_TRANSCRIPT_TTL: Duration = Duration::hours(24);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
 pub struct FrontendSettings {
+    /// "hold" (push-to-talk), "toggle", or "hybrid": a short tap toggles, a long
+    /// press behaves as push-to-talk.
     pub hotkey_mode: String,
+    /// In hybrid mode, presses held at least this long stop dictation on release.
+    pub hybrid_hold_threshold_ms: u32,
     pub push_to_talk_hotkey: String,
     pub toggle_to_talk_hotkey: String,
+    /// Chord that re-copies the last transcript to the clipboard; empty disables it.
+    pub recopy_transcript_hotkey: String,
+    /// Hold-to-talk chord for voice commands; empty disables command mode.
+    pub command_hotkey: String,
+    /// Phrases spoken after the command hotkey and what each one does.
+    pub voice_commands: Vec<VoiceCommand>,
+    /// Chord that turns continuous (always listening) dictation on and off; empty disables it.
+    pub continuous_hotkey: String,
+    /// Pause that ends an utterance in continuous dictation.
+    pub continuous_end_silence_ms: u32,
     pub hud_theme: String,
+    /// `#rrggbb` tint for the listening HUD; `None` keeps the built-in palette.
+    pub hud_accent_color: Option<String>,
     #[serde(alias = "showOverlayOnWayland")]
     pub show_hud_overlay: bool,
+    /// Keep the overlay hidden while the focused window is fullscreen (games, slides).
+    pub suppress_overlay_in_fullscreen: bool,
+    /// Length of the overlay's entrance animation.
+    pub overlay_show_animation_ms: u32,
+    /// How long the overlay window stays up after going idle, for its exit animation.
+    pub overlay_hide_delay_ms: u32,
+    /// How long HUD toasts (language switches, delivered transcripts) stay visible.
+    pub hud_toast_ms: u32,
+    /// After a successful paste, show the pasted text on the HUD.
+    pub transcript_toast: bool,
+    /// Keep transcripts and audio inside the session: forces off debug transcripts, trace
+    /// export and transcript log lines (see `core::privacy`).
+    pub strict_privacy: bool,
+    /// Give the window overlay Cancel/Done buttons instead of making it click-through.
+    pub interactive_overlay: bool,
     pub asr_family: String,
     pub whisper_backend: String,
     pub whisper_model: String,
     pub whisper_model_language: String,
     pub whisper_precision: String,
+    /// Prefetch ASR weights through a shared page-cache mapping before loading.
+    pub mmap_model_weights: bool,
+    /// Switch back to the last model that warmed up when the selected one fails to.
+    /// Off leaves the failing selection in place and only reports the error.
+    pub auto_revert_asr: bool,
+    /// When launch-time warmup and default-model downloads start: "immediate",
+    /// "delay" (after `startup_warmup_secs`), "idle" (once the CPU has been idle that
+    /// long) or "hotkey" (on the first dictation).
+    pub startup_warmup: String,
+    pub startup_warmup_secs: u32,
+    /// Where ASR runs: "cpu", "auto" (first GPU found), "cuda" or "rocm". The
+    /// `SHERPA_PROVIDER` and `CT2_DEVICE` env vars still override it.
+    pub asr_device: String,
+    /// Longest dictation kept, in seconds; the start of a longer one is dropped.
+    pub max_dictation_secs: u32,
+    /// Transcribe the whole recording even when the VAD heard too little speech,
+    /// for soft voices that keep getting rejected as silence.
+    pub always_transcribe: bool,
+    /// Paste chord in hotkey syntax, e.g. "Ctrl+Shift+V" or "Shift+Insert".
     pub paste_shortcut: String,
+    /// Per-app paste chords keyed by X11 window class (e.g. "xterm" -> "Shift+Insert").
+    pub app_paste_shortcuts: BTreeMap<String, String>,
+    /// Retry a failed paste with Shift+Insert (and the PRIMARY selection) before reporting it.
+    pub paste_fallback_shift_insert: bool,
+    /// Type transcripts out as keystrokes instead of pasting, for apps that block paste.
+    pub type_out: bool,
+    /// Pause after each typed character in ms.
+    pub type_out_char_delay_ms: u32,
+    /// Paste multi-line text into terminals instead of typing it, where each typed
+    /// newline would run a line.
+    pub type_out_terminal_paste: bool,
+    /// Hold the final transcript for review in the main window; paste only once confirmed.
+    pub confirm_before_paste: bool,
+    /// Alternative transcripts to offer during review (CT2 Whisper only); 0 disables.
+    pub transcript_alternatives: u32,
+    /// Built-in redactions to apply ("credit-card", "email").
+    pub redaction_rules: Vec<String>,
+    /// Extra regexes whose matches are redacted.
+    pub custom_redactions: Vec<String>,
+    /// Also redact the pasted text; otherwise only logged and stored copies are redacted.
+    pub redact_before_paste: bool,
     pub language: String,
     pub auto_detect_language: bool,
+    /// Second language for code-switched dictation with multilingual CT2 Whisper models:
+    /// each segment may be in `language` or this one. Empty disables it.
+    pub secondary_language: String,
+    /// Chord that moves `language` to the next entry of `language_cycle`; empty disables it.
+    pub language_cycle_hotkey: String,
+    /// Languages the cycle hotkey steps through, e.g. `["en", "de"]`.
+    pub language_cycle: Vec<String>,
+    /// ASR selection to switch to when cycling to a language; languages without an
+    /// entry keep the current model.
+    pub language_models: BTreeMap<String, AsrSelection>,
+    /// Names, jargon and acronyms the recognizer should favour, one term per entry.
+    pub custom_vocabulary: Vec<String>,
+    /// Turn pauses in Parakeet dictations into commas and sentence breaks.
+    pub pause_punctuation: bool,
+    /// Pause thresholds keyed by language code; "default" covers the rest.
+    pub pause_thresholds: BTreeMap<String, PauseThresholds>,
+    /// "off", "fast" or "polish".
     pub autoclean_mode: String,
+    /// Base URL of the local LLM server used by polish mode.
+    pub polish_endpoint: String,
+    /// "ollama" or "openai" (llama.cpp's OpenAI-compatible server).
+    pub polish_api: String,
+    pub polish_model: String,
+    /// Prompt with a `{text}` placeholder; empty uses the built-in one.
+    pub polish_prompt: String,
+    /// Longest wait for the LLM before falling back to fast cleanup.
+    pub polish_timeout_ms: u32,
+    /// The user agreed to send transcripts (and the stored API key) to a polish server
+    /// on another machine. Without it only loopback endpoints are used.
+    pub polish_allow_remote: bool,
+    /// Locale tag for decimal/quote/date style in cleaned text ("off", "auto", or e.g. "de-DE").
+    pub formatting_locale: String,
+    /// Language of notifications and hints from the backend ("system" or e.g. "de").
+    pub message_locale: String,
     pub debug_transcripts: bool,
+    /// Keep the audio of dictations that produced no transcript for replay.
+    pub keep_failed_audio: bool,
+    pub failed_audio_retention_days: u32,
+    /// Audio/VAD diagnostics emission interval while dictating; 0 turns them off.
+    pub diagnostics_interval_ms: u32,
+    /// "normal" or "low-power": built-in rate limits for metrics, diagnostics, model
+    /// status and download progress events.
+    pub event_rate_profile: String,
+    /// Per-event minimum interval overrides keyed by event name; 0 removes the limit.
+    pub event_min_interval_ms: BTreeMap<String, u32>,
+    /// Write each dictation's stage timeline as a Chrome trace under the cache dir.
+    pub export_session_traces: bool,
+    /// Keep delivered transcripts in the local history store.
+    pub history_enabled: bool,
+    /// Thresholds, hysteresis and actions for performance mode.
+    pub performance_policy: PerformancePolicy,
+    /// What to do when performance mode persists: "off", "offer" a lighter model, or
+    /// switch to it "auto"matically.
+    pub model_downshift: String,
+    /// Short audible cues for session start/stop, paste success and errors.
+    pub sound_cues_enabled: bool,
+    /// "soft", "click" or "chime".
+    pub sound_cue_theme: String,
+    pub sound_cue_start: bool,
+    pub sound_cue_stop: bool,
+    pub sound_cue_paste: bool,
+    pub sound_cue_error: bool,
+    /// Single-device choice from older builds; migrated into `audio_device_preferences`.
     pub audio_device_id: Option<String>,
+    /// Input device names in priority order, e.g. headset, then webcam mic. Empty uses
+    /// the system default.
+    pub audio_device_preferences: Vec<String>,
+    /// Open the microphone only while dictating instead of capturing continuously.
+    pub on_demand_capture: bool,
+    /// Switch the Bluetooth headset named in `audio_device_preferences` from A2DP to its
+    /// hands-free profile (which has the mic) for each dictation and back afterwards.
+    pub bluetooth_auto_profile: bool,
     pub vad_sensitivity: String,
+    /// Only transcribe speech matching the enrolled speaker profile.
+    pub speaker_gate: bool,
+    /// Cosine similarity an utterance needs to pass the speaker gate.
+    pub speaker_gate_threshold: f32,
+    /// Background app update checks: "daily", "weekly" or "never".
+    pub update_check_cadence: String,
+    /// Download a found update in the background so applying it is immediate.
+    pub pre_download_updates: bool,
+    /// Ids of secrets held in the system keyring (see `core::secrets`); the values never
+    /// touch this file. Owned by the backend: `write_frontend` keeps the stored list.
+    pub stored_secrets: Vec<String>,
     #[serde(default, skip_serializing)]
     #[serde(rename = "asrBackend")]
     pub legacy_asr_backend: Option<String>,
@@ -72,6 +238,23 @@ impl AsrSelection {
     }
 }
 
+/// Hotword decoding slows down with every term; beyond this the list is cut off.
+const MAX_VOCABULARY_TERMS: usize = 200;
+
+/// Longer pauses would make a sentence take minutes to type.
+const MAX_TYPE_OUT_CHAR_DELAY_MS: u32 = 200;
+
+impl FrontendSettings {
+    /// Pause thresholds for the dictation language, falling back to the "default" entry.
+    pub fn pause_thresholds_for_language(&self) -> PauseThresholds {
+        self.pause_thresholds
+            .get(&self.language)
+            .or_else(|| self.pause_thresholds.get("default"))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
 // Linux-only defaults.
 pub const DEFAULT_PUSH_TO_TALK_HOTKEY: &str = "RightAlt";
 pub const DEFAULT_TOGGLE_TO_TALK_HOTKEY: &str = "RightAlt";
@@ -80,22 +263,91 @@ impl Default for FrontendSettings {
     fn default() -> Self {
         Self {
             hotkey_mode: "hold".into(),
+            hybrid_hold_threshold_ms: 350,
             push_to_talk_hotkey: DEFAULT_PUSH_TO_TALK_HOTKEY.into(),
             toggle_to_talk_hotkey: DEFAULT_TOGGLE_TO_TALK_HOTKEY.into(),
+            recopy_transcript_hotkey: String::new(),
+            command_hotkey: String::new(),
+            voice_commands: voice_commands::default_commands(),
+            continuous_hotkey: String::new(),
+            continuous_end_silence_ms: 900,
             hud_theme: "system".into(),
+            hud_accent_color: None,
             show_hud_overlay: false,
+            suppress_overlay_in_fullscreen: true,
+            overlay_show_animation_ms: 420,
+            overlay_hide_delay_ms: 260,
+            hud_toast_ms: 1_800,
+            transcript_toast: false,
+            strict_privacy: false,
+            interactive_overlay: false,
             asr_family: "parakeet".into(),
             whisper_backend: "ct2".into(),
             whisper_model: "small".into(),
             whisper_model_language: "multi".into(),
             whisper_precision: "int8".into(),
-            paste_shortcut: "ctrl-shift-v".into(),
+            mmap_model_weights: true,
+            auto_revert_asr: true,
+            startup_warmup: "idle".into(),
+            startup_warmup_secs: 5,
+            asr_device: "cpu".into(),
+            max_dictation_secs: DEFAULT_MAX_SESSION_SECS,
+            always_transcribe: false,
+            paste_shortcut: "Ctrl+Shift+V".into(),
+            app_paste_shortcuts: BTreeMap::new(),
+            paste_fallback_shift_insert: false,
+            type_out: false,
+            type_out_char_delay_ms: 5,
+            type_out_terminal_paste: true,
+            confirm_before_paste: false,
+            transcript_alternatives: 0,
+            redaction_rules: Vec::new(),
+            custom_redactions: Vec::new(),
+            redact_before_paste: false,
             language: "auto".into(),
             auto_detect_language: true,
+            secondary_language: String::new(),
+            language_cycle_hotkey: String::new(),
+            language_cycle: Vec::new(),
+            language_models: BTreeMap::new(),
+            custom_vocabulary: Vec::new(),
+            pause_punctuation: true,
+            pause_thresholds: BTreeMap::from([("default".to_string(), PauseThresholds::default())]),
             autoclean_mode: "fast".into(),
+            polish_endpoint: DEFAULT_POLISH_ENDPOINT.into(),
+            polish_api: "ollama".into(),
+            polish_model: DEFAULT_POLISH_MODEL.into(),
+            polish_prompt: String::new(),
+            polish_timeout_ms: 4_000,
+            polish_allow_remote: false,
+            formatting_locale: "off".into(),
+            message_locale: "system".into(),
             debug_transcripts: false,
+            keep_failed_audio: false,
+            failed_audio_retention_days: 3,
+            diagnostics_interval_ms: 250,
+            event_rate_profile: "normal".into(),
+            event_min_interval_ms: BTreeMap::new(),
+            export_session_traces: false,
+            history_enabled: true,
+            performance_policy: PerformancePolicy::default(),
+            model_downshift: "offer".into(),
+            sound_cues_enabled: false,
+            sound_cue_theme: "soft".into(),
+            sound_cue_start: true,
+            sound_cue_stop: true,
+            sound_cue_paste: true,
+            sound_cue_error: true,
             audio_device_id: None,
+            audio_device_preferences: Vec::new(),
+            on_demand_capture: false,
+            bluetooth_auto_profile: false,
             vad_sensitivity: "medium".into(),
+            speaker_gate: false,
+            speaker_gate_threshold: DEFAULT_SPEAKER_THRESHOLD,
+            update_check_cadence: "daily".into(),
+            pre_download_updates: false,
+            stored_secrets: Vec::new(),
             legacy_asr_backend: None,
         }
     }
@@ -120,21 +372,57 @@ impl Default for PersistedSettings {
     }
 }
 
+/// What happened when config.json could not be parsed at startup.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsRecovery {
+    /// Where the unreadable file was moved.
+    pub corrupt_path: PathBuf,
+    /// The backup the settings came from; None means they were reset to defaults.
+    pub restored_from: Option<PathBuf>,
+    pub error: String,
+}
+
 pub struct SettingsManager {
     path: PathBuf,
     inner: RwLock<PersistedSettings>,
+    recovery: Mutex<Option<SettingsRecovery>>,
+    journal: SettingsJournal,
 }
 
 impl SettingsManager {
     pub fn new() -> Self {
         let config_path = resolve_config_path().expect("failed to resolve config directory");
-        let persisted = load_settings(&config_path).unwrap_or_default();
+        let (mut persisted, recovery) = load_or_recover(&config_path);
+        let journal = SettingsJournal::new(config_path.with_file_name(JOURNAL_FILE));
+
+        // Persist what migration rewrote so it is journaled once, not on every start.
+        let loaded = persisted.frontend.clone();
+        migrate_frontend_settings(&mut persisted.frontend);
+        if journal.record(ChangeSource::Migration, &loaded, &persisted.frontend) {
+            if let Err(error) = persist_settings(&config_path, &persisted) {
+                tracing::warn!("settings_migration_write_failed error={error:#}");
+            }
+        }
+
         Self {
             path: config_path,
             inner: RwLock::new(persisted),
+            recovery: Mutex::new(recovery),
+            journal,
         }
     }
 
+    /// The startup recovery of a corrupt config, if one happened and wasn't taken yet.
+    pub fn recovery(&self) -> Option<SettingsRecovery> {
+        self.recovery.lock().clone()
+    }
+
+    /// Like `recovery`, but reports it only once.
+    pub fn take_recovery(&self) -> Option<SettingsRecovery> {
+        self.recovery.lock().take()
+    }
+
     pub fn read_frontend(&self) -> Result<FrontendSettings> {
         let mut guard = self.inner.write();
         maybe_expire_debug_transcripts(&mut guard);
@@ -142,10 +430,12 @@ impl SettingsManager {
         Ok(guard.frontend.clone())
     }
 
-    pub fn write_frontend(&self, settings: FrontendSettings) -> Result<()> {
+    /// Persists `settings` and journals the fields that changed under `source`.
+    pub fn write_frontend(&self, settings: FrontendSettings, source: ChangeSource) -> Result<()> {
         let mut guard = self.inner.write();
         let mut settings = settings;
         migrate_frontend_settings(&mut settings);
+        settings.stored_secrets = guard.frontend.stored_secrets.clone();
 
         if settings.debug_transcripts {
             guard.debug_transcripts_until = Some(OffsetDateTime::now_utc() + DEBUG_TRANSCRIPT_TTL);
@@ -153,13 +443,48 @@ impl SettingsManager {
             guard.debug_transcripts_until = None;
         }
 
-        guard.frontend = settings.clone();
+        let previous = std::mem::replace(&mut guard.frontend, settings.clone());
         guard.frontend.debug_transcripts = settings.debug_transcripts;
 
         persist_settings(self.path.as_path(), &guard)?;
+        self.journal.record(source, &previous, &guard.frontend);
         Ok(())
     }
 
+    /// Restores every setting to its default and persists the result.
+    pub fn reset(&self) -> Result<()> {
+        let mut guard = self.inner.write();
+        let previous = std::mem::take(&mut *guard);
+        persist_settings(self.path.as_path(), &guard)?;
+        self.journal
+            .record(ChangeSource::Reset, &previous.frontend, &guard.frontend);
+        Ok(())
+    }
+
+    /// Journaled settings changes, newest first.
+    pub fn history(&self, limit: usize) -> Vec<SettingsChange> {
+        self.journal.list(limit)
+    }
+
+    /// Records whether the keyring holds secret `id`.
+    pub fn write_stored_secret(&self, id: &str, stored: bool) -> Result<()> {
+        let mut guard = self.inner.write();
+        let ids = &mut guard.frontend.stored_secrets;
+        ids.retain(|existing| existing != id);
+        if stored {
+            ids.push(id.to_string());
+            ids.sort();
+        }
+        persist_settings(self.path.as_path(), &guard)
+    }
+
+    /// End of the debug transcript window, if one is open.
+    pub fn debug_transcripts_until(&self) -> Option<OffsetDateTime> {
+        let mut guard = self.inner.write();
+        maybe_expire_debug_transcripts(&mut guard);
+        guard.debug_transcripts_until
+    }
+
     pub fn read_last_known_good_asr(&self) -> Option<AsrSelection> {
         let guard = self.inner.read();
         guard.last_known_good_asr.clone()
@@ -172,7 +497,8 @@ impl SettingsManager {
         Ok(())
     }
 
-    /// Returns the current active hotkey based on the hotkey mode setting.
+    /// Returns the current active hotkey based on the hotkey mode setting. Hybrid mode
+    /// shares the push-to-talk hotkey.
     pub fn current_hotkey(&self) -> String {
         let guard = self.inner.read();
         match guard.frontend.hotkey_mode.as_str() {
@@ -203,16 +529,95 @@ fn load_settings(path: &Path) -> Result<PersistedSettings> {
     Ok(parsed)
 }
 
+/// Loads config.json, falling back to the newest backup that parses when it is
+/// unreadable. The bad file is kept next to it as `config.json.corrupt`.
+fn load_or_recover(path: &Path) -> (PersistedSettings, Option<SettingsRecovery>) {
+    let error = match load_settings(path) {
+        Ok(settings) => return (settings, None),
+        Err(error) => error,
+    };
+    tracing::warn!("settings_load_failed path={path:?} error={error:#}");
+
+    let corrupt_path = path.with_extension("json.corrupt");
+    if let Err(error) = fs::rename(path, &corrupt_path) {
+        tracing::warn!("settings_set_aside_failed error={error}");
+    }
+
+    let restored = (1..=CONFIG_BACKUPS)
+        .map(|index| backup_path(path, index))
+        .find_map(|backup| {
+            load_settings(&backup)
+                .ok()
+                .map(|settings| (backup, settings))
+        });
+    let (settings, restored_from) = match restored {
+        Some((backup, settings)) => (settings, Some(backup)),
+        None => (PersistedSettings::default(), None),
+    };
+    if let Err(error) = write_atomically(path, &settings) {
+        tracing::warn!("settings_restore_write_failed error={error:#}");
+    }
+    tracing::warn!("settings_recovered restored_from={restored_from:?}");
+
+    let recovery = SettingsRecovery {
+        corrupt_path,
+        restored_from,
+        error: format!("{error:#}"),
+    };
+    (settings, Some(recovery))
+}
+
+/// Replaces config.json atomically after rotating the current (parseable) file into
+/// the backups, so a crash mid-write leaves either the old or the new file intact.
 fn persist_settings(path: &Path, settings: &PersistedSettings) -> Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent).with_context(|| format!("create dir {parent:?}"))?;
     }
+    if path.exists() && load_settings(path).is_ok() {
+        rotate_backups(path);
+    }
+    write_atomically(path, settings)
+}
+
+fn write_atomically(path: &Path, settings: &PersistedSettings) -> Result<()> {
     let serialized =
         serde_json::to_vec_pretty(settings).context("serialize settings to json failed")?;
-    fs::write(path, serialized).with_context(|| format!("write settings to {path:?}"))?;
+    let temp_path = path.with_extension("json.tmp");
+    let mut file = fs::File::create(&temp_path).with_context(|| format!("create {temp_path:?}"))?;
+    file.write_all(&serialized)
+        .and_then(|_| file.sync_all())
+        .with_context(|| format!("write settings to {temp_path:?}"))?;
+    drop(file);
+    fs::rename(&temp_path, path).with_context(|| format!("replace {path:?}"))?;
+    // Persist the rename itself; not every filesystem supports syncing a directory.
+    if let Some(parent) = path.parent() {
+        if let Ok(dir) = fs::File::open(parent) {
+            let _ = dir.sync_all();
+        }
+    }
     Ok(())
 }
 
+/// Shifts `.bak.1`..`.bak.N-1` up by one, dropping the oldest, and copies the current
+/// config into `.bak.1`. Best effort: a failed backup never blocks saving.
+fn rotate_backups(path: &Path) {
+    for index in (1..CONFIG_BACKUPS).rev() {
+        let from = backup_path(path, index);
+        if from.exists() {
+            let _ = fs::rename(&from, backup_path(path, index + 1));
+        }
+    }
+    if let Err(error) = fs::copy(path, backup_path(path, 1)) {
+        tracing::debug!("settings_backup_failed error={error}");
+    }
+}
+
+fn backup_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".bak.{index}"));
+    path.with_file_name(name)
+}
+
 fn maybe_expire_debug_transcripts(settings: &mut PersistedSettings) {
     if let Some(expires_at) = settings.debug_transcripts_until {
         if OffsetDateTime::now_utc() > expires_at {
@@ -227,6 +632,98 @@ fn maybe_expire_debug_transcripts(settings: &mut PersistedSettings) {
 }
 
 fn migrate_frontend_settings(settings: &mut FrontendSettings) {
+    // Older builds only knew "hold" and "toggle"; anything unrecognised falls back to hold.
+    if !matches!(settings.hotkey_mode.as_str(), "hold" | "toggle" | "hybrid") {
+        settings.hotkey_mode = "hold".into();
+    }
+    settings.hybrid_hold_threshold_ms = settings.hybrid_hold_threshold_ms.clamp(100, 2_000);
+    settings.max_dictation_secs = settings.max_dictation_secs.clamp(30, 600);
+    settings.continuous_end_silence_ms = settings.continuous_end_silence_ms.clamp(400, 3_000);
+    settings.failed_audio_retention_days = settings.failed_audio_retention_days.clamp(1, 30);
+    if !matches!(
+        settings.startup_warmup.as_str(),
+        "immediate" | "delay" | "idle" | "hotkey"
+    ) {
+        settings.startup_warmup = "idle".into();
+    }
+    settings.startup_warmup_secs = settings.startup_warmup_secs.min(300);
+    if !matches!(
+        settings.asr_device.as_str(),
+        "cpu" | "auto" | "cuda" | "rocm"
+    ) {
+        settings.asr_device = "cpu".into();
+    }
+    settings
+        .voice_commands
+        .retain(|command| !command.phrase.trim().is_empty());
+
+    let mut cycle: Vec<String> = Vec::new();
+    for language in &settings.language_cycle {
+        let language = language.trim().to_ascii_lowercase();
+        if !language.is_empty() && !cycle.contains(&language) {
+            cycle.push(language);
+        }
+    }
+    settings.language_cycle = cycle;
+
+    let mut vocabulary: Vec<String> = Vec::new();
+    for term in &settings.custom_vocabulary {
+        let term = term.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !term.is_empty()
+            && !vocabulary
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(&term))
+            && vocabulary.len() < MAX_VOCABULARY_TERMS
+        {
+            vocabulary.push(term);
+        }
+    }
+    settings.custom_vocabulary = vocabulary;
+
+    settings.type_out_char_delay_ms = settings
+        .type_out_char_delay_ms
+        .min(MAX_TYPE_OUT_CHAR_DELAY_MS);
+
+    let mut pause_thresholds = BTreeMap::new();
+    for (language, thresholds) in &settings.pause_thresholds {
+        let language = language.trim().to_ascii_lowercase();
+        if language.is_empty() {
+            continue;
+        }
+        let comma_ms = thresholds.comma_ms.clamp(150, 3_000);
+        let period_ms = thresholds.period_ms.clamp(comma_ms + 50, 6_000);
+        pause_thresholds.insert(
+            language,
+            PauseThresholds {
+                comma_ms,
+                period_ms,
+            },
+        );
+    }
+    pause_thresholds
+        .entry("default".to_string())
+        .or_insert_with(PauseThresholds::default);
+    settings.pause_thresholds = pause_thresholds;
+
+    if let Some(device) = settings.audio_device_id.take() {
+        if settings.audio_device_preferences.is_empty() {
+            settings.audio_device_preferences.push(device);
+        }
+    }
+    let mut preferences: Vec<String> = Vec::new();
+    for device in &settings.audio_device_preferences {
+        let device = device.trim();
+        if !device.is_empty() && !preferences.iter().any(|known| known == device) {
+            preferences.push(device.to_string());
+        }
+    }
+    settings.audio_device_preferences = preferences;
+
+    settings.secondary_language = settings.secondary_language.trim().to_ascii_lowercase();
+    if settings.secondary_language == "auto" || settings.secondary_language == settings.language {
+        settings.secondary_language.clear();
+    }
+
     // Keep hotkeys non-empty.
     if settings.push_to_talk_hotkey.trim().is_empty() {
         settings.push_to_talk_hotkey = DEFAULT_PUSH_TO_TALK_HOTKEY.into();
@@ -278,9 +775,96 @@ fn migrate_frontend_settings(settings: &mut FrontendSettings) {
         settings.whisper_precision = "int8".into();
     }
 
-    if settings.autoclean_mode == "polish" {
+    match settings.paste_shortcut.trim() {
+        "" | "ctrl-shift-v" => settings.paste_shortcut = "Ctrl+Shift+V".into(),
+        "ctrl-v" => settings.paste_shortcut = "Ctrl+V".into(),
+        _ => {}
+    }
+
+    if settings.formatting_locale.trim().is_empty() {
+        settings.formatting_locale = "off".into();
+    }
+
+    if settings.message_locale.trim().is_empty() {
+        settings.message_locale = "system".into();
+    }
+
+    if settings.sound_cue_theme.trim().is_empty() {
+        settings.sound_cue_theme = "soft".into();
+    }
+
+    // Every alternative is a full extra decode.
+    settings.transcript_alternatives = settings.transcript_alternatives.min(5);
+    settings
+        .redaction_rules
+        .retain(|id| BUILTIN_REDACTIONS.contains(&id.as_str()));
+    settings.redaction_rules.sort();
+    settings.redaction_rules.dedup();
+    settings
+        .custom_redactions
+        .retain(|pattern| !pattern.trim().is_empty());
+    settings.performance_policy = settings.performance_policy.clone().sanitized();
+    if !matches!(settings.model_downshift.as_str(), "off" | "offer" | "auto") {
+        settings.model_downshift = "offer".into();
+    }
+
+    if !matches!(
+        settings.hud_theme.as_str(),
+        "system" | "light" | "dark" | "high-contrast"
+    ) {
+        settings.hud_theme = "system".into();
+    }
+    settings.hud_accent_color = settings
+        .hud_accent_color
+        .take()
+        .map(|color| color.trim().to_ascii_lowercase())
+        .filter(|color| {
+            color.len() == 7
+                && color.starts_with('#')
+                && color[1..].chars().all(|c| c.is_ascii_hexdigit())
+        });
+
+    if !settings.speaker_gate_threshold.is_finite() {
+        settings.speaker_gate_threshold = DEFAULT_SPEAKER_THRESHOLD;
+    }
+    settings.speaker_gate_threshold = settings.speaker_gate_threshold.clamp(0.1, 0.95);
+
+    if !matches!(
+        settings.update_check_cadence.as_str(),
+        "daily" | "weekly" | "never"
+    ) {
+        settings.update_check_cadence = "daily".into();
+    }
+
+    // Faster than 50 ms floods the webview without telling the user anything new.
+    settings.overlay_show_animation_ms = settings.overlay_show_animation_ms.min(2_000);
+    settings.overlay_hide_delay_ms = settings.overlay_hide_delay_ms.min(2_000);
+    settings.hud_toast_ms = settings.hud_toast_ms.clamp(500, 10_000);
+
+    if settings.diagnostics_interval_ms != 0 {
+        settings.diagnostics_interval_ms = settings.diagnostics_interval_ms.clamp(50, 5_000);
+    }
+    if !matches!(settings.event_rate_profile.as_str(), "normal" | "low-power") {
+        settings.event_rate_profile = "normal".into();
+    }
+    for interval in settings.event_min_interval_ms.values_mut() {
+        *interval = (*interval).min(10_000);
+    }
+
+    settings.stored_secrets.retain(|id| secrets::is_known(id));
+    settings.stored_secrets.sort();
+    settings.stored_secrets.dedup();
+
+    if !matches!(settings.autoclean_mode.as_str(), "off" | "fast" | "polish") {
         settings.autoclean_mode = "fast".into();
     }
+    if !matches!(settings.polish_api.as_str(), "ollama" | "openai") {
+        settings.polish_api = "ollama".into();
+    }
+    if settings.polish_endpoint.trim().is_empty() {
+        settings.polish_endpoint = DEFAULT_POLISH_ENDPOINT.into();
+    }
+    settings.polish_timeout_ms = settings.polish_timeout_ms.clamp(500, 30_000);
 
     if matches!(
         settings.whisper_model.as_str(),
@@ -288,4 +872,33 @@ fn migrate_frontend_settings(settings: &mut FrontendSettings) {
     ) {
         settings.whisper_model_language = "multi".into();
     }
+
+    privacy::enforce(settings);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corrupt_config_is_restored_from_the_newest_good_backup() {
+        let dir = std::env::temp_dir().join(format!("openflow-settings-{}", uuid::Uuid::new_v4()));
+        let path = dir.join(CONFIG_FILE);
+        let mut settings = PersistedSettings::default();
+        for hotkey in ["F7", "F8", "F9"] {
+            settings.frontend.command_hotkey = hotkey.into();
+            persist_settings(&path, &settings).unwrap();
+        }
+        assert!(backup_path(&path, 2).exists());
+
+        fs::write(&path, b"{\"frontend\": {").unwrap();
+        let (loaded, recovery) = load_or_recover(&path);
+        let recovery = recovery.expect("recovery reported");
+        assert_eq!(loaded.frontend.command_hotkey, "F8");
+        assert_eq!(recovery.restored_from, Some(backup_path(&path, 1)));
+        assert!(recovery.corrupt_path.exists());
+        assert_eq!(load_settings(&path).unwrap().frontend.command_hotkey, "F8");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
@@ -0,0 +1,185 @@
+//! Local journal of settings changes, one JSON line per changed field, next to
+//! config.json. Answers "it worked yesterday" reports, including changes the backend
+//! made on its own (migration, warmup fallback, model downshift).
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::core::settings::FrontendSettings;
+
+pub const JOURNAL_FILE: &str = "settings-history.jsonl";
+const MAX_ENTRIES: usize = 500;
+/// Appends run this far past the cap before the file is rewritten without the oldest.
+const COMPACT_SLACK: usize = 100;
+
+/// Who made a settings change.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChangeSource {
+    /// Saved from the settings UI (`update_settings`).
+    Ui,
+    /// Rewritten by `migrate_frontend_settings` when loading an older config.
+    Migration,
+    LanguageCycle,
+    ModelDownshift,
+    /// Warmup failed and the ASR selection was reverted to the last one that worked.
+    WarmupFallback,
+    Reset,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsChange {
+    #[serde(with = "time::serde::rfc3339")]
+    pub changed_at: OffsetDateTime,
+    pub source: ChangeSource,
+    /// camelCase settings key, as the frontend names it.
+    pub field: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+pub struct SettingsJournal {
+    path: PathBuf,
+    /// Serializes appends and compaction.
+    write_lock: Mutex<()>,
+}
+
+impl SettingsJournal {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// Records every field that differs between `old` and `new`; false if none did.
+    pub fn record(
+        &self,
+        source: ChangeSource,
+        old: &FrontendSettings,
+        new: &FrontendSettings,
+    ) -> bool {
+        let changes = diff(source, old, new);
+        if changes.is_empty() {
+            return false;
+        }
+        tracing::info!(
+            "settings_changed source={source:?} fields={}",
+            changes
+                .iter()
+                .map(|change| change.field.as_str())
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+        let _guard = self.write_lock.lock();
+        if let Err(error) = self.append(&changes) {
+            tracing::warn!("settings_journal_write_failed error={error:#}");
+        }
+        true
+    }
+
+    /// Newest first, at most `limit` entries.
+    pub fn list(&self, limit: usize) -> Vec<SettingsChange> {
+        let mut entries = load_entries(&self.path);
+        entries.reverse();
+        entries.truncate(limit);
+        entries
+    }
+
+    fn append(&self, changes: &[SettingsChange]) -> Result<()> {
+        let mut body = String::new();
+        for change in changes {
+            body.push_str(&serde_json::to_string(change).context("serialize settings change")?);
+            body.push('\n');
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(body.as_bytes()))
+            .with_context(|| format!("append to {}", self.path.display()))?;
+
+        let entries = load_entries(&self.path);
+        if entries.len() > MAX_ENTRIES + COMPACT_SLACK {
+            rewrite(&self.path, &entries[entries.len() - MAX_ENTRIES..])?;
+        }
+        Ok(())
+    }
+}
+
+/// One change per top-level field whose serialized value differs.
+pub fn diff(
+    source: ChangeSource,
+    old: &FrontendSettings,
+    new: &FrontendSettings,
+) -> Vec<SettingsChange> {
+    let (Ok(Value::Object(old)), Ok(Value::Object(new))) =
+        (serde_json::to_value(old), serde_json::to_value(new))
+    else {
+        return Vec::new();
+    };
+    let changed_at = OffsetDateTime::now_utc();
+    new.into_iter()
+        .filter_map(|(field, new_value)| {
+            let old_value = old.get(&field).cloned().unwrap_or(Value::Null);
+            (old_value != new_value).then(|| SettingsChange {
+                changed_at,
+                source,
+                field,
+                old: old_value,
+                new: new_value,
+            })
+        })
+        .collect()
+}
+
+/// Lines that don't parse (e.g. cut off by a crash mid-write) are skipped.
+fn load_entries(path: &Path) -> Vec<SettingsChange> {
+    fs::read_to_string(path)
+        .map(|text| {
+            text.lines()
+                .filter(|line| !line.trim().is_empty())
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn rewrite(path: &Path, entries: &[SettingsChange]) -> Result<()> {
+    let mut body = String::new();
+    for entry in entries {
+        body.push_str(&serde_json::to_string(entry).context("serialize settings change")?);
+        body.push('\n');
+    }
+    fs::write(path, body).with_context(|| format!("write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_reports_changed_fields_by_frontend_name() {
+        let old = FrontendSettings::default();
+        let new = FrontendSettings {
+            whisper_model: "medium".into(),
+            continuous_end_silence_ms: 1_500,
+            ..FrontendSettings::default()
+        };
+        let mut changes = diff(ChangeSource::WarmupFallback, &old, &new);
+        changes.sort_by(|a, b| a.field.cmp(&b.field));
+        let fields: Vec<&str> = changes.iter().map(|change| change.field.as_str()).collect();
+        assert_eq!(fields, vec!["continuousEndSilenceMs", "whisperModel"]);
+        assert_eq!(changes[1].old, Value::from("small"));
+        assert_eq!(changes[1].new, Value::from("medium"));
+        assert!(diff(ChangeSource::Ui, &old, &old).is_empty());
+    }
+}
@@ -0,0 +1,319 @@
+//! Field-level checks run by `update_settings` before anything is persisted, so a bad
+//! value is reported against the control that set it instead of surfacing later as a
+//! warmup or hotkey registration failure.
+
+use serde::Serialize;
+
+use crate::core::hotkeys::KeyChord;
+use crate::core::settings::FrontendSettings;
+use crate::core::voice_commands;
+use crate::llm::is_local_endpoint;
+use crate::output::PasteShortcut;
+
+const WHISPER_SIZES: [&str; 6] = [
+    "tiny",
+    "base",
+    "small",
+    "medium",
+    "large-v3",
+    "large-v3-turbo",
+];
+
+/// One rejected setting. `field` is the camelCase key the frontend uses.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Why `update_settings` refused or failed to apply a change.
+#[derive(Debug, Serialize, thiserror::Error)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum SettingsUpdateError {
+    #[error("invalid settings: {}", describe(errors))]
+    Invalid { errors: Vec<FieldError> },
+    #[error("{message}")]
+    Failed { message: String },
+}
+
+impl From<anyhow::Error> for SettingsUpdateError {
+    fn from(error: anyhow::Error) -> Self {
+        Self::Failed {
+            message: format!("{error:#}"),
+        }
+    }
+}
+
+impl From<tauri::Error> for SettingsUpdateError {
+    fn from(error: tauri::Error) -> Self {
+        Self::Failed {
+            message: error.to_string(),
+        }
+    }
+}
+
+fn describe(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(|error| format!("{}: {}", error.field, error.message))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Checks the values the settings UI can't rule out by construction. Values that
+/// `migrate_frontend_settings` clamps or defaults (ranges, empty dictation hotkeys)
+/// are left to it.
+pub fn validate(settings: &FrontendSettings) -> Vec<FieldError> {
+    let mut errors = Vec::new();
+
+    if !matches!(settings.hotkey_mode.as_str(), "hold" | "toggle" | "hybrid") {
+        errors.push(FieldError::new(
+            "hotkeyMode",
+            format!("unknown hotkey mode {:?}", settings.hotkey_mode),
+        ));
+    }
+    check_hotkeys(settings, &mut errors);
+
+    if !settings.paste_shortcut.trim().is_empty() {
+        if let Err(error) = PasteShortcut::parse(&settings.paste_shortcut) {
+            errors.push(FieldError::new("pasteShortcut", format!("{error:#}")));
+        }
+    }
+    for (class, chord) in &settings.app_paste_shortcuts {
+        if let Err(error) = PasteShortcut::parse(chord) {
+            errors.push(FieldError::new(
+                "appPasteShortcuts",
+                format!("{class}: {error:#}"),
+            ));
+        }
+    }
+    for command in &settings.voice_commands {
+        if command.phrase.trim().is_empty() {
+            continue;
+        }
+        if let Err(error) = voice_commands::parse_action(command) {
+            errors.push(FieldError::new(
+                "voiceCommands",
+                format!("{:?}: {error:#}", command.phrase),
+            ));
+        }
+    }
+
+    check_asr_selection(settings, &mut errors);
+    check_polish(settings, &mut errors);
+    if !matches!(
+        settings.asr_device.as_str(),
+        "cpu" | "auto" | "cuda" | "rocm"
+    ) {
+        errors.push(FieldError::new(
+            "asrDevice",
+            format!("unknown device {:?}", settings.asr_device),
+        ));
+    }
+
+    if let Some(color) = settings.hud_accent_color.as_deref() {
+        let color = color.trim();
+        let valid = color.len() == 7
+            && color.starts_with('#')
+            && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+        if !color.is_empty() && !valid {
+            errors.push(FieldError::new(
+                "hudAccentColor",
+                "expected a color like #3b82f6",
+            ));
+        }
+    }
+
+    errors
+}
+
+/// Every chord must name a key the hotkey backends know, and no two bindings may share
+/// a chord: the second registration would silently lose.
+fn check_hotkeys(settings: &FrontendSettings, errors: &mut Vec<FieldError>) {
+    let dictation = match settings.hotkey_mode.as_str() {
+        "toggle" => (
+            "toggleToTalkHotkey",
+            "the dictation hotkey",
+            &settings.toggle_to_talk_hotkey,
+        ),
+        _ => (
+            "pushToTalkHotkey",
+            "the dictation hotkey",
+            &settings.push_to_talk_hotkey,
+        ),
+    };
+    let inactive = match settings.hotkey_mode.as_str() {
+        "toggle" => ("pushToTalkHotkey", &settings.push_to_talk_hotkey),
+        _ => ("toggleToTalkHotkey", &settings.toggle_to_talk_hotkey),
+    };
+    if !inactive.1.trim().is_empty() {
+        if let Err(error) = parse_chord(inactive.1) {
+            errors.push(FieldError::new(inactive.0, format!("{error:#}")));
+        }
+    }
+
+    let bindings = [
+        dictation,
+        (
+            "recopyTranscriptHotkey",
+            "the re-copy hotkey",
+            &settings.recopy_transcript_hotkey,
+        ),
+        (
+            "languageCycleHotkey",
+            "the language hotkey",
+            &settings.language_cycle_hotkey,
+        ),
+        (
+            "commandHotkey",
+            "the voice command hotkey",
+            &settings.command_hotkey,
+        ),
+        (
+            "continuousHotkey",
+            "the continuous dictation hotkey",
+            &settings.continuous_hotkey,
+        ),
+    ];
+    let mut seen: Vec<(String, &str)> = Vec::new();
+    for (field, label, chord) in bindings {
+        if chord.trim().is_empty() {
+            continue;
+        }
+        let parsed = match parse_chord(chord) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                errors.push(FieldError::new(field, format!("{error:#}")));
+                continue;
+            }
+        };
+        let normalized = parsed.to_string();
+        match seen.iter().find(|(known, _)| *known == normalized) {
+            Some((_, other)) => errors.push(FieldError::new(
+                field,
+                format!("{normalized} is already used by {other}"),
+            )),
+            None => seen.push((normalized, label)),
+        }
+    }
+}
+
+fn parse_chord(chord: &str) -> anyhow::Result<KeyChord> {
+    let parsed = KeyChord::parse(chord)?;
+    parsed.evdev_key()?;
+    Ok(parsed)
+}
+
+fn check_polish(settings: &FrontendSettings, errors: &mut Vec<FieldError>) {
+    if !matches!(settings.autoclean_mode.as_str(), "off" | "fast" | "polish") {
+        errors.push(FieldError::new(
+            "autocleanMode",
+            format!("unknown autoclean mode {:?}", settings.autoclean_mode),
+        ));
+    }
+    if settings.autoclean_mode != "polish" {
+        return;
+    }
+    if !matches!(settings.polish_api.as_str(), "ollama" | "openai") {
+        errors.push(FieldError::new(
+            "polishApi",
+            format!("unknown server type {:?}", settings.polish_api),
+        ));
+    }
+    if reqwest::Url::parse(settings.polish_endpoint.trim()).is_err() {
+        errors.push(FieldError::new(
+            "polishEndpoint",
+            "expected a URL like http://127.0.0.1:11434",
+        ));
+    } else if settings.strict_privacy && !is_local_endpoint(&settings.polish_endpoint) {
+        errors.push(FieldError::new(
+            "polishEndpoint",
+            "strict privacy only allows a server on this machine",
+        ));
+    } else if !settings.polish_allow_remote && !is_local_endpoint(&settings.polish_endpoint) {
+        errors.push(FieldError::new(
+            "polishEndpoint",
+            "allow sending transcripts to another machine to use this server",
+        ));
+    }
+    if settings.polish_model.trim().is_empty() {
+        errors.push(FieldError::new("polishModel", "name the model to use"));
+    }
+}
+
+fn check_asr_selection(settings: &FrontendSettings, errors: &mut Vec<FieldError>) {
+    match settings.asr_family.as_str() {
+        "parakeet" => return,
+        "whisper" => {}
+        other => {
+            errors.push(FieldError::new(
+                "asrFamily",
+                format!("unknown ASR family {other:?}"),
+            ));
+            return;
+        }
+    }
+    if !matches!(settings.whisper_backend.as_str(), "ct2" | "onnx" | "cpp") {
+        errors.push(FieldError::new(
+            "whisperBackend",
+            format!("unknown Whisper backend {:?}", settings.whisper_backend),
+        ));
+    }
+    if !WHISPER_SIZES.contains(&settings.whisper_model.as_str()) {
+        errors.push(FieldError::new(
+            "whisperModel",
+            format!("unknown Whisper model size {:?}", settings.whisper_model),
+        ));
+    }
+    if !matches!(settings.whisper_model_language.as_str(), "multi" | "en") {
+        errors.push(FieldError::new(
+            "whisperModelLanguage",
+            format!(
+                "unknown model language {:?}",
+                settings.whisper_model_language
+            ),
+        ));
+    }
+    if !matches!(settings.whisper_precision.as_str(), "int8" | "float") {
+        errors.push(FieldError::new(
+            "whisperPrecision",
+            format!("unknown precision {:?}", settings.whisper_precision),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_chords_and_selections_are_reported_per_field() {
+        assert!(validate(&FrontendSettings::default()).is_empty());
+
+        let settings = FrontendSettings {
+            command_hotkey: "Ctrl+NotAKey".into(),
+            recopy_transcript_hotkey: FrontendSettings::default().push_to_talk_hotkey,
+            asr_family: "whisper".into(),
+            whisper_backend: "tflite".into(),
+            ..FrontendSettings::default()
+        };
+        let fields: Vec<String> = validate(&settings)
+            .into_iter()
+            .map(|error| error.field)
+            .collect();
+        assert_eq!(
+            fields,
+            vec!["recopyTranscriptHotkey", "commandHotkey", "whisperBackend"]
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Simulated dictation: drives a recorded clip or a ready-made transcript through the
+//! finalize path (trim, ASR, autoclean, output injection) without a microphone or a
+//! hotkey, and records what the pipeline emitted. Used to reproduce paste and
+//! clipboard bugs deterministically, e.g. in CI containers under Xvfb.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{bail, Context, Result};
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::{AppHandle, EventId, Listener};
+
+use crate::core::events;
+
+/// Events a simulated dictation can produce, recorded in emission order.
+const RECORDED_EVENTS: &[&str] = &[
+    events::EVENT_TRANSCRIPTION_PARTIAL,
+    events::EVENT_TRANSCRIPTION_ALTERNATIVES,
+    events::EVENT_TRANSCRIPTION_OUTPUT,
+    events::EVENT_TRANSCRIPTION_ERROR,
+    events::EVENT_TRANSCRIPTION_SKIPPED,
+    events::EVENT_PENDING_TRANSCRIPT,
+    events::EVENT_PASTE_SUCCEEDED,
+    events::EVENT_PASTE_UNCONFIRMED,
+    events::EVENT_PASTE_FAILED,
+    events::EVENT_SECURE_BLOCKED,
+    events::EVENT_PERFORMANCE_METRICS,
+    events::EVENT_SESSION_TIMING,
+    events::EVENT_LATENCY_BUDGET_EXCEEDED,
+];
+
+pub enum SimulatedInput {
+    /// Mono samples at the pipeline's sample rate, replayed as captured audio.
+    Audio(Vec<f32>),
+    /// A transcript handed straight to autoclean and output, skipping capture and ASR.
+    Text(String),
+}
+
+impl SimulatedInput {
+    /// An existing file is read as a WAV clip; anything else is taken as transcript text.
+    pub fn parse(input: &str, sample_rate: u32) -> Result<Self> {
+        let path = Path::new(input);
+        if path.is_file() {
+            return read_wav(path, sample_rate).map(SimulatedInput::Audio);
+        }
+        if input.trim().is_empty() {
+            bail!("simulated dictation needs a WAV path or transcript text");
+        }
+        Ok(SimulatedInput::Text(input.to_string()))
+    }
+}
+
+/// Reads a WAV file as mono samples at `sample_rate`, averaging channels and
+/// resampling linearly when the file differs.
+pub fn read_wav(path: &Path, sample_rate: u32) -> Result<Vec<f32>> {
+    let mut reader =
+        hound::WavReader::open(path).with_context(|| format!("open {}", path.display()))?;
+    let spec = reader.spec();
+    let interleaved: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader.samples::<f32>().collect::<Result<_, _>>(),
+        hound::SampleFormat::Int => {
+            let scale = (1u64 << (spec.bits_per_sample.saturating_sub(1))) as f32;
+            reader
+                .samples::<i32>()
+                .map(|sample| sample.map(|value| value as f32 / scale))
+                .collect::<Result<_, _>>()
+        }
+    }
+    .with_context(|| format!("decode {}", path.display()))?;
+
+    let channels = spec.channels.max(1) as usize;
+    let mono: Vec<f32> = interleaved
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect();
+    Ok(resample_linear(&mono, spec.sample_rate, sample_rate))
+}
+
+fn resample_linear(samples: &[f32], from: u32, to: u32) -> Vec<f32> {
+    if from == to || from == 0 || samples.is_empty() {
+        return samples.to_vec();
+    }
+    let ratio = from as f64 / to as f64;
+    let len = (samples.len() as f64 / ratio).round() as usize;
+    (0..len)
+        .map(|index| {
+            let position = index as f64 * ratio;
+            let base = position.floor() as usize;
+            let next = (base + 1).min(samples.len() - 1);
+            let fraction = (position - base as f64) as f32;
+            let base = base.min(samples.len() - 1);
+            samples[base] + (samples[next] - samples[base]) * fraction
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedEvent {
+    pub event: String,
+    pub payload: serde_json::Value,
+}
+
+/// Listens for [`RECORDED_EVENTS`] until [`EventRecorder::finish`].
+pub struct EventRecorder {
+    app: AppHandle,
+    listeners: Vec<EventId>,
+    events: Arc<Mutex<Vec<SimulatedEvent>>>,
+}
+
+impl EventRecorder {
+    pub fn start(app: &AppHandle) -> Self {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let listeners = RECORDED_EVENTS
+            .iter()
+            .map(|&name| {
+                let events = Arc::clone(&events);
+                app.listen_any(name, move |event| {
+                    let payload =
+                        serde_json::from_str(event.payload()).unwrap_or(serde_json::Value::Null);
+                    events.lock().push(SimulatedEvent {
+                        event: name.to_string(),
+                        payload,
+                    });
+                })
+            })
+            .collect();
+        Self {
+            app: app.clone(),
+            listeners,
+            events,
+        }
+    }
+
+    pub fn finish(self) -> Vec<SimulatedEvent> {
+        for id in &self.listeners {
+            self.app.unlisten(*id);
+        }
+        std::mem::take(&mut *self.events.lock())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wav_is_mixed_to_mono_and_resampled() {
+        let path =
+            std::env::temp_dir().join(format!("openflow-simulated-{}.wav", uuid::Uuid::new_v4()));
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 8_000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+        for _ in 0..800 {
+            writer.write_sample(i16::MAX / 2).unwrap();
+            writer.write_sample(0i16).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let samples = read_wav(&path, 16_000).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(samples.len(), 1_600);
+        assert!(samples.iter().all(|sample| (sample - 0.25).abs() < 1e-3));
+    }
+}
@@ -0,0 +1,68 @@
+//! When the launch-time model warmup and default-model downloads start. Starting them
+//! right at login competes with the desktop's own startup for CPU and disk.
+
+use std::time::{Duration, Instant};
+
+use sysinfo::System;
+
+use crate::core::settings::FrontendSettings;
+
+/// CPU load below which the system counts as idle.
+const IDLE_CPU_PERCENT: f32 = 25.0;
+/// An idle trigger on a machine that never quiets down still fires after this long.
+const IDLE_WAIT_MAX: Duration = Duration::from_secs(120);
+const IDLE_POLL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartupTrigger {
+    Immediate,
+    /// A fixed delay after launch.
+    Delay(Duration),
+    /// After the CPU has stayed idle this long.
+    Idle(Duration),
+    /// Only once dictation is first requested.
+    Hotkey,
+}
+
+impl StartupTrigger {
+    pub fn from_settings(settings: &FrontendSettings) -> Self {
+        let secs = Duration::from_secs(u64::from(settings.startup_warmup_secs));
+        match settings.startup_warmup.as_str() {
+            "immediate" => Self::Immediate,
+            "delay" => Self::Delay(secs),
+            "hotkey" => Self::Hotkey,
+            _ => Self::Idle(secs),
+        }
+    }
+}
+
+/// Waits until CPU load has stayed under `IDLE_CPU_PERCENT` for `quiet`, or
+/// `IDLE_WAIT_MAX` has passed.
+pub async fn wait_for_idle(quiet: Duration) {
+    let started = Instant::now();
+    let mut system = System::new();
+    system.refresh_cpu_usage();
+    let mut idle_since: Option<Instant> = None;
+    loop {
+        tokio::time::sleep(IDLE_POLL).await;
+        system.refresh_cpu_usage();
+        let usage = system.global_cpu_info().cpu_usage();
+        let now = Instant::now();
+        if usage < IDLE_CPU_PERCENT {
+            let since = *idle_since.get_or_insert(now);
+            if now.duration_since(since) >= quiet {
+                tracing::info!(
+                    "startup_idle_reached waited_ms={}",
+                    started.elapsed().as_millis()
+                );
+                return;
+            }
+        } else {
+            idle_since = None;
+        }
+        if started.elapsed() >= IDLE_WAIT_MAX {
+            tracing::info!("startup_idle_timeout cpu_percent={usage:.0}");
+            return;
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! CPU thermal state from sysfs, so long decodes can back off while the CPU is
+//! throttling instead of spinning the fans up on every dictation.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const THERMAL_ROOT: &str = "/sys/class/thermal";
+const CPU_ROOT: &str = "/sys/devices/system/cpu";
+/// Used when a zone reports no passive trip point.
+const FALLBACK_TRIP_C: f32 = 90.0;
+/// Passive trips below this are firmware noise, not a throttle point.
+const MIN_TRIP_C: f32 = 60.0;
+/// Throttling ends once the CPU stays this far under its trip point...
+const COOL_MARGIN_C: f32 = 8.0;
+/// ...for this many samples in a row.
+const COOL_SAMPLES: u32 = 5;
+/// Thread count assumed when none is configured.
+const DEFAULT_DECODE_THREADS: usize = 4;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ThermalSample {
+    /// Hottest CPU zone, in °C.
+    pub temp_c: Option<f32>,
+    /// Trip point of that zone.
+    pub trip_c: Option<f32>,
+    pub throttled: bool,
+    /// `throttled` flipped with this sample.
+    pub changed: bool,
+}
+
+struct CpuZone {
+    temp_path: PathBuf,
+    trip_c: f32,
+}
+
+pub struct ThermalMonitor {
+    zones: Vec<CpuZone>,
+    throttle_counters: Vec<PathBuf>,
+    last_throttle_count: Option<u64>,
+    throttled: bool,
+    cool_samples: u32,
+}
+
+impl ThermalMonitor {
+    pub fn discover() -> Self {
+        Self::from_roots(Path::new(THERMAL_ROOT), Path::new(CPU_ROOT))
+    }
+
+    fn from_roots(thermal_root: &Path, cpu_root: &Path) -> Self {
+        let zones = cpu_zones(thermal_root);
+        let throttle_counters = throttle_counters(cpu_root);
+        tracing::debug!(
+            "thermal_monitor zones={} throttle_counters={}",
+            zones.len(),
+            throttle_counters.len()
+        );
+        Self::new(zones, throttle_counters)
+    }
+
+    fn new(zones: Vec<CpuZone>, throttle_counters: Vec<PathBuf>) -> Self {
+        Self {
+            zones,
+            throttle_counters,
+            last_throttle_count: None,
+            throttled: false,
+            cool_samples: 0,
+        }
+    }
+
+    /// No zone or counter to read; every sample reports an unthrottled CPU.
+    pub fn is_available(&self) -> bool {
+        !self.zones.is_empty() || !self.throttle_counters.is_empty()
+    }
+
+    pub fn sample(&mut self) -> ThermalSample {
+        let hottest = self
+            .zones
+            .iter()
+            .filter_map(|zone| Some((read_millidegrees(&zone.temp_path)?, zone.trip_c)))
+            .max_by(|a, b| (a.0 - a.1).total_cmp(&(b.0 - b.1)));
+        let throttle_count = if self.throttle_counters.is_empty() {
+            None
+        } else {
+            Some(
+                self.throttle_counters
+                    .iter()
+                    .filter_map(|path| read_u64(path))
+                    .sum(),
+            )
+        };
+        self.update(hottest, throttle_count)
+    }
+
+    fn update(
+        &mut self,
+        hottest: Option<(f32, f32)>,
+        throttle_count: Option<u64>,
+    ) -> ThermalSample {
+        let throttle_events = match (self.last_throttle_count, throttle_count) {
+            (Some(last), Some(count)) => count > last,
+            _ => false,
+        };
+        if throttle_count.is_some() {
+            self.last_throttle_count = throttle_count;
+        }
+
+        let hot = hottest.is_some_and(|(temp, trip)| temp >= trip);
+        let was_throttled = self.throttled;
+        if hot || throttle_events {
+            self.throttled = true;
+            self.cool_samples = 0;
+        } else if self.throttled {
+            let cool = match hottest {
+                Some((temp, trip)) => temp < trip - COOL_MARGIN_C,
+                None => true,
+            };
+            self.cool_samples = if cool { self.cool_samples + 1 } else { 0 };
+            if self.cool_samples >= COOL_SAMPLES {
+                self.throttled = false;
+                self.cool_samples = 0;
+            }
+        }
+
+        ThermalSample {
+            temp_c: hottest.map(|(temp, _)| temp),
+            trip_c: hottest.map(|(_, trip)| trip),
+            throttled: self.throttled,
+            changed: self.throttled != was_throttled,
+        }
+    }
+}
+
+/// Decode threads to use while throttled: half the configured count, at least one.
+pub fn throttled_threads(configured: Option<i32>) -> usize {
+    let base = configured
+        .filter(|threads| *threads > 0)
+        .map(|threads| threads as usize)
+        .unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|count| count.get().min(DEFAULT_DECODE_THREADS))
+                .unwrap_or(DEFAULT_DECODE_THREADS)
+        });
+    (base / 2).max(1)
+}
+
+fn cpu_zones(thermal_root: &Path) -> Vec<CpuZone> {
+    let Ok(entries) = fs::read_dir(thermal_root) else {
+        return Vec::new();
+    };
+    let mut preferred = Vec::new();
+    let mut acpi = Vec::new();
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        let is_zone = dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("thermal_zone"));
+        if !is_zone {
+            continue;
+        }
+        let Ok(kind) = fs::read_to_string(dir.join("type")) else {
+            continue;
+        };
+        let kind = kind.trim().to_ascii_lowercase();
+        let zone = CpuZone {
+            temp_path: dir.join("temp"),
+            trip_c: passive_trip(&dir).unwrap_or(FALLBACK_TRIP_C),
+        };
+        if is_cpu_zone(&kind) {
+            preferred.push(zone);
+        } else if kind == "acpitz" {
+            acpi.push(zone);
+        }
+    }
+    // acpitz is often a board sensor; only trust it when nothing CPU-specific exists.
+    if preferred.is_empty() {
+        acpi
+    } else {
+        preferred
+    }
+}
+
+fn is_cpu_zone(kind: &str) -> bool {
+    ["x86_pkg_temp", "cpu", "soc", "k10temp", "tctl"]
+        .iter()
+        .any(|needle| kind.contains(needle))
+}
+
+fn passive_trip(zone_dir: &Path) -> Option<f32> {
+    (0..16)
+        .filter(|index| {
+            fs::read_to_string(zone_dir.join(format!("trip_point_{index}_type")))
+                .is_ok_and(|kind| kind.trim() == "passive")
+        })
+        .filter_map(|index| read_millidegrees(&zone_dir.join(format!("trip_point_{index}_temp"))))
+        .filter(|trip| *trip >= MIN_TRIP_C)
+        .min_by(f32::total_cmp)
+}
+
+fn throttle_counters(cpu_root: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(cpu_root) else {
+        return Vec::new();
+    };
+    let mut counters = Vec::new();
+    for entry in entries.flatten() {
+        let dir = entry.path().join("thermal_throttle");
+        for name in ["core_throttle_count", "package_throttle_count"] {
+            let path = dir.join(name);
+            if path.is_file() {
+                counters.push(path);
+            }
+        }
+    }
+    counters
+}
+
+fn read_millidegrees(path: &Path) -> Option<f32> {
+    parse_millidegrees(&fs::read_to_string(path).ok()?)
+}
+
+fn parse_millidegrees(raw: &str) -> Option<f32> {
+    let value: i64 = raw.trim().parse().ok()?;
+    Some(value as f32 / 1000.0)
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throttles_on_heat_or_counter_and_cools_with_hysteresis() {
+        let mut monitor = ThermalMonitor::new(Vec::new(), Vec::new());
+        assert!(!monitor.update(Some((70.0, 90.0)), Some(10)).throttled);
+
+        let sample = monitor.update(Some((75.0, 90.0)), Some(12));
+        assert!(sample.throttled && sample.changed);
+
+        // Just under the trip point is not cool enough to recover.
+        for _ in 0..COOL_SAMPLES + 2 {
+            assert!(monitor.update(Some((88.0, 90.0)), Some(12)).throttled);
+        }
+        for _ in 0..COOL_SAMPLES - 1 {
+            assert!(monitor.update(Some((70.0, 90.0)), Some(12)).throttled);
+        }
+        let sample = monitor.update(Some((70.0, 90.0)), Some(12));
+        assert!(!sample.throttled && sample.changed);
+
+        assert!(monitor.update(Some((91.0, 90.0)), None).throttled);
+    }
+
+    #[test]
+    fn parses_sysfs_values() {
+        assert_eq!(parse_millidegrees("54000\n"), Some(54.0));
+        assert_eq!(parse_millidegrees("garbage"), None);
+        assert_eq!(throttled_threads(Some(6)), 3);
+        assert_eq!(throttled_threads(Some(1)), 1);
+    }
+}
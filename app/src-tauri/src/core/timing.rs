@@ -0,0 +1,270 @@
+//! Per-session stage timing for the dictation hot path.
+//!
+//! Every stage from capture to injection runs inside a `tracing` span with target
+//! [`STAGE_TARGET`], so a flamegraph or Chrome-trace subscriber sees the same
+//! breakdown as the `session-timing` event. The measured durations are kept on a
+//! [`SessionTimeline`], which can also be written out as a Chrome trace file that
+//! users attach to performance reports (open it in Perfetto or `chrome://tracing`).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tracing::Span;
+
+pub const STAGE_TARGET: &str = "openflow::stage";
+/// Exported traces kept on disk; older files are deleted as new sessions land.
+const MAX_EXPORTED_TRACES: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Stage {
+    /// Key press to key release: audio flowing into the recognizer buffer.
+    Capture,
+    /// Time spent in the VAD during capture, summed over frames.
+    Vad,
+    /// Waiting behind an earlier session on the finalize worker.
+    Queue,
+    Trim,
+    SpeakerGate,
+    Asr,
+    Clean,
+    /// Output delivery as a whole, including the clipboard hold after a paste.
+    Inject,
+    /// Putting the transcript on the clipboard, part of `Inject`.
+    Clipboard,
+    /// Sending the paste chord, part of `Inject`.
+    KeyInject,
+}
+
+impl Stage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Stage::Capture => "capture",
+            Stage::Vad => "vad",
+            Stage::Queue => "queue",
+            Stage::Trim => "trim",
+            Stage::SpeakerGate => "speaker-gate",
+            Stage::Asr => "asr",
+            Stage::Clean => "clean",
+            Stage::Inject => "inject",
+            Stage::Clipboard => "clipboard",
+            Stage::KeyInject => "key-inject",
+        }
+    }
+
+    /// Span for one run of this stage. Span names must be static, hence the match.
+    pub fn span(self, session_id: u64) -> Span {
+        match self {
+            Stage::Capture => tracing::info_span!(target: STAGE_TARGET, "capture", session_id),
+            // Per frame, so only at trace level and without a session.
+            Stage::Vad => tracing::trace_span!(target: STAGE_TARGET, "vad"),
+            Stage::Queue => tracing::info_span!(target: STAGE_TARGET, "queue", session_id),
+            Stage::Trim => tracing::info_span!(target: STAGE_TARGET, "trim", session_id),
+            Stage::SpeakerGate => {
+                tracing::info_span!(target: STAGE_TARGET, "speaker_gate", session_id)
+            }
+            Stage::Asr => tracing::info_span!(target: STAGE_TARGET, "asr", session_id),
+            Stage::Clean => tracing::info_span!(target: STAGE_TARGET, "clean", session_id),
+            Stage::Inject => tracing::info_span!(target: STAGE_TARGET, "inject", session_id),
+            Stage::Clipboard => tracing::info_span!(target: STAGE_TARGET, "clipboard", session_id),
+            Stage::KeyInject => tracing::info_span!(target: STAGE_TARGET, "key_inject", session_id),
+        }
+    }
+
+    /// Capture-side stages run on the audio thread, the rest on the finalize worker.
+    fn thread(self) -> (u32, &'static str) {
+        match self {
+            Stage::Capture | Stage::Vad => (1, "audio"),
+            _ => (2, "dictation-finalize"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StageTiming {
+    pub stage: Stage,
+    /// Offset from the start of capture.
+    pub start_us: u64,
+    pub duration_us: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionTimeline {
+    pub session_id: u64,
+    pub stages: Vec<StageTiming>,
+    #[serde(skip)]
+    origin: Instant,
+}
+
+impl SessionTimeline {
+    pub fn new(session_id: u64, origin: Instant) -> Self {
+        Self {
+            session_id,
+            stages: Vec::new(),
+            origin,
+        }
+    }
+
+    /// When capture started; stage offsets are relative to it.
+    pub fn origin(&self) -> Instant {
+        self.origin
+    }
+
+    /// Runs `work` as `stage`, inside its span, and records how long it took.
+    pub fn measure<T>(&mut self, stage: Stage, work: impl FnOnce() -> T) -> T {
+        let span = stage.span(self.session_id);
+        let started = Instant::now();
+        let result = span.in_scope(work);
+        self.record(stage, started, started.elapsed());
+        result
+    }
+
+    pub fn record(&mut self, stage: Stage, started: Instant, duration: Duration) {
+        self.stages.push(StageTiming {
+            stage,
+            start_us: started.saturating_duration_since(self.origin).as_micros() as u64,
+            duration_us: duration.as_micros() as u64,
+        });
+    }
+
+    /// Capture start to the end of the last recorded stage.
+    pub fn total(&self) -> Duration {
+        let end_us = self
+            .stages
+            .iter()
+            .map(|timing| timing.start_us + timing.duration_us)
+            .max()
+            .unwrap_or(0);
+        Duration::from_micros(end_us)
+    }
+
+    /// End of the last run of `stage`.
+    pub fn end_of(&self, stage: Stage) -> Option<Duration> {
+        self.stages
+            .iter()
+            .filter(|timing| timing.stage == stage)
+            .map(|timing| Duration::from_micros(timing.start_us + timing.duration_us))
+            .max()
+    }
+
+    /// Key release to the text reaching its destination: the paste chord when one
+    /// was sent, otherwise the end of output delivery or of the last stage.
+    pub fn end_to_end(&self) -> Duration {
+        let released = self.end_of(Stage::Capture).unwrap_or_default();
+        let delivered = self
+            .end_of(Stage::KeyInject)
+            .or_else(|| self.end_of(Stage::Inject))
+            .unwrap_or_else(|| self.total());
+        delivered.saturating_sub(released)
+    }
+
+    /// `stage=ms` pairs for log lines.
+    pub fn summary(&self) -> String {
+        self.stages
+            .iter()
+            .map(|timing| {
+                format!(
+                    "{}={:.1}ms",
+                    timing.stage.as_str(),
+                    timing.duration_us as f64 / 1000.0
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The timeline in Chrome's trace event format, one complete event per stage.
+    pub fn chrome_trace(&self) -> serde_json::Value {
+        let mut events = Vec::with_capacity(self.stages.len() + 2);
+        for (tid, name) in [Stage::Capture.thread(), Stage::Asr.thread()] {
+            events.push(json!({
+                "name": "thread_name", "ph": "M", "pid": 1, "tid": tid,
+                "args": { "name": name },
+            }));
+        }
+        for timing in &self.stages {
+            events.push(json!({
+                "name": timing.stage.as_str(),
+                "cat": "stage",
+                "ph": "X",
+                "ts": timing.start_us,
+                "dur": timing.duration_us,
+                "pid": 1,
+                "tid": timing.stage.thread().0,
+                "args": { "session": self.session_id },
+            }));
+        }
+        json!({ "traceEvents": events, "displayTimeUnit": "ms" })
+    }
+}
+
+/// Where exported Chrome traces are written.
+pub fn traces_dir() -> Result<PathBuf> {
+    let dirs =
+        ProjectDirs::from("com", "OpenFlow", "OpenFlow").context("missing project directories")?;
+    Ok(dirs.cache_dir().join("traces"))
+}
+
+/// Writes `timeline` as `<dir>/session-<unix ms>-<id>.json` and prunes old traces.
+pub fn export_chrome_trace(dir: &Path, timeline: &SessionTimeline) -> Result<PathBuf> {
+    fs::create_dir_all(dir).context("create trace directory")?;
+    let stamp = time::OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000;
+    let path = dir.join(format!("session-{stamp}-{}.json", timeline.session_id));
+    let bytes = serde_json::to_vec(&timeline.chrome_trace()).context("serialize trace")?;
+    fs::write(&path, bytes).with_context(|| format!("write {}", path.display()))?;
+    prune_traces(dir);
+    Ok(path)
+}
+
+fn prune_traces(dir: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut traces: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    if traces.len() <= MAX_EXPORTED_TRACES {
+        return;
+    }
+    // Names start with a fixed-width millisecond stamp, so they sort by age.
+    traces.sort();
+    for stale in &traces[..traces.len() - MAX_EXPORTED_TRACES] {
+        let _ = fs::remove_file(stale);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chrome_trace_places_stages_on_their_threads() {
+        let origin = Instant::now();
+        let mut timeline = SessionTimeline::new(7, origin);
+        timeline.record(Stage::Capture, origin, Duration::from_millis(900));
+        timeline.record(
+            Stage::Asr,
+            origin + Duration::from_millis(910),
+            Duration::from_millis(120),
+        );
+        assert_eq!(timeline.total(), Duration::from_millis(1030));
+
+        let trace = timeline.chrome_trace();
+        let events = trace["traceEvents"].as_array().unwrap();
+        let asr = events.iter().find(|event| event["name"] == "asr").unwrap();
+        assert_eq!(asr["ph"], "X");
+        assert_eq!(asr["ts"], 910_000);
+        assert_eq!(asr["dur"], 120_000);
+        assert_eq!(asr["tid"], 2);
+        assert_eq!(asr["args"]["session"], 7);
+    }
+}
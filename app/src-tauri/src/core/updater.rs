@@ -6,11 +6,29 @@ use anyhow::{anyhow, Context, Result};
 use directories::ProjectDirs;
 use reqwest::blocking::Client;
 use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
 use time::{Duration, OffsetDateTime};
 
+use crate::core::app_state::AppState;
+use crate::core::events::{self, UpdateAvailablePayload};
+use crate::models::TransferRate;
+
 const DEFAULT_MANIFEST_URL: &str =
     "https://github.com/logabell/OpenFlow/releases/latest/download/latest.json";
 
+/// latest.json asset key for the AppImage build. Its `tarball` entry names the
+/// AppImage file itself.
+const APPIMAGE_ASSET_KEY: &str = "linux-x86_64-appimage";
+/// How long an on-demand check may reuse the cached manifest.
+const DEFAULT_CACHE_MAX_AGE: Duration = Duration::hours(24);
+/// Delay before the first scheduled check, then how often the schedule is re-read.
+const SCHEDULE_FIRST_CHECK: std::time::Duration = std::time::Duration::from_secs(30);
+const SCHEDULE_TICK: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+/// Fallback source for release notes when latest.json does not carry them.
+const RELEASES_API_URL: &str = "https://api.github.com/repos/logabell/OpenFlow/releases/tags";
+const ELF_MAGIC: &[u8] = b"\x7fELF";
+
 fn env_flag_enabled(key: &str) -> bool {
     let value = match std::env::var(key) {
         Ok(value) => value,
@@ -27,6 +45,31 @@ fn disable_update_checks() -> bool {
     env_flag_enabled("OPENFLOW_TEST_MODE") || env_flag_enabled("OPENFLOW_DISABLE_UPDATE_CHECK")
 }
 
+/// How the running copy was installed, which decides how updates are applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InstallKind {
+    /// `/opt/openflow`, replaced through pkexec.
+    Tarball,
+    /// A single user-owned file, replaced in place without elevation.
+    AppImage,
+}
+
+/// The AppImage file this process was launched from, if any.
+fn running_appimage() -> Option<PathBuf> {
+    std::env::var_os("APPIMAGE")
+        .map(PathBuf::from)
+        .filter(|path| path.is_file())
+}
+
+pub fn install_kind() -> InstallKind {
+    if running_appimage().is_some() {
+        InstallKind::AppImage
+    } else {
+        InstallKind::Tarball
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct LatestAsset {
@@ -41,6 +84,9 @@ struct LatestAsset {
 struct LatestManifest {
     version: String,
     assets: std::collections::HashMap<String, LatestAsset>,
+    /// Markdown release notes for `version`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    notes: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -56,6 +102,7 @@ pub struct UpdateCheckResult {
     pub current_version: String,
     pub latest_version: String,
     pub update_available: bool,
+    pub install_kind: InstallKind,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tarball_url: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -64,6 +111,24 @@ pub struct UpdateCheckResult {
     pub from_cache: bool,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseNotes {
+    pub version: String,
+    /// Markdown body.
+    pub notes: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubRelease {
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    html_url: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DownloadedUpdate {
@@ -78,6 +143,10 @@ pub struct UpdateDownloadProgress {
     pub downloaded_bytes: u64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub total_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes_per_sec: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eta_secs: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -100,6 +169,21 @@ fn updates_dir() -> Result<PathBuf> {
     Ok(project_dirs()?.cache_dir().join("updates"))
 }
 
+fn release_notes_file(version: &str) -> Result<PathBuf> {
+    Ok(project_dirs()?
+        .cache_dir()
+        .join("release-notes")
+        .join(format!("{version}.json")))
+}
+
+fn last_version_file() -> Result<PathBuf> {
+    Ok(project_dirs()?.config_dir().join("last-version"))
+}
+
+fn current_version() -> String {
+    format!("v{}", env!("CARGO_PKG_VERSION"))
+}
+
 fn manifest_url() -> String {
     std::env::var("OPENFLOW_UPDATE_MANIFEST_URL").unwrap_or_else(|_| DEFAULT_MANIFEST_URL.into())
 }
@@ -149,6 +233,15 @@ fn infer_asset_key_from_exe_binary() -> Option<String> {
 }
 
 fn select_asset_key(manifest: &LatestManifest) -> Result<String> {
+    // A tarball cannot replace an AppImage, so there is no fallback here.
+    if install_kind() == InstallKind::AppImage {
+        return if manifest.assets.contains_key(APPIMAGE_ASSET_KEY) {
+            Ok(APPIMAGE_ASSET_KEY.to_string())
+        } else {
+            Err(anyhow!("latest.json has no {APPIMAGE_ASSET_KEY} asset"))
+        };
+    }
+
     let mut candidates: Vec<String> = Vec::new();
     if let Some(key) = build_flavor_from_install_dir() {
         candidates.push(key);
@@ -245,7 +338,18 @@ fn fetch_manifest(client: &Client, url: &str) -> Result<LatestManifest> {
 }
 
 pub fn check_for_updates(force: bool) -> Result<UpdateCheckResult> {
-    let current_version = format!("v{}", env!("CARGO_PKG_VERSION"));
+    let max_age = if force {
+        Duration::ZERO
+    } else {
+        DEFAULT_CACHE_MAX_AGE
+    };
+    check_for_updates_within(max_age)
+}
+
+/// Checks for updates, answering from the cached manifest if it is younger than
+/// `max_age`.
+fn check_for_updates_within(max_age: Duration) -> Result<UpdateCheckResult> {
+    let current_version = current_version();
 
     if disable_update_checks() {
         let checked_at_unix = OffsetDateTime::now_utc().unix_timestamp();
@@ -253,6 +357,7 @@ pub fn check_for_updates(force: bool) -> Result<UpdateCheckResult> {
             current_version: current_version.clone(),
             latest_version: current_version,
             update_available: false,
+            install_kind: install_kind(),
             tarball_url: None,
             sha256_url: None,
             checked_at_unix,
@@ -266,8 +371,7 @@ pub fn check_for_updates(force: bool) -> Result<UpdateCheckResult> {
     let now = OffsetDateTime::now_utc();
     let cache_path = cache_file()?;
 
-    let max_age = Duration::hours(24);
-    if !force {
+    if max_age > Duration::ZERO {
         if let Some(cache) = read_cache(&cache_path) {
             let checked_at = OffsetDateTime::from_unix_timestamp(cache.checked_at_unix).ok();
             if let Some(checked_at) = checked_at {
@@ -287,6 +391,13 @@ pub fn check_for_updates(force: bool) -> Result<UpdateCheckResult> {
     let client = Client::builder().build().context("create http client")?;
     let manifest = fetch_manifest(&client, &url)?;
     let checked_at_unix = now.unix_timestamp();
+    if let Some(notes) = manifest.notes.clone() {
+        write_release_notes(&ReleaseNotes {
+            version: manifest.version.clone(),
+            notes,
+            url: None,
+        });
+    }
     write_cache(
         &cache_path,
         &UpdateCache {
@@ -328,6 +439,7 @@ fn build_result(
         current_version: current_version.to_string(),
         latest_version,
         update_available,
+        install_kind: install_kind(),
         tarball_url: Some(tarball_url),
         sha256_url: Some(sha256_url),
         checked_at_unix,
@@ -335,6 +447,115 @@ fn build_result(
     })
 }
 
+/// Release notes for `version` (the running version when None): from the cache,
+/// then latest.json, then the GitHub release body. Fetched notes are cached.
+pub fn get_release_notes(version: Option<&str>) -> Result<ReleaseNotes> {
+    let version = match version.map(str::trim).filter(|v| !v.is_empty()) {
+        Some(version) => format!("v{}", version.trim_start_matches('v')),
+        None => current_version(),
+    };
+
+    if let Some(cached) = release_notes_file(&version)
+        .ok()
+        .and_then(|path| fs::read(path).ok())
+        .and_then(|bytes| serde_json::from_slice::<ReleaseNotes>(&bytes).ok())
+    {
+        return Ok(cached);
+    }
+    if let Some(notes) = cache_file()
+        .ok()
+        .and_then(|path| read_cache(&path))
+        .filter(|cache| cache.manifest.version == version)
+        .and_then(|cache| cache.manifest.notes)
+    {
+        let notes = ReleaseNotes {
+            version,
+            notes,
+            url: None,
+        };
+        write_release_notes(&notes);
+        return Ok(notes);
+    }
+    if disable_update_checks() {
+        anyhow::bail!("release notes for {version} are not cached");
+    }
+
+    let url = format!("{RELEASES_API_URL}/{version}");
+    let client = Client::builder().build().context("create http client")?;
+    let release: GithubRelease = client
+        .get(&url)
+        .header(reqwest::header::USER_AGENT, "OpenFlow")
+        .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+        .send()
+        .with_context(|| format!("request {url}"))?
+        .error_for_status()
+        .with_context(|| format!("fetch release {version}"))?
+        .json()
+        .context("parse GitHub release")?;
+    let notes = ReleaseNotes {
+        version,
+        notes: release.body.unwrap_or_default().trim().to_string(),
+        url: release.html_url,
+    };
+    write_release_notes(&notes);
+    Ok(notes)
+}
+
+/// Deletes the cached manifest, downloaded update payloads and cached release
+/// notes. Returns the bytes reclaimed.
+pub fn clear_update_cache() -> Result<u64> {
+    let cache_dir = project_dirs()?.cache_dir().to_path_buf();
+    let mut reclaimed = 0u64;
+    for path in [
+        cache_file()?,
+        updates_dir()?,
+        cache_dir.join("release-notes"),
+    ] {
+        if !path.exists() {
+            continue;
+        }
+        let size = crate::models::total_size(&path);
+        if path.is_dir() {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_file(&path)
+        }
+        .with_context(|| format!("remove {}", path.display()))?;
+        reclaimed += size;
+    }
+    Ok(reclaimed)
+}
+
+fn write_release_notes(notes: &ReleaseNotes) {
+    let Ok(path) = release_notes_file(&notes.version) else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Ok(bytes) = serde_json::to_vec_pretty(notes) {
+        let _ = fs::write(path, bytes);
+    }
+}
+
+/// Records the running version and returns it if the previous launch ran an older
+/// one, i.e. the first launch after an update. Fresh installs return None.
+pub fn take_updated_version() -> Result<Option<String>> {
+    let path = last_version_file()?;
+    let current = current_version();
+    let previous = fs::read_to_string(&path).ok();
+    if previous.as_deref().map(str::trim) == Some(current.as_str()) {
+        return Ok(None);
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context("create config directory")?;
+    }
+    fs::write(&path, &current).context("record launched version")?;
+    Ok(previous
+        .filter(|previous| is_newer(&current, previous.trim()))
+        .map(|_| current))
+}
+
 #[allow(dead_code)]
 pub fn download_update(force: bool) -> Result<DownloadedUpdate> {
     download_update_with_progress(force, |_| {})
@@ -364,8 +585,11 @@ where
     let dir = updates_dir()?;
     fs::create_dir_all(&dir).context("create updates directory")?;
 
-    let tarball_path = dir.join("openflow-update.tar.gz");
-    let sha_path = dir.join("openflow-update.tar.gz.sha256");
+    let tarball_path = match install_kind() {
+        InstallKind::AppImage => dir.join("openflow-update.AppImage"),
+        InstallKind::Tarball => dir.join("openflow-update.tar.gz"),
+    };
+    let sha_path = sibling_with_suffix(&tarball_path, ".sha256");
 
     if !force && tarball_path.is_file() && sha_path.is_file() {
         if verify_sha256_file(&tarball_path, &sha_path).is_ok() {
@@ -378,19 +602,27 @@ where
 
     let client = Client::builder().build().context("create http client")?;
 
+    let mut rate = TransferRate::new();
     download_url_to_file_with_progress(&client, &tarball_url, &tarball_path, |d, t| {
+        rate.record(d);
         on_progress(UpdateDownloadProgress {
             stage: "tarball".to_string(),
             downloaded_bytes: d,
             total_bytes: t,
+            bytes_per_sec: rate.bytes_per_sec(),
+            eta_secs: rate.eta_secs(d, t),
         });
     })?;
 
+    let mut rate = TransferRate::new();
     download_url_to_file_with_progress(&client, &sha_url, &sha_path, |d, t| {
+        rate.record(d);
         on_progress(UpdateDownloadProgress {
             stage: "sha256".to_string(),
             downloaded_bytes: d,
             total_bytes: t,
+            bytes_per_sec: rate.bytes_per_sec(),
+            eta_secs: rate.eta_secs(d, t),
         });
     })?;
 
@@ -452,6 +684,12 @@ fn download_url_to_file_with_progress(
     Ok(())
 }
 
+fn sibling_with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
 fn verify_sha256_file(tarball: &Path, sha_file: &Path) -> Result<()> {
     let expected = fs::read_to_string(sha_file)
         .with_context(|| format!("read sha256 file {}", sha_file.display()))?
@@ -467,6 +705,88 @@ fn verify_sha256_file(tarball: &Path, sha_file: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Applies a downloaded update the way the running install expects.
+pub fn apply_update_with_progress<F>(payload_path: &str, on_progress: F) -> Result<()>
+where
+    F: FnMut(UpdateApplyProgress),
+{
+    match install_kind() {
+        InstallKind::AppImage => apply_appimage_update_with_progress(payload_path, on_progress),
+        InstallKind::Tarball => apply_update_with_pkexec_with_progress(payload_path, on_progress),
+    }
+}
+
+fn ensure_in_updates_dir(path: &str) -> Result<PathBuf> {
+    let allowed_dir = updates_dir()?;
+    let canonical = PathBuf::from(path)
+        .canonicalize()
+        .with_context(|| format!("canonicalize {path}"))?;
+    let allowed = allowed_dir.canonicalize().unwrap_or(allowed_dir);
+    if !canonical.starts_with(&allowed) {
+        anyhow::bail!("refusing to apply update from outside cache dir");
+    }
+    Ok(canonical)
+}
+
+/// Replaces the running AppImage with the downloaded one. The new image is copied
+/// next to the old one and renamed over it, so the file at `$APPIMAGE` is always
+/// a complete image; the running process keeps its already-mounted copy.
+pub fn apply_appimage_update_with_progress<F>(payload_path: &str, mut on_progress: F) -> Result<()>
+where
+    F: FnMut(UpdateApplyProgress),
+{
+    use std::os::unix::fs::PermissionsExt;
+
+    let target = running_appimage().ok_or_else(|| anyhow!("not running from an AppImage"))?;
+    let payload = ensure_in_updates_dir(payload_path)?;
+
+    on_progress(UpdateApplyProgress {
+        stage: "validate".to_string(),
+        message: None,
+    });
+    verify_sha256_file(&payload, &sibling_with_suffix(&payload, ".sha256"))?;
+    let mut magic = [0u8; 4];
+    fs::File::open(&payload)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .context("read downloaded AppImage")?;
+    if magic != ELF_MAGIC {
+        anyhow::bail!("invalid update payload (not an AppImage)");
+    }
+
+    on_progress(UpdateApplyProgress {
+        stage: "swap".to_string(),
+        message: None,
+    });
+    let target = target.canonicalize().unwrap_or(target);
+    let file_name = target
+        .file_name()
+        .ok_or_else(|| anyhow!("AppImage path has no file name"))?
+        .to_string_lossy()
+        .into_owned();
+    let staged = target.with_file_name(format!(".{file_name}.update"));
+    let result = (|| -> Result<()> {
+        fs::copy(&payload, &staged)
+            .with_context(|| format!("stage AppImage next to {}", target.display()))?;
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o755))
+            .context("mark AppImage executable")?;
+        fs::File::open(&staged)
+            .and_then(|file| file.sync_all())
+            .context("flush staged AppImage")?;
+        fs::rename(&staged, &target).with_context(|| format!("replace {}", target.display()))
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&staged);
+    }
+    result?;
+    let _ = fs::remove_file(&payload);
+
+    on_progress(UpdateApplyProgress {
+        stage: "done".to_string(),
+        message: None,
+    });
+    Ok(())
+}
+
 #[allow(dead_code)]
 pub fn apply_update_with_pkexec(tarball_path: &str) -> Result<()> {
     apply_update_with_pkexec_with_progress(tarball_path, |_| {})
@@ -483,15 +803,7 @@ where
         anyhow::bail!("update tarball not found: {tarball_path}");
     }
 
-    let allowed_dir = updates_dir()?;
-    let tarball = PathBuf::from(tarball_path);
-    let canonical = tarball
-        .canonicalize()
-        .with_context(|| format!("canonicalize {tarball_path}"))?;
-    let allowed = allowed_dir.canonicalize().unwrap_or(allowed_dir);
-    if !canonical.starts_with(&allowed) {
-        anyhow::bail!("refusing to apply update from outside cache dir");
-    }
+    let canonical = ensure_in_updates_dir(tarball_path)?;
 
     let pkexec = if Path::new("/usr/bin/pkexec").is_file() {
         "/usr/bin/pkexec"
@@ -620,3 +932,85 @@ progress "done"
 
     Ok(())
 }
+
+fn cadence_interval(cadence: &str) -> Option<Duration> {
+    match cadence {
+        "daily" => Some(Duration::days(1)),
+        "weekly" => Some(Duration::weeks(1)),
+        _ => None,
+    }
+}
+
+/// Checks for app updates on the cadence chosen in settings, announcing each new
+/// version once per run and optionally downloading it ahead of time.
+pub fn spawn_update_scheduler(app: AppHandle) {
+    let spawned = std::thread::Builder::new()
+        .name("app-updates".into())
+        .spawn(move || {
+            std::thread::sleep(SCHEDULE_FIRST_CHECK);
+            let mut announced: Option<(String, bool)> = None;
+            loop {
+                let settings = app
+                    .try_state::<AppState>()
+                    .and_then(|state| state.settings_manager().read_frontend().ok());
+                let interval = settings
+                    .as_ref()
+                    .and_then(|settings| cadence_interval(&settings.update_check_cadence));
+                if let (Some(settings), Some(interval)) = (settings, interval) {
+                    run_scheduled_check(
+                        &app,
+                        interval,
+                        settings.pre_download_updates,
+                        &mut announced,
+                    );
+                }
+                std::thread::sleep(SCHEDULE_TICK);
+            }
+        });
+    if let Err(error) = spawned {
+        tracing::warn!("failed to start app update scheduler: {error}");
+    }
+}
+
+fn run_scheduled_check(
+    app: &AppHandle,
+    interval: Duration,
+    pre_download: bool,
+    announced: &mut Option<(String, bool)>,
+) {
+    let check = match check_for_updates_within(interval) {
+        Ok(check) => check,
+        Err(error) => {
+            tracing::debug!("Scheduled update check failed: {error:?}");
+            return;
+        }
+    };
+    if !check.update_available {
+        return;
+    }
+
+    let downloaded = if pre_download {
+        match download_update_with_progress(false, |_| {}) {
+            Ok(downloaded) if !downloaded.tarball_path.is_empty() => Some(downloaded),
+            Ok(_) => None,
+            Err(error) => {
+                tracing::warn!("Background update download failed: {error:?}");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let key = (check.latest_version.clone(), downloaded.is_some());
+    if announced.as_ref() == Some(&key) {
+        return;
+    }
+    tracing::info!(
+        "app_update_available version={} downloaded={}",
+        check.latest_version,
+        downloaded.is_some()
+    );
+    *announced = Some(key);
+    events::emit_update_available(app, UpdateAvailablePayload { check, downloaded });
+}
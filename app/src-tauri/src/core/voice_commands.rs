@@ -0,0 +1,276 @@
+//! Voice command mode: a session started with the command hotkey is matched against
+//! user-defined phrases and executed instead of pasted.
+
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::core::linux_setup::binary_in_path;
+use crate::output::PasteShortcut;
+
+/// A phrase and what it does, as stored in settings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceCommand {
+    /// Matched case- and punctuation-insensitively against the whole utterance.
+    pub phrase: String,
+    /// "keys", "text", "delete-last-sentence", "delete-last-utterance",
+    /// "select-last-sentence", "replace" or "launch".
+    pub action: String,
+    /// Chord for "keys" (e.g. "Ctrl+Z"), literal text for "text", program and
+    /// arguments for "launch"; unused by the editing actions.
+    pub argument: String,
+}
+
+impl VoiceCommand {
+    fn new(phrase: &str, action: &str, argument: &str) -> Self {
+        Self {
+            phrase: phrase.into(),
+            action: action.into(),
+            argument: argument.into(),
+        }
+    }
+}
+
+pub fn default_commands() -> Vec<VoiceCommand> {
+    vec![
+        VoiceCommand::new("new line", "keys", "Enter"),
+        VoiceCommand::new("new paragraph", "text", "\n\n"),
+        VoiceCommand::new("delete last sentence", "delete-last-sentence", ""),
+        VoiceCommand::new("scratch that", "delete-last-utterance", ""),
+        VoiceCommand::new("select last sentence", "select-last-sentence", ""),
+        VoiceCommand::new("replace", "replace", ""),
+        VoiceCommand::new("undo", "keys", "Ctrl+Z"),
+        VoiceCommand::new("select all", "keys", "Ctrl+A"),
+        VoiceCommand::new("open terminal", "launch", "x-terminal-emulator"),
+    ]
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandAction {
+    Keys(PasteShortcut),
+    Text(String),
+    DeleteLastSentence,
+    /// Erases the whole last dictation in the focused window.
+    DeleteLastUtterance,
+    SelectLastSentence,
+    /// "<phrase> X with Y": rewrites the last X in the last dictation as Y.
+    Replace,
+    Launch(Vec<String>),
+}
+
+#[derive(Debug, Clone)]
+pub struct RegisteredCommand {
+    pub phrase: String,
+    pub action: CommandAction,
+    key: String,
+}
+
+/// Parsed commands; entries that fail to parse are logged and left out.
+#[derive(Debug, Clone, Default)]
+pub struct CommandRegistry {
+    commands: Vec<RegisteredCommand>,
+}
+
+impl CommandRegistry {
+    pub fn from_settings(commands: &[VoiceCommand]) -> Self {
+        let mut registered: Vec<RegisteredCommand> = Vec::new();
+        for command in commands {
+            let key = normalize(&command.phrase);
+            if key.is_empty() || registered.iter().any(|existing| existing.key == key) {
+                continue;
+            }
+            match parse_action(command) {
+                Ok(action) => registered.push(RegisteredCommand {
+                    phrase: command.phrase.trim().to_string(),
+                    action,
+                    key,
+                }),
+                Err(error) => warn!(
+                    "voice_command_invalid phrase={:?} error={error:#}",
+                    command.phrase
+                ),
+            }
+        }
+        Self {
+            commands: registered,
+        }
+    }
+
+    /// The command whose phrase the utterance consists of, ignoring case, punctuation
+    /// and spacing ("New line." matches "new line"). Replace commands only need the
+    /// utterance to start with their phrase.
+    pub fn lookup(&self, utterance: &str) -> Option<&RegisteredCommand> {
+        let key = normalize(utterance);
+        self.commands
+            .iter()
+            .find(|command| command.key == key)
+            .or_else(|| {
+                self.commands.iter().find(|command| {
+                    command.action == CommandAction::Replace
+                        && key
+                            .strip_prefix(&command.key)
+                            .is_some_and(|rest| rest.starts_with(' '))
+                })
+            })
+    }
+}
+
+/// What `command` does, or why its argument can't be used.
+pub fn parse_action(command: &VoiceCommand) -> Result<CommandAction> {
+    let argument = command.argument.trim();
+    match command.action.as_str() {
+        "keys" => Ok(CommandAction::Keys(PasteShortcut::parse(argument)?)),
+        "text" if !command.argument.is_empty() => Ok(CommandAction::Text(command.argument.clone())),
+        "delete-last-sentence" => Ok(CommandAction::DeleteLastSentence),
+        "delete-last-utterance" => Ok(CommandAction::DeleteLastUtterance),
+        "select-last-sentence" => Ok(CommandAction::SelectLastSentence),
+        "replace" => Ok(CommandAction::Replace),
+        "launch" => {
+            let argv: Vec<String> = argument.split_whitespace().map(str::to_string).collect();
+            if argv.is_empty() {
+                bail!("launch command is empty");
+            }
+            Ok(CommandAction::Launch(argv))
+        }
+        "text" => bail!("text is empty"),
+        other => bail!("unknown action {other:?}"),
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Splits `text` before its last sentence. The removed part includes the whitespace
+/// separating it from the kept text, so deleting it leaves no trailing space.
+pub fn split_last_sentence(text: &str) -> (&str, &str) {
+    let body = text.trim_end_matches(|c: char| c.is_whitespace() || ".!?".contains(c));
+    let start = body
+        .char_indices()
+        .filter(|(_, c)| ".!?\n".contains(*c))
+        .map(|(index, c)| index + c.len_utf8())
+        .next_back()
+        .unwrap_or(0);
+    let kept = text[..start].trim_end_matches(|c: char| c == ' ' || c == '\t');
+    (kept, &text[kept.len()..])
+}
+
+/// Target and replacement of "<phrase> X with Y", in the utterance's own casing.
+pub fn parse_replacement(phrase: &str, utterance: &str) -> Option<(String, String)> {
+    let words: Vec<&str> = utterance
+        .split_whitespace()
+        .skip(phrase.split_whitespace().count())
+        .collect();
+    let split = words
+        .iter()
+        .enumerate()
+        .skip(1)
+        .find(|(index, word)| *index + 1 < words.len() && normalize(word) == "with")
+        .map(|(index, _)| index)?;
+    let trim = |words: &[&str]| {
+        words
+            .join(" ")
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_string()
+    };
+    let target = trim(&words[..split]);
+    let replacement = trim(&words[split + 1..]);
+    (!target.is_empty() && !replacement.is_empty()).then_some((target, replacement))
+}
+
+/// How to rewrite the tail of `text` so its last `target` (ignoring ASCII case) reads
+/// `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TailEdit {
+    /// Characters to erase from the end.
+    pub erase: usize,
+    /// Text to type after erasing.
+    pub insert: String,
+    /// `text` after the edit.
+    pub result: String,
+}
+
+pub fn replace_last(text: &str, target: &str, replacement: &str) -> Option<TailEdit> {
+    let start = text
+        .to_ascii_lowercase()
+        .rfind(&target.to_ascii_lowercase())?;
+    let end = start + target.len();
+    let insert = format!("{replacement}{}", &text[end..]);
+    Some(TailEdit {
+        erase: text[start..].chars().count(),
+        result: format!("{}{insert}", &text[..start]),
+        insert,
+    })
+}
+
+/// Starts a program detached from the app; it is not waited on.
+pub fn launch(argv: &[String]) -> Result<()> {
+    let Some((program, args)) = argv.split_first() else {
+        bail!("launch command is empty");
+    };
+    if !program.contains('/') && !binary_in_path(program) {
+        bail!("{program} not found in PATH");
+    }
+    let mut child = Command::new(program)
+        .args(args)
+        .spawn()
+        .with_context(|| format!("spawn {program}"))?;
+    // Reap it whenever it exits so it doesn't linger as a zombie.
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_whole_utterances_and_splits_sentences() {
+        let registry = CommandRegistry::from_settings(&default_commands());
+        for command in default_commands() {
+            assert!(
+                registry.lookup(&command.phrase).is_some(),
+                "{}",
+                command.phrase
+            );
+        }
+        let command = registry.lookup(" New line.").expect("new line");
+        assert_eq!(command.phrase, "new line");
+        assert!(registry.lookup("add a new line here").is_none());
+
+        assert_eq!(
+            split_last_sentence("First one. Second one."),
+            ("First one.", " Second one.")
+        );
+        assert_eq!(split_last_sentence("Only one"), ("", "Only one"));
+    }
+
+    #[test]
+    fn replace_takes_arguments_and_rewrites_the_tail() {
+        let registry = CommandRegistry::from_settings(&default_commands());
+        let utterance = "Replace Tuesday with next Wednesday.";
+        let command = registry.lookup(utterance).expect("replace");
+        assert_eq!(command.action, CommandAction::Replace);
+        assert!(registry.lookup("replacement").is_none());
+
+        let (target, replacement) = parse_replacement(&command.phrase, utterance).unwrap();
+        assert_eq!(
+            (target.as_str(), replacement.as_str()),
+            ("Tuesday", "next Wednesday")
+        );
+        assert!(parse_replacement("replace", "replace Tuesday").is_none());
+
+        let edit = replace_last("See you tuesday at noon.", &target, &replacement).unwrap();
+        assert_eq!(edit.erase, "tuesday at noon.".len());
+        assert_eq!(edit.insert, "next Wednesday at noon.");
+        assert_eq!(edit.result, "See you next Wednesday at noon.");
+        assert!(replace_last("Nothing here", "Friday", "Monday").is_none());
+    }
+}
@@ -1,11 +1,17 @@
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+use super::corrections::ReplacementRules;
+use super::formatting::FormattingLocale;
+use super::polish::{self, PolishConfig};
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub enum AutocleanMode {
     Off,
     Fast,
+    /// Fast cleanup, then a pass through a local LLM (see `llm::polish`).
+    Polish,
 }
 
 impl Default for AutocleanMode {
@@ -14,8 +20,71 @@ impl Default for AutocleanMode {
     }
 }
 
+/// Pause lengths that become punctuation when separately decoded segments are joined,
+/// for engines that emit none. Configured per dictation language.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PauseThresholds {
+    pub comma_ms: u32,
+    pub period_ms: u32,
+}
+
+impl Default for PauseThresholds {
+    fn default() -> Self {
+        Self {
+            comma_ms: 450,
+            period_ms: 1_000,
+        }
+    }
+}
+
+impl PauseThresholds {
+    /// Joins `pieces`, where `pauses_ms[i]` is the silence between `pieces[i]` and
+    /// `pieces[i + 1]`. Pieces that already end in punctuation are left alone; an empty
+    /// piece passes its pause on to the next boundary.
+    pub fn join(&self, pieces: &[String], pauses_ms: &[u32]) -> String {
+        let mut joined = String::new();
+        let mut pause = 0u32;
+        for (index, piece) in pieces.iter().enumerate() {
+            if index > 0 {
+                pause = pause.max(pauses_ms.get(index - 1).copied().unwrap_or(0));
+            }
+            let piece = piece.trim();
+            if piece.is_empty() {
+                continue;
+            }
+            if joined.is_empty() {
+                joined.push_str(piece);
+                continue;
+            }
+            let punctuated = joined.ends_with(['.', ',', '!', '?', ';', ':']);
+            let sentence_end = !punctuated && pause >= self.period_ms;
+            if sentence_end {
+                joined.push('.');
+            } else if !punctuated && pause >= self.comma_ms {
+                joined.push(',');
+            }
+            joined.push(' ');
+            if sentence_end {
+                let mut chars = piece.chars();
+                if let Some(first) = chars.next() {
+                    joined.extend(first.to_uppercase());
+                    joined.push_str(chars.as_str());
+                }
+            } else {
+                joined.push_str(piece);
+            }
+            pause = 0;
+        }
+        joined
+    }
+}
+
 pub struct TierOneRuleSet {
     filler_re: Regex,
+    /// Fillers for code-switched text: "um" is also a German word ("um drei Uhr"), so it
+    /// only counts as a filler when set off by a comma.
+    code_switch_filler_re: Regex,
     whitespace_re: Regex,
 }
 
@@ -23,17 +92,26 @@ impl TierOneRuleSet {
     pub fn new() -> Self {
         Self {
             filler_re: Regex::new(r"\b(um|uh|like|you know)\b[, ]*").unwrap(),
+            code_switch_filler_re: Regex::new(r"\b(?:uh|like|you know)\b[, ]*|\bum, *").unwrap(),
             whitespace_re: Regex::new(r"\s+").unwrap(),
         }
     }
 
     pub fn apply(&self, raw: &str) -> String {
+        self.apply_with(raw, &self.filler_re)
+    }
+
+    pub fn apply_code_switched(&self, raw: &str) -> String {
+        self.apply_with(raw, &self.code_switch_filler_re)
+    }
+
+    fn apply_with(&self, raw: &str, filler_re: &Regex) -> String {
         let trimmed = raw.trim();
         if trimmed.is_empty() {
             return String::new();
         }
 
-        let without_fillers = self.filler_re.replace_all(trimmed, "");
+        let without_fillers = filler_re.replace_all(trimmed, "");
         let cleaned = self.whitespace_re.replace_all(&without_fillers, " ");
         punctuate(&cleaned)
     }
@@ -42,6 +120,11 @@ impl TierOneRuleSet {
 pub struct AutocleanService {
     tier_one: TierOneRuleSet,
     mode: std::sync::Mutex<AutocleanMode>,
+    locale: std::sync::Mutex<Option<FormattingLocale>>,
+    replacements: std::sync::Mutex<ReplacementRules>,
+    code_switching: std::sync::atomic::AtomicBool,
+    pause_punctuation: std::sync::Mutex<Option<PauseThresholds>>,
+    polish: std::sync::Mutex<PolishConfig>,
 }
 
 impl AutocleanService {
@@ -49,6 +132,89 @@ impl AutocleanService {
         Self {
             tier_one: TierOneRuleSet::new(),
             mode: std::sync::Mutex::new(AutocleanMode::Fast),
+            locale: std::sync::Mutex::new(None),
+            replacements: std::sync::Mutex::new(ReplacementRules::default()),
+            code_switching: std::sync::atomic::AtomicBool::new(false),
+            pause_punctuation: std::sync::Mutex::new(None),
+            polish: std::sync::Mutex::new(PolishConfig::default()),
+        }
+    }
+
+    pub fn set_locale(&self, locale: Option<FormattingLocale>) {
+        if let Ok(mut guard) = self.locale.lock() {
+            *guard = locale;
+        }
+    }
+
+    /// Rules learned from user corrections; applied in every mode, after formatting.
+    pub fn set_replacements(&self, rules: ReplacementRules) {
+        if let Ok(mut guard) = self.replacements.lock() {
+            *guard = rules;
+        }
+    }
+
+    /// Dictations may mix languages; keeps words that are only fillers in English.
+    pub fn set_code_switching(&self, enabled: bool) {
+        self.code_switching
+            .store(enabled, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Punctuates pauses between separately decoded segments; None joins them with spaces.
+    pub fn set_pause_punctuation(&self, thresholds: Option<PauseThresholds>) {
+        if let Ok(mut guard) = self.pause_punctuation.lock() {
+            *guard = thresholds;
+        }
+    }
+
+    pub fn pause_punctuation(&self) -> Option<PauseThresholds> {
+        *self
+            .pause_punctuation
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+
+    pub fn set_polish(&self, config: PolishConfig) {
+        if let Ok(mut guard) = self.polish.lock() {
+            *guard = config;
+        }
+    }
+
+    /// In polish mode, runs `cleaned` (the output of `clean`) through the LLM; any
+    /// failure keeps the fast cleanup. Other modes return it unchanged.
+    pub fn polish(&self, cleaned: &str) -> String {
+        if self.mode() != AutocleanMode::Polish || cleaned.trim().is_empty() {
+            return cleaned.to_string();
+        }
+        let config = self
+            .polish
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .clone();
+        let started = std::time::Instant::now();
+        match polish::polish(&config, cleaned) {
+            Ok(polished) => {
+                tracing::debug!(
+                    "polish_applied elapsed_ms={}",
+                    started.elapsed().as_millis()
+                );
+                // The model may undo learned corrections; reapply them.
+                let replacements = self
+                    .replacements
+                    .lock()
+                    .unwrap_or_else(|error| error.into_inner());
+                if replacements.is_empty() {
+                    polished
+                } else {
+                    replacements.apply(&polished)
+                }
+            }
+            Err(error) => {
+                tracing::warn!(
+                    "polish_failed fallback=fast elapsed_ms={} error={error:#}",
+                    started.elapsed().as_millis()
+                );
+                cleaned.to_string()
+            }
         }
     }
 
@@ -63,10 +229,56 @@ impl AutocleanService {
     }
 
     pub fn clean(&self, text: &str) -> String {
+        let cleaned = self.clean_with_mode(text);
+        let replacements = self
+            .replacements
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        if replacements.is_empty() {
+            cleaned
+        } else {
+            replacements.apply(&cleaned)
+        }
+    }
+
+    /// Cleans a transcript decoded as `pieces` separated by `pauses_ms` of silence.
+    pub fn clean_timed(&self, pieces: &[String], pauses_ms: &[u32]) -> String {
+        let joined = match self.pause_punctuation() {
+            Some(thresholds) if self.mode() != AutocleanMode::Off => {
+                thresholds.join(pieces, pauses_ms)
+            }
+            _ => pieces
+                .iter()
+                .map(|piece| piece.trim())
+                .filter(|piece| !piece.is_empty())
+                .collect::<Vec<_>>()
+                .join(" "),
+        };
+        self.clean(&joined)
+    }
+
+    fn clean_with_mode(&self, text: &str) -> String {
         let mode = self.mode();
         match mode {
             AutocleanMode::Off => text.to_string(),
-            AutocleanMode::Fast => self.tier_one.apply(text),
+            AutocleanMode::Fast | AutocleanMode::Polish => {
+                let cleaned = if self
+                    .code_switching
+                    .load(std::sync::atomic::Ordering::Relaxed)
+                {
+                    self.tier_one.apply_code_switched(text)
+                } else {
+                    self.tier_one.apply(text)
+                };
+                let locale = *self
+                    .locale
+                    .lock()
+                    .unwrap_or_else(|error| error.into_inner());
+                match locale {
+                    Some(locale) => locale.apply(&cleaned),
+                    None => cleaned,
+                }
+            }
         }
     }
 }
@@ -88,6 +300,28 @@ mod tests {
         let cleaned = service.clean(" um hello  world  ");
         assert_eq!(cleaned, "Hello world.");
     }
+
+    #[test]
+    fn pauses_become_commas_and_sentence_breaks() {
+        let service = AutocleanService::new();
+        service.set_pause_punctuation(Some(PauseThresholds::default()));
+        let pieces = ["so first", "", "we ship it", "then we test", "done."]
+            .map(String::from)
+            .to_vec();
+        let cleaned = service.clean_timed(&pieces, &[500, 100, 1_200, 600]);
+        assert_eq!(cleaned, "So first, we ship it. Then we test, done.");
+    }
+
+    #[test]
+    fn code_switched_text_survives_fast_mode() {
+        let service = AutocleanService::new();
+        service.set_code_switching(true);
+        let cleaned = service.clean("über das Meeting um drei Uhr, um, then send the notes");
+        assert_eq!(
+            cleaned,
+            "Über das Meeting um drei Uhr, then send the notes."
+        );
+    }
 }
 
 fn punctuate(value: &str) -> String {
@@ -97,7 +331,7 @@ fn punctuate(value: &str) -> String {
     }
     let mut chars = sentence.chars();
     if let Some(first) = chars.next() {
-        sentence.replace_range(..1, &first.to_uppercase().to_string());
+        sentence.replace_range(..first.len_utf8(), &first.to_uppercase().to_string());
     }
     sentence
 }
@@ -0,0 +1,309 @@
+//! Local personalization from user corrections.
+//!
+//! Every edit made in the review step is stored as an (original, corrected) pair.
+//! Word-level substitutions that keep recurring are promoted to replacement rules
+//! applied after cleanup, and their corrected words form a vocabulary that ASR
+//! backends can boost. Nothing leaves the machine.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use directories::ProjectDirs;
+use parking_lot::RwLock;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+const CORRECTIONS_FILE: &str = "corrections.json";
+/// A substitution becomes a rule once the user has made it this many times.
+pub const RULE_PROMOTION_COUNT: u32 = 3;
+const MAX_STORED_PAIRS: usize = 500;
+/// Longer differing spans are rewrites, not recognition mistakes.
+const MAX_SUBSTITUTION_WORDS: usize = 4;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CorrectionPair {
+    pub original: String,
+    pub corrected: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub recorded_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Substitution {
+    /// Lowercased, punctuation-stripped words as recognized.
+    pub from: String,
+    /// Words as the user typed them.
+    pub to: String,
+    pub count: u32,
+}
+
+impl Substitution {
+    pub fn is_rule(&self) -> bool {
+        self.count >= RULE_PROMOTION_COUNT
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct CorrectionLog {
+    pairs: Vec<CorrectionPair>,
+    substitutions: Vec<Substitution>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CorrectionSummary {
+    pub pair_count: usize,
+    pub substitutions: Vec<Substitution>,
+    pub vocabulary: Vec<String>,
+}
+
+/// Compiled replacement rules, applied in the order they were learned.
+#[derive(Debug, Clone, Default)]
+pub struct ReplacementRules {
+    rules: Vec<(Regex, String)>,
+}
+
+impl ReplacementRules {
+    pub fn from_substitutions<'a>(
+        substitutions: impl IntoIterator<Item = &'a Substitution>,
+    ) -> Self {
+        let rules = substitutions
+            .into_iter()
+            .filter(|substitution| substitution.is_rule())
+            .filter_map(|substitution| {
+                let pattern = substitution
+                    .from
+                    .split_whitespace()
+                    .map(regex::escape)
+                    .collect::<Vec<_>>()
+                    .join(r"\s+");
+                Regex::new(&format!(r"(?i)\b{pattern}\b"))
+                    .ok()
+                    .map(|re| (re, substitution.to.clone()))
+            })
+            .collect();
+        Self { rules }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    pub fn apply(&self, text: &str) -> String {
+        let mut output = text.to_string();
+        for (re, replacement) in &self.rules {
+            output = re
+                .replace_all(&output, regex::NoExpand(replacement.as_str()))
+                .into_owned();
+        }
+        output
+    }
+}
+
+pub struct CorrectionStore {
+    path: PathBuf,
+    inner: RwLock<CorrectionLog>,
+}
+
+impl CorrectionStore {
+    pub fn new() -> Self {
+        let path = resolve_corrections_path().unwrap_or_else(|_| PathBuf::from(CORRECTIONS_FILE));
+        let log = load_log(&path).unwrap_or_default();
+        Self {
+            path,
+            inner: RwLock::new(log),
+        }
+    }
+
+    /// Records one correction and returns true when it promoted a new rule.
+    pub fn submit(&self, original: &str, corrected: &str) -> Result<bool> {
+        let original = original.trim();
+        let corrected = corrected.trim();
+        if original.is_empty() || corrected.is_empty() || original == corrected {
+            return Ok(false);
+        }
+
+        let mut log = self.inner.write();
+        log.pairs.push(CorrectionPair {
+            original: original.to_string(),
+            corrected: corrected.to_string(),
+            recorded_at: OffsetDateTime::now_utc(),
+        });
+        if log.pairs.len() > MAX_STORED_PAIRS {
+            let excess = log.pairs.len() - MAX_STORED_PAIRS;
+            log.pairs.drain(..excess);
+        }
+
+        let mut promoted = false;
+        for (from, to) in diff_substitutions(original, corrected) {
+            let existing = log
+                .substitutions
+                .iter()
+                .position(|existing| existing.from == from && existing.to == to);
+            match existing {
+                Some(index) => {
+                    let substitution = &mut log.substitutions[index];
+                    substitution.count += 1;
+                    promoted |= substitution.count == RULE_PROMOTION_COUNT;
+                }
+                None => log.substitutions.push(Substitution { from, to, count: 1 }),
+            }
+        }
+
+        persist_log(&self.path, &log)?;
+        Ok(promoted)
+    }
+
+    pub fn rules(&self) -> ReplacementRules {
+        ReplacementRules::from_substitutions(&self.inner.read().substitutions)
+    }
+
+    pub fn summary(&self) -> CorrectionSummary {
+        let log = self.inner.read();
+        let mut substitutions = log.substitutions.clone();
+        substitutions.sort_by(|a, b| b.count.cmp(&a.count));
+        CorrectionSummary {
+            pair_count: log.pairs.len(),
+            vocabulary: vocabulary_of(&log.substitutions),
+            substitutions,
+        }
+    }
+
+    pub fn forget(&self, from: &str, to: &str) -> Result<()> {
+        let mut log = self.inner.write();
+        log.substitutions
+            .retain(|substitution| !(substitution.from == from && substitution.to == to));
+        persist_log(&self.path, &log)
+    }
+
+    pub fn clear(&self) -> Result<()> {
+        let mut log = self.inner.write();
+        *log = CorrectionLog::default();
+        persist_log(&self.path, &log)
+    }
+}
+
+impl Default for CorrectionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn vocabulary_of(substitutions: &[Substitution]) -> Vec<String> {
+    substitutions
+        .iter()
+        .filter(|substitution| substitution.is_rule())
+        .flat_map(|substitution| substitution.to.split_whitespace())
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_string()
+        })
+        .filter(|word| word.chars().count() > 2)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+fn normalize_word(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+/// Differing word spans between the two texts, after dropping the common prefix
+/// and suffix. Case and punctuation-only edits are left to the formatter.
+fn diff_substitutions(original: &str, corrected: &str) -> Vec<(String, String)> {
+    let before: Vec<&str> = original.split_whitespace().collect();
+    let after: Vec<&str> = corrected.split_whitespace().collect();
+
+    let same = |a: &&str, b: &&str| normalize_word(a) == normalize_word(b);
+    let prefix = before
+        .iter()
+        .zip(&after)
+        .take_while(|(a, b)| same(a, b))
+        .count();
+    let suffix = before[prefix..]
+        .iter()
+        .rev()
+        .zip(after[prefix..].iter().rev())
+        .take_while(|(a, b)| same(a, b))
+        .count();
+
+    let from_span = &before[prefix..before.len() - suffix];
+    let to_span = &after[prefix..after.len() - suffix];
+    if from_span.is_empty()
+        || to_span.is_empty()
+        || from_span.len() > MAX_SUBSTITUTION_WORDS
+        || to_span.len() > MAX_SUBSTITUTION_WORDS
+    {
+        return Vec::new();
+    }
+
+    let from = from_span
+        .iter()
+        .map(|word| normalize_word(word))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let to = to_span
+        .iter()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if from.is_empty() || to.is_empty() || from == to.to_lowercase() {
+        return Vec::new();
+    }
+    vec![(from, to)]
+}
+
+fn resolve_corrections_path() -> Result<PathBuf> {
+    let project_dirs =
+        ProjectDirs::from("com", "OpenFlow", "OpenFlow").context("missing project directories")?;
+    let dir = project_dirs.config_dir();
+    fs::create_dir_all(dir).context("creating config directory failed")?;
+    Ok(dir.join(CORRECTIONS_FILE))
+}
+
+fn load_log(path: &Path) -> Result<CorrectionLog> {
+    if !path.exists() {
+        return Ok(CorrectionLog::default());
+    }
+    let bytes = fs::read(path).with_context(|| format!("failed reading {path:?}"))?;
+    serde_json::from_slice(&bytes).context("corrections json could not be parsed")
+}
+
+fn persist_log(path: &Path, log: &CorrectionLog) -> Result<()> {
+    let serialized = serde_json::to_vec_pretty(log).context("serialize corrections failed")?;
+    fs::write(path, serialized).with_context(|| format!("write corrections to {path:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_substitution_becomes_rule() {
+        let pairs = diff_substitutions("Ship it to cube control.", "Ship it to kubectl.");
+        assert_eq!(
+            pairs,
+            vec![("cube control".to_string(), "kubectl".to_string())]
+        );
+
+        let substitution = Substitution {
+            from: pairs[0].0.clone(),
+            to: pairs[0].1.clone(),
+            count: RULE_PROMOTION_COUNT,
+        };
+        let rules = ReplacementRules::from_substitutions([&substitution]);
+        assert_eq!(
+            rules.apply("Run Cube  control apply."),
+            "Run kubectl apply."
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+
+static DECIMAL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(\d+)\.(\d+)\b").unwrap());
+static QUOTED_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#""([^"]+)""#).unwrap());
+static ISO_DATE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(\d{4})-(\d{2})-(\d{2})\b").unwrap());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// “…”
+    English,
+    /// „…“
+    German,
+    /// « … »
+    French,
+    /// 「…」
+    Japanese,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    /// 2024-03-05 stays as-is.
+    Iso,
+    /// 03/05/2024
+    MonthDayYear,
+    /// 05/03/2024
+    DayMonthYearSlash,
+    /// 05.03.2024
+    DayMonthYearDot,
+}
+
+/// Text conventions applied after cleanup, independent of the ASR language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormattingLocale {
+    pub decimal_separator: char,
+    pub quote_style: QuoteStyle,
+    pub date_order: DateOrder,
+}
+
+impl Default for FormattingLocale {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            quote_style: QuoteStyle::English,
+            date_order: DateOrder::Iso,
+        }
+    }
+}
+
+impl FormattingLocale {
+    /// Parses a BCP-47-ish tag such as `de-DE` or `en_GB`. `auto` follows `LC_ALL`/`LANG`;
+    /// `off` (or empty) disables locale formatting.
+    pub fn from_setting(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.is_empty() || value.eq_ignore_ascii_case("off") {
+            return None;
+        }
+        if value.eq_ignore_ascii_case("auto") {
+            let system = ["LC_ALL", "LC_NUMERIC", "LANG"]
+                .iter()
+                .filter_map(|key| std::env::var(key).ok())
+                .find(|value| !value.is_empty() && value != "C" && value != "POSIX");
+            return system.map(|tag| Self::from_tag(&tag));
+        }
+        Some(Self::from_tag(value))
+    }
+
+    fn from_tag(tag: &str) -> Self {
+        // Drop encoding/modifier suffixes (e.g. `de_DE.UTF-8@euro`).
+        let tag = tag.split(['.', '@']).next().unwrap_or_default();
+        let mut parts = tag.split(['-', '_']);
+        let language = parts.next().unwrap_or_default().to_ascii_lowercase();
+        let region = parts.next().unwrap_or_default().to_ascii_uppercase();
+
+        match language.as_str() {
+            "en" => Self {
+                decimal_separator: '.',
+                quote_style: QuoteStyle::English,
+                date_order: if region == "US" || region.is_empty() {
+                    DateOrder::MonthDayYear
+                } else {
+                    DateOrder::DayMonthYearSlash
+                },
+            },
+            "de" | "cs" | "pl" | "da" | "nb" | "fi" | "ru" | "uk" => Self {
+                decimal_separator: ',',
+                quote_style: QuoteStyle::German,
+                date_order: DateOrder::DayMonthYearDot,
+            },
+            "fr" => Self {
+                decimal_separator: ',',
+                quote_style: QuoteStyle::French,
+                date_order: DateOrder::DayMonthYearSlash,
+            },
+            "es" | "it" | "pt" | "nl" => Self {
+                decimal_separator: ',',
+                quote_style: QuoteStyle::English,
+                date_order: DateOrder::DayMonthYearSlash,
+            },
+            "ja" | "zh" => Self {
+                decimal_separator: '.',
+                quote_style: QuoteStyle::Japanese,
+                date_order: DateOrder::Iso,
+            },
+            _ => Self::default(),
+        }
+    }
+
+    pub fn apply(&self, text: &str) -> String {
+        let mut output = text.to_string();
+
+        // Decimals first so reformatted dates like 05.03.2024 are left alone.
+        if self.decimal_separator != '.' {
+            output = DECIMAL_RE
+                .replace_all(&output, |caps: &Captures| {
+                    let whole = caps.get(0).expect("match");
+                    if in_dotted_sequence(&output[..whole.start()], &output[whole.end()..]) {
+                        return whole.as_str().to_string();
+                    }
+                    format!("{}{}{}", &caps[1], self.decimal_separator, &caps[2])
+                })
+                .into_owned();
+        }
+
+        if self.date_order != DateOrder::Iso {
+            output = ISO_DATE_RE
+                .replace_all(&output, |caps: &Captures| {
+                    let (year, month, day) = (&caps[1], &caps[2], &caps[3]);
+                    match self.date_order {
+                        DateOrder::Iso => caps[0].to_string(),
+                        DateOrder::MonthDayYear => format!("{month}/{day}/{year}"),
+                        DateOrder::DayMonthYearSlash => format!("{day}/{month}/{year}"),
+                        DateOrder::DayMonthYearDot => format!("{day}.{month}.{year}"),
+                    }
+                })
+                .into_owned();
+        }
+
+        let (open, close) = match self.quote_style {
+            QuoteStyle::English => ("\u{201C}", "\u{201D}"),
+            QuoteStyle::German => ("\u{201E}", "\u{201C}"),
+            QuoteStyle::French => ("\u{00AB}\u{00A0}", "\u{00A0}\u{00BB}"),
+            QuoteStyle::Japanese => ("\u{300C}", "\u{300D}"),
+        };
+        QUOTED_RE
+            .replace_all(&output, |caps: &Captures| {
+                format!("{open}{}{close}", &caps[1])
+            })
+            .into_owned()
+    }
+}
+
+/// A number followed or preceded by another `.<digit>` group is a version or an IP
+/// address, not a decimal.
+fn in_dotted_sequence(before: &str, after: &str) -> bool {
+    let mut after = after.chars();
+    let dotted_after =
+        after.next() == Some('.') && after.next().is_some_and(|c| c.is_ascii_digit());
+    let mut before = before.chars().rev();
+    let dotted_before =
+        before.next() == Some('.') && before.next().is_some_and(|c| c.is_ascii_digit());
+    dotted_after || dotted_before
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn german_locale_rewrites_decimals_quotes_and_dates() {
+        let locale = FormattingLocale::from_setting("de-DE").unwrap();
+        assert_eq!(
+            locale.apply(r#"It costs 3.5 euros, "cheap" as of 2024-03-05."#),
+            "It costs 3,5 euros, \u{201E}cheap\u{201C} as of 05.03.2024."
+        );
+    }
+
+    #[test]
+    fn leaves_versions_and_ip_addresses_alone() {
+        let locale = FormattingLocale::from_setting("de-DE").unwrap();
+        assert_eq!(locale.apply("Update to 1.2.3 now"), "Update to 1.2.3 now");
+        assert_eq!(locale.apply("Ping 192.168.0.1."), "Ping 192.168.0.1.");
+        assert_eq!(locale.apply("Version 2.5. Done"), "Version 2,5. Done");
+    }
+
+    #[test]
+    fn us_locale_keeps_decimals() {
+        let locale = FormattingLocale::from_setting("en_US.UTF-8").unwrap();
+        assert_eq!(locale.decimal_separator, '.');
+        assert_eq!(locale.apply("2024-03-05 at 1.5x"), "03/05/2024 at 1.5x");
+    }
+
+    #[test]
+    fn off_disables_formatting() {
+        assert!(FormattingLocale::from_setting("off").is_none());
+        assert!(FormattingLocale::from_setting("").is_none());
+    }
+}
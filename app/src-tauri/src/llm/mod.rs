@@ -1,4 +1,16 @@
 mod autoclean;
+mod corrections;
+mod formatting;
+mod polish;
+mod redaction;
 
 #[allow(unused_imports)]
-pub use autoclean::{AutocleanMode, AutocleanService, TierOneRuleSet};
+pub use autoclean::{AutocleanMode, AutocleanService, PauseThresholds, TierOneRuleSet};
+pub use corrections::{CorrectionStore, CorrectionSummary, ReplacementRules};
+pub use formatting::FormattingLocale;
+#[allow(unused_imports)]
+pub use polish::{
+    is_local_endpoint, PolishApi, PolishConfig, DEFAULT_POLISH_ENDPOINT, DEFAULT_POLISH_MODEL,
+    DEFAULT_POLISH_PROMPT,
+};
+pub use redaction::{RedactionRules, BUILTIN_REDACTIONS};
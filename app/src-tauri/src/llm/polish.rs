@@ -0,0 +1,202 @@
+//! Polish mode: runs a cleaned transcript through a local LLM server (Ollama, or
+//! llama.cpp's OpenAI-compatible server) to fix grammar, punctuation and fillers.
+//! Servers on other machines are only contacted once the user has allowed it.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::core::secrets;
+
+pub const DEFAULT_POLISH_ENDPOINT: &str = "http://127.0.0.1:11434";
+pub const DEFAULT_POLISH_MODEL: &str = "llama3.2:3b";
+/// `{text}` is replaced with the transcript.
+pub const DEFAULT_POLISH_PROMPT: &str = "Correct the grammar and punctuation of the \
+dictated text below and remove filler words such as \"um\" and \"uh\". Keep the wording, \
+meaning and language otherwise unchanged. Reply with the corrected text only.\n\n{text}";
+
+/// Polished text longer than this many times the input is treated as a runaway reply.
+const MAX_GROWTH: usize = 2;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PolishApi {
+    /// `POST /api/generate`.
+    Ollama,
+    /// `POST /v1/chat/completions`, as served by llama.cpp's `llama-server`.
+    OpenAi,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolishConfig {
+    pub endpoint: String,
+    pub api: PolishApi,
+    pub model: String,
+    /// Template with a `{text}` placeholder; empty uses `DEFAULT_POLISH_PROMPT`.
+    pub prompt: String,
+    pub timeout: Duration,
+    /// Send transcripts to a non-loopback endpoint; the user consented in settings.
+    pub allow_remote: bool,
+}
+
+impl Default for PolishConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: DEFAULT_POLISH_ENDPOINT.into(),
+            api: PolishApi::Ollama,
+            model: DEFAULT_POLISH_MODEL.into(),
+            prompt: String::new(),
+            timeout: Duration::from_secs(4),
+            allow_remote: false,
+        }
+    }
+}
+
+impl PolishConfig {
+    fn render_prompt(&self, text: &str) -> String {
+        let template = if self.prompt.trim().is_empty() {
+            DEFAULT_POLISH_PROMPT
+        } else {
+            self.prompt.as_str()
+        };
+        if template.contains("{text}") {
+            template.replace("{text}", text)
+        } else {
+            format!("{template}\n\n{text}")
+        }
+    }
+}
+
+/// Sends `text` to the configured server and returns its correction. Fails on
+/// timeouts, HTTP errors, and replies that are empty or far longer than the input.
+/// A remote endpoint the user hasn't allowed is refused before anything is sent.
+pub fn polish(config: &PolishConfig, text: &str) -> Result<String> {
+    let remote = !is_local_endpoint(&config.endpoint);
+    if remote && !config.allow_remote {
+        bail!("polish endpoint is not on this machine and remote polish is not allowed");
+    }
+    let client = Client::builder()
+        .timeout(config.timeout)
+        .build()
+        .context("create http client")?;
+    let base = config.endpoint.trim().trim_end_matches('/');
+    let prompt = config.render_prompt(text);
+
+    let (url, body) = match config.api {
+        PolishApi::Ollama => (
+            format!("{base}/api/generate"),
+            json!({
+                "model": config.model,
+                "prompt": prompt,
+                "stream": false,
+                "options": { "temperature": 0 },
+            }),
+        ),
+        PolishApi::OpenAi => (
+            format!("{base}/v1/chat/completions"),
+            json!({
+                "model": config.model,
+                "messages": [{ "role": "user", "content": prompt }],
+                "temperature": 0,
+                "stream": false,
+            }),
+        ),
+    };
+
+    let mut request = client.post(&url).json(&body);
+    // The key is for the remote server the user approved; local servers never see it.
+    if remote {
+        if let Some(key) = secrets::lookup(secrets::SECRET_REMOTE_LLM_API_KEY) {
+            request = request.bearer_auth(key);
+        }
+    }
+    let response = request
+        .send()
+        .with_context(|| format!("polish request to {url}"))?
+        .error_for_status()
+        .with_context(|| format!("polish request to {url}"))?;
+    let reply: serde_json::Value = response.json().context("parse polish response")?;
+
+    let content = match config.api {
+        PolishApi::Ollama => reply.get("response").and_then(|value| value.as_str()),
+        PolishApi::OpenAi => reply
+            .pointer("/choices/0/message/content")
+            .and_then(|value| value.as_str()),
+    }
+    .ok_or_else(|| anyhow!("polish response has no text"))?;
+
+    let polished = strip_wrapping_quotes(content.trim());
+    if polished.is_empty() {
+        bail!("polish returned empty text");
+    }
+    if polished.chars().count() > text.chars().count() * MAX_GROWTH + 40 {
+        bail!("polish reply is much longer than the transcript");
+    }
+    Ok(polished.to_string())
+}
+
+/// Whether `endpoint` points at this machine. Strict privacy only allows those, and
+/// others need `PolishConfig::allow_remote`.
+pub fn is_local_endpoint(endpoint: &str) -> bool {
+    let Ok(url) = reqwest::Url::parse(endpoint.trim()) else {
+        return false;
+    };
+    let Some(host) = url.host_str() else {
+        return false;
+    };
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    match host.parse::<std::net::IpAddr>() {
+        Ok(ip) => ip.is_loopback(),
+        Err(_) => host.eq_ignore_ascii_case("localhost"),
+    }
+}
+
+/// Models sometimes quote their whole answer.
+fn strip_wrapping_quotes(text: &str) -> &str {
+    for (open, close) in [('"', '"'), ('“', '”')] {
+        if let Some(inner) = text
+            .strip_prefix(open)
+            .and_then(|rest| rest.strip_suffix(close))
+        {
+            if !inner.contains(open) && !inner.contains(close) {
+                return inner.trim();
+            }
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prompt_and_endpoint_helpers() {
+        let config = PolishConfig {
+            prompt: "Fix: {text}".into(),
+            ..PolishConfig::default()
+        };
+        assert_eq!(config.render_prompt("um hello"), "Fix: um hello");
+        assert!(PolishConfig::default()
+            .render_prompt("hi")
+            .ends_with("\n\nhi"));
+
+        assert!(is_local_endpoint("http://127.0.0.1:11434"));
+        assert!(is_local_endpoint("http://localhost:8080/"));
+        assert!(is_local_endpoint("http://[::1]:8080"));
+        assert!(!is_local_endpoint("https://api.example.com"));
+
+        let remote = PolishConfig {
+            endpoint: "https://api.example.com".into(),
+            ..PolishConfig::default()
+        };
+        let error = polish(&remote, "hello").unwrap_err();
+        assert!(error.to_string().contains("not allowed"));
+
+        assert_eq!(strip_wrapping_quotes("\"Hello there.\""), "Hello there.");
+        assert_eq!(strip_wrapping_quotes("\"a\" and \"b\""), "\"a\" and \"b\"");
+    }
+}
@@ -0,0 +1,111 @@
+//! Masking of sensitive spans (card numbers, emails, user patterns) in transcripts.
+//!
+//! Redacted text is what gets logged or stored; it is pasted only when the user asks
+//! for redaction before paste as well.
+
+use regex::{Captures, Regex};
+
+pub const REDACTION_MASK: &str = "████";
+
+/// Built-in rule ids accepted in `redaction_rules`.
+pub const BUILTIN_REDACTIONS: &[&str] = &["credit-card", "email"];
+
+/// Compiled redaction patterns, applied in order.
+#[derive(Debug, Clone, Default)]
+pub struct RedactionRules {
+    rules: Vec<(Regex, bool)>,
+}
+
+impl RedactionRules {
+    /// Compiles the enabled built-ins plus `custom` regexes. Invalid patterns are
+    /// skipped and returned with their error so the caller can report them.
+    pub fn compile(builtins: &[String], custom: &[String]) -> (Self, Vec<String>) {
+        let mut rules = Vec::new();
+        let mut invalid = Vec::new();
+        for id in builtins {
+            match id.as_str() {
+                // 13-19 digits, optionally grouped by spaces or dashes; Luhn-checked on match.
+                "credit-card" => {
+                    rules.push((Regex::new(r"\b(?:\d[ -]?){12,18}\d\b").unwrap(), true))
+                }
+                "email" => rules.push((
+                    Regex::new(r"(?i)\b[a-z0-9._%+-]+@[a-z0-9.-]+\.[a-z]{2,}\b").unwrap(),
+                    false,
+                )),
+                _ => {}
+            }
+        }
+        for pattern in custom {
+            match Regex::new(pattern) {
+                Ok(re) => rules.push((re, false)),
+                Err(error) => invalid.push(format!("{pattern}: {error}")),
+            }
+        }
+        (Self { rules }, invalid)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    pub fn apply(&self, text: &str) -> String {
+        let mut output = text.to_string();
+        for (re, luhn) in &self.rules {
+            output = re
+                .replace_all(&output, |captures: &Captures| {
+                    let matched = &captures[0];
+                    if *luhn && !luhn_valid(matched) {
+                        matched.to_string()
+                    } else {
+                        REDACTION_MASK.to_string()
+                    }
+                })
+                .into_owned();
+        }
+        output
+    }
+}
+
+fn luhn_valid(candidate: &str) -> bool {
+    let digits: Vec<u32> = candidate.chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.len() < 13 {
+        return false;
+    }
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, &digit)| {
+            if index % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                digit
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_cards_emails_and_custom_patterns() {
+        let (rules, invalid) = RedactionRules::compile(
+            &["credit-card".into(), "email".into()],
+            &[r"(?i)project \w+".into(), "(".into()],
+        );
+        assert_eq!(invalid.len(), 1);
+        let text = "Card 4111 1111 1111 1111, order 1234 5678 9012 3456, mail jo@example.com about project falcon";
+        assert_eq!(
+            rules.apply(text),
+            "Card ████, order 1234 5678 9012 3456, mail ████ about ████"
+        );
+    }
+}
@@ -6,13 +6,20 @@ mod models;
 mod output;
 mod vad;
 
-use anyhow::anyhow;
+use anyhow::{anyhow, Context};
+use asr::WeightLoadReport;
 use audio::{list_input_devices, AudioDeviceInfo};
-use core::{app_state::AppState, pipeline::OutputMode, settings::FrontendSettings};
+use core::{
+    app_state::AppState, pipeline::OutputMode, settings::FrontendSettings,
+    settings_journal::ChangeSource, settings_validation::SettingsUpdateError,
+    simulation::SimulatedEvent,
+};
+use llm::CorrectionSummary;
 use models::ModelAsset;
 use tauri::{image::Image, include_image, WebviewWindowBuilder};
 use tauri::{AppHandle, Manager};
 use tracing::metadata::LevelFilter;
+use vad::SpeakerProfileSummary;
 
 const APP_ICON: Image<'_> = include_image!("./icons/32x32.png");
 
@@ -26,20 +33,21 @@ async fn update_settings(
     app: AppHandle,
     state: tauri::State<'_, AppState>,
     settings: FrontendSettings,
-) -> tauri::Result<()> {
+) -> Result<(), SettingsUpdateError> {
+    let errors = state.validate_settings(&settings);
+    if !errors.is_empty() {
+        let fields: Vec<&str> = errors.iter().map(|error| error.field.as_str()).collect();
+        tracing::warn!("settings_rejected fields={}", fields.join(","));
+        return Err(SettingsUpdateError::Invalid { errors });
+    }
+
     state
         .settings_manager()
-        .write_frontend(settings)
-        .map_err(tauri::Error::from)?;
+        .write_frontend(settings, ChangeSource::Ui)?;
 
-    let fresh = state
-        .settings_manager()
-        .read_frontend()
-        .map_err(tauri::Error::from)?;
+    let fresh = state.settings_manager().read_frontend()?;
 
-    state
-        .configure_pipeline(Some(&app), &fresh)
-        .map_err(tauri::Error::from)?;
+    state.configure_pipeline(Some(&app), &fresh)?;
 
     state.sync_hud_overlay_mode(&app);
 
@@ -52,9 +60,106 @@ async fn update_settings(
     Ok(())
 }
 
+/// Stores `value` in the system keyring under secret `id` (empty clears it) and returns
+/// settings with the updated `storedSecrets`.
+#[tauri::command]
+async fn set_secret(app: AppHandle, id: String, value: String) -> tauri::Result<FrontendSettings> {
+    tokio::task::spawn_blocking(move || {
+        core::secrets::set(&id, &value)?;
+        let manager = app.state::<AppState>().settings_manager();
+        manager.write_stored_secret(&id, !value.trim().is_empty())?;
+        manager.read_frontend()
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+async fn clear_secret(app: AppHandle, id: String) -> tauri::Result<FrontendSettings> {
+    tokio::task::spawn_blocking(move || {
+        core::secrets::clear(&id)?;
+        let manager = app.state::<AppState>().settings_manager();
+        manager.write_stored_secret(&id, false)?;
+        manager.read_frontend()
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
 #[tauri::command]
-async fn hud_ready(app: AppHandle, state: tauri::State<'_, AppState>) -> tauri::Result<()> {
+async fn detect_gpus() -> tauri::Result<core::gpu::GpuInventory> {
+    tokio::task::spawn_blocking(|| core::gpu::inventory().clone())
+        .await
+        .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))
+}
+
+#[tauri::command]
+async fn secret_store_available() -> tauri::Result<bool> {
+    Ok(core::secrets::available())
+}
+
+#[tauri::command]
+async fn debug_capture_entries() -> tauri::Result<Vec<core::debug_capture::CaptureEntry>> {
+    tokio::task::spawn_blocking(core::debug_capture::entries)
+        .await
+        .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+        .map_err(tauri::Error::from)
+}
+
+/// Deletes every captured debug session now, without waiting for the TTL.
+#[tauri::command]
+async fn purge_debug_data() -> tauri::Result<usize> {
+    tokio::task::spawn_blocking(core::debug_capture::purge_all)
+        .await
+        .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+        .map_err(tauri::Error::from)
+}
+
+/// Clears the given caches and runtime state, re-applying settings if they were reset.
+#[tauri::command]
+async fn reset_app_state(
+    app: AppHandle,
+    scope: Vec<core::recovery::ResetScope>,
+) -> tauri::Result<core::recovery::ResetReport> {
+    let handle = app.clone();
+    let report =
+        tokio::task::spawn_blocking(move || core::recovery::reset_app_state(&handle, &scope))
+            .await
+            .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?;
+
+    if report
+        .cleared
+        .contains(&core::recovery::ResetScope::Settings)
+    {
+        let state = app.state::<AppState>();
+        let fresh = state
+            .settings_manager()
+            .read_frontend()
+            .map_err(tauri::Error::from)?;
+        state
+            .configure_pipeline(Some(&app), &fresh)
+            .map_err(tauri::Error::from)?;
+        state.sync_hud_overlay_mode(&app);
+        core::hotkeys::reregister(&app).await?;
+    }
+    Ok(report)
+}
+
+#[tauri::command]
+async fn hud_ready(
+    app: AppHandle,
+    window: tauri::Window,
+    state: tauri::State<'_, AppState>,
+) -> tauri::Result<()> {
     state.replay_hud_state(&app);
+    // Startup recovery happened before any window could listen; tell the main one now.
+    if window.label() == "main" {
+        if let Some(recovery) = state.settings_manager().take_recovery() {
+            core::events::emit_settings_recovered(&app, recovery);
+        }
+    }
     Ok(())
 }
 
@@ -76,14 +181,55 @@ async fn linux_permissions_status() -> tauri::Result<core::linux_setup::LinuxPer
 }
 
 #[tauri::command]
-async fn linux_enable_permissions() -> tauri::Result<()> {
+async fn run_preflight(app: AppHandle) -> tauri::Result<core::preflight::PreflightReport> {
+    tokio::task::spawn_blocking(move || core::preflight::run_preflight(&app))
+        .await
+        .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))
+}
+
+#[tauri::command]
+async fn linux_enable_permissions(app: AppHandle) -> tauri::Result<()> {
     tokio::task::spawn_blocking(|| crate::core::linux_setup::enable_permissions_for_current_user())
         .await
         .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
         .map_err(tauri::Error::from)?;
+    watch_input_access(app);
     Ok(())
 }
 
+#[tauri::command]
+async fn generate_setup_script() -> tauri::Result<String> {
+    tokio::task::spawn_blocking(core::linux_setup::generate_setup_script)
+        .await
+        .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+        .map_err(tauri::Error::from)
+}
+
+/// The main window may start hidden in the tray, so a recovered config is also announced
+/// on the desktop.
+fn notify_settings_recovered(recovery: &core::settings::SettingsRecovery) {
+    let summary = if recovery.restored_from.is_some() {
+        core::i18n::message("notify-settings-recovered", &[])
+    } else {
+        core::i18n::message("notify-settings-reset", &[])
+    };
+    let path = recovery.corrupt_path.display().to_string();
+    let body = core::i18n::message("notify-settings-recovered-body", &[("path", &path)]);
+    output::notify::show(&summary, &body);
+}
+
+/// Starts evdev hotkeys as soon as /dev/input access works, without a relogin.
+fn watch_input_access(app: AppHandle) {
+    core::linux_setup::watch_for_input_access(move || {
+        tauri::async_runtime::spawn(async move {
+            if let Err(error) = core::hotkeys::register(&app).await {
+                tracing::warn!("hotkey registration after permission change failed: {error}");
+            }
+            core::events::emit_linux_permissions_changed(&app);
+        });
+    });
+}
+
 #[tauri::command]
 async fn gnome_hud_extension_status() -> tauri::Result<core::linux_setup::GnomeHudExtensionStatus> {
     Ok(core::linux_setup::gnome_hud_extension_status())
@@ -107,6 +253,20 @@ async fn check_for_updates(force: Option<bool>) -> tauri::Result<core::updater::
         .map_err(tauri::Error::from)
 }
 
+#[tauri::command]
+async fn get_release_notes(version: Option<String>) -> tauri::Result<core::updater::ReleaseNotes> {
+    tokio::task::spawn_blocking(move || crate::core::updater::get_release_notes(version.as_deref()))
+        .await
+        .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+        .map_err(tauri::Error::from)
+}
+
+/// The version just updated to, once per update.
+#[tauri::command]
+fn take_updated_version() -> tauri::Result<Option<String>> {
+    crate::core::updater::take_updated_version().map_err(tauri::Error::from)
+}
+
 #[tauri::command]
 async fn download_update(
     app: AppHandle,
@@ -126,7 +286,7 @@ async fn download_update(
 #[tauri::command]
 async fn apply_update(app: AppHandle, tarball_path: String) -> tauri::Result<()> {
     tokio::task::spawn_blocking(move || {
-        crate::core::updater::apply_update_with_pkexec_with_progress(&tarball_path, |progress| {
+        crate::core::updater::apply_update_with_progress(&tarball_path, |progress| {
             crate::core::events::emit_update_apply_progress(&app, progress);
         })
     })
@@ -143,42 +303,11 @@ async fn quit_app(app: AppHandle) -> tauri::Result<()> {
 
 #[tauri::command]
 async fn restart_app(app: AppHandle) -> tauri::Result<()> {
-    let candidates = [
-        "/opt/openflow/openflow",
-        "/usr/local/bin/openflow",
-        "openflow",
-    ];
-
-    let mut errors: Vec<String> = Vec::new();
-    for candidate in candidates {
-        if candidate.contains('/') && !std::path::Path::new(candidate).is_file() {
-            continue;
-        }
-
-        match std::process::Command::new(candidate)
-            .stdin(std::process::Stdio::null())
-            .stdout(std::process::Stdio::null())
-            .stderr(std::process::Stdio::null())
-            .spawn()
-        {
-            Ok(_) => {
-                app.exit(0);
-                return Ok(());
-            }
-            Err(err) => {
-                errors.push(format!("{candidate}: {err}"));
-            }
-        }
-    }
-
-    Err(tauri::Error::from(anyhow!(
-        "Failed to restart app. {}",
-        if errors.is_empty() {
-            "No restart candidates found.".to_string()
-        } else {
-            errors.join("; ")
-        }
-    )))
+    core::restart::spawn_replacement()
+        .context("Failed to restart app")
+        .map_err(tauri::Error::from)?;
+    app.exit(0);
+    Ok(())
 }
 
 #[tauri::command]
@@ -212,6 +341,12 @@ async fn complete_dictation(
     Ok(())
 }
 
+#[tauri::command]
+async fn cancel_dictation(app: AppHandle, state: tauri::State<'_, AppState>) -> tauri::Result<()> {
+    state.cancel_session(&app);
+    Ok(())
+}
+
 #[tauri::command]
 async fn list_models(state: tauri::State<'_, AppState>) -> tauri::Result<Vec<ModelAsset>> {
     let manager_arc = state.model_manager();
@@ -221,14 +356,63 @@ async fn list_models(state: tauri::State<'_, AppState>) -> tauri::Result<Vec<Mod
     Ok(manager.assets().into_iter().cloned().collect())
 }
 
+#[tauri::command]
+async fn query_models(
+    state: tauri::State<'_, AppState>,
+    filter: Option<models::ModelFilter>,
+) -> tauri::Result<Vec<ModelAsset>> {
+    let manager_arc = state.model_manager();
+    let manager = manager_arc
+        .lock()
+        .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?;
+    Ok(manager.query(&filter.unwrap_or_default()))
+}
+
 #[tauri::command]
 async fn install_model_asset(
     app: AppHandle,
     state: tauri::State<'_, AppState>,
     name: String,
+    priority: Option<models::DownloadPriority>,
+) -> tauri::Result<()> {
+    state
+        .queue_model_download(&app, &name, priority.unwrap_or_default())
+        .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+async fn model_download_queue(
+    state: tauri::State<'_, AppState>,
+) -> tauri::Result<Vec<models::QueuedDownload>> {
+    Ok(state.download_queue())
+}
+
+#[tauri::command]
+async fn reorder_download_queue(
+    state: tauri::State<'_, AppState>,
+    order: Vec<models::QueuedDownloadKey>,
+) -> tauri::Result<()> {
+    state.reorder_download_queue(&order);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_download_priority(
+    state: tauri::State<'_, AppState>,
+    name: String,
+    priority: models::DownloadPriority,
+) -> tauri::Result<()> {
+    state.set_download_priority(&name, priority);
+    Ok(())
+}
+
+#[tauri::command]
+async fn cancel_model_download(
+    state: tauri::State<'_, AppState>,
+    name: String,
 ) -> tauri::Result<()> {
     state
-        .queue_model_download(&app, &name)
+        .cancel_model_download(&name)
         .map_err(tauri::Error::from)
 }
 
@@ -243,6 +427,50 @@ async fn uninstall_model_asset(
         .map_err(tauri::Error::from)
 }
 
+#[tauri::command]
+async fn update_model_asset(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    name: String,
+) -> tauri::Result<()> {
+    state
+        .update_model_asset(&app, &name)
+        .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+async fn rollback_model_asset(app: AppHandle, name: String) -> tauri::Result<()> {
+    tokio::task::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        state.rollback_model_asset(&app, &name)
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+async fn link_model_asset(app: AppHandle, name: String, path: String) -> tauri::Result<()> {
+    tokio::task::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        state.link_model_asset(&app, &name, std::path::Path::new(&path))
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+async fn check_model_updates(app: AppHandle) -> tauri::Result<Vec<ModelAsset>> {
+    tokio::task::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        state.check_model_updates(&app)
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
 #[tauri::command]
 async fn list_audio_devices() -> tauri::Result<Vec<AudioDeviceInfo>> {
     Ok(list_input_devices())
@@ -263,6 +491,230 @@ async fn set_output_mode(state: tauri::State<'_, AppState>, mode: OutputMode) ->
     Ok(())
 }
 
+#[tauri::command]
+fn open_settings_page(app: AppHandle, route: String) {
+    core::app_state::open_settings_page(&app, &route);
+}
+
+#[tauri::command]
+fn set_diagnostics_subscribed(subscribed: bool) {
+    crate::core::events::set_diagnostics_subscribed(subscribed);
+}
+
+#[tauri::command]
+async fn confirm_pending_transcript(app: AppHandle, text: Option<String>) -> tauri::Result<bool> {
+    tokio::task::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        state.confirm_pending_transcript(&app, text)
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
+/// Puts the last delivered transcript back on the clipboard (overlay toast click).
+#[tauri::command]
+async fn recopy_last_transcript(app: AppHandle) -> tauri::Result<()> {
+    tokio::task::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        state.recopy_last_transcript(&app);
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))
+}
+
+#[tauri::command]
+async fn simulate_dictation(app: AppHandle, input: String) -> tauri::Result<Vec<SimulatedEvent>> {
+    tokio::task::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        state.simulate_dictation(&app, &input)
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+async fn replay_last_audio(app: AppHandle) -> tauri::Result<core::failed_audio::ReplayedAudio> {
+    tokio::task::spawn_blocking(move || app.state::<AppState>().replay_last_audio())
+        .await
+        .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+        .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+async fn apply_model_downshift(app: AppHandle) -> tauri::Result<()> {
+    tokio::task::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        state.apply_model_downshift(&app)
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+async fn revert_model_downshift(app: AppHandle) -> tauri::Result<bool> {
+    tokio::task::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        state.revert_model_downshift(&app)
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+async fn retry_reverted_asr_selection(app: AppHandle) -> tauri::Result<bool> {
+    tokio::task::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        state.retry_reverted_asr_selection(&app)
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+async fn discard_pending_transcript(state: tauri::State<'_, AppState>) -> tauri::Result<bool> {
+    Ok(state.discard_pending_transcript())
+}
+
+#[tauri::command]
+async fn submit_correction(
+    app: AppHandle,
+    original: String,
+    corrected: String,
+) -> tauri::Result<bool> {
+    tokio::task::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        state.submit_correction(&original, &corrected)
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+async fn enroll_speaker(
+    app: AppHandle,
+    seconds: Option<u32>,
+) -> tauri::Result<SpeakerProfileSummary> {
+    tokio::task::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        state.enroll_speaker(seconds.unwrap_or(10))
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+fn asr_load_report(state: tauri::State<'_, AppState>) -> Option<WeightLoadReport> {
+    state.asr_load_report()
+}
+
+#[tauri::command]
+fn speaker_profile(state: tauri::State<'_, AppState>) -> Option<SpeakerProfileSummary> {
+    state.speaker_profile()
+}
+
+#[tauri::command]
+fn clear_speaker_profile(state: tauri::State<'_, AppState>) -> tauri::Result<()> {
+    state.clear_speaker_profile().map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+fn list_corrections(state: tauri::State<'_, AppState>) -> CorrectionSummary {
+    state.correction_summary()
+}
+
+#[tauri::command]
+fn forget_correction(
+    state: tauri::State<'_, AppState>,
+    from: String,
+    to: String,
+) -> tauri::Result<()> {
+    state
+        .forget_correction(&from, &to)
+        .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+fn clear_corrections(state: tauri::State<'_, AppState>) -> tauri::Result<()> {
+    state.clear_corrections().map_err(tauri::Error::from)
+}
+
+/// Page size when the frontend doesn't pass a limit.
+const HISTORY_PAGE: usize = 100;
+
+/// Newest first.
+#[tauri::command]
+fn list_history(
+    state: tauri::State<'_, AppState>,
+    offset: Option<usize>,
+    limit: Option<usize>,
+) -> Vec<core::history::HistoryEntry> {
+    state.list_history(offset.unwrap_or(0), limit.unwrap_or(HISTORY_PAGE))
+}
+
+#[tauri::command]
+fn search_history(
+    state: tauri::State<'_, AppState>,
+    query: String,
+    limit: Option<usize>,
+) -> Vec<core::history::HistoryEntry> {
+    state.search_history(&query, limit.unwrap_or(HISTORY_PAGE))
+}
+
+#[tauri::command]
+async fn repaste_history_entry(app: AppHandle, id: u64) -> tauri::Result<()> {
+    tokio::task::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        state.repaste_history_entry(&app, id)
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
+/// Writes history entries to `path` as Markdown or JSON lines.
+#[tauri::command]
+async fn export_history(
+    app: AppHandle,
+    format: core::history::ExportFormat,
+    range: Option<core::history::ExportRange>,
+    path: String,
+) -> tauri::Result<core::history::ExportSummary> {
+    tokio::task::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        state.export_history(
+            format,
+            range.unwrap_or_default(),
+            std::path::Path::new(&path),
+        )
+    })
+    .await
+    .map_err(|err| tauri::Error::from(anyhow!(err.to_string())))?
+    .map_err(tauri::Error::from)
+}
+
+#[tauri::command]
+fn clear_history(state: tauri::State<'_, AppState>) -> tauri::Result<()> {
+    state.clear_history().map_err(tauri::Error::from)
+}
+
+/// Journaled settings changes, newest first.
+#[tauri::command]
+fn get_settings_history(
+    state: tauri::State<'_, AppState>,
+    limit: Option<usize>,
+) -> Vec<core::settings_journal::SettingsChange> {
+    state
+        .settings_manager()
+        .history(limit.unwrap_or(HISTORY_PAGE))
+}
+
 #[cfg(debug_assertions)]
 #[tauri::command]
 async fn get_logs() -> Vec<String> {
@@ -287,20 +739,32 @@ fn setup_logging() {
 
 fn main() {
     setup_logging();
+    core::restart::wait_for_previous_instance();
 
     tauri::Builder::default()
         .manage(AppState::new())
         .invoke_handler(tauri::generate_handler![
             get_settings,
             update_settings,
+            set_secret,
+            clear_secret,
+            secret_store_available,
+            detect_gpus,
+            debug_capture_entries,
+            purge_debug_data,
+            reset_app_state,
             hud_ready,
             register_hotkeys,
             unregister_hotkeys,
             linux_permissions_status,
             linux_enable_permissions,
+            generate_setup_script,
+            run_preflight,
             gnome_hud_extension_status,
             gnome_hud_extension_install,
             check_for_updates,
+            get_release_notes,
+            take_updated_version,
             download_update,
             apply_update,
             quit_app,
@@ -308,11 +772,45 @@ fn main() {
             begin_dictation,
             mark_dictation_processing,
             complete_dictation,
+            cancel_dictation,
             secure_field_blocked,
             set_output_mode,
+            set_diagnostics_subscribed,
+            open_settings_page,
+            apply_model_downshift,
+            revert_model_downshift,
+            retry_reverted_asr_selection,
+            confirm_pending_transcript,
+            recopy_last_transcript,
+            simulate_dictation,
+            replay_last_audio,
+            discard_pending_transcript,
+            submit_correction,
+            list_corrections,
+            forget_correction,
+            clear_corrections,
+            list_history,
+            search_history,
+            repaste_history_entry,
+            clear_history,
+            export_history,
+            get_settings_history,
+            enroll_speaker,
+            speaker_profile,
+            clear_speaker_profile,
+            asr_load_report,
             list_models,
+            query_models,
             install_model_asset,
+            model_download_queue,
+            reorder_download_queue,
+            set_download_priority,
+            cancel_model_download,
             uninstall_model_asset,
+            update_model_asset,
+            rollback_model_asset,
+            link_model_asset,
+            check_model_updates,
             list_audio_devices,
             #[cfg(debug_assertions)]
             get_logs
@@ -341,14 +839,23 @@ fn main() {
             output::tray::initialize(app)?;
             if let Some(state) = app.try_state::<AppState>() {
                 let handle = app.handle();
+                if let Some(recovery) = state.settings_manager().recovery() {
+                    notify_settings_recovered(&recovery);
+                }
                 state.initialize_models(&handle)?;
                 if let Err(error) = state.initialize_pipeline(&handle) {
                     tracing::warn!("Failed to initialize pipeline: {error:?}");
                 }
                 state.sync_hud_overlay_mode(&handle);
+                core::hud_control::spawn(handle.clone());
+                models::spawn_update_checker(handle.clone());
+                core::updater::spawn_update_scheduler(handle.clone());
+                if core::linux_setup::input_group_pending_relogin() {
+                    watch_input_access(handle.clone());
+                }
 
-                // Always start ASR warmup on launch (non-blocking).
-                state.kickoff_asr_warmup(&handle);
+                // ASR warmup and default-model downloads, once the startup trigger fires.
+                state.schedule_startup_work(&handle);
                 #[cfg(debug_assertions)]
                 {
                     crate::output::logs::initialize(&handle);
@@ -356,6 +863,13 @@ fn main() {
             }
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                if let Some(state) = app.try_state::<AppState>() {
+                    state.shutdown(app);
+                }
+            }
+        });
 }
@@ -0,0 +1,218 @@
+//! Descriptive metadata for the built-in catalog, so the UI can recommend
+//! models by language, footprint and quality instead of listing asset names.
+
+use serde::{Deserialize, Serialize};
+
+use super::manager::{ModelAsset, ModelKind, ModelStatus};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum QualityTier {
+    Basic,
+    Standard,
+    High,
+    Best,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelInfo {
+    /// ISO 639-1 codes; empty when `multilingual`.
+    #[serde(default)]
+    pub languages: Vec<String>,
+    /// Handles any language (or, for VAD and speaker models, is language-agnostic).
+    #[serde(default)]
+    pub multilingual: bool,
+    /// Approximate download size, known before `size_bytes` is.
+    pub download_mb: u32,
+    /// Approximate resident memory while loaded.
+    pub ram_mb: u32,
+    pub license: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality: Option<QualityTier>,
+}
+
+/// Criteria for `query_models`; unset fields match everything.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ModelFilter {
+    pub kind: Option<ModelKind>,
+    pub language: Option<String>,
+    pub max_ram_mb: Option<u32>,
+    pub max_download_mb: Option<u32>,
+    pub min_quality: Option<QualityTier>,
+    pub installed_only: bool,
+}
+
+impl ModelFilter {
+    pub fn matches(&self, asset: &ModelAsset) -> bool {
+        if self.kind.as_ref().is_some_and(|kind| kind != &asset.kind) {
+            return false;
+        }
+        if self.installed_only && !matches!(asset.status, ModelStatus::Installed) {
+            return false;
+        }
+        let needs_info = self.language.is_some()
+            || self.max_ram_mb.is_some()
+            || self.max_download_mb.is_some()
+            || self.min_quality.is_some();
+        let Some(info) = asset.info.as_ref() else {
+            // Assets from system manifests may carry no metadata; only
+            // metadata-free filters can vouch for them.
+            return !needs_info;
+        };
+        if let Some(language) = self.language.as_deref() {
+            let language = language.trim().to_ascii_lowercase();
+            if !info.multilingual && !info.languages.iter().any(|code| *code == language) {
+                return false;
+            }
+        }
+        if self.max_ram_mb.is_some_and(|max| info.ram_mb > max) {
+            return false;
+        }
+        if self
+            .max_download_mb
+            .is_some_and(|max| info.download_mb > max)
+        {
+            return false;
+        }
+        if let Some(min) = self.min_quality {
+            match info.quality {
+                Some(quality) if quality >= min => {}
+                _ => return false,
+            }
+        }
+        true
+    }
+}
+
+/// Metadata for a built-in asset, keyed by its catalog name.
+pub fn info_for(name: &str) -> Option<ModelInfo> {
+    match name {
+        "parakeet-tdt-0.6b-v2-int8" => {
+            Some(info(&["en"], 660, 1500, "CC-BY-4.0", QualityTier::High))
+        }
+        "silero-vad-onnx" => Some(agnostic(2, 30, "MIT")),
+        "speaker-ecapa-onnx" => Some(agnostic(80, 150, "Apache-2.0")),
+        "onnxruntime-gpu-cuda12" => Some(agnostic(210, 0, "MIT")),
+        _ => whisper_info(name),
+    }
+}
+
+fn whisper_info(name: &str) -> Option<ModelInfo> {
+    if let Some(rest) = name.strip_prefix("whisper-cpp-") {
+        return whisper_cpp_info(rest);
+    }
+    let (rest, ct2) = match name.strip_prefix("whisper-ct2-") {
+        Some(rest) => (rest, true),
+        None => (name.strip_prefix("whisper-onnx-")?, false),
+    };
+    // ONNX names end in the precision; CT2 weights are always float16.
+    let (rest, int8) = match rest.strip_suffix("-int8") {
+        Some(rest) => (rest, true),
+        None => (rest.strip_suffix("-float").unwrap_or(rest), false),
+    };
+    let (size, english_only) = match rest.strip_suffix("-en") {
+        Some(size) => (size, true),
+        None => (rest, false),
+    };
+    // (float16 download, fp32 download, int8 download, fp32 RAM) in MB.
+    let (ct2_mb, float_mb, int8_mb, ram_mb, quality) = match size {
+        "tiny" => (75, 150, 100, 400, QualityTier::Basic),
+        "base" => (145, 290, 160, 600, QualityTier::Basic),
+        "small" => (485, 970, 360, 1200, QualityTier::Standard),
+        "medium" => (1530, 3000, 950, 2800, QualityTier::High),
+        "large-v3" => (3090, 6200, 1750, 5000, QualityTier::Best),
+        "large-v3-turbo" => (1620, 3200, 1000, 3000, QualityTier::High),
+        _ => return None,
+    };
+    let (download_mb, ram_mb) = if ct2 {
+        (ct2_mb, ram_mb * 3 / 5)
+    } else if int8 {
+        (int8_mb, ram_mb / 2)
+    } else {
+        (float_mb, ram_mb)
+    };
+    let languages: &[&str] = if english_only { &["en"] } else { &[] };
+    Some(info(languages, download_mb, ram_mb, "MIT", quality))
+}
+
+/// whisper.cpp ggml files: float16 or 5-bit quantized.
+fn whisper_cpp_info(rest: &str) -> Option<ModelInfo> {
+    let (rest, quantized) = match rest.strip_suffix("-q5") {
+        Some(rest) => (rest, true),
+        None => (rest.strip_suffix("-float")?, false),
+    };
+    let (size, english_only) = match rest.strip_suffix("-en") {
+        Some(size) => (size, true),
+        None => (rest, false),
+    };
+    // (float16 download, q5 download, float16 RAM) in MB.
+    let (float_mb, q5_mb, ram_mb, quality) = match size {
+        "tiny" => (75, 31, 280, QualityTier::Basic),
+        "base" => (142, 57, 390, QualityTier::Basic),
+        "small" => (466, 181, 850, QualityTier::Standard),
+        "medium" => (1500, 514, 2100, QualityTier::High),
+        "large-v3" => (3100, 1080, 3900, QualityTier::Best),
+        "large-v3-turbo" => (1620, 547, 2100, QualityTier::High),
+        _ => return None,
+    };
+    let (download_mb, ram_mb) = if quantized {
+        (q5_mb, ram_mb / 2)
+    } else {
+        (float_mb, ram_mb)
+    };
+    let languages: &[&str] = if english_only { &["en"] } else { &[] };
+    Some(info(languages, download_mb, ram_mb, "MIT", quality))
+}
+
+fn info(
+    languages: &[&str],
+    download_mb: u32,
+    ram_mb: u32,
+    license: &str,
+    quality: QualityTier,
+) -> ModelInfo {
+    ModelInfo {
+        languages: languages.iter().map(|code| code.to_string()).collect(),
+        multilingual: languages.is_empty(),
+        download_mb,
+        ram_mb,
+        license: license.into(),
+        quality: Some(quality),
+    }
+}
+
+fn agnostic(download_mb: u32, ram_mb: u32, license: &str) -> ModelInfo {
+    ModelInfo {
+        languages: Vec::new(),
+        multilingual: true,
+        download_mb,
+        ram_mb,
+        license: license.into(),
+        quality: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn whisper_names_map_to_size_language_and_precision() {
+        let en = info_for("whisper-onnx-small-en-int8").unwrap();
+        assert_eq!(en.languages, vec!["en".to_string()]);
+        assert!(!en.multilingual);
+        assert_eq!(en.download_mb, 360);
+
+        let turbo = info_for("whisper-ct2-large-v3-turbo").unwrap();
+        assert!(turbo.multilingual);
+        assert_eq!(turbo.quality, Some(QualityTier::High));
+
+        let cpp = info_for("whisper-cpp-base-en-q5").unwrap();
+        assert_eq!(cpp.languages, vec!["en".to_string()]);
+        assert_eq!(cpp.download_mb, 57);
+
+        assert!(info_for("whisper-ct2-huge").is_none());
+    }
+}
@@ -2,22 +2,35 @@ use std::{
     fs::{self, File},
     io::{self, Read, Write},
     path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
 };
 
 use anyhow::{anyhow, Context, Result};
 use bzip2::read::BzDecoder;
+use crossbeam_channel::{bounded, Receiver};
 use flate2::read::GzDecoder;
 use globset::{Glob, GlobSet, GlobSetBuilder};
 use reqwest::blocking::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use tar::Archive;
 use zip::read::ZipArchive;
 
+use crate::core::secrets;
+
 use super::{
     manager::{ArchiveFormat, ModelArchiveSource, ModelAsset, ModelHfSource, ModelSource},
     metadata::compute_sha256,
+    revisions,
 };
 
+const CHUNK_SIZE: usize = 32 * 1024;
+/// Per-file size/etag record kept inside HF model directories for delta syncs.
+const HF_FILE_INDEX: &str = ".openflow-hf-files.json";
+/// Chunks buffered between the network thread and the extractor (~8 MiB).
+const STREAM_QUEUE_CHUNKS: usize = 256;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ArchiveDownloadPlan {
     pub uri: String,
@@ -46,6 +59,8 @@ pub struct HfRepoDownloadPlan {
     pub destination: PathBuf,
     pub include: Vec<String>,
     pub exclude: Vec<String>,
+    /// Existing install to take unchanged files from; defaults to `destination`.
+    pub reuse_from: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -54,8 +69,32 @@ pub enum DownloadPlan {
     HfRepo(HfRepoDownloadPlan),
 }
 
+impl DownloadPlan {
+    pub fn destination(&self) -> &Path {
+        match self {
+            DownloadPlan::Archive(plan) => &plan.destination,
+            DownloadPlan::HfRepo(plan) => &plan.destination,
+        }
+    }
+
+    /// Whether running the plan again after an interrupt keeps what was fetched.
+    /// Tar archives are extracted as they stream and start over.
+    pub fn resumable(&self) -> bool {
+        match self {
+            DownloadPlan::Archive(plan) => !matches!(
+                plan.archive_format,
+                ArchiveFormat::TarGz | ArchiveFormat::TarBz2
+            ),
+            DownloadPlan::HfRepo(_) => true,
+        }
+    }
+}
+
+/// Plans a download into a new revision directory; the installed revision (if any)
+/// is left alone until `revisions::activate` switches to the new one.
 pub fn plan_for(asset: &ModelAsset, models_dir: PathBuf) -> Option<DownloadPlan> {
     let source = asset.source.as_ref()?;
+    let destination = revisions::new_revision_dir(&asset.install_dir(&models_dir));
     match source {
         ModelSource::Archive(ModelArchiveSource {
             uri,
@@ -64,7 +103,7 @@ pub fn plan_for(asset: &ModelAsset, models_dir: PathBuf) -> Option<DownloadPlan>
         }) => Some(DownloadPlan::Archive(ArchiveDownloadPlan {
             uri: uri.clone(),
             archive_format: *archive_format,
-            destination: asset.path(&models_dir),
+            destination,
             strip_prefix_components: *strip_prefix_components,
             expected_size_bytes: if asset.size_bytes > 0 {
                 Some(asset.size_bytes)
@@ -82,9 +121,10 @@ pub fn plan_for(asset: &ModelAsset, models_dir: PathBuf) -> Option<DownloadPlan>
         }) => Some(DownloadPlan::HfRepo(HfRepoDownloadPlan {
             repo: repo.clone(),
             revision: revision.clone().unwrap_or_else(|| "main".into()),
-            destination: asset.path(&models_dir),
+            destination,
             include: include.clone(),
             exclude: exclude.clone(),
+            reuse_from: Some(asset.path(&models_dir)),
         })),
     }
 }
@@ -94,10 +134,42 @@ pub struct DownloadOutcome {
     pub final_path: PathBuf,
     pub total_size_bytes: u64,
     pub checksum: Option<String>,
+    /// Upstream revision that was installed: HF commit sha or the archive's ETag.
+    pub revision: Option<String>,
+}
+
+/// Stops a transfer in flight with an error. Either flag interrupts it; they only
+/// tell the caller whether to keep the partial files for a resume or throw them away.
+#[derive(Debug, Default)]
+pub struct DownloadInterrupt {
+    /// Pause for a higher-priority download; the same plan resumes later.
+    pub pause: AtomicBool,
+    /// Cancelled by the user; the partial download is discarded.
+    pub cancel: AtomicBool,
 }
 
+impl DownloadInterrupt {
+    pub fn is_set(&self) -> bool {
+        self.pause.load(Ordering::SeqCst) || self.cancel.load(Ordering::SeqCst)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.load(Ordering::SeqCst)
+    }
+
+    /// Clears both flags before the next job starts.
+    pub fn reset(&self) {
+        self.pause.store(false, Ordering::SeqCst);
+        self.cancel.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Downloads and installs `plan`. Setting `interrupt` stops the transfer with an
+/// error; running the same plan again resumes single-file archives and keeps the
+/// HF files already fetched, while tar archives start over.
 pub fn download_and_extract_with_progress<F>(
     plan: &DownloadPlan,
+    interrupt: &DownloadInterrupt,
     mut progress: F,
 ) -> Result<DownloadOutcome>
 where
@@ -105,11 +177,18 @@ where
 {
     let client = Client::builder().build().context("create http client")?;
     match plan {
-        DownloadPlan::Archive(plan) => download_archive(&client, plan, &mut progress),
-        DownloadPlan::HfRepo(plan) => download_hf_repo(&client, plan, &mut progress),
+        DownloadPlan::Archive(plan) => download_archive(&client, plan, interrupt, &mut progress),
+        DownloadPlan::HfRepo(plan) => download_hf_repo(&client, plan, interrupt, &mut progress),
     }
 }
 
+fn check_interrupt(interrupt: &DownloadInterrupt) -> Result<()> {
+    if interrupt.is_set() {
+        return Err(anyhow!("download interrupted"));
+    }
+    Ok(())
+}
+
 impl ArchiveFormat {
     #[must_use]
     pub fn extension(&self) -> &'static str {
@@ -130,21 +209,152 @@ pub struct DownloadProgress {
 fn download_archive<F>(
     client: &Client,
     plan: &ArchiveDownloadPlan,
+    interrupt: &DownloadInterrupt,
     progress: &mut F,
 ) -> Result<DownloadOutcome>
 where
     F: FnMut(DownloadProgress),
 {
+    if matches!(
+        plan.archive_format,
+        ArchiveFormat::TarGz | ArchiveFormat::TarBz2
+    ) {
+        return stream_tar_archive(client, plan, interrupt, progress);
+    }
+
     let staging = plan.staging_path();
     if let Some(parent) = staging.parent() {
         fs::create_dir_all(parent).context("create staging directory")?;
     }
 
-    let _bytes_downloaded = download_to_file(client, plan, &staging, progress)?;
+    let (_bytes_downloaded, revision) =
+        download_to_file(client, plan, &staging, interrupt, progress)?;
 
     let size = fs::metadata(&staging)
         .context("stat downloaded file")?
         .len();
+    let checksum = compute_sha256(&staging)?;
+    verify_archive(plan, size, &checksum)?;
+
+    if plan.destination.exists() {
+        fs::remove_dir_all(&plan.destination).with_context(|| {
+            format!("remove existing destination {}", plan.destination.display())
+        })?;
+    }
+    fs::create_dir_all(&plan.destination).context("create destination directory")?;
+
+    extract_archive(plan, &staging)?;
+
+    let _ = fs::remove_file(&staging);
+
+    Ok(DownloadOutcome {
+        final_path: plan.destination.clone(),
+        total_size_bytes: size,
+        checksum: Some(checksum),
+        revision,
+    })
+}
+
+/// Extracts a tar archive while it downloads: a network thread hashes the body and
+/// hands chunks to the decompressor here, so the archive never lands on disk. Output
+/// goes to a sibling directory that only replaces the destination once size and
+/// checksum check out.
+fn stream_tar_archive<F>(
+    client: &Client,
+    plan: &ArchiveDownloadPlan,
+    interrupt: &DownloadInterrupt,
+    progress: &mut F,
+) -> Result<DownloadOutcome>
+where
+    F: FnMut(DownloadProgress),
+{
+    let response = client
+        .get(&plan.uri)
+        .send()
+        .with_context(|| format!("request {}", plan.uri))?
+        .error_for_status()
+        .with_context(|| format!("download {}", plan.uri))?;
+    let total = plan.expected_size_bytes.or(response.content_length());
+    let revision = response_etag(&response);
+
+    let staging = plan.destination.with_extension("extracting");
+    if staging.exists() {
+        let _ = fs::remove_dir_all(&staging);
+    }
+    fs::create_dir_all(&staging).context("create extraction staging directory")?;
+
+    let (chunks_tx, chunks_rx) = bounded(STREAM_QUEUE_CHUNKS);
+    let fetcher = thread::Builder::new()
+        .name("model-download".into())
+        .spawn(move || -> Result<(u64, String)> {
+            let mut response = response;
+            let mut hasher = Sha256::new();
+            let mut size = 0u64;
+            let mut buffer = vec![0u8; CHUNK_SIZE];
+            loop {
+                let read = match response.read(&mut buffer) {
+                    Ok(0) => break,
+                    Ok(read) => read,
+                    Err(error) => {
+                        let _ =
+                            chunks_tx.send(Err(io::Error::new(error.kind(), error.to_string())));
+                        return Err(anyhow!(error).context("read download chunk"));
+                    }
+                };
+                hasher.update(&buffer[..read]);
+                size += read as u64;
+                if chunks_tx.send(Ok(buffer[..read].to_vec())).is_err() {
+                    return Err(anyhow!("extraction stopped before the download finished"));
+                }
+            }
+            Ok((size, format!("{:x}", hasher.finalize())))
+        })
+        .context("spawn download thread")?;
+
+    let mut reader = ChunkReader::new(chunks_rx, total, interrupt, progress);
+    let extracted = match plan.archive_format {
+        ArchiveFormat::TarGz => extract_tar(plan, &staging, GzDecoder::new(&mut reader)),
+        ArchiveFormat::TarBz2 => extract_tar(plan, &staging, BzDecoder::new(&mut reader)),
+        _ => Err(anyhow!("not a tar archive")),
+    }
+    .and_then(|()| {
+        // Trailing padding and compression footers still count toward the checksum.
+        io::copy(&mut reader, &mut io::sink()).context("drain archive stream")?;
+        Ok(())
+    });
+    drop(reader);
+
+    let fetched = fetcher
+        .join()
+        .map_err(|_| anyhow!("download thread panicked"))
+        .and_then(|result| result);
+    let verified = extracted.and(fetched).and_then(|(size, checksum)| {
+        verify_archive(plan, size, &checksum).map(|()| (size, checksum))
+    });
+    let (size, checksum) = match verified {
+        Ok(outcome) => outcome,
+        Err(error) => {
+            let _ = fs::remove_dir_all(&staging);
+            return Err(error);
+        }
+    };
+
+    if plan.destination.exists() {
+        fs::remove_dir_all(&plan.destination).with_context(|| {
+            format!("remove existing destination {}", plan.destination.display())
+        })?;
+    }
+    fs::rename(&staging, &plan.destination).context("finalize extracted archive")?;
+
+    Ok(DownloadOutcome {
+        final_path: plan.destination.clone(),
+        total_size_bytes: size,
+        checksum: Some(checksum),
+        revision,
+    })
+}
+
+fn verify_archive(plan: &ArchiveDownloadPlan, size: u64, checksum: &str) -> Result<()> {
     if let Some(expected) = plan.expected_size_bytes {
         if size != expected {
             return Err(anyhow!(
@@ -154,10 +364,8 @@ where
             ));
         }
     }
-
-    let checksum = compute_sha256(&staging)?;
     if let Some(expected) = &plan.expected_checksum {
-        if &checksum != expected {
+        if checksum != expected {
             return Err(anyhow!(
                 "checksum mismatch: expected {}, got {}",
                 expected,
@@ -165,34 +373,84 @@ where
             ));
         }
     }
+    Ok(())
+}
 
-    if plan.destination.exists() {
-        fs::remove_dir_all(&plan.destination).with_context(|| {
-            format!("remove existing destination {}", plan.destination.display())
-        })?;
-    }
-    fs::create_dir_all(&plan.destination).context("create destination directory")?;
-
-    extract_archive(plan, &staging)?;
+/// Reads the chunks produced by the download thread, reporting progress as the
+/// extractor consumes them.
+struct ChunkReader<'a, F> {
+    chunks: Receiver<io::Result<Vec<u8>>>,
+    current: Vec<u8>,
+    offset: usize,
+    downloaded: u64,
+    total: Option<u64>,
+    interrupt: &'a DownloadInterrupt,
+    progress: &'a mut F,
+}
 
-    let _ = fs::remove_file(&staging);
+impl<'a, F> ChunkReader<'a, F>
+where
+    F: FnMut(DownloadProgress),
+{
+    fn new(
+        chunks: Receiver<io::Result<Vec<u8>>>,
+        total: Option<u64>,
+        interrupt: &'a DownloadInterrupt,
+        progress: &'a mut F,
+    ) -> Self {
+        Self {
+            chunks,
+            current: Vec::new(),
+            offset: 0,
+            downloaded: 0,
+            total,
+            interrupt,
+            progress,
+        }
+    }
+}
 
-    Ok(DownloadOutcome {
-        final_path: plan.destination.clone(),
-        total_size_bytes: size,
-        checksum: Some(checksum),
-    })
+impl<F> Read for ChunkReader<'_, F>
+where
+    F: FnMut(DownloadProgress),
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.offset == self.current.len() {
+            if self.interrupt.is_set() {
+                return Err(io::Error::other("download interrupted"));
+            }
+            match self.chunks.recv() {
+                Ok(Ok(chunk)) => {
+                    self.downloaded += chunk.len() as u64;
+                    (self.progress)(DownloadProgress {
+                        downloaded: self.downloaded,
+                        total: self.total,
+                    });
+                    self.current = chunk;
+                    self.offset = 0;
+                }
+                Ok(Err(error)) => return Err(error),
+                // Sender gone without an error: end of body.
+                Err(_) => return Ok(0),
+            }
+        }
+        let len = buf.len().min(self.current.len() - self.offset);
+        buf[..len].copy_from_slice(&self.current[self.offset..self.offset + len]);
+        self.offset += len;
+        Ok(len)
+    }
 }
 
 fn download_hf_repo<F>(
     client: &Client,
     plan: &HfRepoDownloadPlan,
+    interrupt: &DownloadInterrupt,
     progress: &mut F,
 ) -> Result<DownloadOutcome>
 where
     F: FnMut(DownloadProgress),
 {
-    let files = list_hf_repo_files(client, plan)?;
+    let (files, revision) = list_hf_repo_files(client, plan)?;
     if files.is_empty() {
         return Err(anyhow!("no downloadable files found in HF repo"));
     }
@@ -204,20 +462,51 @@ where
         None
     };
 
+    // Staging only survives an interrupted run of this same plan, whose complete
+    // files are kept.
     let staging = plan.destination.with_extension("download");
-    if staging.exists() {
-        let _ = fs::remove_dir_all(&staging);
-    }
     fs::create_dir_all(&staging).context("create hf staging directory")?;
 
+    let installed_dir = plan.reuse_from.as_deref().unwrap_or(&plan.destination);
+    let previous = read_hf_file_index(installed_dir);
+    let mut index = HfFileIndex::default();
     let mut downloaded = 0u64;
-    for file in files {
+    let mut reused = 0usize;
+    for file in &files {
         let target = staging.join(&file.path);
         if let Some(parent) = target.parent() {
             fs::create_dir_all(parent).context("create hf file parent")?;
         }
-        downloaded += download_hf_file(client, &file.uri, &target, downloaded, total, progress)?;
+        let fetched = file
+            .size
+            .is_some_and(|size| fs::metadata(&target).is_ok_and(|meta| meta.len() == size));
+        if fetched {
+            downloaded += file.size.unwrap_or(0);
+            progress(DownloadProgress { downloaded, total });
+        } else if reuse_unchanged_hf_file(installed_dir, &previous, file, &target) {
+            reused += 1;
+            downloaded += file.size.unwrap_or(0);
+            progress(DownloadProgress { downloaded, total });
+        } else {
+            downloaded += download_hf_file(
+                client, &file.uri, &target, downloaded, total, interrupt, progress,
+            )?;
+        }
+        index.files.push(HfFileRecord {
+            path: file.path.clone(),
+            size: fs::metadata(&target).map(|meta| meta.len()).ok(),
+            etag: file.etag.clone(),
+        });
+    }
+    if reused > 0 {
+        tracing::info!(
+            "hf_delta_sync repo={} reused={} fetched={}",
+            plan.repo,
+            reused,
+            files.len() - reused
+        );
     }
+    write_hf_file_index(&staging, &index)?;
 
     if plan.destination.exists() {
         fs::remove_dir_all(&plan.destination).with_context(|| {
@@ -230,34 +519,116 @@ where
         final_path: plan.destination.clone(),
         total_size_bytes: total_size,
         checksum: None,
+        revision,
     })
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct HfFileIndex {
+    files: Vec<HfFileRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HfFileRecord {
+    path: String,
+    size: Option<u64>,
+    etag: Option<String>,
+}
+
+fn read_hf_file_index(dir: &Path) -> HfFileIndex {
+    fs::read(dir.join(HF_FILE_INDEX))
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+fn write_hf_file_index(dir: &Path, index: &HfFileIndex) -> Result<()> {
+    let serialized = serde_json::to_vec_pretty(index).context("serialize hf file index")?;
+    fs::write(dir.join(HF_FILE_INDEX), serialized).context("write hf file index")
+}
+
+/// Links an already-installed copy of `file` into the staging directory when it is
+/// unchanged upstream. The etag must match when one was recorded; installs that
+/// predate the index fall back to the size alone.
+fn reuse_unchanged_hf_file(
+    installed_dir: &Path,
+    previous: &HfFileIndex,
+    file: &HfRepoFile,
+    target: &Path,
+) -> bool {
+    let existing = installed_dir.join(&file.path);
+    let Ok(meta) = fs::metadata(&existing) else {
+        return false;
+    };
+    let Some(expected_size) = file.size else {
+        return false;
+    };
+    if meta.len() != expected_size {
+        return false;
+    }
+
+    let record = previous
+        .files
+        .iter()
+        .find(|record| record.path == file.path);
+    let etag_matches = match (record.and_then(|r| r.etag.as_ref()), &file.etag) {
+        (Some(recorded), Some(current)) => recorded == current,
+        (Some(_), None) => false,
+        (None, _) => true,
+    };
+    if !etag_matches {
+        return false;
+    }
+
+    // Hard links keep the installed copy intact until the new directory is swapped in.
+    fs::hard_link(&existing, target).is_ok() || fs::copy(&existing, target).is_ok()
+}
+
+/// Downloads to `path`, continuing a partial file left by an interrupted run when
+/// the server honours the range request.
 fn download_to_file<F>(
     client: &Client,
     plan: &ArchiveDownloadPlan,
     path: &Path,
+    interrupt: &DownloadInterrupt,
     progress: &mut F,
-) -> Result<u64>
+) -> Result<(u64, Option<String>)>
 where
     F: FnMut(DownloadProgress),
 {
-    let response = client
-        .get(&plan.uri)
+    let partial = fs::metadata(path).map(|meta| meta.len()).unwrap_or(0);
+    let mut request = client.get(&plan.uri);
+    if partial > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={partial}-"));
+    }
+    let response = request
         .send()
         .with_context(|| format!("request {}", plan.uri))?
         .error_for_status()
         .with_context(|| format!("download {}", plan.uri))?;
 
-    let content_length = response.content_length();
+    let resumed = partial > 0 && response.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+    let offset = if resumed { partial } else { 0 };
+    let content_length = response.content_length().map(|length| length + offset);
     let total = plan.expected_size_bytes.or(content_length);
+    let revision = response_etag(&response);
     let mut response = response;
 
-    let mut file = File::create(path).context("create staging file")?;
-    let mut downloaded = 0u64;
-    const CHUNK_SIZE: usize = 32 * 1024;
+    let mut file = if resumed {
+        tracing::info!("model_download_resumed uri={} offset={offset}", plan.uri);
+        fs::OpenOptions::new()
+            .append(true)
+            .open(path)
+            .context("open partial staging file")?
+    } else {
+        File::create(path).context("create staging file")?
+    };
+    let mut downloaded = offset;
     let mut buffer = vec![0u8; CHUNK_SIZE];
     loop {
+        check_interrupt(interrupt)?;
         let read = response.read(&mut buffer).context("read download chunk")?;
         if read == 0 {
             break;
@@ -267,7 +638,67 @@ where
         downloaded += read as u64;
         progress(DownloadProgress { downloaded, total });
     }
-    Ok(downloaded)
+    Ok((downloaded, revision))
+}
+
+fn response_etag(response: &reqwest::blocking::Response) -> Option<String> {
+    response
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim_matches('"').to_string())
+}
+
+/// Looks up the newest upstream revision of an asset's source without downloading
+/// it: the HF commit sha for repos, the ETag for archives.
+pub fn fetch_latest_revision(asset: &ModelAsset) -> Result<Option<String>> {
+    let client = Client::builder().build().context("create http client")?;
+    match asset.source.as_ref() {
+        Some(ModelSource::HfRepo(source)) => {
+            let revision = source.revision.as_deref().unwrap_or("main");
+            let info = fetch_hf_model_info(&client, &source.repo, revision, false)?;
+            Ok(info.sha)
+        }
+        Some(ModelSource::Archive(source)) => {
+            let response = client
+                .head(&source.uri)
+                .send()
+                .with_context(|| format!("request {}", source.uri))?
+                .error_for_status()
+                .with_context(|| format!("check {}", source.uri))?;
+            Ok(response_etag(&response))
+        }
+        None => Ok(None),
+    }
+}
+
+fn fetch_hf_model_info(
+    client: &Client,
+    repo: &str,
+    revision: &str,
+    blobs: bool,
+) -> Result<HfModelInfo> {
+    let mut info_url = format!("https://huggingface.co/api/models/{repo}/revision/{revision}");
+    if blobs {
+        info_url.push_str("?blobs=true");
+    }
+    hf_get(client, &info_url)
+        .send()
+        .with_context(|| format!("request {info_url}"))?
+        .error_for_status()
+        .with_context(|| format!("fetch hf model metadata for {repo}"))?
+        .json()
+        .context("parse hf metadata")
+}
+
+/// GET against the Hugging Face hub, authenticated when an `hf-token` secret is stored
+/// (gated or private repos). reqwest drops the header on redirects to the CDN host.
+fn hf_get(client: &Client, url: &str) -> reqwest::blocking::RequestBuilder {
+    let request = client.get(url);
+    match secrets::lookup(secrets::SECRET_HF_TOKEN) {
+        Some(token) => request.bearer_auth(token),
+        None => request,
+    }
 }
 
 fn download_hf_file<F>(
@@ -276,13 +707,13 @@ fn download_hf_file<F>(
     path: &Path,
     start_offset: u64,
     total: Option<u64>,
+    interrupt: &DownloadInterrupt,
     progress: &mut F,
 ) -> Result<u64>
 where
     F: FnMut(DownloadProgress),
 {
-    let response = client
-        .get(uri)
+    let response = hf_get(client, uri)
         .send()
         .with_context(|| format!("request {}", uri))?
         .error_for_status()
@@ -291,9 +722,9 @@ where
     let mut response = response;
     let mut file = File::create(path).context("create hf file")?;
     let mut downloaded = 0u64;
-    const CHUNK_SIZE: usize = 32 * 1024;
     let mut buffer = vec![0u8; CHUNK_SIZE];
     loop {
+        check_interrupt(interrupt)?;
         let read = response.read(&mut buffer).context("read hf chunk")?;
         if read == 0 {
             break;
@@ -311,14 +742,14 @@ where
 fn extract_archive(plan: &ArchiveDownloadPlan, archive_path: &Path) -> Result<()> {
     let file = File::open(archive_path).context("open archive")?;
     match plan.archive_format {
-        ArchiveFormat::TarGz => extract_tar(plan, GzDecoder::new(file)),
-        ArchiveFormat::TarBz2 => extract_tar(plan, BzDecoder::new(file)),
+        ArchiveFormat::TarGz => extract_tar(plan, &plan.destination, GzDecoder::new(file)),
+        ArchiveFormat::TarBz2 => extract_tar(plan, &plan.destination, BzDecoder::new(file)),
         ArchiveFormat::Zip => extract_zip(plan, file),
         ArchiveFormat::File => extract_file(plan, file, archive_path),
     }
 }
 
-fn extract_tar<R: Read>(plan: &ArchiveDownloadPlan, reader: R) -> Result<()> {
+fn extract_tar<R: Read>(plan: &ArchiveDownloadPlan, root: &Path, reader: R) -> Result<()> {
     let mut archive = Archive::new(reader);
     for entry in archive.entries().context("iterate tar entries")? {
         let mut entry = entry.context("read tar entry")?;
@@ -331,9 +762,9 @@ fn extract_tar<R: Read>(plan: &ArchiveDownloadPlan, reader: R) -> Result<()> {
             )
         })?;
         let dest = if relative.as_os_str() == "." {
-            plan.destination.clone()
+            root.to_path_buf()
         } else {
-            plan.destination.join(relative)
+            root.join(relative)
         };
         if let Some(parent) = dest.parent() {
             fs::create_dir_all(parent).context("create entry parent")?;
@@ -414,15 +845,30 @@ fn strip_components(path: &Path, count: u8) -> Option<PathBuf> {
 
 #[derive(Debug, Deserialize)]
 struct HfModelInfo {
+    #[serde(default)]
+    sha: Option<String>,
     #[serde(default)]
     siblings: Vec<HfSibling>,
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct HfSibling {
     rfilename: String,
     #[serde(default)]
     size: Option<u64>,
+    /// Git blob id; stands in for the etag of non-LFS files.
+    #[serde(default)]
+    blob_id: Option<String>,
+    #[serde(default)]
+    lfs: Option<HfLfsInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HfLfsInfo {
+    sha256: String,
+    #[serde(default)]
+    size: Option<u64>,
 }
 
 #[derive(Debug)]
@@ -430,18 +876,18 @@ struct HfRepoFile {
     path: String,
     uri: String,
     size: Option<u64>,
+    /// Content identity as served by the hub: the LFS sha256 or the git blob id.
+    etag: Option<String>,
 }
 
-fn list_hf_repo_files(client: &Client, plan: &HfRepoDownloadPlan) -> Result<Vec<HfRepoFile>> {
-    let info_url = format!("https://huggingface.co/api/models/{}", plan.repo);
-    let info: HfModelInfo = client
-        .get(&info_url)
-        .send()
-        .with_context(|| format!("request {info_url}"))?
-        .error_for_status()
-        .with_context(|| format!("fetch hf model metadata for {}", plan.repo))?
-        .json()
-        .context("parse hf metadata")?;
+/// Files to fetch for the plan, plus the commit sha they were listed at.
+fn list_hf_repo_files(
+    client: &Client,
+    plan: &HfRepoDownloadPlan,
+) -> Result<(Vec<HfRepoFile>, Option<String>)> {
+    let info = fetch_hf_model_info(client, &plan.repo, &plan.revision, true)?;
+    // Pin file URLs to the listed commit so a push mid-download can't mix revisions.
+    let resolve_revision = info.sha.clone().unwrap_or_else(|| plan.revision.clone());
 
     let include = compile_globset(&plan.include)?;
     let exclude = compile_globset(&plan.exclude)?;
@@ -461,16 +907,21 @@ fn list_hf_repo_files(client: &Client, plan: &HfRepoDownloadPlan) -> Result<Vec<
         }
         let uri = format!(
             "https://huggingface.co/{}/resolve/{}/{}",
-            plan.repo, plan.revision, filename
+            plan.repo, resolve_revision, filename
         );
+        let (size, etag) = match sibling.lfs {
+            Some(lfs) => (lfs.size.or(sibling.size), Some(lfs.sha256)),
+            None => (sibling.size, sibling.blob_id),
+        };
         files.push(HfRepoFile {
             path: filename,
             uri,
-            size: sibling.size,
+            size,
+            etag,
         });
     }
 
-    Ok(files)
+    Ok((files, info.sha))
 }
 
 fn compile_globset(patterns: &[String]) -> Result<Option<GlobSet>> {
@@ -515,6 +966,69 @@ mod tests {
         assert!(exclude.is_match("model.int8.onnx"));
     }
 
+    #[test]
+    fn tar_gz_extracts_from_streamed_chunks() {
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+            Vec::new(),
+            flate2::Compression::fast(),
+        ));
+        let body = b"hello model";
+        let mut header = tar::Header::new_gnu();
+        header.set_size(body.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, "pkg/tokens.txt", &body[..])
+            .unwrap();
+        let archive = builder.into_inner().unwrap().finish().unwrap();
+
+        let root = std::env::temp_dir().join(format!("openflow-stream-{}", uuid::Uuid::new_v4()));
+        let plan = ArchiveDownloadPlan {
+            uri: String::new(),
+            archive_format: ArchiveFormat::TarGz,
+            destination: root.clone(),
+            strip_prefix_components: 1,
+            expected_size_bytes: None,
+            expected_checksum: None,
+            filename: None,
+        };
+        let (tx, rx) = bounded(STREAM_QUEUE_CHUNKS);
+        for chunk in archive.chunks(7) {
+            tx.send(Ok(chunk.to_vec())).unwrap();
+        }
+        drop(tx);
+
+        let mut seen = 0u64;
+        let mut progress = |update: DownloadProgress| seen = update.downloaded;
+        let interrupt = DownloadInterrupt::default();
+        let mut reader = ChunkReader::new(rx, None, &interrupt, &mut progress);
+        extract_tar(&plan, &root, GzDecoder::new(&mut reader)).unwrap();
+        io::copy(&mut reader, &mut io::sink()).unwrap();
+        drop(reader);
+
+        assert_eq!(fs::read(root.join("tokens.txt")).unwrap(), body);
+        assert_eq!(seen, archive.len() as u64);
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn cancel_stops_a_streamed_transfer_mid_body() {
+        let (tx, rx) = bounded(STREAM_QUEUE_CHUNKS);
+        tx.send(Ok(b"first".to_vec())).unwrap();
+        tx.send(Ok(b"second".to_vec())).unwrap();
+
+        let mut progress = |_: DownloadProgress| {};
+        let interrupt = DownloadInterrupt::default();
+        let mut reader = ChunkReader::new(rx, None, &interrupt, &mut progress);
+        let mut buf = [0u8; 5];
+        assert_eq!(reader.read(&mut buf).unwrap(), 5);
+
+        interrupt.cancel.store(true, Ordering::SeqCst);
+        let error = reader.read(&mut buf).unwrap_err();
+        assert_eq!(error.to_string(), "download interrupted");
+        assert!(interrupt.is_cancelled());
+    }
+
     // Metadata-only smoke test against HuggingFace API.
     // Keeps assertions minimal to reduce flake.
     #[test]
@@ -531,8 +1045,9 @@ mod tests {
             destination: PathBuf::from("/tmp/unused"),
             include: vec!["**/*.bin".into(), "**/*.json".into(), "**/*.txt".into()],
             exclude: Vec::new(),
+            reuse_from: None,
         };
-        let ct2_files = list_hf_repo_files(&client, &ct2_plan).expect("ct2 list");
+        let (ct2_files, _) = list_hf_repo_files(&client, &ct2_plan).expect("ct2 list");
         assert!(!ct2_files.is_empty(), "ct2 filter returned no files");
 
         // Sherpa ONNX whisper float plan should exclude int8 models.
@@ -547,8 +1062,9 @@ mod tests {
                 "**/*.json".into(),
             ],
             exclude: vec!["**/*.int8.onnx".into()],
+            reuse_from: None,
         };
-        let onnx_files = list_hf_repo_files(&client, &onnx_plan).expect("onnx list");
+        let (onnx_files, _) = list_hf_repo_files(&client, &onnx_plan).expect("onnx list");
         assert!(!onnx_files.is_empty(), "onnx filter returned no files");
         assert!(
             !onnx_files.iter().any(|f| f.path.ends_with(".int8.onnx")),
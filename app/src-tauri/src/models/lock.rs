@@ -0,0 +1,104 @@
+//! Per-asset download locks shared between OpenFlow processes.
+//!
+//! Two instances under the same user (or the GUI and a headless run) share the
+//! models directory. Each download holds an exclusive `flock` on
+//! `<models>/.locks/<asset>.lock`; the kernel drops it when the process exits, so
+//! a crashed downloader never leaves a stale lock behind.
+
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::os::fd::AsRawFd;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+const LOCKS_DIR: &str = ".locks";
+
+/// Held for the duration of a download; unlocks on drop.
+#[derive(Debug)]
+pub struct AssetLock {
+    file: File,
+}
+
+impl AssetLock {
+    /// Takes the lock if no other process holds it.
+    pub fn try_acquire(models_dir: &Path, asset_name: &str) -> Result<Option<Self>> {
+        let file = open_lock_file(models_dir, asset_name)?;
+        match flock(&file, libc::LOCK_EX | libc::LOCK_NB) {
+            Ok(()) => Ok(Some(Self { file })),
+            Err(error) if error.raw_os_error() == Some(libc::EWOULDBLOCK) => Ok(None),
+            Err(error) => Err(error).context("lock model asset"),
+        }
+    }
+
+    /// Blocks until the lock is free.
+    pub fn acquire(models_dir: &Path, asset_name: &str) -> Result<Self> {
+        let file = open_lock_file(models_dir, asset_name)?;
+        flock(&file, libc::LOCK_EX).context("wait for model asset lock")?;
+        Ok(Self { file })
+    }
+}
+
+impl Drop for AssetLock {
+    fn drop(&mut self) {
+        let _ = flock(&self.file, libc::LOCK_UN);
+    }
+}
+
+fn lock_path(models_dir: &Path, asset_name: &str) -> PathBuf {
+    models_dir
+        .join(LOCKS_DIR)
+        .join(format!("{}.lock", asset_name.replace('/', "_")))
+}
+
+fn open_lock_file(models_dir: &Path, asset_name: &str) -> Result<File> {
+    let path = lock_path(models_dir, asset_name);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context("create model lock directory")?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&path)
+        .with_context(|| format!("open {}", path.display()))
+}
+
+fn flock(file: &File, operation: libc::c_int) -> io::Result<()> {
+    loop {
+        // Safety: the descriptor is owned by `file` and stays open for the call.
+        if unsafe { libc::flock(file.as_raw_fd(), operation) } == 0 {
+            return Ok(());
+        }
+        let error = io::Error::last_os_error();
+        if error.kind() != io::ErrorKind::Interrupted {
+            return Err(error);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_holder_is_refused_until_release() {
+        let models = std::env::temp_dir().join(format!("openflow-locks-{}", uuid::Uuid::new_v4()));
+        let held = AssetLock::try_acquire(&models, "whisper-small").unwrap();
+        assert!(held.is_some());
+        // flock locks belong to the open file description, so a second open in the
+        // same process contends like another instance would.
+        assert!(AssetLock::try_acquire(&models, "whisper-small")
+            .unwrap()
+            .is_none());
+        assert!(AssetLock::try_acquire(&models, "silero-vad")
+            .unwrap()
+            .is_some());
+
+        drop(held);
+        assert!(AssetLock::try_acquire(&models, "whisper-small")
+            .unwrap()
+            .is_some());
+        let _ = fs::remove_dir_all(&models);
+    }
+}
@@ -3,19 +3,28 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
 
+use super::catalog::{self, ModelFilter, ModelInfo};
+use super::manifest::{self, Passthrough};
 use super::metadata::total_size;
+use super::revisions::{self, CURRENT_LINK};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "kebab-case")]
 pub enum ModelKind {
     WhisperOnnx,
     WhisperCt2,
+    /// Single-file ggml/GGUF Whisper model for whisper.cpp.
+    WhisperCpp,
     Parakeet,
     Vad,
+    /// Speaker-verification embedding model for the optional speaker gate.
+    Speaker,
+    /// CUDA-enabled ONNX Runtime libraries, preloaded for GPU inference.
+    GpuRuntime,
     #[serde(other)]
     Unknown,
 }
@@ -30,6 +39,11 @@ pub enum ModelStatus {
         downloaded_bytes: u64,
         #[serde(default)]
         total_bytes: Option<u64>,
+        /// Smoothed transfer speed; `None` until enough samples arrived.
+        #[serde(default)]
+        bytes_per_sec: Option<u64>,
+        #[serde(default)]
+        eta_secs: Option<u64>,
     },
     Installed,
     Error(String),
@@ -48,23 +62,79 @@ pub struct ModelAsset {
     pub status: ModelStatus,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<ModelSource>,
+    /// Installed in a read-only system models directory rather than the per-user one.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub system_provided: bool,
+    #[serde(default, flatten)]
+    pub revisions: ModelRevisions,
+    /// External directory `current` points at instead of a downloaded revision.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub linked_path: Option<PathBuf>,
+    /// Catalog metadata (languages, footprint, license, quality) for built-in assets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info: Option<ModelInfo>,
+    /// Fields written by a newer OpenFlow, kept so saving does not drop them.
+    #[serde(flatten)]
+    pub unknown_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Upstream revisions (HF commit sha or archive ETag) for update tracking.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ModelRevisions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installed_revision: Option<String>,
+    /// Newest revision seen by the last update check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_revision: Option<String>,
+    /// Revision kept next to the install after an update, for rollback.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_revision: Option<String>,
+}
+
+impl ModelRevisions {
+    pub fn update_available(&self) -> bool {
+        match (&self.installed_revision, &self.latest_revision) {
+            (Some(installed), Some(latest)) => installed != latest,
+            _ => false,
+        }
+    }
 }
 
 impl ModelAsset {
     #[must_use]
-    pub fn path(&self, base_dir: &Path) -> PathBuf {
+    /// Directory holding every downloaded revision of this asset.
+    pub fn install_dir(&self, base_dir: &Path) -> PathBuf {
         base_dir
             .join(&self.kind_path())
             .join(format!("{}-{}", self.name, self.version))
     }
 
+    /// The active revision (the `current` link inside `install_dir`).
+    pub fn path(&self, base_dir: &Path) -> PathBuf {
+        self.install_dir(base_dir).join(CURRENT_LINK)
+    }
+
+    /// System model directories are laid out by admins and may be flat.
+    fn system_path(&self, system_root: &Path) -> PathBuf {
+        let current = self.path(system_root);
+        if current.exists() {
+            current
+        } else {
+            self.install_dir(system_root)
+        }
+    }
+
     #[must_use]
     fn kind_path(&self) -> String {
         match self.kind {
             ModelKind::WhisperOnnx => "asr/whisper-onnx".into(),
             ModelKind::WhisperCt2 => "asr/whisper-ct2".into(),
+            ModelKind::WhisperCpp => "asr/whisper-cpp".into(),
             ModelKind::Parakeet => "asr/parakeet".into(),
             ModelKind::Vad => "vad".into(),
+            ModelKind::Speaker => "speaker".into(),
+            ModelKind::GpuRuntime => "runtime/gpu".into(),
             ModelKind::Unknown => "legacy".into(),
         }
     }
@@ -129,7 +199,10 @@ pub enum ArchiveFormat {
 pub struct ModelManager {
     root: PathBuf,
     manifest: PathBuf,
+    system_roots: Vec<PathBuf>,
     assets: Vec<ModelAsset>,
+    /// Manifest content from a newer schema, written back on save.
+    passthrough: Passthrough,
 }
 
 impl ModelManager {
@@ -139,11 +212,14 @@ impl ModelManager {
         let mut manager = Self {
             root,
             manifest,
+            system_roots: resolve_system_model_dirs(),
             assets: vec![],
+            passthrough: Passthrough::default(),
         };
         manager.load_manifest()?;
         manager.cleanup_legacy_assets();
         manager.register_defaults();
+        manager.migrate_install_layout();
         manager.reconcile_on_disk_state();
         manager.save()?;
         Ok(manager)
@@ -181,15 +257,102 @@ impl ModelManager {
         self.assets.iter().find(|asset| asset.name == name)
     }
 
+    /// Assets matching `filter`, in catalog order.
+    pub fn query(&self, filter: &ModelFilter) -> Vec<ModelAsset> {
+        self.assets
+            .iter()
+            .filter(|asset| filter.matches(asset))
+            .cloned()
+            .collect()
+    }
+
     pub fn save(&self) -> Result<()> {
-        let manifest = File::create(&self.manifest).context("create model manifest")?;
-        serde_json::to_writer_pretty(manifest, &self.assets).context("write model manifest")?;
-        Ok(())
+        manifest::write(&self.manifest, &self.assets, &self.passthrough)
+    }
+
+    /// Takes over an install another OpenFlow process finished, using the entry it
+    /// wrote to the shared manifest. Returns the adopted snapshot.
+    pub fn adopt_from_manifest(&mut self, name: &str) -> Result<Option<ModelAsset>> {
+        let Some(adopted) = manifest::read(&self.manifest)?
+            .assets
+            .into_iter()
+            .find(|asset| asset.name == name)
+        else {
+            return Ok(None);
+        };
+        if !matches!(adopted.status, ModelStatus::Installed)
+            || !looks_installed(&adopted.kind, &adopted.path(&self.root))
+        {
+            return Ok(None);
+        }
+        let Some(asset) = self.asset_by_name_mut(name) else {
+            return Ok(None);
+        };
+        *asset = adopted;
+        Ok(Some(asset.clone()))
+    }
+
+    /// Resets a `Downloading` status left by a download that is no longer running,
+    /// according to what is on disk. Returns the snapshot if it changed.
+    pub fn recover_interrupted(&mut self, name: &str) -> Option<ModelAsset> {
+        let root = self.root.clone();
+        let asset = self.asset_by_name_mut(name)?;
+        if !matches!(asset.status, ModelStatus::Downloading { .. }) {
+            return None;
+        }
+        asset.status = if looks_installed(&asset.kind, &asset.path(&root)) {
+            ModelStatus::Installed
+        } else {
+            ModelStatus::NotInstalled
+        };
+        Some(asset.clone())
+    }
+
+    /// Uses the model files in `target` in place of a download. The directory is
+    /// validated but not copied or hashed, so it can live on a shared mount.
+    pub fn link_external(&mut self, name: &str, target: &Path) -> Result<ModelAsset> {
+        let target =
+            fs::canonicalize(target).with_context(|| format!("resolve {}", target.display()))?;
+        if !target.is_dir() {
+            anyhow::bail!("{} is not a directory", target.display());
+        }
+        let root = self.root.clone();
+        let asset = self
+            .asset_by_name_mut(name)
+            .ok_or_else(|| anyhow!("unknown model {name}"))?;
+        if asset.system_provided {
+            anyhow::bail!("{name} is provided by the system models directory");
+        }
+        if matches!(asset.status, ModelStatus::Downloading { .. }) {
+            anyhow::bail!("{name} is downloading");
+        }
+        validate_external_layout(&asset.kind, &target)?;
+
+        revisions::link_external(&asset.install_dir(&root), &target)
+            .with_context(|| format!("link {name} to {}", target.display()))?;
+        asset.checksum = None;
+        asset.set_size_bytes(total_size(&target));
+        asset.status = ModelStatus::Installed;
+        if asset.linked_path.is_none() && asset.revisions.installed_revision.is_some() {
+            // The replaced download is kept as `previous`; rollback restores it.
+            asset.revisions.previous_revision = asset.revisions.installed_revision.take();
+        }
+        asset.revisions.installed_revision = None;
+        asset.linked_path = Some(target);
+        let snapshot = asset.clone();
+        self.save()?;
+        tracing::info!("model_linked name={name} path={:?}", snapshot.linked_path);
+        Ok(snapshot)
     }
 
     pub fn uninstall_by_name(&mut self, name: &str) -> Result<Option<ModelAsset>> {
         if let Some(asset) = self.assets.iter_mut().find(|asset| asset.name == name) {
-            let path = asset.path(&self.root);
+            if asset.system_provided {
+                anyhow::bail!(
+                    "{name} is provided by the system models directory and cannot be removed"
+                );
+            }
+            let path = asset.install_dir(&self.root);
             if path.exists() {
                 fs::remove_dir_all(&path)
                     .with_context(|| format!("remove model directory {}", path.display()))?;
@@ -197,6 +360,8 @@ impl ModelManager {
             asset.checksum = None;
             asset.size_bytes = 0;
             asset.status = ModelStatus::NotInstalled;
+            asset.revisions = ModelRevisions::default();
+            asset.linked_path = None;
             let snapshot = asset.clone();
             self.save()?;
             return Ok(Some(snapshot));
@@ -205,11 +370,43 @@ impl ModelManager {
     }
 
     fn load_manifest(&mut self) -> Result<()> {
+        // System manifests seed the inventory; the per-user manifest overlays them by
+        // name and is the only one ever written back.
+        for system_root in &self.system_roots {
+            let path = system_root.join("manifest.json");
+            if !path.is_file() {
+                continue;
+            }
+            let assets = match manifest::read(&path) {
+                Ok(manifest) => manifest.assets,
+                Err(error) => {
+                    tracing::warn!(
+                        "Ignoring system model manifest {}: {error:?}",
+                        path.display()
+                    );
+                    continue;
+                }
+            };
+            for asset in assets {
+                if !self.assets.iter().any(|current| current.name == asset.name) {
+                    self.assets.push(asset);
+                }
+            }
+        }
+
         if self.manifest.exists() {
-            let manifest = File::open(&self.manifest).context("open model manifest")?;
-            let assets: Vec<ModelAsset> =
-                serde_json::from_reader(manifest).context("parse model manifest")?;
-            self.assets = assets;
+            let manifest = manifest::read(&self.manifest)?;
+            self.passthrough = manifest.passthrough;
+            for asset in manifest.assets {
+                match self
+                    .assets
+                    .iter_mut()
+                    .find(|current| current.name == asset.name)
+                {
+                    Some(existing) => *existing = asset,
+                    None => self.assets.push(asset),
+                }
+            }
         }
         Ok(())
     }
@@ -218,6 +415,21 @@ impl ModelManager {
         self.root.as_path()
     }
 
+    /// On-disk location of an asset, resolving system-provided installs.
+    pub fn asset_path(&self, asset: &ModelAsset) -> PathBuf {
+        if asset.system_provided {
+            if let Some(path) = self
+                .system_roots
+                .iter()
+                .map(|root| asset.system_path(root))
+                .find(|path| path.exists())
+            {
+                return path;
+            }
+        }
+        asset.path(&self.root)
+    }
+
     fn register_defaults(&mut self) {
         for asset in default_assets() {
             if let Some(existing) = self
@@ -229,6 +441,7 @@ impl ModelManager {
                 if existing.source.is_none() || existing.source != asset.source {
                     existing.source = asset.source.clone();
                 }
+                existing.info = asset.info.clone();
 
                 // For non-installed or error states, also update other metadata
                 if matches!(
@@ -256,7 +469,7 @@ impl ModelManager {
 
         self.assets.retain(|asset| {
             if matches!(asset.kind, ModelKind::Unknown) || asset.name.contains("zipformer") {
-                let path = asset.path(&self.root);
+                let path = asset.install_dir(&self.root);
                 if path.exists() {
                     let _ = fs::remove_dir_all(&path);
                 }
@@ -266,9 +479,61 @@ impl ModelManager {
         });
     }
 
+    fn migrate_install_layout(&self) {
+        for asset in &self.assets {
+            if asset.system_provided {
+                continue;
+            }
+            let install_dir = asset.install_dir(&self.root);
+            if let Err(error) = revisions::migrate_flat_install(&install_dir) {
+                tracing::warn!(
+                    "Failed to migrate {} to versioned layout: {error:?}",
+                    asset.name
+                );
+            }
+        }
+    }
+
     fn reconcile_on_disk_state(&mut self) {
         let root = self.root.clone();
+        let system_roots = self.system_roots.clone();
         for asset in &mut self.assets {
+            let system_path = system_roots
+                .iter()
+                .map(|system_root| asset.system_path(system_root))
+                .find(|path| path.exists() && looks_installed(&asset.kind, path));
+            if let Some(path) = system_path {
+                if !asset.system_provided || !matches!(asset.status, ModelStatus::Installed) {
+                    asset.set_size_bytes(total_size(&path));
+                }
+                asset.system_provided = true;
+                asset.status = ModelStatus::Installed;
+                continue;
+            }
+
+            if asset.system_provided {
+                // The shared copy was removed; fall back to the per-user directory.
+                asset.system_provided = false;
+                asset.status = ModelStatus::NotInstalled;
+            }
+
+            if asset.linked_path.is_some() {
+                let present = looks_installed(&asset.kind, &asset.path(&root));
+                if !present && matches!(asset.status, ModelStatus::Installed) {
+                    tracing::warn!(
+                        "Linked model {} is unavailable at {:?}",
+                        asset.name,
+                        asset.linked_path
+                    );
+                }
+                asset.status = if present {
+                    ModelStatus::Installed
+                } else {
+                    ModelStatus::NotInstalled
+                };
+                continue;
+            }
+
             if matches!(asset.status, ModelStatus::Installed) {
                 continue;
             }
@@ -278,23 +543,13 @@ impl ModelManager {
                 continue;
             }
 
-            let looks_installed = match asset.kind {
-                ModelKind::Vad => find_first_with_extension(&path, "onnx").is_some(),
-                ModelKind::WhisperCt2 => find_first_with_name(&path, "model.bin").is_some(),
-                ModelKind::WhisperOnnx | ModelKind::Parakeet => {
-                    find_tokens_file(&path).is_some()
-                        || find_first_with_extension(&path, "onnx").is_some()
-                }
-                _ => true,
-            };
-
-            if !looks_installed {
+            if !looks_installed(&asset.kind, &path) {
                 continue;
             }
 
             // Best-effort: set checksum from a representative file.
             match asset.kind {
-                ModelKind::Vad => {
+                ModelKind::Vad | ModelKind::Speaker => {
                     if let Some(model) = find_first_with_extension(&path, "onnx") {
                         let _ = asset.update_from_file(model);
                     }
@@ -318,6 +573,39 @@ impl ModelManager {
     }
 }
 
+fn looks_installed(kind: &ModelKind, path: &Path) -> bool {
+    match kind {
+        ModelKind::Vad | ModelKind::Speaker => find_first_with_extension(path, "onnx").is_some(),
+        ModelKind::GpuRuntime => find_first_with_extension(path, ".so").is_some(),
+        ModelKind::WhisperCt2 => find_first_with_name(path, "model.bin").is_some(),
+        ModelKind::WhisperCpp => {
+            find_first_with_extension(path, ".bin").is_some()
+                || find_first_with_extension(path, ".gguf").is_some()
+        }
+        ModelKind::WhisperOnnx | ModelKind::Parakeet => {
+            find_tokens_file(path).is_some() || find_first_with_extension(path, "onnx").is_some()
+        }
+        _ => true,
+    }
+}
+
+/// Read-only counterpart of the checks a download goes through; linked
+/// directories may not be writable, so nothing is fixed up in place.
+fn validate_external_layout(kind: &ModelKind, dir: &Path) -> Result<()> {
+    if matches!(kind, ModelKind::WhisperCt2) {
+        for required in ["model.bin", "config.json", "tokenizer.json"] {
+            if !dir.join(required).is_file() {
+                anyhow::bail!("{} is missing {required}", dir.display());
+            }
+        }
+        return Ok(());
+    }
+    if !looks_installed(kind, dir) {
+        anyhow::bail!("{} does not contain a {kind:?} model", dir.display());
+    }
+    Ok(())
+}
+
 fn find_tokens_file(dir: &Path) -> Option<PathBuf> {
     let default = dir.join("tokens.txt");
     if default.exists() {
@@ -380,10 +668,25 @@ fn resolve_model_dir() -> Result<PathBuf> {
     Ok(dir)
 }
 
+/// Read-only model directories that admins can pre-provision for all users.
+///
+/// `OPENFLOW_SYSTEM_MODELS_DIR` (colon-separated) replaces the built-in defaults.
+fn resolve_system_model_dirs() -> Vec<PathBuf> {
+    let candidates: Vec<PathBuf> = match std::env::var_os("OPENFLOW_SYSTEM_MODELS_DIR") {
+        Some(value) => std::env::split_paths(&value).collect(),
+        None => vec![
+            PathBuf::from("/usr/share/openflow/models"),
+            PathBuf::from("/opt/openflow/models"),
+        ],
+    };
+    candidates.into_iter().filter(|dir| dir.is_dir()).collect()
+}
+
 fn default_assets() -> Vec<ModelAsset> {
     let mut assets = Vec::new();
     assets.extend(default_whisper_ct2_assets());
     assets.extend(default_whisper_onnx_assets());
+    assets.extend(default_whisper_cpp_assets());
     assets.push(ModelAsset {
         kind: ModelKind::Parakeet,
         name: "parakeet-tdt-0.6b-v2-int8".into(),
@@ -391,6 +694,11 @@ fn default_assets() -> Vec<ModelAsset> {
         checksum: None,
         size_bytes: 0,
         status: ModelStatus::NotInstalled,
+        system_provided: false,
+        revisions: ModelRevisions::default(),
+        linked_path: None,
+        info: None,
+        unknown_fields: Default::default(),
         source: Some(ModelSource::Archive(ModelArchiveSource {
             uri: "https://github.com/k2-fsa/sherpa-onnx/releases/download/asr-models/sherpa-onnx-nemo-parakeet-tdt-0.6b-v2-int8.tar.bz2"
                 .into(),
@@ -405,12 +713,56 @@ fn default_assets() -> Vec<ModelAsset> {
         checksum: None,
         size_bytes: 0,
         status: ModelStatus::NotInstalled,
+        system_provided: false,
+        revisions: ModelRevisions::default(),
+        linked_path: None,
+        info: None,
+        unknown_fields: Default::default(),
         source: Some(ModelSource::Archive(ModelArchiveSource {
             uri: "https://raw.githubusercontent.com/snakers4/silero-vad/master/src/silero_vad/data/silero_vad.onnx".into(),
             archive_format: ArchiveFormat::File,
             strip_prefix_components: 0,
         })),
     });
+    assets.push(ModelAsset {
+        kind: ModelKind::Speaker,
+        name: "speaker-ecapa-onnx".into(),
+        version: "voxceleb".into(),
+        checksum: None,
+        size_bytes: 0,
+        status: ModelStatus::NotInstalled,
+        system_provided: false,
+        revisions: ModelRevisions::default(),
+        linked_path: None,
+        info: None,
+        unknown_fields: Default::default(),
+        source: Some(ModelSource::Archive(ModelArchiveSource {
+            uri: "https://github.com/k2-fsa/sherpa-onnx/releases/download/speaker-recongition-models/3dspeaker_speech_ecapa_tdnn_sv_en_voxceleb_16k.onnx".into(),
+            archive_format: ArchiveFormat::File,
+            strip_prefix_components: 0,
+        })),
+    });
+    assets.push(ModelAsset {
+        kind: ModelKind::GpuRuntime,
+        name: "onnxruntime-gpu-cuda12".into(),
+        version: "1.17.1".into(),
+        checksum: None,
+        size_bytes: 0,
+        status: ModelStatus::NotInstalled,
+        system_provided: false,
+        revisions: ModelRevisions::default(),
+        linked_path: None,
+        info: None,
+        unknown_fields: Default::default(),
+        source: Some(ModelSource::Archive(ModelArchiveSource {
+            uri: "https://github.com/microsoft/onnxruntime/releases/download/v1.17.1/onnxruntime-linux-x64-gpu-cuda12-1.17.1.tgz".into(),
+            archive_format: ArchiveFormat::TarGz,
+            strip_prefix_components: 1,
+        })),
+    });
+    for asset in &mut assets {
+        asset.info = catalog::info_for(&asset.name);
+    }
     assets
 }
 
@@ -440,6 +792,11 @@ fn default_whisper_ct2_assets() -> Vec<ModelAsset> {
             checksum: None,
             size_bytes: 0,
             status: ModelStatus::NotInstalled,
+            system_provided: false,
+            revisions: ModelRevisions::default(),
+            linked_path: None,
+            info: None,
+            unknown_fields: Default::default(),
             source: Some(ModelSource::HfRepo(ModelHfSource {
                 repo,
                 revision: None,
@@ -456,6 +813,11 @@ fn default_whisper_ct2_assets() -> Vec<ModelAsset> {
                 checksum: None,
                 size_bytes: 0,
                 status: ModelStatus::NotInstalled,
+                system_provided: false,
+                revisions: ModelRevisions::default(),
+                linked_path: None,
+                info: None,
+                unknown_fields: Default::default(),
                 source: Some(ModelSource::HfRepo(ModelHfSource {
                     repo: format!("Systran/faster-whisper-{size}.en"),
                     revision: None,
@@ -524,6 +886,55 @@ fn default_whisper_onnx_assets() -> Vec<ModelAsset> {
     assets
 }
 
+fn default_whisper_cpp_assets() -> Vec<ModelAsset> {
+    // (size, has English-only variant, quantization published upstream)
+    let sizes = [
+        ("tiny", true, "q5_1"),
+        ("base", true, "q5_1"),
+        ("small", true, "q5_1"),
+        ("medium", true, "q5_0"),
+        ("large-v3", false, "q5_0"),
+        ("large-v3-turbo", false, "q5_0"),
+    ];
+
+    let mut assets = Vec::new();
+    for (size, has_en, quantization) in sizes {
+        let variants: &[(&str, &str)] = if has_en {
+            &[("", ""), ("-en", ".en")]
+        } else {
+            &[("", "")]
+        };
+        for (name_suffix, file_suffix) in variants {
+            for (precision, file) in [
+                ("float", format!("ggml-{size}{file_suffix}.bin")),
+                ("q5", format!("ggml-{size}{file_suffix}-{quantization}.bin")),
+            ] {
+                assets.push(ModelAsset {
+                    kind: ModelKind::WhisperCpp,
+                    name: format!("whisper-cpp-{size}{name_suffix}-{precision}"),
+                    version: "main".into(),
+                    checksum: None,
+                    size_bytes: 0,
+                    status: ModelStatus::NotInstalled,
+                    system_provided: false,
+                    revisions: ModelRevisions::default(),
+                    linked_path: None,
+                    info: None,
+                    unknown_fields: Default::default(),
+                    source: Some(ModelSource::HfRepo(ModelHfSource {
+                        repo: "ggerganov/whisper.cpp".into(),
+                        revision: None,
+                        include: vec![file],
+                        exclude: Vec::new(),
+                    })),
+                });
+            }
+        }
+    }
+
+    assets
+}
+
 fn build_onnx_whisper_asset(
     name: String,
     repo: String,
@@ -537,6 +948,11 @@ fn build_onnx_whisper_asset(
         checksum: None,
         size_bytes: 0,
         status: ModelStatus::NotInstalled,
+        system_provided: false,
+        revisions: ModelRevisions::default(),
+        linked_path: None,
+        info: None,
+        unknown_fields: Default::default(),
         source: Some(ModelSource::HfRepo(ModelHfSource {
             repo,
             revision: None,
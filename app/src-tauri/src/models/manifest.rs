@@ -0,0 +1,183 @@
+//! On-disk format of `manifest.json`.
+//!
+//! The manifest is `{"schemaVersion": N, "assets": [...]}`; version 1 was a bare
+//! array of assets. Older files are migrated step by step on read. Files written
+//! by a newer OpenFlow are read best-effort: top-level fields, asset fields and
+//! whole asset entries this build does not understand are kept and written back
+//! unchanged, so a downgrade followed by an upgrade loses nothing.
+
+use std::fs::{self, File};
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use serde_json::{Map, Value};
+
+use super::manager::{ModelAsset, ModelKind};
+
+pub const MANIFEST_SCHEMA_VERSION: u32 = 2;
+
+/// Step `i` migrates a document from version `i + 1` to `i + 2`.
+const MIGRATIONS: &[fn(&mut Map<String, Value>) -> Result<()>] = &[
+    // 1 -> 2: the bare array became `{schemaVersion, assets}`; entries are unchanged.
+    |_| Ok(()),
+];
+
+/// What a manifest held beyond the assets this build understands.
+#[derive(Debug, Clone, Default)]
+pub struct Passthrough {
+    /// Version to write back: never lower than the version read.
+    pub schema_version: u32,
+    pub fields: Map<String, Value>,
+    pub assets: Vec<Value>,
+}
+
+#[derive(Debug, Default)]
+pub struct ManifestFile {
+    pub assets: Vec<ModelAsset>,
+    pub passthrough: Passthrough,
+}
+
+pub fn read(path: &Path) -> Result<ManifestFile> {
+    let file = File::open(path).context("open model manifest")?;
+    let value: Value = serde_json::from_reader(file).context("parse model manifest")?;
+    let (found, mut document) = upgrade(value)?;
+    if found > MANIFEST_SCHEMA_VERSION {
+        tracing::warn!(
+            "{} uses manifest schema {found}, newer than {MANIFEST_SCHEMA_VERSION}; \
+             preserving fields this version does not know",
+            path.display()
+        );
+    }
+
+    document.remove("schemaVersion");
+    let entries = match document.remove("assets") {
+        Some(Value::Array(entries)) => entries,
+        None => Vec::new(),
+        Some(_) => return Err(anyhow!("model manifest assets is not an array")),
+    };
+
+    let mut manifest = ManifestFile {
+        assets: Vec::with_capacity(entries.len()),
+        passthrough: Passthrough {
+            schema_version: found.max(MANIFEST_SCHEMA_VERSION),
+            fields: document,
+            assets: Vec::new(),
+        },
+    };
+    for entry in entries {
+        // Unknown kinds from older versions are purged by the manager; from a
+        // newer version they are a model this build cannot use yet.
+        let unknown_kind = entry
+            .get("kind")
+            .cloned()
+            .and_then(|kind| serde_json::from_value::<ModelKind>(kind).ok())
+            .is_some_and(|kind| kind == ModelKind::Unknown);
+        if unknown_kind && found > MANIFEST_SCHEMA_VERSION {
+            manifest.passthrough.assets.push(entry);
+            continue;
+        }
+        match serde_json::from_value::<ModelAsset>(entry.clone()) {
+            Ok(asset) => manifest.assets.push(asset),
+            Err(error) => {
+                tracing::warn!("Keeping unreadable model manifest entry as is: {error}");
+                manifest.passthrough.assets.push(entry);
+            }
+        }
+    }
+    Ok(manifest)
+}
+
+pub fn write(path: &Path, assets: &[ModelAsset], passthrough: &Passthrough) -> Result<()> {
+    let mut entries = Vec::with_capacity(assets.len() + passthrough.assets.len());
+    for asset in assets {
+        entries.push(serde_json::to_value(asset).context("serialize model asset")?);
+    }
+    entries.extend(passthrough.assets.iter().cloned());
+
+    let mut document = passthrough.fields.clone();
+    document.insert(
+        "schemaVersion".into(),
+        passthrough
+            .schema_version
+            .max(MANIFEST_SCHEMA_VERSION)
+            .into(),
+    );
+    document.insert("assets".into(), Value::Array(entries));
+
+    let bytes = serde_json::to_vec_pretty(&document).context("write model manifest")?;
+    fs::write(path, bytes).context("create model manifest")
+}
+
+/// Brings a document up to the current schema. Returns the version it was
+/// written with alongside the migrated document.
+fn upgrade(value: Value) -> Result<(u32, Map<String, Value>)> {
+    let mut document = match value {
+        Value::Array(assets) => {
+            let mut document = Map::new();
+            document.insert("schemaVersion".into(), 1.into());
+            document.insert("assets".into(), Value::Array(assets));
+            document
+        }
+        Value::Object(document) => document,
+        _ => return Err(anyhow!("model manifest is neither an object nor an array")),
+    };
+    let found = document
+        .get("schemaVersion")
+        .and_then(Value::as_u64)
+        .map_or(1, |version| version.max(1) as u32);
+
+    for (step, migrate) in MIGRATIONS
+        .iter()
+        .enumerate()
+        .skip(found as usize - 1)
+        .take(MANIFEST_SCHEMA_VERSION.saturating_sub(found) as usize)
+    {
+        migrate(&mut document)
+            .with_context(|| format!("migrate model manifest from schema {}", step + 1))?;
+    }
+    Ok((found, document))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn newer_schema_round_trips_unknown_state() {
+        let path =
+            std::env::temp_dir().join(format!("openflow-manifest-{}.json", uuid::Uuid::new_v4()));
+        fs::write(
+            &path,
+            r#"{
+                "schemaVersion": 99,
+                "pinned": ["vad"],
+                "assets": [
+                    {"kind": "vad", "name": "vad", "version": "v6", "status": "installed",
+                     "lastUsedAt": 1700000000},
+                    {"kind": "future-kind", "name": "future", "version": "1", "status": "installed"}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let manifest = read(&path).unwrap();
+        assert_eq!(manifest.assets.len(), 1);
+        write(&path, &manifest.assets, &manifest.passthrough).unwrap();
+
+        let written: Value = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+        assert_eq!(written["schemaVersion"], 99);
+        assert_eq!(written["pinned"][0], "vad");
+        assert_eq!(written["assets"][0]["lastUsedAt"], 1700000000);
+        assert_eq!(written["assets"][1]["kind"], "future-kind");
+
+        fs::write(
+            &path,
+            r#"[{"kind": "vad", "name": "vad", "version": "v6", "status": "notInstalled"}]"#,
+        )
+        .unwrap();
+        let legacy = read(&path).unwrap();
+        assert_eq!(legacy.assets.len(), 1);
+        assert_eq!(legacy.passthrough.schema_version, MANIFEST_SCHEMA_VERSION);
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -1,16 +1,31 @@
+mod catalog;
 mod ct2;
 mod download;
+mod lock;
 mod manager;
+mod manifest;
 mod metadata;
+mod rate;
+mod revisions;
 mod service;
+mod staging;
 
+#[allow(unused_imports)]
+pub use catalog::{ModelFilter, ModelInfo, QualityTier};
 pub use ct2::prepare_ct2_model_dir;
 #[allow(unused_imports)]
 pub use download::{
-    download_and_extract_with_progress, plan_for as build_download_plan, DownloadOutcome,
-    DownloadPlan, DownloadProgress,
+    download_and_extract_with_progress, plan_for as build_download_plan, DownloadInterrupt,
+    DownloadOutcome, DownloadPlan, DownloadProgress,
 };
 #[allow(unused_imports)]
-pub use manager::{ArchiveFormat, ModelAsset, ModelKind, ModelManager, ModelSource, ModelStatus};
-pub use metadata::compute_sha256;
-pub use service::{sync_runtime_environment, ModelDownloadJob, ModelDownloadService};
+pub use manager::{
+    ArchiveFormat, ModelAsset, ModelKind, ModelManager, ModelRevisions, ModelSource, ModelStatus,
+};
+pub use metadata::{compute_sha256, total_size};
+pub use rate::TransferRate;
+pub use service::{
+    check_model_updates, clear_download_staging, rollback_model_update, spawn_update_checker,
+    sync_runtime_environment, DownloadPriority, ModelDownloadJob, ModelDownloadService,
+    QueuedDownload, QueuedDownloadKey, StagingCleanup,
+};
@@ -0,0 +1,85 @@
+//! Smoothed transfer speed for download progress.
+//!
+//! Progress callbacks fire per chunk, so the raw rate jitters wildly. Samples are
+//! taken at most every [`SAMPLE_INTERVAL`] and folded into an exponential moving
+//! average; the ETA is derived from that average.
+
+use std::time::{Duration, Instant};
+
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(250);
+/// Weight of the newest sample; lower is smoother but slower to react.
+const SMOOTHING: f64 = 0.3;
+
+#[derive(Debug, Default)]
+pub struct TransferRate {
+    last: Option<(Instant, u64)>,
+    bytes_per_sec: Option<f64>,
+}
+
+impl TransferRate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Folds in the cumulative byte count observed now.
+    pub fn record(&mut self, downloaded: u64) {
+        self.record_at(Instant::now(), downloaded);
+    }
+
+    fn record_at(&mut self, now: Instant, downloaded: u64) {
+        let Some((since, base)) = self.last else {
+            // The first sample only sets the baseline; resumed downloads start
+            // with bytes that were not transferred in this session.
+            self.last = Some((now, downloaded));
+            return;
+        };
+        let elapsed = now.duration_since(since);
+        if elapsed < SAMPLE_INTERVAL {
+            return;
+        }
+        let instant = downloaded.saturating_sub(base) as f64 / elapsed.as_secs_f64();
+        self.bytes_per_sec = Some(match self.bytes_per_sec {
+            Some(average) => average + SMOOTHING * (instant - average),
+            None => instant,
+        });
+        self.last = Some((now, downloaded));
+    }
+
+    pub fn bytes_per_sec(&self) -> Option<u64> {
+        self.bytes_per_sec.map(|rate| rate.round() as u64)
+    }
+
+    /// Seconds until `total` at the current rate; `None` while the rate or size is unknown.
+    pub fn eta_secs(&self, downloaded: u64, total: Option<u64>) -> Option<u64> {
+        let total = total.filter(|total| *total > 0)?;
+        let rate = self.bytes_per_sec.filter(|rate| *rate >= 1.0)?;
+        let remaining = total.saturating_sub(downloaded) as f64;
+        Some((remaining / rate).ceil() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smooths_rate_and_estimates_remaining_time() {
+        let start = Instant::now();
+        let mut rate = TransferRate::new();
+        rate.record_at(start, 1_000);
+        assert_eq!(rate.bytes_per_sec(), None);
+
+        // Too soon after the baseline to count as a sample.
+        rate.record_at(start + Duration::from_millis(100), 5_000);
+        assert_eq!(rate.bytes_per_sec(), None);
+
+        rate.record_at(start + Duration::from_secs(1), 11_000);
+        assert_eq!(rate.bytes_per_sec(), Some(10_000));
+        assert_eq!(rate.eta_secs(11_000, Some(61_000)), Some(5));
+
+        // A burst moves the average only part of the way.
+        rate.record_at(start + Duration::from_secs(2), 41_000);
+        assert_eq!(rate.bytes_per_sec(), Some(16_000));
+        assert_eq!(rate.eta_secs(41_000, None), None);
+    }
+}
@@ -0,0 +1,240 @@
+//! Versioned install layout for downloaded models.
+//!
+//! Every download lands in its own `<install>/revisions/<id>` directory and
+//! `<install>/current` is a symlink that is only flipped (atomically, via rename)
+//! once that revision is complete. The replaced revision stays behind
+//! `<install>/previous` for rollback, so an interrupted or failed update never
+//! touches the files the pipeline has open.
+
+use std::fs;
+use std::os::unix::fs::symlink;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use time::OffsetDateTime;
+
+pub const CURRENT_LINK: &str = "current";
+pub const PREVIOUS_LINK: &str = "previous";
+const REVISIONS_DIR: &str = "revisions";
+/// Sibling directories used by the in-place update scheme this layout replaced.
+const LEGACY_PREVIOUS_SUFFIX: &str = ".previous";
+const LEGACY_UPDATE_SUFFIX: &str = ".update";
+
+/// A fresh, not yet created directory for the next revision under `install_dir`.
+pub fn new_revision_dir(install_dir: &Path) -> PathBuf {
+    let revisions = install_dir.join(REVISIONS_DIR);
+    let stamp = OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000;
+    let mut candidate = revisions.join(stamp.to_string());
+    let mut attempt = 1;
+    while candidate.exists() {
+        candidate = revisions.join(format!("{stamp}-{attempt}"));
+        attempt += 1;
+    }
+    candidate
+}
+
+/// The install directory a revision directory belongs to.
+pub fn install_dir_of(revision_dir: &Path) -> Option<&Path> {
+    revision_dir
+        .parent()
+        .filter(|parent| parent.file_name().is_some_and(|name| name == REVISIONS_DIR))
+        .and_then(Path::parent)
+}
+
+/// Makes `revision_dir` current, keeping the replaced revision as `previous` and
+/// deleting the one before it. Returns the `current` link path.
+pub fn activate(revision_dir: &Path) -> Result<PathBuf> {
+    let install_dir = install_dir_of(revision_dir)
+        .ok_or_else(|| anyhow!("{} is not a model revision", revision_dir.display()))?;
+    let replaced = link_target(install_dir, CURRENT_LINK);
+    let discarded = link_target(install_dir, PREVIOUS_LINK);
+
+    point_link(install_dir, CURRENT_LINK, revision_dir)?;
+    // From here on the new revision is live; bookkeeping failures only cost rollback.
+    if let Some(replaced) = replaced.filter(|replaced| replaced != revision_dir) {
+        if let Err(error) = point_link(install_dir, PREVIOUS_LINK, &replaced) {
+            tracing::warn!("Failed to keep {replaced:?} for rollback: {error:?}");
+            return Ok(install_dir.join(CURRENT_LINK));
+        }
+        // Linked external directories are never ours to delete.
+        if let Some(discarded) =
+            discarded.filter(|d| d != &replaced && d != revision_dir && d.starts_with(install_dir))
+        {
+            if let Err(error) = fs::remove_dir_all(&discarded) {
+                tracing::debug!("Failed to prune model revision {discarded:?}: {error}");
+            }
+        }
+    }
+    Ok(install_dir.join(CURRENT_LINK))
+}
+
+/// Swaps `current` and `previous`. Returns the `current` link path.
+pub fn rollback(install_dir: &Path) -> Result<PathBuf> {
+    let previous = link_target(install_dir, PREVIOUS_LINK)
+        .filter(|path| path.is_dir())
+        .ok_or_else(|| anyhow!("no earlier revision to restore"))?;
+    let current = link_target(install_dir, CURRENT_LINK);
+
+    point_link(install_dir, CURRENT_LINK, &previous)?;
+    match current {
+        Some(current) => point_link(install_dir, PREVIOUS_LINK, &current)?,
+        None => {
+            let _ = fs::remove_file(install_dir.join(PREVIOUS_LINK));
+        }
+    }
+    Ok(install_dir.join(CURRENT_LINK))
+}
+
+/// Points `current` at a directory outside the install, e.g. a shared or manually
+/// downloaded model. A downloaded revision it replaces is kept as `previous`.
+/// Returns the `current` link path.
+pub fn link_external(install_dir: &Path, target: &Path) -> Result<PathBuf> {
+    if target.starts_with(install_dir) {
+        return Err(anyhow!("{} is inside the model install", target.display()));
+    }
+    fs::create_dir_all(install_dir).context("create model install directory")?;
+    let replaced = link_target(install_dir, CURRENT_LINK);
+
+    point_link(install_dir, CURRENT_LINK, target)?;
+    if let Some(replaced) = replaced.filter(|replaced| replaced.starts_with(install_dir)) {
+        if let Err(error) = point_link(install_dir, PREVIOUS_LINK, &replaced) {
+            tracing::warn!("Failed to keep {replaced:?} for rollback: {error:?}");
+        }
+    }
+    Ok(install_dir.join(CURRENT_LINK))
+}
+
+/// The directory `current` points at when it lies outside the install.
+pub fn external_target(install_dir: &Path) -> Option<PathBuf> {
+    link_target(install_dir, CURRENT_LINK).filter(|target| !target.starts_with(install_dir))
+}
+
+pub fn revisions_dir(install_dir: &Path) -> PathBuf {
+    install_dir.join(REVISIONS_DIR)
+}
+
+/// Revisions a link points at; every other entry under `revisions/` is garbage.
+pub fn live_revisions(install_dir: &Path) -> Vec<PathBuf> {
+    [CURRENT_LINK, PREVIOUS_LINK]
+        .into_iter()
+        .filter_map(|name| link_target(install_dir, name))
+        .collect()
+}
+
+/// Links `point_link` stages before renaming them into place.
+pub fn staged_links(install_dir: &Path) -> Vec<PathBuf> {
+    [CURRENT_LINK, PREVIOUS_LINK]
+        .into_iter()
+        .map(|name| install_dir.join(format!(".{name}.tmp")))
+        .collect()
+}
+
+pub fn has_previous(install_dir: &Path) -> bool {
+    link_target(install_dir, PREVIOUS_LINK).is_some_and(|path| path.is_dir())
+}
+
+/// Moves a flat install (model files directly in `install_dir`) and any rollback
+/// copy left by the old in-place updater into the revision layout.
+pub fn migrate_flat_install(install_dir: &Path) -> Result<()> {
+    let update = sibling_path(install_dir, LEGACY_UPDATE_SUFFIX);
+    if update.exists() {
+        let _ = fs::remove_dir_all(&update);
+    }
+
+    let is_flat = install_dir.is_dir()
+        && !install_dir.join(REVISIONS_DIR).exists()
+        && fs::symlink_metadata(install_dir.join(CURRENT_LINK)).is_err();
+    if is_flat {
+        let moving = sibling_path(install_dir, ".migrating");
+        fs::rename(install_dir, &moving).context("set aside flat model install")?;
+        fs::create_dir_all(install_dir.join(REVISIONS_DIR))
+            .context("create model revisions directory")?;
+        let revision = new_revision_dir(install_dir);
+        fs::rename(&moving, &revision).context("move model install into revision")?;
+        point_link(install_dir, CURRENT_LINK, &revision)?;
+        tracing::info!("Migrated {install_dir:?} to versioned layout");
+    }
+
+    let previous = sibling_path(install_dir, LEGACY_PREVIOUS_SUFFIX);
+    if previous.is_dir() && install_dir.join(REVISIONS_DIR).is_dir() {
+        let revision = new_revision_dir(install_dir);
+        fs::rename(&previous, &revision).context("move rollback copy into revision")?;
+        point_link(install_dir, PREVIOUS_LINK, &revision)?;
+    }
+    Ok(())
+}
+
+fn link_target(install_dir: &Path, name: &str) -> Option<PathBuf> {
+    let target = fs::read_link(install_dir.join(name)).ok()?;
+    Some(install_dir.join(target))
+}
+
+/// Repoints `install_dir/<name>` at `target` with a rename, so readers see either
+/// the old or the new revision and never a missing link.
+fn point_link(install_dir: &Path, name: &str, target: &Path) -> Result<()> {
+    let relative = target.strip_prefix(install_dir).unwrap_or(target);
+    let staged = install_dir.join(format!(".{name}.tmp"));
+    if fs::symlink_metadata(&staged).is_ok() {
+        fs::remove_file(&staged).context("remove stale revision link")?;
+    }
+    symlink(relative, &staged).with_context(|| format!("link {}", staged.display()))?;
+    fs::rename(&staged, install_dir.join(name))
+        .with_context(|| format!("switch {name} model revision"))
+}
+
+/// `path` with `suffix` appended to its final component (not an extension swap, since
+/// asset directory names contain dots).
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn activate_keeps_one_previous_and_rolls_back() {
+        let install =
+            std::env::temp_dir().join(format!("openflow-revisions-{}", uuid::Uuid::new_v4()));
+        let mut revisions = Vec::new();
+        for label in ["a", "b", "c"] {
+            let revision = new_revision_dir(&install);
+            fs::create_dir_all(&revision).unwrap();
+            fs::write(revision.join("model.onnx"), label).unwrap();
+            activate(&revision).unwrap();
+            revisions.push(revision);
+        }
+
+        let current = install.join(CURRENT_LINK);
+        assert_eq!(fs::read_to_string(current.join("model.onnx")).unwrap(), "c");
+        assert!(!revisions[0].exists(), "oldest revision should be pruned");
+
+        rollback(&install).unwrap();
+        assert_eq!(fs::read_to_string(current.join("model.onnx")).unwrap(), "b");
+        rollback(&install).unwrap();
+        assert_eq!(fs::read_to_string(current.join("model.onnx")).unwrap(), "c");
+        let _ = fs::remove_dir_all(&install);
+    }
+
+    #[test]
+    fn linked_directory_is_never_pruned() {
+        let root = std::env::temp_dir().join(format!("openflow-linked-{}", uuid::Uuid::new_v4()));
+        let install = root.join("whisper-small-1.0");
+        let external = root.join("shared-model");
+        fs::create_dir_all(&external).unwrap();
+        fs::write(external.join("model.bin"), b"shared").unwrap();
+
+        link_external(&install, &external).unwrap();
+        assert_eq!(external_target(&install), Some(external.clone()));
+        for _ in 0..2 {
+            let revision = new_revision_dir(&install);
+            fs::create_dir_all(&revision).unwrap();
+            activate(&revision).unwrap();
+        }
+        assert!(external.join("model.bin").is_file());
+        assert_eq!(external_target(&install), None);
+        let _ = fs::remove_dir_all(&root);
+    }
+}
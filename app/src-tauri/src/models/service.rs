@@ -1,124 +1,500 @@
 use std::{
+    cmp::Reverse,
     fs,
     path::{Path, PathBuf},
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
     thread,
     time::{Duration, Instant},
 };
 
 use anyhow::{anyhow, Context, Result};
-use crossbeam_channel::{unbounded, Receiver, Sender};
+use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Manager};
 
-use crate::core::{app_state::AppState, events};
+use crate::core::{app_state::AppState, events, i18n};
+use crate::output::tray::{self, TrayBadge};
+use crate::vad::SPEAKER_MODEL_ENV;
 
+use super::download::fetch_latest_revision;
+use super::lock::AssetLock;
+use super::revisions;
 use super::{
-    build_download_plan, download_and_extract_with_progress, DownloadOutcome, DownloadProgress,
-    ModelAsset, ModelKind, ModelManager, ModelStatus,
+    build_download_plan, download_and_extract_with_progress, DownloadInterrupt, DownloadOutcome,
+    DownloadPlan, DownloadProgress, ModelAsset, ModelKind, ModelManager, ModelStatus, TransferRate,
 };
 
 use super::metadata::total_size;
+use super::staging;
+
+/// Result of clearing download staging.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StagingCleanup {
+    pub removed: usize,
+    pub reclaimed_bytes: u64,
+    /// Assets left alone because a download for them is running.
+    pub skipped: Vec<String>,
+}
+
+/// Queued jobs run highest priority first; a job that outranks the running
+/// download pauses it, unless that download can't resume (tar archives).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DownloadPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
 
 #[derive(Debug, Clone)]
 pub struct ModelDownloadJob {
     pub asset_name: String,
+    /// Re-download an installed asset as a new revision and switch to it.
+    pub update: bool,
+    pub priority: DownloadPriority,
+}
+
+/// A download waiting for the worker, as shown in the queue.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedDownload {
+    pub asset_name: String,
+    pub update: bool,
+    pub priority: DownloadPriority,
+    /// Paused for a higher-priority download; it resumes where it stopped.
+    pub paused: bool,
+}
+
+/// Identifies a waiting download when reordering the queue.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedDownloadKey {
+    pub asset_name: String,
+    pub update: bool,
+}
+
+#[derive(Debug)]
+struct QueuedJob {
+    job: ModelDownloadJob,
+    /// Plan of a paused download, reused so its partial files are picked up.
+    resume: Option<DownloadPlan>,
+}
+
+#[derive(Debug)]
+struct RunningJob {
+    asset_name: String,
+    update: bool,
+    priority: DownloadPriority,
+    /// Whether pausing keeps its progress. Tar archives extract while they stream and
+    /// would start over, so they are never paused; unknown until the plan is built.
+    preemptible: bool,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    jobs: Vec<QueuedJob>,
+    running: Option<RunningJob>,
+}
+
+#[derive(Debug, Default)]
+struct JobQueue {
+    state: parking_lot::Mutex<QueueState>,
+    available: parking_lot::Condvar,
+    /// Pauses the running download so an outranking job can go first, or cancels it.
+    interrupt: DownloadInterrupt,
+}
+
+impl JobQueue {
+    /// Next job to run, waiting for one; None once the service stopped.
+    fn next(&self, stopped: &AtomicBool) -> Option<QueuedJob> {
+        let mut state = self.state.lock();
+        loop {
+            if stopped.load(Ordering::SeqCst) {
+                return None;
+            }
+            let best = state
+                .jobs
+                .iter()
+                .enumerate()
+                .max_by_key(|(index, queued)| (queued.job.priority, Reverse(*index)))
+                .map(|(index, _)| index);
+            if let Some(index) = best {
+                let queued = state.jobs.remove(index);
+                state.running = Some(RunningJob {
+                    asset_name: queued.job.asset_name.clone(),
+                    update: queued.job.update,
+                    priority: queued.job.priority,
+                    preemptible: queued.resume.is_some(),
+                });
+                self.interrupt.reset();
+                return Some(queued);
+            }
+            self.available.wait(&mut state);
+        }
+    }
+
+    fn finish(&self) {
+        self.state.lock().running = None;
+    }
+
+    /// Records whether the running download can be paused without losing progress,
+    /// and pauses it now if a job that arrived meanwhile outranks it.
+    fn set_preemptible(&self, preemptible: bool) {
+        let mut state = self.state.lock();
+        if let Some(running) = state.running.as_mut() {
+            running.preemptible = preemptible;
+        }
+        self.preempt_if_outranked(&state);
+    }
+
+    /// Drops the waiting jobs for `asset_name` and interrupts it if it is running.
+    /// Returns the dropped jobs and whether it was running.
+    fn cancel(&self, asset_name: &str) -> (Vec<QueuedJob>, bool) {
+        let mut state = self.state.lock();
+        let (cancelled, kept): (Vec<QueuedJob>, Vec<QueuedJob>) = std::mem::take(&mut state.jobs)
+            .into_iter()
+            .partition(|queued| queued.job.asset_name == asset_name);
+        state.jobs = kept;
+        // Checked under the queue lock, so the flag can't land on the next job.
+        let running = state
+            .running
+            .as_ref()
+            .is_some_and(|running| running.asset_name == asset_name);
+        if running {
+            self.interrupt.cancel.store(true, Ordering::SeqCst);
+        }
+        (cancelled, running)
+    }
+
+    fn preempt_if_outranked(&self, state: &QueueState) {
+        let Some(running) = state.running.as_ref().filter(|running| running.preemptible) else {
+            return;
+        };
+        if state
+            .jobs
+            .iter()
+            .any(|queued| queued.job.priority > running.priority)
+        {
+            self.interrupt.pause.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// Moves the given waiting downloads to the front in that order; the rest keep
+    /// their order behind them.
+    fn reorder(&self, order: &[QueuedDownloadKey]) {
+        let mut state = self.state.lock();
+        let mut jobs = std::mem::take(&mut state.jobs);
+        let mut reordered = Vec::with_capacity(jobs.len());
+        for key in order {
+            if let Some(index) = jobs.iter().position(|queued| {
+                queued.job.asset_name == key.asset_name && queued.job.update == key.update
+            }) {
+                reordered.push(jobs.remove(index));
+            }
+        }
+        reordered.extend(jobs);
+        state.jobs = reordered;
+    }
+
+    fn set_priority(&self, asset_name: &str, priority: DownloadPriority) {
+        let mut state = self.state.lock();
+        for queued in state
+            .jobs
+            .iter_mut()
+            .filter(|queued| queued.job.asset_name == asset_name)
+        {
+            queued.job.priority = priority;
+        }
+        self.preempt_if_outranked(&state);
+    }
+
+    fn snapshot(&self) -> Vec<QueuedDownload> {
+        self.state
+            .lock()
+            .jobs
+            .iter()
+            .map(|queued| QueuedDownload {
+                asset_name: queued.job.asset_name.clone(),
+                update: queued.job.update,
+                priority: queued.job.priority,
+                paused: queued.resume.is_some(),
+            })
+            .collect()
+    }
 }
 
+/// First check shortly after launch, then daily.
+const MODEL_UPDATE_FIRST_CHECK: Duration = Duration::from_secs(5 * 60);
+const MODEL_UPDATE_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
 #[derive(Debug)]
 pub struct ModelDownloadService {
-    sender: Sender<ModelDownloadJob>,
+    app: AppHandle,
+    queue: Arc<JobQueue>,
+    stopped: Arc<AtomicBool>,
 }
 
 impl Clone for ModelDownloadService {
     fn clone(&self) -> Self {
         Self {
-            sender: self.sender.clone(),
+            app: self.app.clone(),
+            queue: Arc::clone(&self.queue),
+            stopped: Arc::clone(&self.stopped),
         }
     }
 }
 
 impl ModelDownloadService {
     pub fn new(app: AppHandle, manager: Arc<Mutex<ModelManager>>) -> Result<Self> {
-        let (sender, receiver) = unbounded();
+        let queue = Arc::new(JobQueue::default());
         let models_dir = {
             let guard = manager.lock().map_err(|err| anyhow!(err.to_string()))?;
             guard.root().to_path_buf()
         };
-        thread::spawn(move || worker_loop(receiver, manager, models_dir, app));
-        Ok(Self { sender })
+        let stopped = Arc::new(AtomicBool::new(false));
+        let worker_stopped = Arc::clone(&stopped);
+        let worker_queue = Arc::clone(&queue);
+        let worker_app = app.clone();
+        thread::spawn(move || {
+            worker_loop(
+                worker_queue,
+                manager,
+                models_dir,
+                worker_app,
+                worker_stopped,
+            )
+        });
+        Ok(Self {
+            app,
+            queue,
+            stopped,
+        })
     }
 
+    /// Queues `job`; queuing an asset that is already waiting only raises its
+    /// priority.
     pub fn queue(&self, job: ModelDownloadJob) -> Result<()> {
-        self.sender
-            .send(job)
-            .context("send model download job to worker")
+        if self.stopped.load(Ordering::SeqCst) {
+            anyhow::bail!("model download service stopped");
+        }
+        {
+            let mut state = self.queue.state.lock();
+            let running = state.running.as_ref().is_some_and(|running| {
+                running.asset_name == job.asset_name && running.update == job.update
+            });
+            let waiting = state.jobs.iter_mut().find(|queued| {
+                queued.job.asset_name == job.asset_name && queued.job.update == job.update
+            });
+            match waiting {
+                Some(queued) => queued.job.priority = queued.job.priority.max(job.priority),
+                None if running => return Ok(()),
+                None => state.jobs.push(QueuedJob { job, resume: None }),
+            }
+            self.queue.preempt_if_outranked(&state);
+        }
+        self.queue.available.notify_one();
+        self.emit_queue();
+        Ok(())
+    }
+
+    /// Moves the given waiting downloads to the front in that order; the rest
+    /// keep their order behind them. Priorities still decide what runs next.
+    pub fn reorder(&self, order: &[QueuedDownloadKey]) {
+        self.queue.reorder(order);
+        self.emit_queue();
+    }
+
+    /// Changes the priority of a waiting download, pausing the running one if
+    /// it is now outranked and can resume.
+    pub fn set_priority(&self, asset_name: &str, priority: DownloadPriority) {
+        self.queue.set_priority(asset_name, priority);
+        self.emit_queue();
+    }
+
+    /// Cancels the download of `asset_name`, running, paused or waiting. Partial files
+    /// are removed and the asset goes back to what is on disk: `NotInstalled`, or
+    /// `Installed` for a cancelled update. Returns false if nothing was downloading it.
+    pub fn cancel(&self, manager: &Arc<Mutex<ModelManager>>, asset_name: &str) -> bool {
+        let (cancelled, running) = self.queue.cancel(asset_name);
+        if cancelled.is_empty() && !running {
+            return false;
+        }
+        tracing::info!(
+            "model_download_cancel asset={asset_name} running={running} queued={}",
+            cancelled.len()
+        );
+
+        // The worker cleans up after a running download; paused ones hold no lock and
+        // left their partial revision behind.
+        if !running {
+            for plan in cancelled.iter().filter_map(|queued| queued.resume.as_ref()) {
+                discard_paused(manager, asset_name, plan);
+            }
+            on_download_cancelled(manager, &self.app, asset_name);
+        }
+        self.emit_queue();
+        true
+    }
+
+    /// Downloads waiting behind the running one.
+    pub fn pending(&self) -> Vec<QueuedDownload> {
+        self.queue.snapshot()
+    }
+
+    fn emit_queue(&self) {
+        events::emit_model_download_queue(&self.app, self.queue.snapshot());
+    }
+
+    /// Stops the worker for app exit. The transfer in flight is abandoned with the
+    /// process, so assets still downloading are reset from what is on disk instead
+    /// of being left `Downloading` in the manifest; their staging is collected on
+    /// the next launch. Returns the reset assets.
+    pub fn shutdown(&self, manager: &Arc<Mutex<ModelManager>>) -> Vec<ModelAsset> {
+        self.stopped.store(true, Ordering::SeqCst);
+        self.queue.available.notify_all();
+        let mut guard = match manager.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let downloading: Vec<String> = guard
+            .assets()
+            .into_iter()
+            .filter(|asset| matches!(asset.status, ModelStatus::Downloading { .. }))
+            .map(|asset| asset.name.clone())
+            .collect();
+        let reset: Vec<ModelAsset> = downloading
+            .iter()
+            .filter_map(|name| guard.recover_interrupted(name))
+            .collect();
+        if !reset.is_empty() {
+            if let Err(error) = guard.save() {
+                tracing::warn!("Failed to save model manifest on shutdown: {error:?}");
+            }
+            tracing::info!("model_downloads_cancelled count={}", reset.len());
+        }
+        reset
     }
 }
 
 fn worker_loop(
-    receiver: Receiver<ModelDownloadJob>,
+    queue: Arc<JobQueue>,
     manager: Arc<Mutex<ModelManager>>,
     models_dir: PathBuf,
     app: AppHandle,
+    stopped: Arc<AtomicBool>,
 ) {
-    for job in receiver.iter() {
-        let mut initial_events: Vec<ModelAsset> = Vec::new();
-        let selection_plan = {
-            let mut guard = match manager.lock() {
-                Ok(guard) => guard,
-                Err(poisoned) => poisoned.into_inner(),
-            };
+    collect_stale_staging(&manager, &models_dir);
+    while let Some(queued) = queue.next(&stopped) {
+        events::emit_model_download_queue(&app, queue.snapshot());
+        run_job(&queue, queued, &manager, &models_dir, &app, &stopped);
+        queue.finish();
+    }
+}
 
-            let result = guard.assets_mut().into_iter().find_map(|asset| {
-                if asset.name != job.asset_name {
+fn run_job(
+    queue: &JobQueue,
+    queued: QueuedJob,
+    manager: &Arc<Mutex<ModelManager>>,
+    models_dir: &Path,
+    app: &AppHandle,
+    stopped: &AtomicBool,
+) {
+    let QueuedJob { job, resume } = queued;
+    let Some(_asset_lock) = lock_asset(manager, models_dir, app, &job) else {
+        return;
+    };
+    let mut initial_events: Vec<ModelAsset> = Vec::new();
+    let selection_plan = {
+        let mut guard = match manager.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let result = guard.assets_mut().into_iter().find_map(|asset| {
+            if asset.name != job.asset_name {
+                return None;
+            }
+
+            if resume.is_some() {
+                // A paused download resumes only if nothing reset it meanwhile.
+                if !matches!(asset.status, ModelStatus::Downloading { .. }) {
                     return None;
                 }
+                return Some((asset.name.clone(), resume.clone()));
+            }
 
-                if !matches!(
+            let eligible = if job.update {
+                matches!(asset.status, ModelStatus::Installed)
+                    && !asset.system_provided
+                    && asset.linked_path.is_none()
+            } else {
+                matches!(
                     asset.status,
                     ModelStatus::NotInstalled | ModelStatus::Error(_)
-                ) {
-                    return None;
-                }
-
-                if asset.source.is_none() {
-                    asset.status = ModelStatus::Error("missing download source".into());
-                    initial_events.push(asset.clone());
-                    return Some((asset.name.clone(), None));
-                }
+                )
+            };
+            if !eligible {
+                return None;
+            }
 
-                asset.status = ModelStatus::Downloading {
-                    progress: 0.0,
-                    downloaded_bytes: 0,
-                    total_bytes: None,
-                };
-                let name = asset.name.clone();
-                let plan = build_download_plan(asset, models_dir.clone());
+            if asset.source.is_none() {
+                asset.status = ModelStatus::Error("missing download source".into());
                 initial_events.push(asset.clone());
-                Some((name, plan))
-            });
+                return Some((asset.name.clone(), None));
+            }
 
-            let _ = guard.save();
-            drop(guard);
+            asset.status = ModelStatus::Downloading {
+                progress: 0.0,
+                downloaded_bytes: 0,
+                total_bytes: None,
+                bytes_per_sec: None,
+                eta_secs: None,
+            };
+            let name = asset.name.clone();
+            let plan = build_download_plan(asset, models_dir.to_path_buf());
+            initial_events.push(asset.clone());
+            Some((name, plan))
+        });
 
-            result
-        };
-        for snapshot in initial_events {
-            emit_status(&app, snapshot);
-        }
+        let _ = guard.save();
+        drop(guard);
 
-        let Some((asset_name, plan)) = selection_plan else {
-            continue;
-        };
+        result
+    };
+    for snapshot in initial_events {
+        emit_status(app, snapshot);
+    }
 
-        let Some(plan) = plan else {
-            continue;
-        };
+    let Some((asset_name, plan)) = selection_plan else {
+        return;
+    };
+
+    let Some(plan) = plan else {
+        return;
+    };
+
+    queue.set_preemptible(plan.resumable());
+
+    // Background downloads (the launch-time defaults) yield the disk to everything else.
+    set_io_priority(job.priority == DownloadPriority::Low);
 
-        let mut last_emit_at = Instant::now() - Duration::from_secs(5);
-        let mut last_progress_bucket: i32 = -1;
+    let mut last_emit_at = Instant::now() - Duration::from_secs(5);
+    let mut last_progress_bucket: i32 = -1;
+    let mut rate = TransferRate::new();
 
-        match download_and_extract_with_progress(&plan, |progress: DownloadProgress| {
+    match download_and_extract_with_progress(
+        &plan,
+        &queue.interrupt,
+        |progress: DownloadProgress| {
+            // Shutdown has already reset this asset; don't mark it downloading again.
+            if stopped.load(Ordering::SeqCst) {
+                return;
+            }
+            rate.record(progress.downloaded);
             let fraction = progress_fraction(progress.downloaded, progress.total);
             let bucket = (fraction * 100.0).floor() as i32;
             let now = Instant::now();
@@ -134,25 +510,491 @@ fn worker_loop(
             last_emit_at = now;
             last_progress_bucket = bucket;
 
-            on_progress(
-                &manager,
-                &app,
-                &asset_name,
-                progress.downloaded,
-                progress.total,
+            on_progress(manager, app, &asset_name, &progress, &rate);
+        },
+    ) {
+        Ok(outcome) => match revisions::activate(&outcome.final_path) {
+            Ok(final_path) => {
+                let outcome = DownloadOutcome {
+                    final_path,
+                    ..outcome
+                };
+                on_download_success(manager, app, &asset_name, &outcome, job.update);
+            }
+            Err(error) => {
+                discard_revision(plan.destination());
+                if job.update {
+                    on_update_failure(manager, app, &asset_name, error);
+                } else {
+                    on_download_failure(manager, app, &asset_name, error);
+                }
+            }
+        },
+        Err(_) if queue.interrupt.is_cancelled() => {
+            tracing::info!("model_download_cancelled asset={asset_name}");
+            discard_revision(plan.destination());
+            collect_asset_staging(plan.destination());
+            on_download_cancelled(manager, app, &asset_name);
+        }
+        Err(_) if queue.interrupt.is_set() && !stopped.load(Ordering::SeqCst) => {
+            tracing::info!("model_download_paused asset={asset_name}");
+            queue.state.lock().jobs.insert(
+                0,
+                QueuedJob {
+                    job,
+                    resume: Some(plan),
+                },
+            );
+        }
+        Err(error) if job.update => {
+            discard_revision(plan.destination());
+            on_update_failure(manager, app, &asset_name, error);
+            collect_asset_staging(plan.destination());
+        }
+        Err(error) => {
+            discard_revision(plan.destination());
+            on_download_failure(manager, app, &asset_name, error);
+            collect_asset_staging(plan.destination());
+        }
+    }
+}
+
+/// Startup GC of staging that interrupted downloads left behind, skipping assets
+/// another instance is downloading.
+fn collect_stale_staging(manager: &Arc<Mutex<ModelManager>>, models_dir: &Path) {
+    let assets: Vec<(String, PathBuf)> = {
+        let guard = match manager.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        guard
+            .assets()
+            .into_iter()
+            .filter(|asset| !asset.system_provided)
+            .map(|asset| (asset.name.clone(), asset.install_dir(models_dir)))
+            .collect()
+    };
+
+    let (mut removed, mut reclaimed) = (0usize, 0u64);
+    for (name, install_dir) in assets {
+        let Ok(Some(_lock)) = AssetLock::try_acquire(models_dir, &name) else {
+            continue;
+        };
+        let (count, bytes) = staging::sweep(&install_dir, Some(staging::STALE_STAGING_AGE));
+        removed += count;
+        reclaimed += bytes;
+    }
+    if removed > 0 {
+        tracing::info!("model_staging_gc removed={removed} reclaimed_bytes={reclaimed}");
+    }
+}
+
+/// After a failed download, drops its staging files and older leftovers of the
+/// same asset. The caller holds the asset lock.
+fn collect_asset_staging(revision_dir: &Path) {
+    let Some(install_dir) = revisions::install_dir_of(revision_dir) else {
+        return;
+    };
+    let failed = staging::revision_staging(revision_dir);
+    let (stale, stale_bytes) = staging::sweep(install_dir, Some(staging::STALE_STAGING_AGE));
+    let removed = failed.len() + stale;
+    let reclaimed = staging::remove_artifacts(&failed) + stale_bytes;
+    if removed > 0 {
+        tracing::info!(
+            "model_staging_gc install={install_dir:?} removed={removed} reclaimed_bytes={reclaimed}"
+        );
+    }
+}
+
+/// Takes the cross-process lock for `job`'s asset. When another OpenFlow instance
+/// is already downloading it, waits for that download and adopts its result;
+/// returns None if there is nothing left to do.
+fn lock_asset(
+    manager: &Arc<Mutex<ModelManager>>,
+    models_dir: &Path,
+    app: &AppHandle,
+    job: &ModelDownloadJob,
+) -> Option<Option<AssetLock>> {
+    match AssetLock::try_acquire(models_dir, &job.asset_name) {
+        Ok(Some(lock)) => return Some(Some(lock)),
+        Ok(None) => {}
+        Err(error) => {
+            tracing::warn!("Model lock for {} unavailable: {error:?}", job.asset_name);
+            return Some(None);
+        }
+    }
+
+    tracing::info!(
+        "{} is being downloaded by another instance; waiting",
+        job.asset_name
+    );
+    let (waiting, live_before) = {
+        let guard = match manager.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let Some(asset) = guard.asset_by_name(&job.asset_name) else {
+            return Some(None);
+        };
+        let mut waiting = asset.clone();
+        waiting.status = ModelStatus::Downloading {
+            progress: 0.0,
+            downloaded_bytes: 0,
+            total_bytes: None,
+            bytes_per_sec: None,
+            eta_secs: None,
+        };
+        (waiting, fs::read_link(asset.path(guard.root())).ok())
+    };
+    emit_status(app, waiting);
+
+    let lock = match AssetLock::acquire(models_dir, &job.asset_name) {
+        Ok(lock) => Some(lock),
+        Err(error) => {
+            tracing::warn!(
+                "Waiting on model lock for {} failed: {error:?}",
+                job.asset_name
             );
-        }) {
-            Ok(outcome) => on_download_success(&manager, &app, &asset_name, &outcome),
-            Err(error) => on_download_failure(&manager, &app, &asset_name, error),
+            None
+        }
+    };
+
+    let adopted = {
+        let mut guard = match manager.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let switched = guard
+            .asset_by_name(&job.asset_name)
+            .map(|asset| fs::read_link(asset.path(guard.root())).ok())
+            .is_some_and(|live| live.is_some() && live != live_before);
+        let adopted = if switched {
+            guard
+                .adopt_from_manifest(&job.asset_name)
+                .unwrap_or_else(|error| {
+                    tracing::warn!("Failed to adopt {}: {error:?}", job.asset_name);
+                    None
+                })
+        } else {
+            None
+        };
+        if adopted.is_some() {
+            if let Err(error) = sync_runtime_environment(&guard) {
+                tracing::warn!("Failed to sync adopted model environment: {error:?}");
+            }
+        }
+        adopted
+    };
+
+    let Some(snapshot) = adopted else {
+        // The other download failed or was for a different revision; do our own.
+        if let Some(current) = manager
+            .lock()
+            .ok()
+            .and_then(|guard| guard.asset_by_name(&job.asset_name).cloned())
+        {
+            emit_status(app, current);
+        }
+        return Some(lock);
+    };
+    tracing::info!("Adopted {} installed by another instance", job.asset_name);
+    emit_status(app, snapshot);
+    if let Some(state) = app.try_state::<AppState>() {
+        if let Err(error) = state.reload_pipeline(app) {
+            tracing::warn!("Failed to rebuild speech pipeline after model install: {error:?}");
+        }
+    }
+    None
+}
+
+/// Puts the worker thread's disk I/O in the idle class, or back to the default that
+/// follows its CPU priority.
+fn set_io_priority(idle: bool) {
+    const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+    const IOPRIO_CLASS_SHIFT: libc::c_int = 13;
+    const IOPRIO_CLASS_IDLE: libc::c_int = 3;
+    let value = if idle {
+        IOPRIO_CLASS_IDLE << IOPRIO_CLASS_SHIFT
+    } else {
+        0
+    };
+    // Who 0 is the calling thread.
+    let result = unsafe { libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, 0, value) };
+    if result != 0 {
+        tracing::debug!(
+            "ioprio_set idle={idle} failed: {}",
+            std::io::Error::last_os_error()
+        );
+    }
+}
+
+/// Drops what a paused download fetched so far, unless another instance has taken
+/// over the asset meanwhile.
+fn discard_paused(manager: &Arc<Mutex<ModelManager>>, asset_name: &str, plan: &DownloadPlan) {
+    let models_dir = match manager.lock() {
+        Ok(guard) => guard.root().to_path_buf(),
+        Err(poisoned) => poisoned.into_inner().root().to_path_buf(),
+    };
+    match AssetLock::try_acquire(&models_dir, asset_name) {
+        Ok(Some(_lock)) => {
+            discard_revision(plan.destination());
+            collect_asset_staging(plan.destination());
+        }
+        Ok(None) => tracing::debug!("Skipping cleanup of {asset_name}: it is locked"),
+        Err(error) => tracing::warn!("Skipping cleanup of {asset_name}: {error:?}"),
+    }
+}
+
+/// Resets a cancelled download's status from what is on disk.
+fn on_download_cancelled(manager: &Arc<Mutex<ModelManager>>, app: &AppHandle, asset_name: &str) {
+    let snapshot = {
+        let mut guard = match manager.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let snapshot = guard.recover_interrupted(asset_name);
+        if snapshot.is_some() {
+            if let Err(error) = guard.save() {
+                tracing::warn!("Failed to persist model manifest after cancel: {error:?}");
+            }
+        }
+        snapshot
+    };
+    if let Some(snapshot) = snapshot {
+        emit_status(app, snapshot);
+    }
+}
+
+/// Removes a revision that never became current.
+fn discard_revision(revision_dir: &Path) {
+    if revision_dir.exists() {
+        if let Err(error) = fs::remove_dir_all(revision_dir) {
+            tracing::debug!("Failed to remove partial revision {revision_dir:?}: {error}");
         }
     }
 }
 
+fn on_update_failure(
+    manager: &Arc<Mutex<ModelManager>>,
+    app: &AppHandle,
+    asset_name: &str,
+    error: anyhow::Error,
+) {
+    tracing::warn!("Model update for {asset_name} failed: {error:?}");
+    // The installed revision was never touched, so the asset stays usable.
+    let snapshot = {
+        let mut guard = match manager.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let snapshot = guard.asset_by_name_mut(asset_name).map(|asset| {
+            asset.status = ModelStatus::Installed;
+            asset.clone()
+        });
+        let _ = guard.save();
+        snapshot
+    };
+    if let Some(snapshot) = snapshot {
+        emit_status(app, snapshot);
+    }
+    events::emit_attention_route(
+        app,
+        &events::model_route(asset_name),
+        &i18n::message("notify-model-update-failed", &[]),
+        &error.to_string(),
+    );
+}
+
+/// Switches back to the revision replaced by the last update. Returns the updated
+/// snapshot.
+pub fn rollback_model_update(
+    manager: &Arc<Mutex<ModelManager>>,
+    asset_name: &str,
+) -> Result<ModelAsset> {
+    let mut guard = manager.lock().map_err(|err| anyhow!(err.to_string()))?;
+    let install_dir = {
+        let asset = guard
+            .asset_by_name(asset_name)
+            .ok_or_else(|| anyhow!("unknown model {asset_name}"))?;
+        if asset.system_provided {
+            return Err(anyhow!(
+                "{asset_name} is managed by the system models directory"
+            ));
+        }
+        asset.install_dir(guard.root())
+    };
+    let live =
+        revisions::rollback(&install_dir).with_context(|| format!("roll back {asset_name}"))?;
+
+    let asset = guard
+        .asset_by_name_mut(asset_name)
+        .ok_or_else(|| anyhow!("unknown model {asset_name}"))?;
+    let restored = asset.revisions.previous_revision.take();
+    asset.revisions.previous_revision = asset.revisions.installed_revision.take();
+    asset.revisions.installed_revision = restored;
+    asset.linked_path = revisions::external_target(&install_dir);
+    if asset.linked_path.is_some() {
+        asset.checksum = None;
+    } else if let Some(model) = find_first_with_extension(&live, "onnx")
+        .or_else(|| find_first_with_name(&live, "model.bin"))
+    {
+        let _ = asset.update_from_file(model);
+    }
+    asset.set_size_bytes(total_size(&live));
+    let snapshot = asset.clone();
+    guard.save()?;
+    sync_runtime_environment(&guard)?;
+    Ok(snapshot)
+}
+
+/// Removes the leftovers of interrupted downloads for every asset that is not
+/// downloading right now, in this or another instance, and resets any status
+/// those downloads left stuck on `Downloading`.
+pub fn clear_download_staging(
+    manager: &Arc<Mutex<ModelManager>>,
+    app: &AppHandle,
+) -> Result<StagingCleanup> {
+    let (root, assets): (PathBuf, Vec<(String, PathBuf)>) = {
+        let guard = manager.lock().map_err(|err| anyhow!(err.to_string()))?;
+        let root = guard.root().to_path_buf();
+        let assets = guard
+            .assets()
+            .into_iter()
+            .filter(|asset| !asset.system_provided)
+            .map(|asset| (asset.name.clone(), asset.install_dir(&root)))
+            .collect();
+        (root, assets)
+    };
+
+    let mut cleanup = StagingCleanup::default();
+    let mut recovered = Vec::new();
+    for (name, install_dir) in assets {
+        // A running download holds the asset lock, even within this process.
+        let _lock = match AssetLock::try_acquire(&root, &name) {
+            Ok(Some(lock)) => lock,
+            Ok(None) => {
+                cleanup.skipped.push(name);
+                continue;
+            }
+            Err(error) => {
+                tracing::warn!("Skipping staging cleanup for {name}: {error:?}");
+                cleanup.skipped.push(name);
+                continue;
+            }
+        };
+        let (removed, reclaimed) = staging::sweep(&install_dir, None);
+        cleanup.removed += removed;
+        cleanup.reclaimed_bytes += reclaimed;
+
+        let mut guard = manager.lock().map_err(|err| anyhow!(err.to_string()))?;
+        if let Some(snapshot) = guard.recover_interrupted(&name) {
+            recovered.push(snapshot);
+        }
+    }
+
+    if !recovered.is_empty() {
+        let guard = manager.lock().map_err(|err| anyhow!(err.to_string()))?;
+        guard.save()?;
+    }
+    for snapshot in recovered {
+        emit_status(app, snapshot);
+    }
+    tracing::info!(
+        "Cleared model download staging: removed={} reclaimed_bytes={}",
+        cleanup.removed,
+        cleanup.reclaimed_bytes
+    );
+    Ok(cleanup)
+}
+
+/// Refreshes `latest_revision` for every user-installed asset. Returns the assets
+/// whose update availability changed.
+pub fn check_model_updates(manager: &Arc<Mutex<ModelManager>>) -> Vec<ModelAsset> {
+    let candidates: Vec<ModelAsset> = {
+        let guard = match manager.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        guard
+            .assets()
+            .into_iter()
+            .filter(|asset| {
+                matches!(asset.status, ModelStatus::Installed)
+                    && !asset.system_provided
+                    && asset.linked_path.is_none()
+                    && asset.source.is_some()
+            })
+            .cloned()
+            .collect()
+    };
+
+    let mut changed = Vec::new();
+    for candidate in candidates {
+        let latest = match fetch_latest_revision(&candidate) {
+            Ok(latest) => latest,
+            Err(error) => {
+                tracing::debug!(
+                    "Model update check for {} failed: {error:?}",
+                    candidate.name
+                );
+                continue;
+            }
+        };
+        let mut guard = match manager.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let Some(asset) = guard.asset_by_name_mut(&candidate.name) else {
+            continue;
+        };
+        let was_available = asset.revisions.update_available();
+        // Installs that predate revision tracking adopt the current upstream revision
+        // rather than reporting a spurious update.
+        if asset.revisions.installed_revision.is_none() {
+            asset.revisions.installed_revision = latest.clone();
+        }
+        asset.revisions.latest_revision = latest;
+        if asset.revisions.update_available() != was_available {
+            changed.push(asset.clone());
+        }
+    }
+
+    if let Ok(guard) = manager.lock() {
+        if let Err(error) = guard.save() {
+            tracing::warn!("Failed to persist model update check: {error:?}");
+        }
+    }
+    changed
+}
+
+pub fn spawn_update_checker(app: AppHandle) {
+    let spawned = thread::Builder::new()
+        .name("model-updates".into())
+        .spawn(move || {
+            thread::sleep(MODEL_UPDATE_FIRST_CHECK);
+            loop {
+                if let Some(state) = app.try_state::<AppState>() {
+                    for asset in check_model_updates(&state.model_manager()) {
+                        if asset.revisions.update_available() {
+                            tracing::info!("model_update_available name={}", asset.name);
+                        }
+                        emit_status(&app, asset);
+                    }
+                }
+                thread::sleep(MODEL_UPDATE_INTERVAL);
+            }
+        });
+    if let Err(error) = spawned {
+        tracing::warn!("failed to start model update checker: {error}");
+    }
+}
+
 fn on_download_success(
     manager: &Arc<Mutex<ModelManager>>,
     app: &AppHandle,
     asset_name: &str,
     outcome: &DownloadOutcome,
+    updated: bool,
 ) {
     let (snapshot, manager_result) = {
         let mut guard = match manager.lock() {
@@ -185,11 +1027,16 @@ fn on_download_success(
                         let _ = asset.update_from_file(tokens);
                     }
                 }
-                ModelKind::Vad => {
+                ModelKind::Vad | ModelKind::Speaker => {
                     if let Some(model) = find_first_with_extension(&outcome.final_path, "onnx") {
                         let _ = asset.update_from_file(model);
                     }
                 }
+                ModelKind::WhisperCpp => {
+                    if let Some(model) = find_first_with_extension(&outcome.final_path, ".bin") {
+                        let _ = asset.update_from_file(model);
+                    }
+                }
                 _ => {}
             }
 
@@ -205,6 +1052,14 @@ fn on_download_success(
                         asset.set_checksum(Some(checksum.clone()));
                     }
                 }
+                if updated {
+                    asset.revisions.previous_revision = asset.revisions.installed_revision.take();
+                }
+                asset.revisions.installed_revision = outcome.revision.clone();
+                if outcome.revision.is_some() {
+                    asset.revisions.latest_revision = outcome.revision.clone();
+                }
+                asset.linked_path = None;
                 asset.status = ModelStatus::Installed;
                 snapshot = Some(asset.clone());
             }
@@ -260,6 +1115,19 @@ fn on_download_failure(
 }
 
 fn emit_status(app: &AppHandle, asset: ModelAsset) {
+    match asset.status {
+        ModelStatus::Error(ref message) => {
+            tray::set_badge(app, TrayBadge::DownloadFailed, true);
+            events::emit_attention_route(
+                app,
+                &events::model_route(&asset.name),
+                &i18n::message("notify-model-install-failed", &[]),
+                message,
+            );
+        }
+        ModelStatus::Installed => tray::set_badge(app, TrayBadge::DownloadFailed, false),
+        _ => {}
+    }
     events::emit_model_status(app, asset);
 }
 
@@ -267,9 +1135,11 @@ fn on_progress(
     manager: &Arc<Mutex<ModelManager>>,
     app: &AppHandle,
     asset_name: &str,
-    downloaded: u64,
-    expected: Option<u64>,
+    progress: &DownloadProgress,
+    rate: &TransferRate,
 ) {
+    let downloaded = progress.downloaded;
+    let expected = progress.total;
     let snapshot = if let Ok(mut guard) = manager.lock() {
         if let Some(asset) = guard.asset_by_name_mut(asset_name) {
             let progress = progress_fraction(downloaded, expected);
@@ -285,6 +1155,8 @@ fn on_progress(
                 progress,
                 downloaded_bytes: downloaded,
                 total_bytes: expected,
+                bytes_per_sec: rate.bytes_per_sec(),
+                eta_secs: rate.eta_secs(downloaded, expected),
             };
             Some(asset.clone())
         } else {
@@ -310,13 +1182,78 @@ fn progress_fraction(downloaded: u64, expected: Option<u64>) -> f32 {
 
 pub fn sync_runtime_environment(manager: &ModelManager) -> Result<()> {
     sync_vad_env(manager)?;
+    sync_speaker_env(manager);
+    #[cfg(target_os = "linux")]
+    preload_gpu_runtime(manager);
     Ok(())
 }
 
+/// Loads the downloaded CUDA runtime libraries into the process, so the GPU
+/// providers find them by soname without the directory being on
+/// `LD_LIBRARY_PATH` at startup. Libraries can only be loaded once, so this runs
+/// until it succeeds.
+#[cfg(target_os = "linux")]
+fn preload_gpu_runtime(manager: &ModelManager) {
+    static PRELOADED: AtomicBool = AtomicBool::new(false);
+    if PRELOADED.load(Ordering::SeqCst) {
+        return;
+    }
+    let Some(asset) = manager
+        .primary_asset(&ModelKind::GpuRuntime)
+        .filter(|asset| matches!(asset.status, ModelStatus::Installed))
+    else {
+        return;
+    };
+    let lib_dir = manager.asset_path(asset).join("lib");
+    let Ok(entries) = fs::read_dir(&lib_dir) else {
+        return;
+    };
+    let mut pending: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.contains(".so"))
+        })
+        .collect();
+    // Libraries depend on each other; keep retrying the failures while a pass
+    // still loads something.
+    loop {
+        let before = pending.len();
+        pending.retain(|path| !dlopen_global(path));
+        if pending.is_empty() || pending.len() == before {
+            break;
+        }
+    }
+    for path in &pending {
+        tracing::warn!("gpu_runtime_preload_failed path={}", path.display());
+    }
+    tracing::info!(
+        "gpu_runtime_preloaded dir={} failed={}",
+        lib_dir.display(),
+        pending.len()
+    );
+    PRELOADED.store(true, Ordering::SeqCst);
+}
+
+#[cfg(target_os = "linux")]
+fn dlopen_global(path: &Path) -> bool {
+    use std::os::unix::ffi::OsStrExt;
+
+    let Ok(path) = std::ffi::CString::new(path.as_os_str().as_bytes()) else {
+        return false;
+    };
+    let handle = unsafe { libc::dlopen(path.as_ptr(), libc::RTLD_NOW | libc::RTLD_GLOBAL) };
+    !handle.is_null()
+}
+
 fn sync_vad_env(manager: &ModelManager) -> Result<()> {
     if let Some(asset) = manager.primary_asset(&ModelKind::Vad) {
         if matches!(asset.status, ModelStatus::Installed) {
-            let vad_dir = asset.path(manager.root());
+            let vad_dir = manager.asset_path(asset);
             if let Some(model) = find_first_with_extension(&vad_dir, "onnx") {
                 std::env::set_var("SILERO_VAD_MODEL", model);
                 return Ok(());
@@ -327,6 +1264,17 @@ fn sync_vad_env(manager: &ModelManager) -> Result<()> {
     Ok(())
 }
 
+fn sync_speaker_env(manager: &ModelManager) {
+    let model = manager
+        .primary_asset(&ModelKind::Speaker)
+        .filter(|asset| matches!(asset.status, ModelStatus::Installed))
+        .and_then(|asset| find_first_with_extension(&manager.asset_path(asset), "onnx"));
+    match model {
+        Some(model) => std::env::set_var(SPEAKER_MODEL_ENV, model),
+        None => std::env::remove_var(SPEAKER_MODEL_ENV),
+    }
+}
+
 fn find_tokens_file(dir: &Path) -> Option<PathBuf> {
     let default = dir.join("tokens.txt");
     if default.exists() {
@@ -381,3 +1329,159 @@ where
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queued(asset_name: &str) -> QueuedJob {
+        QueuedJob {
+            job: ModelDownloadJob {
+                asset_name: asset_name.into(),
+                update: false,
+                priority: DownloadPriority::Normal,
+            },
+            resume: None,
+        }
+    }
+
+    fn queued_with(asset_name: &str, update: bool, priority: DownloadPriority) -> QueuedJob {
+        let mut queued = queued(asset_name);
+        queued.job.update = update;
+        queued.job.priority = priority;
+        queued
+    }
+
+    fn waiting(queue: &JobQueue) -> Vec<(String, bool)> {
+        queue
+            .snapshot()
+            .into_iter()
+            .map(|queued| (queued.asset_name, queued.update))
+            .collect()
+    }
+
+    #[test]
+    fn runs_the_highest_priority_first_and_keeps_queue_order_within_a_priority() {
+        let queue = JobQueue::default();
+        let stopped = AtomicBool::new(false);
+        {
+            let mut state = queue.state.lock();
+            state
+                .jobs
+                .push(queued_with("silero-vad", false, DownloadPriority::Low));
+            state.jobs.push(queued("whisper-small"));
+            state.jobs.push(queued("parakeet"));
+            state
+                .jobs
+                .push(queued_with("punctuation", false, DownloadPriority::High));
+        }
+
+        let mut order = Vec::new();
+        while !queue.state.lock().jobs.is_empty() {
+            order.push(queue.next(&stopped).unwrap().job.asset_name);
+            queue.finish();
+        }
+        assert_eq!(
+            order,
+            ["punctuation", "whisper-small", "parakeet", "silero-vad"]
+        );
+    }
+
+    #[test]
+    fn only_resumable_downloads_are_paused_for_an_outranking_job() {
+        let queue = JobQueue::default();
+        let stopped = AtomicBool::new(false);
+        queue.state.lock().jobs.push(queued("parakeet"));
+        queue.next(&stopped).unwrap();
+
+        // A streamed tar archive would start over, so it keeps running.
+        queue.set_preemptible(false);
+        queue.state.lock().jobs.push(queued("silero-vad"));
+        queue.set_priority("silero-vad", DownloadPriority::High);
+        assert!(!queue.interrupt.is_set());
+
+        // Once the plan turns out to be resumable, the waiting job takes over.
+        queue.set_preemptible(true);
+        assert!(queue.interrupt.pause.load(Ordering::SeqCst));
+        assert!(!queue.interrupt.is_cancelled());
+        queue.finish();
+
+        // Equal priority never preempts.
+        queue.interrupt.reset();
+        queue.next(&stopped).unwrap();
+        queue.set_preemptible(true);
+        queue.state.lock().jobs.push(queued("whisper-small"));
+        queue.set_priority("whisper-small", DownloadPriority::High);
+        assert!(!queue.interrupt.is_set());
+    }
+
+    #[test]
+    fn reorder_tells_installs_and_updates_of_one_asset_apart() {
+        let queue = JobQueue::default();
+        {
+            let mut state = queue.state.lock();
+            state.jobs.push(queued("parakeet"));
+            state
+                .jobs
+                .push(queued_with("silero-vad", true, DownloadPriority::Normal));
+            state
+                .jobs
+                .push(queued_with("parakeet", true, DownloadPriority::Normal));
+        }
+
+        queue.reorder(&[QueuedDownloadKey {
+            asset_name: "parakeet".into(),
+            update: true,
+        }]);
+        assert_eq!(
+            waiting(&queue),
+            [
+                ("parakeet".to_string(), true),
+                ("parakeet".to_string(), false),
+                ("silero-vad".to_string(), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn cancelling_a_running_download_discards_it_and_leaves_the_queue_idle() {
+        let queue = JobQueue::default();
+        let stopped = AtomicBool::new(false);
+        queue.state.lock().jobs.push(queued("parakeet"));
+        let running = queue.next(&stopped).unwrap();
+        assert_eq!(running.job.asset_name, "parakeet");
+        queue.state.lock().jobs.push(queued("parakeet"));
+        queue.state.lock().jobs.push(queued("silero-vad"));
+
+        let (dropped, was_running) = queue.cancel("parakeet");
+        assert!(was_running);
+        assert_eq!(dropped.len(), 1);
+        assert!(queue.interrupt.is_cancelled());
+        let waiting: Vec<String> = queue
+            .snapshot()
+            .into_iter()
+            .map(|queued| queued.asset_name)
+            .collect();
+        assert_eq!(waiting, ["silero-vad"]);
+
+        // What the worker does once the transfer stops on the interrupt.
+        let revision = std::env::temp_dir().join(format!(
+            "openflow-cancel-{}/parakeet/rev-partial",
+            std::process::id()
+        ));
+        fs::create_dir_all(&revision).unwrap();
+        fs::write(revision.join("model.onnx.part"), b"partial").unwrap();
+        discard_revision(&revision);
+        assert!(!revision.exists());
+        queue.finish();
+        assert!(queue.state.lock().running.is_none());
+
+        // The cancel flag doesn't carry over to the next download.
+        let next = queue.next(&stopped).unwrap();
+        assert_eq!(next.job.asset_name, "silero-vad");
+        assert!(!queue.interrupt.is_set());
+        queue.finish();
+        assert!(!queue.cancel("parakeet").1);
+        let _ = fs::remove_dir_all(revision.parent().unwrap().parent().unwrap());
+    }
+}
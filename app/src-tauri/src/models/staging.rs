@@ -0,0 +1,162 @@
+//! Cleanup of download and extraction leftovers.
+//!
+//! A finished download leaves exactly the revisions `current` and `previous` point
+//! at. Anything else in an asset's `revisions/` directory (partial revisions and
+//! their `.download`/`.extracting` staging) and the staging siblings the flat
+//! layout used next to the install directory belong to an interrupted download.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use super::metadata::total_size;
+use super::revisions;
+
+/// Garbage collection only touches artifacts untouched for this long, in case an
+/// instance without the per-asset download lock is still writing them.
+pub const STALE_STAGING_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Staging siblings of a flat install: `<name>.download[.<ext>]`, `<name>.extracting`
+/// (named with `Path::with_extension`), `<name>.migrating` and `<name>.update`.
+fn legacy_staging(install_dir: &Path) -> Vec<PathBuf> {
+    let (Some(parent), Some(name), Some(stem)) = (
+        install_dir.parent(),
+        install_dir.file_name().and_then(|name| name.to_str()),
+        install_dir.file_stem().and_then(|stem| stem.to_str()),
+    ) else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(parent) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter(|entry| {
+            let entry_name = entry.file_name();
+            let Some(entry_name) = entry_name.to_str() else {
+                return false;
+            };
+            let staged_extension = entry_name
+                .strip_prefix(stem)
+                .and_then(|rest| rest.strip_prefix('.'))
+                .is_some_and(|ext| ext.starts_with("download") || ext == "extracting");
+            let staged_sibling = entry_name
+                .strip_prefix(name)
+                .is_some_and(|rest| rest == ".migrating" || rest == ".update");
+            staged_extension || staged_sibling
+        })
+        .map(|entry| entry.path())
+        .collect()
+}
+
+/// Everything under `install_dir` that belongs to an interrupted download.
+pub fn orphaned_artifacts(install_dir: &Path) -> Vec<PathBuf> {
+    let mut orphans = legacy_staging(install_dir);
+    let live = revisions::live_revisions(install_dir);
+    if let Ok(entries) = fs::read_dir(revisions::revisions_dir(install_dir)) {
+        orphans.extend(
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| !live.contains(path)),
+        );
+    }
+    for link in revisions::staged_links(install_dir) {
+        if fs::symlink_metadata(&link).is_ok() {
+            orphans.push(link);
+        }
+    }
+    orphans
+}
+
+/// Staging files of one revision: `<revision>.download[.<ext>]` and `<revision>.extracting`.
+pub fn revision_staging(revision_dir: &Path) -> Vec<PathBuf> {
+    let (Some(parent), Some(name)) = (
+        revision_dir.parent(),
+        revision_dir.file_name().and_then(|name| name.to_str()),
+    ) else {
+        return Vec::new();
+    };
+    let prefix = format!("{name}.");
+    let Ok(entries) = fs::read_dir(parent) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .is_some_and(|entry_name| entry_name.starts_with(&prefix))
+        })
+        .map(|entry| entry.path())
+        .collect()
+}
+
+/// Deletes `paths`, returning the bytes reclaimed.
+pub fn remove_artifacts(paths: &[PathBuf]) -> u64 {
+    let mut reclaimed = 0u64;
+    for path in paths {
+        let is_link = fs::symlink_metadata(path).is_ok_and(|meta| meta.file_type().is_symlink());
+        let size = if is_link { 0 } else { total_size(path) };
+        let removed = if !is_link && path.is_dir() {
+            fs::remove_dir_all(path)
+        } else {
+            fs::remove_file(path)
+        };
+        match removed {
+            Ok(()) => reclaimed = reclaimed.saturating_add(size),
+            Err(error) => tracing::debug!("Failed to remove staging artifact {path:?}: {error}"),
+        }
+    }
+    reclaimed
+}
+
+/// Removes the orphaned artifacts of `install_dir`, only those last modified at
+/// least `min_age` ago when given. Returns the count removed and bytes reclaimed.
+pub fn sweep(install_dir: &Path, min_age: Option<Duration>) -> (usize, u64) {
+    let now = SystemTime::now();
+    let orphans: Vec<PathBuf> = orphaned_artifacts(install_dir)
+        .into_iter()
+        .filter(|path| {
+            let Some(min_age) = min_age else {
+                return true;
+            };
+            fs::symlink_metadata(path)
+                .and_then(|meta| meta.modified())
+                .ok()
+                .and_then(|modified| now.duration_since(modified).ok())
+                .is_some_and(|age| age >= min_age)
+        })
+        .collect();
+    (orphans.len(), remove_artifacts(&orphans))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sweep_removes_orphans_but_not_live_revisions() {
+        let root = std::env::temp_dir().join(format!("openflow-staging-{}", uuid::Uuid::new_v4()));
+        let install = root.join("whisper-small-1.0");
+        let live = revisions::new_revision_dir(&install);
+        fs::create_dir_all(&live).unwrap();
+        revisions::activate(&live).unwrap();
+        let partial = live.with_extension("download");
+        fs::create_dir_all(&partial).unwrap();
+        fs::write(partial.join("model.bin"), b"partial").unwrap();
+        let flat_staging = install.with_extension("extracting");
+        fs::create_dir_all(&flat_staging).unwrap();
+
+        let orphans = orphaned_artifacts(&install);
+        assert!(orphans.contains(&partial));
+        assert!(orphans.contains(&flat_staging));
+        assert!(!orphans.contains(&live));
+
+        assert_eq!(sweep(&install, Some(STALE_STAGING_AGE)), (0, 0));
+        assert_eq!(sweep(&install, None), (orphans.len(), 7));
+        assert!(live.is_dir() && !partial.exists() && !flat_staging.exists());
+        let _ = fs::remove_dir_all(&root);
+    }
+}
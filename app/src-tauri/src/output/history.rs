@@ -0,0 +1,86 @@
+//! Text delivered to each window, so editing voice commands can undo or rewrite what
+//! was just dictated there.
+
+use std::collections::VecDeque;
+
+/// Windows remembered; the least recently dictated into is forgotten first.
+const MAX_WINDOWS: usize = 8;
+const MAX_UTTERANCES: usize = 20;
+
+/// Deliveries keyed by X11 window id. Where no id can be read (Wayland) every
+/// delivery lands under `None`.
+#[derive(Debug, Default)]
+pub struct InjectionHistory {
+    windows: VecDeque<(Option<u32>, VecDeque<String>)>,
+}
+
+impl InjectionHistory {
+    pub fn record(&mut self, window: Option<u32>, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        let mut utterances = match self.windows.iter().position(|(id, _)| *id == window) {
+            Some(index) => self
+                .windows
+                .remove(index)
+                .map(|(_, list)| list)
+                .unwrap_or_default(),
+            None => VecDeque::new(),
+        };
+        utterances.push_back(text.to_string());
+        if utterances.len() > MAX_UTTERANCES {
+            utterances.pop_front();
+        }
+        self.windows.push_back((window, utterances));
+        if self.windows.len() > MAX_WINDOWS {
+            self.windows.pop_front();
+        }
+    }
+
+    pub fn last(&self, window: Option<u32>) -> Option<&str> {
+        self.windows
+            .iter()
+            .find(|(id, _)| *id == window)
+            .and_then(|(_, utterances)| utterances.back())
+            .map(String::as_str)
+    }
+
+    /// Swaps the newest utterance for `text`, or drops it when `text` is None so the
+    /// one before becomes the newest.
+    pub fn replace_last(&mut self, window: Option<u32>, text: Option<String>) {
+        let Some(index) = self.windows.iter().position(|(id, _)| *id == window) else {
+            return;
+        };
+        let utterances = &mut self.windows[index].1;
+        utterances.pop_back();
+        if let Some(text) = text.filter(|text| !text.is_empty()) {
+            utterances.push_back(text);
+        }
+        if utterances.is_empty() {
+            self.windows.remove(index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_utterances_per_window_and_unwinds_them() {
+        let mut history = InjectionHistory::default();
+        history.record(Some(1), "Hello there.");
+        history.record(Some(2), "Other window.");
+        history.record(Some(1), " Second line.");
+        assert_eq!(history.last(Some(1)), Some(" Second line."));
+        assert_eq!(history.last(Some(2)), Some("Other window."));
+        assert_eq!(history.last(None), None);
+
+        history.replace_last(Some(1), None);
+        assert_eq!(history.last(Some(1)), Some("Hello there."));
+        history.replace_last(Some(1), Some("Hello.".into()));
+        assert_eq!(history.last(Some(1)), Some("Hello."));
+        history.replace_last(Some(1), None);
+        assert_eq!(history.last(Some(1)), None);
+    }
+}
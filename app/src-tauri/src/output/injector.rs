@@ -1,13 +1,19 @@
 use std::io::Write;
 use std::process::{Command, Stdio};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 #[cfg(debug_assertions)]
 use crate::output::logs;
 use serde::{Deserialize, Serialize};
-use tracing::{info, warn};
-
-use crate::output::uinput;
+use tracing::{debug, info, warn};
+
+use crate::core::hotkeys::KeyChord;
+use crate::output::history::InjectionHistory;
+use crate::output::terminal;
+use crate::output::typing;
+use crate::output::uinput::{self, ChordDelivery};
+use crate::output::virtual_keyboard;
 use crate::output::x11;
 
 static SYNTHETIC_PASTE_SUPPRESS_UNTIL_MS: AtomicU64 = AtomicU64::new(0);
@@ -17,19 +23,56 @@ static SYNTHETIC_PASTE_SUPPRESS_UNTIL_MS: AtomicU64 = AtomicU64::new(0);
 pub enum OutputAction {
     Paste,
     Copy,
+    /// Synthesized keystrokes per character, bypassing the clipboard.
+    Type,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "kebab-case")]
-pub enum PasteShortcut {
-    CtrlV,
-    CtrlShiftV,
+/// Key chord sent to trigger a paste, e.g. `Ctrl+V`, `Ctrl+Shift+V` or `Shift+Insert`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasteShortcut(KeyChord);
+
+impl PasteShortcut {
+    pub fn ctrl_v() -> Self {
+        Self::parse("Ctrl+V").expect("valid chord")
+    }
+
+    pub fn ctrl_shift_v() -> Self {
+        Self::parse("Ctrl+Shift+V").expect("valid chord")
+    }
+
+    pub fn shift_insert() -> Self {
+        Self::parse("Shift+Insert").expect("valid chord")
+    }
+
+    /// Parses a chord with the hotkey syntax. The legacy `ctrl-v` / `ctrl-shift-v`
+    /// setting values are still accepted.
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        let value = match value.trim() {
+            "ctrl-v" => "Ctrl+V",
+            "ctrl-shift-v" => "Ctrl+Shift+V",
+            other => other,
+        };
+        let chord = KeyChord::parse(value)?;
+        // Validate the key up front so a bad setting fails at configure time, not mid-paste.
+        chord.evdev_key()?;
+        Ok(Self(chord))
+    }
+
+    pub fn chord(&self) -> &KeyChord {
+        &self.0
+    }
+
+    pub fn label(&self) -> String {
+        self.0.to_string()
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PasteFailureStep {
     ClipboardWrite,
     KeyInject,
+    /// Focus moved to another window while the paste was in flight.
+    FocusChanged,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -43,6 +86,7 @@ impl PasteFailureStep {
         match self {
             PasteFailureStep::ClipboardWrite => "clipboard",
             PasteFailureStep::KeyInject => "uinput",
+            PasteFailureStep::FocusChanged => "focus-changed",
         }
     }
 }
@@ -53,6 +97,10 @@ pub struct PasteFailure {
     pub kind: PasteFailureKind,
     pub message: String,
     pub transcript_on_clipboard: bool,
+    /// Whether the paste chord was already sent; a retry would risk a double paste.
+    pub chord_sent: bool,
+    /// Read-back of a uinput chord; None for other backends or when no chord was sent.
+    pub chord_delivery: Option<ChordDelivery>,
 }
 
 impl std::fmt::Display for PasteFailure {
@@ -82,20 +130,47 @@ impl std::error::Error for OutputInjectionError {}
 
 impl Default for PasteShortcut {
     fn default() -> Self {
-        PasteShortcut::CtrlShiftV
+        PasteShortcut::ctrl_shift_v()
     }
 }
 
+/// When the steps of the last paste started and how long they took, for session
+/// timing. The hold before the clipboard is restored is not user-visible latency.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PasteTimings {
+    /// Snapshot, write and confirmation of the transcript on the clipboard.
+    pub clipboard: Option<(Instant, Duration)>,
+    /// Sending the paste chord, including the first-paste settle delay.
+    pub chord: Option<(Instant, Duration)>,
+}
+
 pub struct OutputInjector {
     paste_shortcut: std::sync::Mutex<PasteShortcut>,
+    /// Per-app overrides keyed by X11 window class (matched case-insensitively).
+    app_paste_shortcuts: std::sync::Mutex<Vec<(String, PasteShortcut)>>,
     first_paste_attempt: AtomicBool,
+    /// Last text handed to a paste, kept so it can be re-copied after a misdirected paste.
+    last_delivered: std::sync::Mutex<Option<String>>,
+    /// Successful deliveries per window, for the editing voice commands.
+    history: std::sync::Mutex<InjectionHistory>,
+    last_paste_timings: std::sync::Mutex<PasteTimings>,
+    /// Pause after each typed character, for apps that drop fast key events.
+    type_char_delay_ms: AtomicU64,
+    /// Paste multi-line type-out text into terminals instead of typing it.
+    terminal_multiline_paste: AtomicBool,
 }
 
 impl OutputInjector {
     pub fn new() -> Self {
         Self {
             paste_shortcut: std::sync::Mutex::new(PasteShortcut::default()),
+            app_paste_shortcuts: std::sync::Mutex::new(Vec::new()),
             first_paste_attempt: AtomicBool::new(true),
+            last_delivered: std::sync::Mutex::new(None),
+            history: std::sync::Mutex::new(InjectionHistory::default()),
+            last_paste_timings: std::sync::Mutex::new(PasteTimings::default()),
+            type_char_delay_ms: AtomicU64::new(DEFAULT_TYPE_CHAR_DELAY_MS),
+            terminal_multiline_paste: AtomicBool::new(true),
         }
     }
 
@@ -104,6 +179,15 @@ impl OutputInjector {
             return;
         }
 
+        if virtual_keyboard::available() {
+            match virtual_keyboard::prepare() {
+                Ok(()) => {
+                    info!("paste injector prewarmed backend=virtual-keyboard");
+                    return;
+                }
+                Err(error) => warn!("virtual keyboard prewarm failed: {error:#}"),
+            }
+        }
         if let Err(error) = uinput::prepare_virtual_keyboard() {
             warn!("paste injector prewarm failed: {error}");
         } else {
@@ -120,23 +204,96 @@ impl OutputInjector {
     pub fn current_paste_shortcut(&self) -> PasteShortcut {
         self.paste_shortcut
             .lock()
-            .map(|guard| *guard)
+            .map(|guard| guard.clone())
             .unwrap_or_default()
     }
 
-    pub fn inject(&self, text: &str, action: OutputAction) -> Result<(), OutputInjectionError> {
-        let shortcut = self
-            .paste_shortcut
+    pub fn set_type_char_delay(&self, delay_ms: u32) {
+        self.type_char_delay_ms
+            .store(u64::from(delay_ms), Ordering::SeqCst);
+    }
+
+    pub fn set_terminal_multiline_paste(&self, enabled: bool) {
+        self.terminal_multiline_paste
+            .store(enabled, Ordering::SeqCst);
+    }
+
+    /// Whether type-out should paste `text` instead, because it spans lines and the
+    /// focused window is a terminal. Off when the user wants terminals typed into too.
+    pub fn type_out_pastes(&self, text: &str) -> bool {
+        self.terminal_multiline_paste.load(Ordering::SeqCst)
+            && typing::terminal_pastes(terminal::focused_window_class().as_deref(), text)
+    }
+
+    pub fn set_app_paste_shortcuts(&self, shortcuts: Vec<(String, PasteShortcut)>) {
+        if let Ok(mut guard) = self.app_paste_shortcuts.lock() {
+            *guard = shortcuts;
+        }
+    }
+
+    /// Shortcut that will actually be sent for the focused window.
+    ///
+    /// A per-app override wins. Otherwise terminal emulators, which treat Ctrl+V as a
+    /// literal control character, get Ctrl+Shift+V when Ctrl+V is configured.
+    pub fn effective_paste_shortcut(&self) -> PasteShortcut {
+        let configured = self.current_paste_shortcut();
+        let app_shortcuts = self
+            .app_paste_shortcuts
             .lock()
-            .map(|guard| *guard)
+            .map(|guard| guard.clone())
             .unwrap_or_default();
+        if app_shortcuts.is_empty() && configured != PasteShortcut::ctrl_v() {
+            return configured;
+        }
+
+        let Some(class) = terminal::focused_window_class() else {
+            return configured;
+        };
+
+        if let Some((_, shortcut)) = app_shortcuts
+            .into_iter()
+            .find(|(app, _)| app.trim().eq_ignore_ascii_case(&class))
+        {
+            info!(
+                "paste_shortcut_override target={class} shortcut={}",
+                shortcut.label()
+            );
+            return shortcut;
+        }
+
+        if configured == PasteShortcut::ctrl_v() && terminal::is_terminal_class(&class) {
+            info!("paste_shortcut_override target=terminal shortcut=Ctrl+Shift+V");
+            return PasteShortcut::ctrl_shift_v();
+        }
+        configured
+    }
+
+    pub fn inject(
+        &self,
+        text: &str,
+        action: OutputAction,
+        shortcut: &PasteShortcut,
+    ) -> Result<(), OutputInjectionError> {
         match action {
             OutputAction::Paste => {
+                if let Ok(mut guard) = self.last_delivered.lock() {
+                    *guard = Some(text.to_string());
+                }
+                let window = focused_window_id();
                 let first_attempt = self.first_paste_attempt.swap(false, Ordering::SeqCst);
-                match paste_text(text, shortcut, first_attempt) {
+                let mut timings = PasteTimings::default();
+                let pasted = paste_text(text, shortcut, first_attempt, &mut timings);
+                if let Ok(mut guard) = self.last_paste_timings.lock() {
+                    *guard = timings;
+                }
+                match pasted {
                     Ok(()) => {
+                        self.record_delivery(window, text);
                         #[cfg(debug_assertions)]
-                        logs::push_log(format!("Paste -> {}", text));
+                        logs::push_log(format!(
+                            "Paste -> {}",
+                            crate::core::privacy::loggable(text)
+                        ));
                         Ok(())
                     }
                     Err(error) => {
@@ -154,6 +311,41 @@ impl OutputInjector {
                     }
                 }
             }
+            OutputAction::Type => {
+                let backend = match TypeBackend::open() {
+                    Ok(backend) => backend,
+                    Err(error) => {
+                        info!("type_out_fallback reason=no-layout-backend error={error:#}");
+                        return self.inject(text, OutputAction::Paste, shortcut);
+                    }
+                };
+                if let Some(c) = backend.untypeable_char(text) {
+                    info!("type_out_fallback reason=untypeable-char char={c:?}");
+                    return self.inject(text, OutputAction::Paste, shortcut);
+                }
+                if let Ok(mut guard) = self.last_delivered.lock() {
+                    *guard = Some(text.to_string());
+                }
+                let window = focused_window_id();
+                let started = Instant::now();
+                let delay = Duration::from_millis(self.type_char_delay_ms.load(Ordering::SeqCst));
+                let typed = type_text(&backend, text, delay);
+                if typed.is_ok() {
+                    self.record_delivery(window, text);
+                }
+                if let Ok(mut guard) = self.last_paste_timings.lock() {
+                    *guard = PasteTimings {
+                        clipboard: None,
+                        chord: Some((started, started.elapsed())),
+                    };
+                }
+                typed.map_err(|error| {
+                    warn!("Type-out failed: {error}");
+                    #[cfg(debug_assertions)]
+                    logs::push_log(format!("Type-out failed ({error})"));
+                    OutputInjectionError::Paste(error)
+                })
+            }
             OutputAction::Copy => set_clipboard_text(text)
                 .map_err(|error| {
                     warn!("Copy failed: {error}");
@@ -162,6 +354,159 @@ impl OutputInjector {
                 .map(|_| ()),
         }
     }
+
+    pub fn last_delivered_text(&self) -> Option<String> {
+        self.last_delivered
+            .lock()
+            .ok()
+            .and_then(|guard| guard.clone())
+    }
+
+    fn record_delivery(&self, window: Option<u32>, text: &str) {
+        if let Ok(mut history) = self.history.lock() {
+            history.record(window, text);
+        }
+    }
+
+    /// Newest text delivered to the focused window.
+    pub fn last_utterance(&self) -> Option<String> {
+        let window = focused_window_id();
+        self.history
+            .lock()
+            .ok()
+            .and_then(|history| history.last(window).map(str::to_string))
+    }
+
+    /// Rewrites the end of the newest delivery in the focused window, assuming the
+    /// cursor still sits after it: erases `erase` characters, pastes `insert`, and
+    /// remembers `updated` as that delivery (None forgets it).
+    pub fn edit_last_utterance(
+        &self,
+        erase: usize,
+        insert: &str,
+        updated: Option<String>,
+    ) -> anyhow::Result<()> {
+        let window = focused_window_id();
+        let backspace = PasteShortcut::parse("BackSpace")?;
+        self.press_chord(&backspace, erase)?;
+        if !insert.is_empty() {
+            let first_attempt = self.first_paste_attempt.swap(false, Ordering::SeqCst);
+            paste_text(
+                insert,
+                &self.effective_paste_shortcut(),
+                first_attempt,
+                &mut PasteTimings::default(),
+            )
+            .map_err(|error| anyhow::anyhow!(error.to_string()))?;
+        }
+        if let Ok(mut history) = self.history.lock() {
+            history.replace_last(window, updated.clone());
+        }
+        if let Ok(mut guard) = self.last_delivered.lock() {
+            *guard = updated;
+        }
+        Ok(())
+    }
+
+    /// Sends `chord` to the focused window `times` times through the paste chord backend.
+    pub fn press_chord(&self, chord: &PasteShortcut, times: usize) -> anyhow::Result<()> {
+        for _ in 0..times {
+            let (backend, _) = send_paste_chord(chord)?;
+            debug!("key_chord_sent chord={} backend={backend}", chord.label());
+        }
+        Ok(())
+    }
+
+    /// Timings of the last paste attempt, cleared by the call.
+    pub fn take_paste_timings(&self) -> PasteTimings {
+        self.last_paste_timings
+            .lock()
+            .map(|mut guard| std::mem::take(&mut *guard))
+            .unwrap_or_default()
+    }
+
+    /// Retries a paste with Shift+Insert after also placing the text on the PRIMARY
+    /// selection, which many X11 terminals paste from instead of CLIPBOARD.
+    pub fn inject_shift_insert_fallback(&self, text: &str) -> Result<(), OutputInjectionError> {
+        if let Err(error) = set_primary_selection_text(text) {
+            warn!("primary selection write failed: {error}");
+        }
+        info!("paste_fallback shortcut=Shift+Insert");
+        self.inject(text, OutputAction::Paste, &PasteShortcut::shift_insert())
+    }
+}
+
+/// Default pause between typed characters.
+const DEFAULT_TYPE_CHAR_DELAY_MS: u64 = 5;
+
+/// Where typed characters go. Key events only mean something under a known layout:
+/// the virtual keyboard uses the US keymap it uploads, and X11 uses the server's active
+/// mapping. uinput keys are read through the compositor's layout, which we can't see.
+enum TypeBackend {
+    VirtualKeyboard,
+    X11(x11::Typer),
+}
+
+impl TypeBackend {
+    fn open() -> anyhow::Result<Self> {
+        if is_wayland_session() {
+            if virtual_keyboard::available() {
+                return Ok(Self::VirtualKeyboard);
+            }
+            anyhow::bail!("no virtual keyboard; uinput keys depend on the compositor layout");
+        }
+        Ok(Self::X11(x11::Typer::connect()?))
+    }
+
+    fn untypeable_char(&self, text: &str) -> Option<char> {
+        match self {
+            Self::VirtualKeyboard => typing::untypeable_char(text),
+            Self::X11(typer) => typer.untypeable_char(text),
+        }
+    }
+
+    fn type_char(&self, c: char) -> anyhow::Result<()> {
+        match self {
+            Self::VirtualKeyboard => {
+                let chord =
+                    typing::chord_for_char(c).ok_or_else(|| anyhow::anyhow!("no key for {c:?}"))?;
+                virtual_keyboard::send_chord(&chord)
+            }
+            Self::X11(typer) => {
+                arm_synthetic_paste_suppression(Duration::from_millis(400));
+                typer.type_char(c)
+            }
+        }
+    }
+}
+
+/// Types `text` one key press per character.
+fn type_text(backend: &TypeBackend, text: &str, delay: Duration) -> Result<(), PasteFailure> {
+    info!(
+        "type_out_start chars={} delay_ms={}",
+        text.chars().count(),
+        delay.as_millis()
+    );
+    let mut typed = 0usize;
+    for c in text.chars().filter(|&c| c != '\r') {
+        if let Err(error) = backend.type_char(c) {
+            return Err(PasteFailure {
+                step: PasteFailureStep::KeyInject,
+                kind: PasteFailureKind::Failed,
+                message: format!("typing stopped after {typed} characters: {error}"),
+                transcript_on_clipboard: false,
+                // Part of the text may already be in the target; don't retry on top of it.
+                chord_sent: typed > 0,
+                chord_delivery: None,
+            });
+        }
+        typed += 1;
+        if !delay.is_zero() {
+            std::thread::sleep(delay);
+        }
+    }
+    info!("type_out_done chars={typed}");
+    Ok(())
 }
 
 pub fn synthetic_paste_active() -> bool {
@@ -170,26 +515,24 @@ pub fn synthetic_paste_active() -> bool {
 
 fn paste_text(
     text: &str,
-    shortcut: PasteShortcut,
+    shortcut: &PasteShortcut,
     first_attempt: bool,
+    timings: &mut PasteTimings,
 ) -> Result<(), PasteFailure> {
     use std::thread::sleep;
-    use std::time::Duration;
 
     info!(
         "paste_attempt_start chars={} shortcut={} first_since_launch={}",
         text.len(),
-        match shortcut {
-            PasteShortcut::CtrlV => "ctrl-v",
-            PasteShortcut::CtrlShiftV => "ctrl-shift-v",
-        },
+        shortcut.label(),
         first_attempt
     );
 
     if matches!(clipboard_backend(), ClipboardBackend::X11) {
-        return paste_text_x11(text, shortcut);
+        return paste_text_x11(text, shortcut, timings);
     }
 
+    let clipboard_started = Instant::now();
     let previous = snapshot_clipboard().ok().flatten();
 
     // Ensure transcript is available on the clipboard before we inject the paste.
@@ -198,6 +541,8 @@ fn paste_text(
         kind: PasteFailureKind::Failed,
         message: err.to_string(),
         transcript_on_clipboard: false,
+        chord_sent: false,
+        chord_delivery: None,
     })?;
 
     if !wait_for_clipboard_equals(text.as_bytes(), Duration::from_millis(250)) {
@@ -208,16 +553,23 @@ fn paste_text(
                 "Transcript not observed on clipboard before paste; transcript left on clipboard."
                     .to_string(),
             transcript_on_clipboard: true,
+            chord_sent: false,
+            chord_delivery: None,
         });
     }
+    timings.clipboard = Some((clipboard_started, clipboard_started.elapsed()));
 
+    let target_window = focused_window_id();
+    let chord_started = Instant::now();
     if first_attempt && is_wayland_session() {
         // First Wayland paste after launch can race with compositor/input initialization.
         sleep(Duration::from_millis(120));
     }
 
-    let backend = match send_paste_chord(shortcut) {
-        Ok(backend) => backend,
+    let sent = send_paste_chord(shortcut);
+    timings.chord = Some((chord_started, chord_started.elapsed()));
+    let (backend, delivery) = match sent {
+        Ok(sent) => sent,
         Err(error) => {
             // Keep transcript on the clipboard so the user can paste manually.
             let _ = set_clipboard_text(text);
@@ -226,16 +578,29 @@ fn paste_text(
                 kind: PasteFailureKind::Failed,
                 message: error.to_string(),
                 transcript_on_clipboard: true,
+                chord_sent: false,
+                chord_delivery: None,
             });
         }
     };
 
-    info!("paste_chord_sent backend={backend}");
+    info!(
+        "paste_chord_sent backend={backend} delivery={}",
+        delivery.map_or("n/a", |delivery| delivery.as_str())
+    );
 
     // Hold the transcript as the clipboard selection long enough for the target app
     // to request it. Clipboard managers may probe immediately; we must not restore early.
     sleep(Duration::from_millis(650));
 
+    if let Some(failure) = check_focus_unchanged(target_window) {
+        // The transcript is still on the clipboard; leave it there for a manual paste.
+        return Err(PasteFailure {
+            chord_delivery: delivery,
+            ..failure
+        });
+    }
+
     let Some(previous) = previous else {
         return Err(PasteFailure {
             step: PasteFailureStep::ClipboardWrite,
@@ -243,6 +608,8 @@ fn paste_text(
             message: "Previous clipboard could not be snapshotted; transcript left on clipboard."
                 .to_string(),
             transcript_on_clipboard: true,
+            chord_sent: true,
+            chord_delivery: delivery,
         });
     };
 
@@ -255,6 +622,8 @@ fn paste_text(
             message: "Clipboard changed during paste window; not restoring previous clipboard."
                 .to_string(),
             transcript_on_clipboard: false,
+            chord_sent: true,
+            chord_delivery: delivery,
         });
     }
 
@@ -263,16 +632,22 @@ fn paste_text(
         kind: PasteFailureKind::Unconfirmed,
         message: format!("Failed to restore clipboard: {err}"),
         transcript_on_clipboard: true,
+        chord_sent: true,
+        chord_delivery: delivery,
     })?;
 
     info!("paste_attempt_done");
     Ok(())
 }
 
-fn paste_text_x11(text: &str, shortcut: PasteShortcut) -> Result<(), PasteFailure> {
+fn paste_text_x11(
+    text: &str,
+    shortcut: &PasteShortcut,
+    timings: &mut PasteTimings,
+) -> Result<(), PasteFailure> {
     use std::thread::sleep;
-    use std::time::Duration;
 
+    let clipboard_started = Instant::now();
     let previous = snapshot_clipboard().ok().flatten();
 
     if !binary_in_path("xclip") {
@@ -281,6 +656,8 @@ fn paste_text_x11(text: &str, shortcut: PasteShortcut) -> Result<(), PasteFailur
             kind: PasteFailureKind::Failed,
             message: "xclip not found (install xclip)".to_string(),
             transcript_on_clipboard: false,
+            chord_sent: false,
+            chord_delivery: None,
         });
     }
 
@@ -295,6 +672,8 @@ fn paste_text_x11(text: &str, shortcut: PasteShortcut) -> Result<(), PasteFailur
             kind: PasteFailureKind::Failed,
             message: format!("xclip owner start failed: {err}"),
             transcript_on_clipboard: false,
+            chord_sent: false,
+            chord_delivery: None,
         })?;
 
     if let Some(stdin) = owner.stdin.as_mut() {
@@ -305,6 +684,8 @@ fn paste_text_x11(text: &str, shortcut: PasteShortcut) -> Result<(), PasteFailur
                 kind: PasteFailureKind::Failed,
                 message: format!("xclip owner write failed: {err}"),
                 transcript_on_clipboard: false,
+                chord_sent: false,
+                chord_delivery: None,
             })?;
     }
     owner.stdin.take();
@@ -321,11 +702,18 @@ fn paste_text_x11(text: &str, shortcut: PasteShortcut) -> Result<(), PasteFailur
                 "xclip foreground clipboard owner exited before paste completed (status {status}); transcript left on clipboard."
             ),
             transcript_on_clipboard: true,
+            chord_sent: false,
+            chord_delivery: None,
         });
     }
-
-    let backend = match send_paste_chord(shortcut) {
-        Ok(backend) => backend,
+    timings.clipboard = Some((clipboard_started, clipboard_started.elapsed()));
+
+    let target_window = focused_window_id();
+    let chord_started = Instant::now();
+    let sent = send_paste_chord(shortcut);
+    timings.chord = Some((chord_started, chord_started.elapsed()));
+    let (backend, delivery) = match sent {
+        Ok(sent) => sent,
         Err(error) => {
             stop_x11_clipboard_owner(&mut owner);
             let _ = set_clipboard_text_x11(text);
@@ -334,16 +722,30 @@ fn paste_text_x11(text: &str, shortcut: PasteShortcut) -> Result<(), PasteFailur
                 kind: PasteFailureKind::Failed,
                 message: error.to_string(),
                 transcript_on_clipboard: true,
+                chord_sent: false,
+                chord_delivery: None,
             });
         }
     };
 
-    info!("paste_chord_sent backend={backend}");
+    info!(
+        "paste_chord_sent backend={backend} delivery={}",
+        delivery.map_or("n/a", |delivery| delivery.as_str())
+    );
 
     // Keep the X11 selection owner alive long enough for clipboard managers and the
     // target application to read the transcript without racing restoration.
     sleep(Duration::from_millis(650));
 
+    if let Some(failure) = check_focus_unchanged(target_window) {
+        stop_x11_clipboard_owner(&mut owner);
+        let _ = set_clipboard_text_x11(text);
+        return Err(PasteFailure {
+            chord_delivery: delivery,
+            ..failure
+        });
+    }
+
     let Some(previous) = previous else {
         stop_x11_clipboard_owner(&mut owner);
         let _ = set_clipboard_text_x11(text);
@@ -353,6 +755,8 @@ fn paste_text_x11(text: &str, shortcut: PasteShortcut) -> Result<(), PasteFailur
             message: "Previous clipboard could not be snapshotted; transcript left on clipboard."
                 .to_string(),
             transcript_on_clipboard: true,
+            chord_sent: true,
+            chord_delivery: delivery,
         });
     };
 
@@ -364,6 +768,8 @@ fn paste_text_x11(text: &str, shortcut: PasteShortcut) -> Result<(), PasteFailur
             message: "Clipboard changed during paste window; not restoring previous clipboard."
                 .to_string(),
             transcript_on_clipboard: false,
+            chord_sent: true,
+            chord_delivery: delivery,
         });
     }
 
@@ -374,6 +780,8 @@ fn paste_text_x11(text: &str, shortcut: PasteShortcut) -> Result<(), PasteFailur
         kind: PasteFailureKind::Unconfirmed,
         message: format!("Failed to restore clipboard: {err}"),
         transcript_on_clipboard: true,
+        chord_sent: true,
+        chord_delivery: delivery,
     })?;
 
     info!("x11_paste_clipboard_restored");
@@ -381,27 +789,68 @@ fn paste_text_x11(text: &str, shortcut: PasteShortcut) -> Result<(), PasteFailur
     Ok(())
 }
 
+/// X11 id of the focused window, or None where it cannot be read (e.g. Wayland).
+fn focused_window_id() -> Option<u32> {
+    match x11::active_window_id() {
+        Ok(window) => window,
+        Err(error) => {
+            debug!("focused_window id unavailable: {error}");
+            None
+        }
+    }
+}
+
+/// Fails the paste when the window focused before the chord is no longer focused after
+/// the hold, e.g. because a notification or the overlay took focus mid-paste.
+fn check_focus_unchanged(before: Option<u32>) -> Option<PasteFailure> {
+    let before = before?;
+    let after = focused_window_id()?;
+    if before == after {
+        return None;
+    }
+    warn!("paste_focus_changed before={before:#x} after={after:#x}");
+    Some(PasteFailure {
+        step: PasteFailureStep::FocusChanged,
+        kind: PasteFailureKind::Failed,
+        message: "Focus moved to another window during paste; transcript left on clipboard."
+            .to_string(),
+        transcript_on_clipboard: true,
+        chord_sent: true,
+        chord_delivery: None,
+    })
+}
+
 fn is_wayland_session() -> bool {
     let xdg_session_type = std::env::var("XDG_SESSION_TYPE").unwrap_or_default();
     let wayland_display = std::env::var("WAYLAND_DISPLAY").unwrap_or_default();
     xdg_session_type == "wayland" || !wayland_display.is_empty()
 }
 
-fn send_paste_chord(shortcut: PasteShortcut) -> anyhow::Result<&'static str> {
+/// Sends the chord through the first working backend. uinput chords also report whether
+/// they were read back from the device.
+fn send_paste_chord(
+    shortcut: &PasteShortcut,
+) -> anyhow::Result<(&'static str, Option<ChordDelivery>)> {
     if is_wayland_session() {
-        uinput::send_paste(shortcut)?;
-        return Ok("uinput-wayland");
+        if virtual_keyboard::available() {
+            match virtual_keyboard::send_chord(shortcut.chord()) {
+                Ok(()) => return Ok(("virtual-keyboard", None)),
+                Err(error) => warn!("virtual keyboard chord failed, trying uinput: {error:#}"),
+            }
+        }
+        let delivery = uinput::send_paste(shortcut)?;
+        return Ok(("uinput-wayland", Some(delivery)));
     }
 
     arm_synthetic_paste_suppression(std::time::Duration::from_millis(400));
 
     // Prefer X11 injection on X11 sessions (e.g. VNC/Xvfb).
     match x11::send_paste(shortcut) {
-        Ok(()) => Ok("x11"),
+        Ok(()) => Ok(("x11", None)),
         Err(x11_err) => {
             // Fall back to uinput if available.
             match uinput::send_paste(shortcut) {
-                Ok(()) => Ok("uinput-fallback"),
+                Ok(delivery) => Ok(("uinput-fallback", Some(delivery))),
                 Err(uinput_err) => anyhow::bail!(
                     "X11 injection failed: {x11_err}; uinput injection failed: {uinput_err}"
                 ),
@@ -587,6 +1036,35 @@ fn choose_preferred_type_wayland(types: &[String]) -> Option<&str> {
         .map(|t| t.as_str())
 }
 
+fn set_primary_selection_text(text: &str) -> anyhow::Result<()> {
+    let mut child = match clipboard_backend() {
+        ClipboardBackend::Wayland => {
+            ensure_wayland_clipboard_ready()?;
+            Command::new(resolve_binary("wl-copy"))
+                .arg("--primary")
+                .stdin(Stdio::piped())
+                .spawn()?
+        }
+        ClipboardBackend::X11 => {
+            if !binary_in_path("xclip") {
+                anyhow::bail!("xclip not found (install xclip)");
+            }
+            Command::new(resolve_binary("xclip"))
+                .args(["-selection", "primary", "-in"])
+                .stdin(Stdio::piped())
+                .spawn()?
+        }
+    };
+    if let Some(stdin) = child.stdin.as_mut() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        anyhow::bail!("primary selection write failed with status {status}");
+    }
+    Ok(())
+}
+
 fn set_clipboard_text_wayland(text: &str) -> anyhow::Result<()> {
     ensure_wayland_clipboard_ready()?;
     let mut child = Command::new(resolve_binary("wl-copy"))
@@ -1,8 +1,14 @@
+mod history;
 mod injector;
 #[cfg(debug_assertions)]
 pub mod logs;
+pub mod notify;
+pub mod sound;
+mod terminal;
 pub mod tray;
+mod typing;
 pub mod uinput;
+pub mod virtual_keyboard;
 pub mod x11;
 
 pub use injector::{
@@ -0,0 +1,43 @@
+use std::process::{Command, Stdio};
+
+use tracing::debug;
+
+const PREVIEW_CHARS: usize = 120;
+
+/// Best-effort desktop notification via `notify-send`; silently skipped when it is missing.
+pub fn show(summary: &str, body: &str) {
+    let spawned = Command::new("notify-send")
+        // `--` so a transcript starting with `-` isn't parsed as an option.
+        .args([
+            "--app-name=OpenFlow",
+            "--expire-time=4000",
+            "--",
+            summary,
+            body,
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+
+    match spawned {
+        Ok(mut child) => {
+            // Reap in the background so we never block the caller on the notification daemon.
+            std::thread::spawn(move || {
+                let _ = child.wait();
+            });
+        }
+        Err(error) => debug!("notify-send unavailable: {error}"),
+    }
+}
+
+/// Single-line preview of a transcript for notification bodies.
+pub fn preview(text: &str) -> String {
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if flat.chars().count() <= PREVIEW_CHARS {
+        return flat;
+    }
+    let mut out: String = flat.chars().take(PREVIEW_CHARS).collect();
+    out.push('…');
+    out
+}
@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use crossbeam_channel::{unbounded, Sender};
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use rodio::source::{SineWave, Source};
+use rodio::{OutputStream, Sink};
+use tracing::{debug, warn};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundCue {
+    ListenStart,
+    ListenStop,
+    PasteSuccess,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundTheme {
+    Soft,
+    Click,
+    Chime,
+}
+
+impl SoundTheme {
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "click" => SoundTheme::Click,
+            "chime" => SoundTheme::Chime,
+            _ => SoundTheme::Soft,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SoundCueConfig {
+    pub enabled: bool,
+    pub theme: SoundTheme,
+    pub listen_start: bool,
+    pub listen_stop: bool,
+    pub paste_success: bool,
+    pub error: bool,
+}
+
+impl Default for SoundCueConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            theme: SoundTheme::Soft,
+            listen_start: true,
+            listen_stop: true,
+            paste_success: true,
+            error: true,
+        }
+    }
+}
+
+static CONFIG: Lazy<RwLock<SoundCueConfig>> = Lazy::new(|| RwLock::new(SoundCueConfig::default()));
+
+// rodio's OutputStream is not Send, so a single thread owns it and plays queued cues.
+static PLAYER: Lazy<Option<Sender<(SoundTheme, SoundCue)>>> = Lazy::new(spawn_player);
+
+pub fn configure(config: SoundCueConfig) {
+    *CONFIG.write() = config;
+}
+
+/// Plays a cue if cues are enabled and the event toggle is on. Never blocks.
+pub fn play(cue: SoundCue) {
+    let config = *CONFIG.read();
+    let wanted = match cue {
+        SoundCue::ListenStart => config.listen_start,
+        SoundCue::ListenStop => config.listen_stop,
+        SoundCue::PasteSuccess => config.paste_success,
+        SoundCue::Error => config.error,
+    };
+    if !config.enabled || !wanted {
+        return;
+    }
+
+    if let Some(sender) = PLAYER.as_ref() {
+        let _ = sender.send((config.theme, cue));
+    }
+}
+
+fn spawn_player() -> Option<Sender<(SoundTheme, SoundCue)>> {
+    let (sender, receiver) = unbounded::<(SoundTheme, SoundCue)>();
+    let spawned = std::thread::Builder::new()
+        .name("sound-cues".into())
+        .spawn(move || {
+            let (_stream, handle) = match OutputStream::try_default() {
+                Ok(output) => output,
+                Err(error) => {
+                    warn!("sound cues unavailable: {error}");
+                    return;
+                }
+            };
+
+            for (theme, cue) in receiver.iter() {
+                let sink = match Sink::try_new(&handle) {
+                    Ok(sink) => sink,
+                    Err(error) => {
+                        debug!("sound cue sink failed: {error}");
+                        continue;
+                    }
+                };
+                for (frequency, millis) in tones(theme, cue) {
+                    sink.append(
+                        SineWave::new(*frequency)
+                            .take_duration(Duration::from_millis(*millis))
+                            .fade_in(Duration::from_millis(8))
+                            .amplify(0.18),
+                    );
+                }
+                sink.detach();
+            }
+        });
+
+    match spawned {
+        Ok(_) => Some(sender),
+        Err(error) => {
+            warn!("failed to start sound cue thread: {error}");
+            None
+        }
+    }
+}
+
+/// (frequency Hz, duration ms) steps for each cue. Rising for start/success, falling for stop.
+fn tones(theme: SoundTheme, cue: SoundCue) -> &'static [(f32, u64)] {
+    match (theme, cue) {
+        (SoundTheme::Soft, SoundCue::ListenStart) => &[(660.0, 70), (880.0, 90)],
+        (SoundTheme::Soft, SoundCue::ListenStop) => &[(880.0, 70), (660.0, 90)],
+        (SoundTheme::Soft, SoundCue::PasteSuccess) => &[(990.0, 60)],
+        (SoundTheme::Soft, SoundCue::Error) => &[(330.0, 120), (262.0, 160)],
+        (SoundTheme::Click, SoundCue::ListenStart) => &[(1500.0, 15)],
+        (SoundTheme::Click, SoundCue::ListenStop) => &[(1100.0, 15)],
+        (SoundTheme::Click, SoundCue::PasteSuccess) => &[(1800.0, 12)],
+        (SoundTheme::Click, SoundCue::Error) => &[(400.0, 40), (400.0, 40)],
+        (SoundTheme::Chime, SoundCue::ListenStart) => &[(523.0, 80), (659.0, 80), (784.0, 120)],
+        (SoundTheme::Chime, SoundCue::ListenStop) => &[(784.0, 80), (659.0, 80), (523.0, 120)],
+        (SoundTheme::Chime, SoundCue::PasteSuccess) => &[(1047.0, 90), (1319.0, 140)],
+        (SoundTheme::Chime, SoundCue::Error) => &[(392.0, 140), (311.0, 200)],
+    }
+}
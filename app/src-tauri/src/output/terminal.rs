@@ -0,0 +1,71 @@
+use tracing::debug;
+
+use crate::output::x11;
+
+// WM_CLASS class names of common terminal emulators. Matched case-insensitively.
+const TERMINAL_WINDOW_CLASSES: &[&str] = &[
+    "alacritty",
+    "foot",
+    "footclient",
+    "ghostty",
+    "gnome-terminal",
+    "gnome-terminal-server",
+    "guake",
+    "kitty",
+    "konsole",
+    "lxterminal",
+    "mate-terminal",
+    "org.gnome.console",
+    "org.gnome.ptyxis",
+    "org.wezfurlong.wezterm",
+    "qterminal",
+    "rxvt",
+    "sakura",
+    "st-256color",
+    "terminator",
+    "terminology",
+    "tilix",
+    "urxvt",
+    "wezterm",
+    "xfce4-terminal",
+    "xterm",
+    "yakuake",
+];
+
+pub fn is_terminal_class(class: &str) -> bool {
+    let class = class.trim();
+    TERMINAL_WINDOW_CLASSES
+        .iter()
+        .any(|candidate| candidate.eq_ignore_ascii_case(class))
+}
+
+/// Best-effort `WM_CLASS` class of the focused window.
+///
+/// Only X11 exposes the focused window class; on Wayland this returns None and
+/// the configured paste shortcut is used unchanged.
+pub fn focused_window_class() -> Option<String> {
+    match x11::active_window_class() {
+        Ok(class) => {
+            debug!("focused_window class={class:?}");
+            class
+        }
+        Err(error) => {
+            debug!("focused_window class unavailable: {error}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_terminal_classes_case_insensitively() {
+        assert!(is_terminal_class("Alacritty"));
+        assert!(is_terminal_class("Gnome-terminal"));
+        assert!(is_terminal_class(" kitty "));
+        assert!(!is_terminal_class("firefox"));
+        assert!(!is_terminal_class(""));
+    }
+}
@@ -1,18 +1,104 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
 use tauri::{
+    image::Image,
+    include_image,
     menu::{Menu, MenuEvent, MenuItem},
     tray::TrayIcon,
-    App, Emitter, Manager, Runtime,
+    App, AppHandle, Emitter, Manager,
 };
 
+use crate::core::app_state::{open_settings_page, AppState};
+use crate::core::events;
+
+/// Template every tray variant is derived from; tints and badges are applied at runtime.
+const TRAY_TEMPLATE: Image<'_> = include_image!("./icons/tray-icon.png");
+const ANIMATION_FRAME: Duration = Duration::from_millis(450);
+const BADGE_COLOR: [u8; 3] = [235, 64, 52];
+
+static INDICATOR: Lazy<Mutex<TrayIndicator>> = Lazy::new(|| Mutex::new(TrayIndicator::default()));
+static ICON_CACHE: Lazy<Mutex<HashMap<IconKey, Image<'static>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+static ANIMATION_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TrayStatus {
+    #[default]
+    Idle,
+    Listening,
+    Processing,
+    Error,
+}
+
+impl TrayStatus {
+    pub fn from_hud_state(state: &str) -> Self {
+        match state {
+            "listening" => Self::Listening,
+            "processing" => Self::Processing,
+            "asr-error" | "secure-blocked" => Self::Error,
+            _ => Self::Idle,
+        }
+    }
+
+    fn tint(self) -> Option<[u8; 3]> {
+        match self {
+            Self::Idle => None,
+            Self::Listening => Some([32, 178, 255]),
+            Self::Processing => Some([255, 145, 38]),
+            Self::Error => Some([255, 94, 94]),
+        }
+    }
+
+    fn animated(self) -> bool {
+        matches!(self, Self::Listening | Self::Processing)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayBadge {
+    PasteFailed,
+    DownloadFailed,
+}
+
+#[derive(Debug, Default)]
+struct TrayIndicator {
+    status: TrayStatus,
+    paste_failed: bool,
+    download_failed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct IconKey {
+    status: TrayStatus,
+    badge: bool,
+    frame: u8,
+}
+
 pub fn initialize(app: &mut App) -> tauri::Result<()> {
     let handle = app.handle();
     let menu = Menu::new(app)?;
     let show_window = MenuItem::with_id(app, "show", "Show Window", true, None::<&str>)?;
+    let continuous = MenuItem::with_id(
+        app,
+        "continuous",
+        "Toggle Continuous Dictation",
+        true,
+        None::<&str>,
+    )?;
     let settings = MenuItem::with_id(app, "settings", "Settings", true, None::<&str>)?;
+    let models = MenuItem::with_id(app, "models", "Models", true, None::<&str>)?;
+    let permissions = MenuItem::with_id(app, "permissions", "Permissions", true, None::<&str>)?;
     let logs = MenuItem::with_id(app, "logs", "Logs", true, None::<&str>)?;
     let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
     menu.append(&show_window)?;
+    menu.append(&continuous)?;
     menu.append(&settings)?;
+    menu.append(&models)?;
+    menu.append(&permissions)?;
     menu.append(&logs)?;
     menu.append(&quit)?;
 
@@ -24,18 +110,29 @@ pub fn initialize(app: &mut App) -> tauri::Result<()> {
     Ok(())
 }
 
-fn attach_tray_handlers<R: Runtime>(tray: TrayIcon<R>, menu: Menu<R>) -> tauri::Result<()> {
+fn attach_tray_handlers(tray: TrayIcon, menu: Menu) -> tauri::Result<()> {
     tray.set_menu(Some(menu))?;
     tray.on_menu_event(|app, event: MenuEvent| match event.id().as_ref() {
         "show" => {
+            clear_badges(app);
             if let Some(window) = app.get_webview_window("main") {
                 let _ = window.show();
                 let _ = window.set_focus();
             }
         }
+        "continuous" => {
+            let app_handle = app.clone();
+            tauri::async_runtime::spawn_blocking(move || {
+                if let Some(state) = app_handle.try_state::<AppState>() {
+                    state.toggle_continuous(&app_handle);
+                }
+            });
+        }
         "settings" => {
             let _ = app.emit("open-settings", ());
         }
+        "models" => open_settings_page(app, events::ROUTE_MODELS),
+        "permissions" => open_settings_page(app, events::ROUTE_PERMISSIONS),
         "logs" => {
             #[cfg(debug_assertions)]
             {
@@ -50,3 +147,134 @@ fn attach_tray_handlers<R: Runtime>(tray: TrayIcon<R>, menu: Menu<R>) -> tauri::
     });
     Ok(())
 }
+
+/// Mirrors the HUD state on the tray icon; listening and processing pulse.
+pub fn set_status(app: &AppHandle, hud_state: &str) {
+    let status = TrayStatus::from_hud_state(hud_state);
+    {
+        let mut indicator = INDICATOR.lock();
+        if indicator.status == status {
+            return;
+        }
+        indicator.status = status;
+    }
+
+    let generation = ANIMATION_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    refresh(app, 0);
+    if status.animated() {
+        spawn_animation(app.clone(), generation);
+    }
+}
+
+/// Shows or clears the failure badge for one source; the badge stays while any source is set.
+pub fn set_badge(app: &AppHandle, badge: TrayBadge, active: bool) {
+    {
+        let mut indicator = INDICATOR.lock();
+        let slot = match badge {
+            TrayBadge::PasteFailed => &mut indicator.paste_failed,
+            TrayBadge::DownloadFailed => &mut indicator.download_failed,
+        };
+        if *slot == active {
+            return;
+        }
+        *slot = active;
+    }
+    refresh(app, 0);
+}
+
+pub fn clear_badges(app: &AppHandle) {
+    set_badge(app, TrayBadge::PasteFailed, false);
+    set_badge(app, TrayBadge::DownloadFailed, false);
+}
+
+fn spawn_animation(app: AppHandle, generation: u64) {
+    let spawned = std::thread::Builder::new()
+        .name("tray-animation".into())
+        .spawn(move || {
+            let mut frame = 0u8;
+            loop {
+                std::thread::sleep(ANIMATION_FRAME);
+                if ANIMATION_GENERATION.load(Ordering::SeqCst) != generation {
+                    break;
+                }
+                frame ^= 1;
+                refresh(&app, frame);
+            }
+        });
+    if let Err(error) = spawned {
+        tracing::debug!("tray animation unavailable: {error}");
+    }
+}
+
+fn refresh(app: &AppHandle, frame: u8) {
+    let key = {
+        let indicator = INDICATOR.lock();
+        IconKey {
+            status: indicator.status,
+            badge: indicator.paste_failed || indicator.download_failed,
+            frame,
+        }
+    };
+    let Some(tray) = app.tray_by_id("main") else {
+        return;
+    };
+
+    let icon = ICON_CACHE
+        .lock()
+        .entry(key)
+        .or_insert_with(|| render_icon(key))
+        .clone();
+    if let Err(error) = tray.set_icon(Some(icon)) {
+        tracing::debug!("failed to update tray icon: {error}");
+    }
+}
+
+fn render_icon(key: IconKey) -> Image<'static> {
+    let width = TRAY_TEMPLATE.width();
+    let height = TRAY_TEMPLATE.height();
+    let mut rgba = TRAY_TEMPLATE.rgba().to_vec();
+
+    if let Some(tint) = key.status.tint() {
+        let strength = if key.frame == 0 { 0.55 } else { 0.85 };
+        for pixel in rgba.chunks_exact_mut(4) {
+            for (channel, target) in pixel.iter_mut().zip(tint) {
+                *channel = mix(*channel, target, strength);
+            }
+        }
+    }
+
+    if key.badge {
+        draw_badge(&mut rgba, width, height);
+    }
+
+    Image::new_owned(rgba, width, height)
+}
+
+/// Solid dot in the bottom-right corner with a light rim so it reads on dark and light panels.
+fn draw_badge(rgba: &mut [u8], width: u32, height: u32) {
+    let radius = width.min(height) as f32 * 0.22;
+    let center_x = width as f32 - radius - 1.0;
+    let center_y = height as f32 - radius - 1.0;
+
+    for y in 0..height {
+        for x in 0..width {
+            let dx = x as f32 + 0.5 - center_x;
+            let dy = y as f32 + 0.5 - center_y;
+            let distance = (dx * dx + dy * dy).sqrt();
+            let color = if distance <= radius {
+                BADGE_COLOR
+            } else if distance <= radius + 1.0 {
+                [255, 255, 255]
+            } else {
+                continue;
+            };
+            let offset = ((y * width + x) * 4) as usize;
+            rgba[offset..offset + 3].copy_from_slice(&color);
+            rgba[offset + 3] = 255;
+        }
+    }
+}
+
+fn mix(from: u8, to: u8, amount: f32) -> u8 {
+    (from as f32 + (to as f32 - from as f32) * amount).round() as u8
+}
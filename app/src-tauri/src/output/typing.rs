@@ -0,0 +1,106 @@
+//! Key chords for typing text out character by character through the virtual keyboard.
+//! Chords assume a US layout, the one its uploaded keymap defines; X11 typing resolves
+//! keys from the server's active layout instead (see `x11::Typer`).
+
+use crate::core::hotkeys::KeyChord;
+use crate::output::terminal;
+
+/// Unshifted and shifted characters of the US layout's punctuation keys, by key name.
+const PUNCTUATION_KEYS: [(&str, char, char); 11] = [
+    ("Minus", '-', '_'),
+    ("Equal", '=', '+'),
+    ("LeftBrace", '[', '{'),
+    ("RightBrace", ']', '}'),
+    ("Semicolon", ';', ':'),
+    ("Apostrophe", '\'', '"'),
+    ("Grave", '`', '~'),
+    ("Backslash", '\\', '|'),
+    ("Comma", ',', '<'),
+    ("Dot", '.', '>'),
+    ("Slash", '/', '?'),
+];
+
+/// Shifted characters of the digit row, starting at `1`.
+const SHIFTED_DIGITS: [char; 10] = ['!', '@', '#', '$', '%', '^', '&', '*', '(', ')'];
+
+/// The chord that types `c`, or None when the layout has no key for it.
+pub fn chord_for_char(c: char) -> Option<KeyChord> {
+    let (key, shift) = match c {
+        'a'..='z' | '0'..='9' => (c.to_ascii_uppercase().to_string(), false),
+        'A'..='Z' => (c.to_string(), true),
+        ' ' => ("Space".to_string(), false),
+        '\n' => ("Enter".to_string(), false),
+        '\t' => ("Tab".to_string(), false),
+        _ => {
+            if let Some(index) = SHIFTED_DIGITS.iter().position(|&digit| digit == c) {
+                (((index + 1) % 10).to_string(), true)
+            } else {
+                let (name, plain, _) = PUNCTUATION_KEYS
+                    .iter()
+                    .find(|(_, plain, shifted)| *plain == c || *shifted == c)?;
+                (name.to_string(), *plain != c)
+            }
+        }
+    };
+    Some(KeyChord {
+        ctrl: false,
+        alt: false,
+        shift,
+        meta: false,
+        key,
+    })
+}
+
+/// Whether type-out text for a window of `class` goes through the paste path. Typed
+/// newlines reach a terminal as Enter and run each line, while its paste chord lets
+/// the terminal apply bracketed paste. Single lines are typed like anywhere else.
+pub fn terminal_pastes(class: Option<&str>, text: &str) -> bool {
+    text.contains('\n') && class.is_some_and(terminal::is_terminal_class)
+}
+
+/// The first character of `text` that can't be typed, if any.
+pub fn untypeable_char(text: &str) -> Option<char> {
+    text.chars()
+        .filter(|&c| c != '\r')
+        .find(|&c| chord_for_char(c).is_none())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_characters_to_us_layout_chords() {
+        let chord = chord_for_char('H').unwrap();
+        assert!(chord.shift);
+        assert_eq!(chord.key, "H");
+        assert_eq!(chord_for_char('?').unwrap().to_string(), "Shift+Slash");
+        assert_eq!(chord_for_char(')').unwrap().to_string(), "Shift+0");
+        assert_eq!(chord_for_char(',').unwrap().to_string(), "Comma");
+        assert_eq!(chord_for_char('\n').unwrap().to_string(), "Enter");
+        assert_eq!(untypeable_char("Hello, world!\n"), None);
+        assert_eq!(untypeable_char("Grüße"), Some('ü'));
+    }
+
+    #[test]
+    fn multi_line_text_for_terminals_is_pasted() {
+        let text = "git add -A\ngit commit";
+        assert!(terminal_pastes(Some("gnome-terminal-server"), text));
+        assert!(terminal_pastes(Some("Alacritty"), text));
+        assert!(!terminal_pastes(Some("firefox"), text));
+        assert!(!terminal_pastes(None, text));
+    }
+
+    #[test]
+    fn single_lines_are_typed_into_terminals() {
+        assert!(!terminal_pastes(
+            Some("gnome-terminal-server"),
+            "git status"
+        ));
+        assert!(!terminal_pastes(
+            Some("kitty"),
+            "echo \"typed, not pasted\""
+        ));
+        assert_eq!(untypeable_char("git status"), None);
+    }
+}
@@ -1,8 +1,12 @@
-use evdev::{uinput::VirtualDeviceBuilder, AttributeSet, EventType, InputEvent, Key};
+use evdev::{
+    uinput::VirtualDeviceBuilder, AttributeSet, Device, EventType, InputEvent, InputEventKind, Key,
+};
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
+use std::os::fd::AsRawFd;
 use std::thread::sleep;
 use std::time::Duration;
+use tracing::debug;
 
 use super::PasteShortcut;
 
@@ -11,6 +15,36 @@ pub const VIRTUAL_KEYBOARD_NAME: &str = "OpenFlow Virtual Keyboard";
 
 static VIRTUAL_KEYBOARD: Lazy<Mutex<Option<evdev::uinput::VirtualDevice>>> =
     Lazy::new(|| Mutex::new(None));
+/// A reader on our own event node. evdev hands every event to all readers unless one of
+/// them holds a grab, so seeing our chord here means the kernel passed it on.
+static ECHO_PROBE: Lazy<Mutex<Option<Device>>> = Lazy::new(|| Mutex::new(None));
+
+/// What the read-back after a uinput chord showed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChordDelivery {
+    /// The chord came back on our event node, so the compositor/X server could read it.
+    Echoed,
+    /// Another process holds an exclusive grab on the device; nobody else saw the chord.
+    Grabbed,
+    /// Readable node, but the chord did not come back.
+    NotEchoed,
+    /// The kernel lists no evdev handler for the device, so no reader can exist.
+    NoHandler,
+    /// The device is registered but our node can't be read (e.g. not in the input group).
+    Unverified,
+}
+
+impl ChordDelivery {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChordDelivery::Echoed => "echoed",
+            ChordDelivery::Grabbed => "grabbed",
+            ChordDelivery::NotEchoed => "not-echoed",
+            ChordDelivery::NoHandler => "no-handler",
+            ChordDelivery::Unverified => "unverified",
+        }
+    }
+}
 
 fn get_or_create_virtual_keyboard() -> anyhow::Result<bool> {
     let mut guard = VIRTUAL_KEYBOARD.lock();
@@ -18,10 +52,12 @@ fn get_or_create_virtual_keyboard() -> anyhow::Result<bool> {
         return Ok(false);
     }
 
+    // Advertise the standard keyboard range so any configured paste chord can be sent
+    // without rebuilding the device.
     let mut keys = AttributeSet::<Key>::new();
-    keys.insert(Key::KEY_LEFTCTRL);
-    keys.insert(Key::KEY_LEFTSHIFT);
-    keys.insert(Key::KEY_V);
+    for code in Key::KEY_ESC.code()..=Key::KEY_F24.code() {
+        keys.insert(Key::new(code));
+    }
 
     let device = VirtualDeviceBuilder::new()
         .map_err(|err| anyhow::anyhow!(err))?
@@ -45,25 +81,45 @@ pub fn prepare_virtual_keyboard() -> anyhow::Result<()> {
     Ok(())
 }
 
-pub fn send_paste(shortcut: PasteShortcut) -> anyhow::Result<()> {
+pub fn send_paste(shortcut: &PasteShortcut) -> anyhow::Result<ChordDelivery> {
     let _ = get_or_create_virtual_keyboard()?;
 
+    let chord = shortcut.chord();
+    let mut keys = Vec::with_capacity(5);
+    if chord.ctrl {
+        keys.push(Key::KEY_LEFTCTRL);
+    }
+    if chord.alt {
+        keys.push(Key::KEY_LEFTALT);
+    }
+    if chord.shift {
+        keys.push(Key::KEY_LEFTSHIFT);
+    }
+    if chord.meta {
+        keys.push(Key::KEY_LEFTMETA);
+    }
+    let main_key = chord.evdev_key()?;
+    keys.push(main_key);
+
     let mut guard = VIRTUAL_KEYBOARD.lock();
     let device = guard
         .as_mut()
         .ok_or_else(|| anyhow::anyhow!("virtual keyboard not initialized"))?;
+    let mut probe = ECHO_PROBE.lock();
+    if probe.is_none() {
+        *probe = open_echo_probe(device);
+    }
+    if let Some(reader) = probe.as_mut() {
+        // Drop anything left from earlier chords so only this one is counted.
+        drain(reader);
+    }
 
     let event_type = EventType::KEY;
-    let ctrl = Key::KEY_LEFTCTRL.code();
-    let shift = Key::KEY_LEFTSHIFT.code();
-    let v = Key::KEY_V.code();
-
-    let mut down_events = Vec::with_capacity(3);
-    down_events.push(InputEvent::new(event_type, ctrl, 1));
-    if matches!(shortcut, PasteShortcut::CtrlShiftV) {
-        down_events.push(InputEvent::new(event_type, shift, 1));
-    }
-    down_events.push(InputEvent::new(event_type, v, 1));
+
+    let down_events: Vec<InputEvent> = keys
+        .iter()
+        .map(|key| InputEvent::new(event_type, key.code(), 1))
+        .collect();
     device
         .emit(&down_events)
         .map_err(|err| anyhow::anyhow!(err))?;
@@ -71,15 +127,109 @@ pub fn send_paste(shortcut: PasteShortcut) -> anyhow::Result<()> {
     // A tiny delay helps some apps detect the chord reliably.
     sleep(Duration::from_millis(15));
 
-    let mut up_events = Vec::with_capacity(3);
-    up_events.push(InputEvent::new(event_type, v, 0));
-    if matches!(shortcut, PasteShortcut::CtrlShiftV) {
-        up_events.push(InputEvent::new(event_type, shift, 0));
-    }
-    up_events.push(InputEvent::new(event_type, ctrl, 0));
+    let up_events: Vec<InputEvent> = keys
+        .iter()
+        .rev()
+        .map(|key| InputEvent::new(event_type, key.code(), 0))
+        .collect();
     device
         .emit(&up_events)
         .map_err(|err| anyhow::anyhow!(err))?;
 
-    Ok(())
+    let delivery = match probe.as_mut() {
+        Some(reader) => verify_echo(reader, main_key),
+        None => match registered_handlers() {
+            Some(handlers) if handlers.split_whitespace().any(|h| h.starts_with("event")) => {
+                ChordDelivery::Unverified
+            }
+            _ => ChordDelivery::NoHandler,
+        },
+    };
+    debug!("uinput_chord_delivery result={}", delivery.as_str());
+    Ok(delivery)
+}
+
+fn open_echo_probe(device: &mut evdev::uinput::VirtualDevice) -> Option<Device> {
+    let path = device
+        .enumerate_dev_nodes_blocking()
+        .ok()?
+        .flatten()
+        .find(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("event"))
+        })?;
+    match Device::open(&path) {
+        Ok(reader) => {
+            let fd = reader.as_raw_fd();
+            unsafe {
+                let flags = libc::fcntl(fd, libc::F_GETFL);
+                if flags != -1 {
+                    let _ = libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK);
+                }
+            }
+            Some(reader)
+        }
+        Err(error) => {
+            debug!(
+                "uinput_echo_probe_unavailable path={} error={error}",
+                path.display()
+            );
+            None
+        }
+    }
+}
+
+fn drain(reader: &mut Device) {
+    while let Ok(events) = reader.fetch_events() {
+        if events.count() == 0 {
+            break;
+        }
+    }
+}
+
+/// uinput writes reach evdev readers synchronously, so the chord is already queued on
+/// the probe when `emit` returns.
+fn verify_echo(reader: &mut Device, key: Key) -> ChordDelivery {
+    let (mut pressed, mut released) = (false, false);
+    while let Ok(events) = reader.fetch_events() {
+        let mut any = false;
+        for event in events {
+            any = true;
+            if event.kind() == InputEventKind::Key(key) {
+                match event.value() {
+                    1 => pressed = true,
+                    0 => released = true,
+                    _ => {}
+                }
+            }
+        }
+        if !any {
+            break;
+        }
+    }
+    if pressed && released {
+        return ChordDelivery::Echoed;
+    }
+    // A grab elsewhere makes our own grab attempt fail with EBUSY.
+    match reader.grab() {
+        Ok(()) => {
+            let _ = reader.ungrab();
+            ChordDelivery::NotEchoed
+        }
+        Err(error) if error.raw_os_error() == Some(libc::EBUSY) => ChordDelivery::Grabbed,
+        Err(_) => ChordDelivery::NotEchoed,
+    }
+}
+
+/// The `Handlers=` list of our device in /proc/bus/input/devices, if it is registered.
+fn registered_handlers() -> Option<String> {
+    let devices = std::fs::read_to_string("/proc/bus/input/devices").ok()?;
+    let name_line = format!("N: Name=\"{VIRTUAL_KEYBOARD_NAME}\"");
+    devices
+        .split("\n\n")
+        .find(|block| block.lines().any(|line| line.trim() == name_line))?
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("H: Handlers="))
+        .map(str::to_string)
 }
@@ -0,0 +1,173 @@
+//! Key injection through the `zwp_virtual_keyboard_v1` Wayland protocol. wlroots-style
+//! compositors (sway, Hyprland, river) expose it to clients, so chords can be sent
+//! without write access to /dev/uinput.
+
+use std::fs::File;
+use std::io::Write;
+use std::os::fd::{AsFd, FromRawFd};
+use std::time::Instant;
+
+use anyhow::{anyhow, bail, Context, Result};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use tracing::info;
+use wayland_client::globals::{registry_queue_init, GlobalListContents};
+use wayland_client::protocol::{wl_registry, wl_seat};
+use wayland_client::{delegate_noop, Connection, Dispatch, EventQueue, QueueHandle};
+use wayland_protocols_misc::zwp_virtual_keyboard_v1::client::zwp_virtual_keyboard_manager_v1::ZwpVirtualKeyboardManagerV1;
+use wayland_protocols_misc::zwp_virtual_keyboard_v1::client::zwp_virtual_keyboard_v1::ZwpVirtualKeyboardV1;
+
+use crate::core::compositor;
+use crate::core::hotkeys::KeyChord;
+
+/// `wl_keyboard.keymap_format.xkb_v1`.
+const KEYMAP_FORMAT_XKB_V1: u32 = 1;
+/// evdev keycodes and a US layout, resolved by xkbcommon on the compositor side. Key
+/// events then carry plain evdev codes, the same ones the uinput backend sends.
+const KEYMAP: &str = "xkb_keymap {
+  xkb_keycodes { include \"evdev+aliases(qwerty)\" };
+  xkb_types { include \"complete\" };
+  xkb_compat { include \"complete\" };
+  xkb_symbols { include \"pc+us+inet(evdev)\" };
+};
+";
+// Modifier masks of the keymap above.
+const MOD_SHIFT: u32 = 1 << 0;
+const MOD_CONTROL: u32 = 1 << 2;
+const MOD_ALT: u32 = 1 << 3;
+const MOD_SUPER: u32 = 1 << 6;
+
+static KEYBOARD: Lazy<Mutex<Option<VirtualKeyboard>>> = Lazy::new(|| Mutex::new(None));
+
+struct State;
+
+impl Dispatch<wl_registry::WlRegistry, GlobalListContents> for State {
+    fn event(
+        _state: &mut Self,
+        _registry: &wl_registry::WlRegistry,
+        _event: wl_registry::Event,
+        _data: &GlobalListContents,
+        _conn: &Connection,
+        _queue: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+delegate_noop!(State: ignore wl_seat::WlSeat);
+delegate_noop!(State: ZwpVirtualKeyboardManagerV1);
+delegate_noop!(State: ZwpVirtualKeyboardV1);
+
+struct VirtualKeyboard {
+    connection: Connection,
+    queue: EventQueue<State>,
+    keyboard: ZwpVirtualKeyboardV1,
+    created: Instant,
+}
+
+impl VirtualKeyboard {
+    fn connect() -> Result<Self> {
+        let connection = Connection::connect_to_env().context("connect to Wayland display")?;
+        let (globals, mut queue) =
+            registry_queue_init::<State>(&connection).context("read Wayland globals")?;
+        let handle = queue.handle();
+        let seat: wl_seat::WlSeat = globals.bind(&handle, 1..=7, ()).context("bind wl_seat")?;
+        let manager: ZwpVirtualKeyboardManagerV1 = globals
+            .bind(&handle, 1..=1, ())
+            .context("bind zwp_virtual_keyboard_manager_v1")?;
+        let keyboard = manager.create_virtual_keyboard(&seat, &handle, ());
+
+        let keymap = keymap_file()?;
+        keyboard.keymap(
+            KEYMAP_FORMAT_XKB_V1,
+            keymap.as_fd(),
+            KEYMAP.len() as u32 + 1,
+        );
+        queue.roundtrip(&mut State).context("upload keymap")?;
+        Ok(Self {
+            connection,
+            queue,
+            keyboard,
+            created: Instant::now(),
+        })
+    }
+
+    fn send_chord(&mut self, chord: &KeyChord) -> Result<()> {
+        let key = u32::from(chord.evdev_key()?.code());
+        let mut mods = 0;
+        for (held, mask) in [
+            (chord.ctrl, MOD_CONTROL),
+            (chord.alt, MOD_ALT),
+            (chord.shift, MOD_SHIFT),
+            (chord.meta, MOD_SUPER),
+        ] {
+            if held {
+                mods |= mask;
+            }
+        }
+
+        self.keyboard.modifiers(mods, 0, 0, 0);
+        self.keyboard.key(self.timestamp(), key, 1);
+        self.keyboard.key(self.timestamp(), key, 0);
+        self.keyboard.modifiers(0, 0, 0, 0);
+        self.connection.flush().context("flush virtual keyboard")?;
+        // The compositor has handled the chord once the roundtrip returns.
+        self.queue
+            .roundtrip(&mut State)
+            .context("virtual keyboard roundtrip")?;
+        Ok(())
+    }
+
+    fn timestamp(&self) -> u32 {
+        self.created.elapsed().as_millis() as u32
+    }
+}
+
+impl Drop for VirtualKeyboard {
+    fn drop(&mut self) {
+        self.keyboard.destroy();
+        let _ = self.connection.flush();
+    }
+}
+
+/// The keymap in an anonymous file, NUL-terminated as the protocol expects.
+fn keymap_file() -> Result<File> {
+    let fd = unsafe { libc::memfd_create(b"openflow-keymap\0".as_ptr().cast(), libc::MFD_CLOEXEC) };
+    if fd < 0 {
+        bail!("memfd_create: {}", std::io::Error::last_os_error());
+    }
+    let mut file = unsafe { File::from_raw_fd(fd) };
+    file.write_all(KEYMAP.as_bytes())
+        .and_then(|()| file.write_all(&[0]))
+        .context("write keymap")?;
+    Ok(file)
+}
+
+/// The compositor probe picked this backend for paste chords.
+pub fn available() -> bool {
+    compositor::capabilities()
+        .is_some_and(|capabilities| capabilities.injection == "virtual-keyboard")
+}
+
+/// Connects ahead of the first paste so it doesn't pay for the keymap upload.
+pub fn prepare() -> Result<()> {
+    let mut guard = KEYBOARD.lock();
+    if guard.is_none() {
+        *guard = Some(VirtualKeyboard::connect()?);
+        info!("virtual_keyboard_ready");
+    }
+    Ok(())
+}
+
+pub fn send_chord(chord: &KeyChord) -> Result<()> {
+    prepare()?;
+    let mut guard = KEYBOARD.lock();
+    let keyboard = guard
+        .as_mut()
+        .ok_or_else(|| anyhow!("virtual keyboard not initialized"))?;
+    let sent = keyboard.send_chord(chord);
+    if sent.is_err() {
+        // A broken connection (e.g. compositor restart) is reopened on the next chord.
+        *guard = None;
+    }
+    sent
+}
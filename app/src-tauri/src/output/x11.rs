@@ -11,8 +11,10 @@ const XK_CONTROL_L: u32 = 0xffe3;
 const XK_CONTROL_R: u32 = 0xffe4;
 const XK_SHIFT_L: u32 = 0xffe1;
 const XK_SHIFT_R: u32 = 0xffe2;
-const XK_V_UPPER: u32 = 0x0056;
-const XK_V_LOWER: u32 = 0x0076;
+const XK_ALT_L: u32 = 0xffe9;
+const XK_ALT_R: u32 = 0xffea;
+const XK_SUPER_L: u32 = 0xffeb;
+const XK_SUPER_R: u32 = 0xffec;
 
 fn is_wayland_session() -> bool {
     let xdg_session_type = std::env::var("XDG_SESSION_TYPE").unwrap_or_default();
@@ -20,7 +22,7 @@ fn is_wayland_session() -> bool {
     xdg_session_type == "wayland" || !wayland_display.is_empty()
 }
 
-pub fn send_paste(shortcut: PasteShortcut) -> anyhow::Result<()> {
+pub fn send_paste(shortcut: &PasteShortcut) -> anyhow::Result<()> {
     // This backend is only intended for X11.
     if is_wayland_session() {
         anyhow::bail!("x11 paste backend is not available on Wayland");
@@ -44,14 +46,37 @@ pub fn send_paste(shortcut: PasteShortcut) -> anyhow::Result<()> {
         anyhow::bail!("XTEST extension not available");
     }
 
-    let ctrl = keycode_for_any_keysym(&conn, &[XK_CONTROL_L, XK_CONTROL_R])
-        .context("resolve Control keycode")?;
-    let shift = keycode_for_any_keysym(&conn, &[XK_SHIFT_L, XK_SHIFT_R])
-        .context("resolve Shift keycode")?;
-
-    // Prefer lowercase v. Keycode is layout-dependent.
-    let v =
-        keycode_for_any_keysym(&conn, &[XK_V_LOWER, XK_V_UPPER]).context("resolve V keycode")?;
+    let chord = shortcut.chord();
+    let mut keycodes = Vec::with_capacity(5);
+    if chord.ctrl {
+        keycodes.push(
+            keycode_for_any_keysym(&conn, &[XK_CONTROL_L, XK_CONTROL_R])
+                .context("resolve Control keycode")?,
+        );
+    }
+    if chord.alt {
+        keycodes.push(
+            keycode_for_any_keysym(&conn, &[XK_ALT_L, XK_ALT_R]).context("resolve Alt keycode")?,
+        );
+    }
+    if chord.shift {
+        keycodes.push(
+            keycode_for_any_keysym(&conn, &[XK_SHIFT_L, XK_SHIFT_R])
+                .context("resolve Shift keycode")?,
+        );
+    }
+    if chord.meta {
+        keycodes.push(
+            keycode_for_any_keysym(&conn, &[XK_SUPER_L, XK_SUPER_R])
+                .context("resolve Super keycode")?,
+        );
+    }
+    // Keycode is layout-dependent; resolved through the same table as hotkeys.
+    keycodes.push(
+        chord
+            .x11_keycode(&conn)
+            .with_context(|| format!("resolve {} keycode", chord.key))?,
+    );
 
     use x11rb::protocol::xproto;
     use x11rb::protocol::xtest::ConnectionExt as _;
@@ -59,25 +84,14 @@ pub fn send_paste(shortcut: PasteShortcut) -> anyhow::Result<()> {
     let press = xproto::KEY_PRESS_EVENT;
     let release = xproto::KEY_RELEASE_EVENT;
 
-    // Press
-    conn.xtest_fake_input(press, ctrl, 0, root, 0, 0, 0)
-        .context("xtest ctrl down")?;
-    if matches!(shortcut, PasteShortcut::CtrlShiftV) {
-        conn.xtest_fake_input(press, shift, 0, root, 0, 0, 0)
-            .context("xtest shift down")?;
+    for &keycode in &keycodes {
+        conn.xtest_fake_input(press, keycode, 0, root, 0, 0, 0)
+            .context("xtest key down")?;
     }
-    conn.xtest_fake_input(press, v, 0, root, 0, 0, 0)
-        .context("xtest v down")?;
-
-    // Release
-    conn.xtest_fake_input(release, v, 0, root, 0, 0, 0)
-        .context("xtest v up")?;
-    if matches!(shortcut, PasteShortcut::CtrlShiftV) {
-        conn.xtest_fake_input(release, shift, 0, root, 0, 0, 0)
-            .context("xtest shift up")?;
+    for &keycode in keycodes.iter().rev() {
+        conn.xtest_fake_input(release, keycode, 0, root, 0, 0, 0)
+            .context("xtest key up")?;
     }
-    conn.xtest_fake_input(release, ctrl, 0, root, 0, 0, 0)
-        .context("xtest ctrl up")?;
 
     conn.flush().context("flush X11")?;
     Ok(())
@@ -129,3 +143,332 @@ fn keycode_for_keysym<C: x11rb::connection::Connection>(
 
     Ok(None)
 }
+
+/// Class and title of the focused X11 window.
+#[derive(Debug, Clone, Default)]
+pub struct ActiveWindow {
+    pub class: Option<String>,
+    pub title: Option<String>,
+}
+
+/// Returns the `WM_CLASS` class name of the currently focused X11 window, if any.
+pub fn active_window_class() -> anyhow::Result<Option<String>> {
+    Ok(active_window()?.and_then(|window| window.class))
+}
+
+/// Returns the X11 id of the currently focused window, if any.
+pub fn active_window_id() -> anyhow::Result<Option<u32>> {
+    let (conn, root) = connect()?;
+    focused_window(&conn, root)
+}
+
+/// Returns the class and title of the currently focused X11 window, if any.
+pub fn active_window() -> anyhow::Result<Option<ActiveWindow>> {
+    use x11rb::protocol::xproto::AtomEnum;
+
+    let (conn, root) = connect()?;
+    let Some(window) = focused_window(&conn, root)? else {
+        return Ok(None);
+    };
+
+    let class = conn
+        .get_property(false, window, AtomEnum::WM_CLASS, AtomEnum::STRING, 0, 256)
+        .context("get WM_CLASS")?
+        .reply()
+        .context("read WM_CLASS")?;
+
+    // WM_CLASS is two NUL-terminated strings: instance name, then class name.
+    let class_name = class
+        .value
+        .split(|byte| *byte == 0)
+        .filter(|part| !part.is_empty())
+        .map(|part| String::from_utf8_lossy(part).into_owned())
+        .last();
+
+    Ok(Some(ActiveWindow {
+        class: class_name,
+        title: window_title(&conn, window)?,
+    }))
+}
+
+/// Whether the focused X11 window has `_NET_WM_STATE_FULLSCREEN` set.
+pub fn active_window_fullscreen() -> anyhow::Result<bool> {
+    use x11rb::protocol::xproto::AtomEnum;
+
+    let (conn, root) = connect()?;
+    let Some(window) = focused_window(&conn, root)? else {
+        return Ok(false);
+    };
+
+    let intern = |name: &[u8]| -> anyhow::Result<u32> {
+        Ok(conn
+            .intern_atom(true, name)
+            .context("intern _NET_WM_STATE atom")?
+            .reply()
+            .context("read _NET_WM_STATE atom")?
+            .atom)
+    };
+    let state_atom = intern(b"_NET_WM_STATE")?;
+    let fullscreen_atom = intern(b"_NET_WM_STATE_FULLSCREEN")?;
+    if state_atom == x11rb::NONE || fullscreen_atom == x11rb::NONE {
+        return Ok(false);
+    }
+
+    let state = conn
+        .get_property(false, window, state_atom, AtomEnum::ATOM, 0, 64)
+        .context("get _NET_WM_STATE")?
+        .reply()
+        .context("read _NET_WM_STATE")?;
+    Ok(state
+        .value32()
+        .is_some_and(|mut atoms| atoms.any(|atom| atom == fullscreen_atom)))
+}
+
+fn connect() -> anyhow::Result<(x11rb::rust_connection::RustConnection, u32)> {
+    if is_wayland_session() {
+        anyhow::bail!("x11 window inspection is not available on Wayland");
+    }
+
+    let display = std::env::var("DISPLAY").unwrap_or_default();
+    if display.trim().is_empty() {
+        anyhow::bail!("DISPLAY is not set");
+    }
+
+    let (conn, screen_num) = x11rb::connect(None).context("connect to X11")?;
+    let root = conn.setup().roots[screen_num].root;
+    Ok((conn, root))
+}
+
+/// `_NET_ACTIVE_WINDOW` of the root window.
+fn focused_window<C: x11rb::connection::Connection>(
+    conn: &C,
+    root: u32,
+) -> anyhow::Result<Option<u32>> {
+    use x11rb::protocol::xproto::AtomEnum;
+
+    let active_atom = conn
+        .intern_atom(true, b"_NET_ACTIVE_WINDOW")
+        .context("intern _NET_ACTIVE_WINDOW")?
+        .reply()
+        .context("read _NET_ACTIVE_WINDOW atom")?
+        .atom;
+    if active_atom == x11rb::NONE {
+        return Ok(None);
+    }
+
+    let active = conn
+        .get_property(false, root, active_atom, AtomEnum::WINDOW, 0, 1)
+        .context("get _NET_ACTIVE_WINDOW")?
+        .reply()
+        .context("read _NET_ACTIVE_WINDOW")?;
+    Ok(active
+        .value32()
+        .and_then(|mut values| values.next())
+        .filter(|window| *window != x11rb::NONE))
+}
+
+/// `_NET_WM_NAME` (UTF-8), falling back to the legacy `WM_NAME`.
+fn window_title<C: x11rb::connection::Connection>(
+    conn: &C,
+    window: u32,
+) -> anyhow::Result<Option<String>> {
+    use x11rb::protocol::xproto::AtomEnum;
+
+    let net_wm_name = conn
+        .intern_atom(true, b"_NET_WM_NAME")
+        .context("intern _NET_WM_NAME")?
+        .reply()
+        .context("read _NET_WM_NAME atom")?
+        .atom;
+    let utf8_string = conn
+        .intern_atom(true, b"UTF8_STRING")
+        .context("intern UTF8_STRING")?
+        .reply()
+        .context("read UTF8_STRING atom")?
+        .atom;
+
+    let mut candidates: Vec<(u32, u32)> = vec![(AtomEnum::WM_NAME.into(), AtomEnum::ANY.into())];
+    if net_wm_name != x11rb::NONE && utf8_string != x11rb::NONE {
+        candidates.insert(0, (net_wm_name, utf8_string));
+    }
+    for (property, kind) in candidates {
+        let reply = conn
+            .get_property(false, window, property, kind, 0, 1024)
+            .context("get window title")?
+            .reply()
+            .context("read window title")?;
+        let title = String::from_utf8_lossy(&reply.value).trim().to_string();
+        if !title.is_empty() {
+            return Ok(Some(title));
+        }
+    }
+    Ok(None)
+}
+
+// Keysyms for the whitespace type-out needs; printable characters map directly.
+const XK_TAB: u32 = 0xff09;
+const XK_RETURN: u32 = 0xff0d;
+const XK_ISO_LEVEL3_SHIFT: u32 = 0xfe03;
+
+/// X11 keysym for `c`: Latin-1 characters are their own keysym, the rest of Unicode
+/// sits at `0x0100_0000 + codepoint`.
+fn keysym_for_char(c: char) -> u32 {
+    match c {
+        '\n' => XK_RETURN,
+        '\t' => XK_TAB,
+        ' '..='~' | '\u{a0}'..='\u{ff}' => u32::from(c),
+        _ => 0x0100_0000 + u32::from(c),
+    }
+}
+
+/// Keycode and modifiers that produce `keysym` in a core keyboard mapping. Columns 0/1
+/// are the first group's plain and Shift levels, 4/5 its AltGr levels.
+fn find_keysym(mapping: &[(u8, Vec<u32>)], keysym: u32) -> Option<(u8, bool, bool)> {
+    for (column, shift, level3) in [
+        (0, false, false),
+        (1, true, false),
+        (4, false, true),
+        (5, true, true),
+    ] {
+        if let Some((keycode, _)) = mapping
+            .iter()
+            .find(|(_, keysyms)| keysyms.get(column) == Some(&keysym))
+        {
+            return Some((*keycode, shift, level3));
+        }
+    }
+    None
+}
+
+/// Types characters through XTEST using the keycodes of the server's active layout, so
+/// a German or French keymap types what the transcript says.
+pub struct Typer {
+    conn: x11rb::rust_connection::RustConnection,
+    root: u32,
+    mapping: Vec<(u8, Vec<u32>)>,
+    shift: Option<u8>,
+    level3: Option<u8>,
+}
+
+impl Typer {
+    pub fn connect() -> anyhow::Result<Self> {
+        let (conn, root) = connect()?;
+        let xtest = conn
+            .query_extension(b"XTEST")
+            .context("query XTEST extension")?
+            .reply()
+            .context("read XTEST extension reply")?;
+        if !xtest.present {
+            anyhow::bail!("XTEST extension not available");
+        }
+
+        let setup = conn.setup();
+        let (min, max) = (setup.min_keycode, setup.max_keycode);
+        if max < min {
+            anyhow::bail!("X server reports no keycodes");
+        }
+        let reply = conn
+            .get_keyboard_mapping(min, max - min + 1)
+            .context("get_keyboard_mapping")?
+            .reply()
+            .context("read keyboard mapping")?;
+        let per = usize::from(reply.keysyms_per_keycode);
+        if per == 0 {
+            anyhow::bail!("X server reports an empty keyboard mapping");
+        }
+        let mapping: Vec<(u8, Vec<u32>)> = reply
+            .keysyms
+            .chunks(per)
+            .enumerate()
+            .map(|(index, keysyms)| (min + index as u8, keysyms.to_vec()))
+            .collect();
+        let shift = [XK_SHIFT_L, XK_SHIFT_R]
+            .iter()
+            .find_map(|&keysym| find_keysym(&mapping, keysym).map(|(code, ..)| code));
+        let level3 = find_keysym(&mapping, XK_ISO_LEVEL3_SHIFT).map(|(code, ..)| code);
+        Ok(Self {
+            conn,
+            root,
+            mapping,
+            shift,
+            level3,
+        })
+    }
+
+    /// Keycodes to hold, in press order, to type `c`; None when the layout lacks it.
+    fn keycodes(&self, c: char) -> Option<Vec<u8>> {
+        let (keycode, shift, level3) = find_keysym(&self.mapping, keysym_for_char(c))?;
+        let mut keycodes = Vec::with_capacity(3);
+        if shift {
+            keycodes.push(self.shift?);
+        }
+        if level3 {
+            keycodes.push(self.level3?);
+        }
+        keycodes.push(keycode);
+        Some(keycodes)
+    }
+
+    /// The first character of `text` the active layout can't type, if any.
+    pub fn untypeable_char(&self, text: &str) -> Option<char> {
+        text.chars()
+            .filter(|&c| c != '\r')
+            .find(|&c| self.keycodes(c).is_none())
+    }
+
+    pub fn type_char(&self, c: char) -> anyhow::Result<()> {
+        use x11rb::protocol::xproto;
+        use x11rb::protocol::xtest::ConnectionExt as _;
+
+        let keycodes = self
+            .keycodes(c)
+            .with_context(|| format!("no key for {c:?} in the active layout"))?;
+        for &keycode in &keycodes {
+            self.conn
+                .xtest_fake_input(xproto::KEY_PRESS_EVENT, keycode, 0, self.root, 0, 0, 0)
+                .context("xtest key down")?;
+        }
+        for &keycode in keycodes.iter().rev() {
+            self.conn
+                .xtest_fake_input(xproto::KEY_RELEASE_EVENT, keycode, 0, self.root, 0, 0, 0)
+                .context("xtest key up")?;
+        }
+        self.conn.flush().context("flush X11")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_characters_from_the_active_layout() {
+        // A slice of the German layout: Y and Z swapped, @ on AltGr+Q.
+        let mapping = vec![
+            (24, vec![0x71, 0x51, 0x71, 0x51, 0x40, 0]),
+            (29, vec![0x7a, 0x5a, 0x7a, 0x5a, 0, 0]),
+            (52, vec![0x79, 0x59, 0x79, 0x59, 0, 0]),
+            (36, vec![XK_RETURN, 0, XK_RETURN, 0, 0, 0]),
+        ];
+        assert_eq!(
+            find_keysym(&mapping, keysym_for_char('z')),
+            Some((29, false, false))
+        );
+        assert_eq!(
+            find_keysym(&mapping, keysym_for_char('Y')),
+            Some((52, true, false))
+        );
+        assert_eq!(
+            find_keysym(&mapping, keysym_for_char('@')),
+            Some((24, false, true))
+        );
+        assert_eq!(
+            find_keysym(&mapping, keysym_for_char('\n')),
+            Some((36, false, false))
+        );
+        assert_eq!(keysym_for_char('ü'), 0xfc);
+        assert_eq!(keysym_for_char('€'), 0x0100_20ac);
+        assert_eq!(find_keysym(&mapping, keysym_for_char('x')), None);
+    }
+}
@@ -1,5 +1,9 @@
 mod engine;
 #[cfg(feature = "vad-silero")]
 pub mod silero;
+mod speaker;
 
 pub use engine::{VadBackend, VadConfig, VadDecision, VadObservation, VoiceActivityDetector};
+pub use speaker::{
+    SpeakerGate, SpeakerProfileSummary, DEFAULT_SPEAKER_THRESHOLD, SPEAKER_MODEL_ENV,
+};
@@ -0,0 +1,274 @@
+//! Speaker-profile gate: drops speech that doesn't sound like the enrolled user
+//! (TV, office chatter) before it reaches ASR.
+//!
+//! Embeddings come from a small speaker-verification ONNX model loaded through
+//! sherpa-onnx; the enrolled profile is a single averaged embedding on disk.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use anyhow::{anyhow, Context, Result};
+use directories::ProjectDirs;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+const PROFILE_FILE: &str = "speaker_profile.json";
+pub const SPEAKER_MODEL_ENV: &str = "OPENFLOW_SPEAKER_MODEL";
+pub const DEFAULT_SPEAKER_THRESHOLD: f32 = 0.5;
+/// Embeddings from shorter clips are too noisy to reject on.
+const MIN_GATED_SECONDS: f32 = 0.8;
+/// Enrollment audio is embedded in windows this long and averaged.
+const ENROLLMENT_WINDOW_SECONDS: f32 = 3.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SpeakerProfile {
+    embedding: Vec<f32>,
+    #[serde(with = "time::serde::rfc3339")]
+    enrolled_at: OffsetDateTime,
+    enrollment_seconds: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeakerProfileSummary {
+    #[serde(with = "time::serde::rfc3339")]
+    pub enrolled_at: OffsetDateTime,
+    pub enrollment_seconds: f32,
+}
+
+impl From<&SpeakerProfile> for SpeakerProfileSummary {
+    fn from(profile: &SpeakerProfile) -> Self {
+        Self {
+            enrolled_at: profile.enrolled_at,
+            enrollment_seconds: profile.enrollment_seconds,
+        }
+    }
+}
+
+pub struct SpeakerGate {
+    enabled: AtomicBool,
+    threshold: Mutex<f32>,
+    profile_path: Option<PathBuf>,
+    profile: Mutex<Option<SpeakerProfile>>,
+    embedder: Mutex<Option<(PathBuf, embedder::SpeakerEmbedder)>>,
+}
+
+impl SpeakerGate {
+    pub fn new() -> Self {
+        let profile_path = resolve_profile_path().ok();
+        let profile = profile_path
+            .as_deref()
+            .and_then(|path| match load_profile(path) {
+                Ok(profile) => profile,
+                Err(error) => {
+                    tracing::warn!("ignoring unreadable speaker profile: {error:?}");
+                    None
+                }
+            });
+        Self {
+            enabled: AtomicBool::new(false),
+            threshold: Mutex::new(DEFAULT_SPEAKER_THRESHOLD),
+            profile_path,
+            profile: Mutex::new(profile),
+            embedder: Mutex::new(None),
+        }
+    }
+
+    pub fn configure(&self, enabled: bool, threshold: f32) {
+        self.enabled.store(enabled, Ordering::SeqCst);
+        *self.threshold.lock() = threshold.clamp(0.0, 1.0);
+    }
+
+    /// True when the gate is switched on and there is a profile to compare against.
+    pub fn is_active(&self) -> bool {
+        self.enabled.load(Ordering::SeqCst) && self.profile.lock().is_some()
+    }
+
+    pub fn profile_summary(&self) -> Option<SpeakerProfileSummary> {
+        self.profile
+            .lock()
+            .as_ref()
+            .map(SpeakerProfileSummary::from)
+    }
+
+    /// Returns whether `samples` match the enrolled speaker, with the similarity
+    /// score. Clips too short to judge, and a missing profile, always pass.
+    pub fn accepts(&self, sample_rate: u32, samples: &[f32]) -> Result<(bool, Option<f32>)> {
+        if (samples.len() as f32) < MIN_GATED_SECONDS * sample_rate as f32 {
+            return Ok((true, None));
+        }
+        let Some(profile) = self.profile.lock().as_ref().map(|p| p.embedding.clone()) else {
+            return Ok((true, None));
+        };
+        let embedding = self.embed(sample_rate, samples)?;
+        let similarity = cosine_similarity(&embedding, &profile);
+        Ok((similarity >= *self.threshold.lock(), Some(similarity)))
+    }
+
+    /// Builds and stores a profile from enrollment audio.
+    pub fn enroll(&self, sample_rate: u32, samples: &[f32]) -> Result<SpeakerProfileSummary> {
+        let window = (ENROLLMENT_WINDOW_SECONDS * sample_rate as f32) as usize;
+        let mut embeddings = Vec::new();
+        for chunk in samples.chunks(window.max(1)) {
+            if (chunk.len() as f32) < MIN_GATED_SECONDS * sample_rate as f32 {
+                continue;
+            }
+            embeddings.push(self.embed(sample_rate, chunk)?);
+        }
+        let embedding = average_embedding(&embeddings)
+            .ok_or_else(|| anyhow!("enrollment recording was too short"))?;
+
+        let profile = SpeakerProfile {
+            embedding,
+            enrolled_at: OffsetDateTime::now_utc(),
+            enrollment_seconds: samples.len() as f32 / sample_rate as f32,
+        };
+        if let Some(path) = &self.profile_path {
+            persist_profile(path, &profile)?;
+        }
+        let summary = SpeakerProfileSummary::from(&profile);
+        *self.profile.lock() = Some(profile);
+        Ok(summary)
+    }
+
+    pub fn clear_profile(&self) -> Result<()> {
+        *self.profile.lock() = None;
+        if let Some(path) = &self.profile_path {
+            if path.exists() {
+                fs::remove_file(path).with_context(|| format!("remove {path:?}"))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn embed(&self, sample_rate: u32, samples: &[f32]) -> Result<Vec<f32>> {
+        let model = std::env::var_os(SPEAKER_MODEL_ENV)
+            .map(PathBuf::from)
+            .ok_or_else(|| anyhow!("speaker model is not installed"))?;
+
+        let mut guard = self.embedder.lock();
+        let stale = guard
+            .as_ref()
+            .map(|(path, _)| path != &model)
+            .unwrap_or(true);
+        if stale {
+            *guard = Some((model.clone(), embedder::SpeakerEmbedder::load(&model)?));
+        }
+        let (_, embedder) = guard.as_mut().expect("embedder loaded above");
+        embedder.embed(sample_rate, samples)
+    }
+}
+
+impl Default for SpeakerGate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+/// Mean of L2-normalized embeddings, so one loud window can't dominate.
+fn average_embedding(embeddings: &[Vec<f32>]) -> Option<Vec<f32>> {
+    let first = embeddings.first()?;
+    let mut sum = vec![0.0f32; first.len()];
+    for embedding in embeddings.iter().filter(|e| e.len() == first.len()) {
+        let norm = embedding
+            .iter()
+            .map(|x| x * x)
+            .sum::<f32>()
+            .sqrt()
+            .max(f32::EPSILON);
+        for (acc, value) in sum.iter_mut().zip(embedding) {
+            *acc += value / norm;
+        }
+    }
+    Some(sum)
+}
+
+fn resolve_profile_path() -> Result<PathBuf> {
+    let project_dirs =
+        ProjectDirs::from("com", "OpenFlow", "OpenFlow").context("missing project directories")?;
+    let dir = project_dirs.config_dir();
+    fs::create_dir_all(dir).context("creating config directory failed")?;
+    Ok(dir.join(PROFILE_FILE))
+}
+
+fn load_profile(path: &Path) -> Result<Option<SpeakerProfile>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let bytes = fs::read(path).with_context(|| format!("failed reading {path:?}"))?;
+    let profile = serde_json::from_slice(&bytes).context("speaker profile could not be parsed")?;
+    Ok(Some(profile))
+}
+
+fn persist_profile(path: &Path, profile: &SpeakerProfile) -> Result<()> {
+    let serialized = serde_json::to_vec(profile).context("serialize speaker profile failed")?;
+    fs::write(path, serialized).with_context(|| format!("write speaker profile to {path:?}"))
+}
+
+#[cfg(feature = "speaker-gate")]
+mod embedder {
+    use std::path::Path;
+
+    use anyhow::{anyhow, Result};
+    use sherpa_rs::speaker_id::{EmbeddingExtractor, ExtractorConfig};
+
+    pub struct SpeakerEmbedder {
+        extractor: EmbeddingExtractor,
+    }
+
+    impl SpeakerEmbedder {
+        pub fn load(model: &Path) -> Result<Self> {
+            let provider = std::env::var("SHERPA_PROVIDER").unwrap_or_else(|_| "cpu".into());
+            let config = ExtractorConfig {
+                model: model.to_string_lossy().into_owned(),
+                provider: Some(provider),
+                num_threads: Some(1),
+                debug: false,
+            };
+            let extractor = EmbeddingExtractor::new(config)
+                .map_err(|err| anyhow!("init speaker embedding model: {err}"))?;
+            Ok(Self { extractor })
+        }
+
+        pub fn embed(&mut self, sample_rate: u32, samples: &[f32]) -> Result<Vec<f32>> {
+            self.extractor
+                .compute_speaker_embedding(samples.to_vec(), sample_rate)
+                .map_err(|err| anyhow!("speaker embedding failed: {err}"))
+        }
+    }
+}
+
+#[cfg(not(feature = "speaker-gate"))]
+mod embedder {
+    use std::path::Path;
+
+    use anyhow::{bail, Result};
+
+    pub struct SpeakerEmbedder;
+
+    impl SpeakerEmbedder {
+        pub fn load(_model: &Path) -> Result<Self> {
+            bail!("speaker gate support was not compiled in")
+        }
+
+        pub fn embed(&mut self, _sample_rate: u32, _samples: &[f32]) -> Result<Vec<f32>> {
+            bail!("speaker gate support was not compiled in")
+        }
+    }
+}
@@ -4,17 +4,21 @@ import { listen } from "@tauri-apps/api/event";
 import {
   useAppStore,
   type HudState,
+  type HudDownloadProgress,
   type AppSettings,
   DEFAULT_APP_SETTINGS,
   type ModelSnapshotPayload,
+  type TranscriptAlternatives,
 } from "./state/appStore";
 import { applyThemePreference } from "./ui/theme";
 import Dashboard from "./components/Dashboard";
 import SettingsPanel from "./components/SettingsPanel";
 import ToastStack from "./components/ToastStack";
+import PendingTranscript from "./components/PendingTranscript";
 
 type LinuxPermissionsStatus = {
   uinputWritable: boolean;
+  virtualKeyboardInjection?: boolean;
   x11Session?: boolean;
   x11DisplayAvailable?: boolean;
   x11HotkeysAvailable?: boolean;
@@ -38,14 +42,72 @@ type UpdateCheckResult = {
   fromCache: boolean;
 };
 
+type UpdateAvailablePayload = {
+  check: UpdateCheckResult;
+  downloaded: { version: string; tarballPath: string } | null;
+};
+
 type PasteFailedPayload = {
   step: string;
   message: string;
   shortcut: string;
   transcriptOnClipboard: boolean;
+  chordDelivery?: "echoed" | "grabbed" | "not-echoed" | "no-handler" | "unverified";
   linux?: LinuxPermissionsStatus;
 };
 
+type PerformanceModePayload = {
+  performanceMode: boolean;
+  reason: string;
+  detail: string;
+  actions: string[];
+};
+
+type ModelDownshiftPayload = {
+  applied: boolean;
+  fromLabel: string;
+  toLabel: string;
+};
+
+type AsrSelectionRevertedPayload = {
+  originalLabel: string;
+  fallbackLabel: string;
+  error: string;
+};
+
+type SettingsRecoveredPayload = {
+  corruptPath: string;
+  restoredFrom: string | null;
+  error: string;
+};
+
+type AttentionRoutePayload = {
+  route: string;
+  title: string;
+  message: string;
+};
+
+const openSettingsPage = (route: string) => {
+  invoke("open_settings_page", { route }).catch((error) =>
+    console.error("Failed to open settings page", error),
+  );
+};
+
+// Separates "the app ignored the paste keys" from "the keys never reached any app".
+const chordDeliveryHint = (delivery: PasteFailedPayload["chordDelivery"]) => {
+  switch (delivery) {
+    case "echoed":
+      return "The paste keys were delivered; the target app did not act on them.";
+    case "grabbed":
+      return "Another program (e.g. a key remapper) holds a grab on OpenFlow's virtual keyboard, so the paste keys may not have reached the app.";
+    case "not-echoed":
+    case "no-handler":
+      return "The paste keys never left OpenFlow's virtual keyboard.";
+    default:
+      return null;
+  }
+};
+
 type TranscriptionSkippedPayload = {
   reason: string;
   message: string;
@@ -58,6 +120,8 @@ const App = () => {
     initialize,
     settingsVisible,
     setHudState,
+    setHudDownloadProgress,
+    setPendingTranscript,
     toggleSettings,
     setSettingsState,
     setMetrics,
@@ -88,19 +152,162 @@ const App = () => {
       });
       unlisteners.push(() => hudDispose());
 
+      const hudDownloadDispose = await listen<HudDownloadProgress>(
+        "hud-download-progress",
+        (event) => {
+          if (event.payload) {
+            setHudDownloadProgress(event.payload);
+          }
+        },
+      );
+      unlisteners.push(() => hudDownloadDispose());
+
+      const pendingTranscriptDispose = await listen<string | null>(
+        "pending-transcript",
+        (event) => {
+          setPendingTranscript(event.payload ?? null);
+        },
+      );
+      unlisteners.push(() => pendingTranscriptDispose());
+
+      const alternativesDispose = await listen<TranscriptAlternatives>(
+        "transcription-alternatives",
+        (event) => {
+          useAppStore.getState().setTranscriptAlternatives(event.payload ?? null);
+        },
+      );
+      unlisteners.push(() => alternativesDispose());
+
+      const recopiedDispose = await listen<string | null>(
+        "transcript-recopied",
+        (event) => {
+          notify(
+            event.payload
+              ? {
+                  title: "Transcript copied",
+                  description: "Paste it manually with your usual shortcut.",
+                  variant: "success",
+                }
+              : {
+                  title: "Nothing to copy",
+                  description: "No transcript has been delivered yet.",
+                  variant: "warning",
+                },
+          );
+        },
+      );
+      unlisteners.push(() => recopiedDispose());
+
+      // Sent from hud_ready below when config.json was unreadable at startup.
+      const settingsRecoveredDispose = await listen<SettingsRecoveredPayload>(
+        "settings-recovered",
+        (event) => {
+          const { restoredFrom, corruptPath } = event.payload;
+          void useAppStore.getState().refreshSettings();
+          notify({
+            title: restoredFrom
+              ? "Settings were restored from a backup"
+              : "Settings were reset to defaults",
+            description: `config.json could not be read and was kept as ${corruptPath}.`,
+            variant: "warning",
+          });
+        },
+      );
+      unlisteners.push(() => settingsRecoveredDispose());
+
       // Replay current HUD state in case early emissions were missed.
       invoke("hud_ready").catch((error) =>
         console.error("Failed to request HUD replay", error),
       );
 
-      const performanceDispose = await listen("performance-warning", () => {
-        const currentState = useAppStore.getState().hudState;
-        if (currentState === "idle" || currentState === "performance-warning") {
-          setHudState("performance-warning");
-        }
-      });
+      const performanceDispose = await listen<PerformanceModePayload>(
+        "performance-warning",
+        (event) => {
+          const currentState = useAppStore.getState().hudState;
+          if (currentState === "idle" || currentState === "performance-warning") {
+            setHudState("performance-warning");
+          }
+          if (event.payload?.actions?.includes("suggest-model-downshift")) {
+            notify({
+              title: "Transcription is running slow",
+              description: `${event.payload.detail}. A smaller or int8 model may keep up better.`,
+              variant: "warning",
+              action: {
+                label: "Open Settings",
+                onClick: () => toggleSettings(true),
+              },
+            });
+          }
+        },
+      );
       unlisteners.push(() => performanceDispose());
 
+      const downshiftDispose = await listen<ModelDownshiftPayload>(
+        "model-downshift",
+        (event) => {
+          const { applied, fromLabel, toLabel } = event.payload;
+          if (applied) {
+            void useAppStore.getState().refreshSettings();
+            notify({
+              title: `Switched to ${toLabel}`,
+              description: `${fromLabel} kept falling behind.`,
+              variant: "info",
+              action: {
+                label: "Revert",
+                onClick: () => {
+                  invoke<boolean>("revert_model_downshift")
+                    .then(() => useAppStore.getState().refreshSettings())
+                    .catch((error) => console.error("Failed to revert model downshift", error));
+                },
+              },
+            });
+          } else {
+            notify({
+              title: "Transcription keeps falling behind",
+              description: `${toLabel} should keep up better than ${fromLabel}.`,
+              variant: "warning",
+              action: {
+                label: `Use ${toLabel}`,
+                onClick: () => {
+                  invoke("apply_model_downshift").catch((error) =>
+                    console.error("Failed to apply model downshift", error),
+                  );
+                },
+              },
+            });
+          }
+        },
+      );
+      unlisteners.push(() => downshiftDispose());
+
+      const asrRevertedDispose = await listen<AsrSelectionRevertedPayload>(
+        "asr-selection-reverted",
+        (event) => {
+          const { originalLabel, fallbackLabel, error } = event.payload;
+          void useAppStore.getState().refreshSettings();
+          notify({
+            title: `${originalLabel} failed to load; using ${fallbackLabel}`,
+            description: error,
+            variant: "warning",
+            action: {
+              label: "Retry",
+              onClick: () => {
+                invoke<boolean>("retry_reverted_asr_selection")
+                  .then(() => useAppStore.getState().refreshSettings())
+                  .catch((err) => console.error("Failed to retry ASR selection", err));
+              },
+            },
+          });
+        },
+      );
+      unlisteners.push(() => asrRevertedDispose());
+
+      // The cycle hotkey rewrites settings behind the settings panel's back.
+      const languageCycledDispose = await listen("language-cycled", () => {
+        void useAppStore.getState().refreshSettings();
+      });
+      unlisteners.push(() => languageCycledDispose());
+
       const performanceRecoveredDispose = await listen(
         "performance-recovered",
         () => {
@@ -112,11 +319,37 @@ const App = () => {
       );
       unlisteners.push(() => performanceRecoveredDispose());
 
+      // Setup finished taking effect in the background (no relogin needed).
+      const permissionsDispose = await listen("linux-permissions-changed", () => {
+        void useAppStore.getState().refreshLinuxPermissions();
+      });
+      unlisteners.push(() => permissionsDispose());
+
       const secureDispose = await listen("secure-field-blocked", () => {
         setHudState("secure-blocked");
       });
       unlisteners.push(() => secureDispose());
 
+      // Background checks run on the cadence chosen in Settings → Updates.
+      const updateAvailableDispose = await listen<UpdateAvailablePayload>(
+        "update-available",
+        (event) => {
+          const { check, downloaded } = event.payload;
+          notify({
+            title: `Update available: ${check.latestVersion}`,
+            description: downloaded
+              ? "The update is downloaded. Open Settings to apply it."
+              : "Open Settings to download and apply the update.",
+            variant: "info",
+            action: {
+              label: "Open Settings",
+              onClick: () => toggleSettings(true),
+            },
+          });
+        },
+      );
+      unlisteners.push(() => updateAvailableDispose());
+
       const autocleanDispose = await listen<AppSettings["autocleanMode"]>(
         "autoclean-mode",
         (event) => {
@@ -137,6 +370,31 @@ const App = () => {
       });
       unlisteners.push(() => settingsDispose());
 
+      const navigateDispose = await listen<string>("navigate", (event) => {
+        if (event.payload) {
+          useAppStore.getState().openSettingsPage(event.payload);
+        }
+      });
+      unlisteners.push(() => navigateDispose());
+
+      const attentionDispose = await listen<AttentionRoutePayload>(
+        "attention-route",
+        (event) => {
+          const payload = event.payload;
+          if (!payload) return;
+          notify({
+            title: payload.title,
+            description: payload.message,
+            variant: "error",
+            action: {
+              label: "Fix",
+              onClick: () => openSettingsPage(payload.route),
+            },
+          });
+        },
+      );
+      unlisteners.push(() => attentionDispose());
+
       const metricsDispose = await listen<Record<string, unknown>>(
         "performance-metrics",
         (event) => {
@@ -188,8 +446,23 @@ const App = () => {
           const payload = event.payload;
           if (!payload) return;
 
+          // Nothing to fix in setup: another window grabbed focus mid-paste.
+          if (payload.step === "focus-changed") {
+            notify({
+              title: "Paste interrupted",
+              description:
+                "Focus moved to another window while pasting. Click back into your field and paste the transcript from the clipboard.",
+              variant: "warning",
+            });
+            return;
+          }
+
           const parts: string[] = [];
           parts.push(`${payload.step}: ${payload.message}`);
+          const deliveryHint = chordDeliveryHint(payload.chordDelivery);
+          if (deliveryHint) {
+            parts.push(deliveryHint);
+          }
 
           const linux = payload.linux;
           if (linux) {
@@ -207,14 +480,12 @@ const App = () => {
             } else {
               parts.push("Install wl-clipboard (Wayland) or xclip (X11) for clipboard support.");
             }
-            if (!linux.uinputWritable) {
-              parts.push(
-                "Open Settings → Linux Setup and click Enable (admin), then log out/in.",
-              );
+            if (!linux.uinputWritable && !linux.virtualKeyboardInjection) {
+              parts.push("Open Linux Setup and click Enable (admin), then log out/in.");
             }
           }
 
-          if (payload.shortcut === "ctrl-shift-v") {
+          if (payload.shortcut === "Ctrl+Shift+V") {
             parts.push("If the target app doesn't support Ctrl+Shift+V, switch to Ctrl+V.");
           }
 
@@ -226,6 +497,13 @@ const App = () => {
             title: "Paste failed",
             description: parts.join(" "),
             variant: "error",
+            action:
+              payload.linux?.uinputWritable === false && !payload.linux?.virtualKeyboardInjection
+                ? {
+                    label: "Open Linux Setup",
+                    onClick: () => openSettingsPage("settings/permissions"),
+                  }
+                : undefined,
           });
         },
       );
@@ -239,8 +517,12 @@ const App = () => {
 
           const parts: string[] = [];
           parts.push(payload.message);
+          const deliveryHint = chordDeliveryHint(payload.chordDelivery);
+          if (deliveryHint) {
+            parts.push(deliveryHint);
+          }
 
-          if (payload.shortcut === "ctrl-shift-v") {
+          if (payload.shortcut === "Ctrl+Shift+V") {
             parts.push(
               "If the target app doesn't support Ctrl+Shift+V, switch to Ctrl+V.",
             );
@@ -286,26 +568,52 @@ const App = () => {
     registerListener().catch((error) =>
       console.error("Failed to attach listeners", error),
     );
-    invoke("register_hotkeys").catch((error) =>
-      console.error("Failed to register hotkeys", error),
-    );
+    invoke("register_hotkeys")
+      .catch((error) => console.error("Failed to register hotkeys", error))
+      .finally(async () => {
+        // Preflight runs after hotkey registration so the hotkey check is meaningful.
+        const report = await useAppStore.getState().runPreflight();
+        const failing = report?.checks.filter((check) => check.severity === "error") ?? [];
+        if (!report || failing.length === 0) {
+          return;
+        }
+        notify({
+          title: "Setup needs attention",
+          description: failing
+            .map((check) => `${check.label}: ${check.remedy ?? check.message}`)
+            .join(" "),
+          variant: "error",
+          action: failing[0].route
+            ? {
+                label: "Fix",
+                onClick: () => openSettingsPage(failing[0].route as string),
+              }
+            : {
+                label: "Copy report",
+                onClick: () => {
+                  navigator.clipboard
+                    .writeText(JSON.stringify(report, null, 2))
+                    .catch((error) => console.error("Failed to copy preflight report", error));
+                },
+              },
+        });
+      });
 
-    // Check for updates in the background (cached on the backend).
-    invoke<UpdateCheckResult>("check_for_updates", { force: false })
-      .then((result) => {
-        if (!result?.updateAvailable) return;
+    invoke<string | null>("take_updated_version")
+      .then((version) => {
+        if (!version) return;
         notify({
-          title: `Update available: ${result.latestVersion}`,
-          description: "Open Settings to download and apply the update.",
+          title: `OpenFlow updated to ${version}`,
+          description: "See what's new under Settings → Updates.",
           variant: "info",
           action: {
-            label: "Open Settings",
+            label: "What's new",
             onClick: () => toggleSettings(true),
           },
         });
       })
       .catch((error) => {
-        console.debug("Update check failed", error);
+        console.debug("Failed to read launched version", error);
       });
 
     return () => {
@@ -317,6 +625,8 @@ const App = () => {
   }, [
     initialize,
     setHudState,
+    setHudDownloadProgress,
+    setPendingTranscript,
     toggleSettings,
     setSettingsState,
     setMetrics,
@@ -329,6 +639,7 @@ const App = () => {
     <>
       <Dashboard />
       {settingsVisible && <SettingsPanel />}
+      <PendingTranscript />
       <ToastStack />
     </>
   );
@@ -1,12 +1,25 @@
 import { useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
-import { useAppStore, type HudState, type AppSettings } from "./state/appStore";
+import {
+  useAppStore,
+  type HudState,
+  type HudDownloadProgress,
+  type HudLevels,
+  type TranscriptToast,
+  type AppSettings,
+} from "./state/appStore";
 import StatusOrb from "./components/StatusOrb";
 import { applyThemePreference } from "./ui/theme";
 
 const OverlayApp = () => {
   const setHudState = useAppStore((state) => state.setHudState);
+  const setHudDownloadProgress = useAppStore(
+    (state) => state.setHudDownloadProgress,
+  );
+  const setHudLevels = useAppStore((state) => state.setHudLevels);
+  const setHudToast = useAppStore((state) => state.setHudToast);
+  const setTranscriptToast = useAppStore((state) => state.setTranscriptToast);
   const refreshSettings = useAppStore((state) => state.refreshSettings);
   const themePreference = useAppStore(
     (state) => (state.settings?.hudTheme ?? "system") as AppSettings["hudTheme"],
@@ -28,10 +41,68 @@ const OverlayApp = () => {
       const hudDispose = await listen<HudState>("hud-state", (event) => {
         if (event.payload) {
           setHudState(event.payload);
+          // The next session's waveform starts flat instead of where the last one ended.
+          if (event.payload !== "listening") {
+            setHudLevels(null);
+          }
         }
       });
       unlisteners.push(() => hudDispose());
 
+      const hudDownloadDispose = await listen<HudDownloadProgress>(
+        "hud-download-progress",
+        (event) => {
+          if (event.payload) {
+            setHudDownloadProgress(event.payload);
+          }
+        },
+      );
+      unlisteners.push(() => hudDownloadDispose());
+
+      const hudLevelsDispose = await listen<HudLevels>("hud-levels", (event) => {
+        setHudLevels(event.payload);
+      });
+      unlisteners.push(() => hudLevelsDispose());
+
+      // Leave room for the exit animation before the backend hides the window.
+      const toastVisibleMs = () => {
+        const settings = useAppStore.getState().settings;
+        return Math.max(
+          0,
+          (settings?.hudToastMs ?? 1800) - (settings?.overlayHideDelayMs ?? 260),
+        );
+      };
+
+      let toastTimer: number | undefined;
+      const toastDispose = await listen<string>("hud-toast", (event) => {
+        setTranscriptToast(null);
+        setHudToast(event.payload);
+        window.clearTimeout(toastTimer);
+        toastTimer = window.setTimeout(() => setHudToast(null), toastVisibleMs());
+      });
+      const transcriptToastDispose = await listen<TranscriptToast>(
+        "transcript-toast",
+        (event) => {
+          setHudToast(null);
+          setTranscriptToast(event.payload);
+          window.clearTimeout(toastTimer);
+          toastTimer = window.setTimeout(() => setTranscriptToast(null), toastVisibleMs());
+        },
+      );
+      unlisteners.push(() => {
+        window.clearTimeout(toastTimer);
+        toastDispose();
+        transcriptToastDispose();
+      });
+
+      // Theme/accent changes arrive while the overlay stays mounted.
+      const appearanceDispose = await listen("hud-appearance", () => {
+        refreshSettings().catch((error) =>
+          console.error("Failed to refresh overlay appearance", error),
+        );
+      });
+      unlisteners.push(() => appearanceDispose());
+
       // Ask backend to replay the latest HUD state (overlay is created lazily).
       invoke("hud_ready").catch((error) =>
         console.error("Failed to request HUD replay", error),
@@ -69,7 +140,14 @@ const OverlayApp = () => {
     return () => {
       unlisteners.forEach((dispose) => dispose());
     };
-  }, [refreshSettings, setHudState]);
+  }, [
+    refreshSettings,
+    setHudState,
+    setHudDownloadProgress,
+    setHudLevels,
+    setHudToast,
+    setTranscriptToast,
+  ]);
 
   return (
     <div className="pointer-events-none relative h-screen w-screen bg-transparent">
@@ -6,12 +6,14 @@ import {
   useAppStore,
 } from "../state/appStore";
 import DebugPanel from "./DebugPanel";
+import HistoryPanel from "./HistoryPanel";
 import { Badge, Button, Card, Kbd } from "../ui/primitives";
 
 const Dashboard = () => {
   const {
     toggleSettings,
     hudState,
+    hudDownloadProgress,
     models,
     settings,
     metrics,
@@ -20,6 +22,7 @@ const Dashboard = () => {
     refreshLinuxPermissions,
   } = useAppStore();
   const [showDebug, setShowDebug] = useState(false);
+  const [showHistory, setShowHistory] = useState(false);
   const [authBusy, setAuthBusy] = useState(false);
   const [authRecheckFailed, setAuthRecheckFailed] = useState(false);
 
@@ -35,9 +38,13 @@ const Dashboard = () => {
   const whisperAssetName =
     whisperBackend === "ct2"
       ? `whisper-ct2-${whisperModel}${whisperLanguageNormalized === "en" ? "-en" : ""}`
-      : `whisper-onnx-${whisperModel}${
-          whisperLanguageNormalized === "en" ? "-en" : ""
-        }-${whisperPrecision}`;
+      : whisperBackend === "cpp"
+        ? `whisper-cpp-${whisperModel}${whisperLanguageNormalized === "en" ? "-en" : ""}-${
+            whisperPrecision === "float" ? "float" : "q5"
+          }`
+        : `whisper-onnx-${whisperModel}${
+            whisperLanguageNormalized === "en" ? "-en" : ""
+          }-${whisperPrecision}`;
   const asrModel =
     asrFamily === "whisper"
       ? models.find((m) => m.name === whisperAssetName)
@@ -55,7 +62,8 @@ const Dashboard = () => {
   const waylandAuthRequired =
     Boolean(linuxPermissions?.supported) &&
     Boolean(linuxPermissions?.waylandSession) &&
-    !linuxPermissions?.uinputWritable;
+    !linuxPermissions?.uinputWritable &&
+    !linuxPermissions?.virtualKeyboardInjection;
   const authPrereqsAvailable =
     Boolean(linuxPermissions?.pkexecAvailable) &&
     Boolean(linuxPermissions?.setfaclAvailable);
@@ -81,7 +89,8 @@ const Dashboard = () => {
       const stillNotReady =
         Boolean(refreshed?.supported) &&
         Boolean(refreshed?.waylandSession) &&
-        !refreshed?.uinputWritable;
+        !refreshed?.uinputWritable &&
+        !refreshed?.virtualKeyboardInjection;
 
       setAuthRecheckFailed(authErrored || stillNotReady);
     } finally {
@@ -106,7 +115,11 @@ const Dashboard = () => {
           : "Healthy";
 
   const isToggleMode = settings?.hotkeyMode === "toggle";
-  const modeVerb = isToggleMode ? "Toggle" : "Hold";
+  const modeVerb = isToggleMode
+    ? "Toggle"
+    : settings?.hotkeyMode === "hybrid"
+      ? "Tap or hold"
+      : "Hold";
   const modeHotkey = isToggleMode
     ? (settings?.toggleToTalkHotkey ?? DEFAULT_TOGGLE_TO_TALK_HOTKEY)
     : (settings?.pushToTalkHotkey ?? DEFAULT_PUSH_TO_TALK_HOTKEY);
@@ -151,6 +164,9 @@ const Dashboard = () => {
               </span>
             )}
           </div>
+          <Button variant="secondary" size="sm" onClick={() => setShowHistory(true)}>
+            History
+          </Button>
           <Button variant="secondary" size="sm" onClick={() => setShowDebug(true)}>
             Debug
           </Button>
@@ -170,6 +186,11 @@ const Dashboard = () => {
                   <span className="font-semibold">Paste injection needs authentication.</span>{" "}
                   OpenFlow needs Wayland input permissions to paste into the active app.
                 </p>
+                {linuxPermissions?.accessWatch === "waiting" && (
+                  <p className="mt-2 text-xs text-muted">
+                    Waiting for the new permissions to take effect; hotkeys start automatically.
+                  </p>
+                )}
                 {authRecheckFailed && (
                   <p className="mt-2 text-xs text-muted">
                     Authentication completed, but permissions are still not ready. You may need to
@@ -207,6 +228,12 @@ const Dashboard = () => {
             {hudState === "idle" && "Ready to Dictate"}
             {hudState === "listening" && "Listening..."}
             {hudState === "processing" && "Processing..."}
+            {hudState === "downloading" &&
+              `Downloading model${
+                hudDownloadProgress
+                  ? ` ${Math.round(hudDownloadProgress.progress * 100)}%`
+                  : "..."
+              }`}
             {hudState === "performance-warning" && "Performance Mode"}
             {hudState === "secure-blocked" && "Secure Field Blocked"}
           </h2>
@@ -267,6 +294,7 @@ const Dashboard = () => {
       </footer>
 
       {showDebug && <DebugPanel onClose={() => setShowDebug(false)} />}
+      {showHistory && <HistoryPanel onClose={() => setShowHistory(false)} />}
     </div>
   );
 };
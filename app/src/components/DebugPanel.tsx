@@ -1,7 +1,7 @@
 import { useState, useEffect, useCallback, useRef, type PointerEvent } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
-import { useAppStore } from "../state/appStore";
+import { useAppStore, type AppSettings, type SettingsChange } from "../state/appStore";
 import { AccordionSection, Button, Card, Select, Tabs } from "../ui/primitives";
 
 interface DebugLog {
@@ -17,6 +17,7 @@ interface AudioDiagnosticsPayload {
   synthetic: boolean;
   rms: number;
   peak: number;
+  warning?: string;
 }
 
 interface VadDiagnosticsPayload {
@@ -32,6 +33,20 @@ interface PasteFailedPayload {
   message: string;
   shortcut: string;
   transcriptOnClipboard: boolean;
+  chordDelivery?: string;
+}
+
+interface TrimReport {
+  sessionId: number;
+  sampleRate: number;
+  totalSamples: number;
+  activeSamples: number;
+  minActiveSamples: number;
+  keptSamples: number;
+  droppedSamples: number;
+  segments: { startMs: number; endMs: number }[];
+  checks: { code: string; passed: boolean }[];
+  rejected?: string;
 }
 
 interface PasteSucceededPayload {
@@ -39,6 +54,54 @@ interface PasteSucceededPayload {
   chars: number;
 }
 
+type ResetScope = "update-cache" | "model-staging" | "hud-state" | "settings";
+
+interface ResetReport {
+  cleared: ResetScope[];
+  reclaimedBytes: number;
+  skippedModels: string[];
+  errors: string[];
+}
+
+interface SessionTiming {
+  sessionId: number;
+  totalMs: number;
+  stages: { stage: string; startUs: number; durationUs: number }[];
+  tracePath: string | null;
+}
+
+interface SimulatedEvent {
+  event: string;
+  payload: unknown;
+}
+
+interface TranscriptionOutput {
+  text: string;
+  mode: "paste" | "emit-only";
+  routing: {
+    windowClass: string | null;
+    windowTitle: string | null;
+    sessionId: number;
+    profile: string | null;
+  } | null;
+}
+
+interface PrivacyPosture {
+  strict: boolean;
+  debugTranscripts: boolean;
+  failedAudio: boolean;
+  sessionTraces: boolean;
+  history: boolean;
+  corrections: boolean;
+  transcriptLogs: boolean;
+}
+
+interface ReplayedAudio {
+  path: string;
+  transcript: string;
+  latencyMs: number;
+}
+
 const DebugPanel = ({ onClose }: { onClose: () => void }) => {
   const {
     hudState,
@@ -60,12 +123,18 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
   const [hotkeyTriggerDescription, setHotkeyTriggerDescription] = useState<string>("");
   const [isTestingAudio, setIsTestingAudio] = useState(false);
   const [sandboxText, setSandboxText] = useState<string>("");
+  const [simulationInput, setSimulationInput] = useState<string>("");
+  const [isSimulating, setIsSimulating] = useState(false);
   const [outputMode, setOutputMode] = useState<"paste" | "emit-only">("paste");
   const [isHolding, setIsHolding] = useState(false);
   const [audioDiagnostics, setAudioDiagnostics] = useState<AudioDiagnosticsPayload | null>(null);
   const [vadDiagnostics, setVadDiagnostics] = useState<VadDiagnosticsPayload | null>(null);
+  const [sessionTiming, setSessionTiming] = useState<SessionTiming | null>(null);
+  const [trimReport, setTrimReport] = useState<TrimReport | null>(null);
   const [panels, setPanels] = useState({ engine: true, audio: false, logs: true });
   const [logTab, setLogTab] = useState<"live" | "backend">("live");
+  const [capturedSessions, setCapturedSessions] = useState<unknown[] | null>(null);
+  const [settingsHistory, setSettingsHistory] = useState<SettingsChange[]>([]);
 
   const mountedRef = useRef(true);
   const isHoldingRef = useRef(false);
@@ -95,6 +164,41 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
     [addLog, settings, updateSettings],
   );
 
+  const refreshCapturedSessions = useCallback(async () => {
+    try {
+      setCapturedSessions(await invoke<unknown[]>("debug_capture_entries"));
+    } catch (error) {
+      addLog("error", `Failed to read debug capture: ${error}`);
+    }
+  }, [addLog]);
+
+  useEffect(() => {
+    void refreshCapturedSessions();
+  }, [refreshCapturedSessions, sessionTiming, settings?.debugTranscripts]);
+
+  const refreshSettingsHistory = useCallback(async () => {
+    try {
+      setSettingsHistory(await invoke<SettingsChange[]>("get_settings_history", { limit: 50 }));
+    } catch (error) {
+      addLog("error", `Failed to read settings history: ${error}`);
+    }
+  }, [addLog]);
+
+  // Refetch whenever settings change, including backend-initiated rewrites.
+  useEffect(() => {
+    void refreshSettingsHistory();
+  }, [refreshSettingsHistory, settings]);
+
+  const handlePurgeDebugData = useCallback(async () => {
+    try {
+      const removed = await invoke<number>("purge_debug_data");
+      addLog("info", `Purged ${removed} captured session(s)`);
+      setCapturedSessions([]);
+    } catch (error) {
+      addLog("error", `Failed to purge debug data: ${error}`);
+    }
+  }, [addLog]);
+
   const stopHoldToTalk = useCallback(
     async (reason: string, opts?: { silent?: boolean }) => {
       if (!isHoldingRef.current || stopInFlightRef.current) return;
@@ -179,12 +283,14 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
       });
       unlisteners.push(hotkeyUnregistered);
 
-      const transcription = await listen<string>("transcription-output", (event) => {
-        addLog("success", `Transcription: ${event.payload}`);
-        if (typeof event.payload === "string" && event.payload.trim().length > 0) {
+      const transcription = await listen<TranscriptionOutput>("transcription-output", (event) => {
+        const { text, mode, routing } = event.payload;
+        const target = routing?.windowClass ? ` -> ${routing.windowClass}` : "";
+        addLog("success", `Transcription (${mode}${target}): ${text}`);
+        if (typeof text === "string" && text.trim().length > 0) {
           setSandboxText((prev) => {
             const next = prev.trimEnd();
-            return next.length === 0 ? event.payload : `${next}\n${event.payload}`;
+            return next.length === 0 ? text : `${next}\n${text}`;
           });
         }
       });
@@ -197,11 +303,30 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
       });
       unlisteners.push(transcriptionError);
 
+      const privacyPosture = await listen<PrivacyPosture>("privacy-posture", (event) => {
+        if (!event.payload) return;
+        const {
+          strict,
+          debugTranscripts,
+          failedAudio,
+          sessionTraces,
+          history,
+          corrections,
+          transcriptLogs,
+        } = event.payload;
+        addLog(
+          "info",
+          `Privacy posture: strict=${strict} debugTranscripts=${debugTranscripts} failedAudio=${failedAudio} sessionTraces=${sessionTraces} history=${history} corrections=${corrections} transcriptLogs=${transcriptLogs}`,
+        );
+      });
+      unlisteners.push(privacyPosture);
+
       const pasteFailed = await listen<PasteFailedPayload>("paste-failed", (event) => {
         if (!event.payload) return;
-        const { step, message, shortcut, transcriptOnClipboard } = event.payload;
+        const { step, message, shortcut, transcriptOnClipboard, chordDelivery } = event.payload;
         const extra = transcriptOnClipboard ? " (transcript left on clipboard)" : "";
-        addLog("error", `Paste failed [${step}] (${shortcut}): ${message}${extra}`);
+        const delivery = chordDelivery ? ` [uinput ${chordDelivery}]` : "";
+        addLog("error", `Paste failed [${step}] (${shortcut}): ${message}${extra}${delivery}`);
       });
       unlisteners.push(pasteFailed);
 
@@ -227,6 +352,19 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
       );
       unlisteners.push(pasteSucceeded);
 
+      const trimReportListener = await listen<TrimReport>("trim-report", (event) => {
+        if (!event.payload) return;
+        const { sessionId, segments, keptSamples, totalSamples, rejected } = event.payload;
+        setTrimReport(event.payload);
+        addLog(
+          rejected ? "warning" : "info",
+          rejected
+            ? `Trim rejected session ${sessionId}: ${rejected}`
+            : `Trim kept ${keptSamples}/${totalSamples} samples in ${segments.length} segment(s)`,
+        );
+      });
+      unlisteners.push(trimReportListener);
+
       const audioDiag = await listen<AudioDiagnosticsPayload>("audio-diagnostics", (event) => {
         if (event.payload) {
           setAudioDiagnostics(event.payload);
@@ -240,12 +378,49 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
         }
       });
       unlisteners.push(vadDiag);
+      invoke("set_diagnostics_subscribed", { subscribed: true }).catch(() => {});
+      unlisteners.push(() => {
+        invoke("set_diagnostics_subscribed", { subscribed: false }).catch(() => {});
+      });
+
+      const timing = await listen<SessionTiming>("session-timing", (event) => {
+        setSessionTiming(event.payload);
+        if (event.payload.tracePath) {
+          addLog("info", `Session trace written to ${event.payload.tracePath}`);
+        }
+      });
+      unlisteners.push(timing);
+
+      const budgetExceeded = await listen<{
+        sessionId: number;
+        endToEndMs: number;
+        targetMs: number;
+        culprit: string | null;
+      }>("latency-budget-exceeded", (event) => {
+        const { sessionId, endToEndMs, targetMs, culprit } = event.payload;
+        addLog(
+          "warning",
+          `Session ${sessionId} over latency budget: ${endToEndMs} ms` +
+            (targetMs > 0 ? ` (target ${targetMs} ms)` : "") +
+            (culprit ? `, mostly ${culprit}` : ""),
+        );
+      });
+      unlisteners.push(budgetExceeded);
 
-      const perfWarning = await listen("performance-warning", () => {
-        addLog("warning", "Performance warning triggered");
+      const perfWarning = await listen<{ detail?: string }>("performance-warning", (event) => {
+        const detail = event.payload?.detail;
+        addLog("warning", detail ? `Performance warning: ${detail}` : "Performance warning triggered");
       });
       unlisteners.push(perfWarning);
 
+      const thermalThrottle = await listen<{ throttled: boolean; detail: string }>(
+        "thermal-throttle",
+        (event) => {
+          addLog(event.payload.throttled ? "warning" : "info", `Thermal: ${event.payload.detail}`);
+        },
+      );
+      unlisteners.push(thermalThrottle);
+
       const secureBlocked = await listen("secure-field-blocked", () => {
         addLog("warning", "Secure field blocked");
       });
@@ -393,6 +568,21 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
     }
   };
 
+  const handleSimulateDictation = async () => {
+    const input = simulationInput.trim();
+    if (!input) return;
+    setIsSimulating(true);
+    addLog("info", "Running simulated dictation...");
+    try {
+      const events = await invoke<SimulatedEvent[]>("simulate_dictation", { input });
+      addLog("success", `Simulated dictation finished with ${events.length} events`);
+      events.forEach((e) => addLog("info", `  ${e.event} ${JSON.stringify(e.payload)}`));
+    } catch (err) {
+      addLog("error", `Simulated dictation failed: ${err}`);
+    }
+    setIsSimulating(false);
+  };
+
   const handleReregisterHotkey = async () => {
     try {
       addLog("info", "Re-registering hotkey...");
@@ -403,6 +593,25 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
     }
   };
 
+  const handleResetState = async () => {
+    // Everything except settings, which the command only resets when asked to.
+    const scope: ResetScope[] = ["update-cache", "model-staging", "hud-state"];
+    try {
+      addLog("info", "Clearing caches and stale runtime state...");
+      const report = await invoke<ResetReport>("reset_app_state", { scope });
+      addLog(
+        report.errors.length > 0 ? "warning" : "success",
+        `Cleared ${report.cleared.join(", ") || "nothing"} (${Math.round(report.reclaimedBytes / 1024)} KiB reclaimed)`,
+      );
+      if (report.skippedModels.length > 0) {
+        addLog("info", `Kept staging for downloading models: ${report.skippedModels.join(", ")}`);
+      }
+      report.errors.forEach((message) => addLog("error", `Reset failed: ${message}`));
+    } catch (err) {
+      addLog("error", `Reset failed: ${err}`);
+    }
+  };
+
   const handleTestAudio = async () => {
     setIsTestingAudio(true);
     addLog("info", "Testing audio device detection...");
@@ -475,9 +684,13 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
   const whisperAssetName =
     whisperBackend === "ct2"
       ? `whisper-ct2-${whisperModel}${whisperLanguageNormalized === "en" ? "-en" : ""}`
-      : `whisper-onnx-${whisperModel}${
-          whisperLanguageNormalized === "en" ? "-en" : ""
-        }-${whisperPrecision}`;
+      : whisperBackend === "cpp"
+        ? `whisper-cpp-${whisperModel}${whisperLanguageNormalized === "en" ? "-en" : ""}-${
+            whisperPrecision === "float" ? "float" : "q5"
+          }`
+        : `whisper-onnx-${whisperModel}${
+            whisperLanguageNormalized === "en" ? "-en" : ""
+          }-${whisperPrecision}`;
 
   const activeAsrModel =
     asrFamily === "whisper"
@@ -566,6 +779,16 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
                       label="Perf"
                       value={metrics?.performanceMode ? "Mode ON" : "Normal"}
                     />
+                    <MetricTile
+                      label="Thermal"
+                      value={
+                        metrics?.thermalThrottled
+                          ? "Throttled"
+                          : typeof metrics?.cpuTempC === "number"
+                            ? `${metrics.cpuTempC.toFixed(0)} °C`
+                            : "—"
+                      }
+                    />
                   </div>
 
                   <label className="flex items-center gap-2 text-xs text-fg">
@@ -575,9 +798,206 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
                       onChange={(event) => {
                         void handleToggleDebugTranscripts(event.target.checked);
                       }}
-                      disabled={!settings}
+                      disabled={!settings || settings.strictPrivacy}
                     />
                     Enable debug transcripts (auto-disables after 24h)
+                    {settings?.strictPrivacy ? " — off in strict privacy" : ""}
+                  </label>
+
+                  {/* Captured sessions are encrypted on disk and purged when the 24h window ends. */}
+                  <div className="flex items-center justify-between gap-3 text-xs">
+                    <span className="text-muted">
+                      Captured sessions: {capturedSessions?.length ?? 0}
+                    </span>
+                    <div className="flex gap-2">
+                      <Button
+                        variant="secondary"
+                        size="sm"
+                        disabled={!capturedSessions?.length}
+                        onClick={() => {
+                          void navigator.clipboard
+                            .writeText(JSON.stringify(capturedSessions, null, 2))
+                            .then(() => addLog("info", "Debug capture copied to clipboard"))
+                            .catch((error) => addLog("error", `Copy failed: ${error}`));
+                        }}
+                      >
+                        Copy bundle
+                      </Button>
+                      <Button
+                        variant="ghost"
+                        size="sm"
+                        disabled={!capturedSessions?.length}
+                        onClick={() => void handlePurgeDebugData()}
+                      >
+                        Purge debug data
+                      </Button>
+                    </div>
+                  </div>
+
+                  {/* Clips of dictations that produced nothing; replay re-runs ASR on the newest. */}
+                  <div className="flex items-center justify-between gap-3 text-xs">
+                    <label className="flex items-center gap-2 text-fg">
+                      <input
+                        type="checkbox"
+                        checked={settings?.keepFailedAudio ?? false}
+                        onChange={(event) => {
+                          if (!settings) return;
+                          updateSettings({
+                            ...settings,
+                            keepFailedAudio: event.target.checked,
+                          }).catch((error) =>
+                            addLog("error", `Failed to update failed audio retention: ${error}`),
+                          );
+                        }}
+                        disabled={!settings || settings.strictPrivacy}
+                      />
+                      Keep audio of failed dictations ({settings?.failedAudioRetentionDays ?? 3} days)
+                    </label>
+                    <Button
+                      variant="secondary"
+                      size="sm"
+                      disabled={!settings?.keepFailedAudio}
+                      onClick={() => {
+                        invoke<ReplayedAudio>("replay_last_audio")
+                          .then((replayed) =>
+                            addLog(
+                              "info",
+                              `Replayed ${replayed.path} in ${replayed.latencyMs} ms: "${replayed.transcript}"`,
+                            ),
+                          )
+                          .catch((error) => addLog("error", `Replay failed: ${error}`));
+                      }}
+                    >
+                      Replay last failed audio
+                    </Button>
+                  </div>
+
+                  <label className="flex items-center gap-2 text-xs text-fg">
+                    <input
+                      type="checkbox"
+                      checked={settings?.exportSessionTraces ?? false}
+                      onChange={(event) => {
+                        if (!settings) return;
+                        updateSettings({
+                          ...settings,
+                          exportSessionTraces: event.target.checked,
+                        }).catch((error) =>
+                          addLog("error", `Failed to update trace export: ${error}`),
+                        );
+                      }}
+                      disabled={!settings || settings.strictPrivacy}
+                    />
+                    Export a Chrome trace per dictation
+                    {settings?.strictPrivacy ? " — off in strict privacy" : ""}
+                  </label>
+
+                  {sessionTiming && (
+                    <div className="rounded-vibe border border-border bg-surface2 p-3 text-xs">
+                      <div className="flex items-center justify-between gap-3">
+                        <span className="text-muted">Session {sessionTiming.sessionId}</span>
+                        <span className="font-mono text-fg">{sessionTiming.totalMs} ms</span>
+                      </div>
+                      {sessionTiming.stages.map((timing) => (
+                        <div
+                          key={timing.stage}
+                          className="mt-1 flex items-center justify-between gap-3"
+                        >
+                          <span className="text-muted">{timing.stage}</span>
+                          <span className="font-mono text-fg">
+                            {(timing.durationUs / 1000).toFixed(1)} ms
+                          </span>
+                        </div>
+                      ))}
+                    </div>
+                  )}
+
+                  {trimReport && (
+                    <div className="rounded-vibe border border-border bg-surface2 p-3 text-xs">
+                      <div className="flex items-center justify-between gap-3">
+                        <span className="text-muted">Trim, session {trimReport.sessionId}</span>
+                        <span className="font-mono text-fg">
+                          {trimReport.rejected ?? "accepted"}
+                        </span>
+                      </div>
+                      <div className="mt-1 flex items-center justify-between gap-3">
+                        <span className="text-muted">Speech / minimum</span>
+                        <span className="font-mono text-fg">
+                          {((trimReport.activeSamples / trimReport.sampleRate) * 1000).toFixed(0)} /{" "}
+                          {((trimReport.minActiveSamples / trimReport.sampleRate) * 1000).toFixed(0)} ms
+                        </span>
+                      </div>
+                      <div className="mt-1 flex items-center justify-between gap-3">
+                        <span className="text-muted">Kept / dropped</span>
+                        <span className="font-mono text-fg">
+                          {((trimReport.keptSamples / trimReport.sampleRate) * 1000).toFixed(0)} /{" "}
+                          {((trimReport.droppedSamples / trimReport.sampleRate) * 1000).toFixed(0)} ms
+                        </span>
+                      </div>
+                      {trimReport.segments.map((segment) => (
+                        <div
+                          key={segment.startMs}
+                          className="mt-1 flex items-center justify-between gap-3"
+                        >
+                          <span className="text-muted">segment</span>
+                          <span className="font-mono text-fg">
+                            {segment.startMs}–{segment.endMs} ms
+                          </span>
+                        </div>
+                      ))}
+                      <div className="mt-1 flex flex-wrap gap-2">
+                        {trimReport.checks.map((check) => (
+                          <span
+                            key={check.code}
+                            className={`font-mono ${check.passed ? "text-muted" : "text-bad"}`}
+                          >
+                            {check.passed ? "✓" : "✗"} {check.code}
+                          </span>
+                        ))}
+                      </div>
+                    </div>
+                  )}
+
+                  <label className="flex items-center justify-between gap-3 text-xs text-fg">
+                    <span>Audio/VAD diagnostics</span>
+                    <Select
+                      width="md"
+                      size="sm"
+                      value={String(settings?.diagnosticsIntervalMs ?? 250)}
+                      onChange={(v) => {
+                        if (!settings) return;
+                        updateSettings({ ...settings, diagnosticsIntervalMs: Number(v) }).catch(
+                          (error) => addLog("error", `Failed to update diagnostics rate: ${error}`),
+                        );
+                      }}
+                      options={[
+                        { value: "0", label: "Off" },
+                        { value: "100", label: "Every 100 ms" },
+                        { value: "250", label: "Every 250 ms" },
+                        { value: "1000", label: "Every second" },
+                      ]}
+                    />
+                  </label>
+
+                  <label className="flex items-center justify-between gap-3 text-xs text-fg">
+                    <span>Event rate limits</span>
+                    <Select
+                      width="md"
+                      size="sm"
+                      value={settings?.eventRateProfile ?? "normal"}
+                      onChange={(v) => {
+                        if (!settings) return;
+                        updateSettings({
+                          ...settings,
+                          eventRateProfile: v as AppSettings["eventRateProfile"],
+                        }).catch((error) =>
+                          addLog("error", `Failed to update event rate limits: ${error}`),
+                        );
+                      }}
+                      options={[
+                        { value: "normal", label: "Normal" },
+                        { value: "low-power", label: "Low-power device" },
+                      ]}
+                    />
                   </label>
 
                   <div className="rounded-vibe border border-border bg-surface2 p-3 text-xs">
@@ -669,6 +1089,9 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
                           : "—"}
                       </span>
                     </div>
+                    {audioDiagnostics?.warning && (
+                      <div className="mt-2 text-warn">{audioDiagnostics.warning}</div>
+                    )}
                   </div>
 
                   <div>
@@ -724,6 +1147,9 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
                     >
                       Test Audio Devices
                     </Button>
+                    <Button variant="secondary" onClick={handleResetState}>
+                      Clear Caches
+                    </Button>
                     <Button variant="ghost" onClick={handleClearLogs}>
                       Clear Live Events
                     </Button>
@@ -792,6 +1218,26 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
                         Clear
                       </Button>
                     </div>
+
+                    {/* Runs the finalize path without the mic; a WAV path is replayed as audio. */}
+                    <div className="mt-3 flex flex-wrap gap-2">
+                      <input
+                        className="min-w-0 flex-1 rounded-vibe border border-border bg-surface px-3 py-2 font-mono text-xs text-fg outline-none focus:border-accent/50"
+                        value={simulationInput}
+                        onChange={(e) => setSimulationInput(e.target.value)}
+                        placeholder="WAV path or transcript text"
+                      />
+                      <Button
+                        variant="secondary"
+                        className="px-4"
+                        onClick={() => {
+                          void handleSimulateDictation();
+                        }}
+                        disabled={isSimulating || !simulationInput.trim() || hudState !== "idle"}
+                      >
+                        {isSimulating ? "Simulating..." : "Simulate"}
+                      </Button>
+                    </div>
                   </div>
 
                   <div className="rounded-vibe border border-border bg-surface2 p-4">
@@ -851,6 +1297,45 @@ const DebugPanel = ({ onClose }: { onClose: () => void }) => {
                       )}
                     </div>
                   </div>
+
+                  <div className="rounded-vibe border border-border bg-surface2 p-4">
+                    <div className="flex items-center justify-between gap-3">
+                      <div>
+                        <div className="text-sm font-semibold text-fg">Settings history</div>
+                        <div className="mt-0.5 text-xs text-muted">
+                          Recent changes, including ones the app made on its own.
+                        </div>
+                      </div>
+                      <Button
+                        variant="secondary"
+                        size="sm"
+                        onClick={() => {
+                          void refreshSettingsHistory();
+                        }}
+                      >
+                        Refresh
+                      </Button>
+                    </div>
+
+                    <div className="mt-3 max-h-[220px] overflow-y-auto rounded-vibe border border-border bg-bg p-3 font-mono text-xs">
+                      {settingsHistory.length === 0 ? (
+                        <p className="text-muted">No settings changes recorded yet.</p>
+                      ) : (
+                        settingsHistory.map((change, index) => (
+                          <div key={`${change.changedAt}-${change.field}-${index}`} className="mb-1 flex gap-2">
+                            <span className="flex-shrink-0 text-muted/70">
+                              {new Date(change.changedAt).toLocaleString()}
+                            </span>
+                            <span className="flex-shrink-0 text-muted">[{change.source}]</span>
+                            <span className="break-all text-fg">
+                              {change.field}: {JSON.stringify(change.old)} →{" "}
+                              {JSON.stringify(change.new)}
+                            </span>
+                          </div>
+                        ))
+                      )}
+                    </div>
+                  </div>
                 </div>
               </AccordionSection>
             </div>
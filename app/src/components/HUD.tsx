@@ -4,6 +4,7 @@ import PlasmaOrb from "./PlasmaOrb";
 
 const HUD = () => {
   const hudState = useAppStore((state) => state.hudState);
+  const downloadProgress = useAppStore((state) => state.hudDownloadProgress);
   const isVisible = hudState !== "idle";
 
   return (
@@ -17,6 +18,11 @@ const HUD = () => {
             transition={{ duration: 0.42, ease: [0.22, 1, 0.36, 1] }}
           >
             <PlasmaOrb state={hudState} size={106} />
+            {hudState === "downloading" && downloadProgress && (
+              <p className="mt-2 text-center text-xs font-medium text-muted">
+                Downloading model {Math.round(downloadProgress.progress * 100)}%
+              </p>
+            )}
           </motion.div>
         )}
       </AnimatePresence>
@@ -0,0 +1,145 @@
+import { useCallback, useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { useAppStore, type HistoryEntry } from "../state/appStore";
+import { Badge, Button, Card } from "../ui/primitives";
+
+const OUTCOME_LABELS: Record<HistoryEntry["outcome"], string> = {
+  pasted: "Pasted",
+  "paste-failed": "Paste failed",
+  "paste-unconfirmed": "Unconfirmed",
+  emitted: "Emitted",
+};
+
+const HistoryPanel = ({ onClose }: { onClose: () => void }) => {
+  const { settings, notify } = useAppStore();
+  const [query, setQuery] = useState("");
+  const [entries, setEntries] = useState<HistoryEntry[]>([]);
+  const [loading, setLoading] = useState(false);
+
+  const load = useCallback(async (search: string) => {
+    setLoading(true);
+    try {
+      const result = search.trim()
+        ? await invoke<HistoryEntry[]>("search_history", { query: search })
+        : await invoke<HistoryEntry[]>("list_history");
+      setEntries(result);
+    } catch (error) {
+      notify({ title: "Could not load history", description: String(error), variant: "error" });
+    } finally {
+      setLoading(false);
+    }
+  }, [notify]);
+
+  useEffect(() => {
+    // Debounce so typing doesn't query on every keystroke.
+    const timer = setTimeout(() => void load(query), 150);
+    return () => clearTimeout(timer);
+  }, [query, load]);
+
+  const handleRepaste = async (entry: HistoryEntry) => {
+    try {
+      await invoke("repaste_history_entry", { id: entry.id });
+    } catch (error) {
+      notify({ title: "Re-paste failed", description: String(error), variant: "error" });
+    }
+  };
+
+  const handleCopy = (entry: HistoryEntry) => {
+    void navigator.clipboard
+      .writeText(entry.text)
+      .then(() => notify({ title: "Copied to clipboard", variant: "success" }))
+      .catch((error) =>
+        notify({ title: "Copy failed", description: String(error), variant: "error" }),
+      );
+  };
+
+  const handleClear = async () => {
+    try {
+      await invoke("clear_history");
+      setEntries([]);
+    } catch (error) {
+      notify({ title: "Could not clear history", description: String(error), variant: "error" });
+    }
+  };
+
+  const historyOff = settings ? !settings.historyEnabled || settings.strictPrivacy : false;
+
+  return (
+    <div className="fixed inset-0 z-50 flex items-center justify-center bg-black/80 p-4">
+      <Card className="flex h-[85vh] w-[900px] max-w-full flex-col overflow-hidden bg-surface">
+        <header className="flex items-center justify-between border-b border-border bg-surface2 px-5 py-3">
+          <h2 className="text-lg font-semibold text-fg">Dictation history</h2>
+          <div className="flex items-center gap-2">
+            <Button
+              variant="ghost"
+              size="sm"
+              disabled={!entries.length}
+              onClick={() => void handleClear()}
+            >
+              Clear history
+            </Button>
+            <Button variant="secondary" size="sm" onClick={onClose}>
+              Close
+            </Button>
+          </div>
+        </header>
+
+        <div className="border-b border-border px-5 py-3">
+          <input
+            type="search"
+            className="w-full rounded-vibe border border-border bg-surface2 px-3 py-2 text-sm text-fg"
+            placeholder="Search transcripts and apps"
+            value={query}
+            onChange={(event) => setQuery(event.target.value)}
+            autoFocus
+          />
+          {historyOff && (
+            <p className="mt-2 text-xs text-muted">
+              History is off{settings?.strictPrivacy ? " in strict privacy" : ""}; new dictations
+              are not recorded.
+            </p>
+          )}
+        </div>
+
+        <div className="flex-1 overflow-y-auto p-5">
+          {!entries.length ? (
+            <p className="text-center text-sm text-muted">
+              {loading ? "Loading..." : query.trim() ? "No matching dictations." : "No dictations yet."}
+            </p>
+          ) : (
+            <ul className="grid gap-3">
+              {entries.map((entry) => (
+                <li
+                  key={entry.id}
+                  className="rounded-vibe border border-border bg-surface2 p-3 text-sm"
+                >
+                  <div className="flex items-center justify-between gap-3 text-xs text-muted">
+                    <span>
+                      {new Date(entry.recordedAt).toLocaleString()}
+                      {entry.app ? ` · ${entry.app}` : ""} · {entry.wordCount}{" "}
+                      {entry.wordCount === 1 ? "word" : "words"}
+                    </span>
+                    <Badge tone={entry.outcome === "paste-failed" ? "bad" : "neutral"}>
+                      {OUTCOME_LABELS[entry.outcome] ?? entry.outcome}
+                    </Badge>
+                  </div>
+                  <p className="mt-2 whitespace-pre-wrap break-words text-fg">{entry.text}</p>
+                  <div className="mt-2 flex justify-end gap-2">
+                    <Button variant="ghost" size="sm" onClick={() => handleCopy(entry)}>
+                      Copy
+                    </Button>
+                    <Button variant="secondary" size="sm" onClick={() => void handleRepaste(entry)}>
+                      Re-paste
+                    </Button>
+                  </div>
+                </li>
+              ))}
+            </ul>
+          )}
+        </div>
+      </Card>
+    </div>
+  );
+};
+
+export default HistoryPanel;
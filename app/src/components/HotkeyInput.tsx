@@ -17,6 +17,19 @@ function parseHotkey(hotkey: string): { modifiers: string[]; key: string } {
   return { modifiers: parts, key };
 }
 
+// evdev button names by MouseEvent.button. Left and right click can't be hotkeys.
+const MOUSE_BUTTONS: Record<number, string> = {
+  1: "BTN_MIDDLE",
+  3: "BTN_SIDE",
+  4: "BTN_EXTRA",
+};
+
+const BUTTON_LABELS: Record<string, string> = {
+  BTN_MIDDLE: "Middle Click",
+  BTN_SIDE: "Mouse Back",
+  BTN_EXTRA: "Mouse Forward",
+};
+
 /**
  * Format a hotkey for display (more readable version).
  */
@@ -42,7 +55,9 @@ function formatHotkeyDisplay(hotkey: string): string {
 
   // Format the key nicely
   let displayKey = key;
-  if (key === " " || key.toLowerCase() === "space") {
+  if (BUTTON_LABELS[key]) {
+    displayKey = BUTTON_LABELS[key];
+  } else if (key === " " || key.toLowerCase() === "space") {
     displayKey = "Space";
   } else if (key.length === 1) {
     displayKey = key.toUpperCase();
@@ -117,6 +132,23 @@ function keyboardEventToHotkey(event: KeyboardEvent): string | null {
   return parts.join("+");
 }
 
+/**
+ * Convert a mouse event to a hotkey string; buttons are only seen by the evdev listener.
+ */
+function mouseEventToHotkey(event: MouseEvent): string | null {
+  const button = MOUSE_BUTTONS[event.button];
+  if (!button) {
+    return null;
+  }
+  const parts: string[] = [];
+  if (event.ctrlKey) parts.push("Ctrl");
+  if (event.altKey) parts.push("Alt");
+  if (event.shiftKey) parts.push("Shift");
+  if (event.metaKey) parts.push("Meta");
+  parts.push(button);
+  return parts.join("+");
+}
+
 /**
  * A component for capturing and displaying keyboard hotkey combinations.
  * Click to start recording, press a key combination, and it will be captured.
@@ -168,16 +200,46 @@ const HotkeyInput = ({
     [isRecording, pendingHotkey, onChange]
   );
 
+  // Side and middle buttons commit on release, like keys. Swallowing them also keeps
+  // the back button from navigating the webview.
+  const handleMouseDown = useCallback(
+    (event: MouseEvent) => {
+      if (!isRecording) return;
+      const hotkey = mouseEventToHotkey(event);
+      if (!hotkey) return;
+      event.preventDefault();
+      event.stopPropagation();
+      setPendingHotkey(hotkey);
+    },
+    [isRecording]
+  );
+
+  const handleMouseUp = useCallback(
+    (event: MouseEvent) => {
+      if (!isRecording || !pendingHotkey || !MOUSE_BUTTONS[event.button]) return;
+      event.preventDefault();
+      event.stopPropagation();
+      onChange(pendingHotkey);
+      setIsRecording(false);
+      setPendingHotkey(null);
+    },
+    [isRecording, pendingHotkey, onChange]
+  );
+
   useEffect(() => {
     if (isRecording) {
       window.addEventListener("keydown", handleKeyDown, true);
       window.addEventListener("keyup", handleKeyUp, true);
+      window.addEventListener("mousedown", handleMouseDown, true);
+      window.addEventListener("mouseup", handleMouseUp, true);
       return () => {
         window.removeEventListener("keydown", handleKeyDown, true);
         window.removeEventListener("keyup", handleKeyUp, true);
+        window.removeEventListener("mousedown", handleMouseDown, true);
+        window.removeEventListener("mouseup", handleMouseUp, true);
       };
     }
-  }, [isRecording, handleKeyDown, handleKeyUp]);
+  }, [isRecording, handleKeyDown, handleKeyUp, handleMouseDown, handleMouseUp]);
 
   // Close recording if clicking outside
   useEffect(() => {
@@ -208,7 +270,7 @@ const HotkeyInput = ({
   const displayValue = isRecording
     ? pendingHotkey
       ? formatHotkeyDisplay(pendingHotkey)
-      : "Press a key combination..."
+      : "Press a key combination or mouse button..."
     : value
       ? formatHotkeyDisplay(value)
       : placeholder;
@@ -0,0 +1,48 @@
+import { type HudLevels } from "../state/appStore";
+
+// The history is right-aligned; until it fills up, the missing points draw as dim stubs.
+const BAR_COUNT = 30;
+const BAR_WIDTH = 3;
+const BAR_GAP = 2;
+const HEIGHT = 24;
+
+// Speech RMS rarely passes 0.3; scale it so normal speech fills most of the height.
+const levelToHeight = (rms: number, peak: number) => {
+  const level = Math.min(1, Math.max(rms * 3, peak * 0.6));
+  return Math.max(2, Math.round(Math.sqrt(level) * HEIGHT));
+};
+
+const LevelWaveform = ({ levels, accent }: { levels: HudLevels; accent?: string | null }) => {
+  const padding = Math.max(0, BAR_COUNT - levels.rms.length);
+  const width = BAR_COUNT * (BAR_WIDTH + BAR_GAP) - BAR_GAP;
+
+  return (
+    <svg
+      className="mx-auto mt-2 block text-accent"
+      width={width}
+      height={HEIGHT}
+      viewBox={`0 0 ${width} ${HEIGHT}`}
+      aria-hidden="true"
+    >
+      {Array.from({ length: BAR_COUNT }, (_, index) => {
+        const point = index - padding;
+        const height =
+          point >= 0 ? levelToHeight(levels.rms[point] ?? 0, levels.peak[point] ?? 0) : 2;
+        return (
+          <rect
+            key={index}
+            x={index * (BAR_WIDTH + BAR_GAP)}
+            y={(HEIGHT - height) / 2}
+            width={BAR_WIDTH}
+            height={height}
+            rx={1.5}
+            fill={accent ?? "currentColor"}
+            opacity={point >= 0 ? 0.9 : 0.3}
+          />
+        );
+      })}
+    </svg>
+  );
+};
+
+export default LevelWaveform;
@@ -0,0 +1,103 @@
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { useAppStore } from "../state/appStore";
+import { Button, Kbd } from "../ui/primitives";
+
+const PendingTranscript = () => {
+  const pendingTranscript = useAppStore((state) => state.pendingTranscript);
+  const setPendingTranscript = useAppStore((state) => state.setPendingTranscript);
+  const transcriptAlternatives = useAppStore((state) => state.transcriptAlternatives);
+  const [draft, setDraft] = useState("");
+
+  useEffect(() => {
+    setDraft(pendingTranscript ?? "");
+  }, [pendingTranscript]);
+
+  if (pendingTranscript === null) {
+    return null;
+  }
+
+  // Alternatives describe a single dictation; once queued dictations were appended
+  // they no longer line up with the held text.
+  const alternatives =
+    transcriptAlternatives?.text === pendingTranscript
+      ? transcriptAlternatives.alternatives.slice(0, 9)
+      : [];
+
+  const confirm = () => {
+    setPendingTranscript(null);
+    if (draft.trim() && draft !== pendingTranscript) {
+      invoke("submit_correction", { original: pendingTranscript, corrected: draft }).catch(
+        (error) => console.error("Failed to record correction", error),
+      );
+    }
+    invoke("confirm_pending_transcript", {
+      text: draft === pendingTranscript ? null : draft,
+    }).catch((error) => console.error("Failed to confirm transcript", error));
+  };
+
+  const discard = () => {
+    setPendingTranscript(null);
+    invoke("discard_pending_transcript").catch((error) =>
+      console.error("Failed to discard transcript", error),
+    );
+  };
+
+  return (
+    <div className="fixed inset-x-0 bottom-4 z-[900] flex justify-center px-4">
+      <div className="w-full max-w-lg rounded-vibe border border-border bg-surface p-4 shadow-[0_6px_0_hsl(var(--shadow)/0.22),0_18px_50px_hsl(var(--shadow)/0.35)]">
+        <p className="text-sm font-semibold text-fg">Review transcript</p>
+        <textarea
+          autoFocus
+          className="mt-2 h-28 w-full resize-none rounded-vibe border border-border bg-surface2 p-2 text-sm text-fg"
+          value={draft}
+          onChange={(event) => setDraft(event.target.value)}
+          onKeyDown={(event) => {
+            const picked = event.altKey ? Number.parseInt(event.key, 10) : NaN;
+            if (picked >= 1 && picked <= alternatives.length) {
+              event.preventDefault();
+              setDraft(alternatives[picked - 1]);
+            } else if (event.key === "Enter" && !event.shiftKey) {
+              event.preventDefault();
+              confirm();
+            } else if (event.key === "Escape") {
+              event.preventDefault();
+              discard();
+            }
+          }}
+        />
+        {alternatives.length > 0 && (
+          <ol className="mt-2 grid gap-1">
+            {alternatives.map((alternative, index) => (
+              <li key={alternative}>
+                <button
+                  type="button"
+                  className="flex w-full items-start gap-2 rounded-vibe px-2 py-1 text-left text-xs text-muted hover:bg-surface2 hover:text-fg"
+                  onClick={() => setDraft(alternative)}
+                >
+                  <Kbd>Alt+{index + 1}</Kbd>
+                  <span>{alternative}</span>
+                </button>
+              </li>
+            ))}
+          </ol>
+        )}
+        <div className="mt-2 flex items-center justify-between gap-3">
+          <p className="text-xs text-muted">
+            <Kbd>Enter</Kbd> or the dictation hotkey to paste, <Kbd>Esc</Kbd> to discard
+          </p>
+          <div className="flex gap-2">
+            <Button variant="ghost" size="sm" onClick={discard}>
+              Discard
+            </Button>
+            <Button variant="primary" size="sm" onClick={confirm}>
+              Paste
+            </Button>
+          </div>
+        </div>
+      </div>
+    </div>
+  );
+};
+
+export default PendingTranscript;
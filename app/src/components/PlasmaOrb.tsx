@@ -10,7 +10,30 @@ type OrbPalette = {
   spark: string;
 };
 
-function paletteForState(state: HudState): OrbPalette {
+function accentPalette(hex: string): OrbPalette | null {
+  const match = /^#([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$/i.exec(hex);
+  if (!match) {
+    return null;
+  }
+  const [r, g, b] = match.slice(1).map((part) => Number.parseInt(part, 16));
+  // Lighter tints of the accent for the inner strokes, as in the built-in palettes.
+  const tint = (amount: number, alpha: number) =>
+    `rgba(${[r, g, b].map((c) => Math.round(c + (255 - c) * amount)).join(", ")}, ${alpha})`;
+  return {
+    glow: tint(0, 0.82),
+    ring: tint(0.4, 0.68),
+    arc: tint(0.55, 0.95),
+    arcSoft: tint(0.35, 0.52),
+    spark: tint(0.75, 0.8),
+  };
+}
+
+function paletteForState(state: HudState, accent?: string | null): OrbPalette {
+  const accented = state === "listening" && accent ? accentPalette(accent) : null;
+  if (accented) {
+    return accented;
+  }
+
   if (state === "listening") {
     return {
       glow: "rgba(20, 170, 255, 0.82)",
@@ -63,10 +86,12 @@ function paletteForState(state: HudState): OrbPalette {
 type PlasmaOrbProps = {
   state: HudState;
   size?: number;
+  // `#rrggbb` tint for the listening state.
+  accent?: string | null;
 };
 
-const PlasmaOrb = ({ state, size = 104 }: PlasmaOrbProps) => {
-  const palette = paletteForState(state);
+const PlasmaOrb = ({ state, size = 104, accent = null }: PlasmaOrbProps) => {
+  const palette = paletteForState(state, accent);
   const isProcessing = state === "processing";
   const ringArcCycle = 278;
   const sparkRingCycle = 127;
@@ -6,15 +6,25 @@ import { AccordionSection, Badge, Button, Card, Disclosure, Select } from "../ui
 import type {
   AppSettings,
   AudioDevice,
+  LatencyBudget,
+  PauseThresholds,
+  PerformancePolicy,
+  TimingStage,
   DownloadLogEntry,
   LinuxPermissionsStatus,
   GnomeHudExtensionStatus,
+  ModelQuery,
   ModelRecord,
   ModelStateKind,
+  QualityTier,
+  RawModelAsset,
+  SettingsFieldError,
+  VoiceCommand,
 } from "../state/appStore";
 import {
   DEFAULT_PUSH_TO_TALK_HOTKEY,
   DEFAULT_TOGGLE_TO_TALK_HOTKEY,
+  settingsFieldErrors,
 } from "../state/appStore";
 import HotkeyInput from "./HotkeyInput";
 
@@ -22,12 +32,19 @@ type UpdateCheckResult = {
   currentVersion: string;
   latestVersion: string;
   updateAvailable: boolean;
+  installKind: "tarball" | "appimage";
   tarballUrl?: string | null;
   sha256Url?: string | null;
   checkedAtUnix: number;
   fromCache: boolean;
 };
 
+type ReleaseNotes = {
+  version: string;
+  notes: string;
+  url?: string | null;
+};
+
 type DownloadedUpdate = {
   version: string;
   tarballPath: string;
@@ -37,6 +54,8 @@ type UpdateDownloadProgress = {
   stage: string;
   downloadedBytes: number;
   totalBytes?: number | null;
+  bytesPerSec?: number | null;
+  etaSecs?: number | null;
 };
 
 type UpdateApplyProgress = {
@@ -52,7 +71,7 @@ const PRESET_SINGLE_KEYS = [
 ] as const;
 
 type WhisperSize = "tiny" | "base" | "small" | "medium" | "large-v3" | "large-v3-turbo";
-type WhisperBackend = "ct2" | "onnx";
+type WhisperBackend = "ct2" | "onnx" | "cpp";
 type WhisperLanguage = "en" | "multi";
 type WhisperPrecision = "int8" | "float";
 
@@ -114,6 +133,10 @@ function resolveWhisperAssetName(
   }
 
   const langSuffix = modelLanguage === "en" ? "-en" : "";
+  if (backend === "cpp") {
+    // whisper.cpp ships 5-bit quantizations in place of int8.
+    return `whisper-cpp-${size}${langSuffix}-${precision === "float" ? "float" : "q5"}`;
+  }
   return `whisper-onnx-${size}${langSuffix}-${precision}`;
 }
 
@@ -140,24 +163,611 @@ function hotkeyUsesCtrl(value: string): boolean {
     .some((part) => part === "ctrl" || part === "leftctrl" || part === "rightctrl");
 }
 
-function hotkeyMatchesPasteShortcut(
-  hotkey: string,
-  pasteShortcut: AppSettings["pasteShortcut"],
-): boolean {
-  const normalized = hotkey
+function normalizeChord(value: string): string {
+  return value
     .split("+")
     .map(normalizeHotkeyPart)
     .filter(Boolean)
     .join("+");
+}
 
-  const expected = pasteShortcut === "ctrl-v" ? "ctrl+v" : "ctrl+shift+v";
-  return normalized === expected;
+function hotkeyMatchesPasteShortcut(hotkey: string, pasteShortcut: string): boolean {
+  return normalizeChord(hotkey) === normalizeChord(pasteShortcut);
 }
 
+const PASTE_SHORTCUT_PRESETS = ["Ctrl+Shift+V", "Ctrl+V", "Shift+Insert"] as const;
+
+// Voice command actions that edit the last dictation and take no argument.
+const EDITING_COMMAND_ACTIONS: VoiceCommand["action"][] = [
+  "delete-last-sentence",
+  "delete-last-utterance",
+  "select-last-sentence",
+  "replace",
+];
+
+const DICTATION_LANGUAGES = [
+  { value: "auto", label: "Auto Detect" },
+  { value: "en", label: "English" },
+  { value: "es", label: "Spanish" },
+  { value: "de", label: "German" },
+  { value: "fr", label: "French" },
+];
+
 function isPresetSingleKey(value: string): boolean {
   return PRESET_SINGLE_KEYS.includes(value as (typeof PRESET_SINGLE_KEYS)[number]);
 }
 
+const PasteShortcutField = ({
+  value,
+  onChange,
+}: {
+  value: string;
+  onChange: (chord: string) => void;
+}) => {
+  const isPreset = PASTE_SHORTCUT_PRESETS.some((preset) => preset === value);
+  const [customOpen, setCustomOpen] = useState(!isPreset);
+  const showCustom = customOpen || !isPreset;
+
+  return (
+    <>
+      <label className="flex items-center justify-between gap-3">
+        <span>Paste Shortcut</span>
+        <Select
+          width="md"
+          value={showCustom ? "__custom__" : value}
+          onChange={(v) => {
+            if (v === "__custom__") {
+              setCustomOpen(true);
+              return;
+            }
+            setCustomOpen(false);
+            onChange(v);
+          }}
+          options={[
+            { value: "Ctrl+Shift+V", label: "Ctrl+Shift+V", description: "Terminal friendly" },
+            { value: "Ctrl+V", label: "Ctrl+V" },
+            { value: "Shift+Insert", label: "Shift+Insert", description: "X11 terminals" },
+            { value: "__custom__", label: "Custom…" },
+          ]}
+        />
+      </label>
+      {showCustom && (
+        <HotkeyInput value={value} onChange={onChange} placeholder="Press the paste chord" />
+      )}
+    </>
+  );
+};
+
+// Stages with a share of the end-to-end budget; matches `default_budget_share`.
+const BUDGETED_STAGES: { stage: TimingStage; label: string; share: number }[] = [
+  { stage: "queue", label: "Queue wait", share: 0.05 },
+  { stage: "trim", label: "Trim", share: 0.02 },
+  { stage: "speaker-gate", label: "Speaker gate", share: 0.08 },
+  { stage: "asr", label: "ASR decode", share: 0.6 },
+  { stage: "clean", label: "Cleanup", share: 0.05 },
+  { stage: "clipboard", label: "Clipboard", share: 0.1 },
+  { stage: "key-inject", label: "Paste keystroke", share: 0.1 },
+];
+
+const LatencyBudgetEditor = ({
+  value,
+  onChange,
+}: {
+  value: LatencyBudget;
+  onChange: (next: LatencyBudget) => void;
+}) => {
+  const setStage = (stage: TimingStage, raw: string) => {
+    const stageMs = { ...value.stageMs };
+    const parsed = Number(raw);
+    if (raw.trim() === "" || !Number.isFinite(parsed)) {
+      delete stageMs[stage];
+    } else {
+      stageMs[stage] = Math.max(0, Math.round(parsed));
+    }
+    onChange({ ...value, stageMs });
+  };
+
+  return (
+    <div className="grid gap-3">
+      <label className="flex items-center justify-between gap-3">
+        <span>Target latency after key release (ms, 0 = off)</span>
+        <input
+          type="number"
+          min={0}
+          step={100}
+          className="w-24 rounded-vibe border border-border bg-surface2 px-2 py-1 text-right text-sm"
+          value={value.targetMs}
+          onChange={(event) => {
+            const parsed = Number(event.target.value);
+            if (Number.isFinite(parsed)) {
+              onChange({ ...value, targetMs: Math.max(0, Math.round(parsed)) });
+            }
+          }}
+        />
+      </label>
+      {BUDGETED_STAGES.map(({ stage, label, share }) => (
+        <label key={stage} className="flex items-center justify-between gap-3 text-xs">
+          <span className="text-muted">{label} budget (ms)</span>
+          <input
+            type="number"
+            min={0}
+            className="w-24 rounded-vibe border border-border bg-surface2 px-2 py-1 text-right text-sm"
+            placeholder={value.targetMs > 0 ? String(Math.round(value.targetMs * share)) : "—"}
+            value={value.stageMs[stage] ?? ""}
+            onChange={(event) => setStage(stage, event.target.value)}
+          />
+        </label>
+      ))}
+    </div>
+  );
+};
+
+// Parakeet emits no punctuation; pauses between speech segments stand in for it.
+const PauseThresholdsEditor = ({
+  enabled,
+  language,
+  value,
+  onToggle,
+  onChange,
+}: {
+  enabled: boolean;
+  language: string;
+  value: Record<string, PauseThresholds>;
+  onToggle: (enabled: boolean) => void;
+  onChange: (next: Record<string, PauseThresholds>) => void;
+}) => {
+  const key = language === "auto" ? "default" : language;
+  const current = value[key] ?? value.default ?? { commaMs: 450, periodMs: 1000 };
+  const field = (name: keyof PauseThresholds, label: string) => (
+    <label className="flex items-center justify-between gap-3 text-xs">
+      <span className="text-muted">{label}</span>
+      <input
+        type="number"
+        min={150}
+        step={50}
+        disabled={!enabled}
+        className="w-24 rounded-vibe border border-border bg-surface2 px-2 py-1 text-right text-sm"
+        value={current[name]}
+        onChange={(event) => {
+          const parsed = Number(event.target.value);
+          if (Number.isFinite(parsed)) {
+            onChange({ ...value, [key]: { ...current, [name]: Math.round(parsed) } });
+          }
+        }}
+      />
+    </label>
+  );
+
+  return (
+    <div className="grid gap-2">
+      <label className="flex items-center gap-2 text-sm">
+        <input type="checkbox" checked={enabled} onChange={(event) => onToggle(event.target.checked)} />
+        Punctuate pauses in Parakeet transcripts
+      </label>
+      {field("commaMs", `Comma after pause (ms, ${key})`)}
+      {field("periodMs", `Sentence break after pause (ms, ${key})`)}
+    </div>
+  );
+};
+
+const PerformancePolicyEditor = ({
+  value,
+  onChange,
+}: {
+  value: PerformancePolicy;
+  onChange: (next: PerformancePolicy) => void;
+}) => {
+  const numberField = (key: keyof PerformancePolicy, label: string, min: number, step = 1) => (
+    <label className="flex items-center justify-between gap-3">
+      <span>{label}</span>
+      <input
+        type="number"
+        min={min}
+        step={step}
+        className="w-24 rounded-vibe border border-border bg-surface2 px-2 py-1 text-right text-sm"
+        value={value[key] as number}
+        onChange={(event) => {
+          const parsed = Number(event.target.value);
+          if (Number.isFinite(parsed)) {
+            onChange({ ...value, [key]: Math.max(min, parsed) });
+          }
+        }}
+      />
+    </label>
+  );
+
+  return (
+    <div className="grid gap-3">
+      {numberField("slowLatencyMs", "Slow when latency exceeds (ms)", 100, 100)}
+      {numberField("slowCpuPercent", "…and CPU is above (%)", 0, 5)}
+      {numberField("enterAfterSlowSessions", "Turn on after slow sessions", 1)}
+      {numberField("exitAfterFastSessions", "Turn off after normal sessions", 1)}
+      {numberField("recoverCpuPercent", "Turn off early below CPU % (0 = never)", 0, 5)}
+      <label className="flex items-center gap-2 text-sm">
+        <input
+          type="checkbox"
+          checked={value.shortenVadHangover}
+          onChange={(event) => onChange({ ...value, shortenVadHangover: event.target.checked })}
+        />
+        Shorten VAD hangover
+      </label>
+      {value.shortenVadHangover && numberField("hangoverCapMs", "Hangover cap (ms)", 50, 50)}
+      <label className="flex items-center gap-2 text-sm">
+        <input
+          type="checkbox"
+          checked={value.suggestModelDownshift}
+          onChange={(event) => onChange({ ...value, suggestModelDownshift: event.target.checked })}
+        />
+        Suggest a lighter model
+      </label>
+      <LatencyBudgetEditor
+        value={value.latencyBudget}
+        onChange={(latencyBudget) => onChange({ ...value, latencyBudget })}
+      />
+    </div>
+  );
+};
+
+const AppPasteShortcutsEditor = ({
+  value,
+  onChange,
+}: {
+  value: Record<string, string>;
+  onChange: (next: Record<string, string>) => void;
+}) => {
+  const [appClass, setAppClass] = useState("");
+  const [chord, setChord] = useState("Shift+Insert");
+  const entries = Object.entries(value);
+
+  return (
+    <div className="grid gap-2 text-sm">
+      <div>
+        <span>Per-app paste shortcuts</span>
+        <p className="text-xs text-muted">
+          Matched against the focused window class on X11 (e.g. xterm, Remmina).
+        </p>
+      </div>
+      {entries.map(([app, appChord]) => (
+        <div key={app} className="flex items-center justify-between gap-3">
+          <span className="text-muted">{app}</span>
+          <div className="flex items-center gap-2">
+            <span>{appChord}</span>
+            <Button
+              variant="ghost"
+              size="sm"
+              onClick={() => {
+                const next = { ...value };
+                delete next[app];
+                onChange(next);
+              }}
+            >
+              Remove
+            </Button>
+          </div>
+        </div>
+      ))}
+      <div className="flex items-center gap-2">
+        <input
+          className="w-32 rounded-vibe border border-border bg-surface2 px-2 py-1 text-sm"
+          placeholder="Window class"
+          value={appClass}
+          onChange={(event) => setAppClass(event.target.value)}
+        />
+        <HotkeyInput value={chord} onChange={setChord} placeholder="Paste chord" />
+        <Button
+          variant="secondary"
+          size="sm"
+          disabled={!appClass.trim() || !chord}
+          onClick={() => {
+            onChange({ ...value, [appClass.trim()]: chord });
+            setAppClass("");
+          }}
+        >
+          Add
+        </Button>
+      </div>
+    </div>
+  );
+};
+
+type SpeakerProfileSummary = { enrolledAt: string; enrollmentSeconds: number };
+
+type GpuInventory = {
+  devices: { vendor: string; name: string }[];
+  cuda: boolean;
+  rocm: boolean;
+};
+
+type GpuFallback = {
+  device: string;
+  reason: string;
+};
+
+// Picks the ASR device; GPU loads that fail fall back to the CPU and are reported here.
+const GpuAccelerationCard = ({
+  draft,
+  record,
+  onChange,
+  onInstallAsset,
+}: {
+  draft: AppSettings;
+  record: ModelRecord | undefined;
+  onChange: <K extends keyof AppSettings>(key: K, value: AppSettings[K]) => void;
+  onInstallAsset: (name: string) => void;
+}) => {
+  const [inventory, setInventory] = useState<GpuInventory | null>(null);
+  const [fallback, setFallback] = useState<GpuFallback | null>(null);
+
+  useEffect(() => {
+    invoke<GpuInventory>("detect_gpus")
+      .then(setInventory)
+      .catch((err) => console.error("Failed to detect GPUs", err));
+    const unlisten = listen<GpuFallback>("asr-gpu-fallback", (event) =>
+      setFallback(event.payload),
+    );
+    return () => {
+      void unlisten.then((dispose) => dispose());
+    };
+  }, []);
+
+  const detected = inventory?.devices.map((device) => device.name).join(", ");
+
+  return (
+    <Card className="p-4">
+      <div className="text-sm font-semibold text-fg">GPU acceleration (optional)</div>
+      <div className="mt-1 text-xs text-muted">
+        {detected ? `Detected: ${detected}` : "No supported GPU detected."}
+      </div>
+      <div className="mt-3 grid gap-3">
+        <label className="flex items-center justify-between gap-3">
+          <span>Run speech recognition on</span>
+          <Select
+            width="md"
+            value={draft.asrDevice}
+            onChange={(v) => onChange("asrDevice", v as AppSettings["asrDevice"])}
+            options={[
+              { value: "cpu", label: "CPU" },
+              { value: "auto", label: "Best available" },
+              { value: "cuda", label: "NVIDIA (CUDA)", disabled: !inventory?.cuda },
+              { value: "rocm", label: "AMD (ROCm)", disabled: !inventory?.rocm },
+            ]}
+          />
+        </label>
+        {draft.asrDevice !== "cpu" && (
+          <CompactDownloadRow
+            title="CUDA runtime libraries"
+            subtitle="Needed by GPU builds when CUDA isn't installed system-wide."
+            record={record}
+            assetName={record?.name ?? ""}
+            onInstall={onInstallAsset}
+          />
+        )}
+        {fallback && (
+          <p className="text-xs text-warn">
+            {fallback.device.toUpperCase()} could not be used, running on the CPU: {fallback.reason}
+          </p>
+        )}
+      </div>
+    </Card>
+  );
+};
+
+const SPEAKER_ENROLLMENT_SECONDS = 10;
+
+const SpeakerGateCard = ({
+  draft,
+  record,
+  onChange,
+  onInstallAsset,
+}: {
+  draft: AppSettings;
+  record: ModelRecord | undefined;
+  onChange: <K extends keyof AppSettings>(key: K, value: AppSettings[K]) => void;
+  onInstallAsset: (name: string) => void;
+}) => {
+  const [profile, setProfile] = useState<SpeakerProfileSummary | null>(null);
+  const [enrolling, setEnrolling] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const installed = record?.status.state === "installed";
+
+  useEffect(() => {
+    invoke<SpeakerProfileSummary | null>("speaker_profile")
+      .then(setProfile)
+      .catch((err) => console.error("Failed to load speaker profile", err));
+  }, []);
+
+  const enroll = async () => {
+    setEnrolling(true);
+    setError(null);
+    try {
+      setProfile(
+        await invoke<SpeakerProfileSummary>("enroll_speaker", {
+          seconds: SPEAKER_ENROLLMENT_SECONDS,
+        }),
+      );
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setEnrolling(false);
+    }
+  };
+
+  const clear = () => {
+    invoke("clear_speaker_profile")
+      .then(() => setProfile(null))
+      .catch((err) => setError(String(err)));
+  };
+
+  return (
+    <Card className="p-4">
+      <div className="text-sm font-semibold text-fg">Speaker Gate (optional)</div>
+      <div className="mt-1 text-xs text-muted">
+        Only transcribe your voice; speech from TVs or nearby people is dropped before ASR.
+      </div>
+      <div className="mt-3 grid gap-3">
+        <CompactDownloadRow
+          title="Speaker embedding (ECAPA)"
+          record={record}
+          assetName={record?.name ?? ""}
+          onInstall={onInstallAsset}
+        />
+        <div className="flex items-center justify-between gap-3 text-sm">
+          <span className="text-muted">
+            {profile
+              ? `Enrolled ${new Date(profile.enrolledAt).toLocaleDateString()} (${Math.round(profile.enrollmentSeconds)} s)`
+              : "No voice enrolled"}
+          </span>
+          <div className="flex gap-2">
+            {profile && (
+              <Button variant="ghost" size="sm" onClick={clear} disabled={enrolling}>
+                Forget
+              </Button>
+            )}
+            <Button
+              variant="secondary"
+              size="sm"
+              onClick={() => void enroll()}
+              disabled={!installed || enrolling}
+            >
+              {enrolling
+                ? `Speak for ${SPEAKER_ENROLLMENT_SECONDS} s…`
+                : profile
+                  ? "Re-enroll"
+                  : "Enroll my voice"}
+            </Button>
+          </div>
+        </div>
+        {error && <p className="text-xs text-warn">{error}</p>}
+        <label className="flex items-center gap-2 text-sm">
+          <input
+            type="checkbox"
+            checked={draft.speakerGate}
+            disabled={!profile}
+            onChange={(event) => onChange("speakerGate", event.target.checked)}
+          />
+          Only transcribe the enrolled speaker
+        </label>
+        {draft.speakerGate && (
+          <label className="flex items-center justify-between gap-3">
+            <span>Match strictness</span>
+            <Select
+              width="md"
+              value={String(draft.speakerGateThreshold)}
+              onChange={(v) => onChange("speakerGateThreshold", Number(v))}
+              options={[
+                { value: "0.35", label: "Lenient" },
+                { value: "0.5", label: "Balanced" },
+                { value: "0.65", label: "Strict" },
+              ]}
+            />
+          </label>
+        )}
+      </div>
+    </Card>
+  );
+};
+
+type CorrectionSubstitution = { from: string; to: string; count: number };
+type CorrectionSummary = {
+  pairCount: number;
+  substitutions: CorrectionSubstitution[];
+  vocabulary: string[];
+};
+
+// Must match RULE_PROMOTION_COUNT in llm/corrections.rs.
+const CORRECTION_RULE_COUNT = 3;
+
+const LearnedCorrections = () => {
+  const [summary, setSummary] = useState<CorrectionSummary | null>(null);
+
+  const refresh = useCallback(() => {
+    invoke<CorrectionSummary>("list_corrections")
+      .then(setSummary)
+      .catch((error) => console.error("Failed to load corrections", error));
+  }, []);
+
+  useEffect(() => {
+    refresh();
+  }, [refresh]);
+
+  if (!summary || summary.pairCount === 0) {
+    return null;
+  }
+
+  const rules = summary.substitutions.filter((entry) => entry.count >= CORRECTION_RULE_COUNT);
+  const pending = summary.substitutions.length - rules.length;
+
+  return (
+    <div className="grid gap-2 text-sm">
+      <div>
+        <span>Learned corrections</span>
+        <p className="text-xs text-muted">
+          Edits made while reviewing transcripts. A fix repeated {CORRECTION_RULE_COUNT} times is
+          applied automatically. {summary.pairCount} stored
+          {pending > 0 ? `, ${pending} still learning` : ""}.
+        </p>
+      </div>
+      {rules.map((rule) => (
+        <div key={`${rule.from}->${rule.to}`} className="flex items-center justify-between gap-3">
+          <span className="text-muted">
+            {rule.from} → <span className="text-fg">{rule.to}</span>
+          </span>
+          <Button
+            variant="ghost"
+            size="sm"
+            onClick={() => {
+              invoke("forget_correction", { from: rule.from, to: rule.to })
+                .then(refresh)
+                .catch((error) => console.error("Failed to forget correction", error));
+            }}
+          >
+            Remove
+          </Button>
+        </div>
+      ))}
+      <div>
+        <Button
+          variant="secondary"
+          size="sm"
+          onClick={() => {
+            invoke("clear_corrections")
+              .then(refresh)
+              .catch((error) => console.error("Failed to clear corrections", error));
+          }}
+        >
+          Clear learned corrections
+        </Button>
+      </div>
+    </div>
+  );
+};
+
+type SettingsSectionKey = "general" | "models" | "updates" | "secrets" | "linux";
+
+const resolveSettingsRoute = (
+  route: string,
+): { section: SettingsSectionKey; elementId?: string } | null => {
+  if (route.startsWith("models/")) {
+    return { section: "models", elementId: `model-${route.slice("models/".length)}` };
+  }
+  switch (route) {
+    case "settings":
+    case "settings/general":
+      return { section: "general" };
+    case "settings/models":
+      return { section: "models" };
+    case "settings/updates":
+      return { section: "updates" };
+    case "settings/secrets":
+      return { section: "secrets" };
+    case "settings/permissions":
+    case "settings/linux":
+      return { section: "linux" };
+    default:
+      return null;
+  }
+};
+
 const SettingsPanel = () => {
   const {
     settings,
@@ -174,9 +784,13 @@ const SettingsPanel = () => {
     gnomeHudExtensionStatus,
     refreshGnomeHudExtensionStatus,
     installGnomeHudExtension,
+    settingsRoute,
+    clearSettingsRoute,
+    setSettingsState,
   } = useAppStore();
 
   const [draft, setDraft] = useState<AppSettings | null>(null);
+  const [fieldErrors, setFieldErrors] = useState<SettingsFieldError[]>([]);
   const [linuxSetupBusy, setLinuxSetupBusy] = useState(false);
   const [linuxSetupMessage, setLinuxSetupMessage] = useState<string | null>(null);
   const [hudExtensionBusy, setHudExtensionBusy] = useState(false);
@@ -193,6 +807,7 @@ const SettingsPanel = () => {
     general: true,
     models: true,
     updates: false,
+    secrets: false,
     linux: false,
   });
 
@@ -233,6 +848,26 @@ const SettingsPanel = () => {
     };
   }, []);
 
+  // Deep links from the backend ("settings/<section>" or "models/<asset>"): open the
+  // matching section and scroll the target into view once it has rendered.
+  useEffect(() => {
+    if (!settingsRoute || !draft) {
+      return;
+    }
+    const target = resolveSettingsRoute(settingsRoute);
+    clearSettingsRoute();
+    if (!target) {
+      return;
+    }
+    setSections((s) => ({ ...s, [target.section]: true }));
+    requestAnimationFrame(() => {
+      const element =
+        (target.elementId && document.getElementById(target.elementId)) ||
+        document.getElementById(`settings-${target.section}`);
+      element?.scrollIntoView({ behavior: "smooth", block: "start" });
+    });
+  }, [settingsRoute, draft, clearSettingsRoute]);
+
   const lastLoadedSettingsRef = useRef<AppSettings | null>(null);
 
   useEffect(() => {
@@ -273,13 +908,25 @@ const SettingsPanel = () => {
     value: AppSettings[K],
   ) => {
     setDraft((prev) => (prev ? { ...prev, [key]: value } : prev));
+    setFieldErrors((prev) => prev.filter((error) => error.field !== key));
   };
 
   const handleSave = async () => {
     if (!draft) {
       return;
     }
-    await updateSettings(draft);
+    try {
+      await updateSettings(draft);
+    } catch (error) {
+      // Rejected fields stay listed until edited; anything else is a real failure.
+      const errors = settingsFieldErrors(error);
+      if (!errors) {
+        throw error;
+      }
+      setFieldErrors(errors);
+      return;
+    }
+    setFieldErrors([]);
     toggleSettings(false);
   };
 
@@ -369,7 +1016,11 @@ const SettingsPanel = () => {
     setUpdateBusy(true);
     setUpdateMessage(null);
     setUpdateProgress(null);
-    setUpdateApplyProgress({ stage: "auth", message: "Waiting for admin approval" });
+    setUpdateApplyProgress(
+      updateInfo?.installKind === "appimage"
+        ? { stage: "starting", message: null }
+        : { stage: "auth", message: "Waiting for admin approval" },
+    );
     try {
       await invoke("apply_update", { tarballPath: downloadedUpdate.tarballPath });
       setUpdateApplied(true);
@@ -412,6 +1063,7 @@ const SettingsPanel = () => {
         <div className="mt-6 flex-1 overflow-y-auto pr-2">
           <div className="space-y-4">
             <AccordionSection
+              id="settings-general"
               title="General"
               description="Audio input, VAD, autoclean, hotkey mode, and theme."
               open={sections.general}
@@ -433,6 +1085,7 @@ const SettingsPanel = () => {
             </AccordionSection>
 
             <AccordionSection
+              id="settings-models"
               title="Models"
               description="Guided setup plus advanced Whisper controls."
               open={sections.models}
@@ -449,12 +1102,15 @@ const SettingsPanel = () => {
             </AccordionSection>
 
             <AccordionSection
+              id="settings-updates"
               title="Updates"
               description="Check GitHub Releases and update the /opt install."
               open={sections.updates}
               onToggle={() => setSections((s) => ({ ...s, updates: !s.updates }))}
             >
               <UpdatesSection
+                draft={draft}
+                onChange={handleChange}
                 linuxStatus={linuxPermissions}
                 info={updateInfo}
                 downloaded={downloadedUpdate}
@@ -472,6 +1128,23 @@ const SettingsPanel = () => {
             </AccordionSection>
 
             <AccordionSection
+              id="settings-secrets"
+              title="Secrets"
+              description="API keys and tokens, kept in the system keyring instead of config.json."
+              open={sections.secrets}
+              onToggle={() => setSections((s) => ({ ...s, secrets: !s.secrets }))}
+            >
+              <SecretsSection
+                storedSecrets={draft.storedSecrets}
+                onStored={(next) => {
+                  setSettingsState(next);
+                  setDraft((prev) => (prev ? { ...prev, storedSecrets: next.storedSecrets } : prev));
+                }}
+              />
+            </AccordionSection>
+
+            <AccordionSection
+              id="settings-linux"
               title="Linux Setup"
               description="Wayland paste/hotkey permissions overview and one-click setup."
               open={sections.linux}
@@ -488,6 +1161,20 @@ const SettingsPanel = () => {
           </div>
         </div>
 
+        {fieldErrors.length > 0 && (
+          <div
+            role="alert"
+            className="mt-6 grid gap-1 rounded-vibe border border-warn/30 bg-warn/10 p-3 text-xs text-fg"
+          >
+            <div className="font-semibold">Fix these settings before saving:</div>
+            {fieldErrors.map((error, index) => (
+              <div key={`${error.field}-${index}`}>
+                <span className="font-mono text-warn">{error.field}</span>: {error.message}
+              </div>
+            ))}
+          </div>
+        )}
+
         <footer className="mt-6 flex justify-end gap-3">
           <Button variant="secondary" onClick={() => toggleSettings(false)}>
             Cancel
@@ -546,8 +1233,11 @@ function parseWhisperAssetName(name: string): WhisperVariant | null {
     };
   }
 
-  if (name.startsWith("whisper-onnx-")) {
-    const rest = name.slice("whisper-onnx-".length);
+  const prefixed = (["onnx", "cpp"] as const).find((backend) =>
+    name.startsWith(`whisper-${backend}-`),
+  );
+  if (prefixed) {
+    const rest = name.slice(`whisper-${prefixed}-`.length);
     const size = sizeIds.find((candidate) => rest.startsWith(candidate)) as WhisperSize | undefined;
     if (!size) {
       return null;
@@ -561,7 +1251,7 @@ function parseWhisperAssetName(name: string): WhisperVariant | null {
     const language: WhisperLanguage = suffixParts.includes("en") ? "en" : "multi";
     const precision: WhisperPrecision = suffixParts.includes("float") ? "float" : "int8";
     return {
-      backend: "onnx",
+      backend: prefixed,
       size,
       language,
       precision,
@@ -573,11 +1263,334 @@ function parseWhisperAssetName(name: string): WhisperVariant | null {
 
 function statusLabel(status: ModelStateKind) {
   if (status.state === "installed") return "Installed";
-  if (status.state === "downloading") return `Downloading ${Math.round(status.progress * 100)}%`;
+  if (status.state === "downloading") {
+    const transfer = formatTransfer(status.bytesPerSec, status.etaSecs);
+    return `Downloading ${Math.round(status.progress * 100)}%${transfer ? ` · ${transfer}` : ""}`;
+  }
   if (status.state === "error") return "Error";
   return "Not installed";
 }
 
+type WeightLoadReport = {
+  backend: string;
+  mode: "mapped-prefetch" | "direct";
+  weightFiles: number;
+  weightBytes: number;
+  elapsedMs: number;
+};
+
+// Reports how the last ASR warmup loaded its weights.
+const WeightLoadStatus = () => {
+  const [report, setReport] = useState<WeightLoadReport | null>(null);
+
+  useEffect(() => {
+    invoke<WeightLoadReport | null>("asr_load_report")
+      .then((current) => current && setReport(current))
+      .catch((err) => console.error("Failed to load ASR weight report", err));
+    const unlisten = listen<WeightLoadReport>("asr-weights-loaded", (event) =>
+      setReport(event.payload),
+    );
+    return () => {
+      void unlisten.then((dispose) => dispose());
+    };
+  }, []);
+
+  if (!report) {
+    return null;
+  }
+  return (
+    <p className="text-xs text-muted">
+      Last load: {report.mode === "mapped-prefetch" ? "mapped + prefetched" : "direct read"},{" "}
+      {formatBytes(report.weightBytes)} in {report.elapsedMs} ms
+    </p>
+  );
+};
+
+// Update / roll back controls for an installed asset with tracked upstream revisions.
+const ModelRevisionActions = ({ record }: { record: ModelRecord | undefined }) => {
+  const updateModelAsset = useAppStore((state) => state.updateModelAsset);
+  const rollbackModelAsset = useAppStore((state) => state.rollbackModelAsset);
+  if (!record || record.systemProvided || record.status.state !== "installed") {
+    return null;
+  }
+  if (!record.updateAvailable && !record.previousRevision) {
+    return null;
+  }
+
+  return (
+    <>
+      {record.updateAvailable && (
+        <Button
+          variant="primary"
+          size="sm"
+          title={`Upstream revision ${record.latestRevision?.slice(0, 12) ?? ""}`}
+          onClick={() => void updateModelAsset(record.name)}
+        >
+          Update
+        </Button>
+      )}
+      {record.previousRevision && (
+        <Button variant="ghost" size="sm" onClick={() => void rollbackModelAsset(record.name)}>
+          Roll back
+        </Button>
+      )}
+    </>
+  );
+};
+
+// Stops a running download; its partial files are removed.
+const CancelDownloadButton = ({ record }: { record: ModelRecord | undefined }) => {
+  const cancelModelDownload = useAppStore((state) => state.cancelModelDownload);
+  if (!record || record.status.state !== "downloading") {
+    return null;
+  }
+  return (
+    <Button variant="ghost" size="sm" onClick={() => void cancelModelDownload(record.name)}>
+      Cancel
+    </Button>
+  );
+};
+
+// Points an asset at a model directory that already exists on disk (a shared
+// mount or a manual download) instead of downloading it.
+const LinkExistingFolder = ({ record }: { record: ModelRecord | undefined }) => {
+  const linkModelAsset = useAppStore((state) => state.linkModelAsset);
+  const [open, setOpen] = useState(false);
+  const [path, setPath] = useState("");
+  if (!record || record.systemProvided || record.status.state === "downloading") {
+    return null;
+  }
+  if (!open) {
+    return (
+      <Button variant="ghost" size="sm" onClick={() => setOpen(true)}>
+        Use folder…
+      </Button>
+    );
+  }
+
+  const submit = async () => {
+    await linkModelAsset(record.name, path.trim());
+    setOpen(false);
+    setPath("");
+  };
+  return (
+    <div className="flex items-center gap-2">
+      <input
+        type="text"
+        className="w-64 rounded-vibe border border-border bg-surface2 px-2 py-1 text-sm"
+        placeholder="/path/to/model"
+        value={path}
+        onChange={(event) => setPath(event.target.value)}
+      />
+      <Button variant="primary" size="sm" disabled={!path.trim()} onClick={() => void submit()}>
+        Link
+      </Button>
+      <Button variant="ghost" size="sm" onClick={() => setOpen(false)}>
+        Cancel
+      </Button>
+    </div>
+  );
+};
+
+type QueuedDownload = {
+  assetName: string;
+  update: boolean;
+  priority: "low" | "normal" | "high";
+  paused: boolean;
+};
+
+// Downloads waiting behind the running one; "Now" pauses the running download.
+const DownloadQueueCard = () => {
+  const cancelModelDownload = useAppStore((state) => state.cancelModelDownload);
+  const [queue, setQueue] = useState<QueuedDownload[]>([]);
+
+  useEffect(() => {
+    invoke<QueuedDownload[]>("model_download_queue")
+      .then(setQueue)
+      .catch((err) => console.error("Failed to load download queue", err));
+    const unlisten = listen<QueuedDownload[]>("model-download-queue", (event) =>
+      setQueue(event.payload),
+    );
+    return () => {
+      void unlisten.then((dispose) => dispose());
+    };
+  }, []);
+
+  if (queue.length === 0) {
+    return null;
+  }
+
+  const moveUp = (index: number) => {
+    const order = queue.map(({ assetName, update }) => ({ assetName, update }));
+    [order[index - 1], order[index]] = [order[index], order[index - 1]];
+    invoke("reorder_download_queue", { order }).catch((err) =>
+      console.error("Failed to reorder downloads", err),
+    );
+  };
+
+  const downloadNow = (name: string) => {
+    invoke("set_download_priority", { name, priority: "high" }).catch((err) =>
+      console.error("Failed to prioritize download", err),
+    );
+  };
+
+  return (
+    <Card className="p-4">
+      <div className="text-sm font-semibold text-fg">Download queue</div>
+      <div className="mt-3 grid gap-2">
+        {queue.map((item, index) => (
+          <div key={item.assetName} className="flex items-center justify-between gap-3 text-sm">
+            <span className="truncate">
+              {item.assetName}
+              {item.paused && <span className="text-xs text-muted"> (paused)</span>}
+              {item.priority === "high" && <Badge className="ml-2">Next</Badge>}
+            </span>
+            <div className="flex gap-2">
+              <Button
+                variant="ghost"
+                size="sm"
+                disabled={index === 0}
+                onClick={() => moveUp(index)}
+              >
+                Move up
+              </Button>
+              <Button
+                variant="secondary"
+                size="sm"
+                disabled={item.priority === "high"}
+                onClick={() => downloadNow(item.assetName)}
+              >
+                Now
+              </Button>
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => void cancelModelDownload(item.assetName)}
+              >
+                Cancel
+              </Button>
+            </div>
+          </div>
+        ))}
+      </div>
+    </Card>
+  );
+};
+
+const FINDER_LANGUAGES = [
+  { value: "any", label: "Any language" },
+  { value: "en", label: "English" },
+  { value: "de", label: "German" },
+  { value: "es", label: "Spanish" },
+  { value: "fr", label: "French" },
+  { value: "ja", label: "Japanese" },
+  { value: "zh", label: "Chinese" },
+];
+
+const FINDER_RAM = [
+  { value: "any", label: "Any memory" },
+  { value: "1024", label: "Up to 1 GB RAM" },
+  { value: "2048", label: "Up to 2 GB RAM" },
+  { value: "4096", label: "Up to 4 GB RAM" },
+];
+
+const FINDER_QUALITY: Array<{ value: "any" | QualityTier; label: string }> = [
+  { value: "any", label: "Any quality" },
+  { value: "standard", label: "Standard or better" },
+  { value: "high", label: "High or better" },
+  { value: "best", label: "Best" },
+];
+
+// Narrows the catalog by language, memory and quality using its metadata.
+const ModelFinderCard = ({
+  models,
+  onInstallAsset,
+}: {
+  models: ModelRecord[];
+  onInstallAsset: (name: string) => void;
+}) => {
+  const [language, setLanguage] = useState("any");
+  const [maxRam, setMaxRam] = useState("any");
+  const [minQuality, setMinQuality] = useState<"any" | QualityTier>("any");
+  const [matches, setMatches] = useState<RawModelAsset[]>([]);
+
+  useEffect(() => {
+    const filter: ModelQuery = {
+      language: language === "any" ? undefined : language,
+      maxRamMb: maxRam === "any" ? undefined : Number(maxRam),
+      minQuality: minQuality === "any" ? undefined : minQuality,
+    };
+    invoke<RawModelAsset[]>("query_models", { filter })
+      .then((assets) => setMatches(assets.filter((asset) => asset.info?.quality)))
+      .catch((err) => console.error("Failed to query models", err));
+  }, [language, maxRam, minQuality]);
+
+  return (
+    <Card className="p-4">
+      <div className="text-sm font-semibold text-fg">Find a speech model</div>
+      <div className="mt-1 text-xs text-muted">
+        Filter the catalog by what your machine and languages need.
+      </div>
+      <div className="mt-3 flex flex-wrap gap-2">
+        <Select
+          value={language}
+          onChange={setLanguage}
+          options={FINDER_LANGUAGES}
+          size="sm"
+          width="sm"
+          ariaLabel="Language"
+        />
+        <Select
+          value={maxRam}
+          onChange={setMaxRam}
+          options={FINDER_RAM}
+          size="sm"
+          width="sm"
+          ariaLabel="Memory"
+        />
+        <Select
+          value={minQuality}
+          onChange={setMinQuality}
+          options={FINDER_QUALITY}
+          size="sm"
+          width="sm"
+          ariaLabel="Quality"
+        />
+      </div>
+      <div className="mt-3 grid max-h-64 gap-2 overflow-y-auto">
+        {matches.length === 0 && (
+          <div className="text-xs text-muted">No model matches these filters.</div>
+        )}
+        {matches.map((asset) => {
+          const record = models.find((model) => model.name === asset.name);
+          const installed = record?.status.state === "installed";
+          return (
+            <div key={asset.name} className="flex items-center justify-between gap-3 text-sm">
+              <div className="min-w-0">
+                <div className="truncate text-fg">
+                  {asset.name}
+                  {asset.info?.quality && <Badge className="ml-2">{asset.info.quality}</Badge>}
+                </div>
+                {asset.info && (
+                  <div className="text-xs text-muted">{describeModelInfo(asset.info)}</div>
+                )}
+              </div>
+              <Button
+                variant="secondary"
+                size="sm"
+                disabled={installed || record?.status.state === "downloading"}
+                onClick={() => onInstallAsset(asset.name)}
+              >
+                {installed ? "Installed" : "Install"}
+              </Button>
+            </div>
+          );
+        })}
+      </div>
+    </Card>
+  );
+};
+
 const CompactDownloadRow = ({
   title,
   subtitle,
@@ -610,17 +1623,35 @@ const CompactDownloadRow = ({
               Download size:{" "}
               <span className="font-medium text-fg">{formatBytes(record?.sizeBytes ?? 0)}</span>
             </span>
+            {record?.updateAvailable && (
+              <span className="rounded-vibe border border-info/35 bg-info/10 px-2 py-1 text-info">
+                Update available
+              </span>
+            )}
+            {record?.linkedPath && (
+              <span
+                className="truncate rounded-vibe border border-border bg-surface px-2 py-1"
+                title={record.linkedPath}
+              >
+                Linked: <span className="font-medium text-fg">{record.linkedPath}</span>
+              </span>
+            )}
           </div>
         </div>
-        <Button
-          variant={installed ? "secondary" : "primary"}
-          size="sm"
-          disabled={!available || installed || downloading}
-          title={!available ? "Unavailable in model manifest" : undefined}
-          onClick={() => onInstall(assetName)}
-        >
-          {installed ? "Installed" : downloading ? "Downloading…" : "Download"}
-        </Button>
+        <div className="flex flex-wrap items-center justify-end gap-2">
+          <ModelRevisionActions record={record} />
+          <LinkExistingFolder record={record} />
+          <CancelDownloadButton record={record} />
+          <Button
+            variant={installed ? "secondary" : "primary"}
+            size="sm"
+            disabled={!available || installed || downloading}
+            title={!available ? "Unavailable in model manifest" : undefined}
+            onClick={() => onInstall(assetName)}
+          >
+            {installed ? "Installed" : downloading ? "Downloading…" : "Download"}
+          </Button>
+        </div>
       </div>
       {downloading && (
         <div className="mt-3 h-2 w-full overflow-hidden rounded-vibe border border-border bg-surface">
@@ -656,6 +1687,7 @@ const ModelsSection = ({
   onUninstallAsset: (name: string) => void;
   onApplyImmediate: (partial: Partial<AppSettings>) => Promise<void>;
 }) => {
+  const checkModelUpdates = useAppStore((state) => state.checkModelUpdates);
   const vadModel = useMemo(
     () => models.find((model) => model.kind === "vad"),
     [models],
@@ -664,6 +1696,14 @@ const ModelsSection = ({
     () => models.find((model) => model.kind === "parakeet"),
     [models],
   );
+  const speakerModel = useMemo(
+    () => models.find((model) => model.kind === "speaker"),
+    [models],
+  );
+  const gpuRuntimeModel = useMemo(
+    () => models.find((model) => model.kind === "gpu-runtime"),
+    [models],
+  );
 
   const guidedVariant = useMemo((): WhisperVariant => {
     const size = draft.whisperModel as WhisperSize;
@@ -753,7 +1793,13 @@ const ModelsSection = ({
   const installedAsrAssets = useMemo(() => {
     return models
       .filter((m) => m.status.state === "installed")
-      .filter((m) => m.kind === "parakeet" || m.kind === "whisper-ct2" || m.kind === "whisper-onnx");
+      .filter(
+        (m) =>
+          m.kind === "parakeet" ||
+          m.kind === "whisper-ct2" ||
+          m.kind === "whisper-onnx" ||
+          m.kind === "whisper-cpp",
+      );
   }, [models]);
 
   const selectWhisperVariant = useCallback(
@@ -854,6 +1900,10 @@ const ModelsSection = ({
         </Card>
       </div>
 
+      <DownloadQueueCard />
+
+      <ModelFinderCard models={models} onInstallAsset={onInstallAsset} />
+
       <div className="grid gap-3">
         <div className="text-xs font-medium uppercase tracking-wide text-muted">Required</div>
 
@@ -870,6 +1920,20 @@ const ModelsSection = ({
           </div>
         </Card>
 
+        <SpeakerGateCard
+          draft={draft}
+          record={speakerModel}
+          onChange={onChange}
+          onInstallAsset={onInstallAsset}
+        />
+
+        <GpuAccelerationCard
+          draft={draft}
+          record={gpuRuntimeModel}
+          onChange={onChange}
+          onInstallAsset={onInstallAsset}
+        />
+
         {draft.asrFamily === "parakeet" && (
           <Card className="p-4">
             <div className="text-sm font-semibold text-fg">Parakeet availability</div>
@@ -914,10 +1978,15 @@ const ModelsSection = ({
                           ? "Downloading…"
                           : "Download"}
                   </Button>
+                  <CancelDownloadButton record={parakeetModel} />
                   <Button
                     variant="secondary"
                     size="sm"
-                    disabled={!parakeetModel || parakeetModel.status.state !== "installed"}
+                    disabled={
+                      !parakeetModel ||
+                      parakeetModel.status.state !== "installed" ||
+                      parakeetModel.systemProvided
+                    }
                     onClick={() => {
                       if (!parakeetModel) return;
                       const isActive = activeAsrAssetName === parakeetModel.name;
@@ -967,6 +2036,7 @@ const ModelsSection = ({
                 {([
                   { value: "ct2" as const, label: "CT2" },
                   { value: "onnx" as const, label: "ONNX" },
+                  { value: "cpp" as const, label: "whisper.cpp" },
                 ] as const).map((tab) => {
                   const active = draft.whisperBackend === tab.value;
                   return (
@@ -1027,9 +2097,16 @@ const ModelsSection = ({
                     });
                   }
                 } else {
+                  const backend = draft.whisperBackend as WhisperBackend;
                   for (const precision of ["int8", "float"] as const) {
+                    const precisionLabel =
+                      backend === "cpp"
+                        ? precision === "float"
+                          ? "F16"
+                          : "Q5"
+                        : precision.toUpperCase();
                     const multiVariant: WhisperVariant = {
-                      backend: "onnx",
+                      backend,
                       size: size.id,
                       language: "multi",
                       precision,
@@ -1037,7 +2114,7 @@ const ModelsSection = ({
                     const multiAsset = whisperVariantAssetName(multiVariant);
                     const multiRecord = models.find((m) => m.name === multiAsset);
                     chips.push({
-                      label: `Multi ${precision.toUpperCase()}`,
+                      label: `Multi ${precisionLabel}`,
                       variant: multiVariant,
                       assetName: multiAsset,
                       installed: multiRecord?.status.state === "installed",
@@ -1045,7 +2122,7 @@ const ModelsSection = ({
 
                     if (size.hasEnglish) {
                       const enVariant: WhisperVariant = {
-                        backend: "onnx",
+                        backend,
                         size: size.id,
                         language: "en",
                         precision,
@@ -1053,7 +2130,7 @@ const ModelsSection = ({
                       const enAsset = whisperVariantAssetName(enVariant);
                       const enRecord = models.find((m) => m.name === enAsset);
                       chips.push({
-                        label: `EN ${precision.toUpperCase()}`,
+                        label: `EN ${precisionLabel}`,
                         variant: enVariant,
                         assetName: enAsset,
                         installed: enRecord?.status.state === "installed",
@@ -1145,10 +2222,16 @@ const ModelsSection = ({
                           : "Download"}
                   </Button>
 
+                  <CancelDownloadButton record={selectedWhisperRecord} />
+
                   <Button
                     variant="secondary"
                     size="sm"
-                    disabled={!selectedWhisperRecord || selectedWhisperRecord.status.state !== "installed"}
+                    disabled={
+                      !selectedWhisperRecord ||
+                      selectedWhisperRecord.status.state !== "installed" ||
+                      selectedWhisperRecord.systemProvided
+                    }
                     onClick={() => {
                       const isActive = activeAsrAssetName === selectedWhisperAssetName;
                       if (!confirmUninstall(selectedWhisperAssetName, isActive)) return;
@@ -1194,8 +2277,17 @@ const ModelsSection = ({
         )}
 
         <Card className="p-4">
-          <div className="text-sm font-semibold text-fg">Installed models</div>
-          <div className="mt-1 text-xs text-muted">Switch engines instantly; uninstall removes files.</div>
+          <div className="flex items-start justify-between gap-3">
+            <div>
+              <div className="text-sm font-semibold text-fg">Installed models</div>
+              <div className="mt-1 text-xs text-muted">
+                Switch engines instantly; uninstall removes files.
+              </div>
+            </div>
+            <Button variant="ghost" size="sm" onClick={() => void checkModelUpdates()}>
+              Check for updates
+            </Button>
+          </div>
 
           {installedAsrAssets.length === 0 ? (
             <div className="mt-3 rounded-vibe border border-border bg-surface2 p-3 text-xs text-muted">
@@ -1220,6 +2312,7 @@ const ModelsSection = ({
                 return (
                   <div
                     key={asset.name}
+                    id={`model-${asset.name}`}
                     className="flex flex-col gap-2 rounded-vibe border border-border bg-surface2 p-3 md:flex-row md:items-center md:justify-between"
                   >
                     <div className="min-w-0">
@@ -1230,10 +2323,16 @@ const ModelsSection = ({
                             Active
                           </Badge>
                         )}
+                        {asset.updateAvailable && (
+                          <Badge tone="info" className="bg-info/10">
+                            Update available
+                          </Badge>
+                        )}
                       </div>
                       <div className="mt-0.5 text-xs text-muted">{detail}</div>
                     </div>
                     <div className="flex flex-wrap items-center justify-end gap-2">
+                      <ModelRevisionActions record={asset} />
                       <Button
                         variant={isActive ? "secondary" : "primary"}
                         size="sm"
@@ -1254,6 +2353,7 @@ const ModelsSection = ({
                       <Button
                         variant="secondary"
                         size="sm"
+                        disabled={asset.systemProvided}
                         onClick={() => {
                           if (!confirmUninstall(asset.name, isActive)) return;
                           onUninstallAsset(asset.name);
@@ -1277,13 +2377,7 @@ const ModelsSection = ({
                 width="md"
                 value={draft.language}
                 onChange={(v) => onChange("language", v)}
-                options={[
-                  { value: "auto", label: "Auto Detect" },
-                  { value: "en", label: "English" },
-                  { value: "es", label: "Spanish" },
-                  { value: "de", label: "German" },
-                  { value: "fr", label: "French" },
-                ]}
+                options={DICTATION_LANGUAGES}
               />
             </label>
             <label className="flex items-center gap-2 text-sm">
@@ -1295,18 +2389,226 @@ const ModelsSection = ({
               />
               Enable automatic language detection (when supported)
             </label>
+            {/* Code-switching needs a fixed primary language and a multilingual CT2 Whisper model. */}
+            <label className="flex items-center justify-between gap-3">
+              <span>Second dictation language</span>
+              <Select
+                width="md"
+                value={draft.secondaryLanguage}
+                onChange={(v) => onChange("secondaryLanguage", v)}
+                options={[
+                  { value: "", label: "Off" },
+                  ...DICTATION_LANGUAGES.filter(
+                    ({ value }) => value !== "auto" && value !== draft.language,
+                  ),
+                ]}
+                disabled={
+                  draft.language === "auto" ||
+                  draft.asrFamily !== "whisper" ||
+                  draft.whisperBackend !== "ct2" ||
+                  draft.whisperModelLanguage === "en"
+                }
+              />
+            </label>
+            <div className="space-y-2 rounded-vibe border border-border bg-surface2 p-3">
+              <span className="text-muted">
+                Custom vocabulary: names, jargon and acronyms to recognize (Parakeet boosts them
+                while decoding; Whisper transcripts are corrected to these spellings)
+              </span>
+              <textarea
+                rows={4}
+                placeholder="One term per line, e.g. Kubernetes"
+                value={draft.customVocabulary.join("\n")}
+                onChange={(event) => onChange("customVocabulary", event.target.value.split("\n"))}
+                className="w-full rounded-vibe border border-border bg-surface px-2 py-1 text-sm"
+              />
+            </div>
+            <PauseThresholdsEditor
+              enabled={draft.pausePunctuation}
+              language={draft.language}
+              value={draft.pauseThresholds}
+              onToggle={(enabled) => onChange("pausePunctuation", enabled)}
+              onChange={(next) => onChange("pauseThresholds", next)}
+            />
             <label className="flex items-center justify-between gap-3">
-              <span>Paste Shortcut</span>
+              <span>Notification &amp; hint language</span>
+              <Select
+                width="md"
+                value={draft.messageLocale}
+                onChange={(v) => onChange("messageLocale", v)}
+                options={[
+                  { value: "system", label: "System locale" },
+                  { value: "en-US", label: "English" },
+                  { value: "de", label: "German" },
+                ]}
+              />
+            </label>
+            <label className="flex items-center gap-2 text-sm">
+              <input
+                type="checkbox"
+                checked={draft.mmapModelWeights}
+                onChange={(event) => onChange("mmapModelWeights", event.target.checked)}
+              />
+              Prefetch model weights through the shared page cache
+            </label>
+            <label className="flex items-center gap-2 text-sm">
+              <input
+                type="checkbox"
+                checked={draft.autoRevertAsr}
+                onChange={(event) => onChange("autoRevertAsr", event.target.checked)}
+              />
+              Switch back to the last working model when the selected one fails to load
+            </label>
+            {/* Keeps the launch-time model load and downloads out of the login rush. */}
+            <label className="flex items-center justify-between gap-3 text-sm">
+              <span>Load models after launch</span>
               <Select
                 width="md"
-                value={draft.pasteShortcut}
-                onChange={(v) => onChange("pasteShortcut", v as AppSettings["pasteShortcut"])}
+                value={draft.startupWarmup}
+                onChange={(v) => onChange("startupWarmup", v as AppSettings["startupWarmup"])}
                 options={[
-                  { value: "ctrl-shift-v", label: "Ctrl+Shift+V", description: "Terminal friendly" },
-                  { value: "ctrl-v", label: "Ctrl+V" },
+                  { value: "immediate", label: "Right away" },
+                  { value: "delay", label: "After a delay" },
+                  { value: "idle", label: "Once the system is idle" },
+                  { value: "hotkey", label: "On first dictation" },
                 ]}
               />
             </label>
+            {(draft.startupWarmup === "delay" || draft.startupWarmup === "idle") && (
+              <label className="flex items-center justify-between gap-3 text-sm">
+                <span>
+                  {draft.startupWarmup === "delay" ? "Delay (seconds)" : "Idle for (seconds)"}
+                </span>
+                <input
+                  type="number"
+                  min={0}
+                  max={300}
+                  className="w-24 rounded-vibe border border-border bg-surface2 px-2 py-1 text-right text-sm"
+                  value={draft.startupWarmupSecs}
+                  onChange={(event) => {
+                    const parsed = Number(event.target.value);
+                    if (Number.isFinite(parsed)) {
+                      onChange("startupWarmupSecs", Math.max(0, Math.round(parsed)));
+                    }
+                  }}
+                />
+              </label>
+            )}
+            <WeightLoadStatus />
+            <PasteShortcutField
+              value={draft.pasteShortcut}
+              onChange={(chord) => onChange("pasteShortcut", chord)}
+            />
+            <label className="flex items-center gap-2 text-sm">
+              <input
+                type="checkbox"
+                checked={draft.pasteFallbackShiftInsert}
+                onChange={(event) => onChange("pasteFallbackShiftInsert", event.target.checked)}
+              />
+              Retry failed pastes with Shift+Insert
+            </label>
+            <label className="flex items-center gap-2 text-sm">
+              <input
+                type="checkbox"
+                checked={draft.typeOut}
+                onChange={(event) => onChange("typeOut", event.target.checked)}
+              />
+              Type transcripts out key by key instead of pasting, including into terminals
+            </label>
+            <label className="flex items-center gap-2 text-sm">
+              <input
+                type="checkbox"
+                checked={draft.typeOutTerminalPaste}
+                disabled={!draft.typeOut}
+                onChange={(event) => onChange("typeOutTerminalPaste", event.target.checked)}
+              />
+              Paste multi-line text into terminals, where each typed line would run
+            </label>
+            <label className="flex items-center justify-between gap-3 text-xs">
+              <span className="text-muted">Pause between typed characters (ms)</span>
+              <input
+                type="number"
+                min={0}
+                max={200}
+                disabled={!draft.typeOut}
+                className="w-24 rounded-vibe border border-border bg-surface2 px-2 py-1 text-right text-sm"
+                value={draft.typeOutCharDelayMs}
+                onChange={(event) => {
+                  const parsed = Number(event.target.value);
+                  if (Number.isFinite(parsed)) {
+                    onChange("typeOutCharDelayMs", Math.min(200, Math.max(0, Math.round(parsed))));
+                  }
+                }}
+              />
+            </label>
+            <AppPasteShortcutsEditor
+              value={draft.appPasteShortcuts}
+              onChange={(next) => onChange("appPasteShortcuts", next)}
+            />
+            <label className="flex items-center gap-2 text-sm">
+              <input
+                type="checkbox"
+                checked={draft.confirmBeforePaste}
+                onChange={(event) => onChange("confirmBeforePaste", event.target.checked)}
+              />
+              Review transcript before pasting
+            </label>
+            {draft.confirmBeforePaste && (
+              <label className="flex items-center justify-between gap-3">
+                <span>Alternatives to offer (CT2 Whisper)</span>
+                <Select
+                  width="md"
+                  value={String(draft.transcriptAlternatives)}
+                  onChange={(v) => onChange("transcriptAlternatives", Number(v))}
+                  options={[
+                    { value: "0", label: "None" },
+                    { value: "1", label: "1" },
+                    { value: "2", label: "2" },
+                    { value: "3", label: "3" },
+                  ]}
+                />
+              </label>
+            )}
+            <div className="space-y-2 rounded-vibe border border-border bg-surface2 p-3">
+              <span className="text-muted">Redact from logs and stored corrections</span>
+              {(
+                [
+                  ["credit-card", "Card numbers"],
+                  ["email", "Email addresses"],
+                ] as const
+              ).map(([id, label]) => (
+                <label key={id} className="flex items-center gap-2 text-sm">
+                  <input
+                    type="checkbox"
+                    checked={draft.redactionRules.includes(id)}
+                    onChange={(event) =>
+                      onChange(
+                        "redactionRules",
+                        event.target.checked
+                          ? [...draft.redactionRules, id]
+                          : draft.redactionRules.filter((rule) => rule !== id),
+                      )
+                    }
+                  />
+                  {label}
+                </label>
+              ))}
+              <textarea
+                rows={3}
+                placeholder="Custom regexes, one per line"
+                value={draft.customRedactions.join("\n")}
+                onChange={(event) => onChange("customRedactions", event.target.value.split("\n"))}
+                className="w-full rounded-vibe border border-border bg-surface px-2 py-1 font-mono text-xs"
+              />
+              <label className="flex items-center gap-2 text-sm">
+                <input
+                  type="checkbox"
+                  checked={draft.redactBeforePaste}
+                  onChange={(event) => onChange("redactBeforePaste", event.target.checked)}
+                />
+                Also replace matches with ████ in pasted text
+              </label>
+            </div>
           </div>
         </Disclosure>
       </div>
@@ -1342,13 +2644,13 @@ const GeneralSection = ({
   type HotkeyMode = AppSettings["hotkeyMode"];
   const activeMode: HotkeyMode = draft.hotkeyMode;
   const hotkeyKey: keyof Pick<AppSettings, "pushToTalkHotkey" | "toggleToTalkHotkey"> =
-    activeMode === "hold" ? "pushToTalkHotkey" : "toggleToTalkHotkey";
+    activeMode === "toggle" ? "toggleToTalkHotkey" : "pushToTalkHotkey";
   const hotkeyValue = draft[hotkeyKey];
   const presetValue = isPresetSingleKey(hotkeyValue) ? hotkeyValue : "__combo__";
   const isCombo = presetValue === "__combo__";
   const ctrlBasedHotkey = hotkeyUsesCtrl(hotkeyValue);
   const hotkeyPasteConflict = hotkeyMatchesPasteShortcut(hotkeyValue, draft.pasteShortcut);
-  const pasteShortcutLabel = draft.pasteShortcut === "ctrl-v" ? "Ctrl+V" : "Ctrl+Shift+V";
+  const pasteShortcutLabel = draft.pasteShortcut;
 
   const presetOptions = [
     ...(isCombo
@@ -1368,16 +2670,25 @@ const GeneralSection = ({
   ] as const;
 
   const activeDefault =
-    activeMode === "hold" ? DEFAULT_PUSH_TO_TALK_HOTKEY : DEFAULT_TOGGLE_TO_TALK_HOTKEY;
-
-  const audioValue = (draft.audioDeviceId ?? "__default__") as "__default__" | string;
-  const audioOptions = [
-    { value: "__default__" as const, label: "System Default" },
-    ...audioDevices.map((d) => ({
-      value: d.id,
-      label: d.name + (d.isDefault ? " (Default)" : ""),
-    })),
+    activeMode === "toggle" ? DEFAULT_TOGGLE_TO_TALK_HOTKEY : DEFAULT_PUSH_TO_TALK_HOTKEY;
+
+  // Devices are tried in list order at session start; the system default comes last.
+  const devicePreferences = draft.audioDevicePreferences;
+  const addDeviceOptions = [
+    { value: "__add__", label: "Add device…", disabled: true },
+    ...audioDevices
+      .filter((d) => !devicePreferences.includes(d.id))
+      .map((d) => ({
+        value: d.id,
+        label: d.name + (d.isDefault ? " (Default)" : ""),
+      })),
   ];
+  const moveDevice = (index: number, offset: number) => {
+    const next = [...devicePreferences];
+    const [device] = next.splice(index, 1);
+    next.splice(index + offset, 0, device);
+    onChange("audioDevicePreferences", next);
+  };
 
   return (
     <div className="grid gap-5">
@@ -1398,15 +2709,68 @@ const GeneralSection = ({
           </Button>
         </div>
 
-        <label className="flex items-center justify-between gap-3">
-          <span>Input Device</span>
-          <Select
-            width="md"
-            value={audioValue}
-            onChange={(v) => onChange("audioDeviceId", v === "__default__" ? null : v)}
-            options={audioOptions}
-          />
-        </label>
+        <div className="grid gap-2">
+          <div className="flex items-center justify-between gap-3">
+            <span>Input Devices</span>
+            <Select
+              width="md"
+              value="__add__"
+              onChange={(v) => onChange("audioDevicePreferences", [...devicePreferences, v])}
+              options={addDeviceOptions}
+              ariaLabel="Add input device"
+            />
+          </div>
+          {devicePreferences.length === 0 ? (
+            <div className="text-xs text-muted">Using the system default device.</div>
+          ) : (
+            <ol className="grid gap-1">
+              {devicePreferences.map((device, index) => {
+                const present = audioDevices.some((d) => d.id === device);
+                return (
+                  <li key={device} className="flex items-center justify-between gap-2 text-sm">
+                    <span className={present ? "text-fg" : "text-muted"}>
+                      {index + 1}. {device}
+                      {present ? "" : " (not connected)"}
+                    </span>
+                    <span className="flex gap-1">
+                      <Button
+                        variant="ghost"
+                        size="sm"
+                        disabled={index === 0}
+                        onClick={() => moveDevice(index, -1)}
+                      >
+                        Up
+                      </Button>
+                      <Button
+                        variant="ghost"
+                        size="sm"
+                        disabled={index === devicePreferences.length - 1}
+                        onClick={() => moveDevice(index, 1)}
+                      >
+                        Down
+                      </Button>
+                      <Button
+                        variant="ghost"
+                        size="sm"
+                        onClick={() =>
+                          onChange(
+                            "audioDevicePreferences",
+                            devicePreferences.filter((d) => d !== device),
+                          )
+                        }
+                      >
+                        Remove
+                      </Button>
+                    </span>
+                  </li>
+                );
+              })}
+            </ol>
+          )}
+          <div className="text-xs text-muted">
+            The first connected device is used; the system default is the last fallback.
+          </div>
+        </div>
 
         <label className="flex items-center justify-between gap-3">
           <span>VAD Sensitivity</span>
@@ -1421,6 +2785,50 @@ const GeneralSection = ({
             ]}
           />
         </label>
+        <label className="flex items-center gap-2 text-sm">
+          <input
+            type="checkbox"
+            checked={draft.alwaysTranscribe}
+            onChange={(event) => onChange("alwaysTranscribe", event.target.checked)}
+          />
+          Always transcribe, even when no speech is detected
+        </label>
+
+        <label className="flex items-center justify-between gap-3">
+          <span>Longest Dictation</span>
+          <Select
+            width="md"
+            value={String(draft.maxDictationSecs)}
+            onChange={(v) => onChange("maxDictationSecs", Number(v))}
+            options={[
+              { value: "60", label: "1 minute" },
+              { value: "120", label: "2 minutes" },
+              { value: "300", label: "5 minutes" },
+              { value: "600", label: "10 minutes" },
+            ]}
+          />
+        </label>
+        <div className="-mt-2 text-xs text-muted">
+          Beyond this the start of the dictation is dropped and the HUD shows a warning.
+        </div>
+
+        <label className="flex items-center gap-2 text-sm">
+          <input
+            type="checkbox"
+            checked={draft.onDemandCapture}
+            onChange={(event) => onChange("onDemandCapture", event.target.checked)}
+          />
+          Open microphone only while dictating
+        </label>
+
+        <label className="flex items-center gap-2 text-sm">
+          <input
+            type="checkbox"
+            checked={draft.bluetoothAutoProfile}
+            onChange={(event) => onChange("bluetoothAutoProfile", event.target.checked)}
+          />
+          Switch the preferred Bluetooth headset to headset mode while dictating
+        </label>
       </div>
 
       <div className="grid gap-3">
@@ -1437,16 +2845,111 @@ const GeneralSection = ({
             options={[
               { value: "off", label: "Off" },
               { value: "fast", label: "Fast (Tier-1)" },
+              { value: "polish", label: "Polish (local LLM)" },
+            ]}
+          />
+        </label>
+        {draft.autocleanMode === "polish" && (
+          <div className="grid gap-2 rounded-vibe border border-border bg-surface2 p-3 text-sm">
+            <div className="text-xs text-muted">
+              Sends each transcript to an Ollama or llama.cpp server to fix grammar and
+              punctuation. If it doesn&apos;t answer in time, the Fast result is pasted.
+            </div>
+            <label className="flex items-center justify-between gap-3">
+              <span>Server</span>
+              <Select
+                width="md"
+                value={draft.polishApi}
+                onChange={(v) => onChange("polishApi", v as AppSettings["polishApi"])}
+                options={[
+                  { value: "ollama", label: "Ollama" },
+                  { value: "openai", label: "llama.cpp (OpenAI API)" },
+                ]}
+                ariaLabel="Polish server type"
+              />
+            </label>
+            <label className="flex items-center justify-between gap-3">
+              <span>Endpoint</span>
+              <input
+                type="text"
+                className="w-56 rounded-vibe border border-border bg-surface2 px-2 py-1 text-sm"
+                value={draft.polishEndpoint}
+                onChange={(event) => onChange("polishEndpoint", event.target.value)}
+              />
+            </label>
+            <label className="flex items-center justify-between gap-3">
+              <span>Model</span>
+              <input
+                type="text"
+                className="w-56 rounded-vibe border border-border bg-surface2 px-2 py-1 text-sm"
+                value={draft.polishModel}
+                onChange={(event) => onChange("polishModel", event.target.value)}
+              />
+            </label>
+            <label className="flex items-center justify-between gap-3">
+              <span>Timeout (ms)</span>
+              <input
+                type="number"
+                min={500}
+                max={30000}
+                step={500}
+                className="w-24 rounded-vibe border border-border bg-surface2 px-2 py-1 text-right text-sm"
+                value={draft.polishTimeoutMs}
+                onChange={(event) => {
+                  const parsed = Number(event.target.value);
+                  if (Number.isFinite(parsed)) {
+                    onChange("polishTimeoutMs", Math.round(parsed));
+                  }
+                }}
+              />
+            </label>
+            <label className="flex items-center gap-2 text-sm">
+              <input
+                type="checkbox"
+                checked={draft.polishAllowRemote}
+                disabled={draft.strictPrivacy}
+                onChange={(event) => onChange("polishAllowRemote", event.target.checked)}
+              />
+              Allow a server on another machine (transcripts and the stored API key are sent to it)
+            </label>
+            <label className="grid gap-1">
+              <span>Prompt</span>
+              <textarea
+                rows={3}
+                className="rounded-vibe border border-border bg-surface2 px-2 py-1 text-xs"
+                placeholder="Built-in prompt. Use {text} where the transcript goes."
+                value={draft.polishPrompt}
+                onChange={(event) => onChange("polishPrompt", event.target.value)}
+              />
+            </label>
+          </div>
+        )}
+        <label className="flex items-center justify-between gap-3">
+          <span>Number &amp; Quote Style</span>
+          <Select
+            width="md"
+            value={draft.formattingLocale}
+            onChange={(v) => onChange("formattingLocale", v)}
+            options={[
+              { value: "off", label: "Unchanged" },
+              { value: "auto", label: "System locale" },
+              { value: "en-US", label: "English (US)" },
+              { value: "en-GB", label: "English (UK)" },
+              { value: "de-DE", label: "German" },
+              { value: "fr-FR", label: "French" },
+              { value: "es-ES", label: "Spanish" },
+              { value: "ja-JP", label: "Japanese" },
             ]}
           />
         </label>
+        <LearnedCorrections />
       </div>
 
       <div className="grid gap-3">
         <div>
           <div className="text-sm font-semibold text-fg">Talk Mode + Hotkey</div>
           <div className="mt-0.5 text-xs text-muted">
-            Push-to-Talk (hold) and Toggle-to-Talk are mutually exclusive.
+            Push-to-Talk (hold), Toggle-to-Talk and Hybrid are mutually exclusive.
           </div>
         </div>
 
@@ -1459,17 +2962,45 @@ const GeneralSection = ({
             options={[
               { value: "hold", label: "Push-to-Talk (Hold)" },
               { value: "toggle", label: "Toggle-to-Talk" },
+              { value: "hybrid", label: "Hybrid (tap or hold)" },
             ]}
           />
         </label>
+        {activeMode === "hybrid" && (
+          <label className="flex items-center justify-between gap-3">
+            <span>Hold longer than (ms) for push-to-talk</span>
+            <input
+              type="number"
+              min={100}
+              max={2000}
+              step={50}
+              className="w-24 rounded-vibe border border-border bg-surface2 px-2 py-1 text-right text-sm"
+              value={draft.hybridHoldThresholdMs}
+              onChange={(event) => {
+                const parsed = Number(event.target.value);
+                if (Number.isFinite(parsed)) {
+                  onChange("hybridHoldThresholdMs", Math.min(2000, Math.max(100, parsed)));
+                }
+              }}
+            />
+          </label>
+        )}
 
         <div className="flex items-start justify-between gap-3">
           <div className="flex flex-col">
-            <span>{activeMode === "hold" ? "Push-to-Talk Hotkey" : "Toggle-to-Talk Hotkey"}</span>
+            <span>
+              {activeMode === "toggle"
+                ? "Toggle-to-Talk Hotkey"
+                : activeMode === "hybrid"
+                  ? "Hybrid Hotkey"
+                  : "Push-to-Talk Hotkey"}
+            </span>
             <span className="mt-0.5 text-xs text-muted">
-              {activeMode === "hold"
-                ? "Hold to record."
-                : "Press once to start, again to stop."}
+              {activeMode === "toggle"
+                ? "Press once to start, again to stop."
+                : activeMode === "hybrid"
+                  ? "Tap to start and tap again to stop, or hold to record until release."
+                  : "Hold to record."}
             </span>
             {isCombo && (
               <span className="mt-1 text-xs text-muted">
@@ -1501,23 +3032,276 @@ const GeneralSection = ({
               options={presetOptions as unknown as Array<{ value: string; label: string; description?: string; disabled?: boolean }>}
               ariaLabel="Hotkey preset"
             />
-            <div className="w-full">
-              <div className="mb-1 text-xs font-medium uppercase tracking-wide text-muted">
-                Record
-              </div>
-              <HotkeyInput value={hotkeyValue} onChange={(hk) => onChange(hotkeyKey, hk)} />
+            <div className="w-full">
+              <div className="mb-1 text-xs font-medium uppercase tracking-wide text-muted">
+                Record
+              </div>
+              <HotkeyInput value={hotkeyValue} onChange={(hk) => onChange(hotkeyKey, hk)} />
+            </div>
+            {hotkeyValue !== activeDefault && (
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => onChange(hotkeyKey, activeDefault)}
+                title="Reset to default"
+              >
+                Reset
+              </Button>
+            )}
+          </div>
+        </div>
+        <div className="grid gap-2 rounded-vibe border border-border bg-surface2 p-3 text-sm">
+          <div>
+            <div className="text-fg">Re-copy last transcript</div>
+            <div className="mt-0.5 text-xs text-muted">
+              Puts the last pasted transcript back on the clipboard, for when it landed in the wrong window.
+            </div>
+          </div>
+          <div className="flex items-center gap-2">
+            <HotkeyInput
+              value={draft.recopyTranscriptHotkey}
+              onChange={(hk) => onChange("recopyTranscriptHotkey", hk)}
+              placeholder="Not set"
+            />
+            {draft.recopyTranscriptHotkey && (
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => onChange("recopyTranscriptHotkey", "")}
+                title="Disable"
+              >
+                Clear
+              </Button>
+            )}
+          </div>
+        </div>
+        <div className="grid gap-2 rounded-vibe border border-border bg-surface2 p-3 text-sm">
+          <div>
+            <div className="text-fg">Cycle dictation language</div>
+            <div className="mt-0.5 text-xs text-muted">
+              Steps through the checked languages in order. Pin a model to switch to it along with
+              the language.
+            </div>
+          </div>
+          <div className="flex items-center gap-2">
+            <HotkeyInput
+              value={draft.languageCycleHotkey}
+              onChange={(hk) => onChange("languageCycleHotkey", hk)}
+              placeholder="Not set"
+            />
+            {draft.languageCycleHotkey && (
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => onChange("languageCycleHotkey", "")}
+                title="Disable"
+              >
+                Clear
+              </Button>
+            )}
+          </div>
+          {draft.languageCycleHotkey &&
+            DICTATION_LANGUAGES.map(({ value, label }) => {
+              const inCycle = draft.languageCycle.includes(value);
+              const pinned = draft.languageModels[value];
+              return (
+                <div key={value} className="flex items-center justify-between gap-3">
+                  <label className="flex items-center gap-2">
+                    <input
+                      type="checkbox"
+                      checked={inCycle}
+                      onChange={(event) =>
+                        onChange(
+                          "languageCycle",
+                          event.target.checked
+                            ? [...draft.languageCycle, value]
+                            : draft.languageCycle.filter((language) => language !== value),
+                        )
+                      }
+                    />
+                    {label}
+                    {pinned && (
+                      <span className="text-xs text-muted">
+                        {pinned.asrFamily === "whisper"
+                          ? `Whisper ${pinned.whisperModel}`
+                          : "Parakeet"}
+                      </span>
+                    )}
+                  </label>
+                  {inCycle && (
+                    <Button
+                      variant="ghost"
+                      size="sm"
+                      onClick={() => {
+                        const next = { ...draft.languageModels };
+                        if (pinned) {
+                          delete next[value];
+                        } else {
+                          next[value] = {
+                            asrFamily: draft.asrFamily,
+                            whisperBackend: draft.whisperBackend,
+                            whisperModel: draft.whisperModel,
+                            whisperModelLanguage: draft.whisperModelLanguage,
+                            whisperPrecision: draft.whisperPrecision,
+                          };
+                        }
+                        onChange("languageModels", next);
+                      }}
+                      title={pinned ? "Keep the current model for this language" : undefined}
+                    >
+                      {pinned ? "Unpin model" : "Pin current model"}
+                    </Button>
+                  )}
+                </div>
+              );
+            })}
+        </div>
+        <div className="grid gap-2 rounded-vibe border border-border bg-surface2 p-3 text-sm">
+          <div>
+            <div className="text-fg">Continuous dictation</div>
+            <div className="mt-0.5 text-xs text-muted">
+              Press this key (or use the tray menu) to keep listening; each utterance is pasted
+              once you pause. Press it again to stop.
+            </div>
+          </div>
+          <div className="flex items-center gap-2">
+            <HotkeyInput
+              value={draft.continuousHotkey}
+              onChange={(hk) => onChange("continuousHotkey", hk)}
+              placeholder="Not set"
+            />
+            {draft.continuousHotkey && (
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => onChange("continuousHotkey", "")}
+                title="Disable"
+              >
+                Clear
+              </Button>
+            )}
+          </div>
+          <label className="flex items-center justify-between gap-3">
+            <span>Pause that ends an utterance</span>
+            <Select
+              width="md"
+              value={String(draft.continuousEndSilenceMs)}
+              onChange={(v) => onChange("continuousEndSilenceMs", Number(v))}
+              options={[
+                { value: "600", label: "0.6 seconds" },
+                { value: "900", label: "0.9 seconds" },
+                { value: "1500", label: "1.5 seconds" },
+                { value: "2500", label: "2.5 seconds" },
+              ]}
+              ariaLabel="Continuous dictation pause"
+            />
+          </label>
+        </div>
+        <div className="grid gap-2 rounded-vibe border border-border bg-surface2 p-3 text-sm">
+          <div>
+            <div className="text-fg">Voice commands</div>
+            <div className="mt-0.5 text-xs text-muted">
+              Hold this key and say a phrase below to run it instead of dictating. Unmatched
+              phrases are never pasted.
             </div>
-            {hotkeyValue !== activeDefault && (
+          </div>
+          <div className="flex items-center gap-2">
+            <HotkeyInput
+              value={draft.commandHotkey}
+              onChange={(hk) => onChange("commandHotkey", hk)}
+              placeholder="Not set"
+            />
+            {draft.commandHotkey && (
               <Button
                 variant="ghost"
                 size="sm"
-                onClick={() => onChange(hotkeyKey, activeDefault)}
-                title="Reset to default"
+                onClick={() => onChange("commandHotkey", "")}
+                title="Disable"
               >
-                Reset
+                Clear
               </Button>
             )}
           </div>
+          {draft.commandHotkey && (
+            <>
+              {draft.voiceCommands.map((command, index) => {
+                const update = (patch: Partial<VoiceCommand>) =>
+                  onChange(
+                    "voiceCommands",
+                    draft.voiceCommands.map((existing, i) =>
+                      i === index ? { ...existing, ...patch } : existing,
+                    ),
+                  );
+                return (
+                  <div key={index} className="flex items-center gap-2">
+                    <input
+                      type="text"
+                      className="w-40 rounded-vibe border border-border bg-surface2 px-2 py-1 text-sm"
+                      placeholder="Phrase"
+                      value={command.phrase}
+                      onChange={(event) => update({ phrase: event.target.value })}
+                    />
+                    <Select
+                      width="sm"
+                      value={command.action}
+                      onChange={(v) => update({ action: v as VoiceCommand["action"] })}
+                      options={[
+                        { value: "keys", label: "Press keys" },
+                        { value: "text", label: "Type text" },
+                        { value: "delete-last-sentence", label: "Delete last sentence" },
+                        { value: "delete-last-utterance", label: "Delete last dictation" },
+                        { value: "select-last-sentence", label: "Select last sentence" },
+                        { value: "replace", label: "Replace X with Y" },
+                        { value: "launch", label: "Run program" },
+                      ]}
+                      ariaLabel="Command action"
+                    />
+                    {!EDITING_COMMAND_ACTIONS.includes(command.action) && (
+                      <input
+                        type="text"
+                        className="w-40 rounded-vibe border border-border bg-surface2 px-2 py-1 text-sm"
+                        placeholder={
+                          command.action === "keys"
+                            ? "Ctrl+Z"
+                            : command.action === "launch"
+                              ? "x-terminal-emulator"
+                              : "Text"
+                        }
+                        value={command.argument}
+                        onChange={(event) => update({ argument: event.target.value })}
+                      />
+                    )}
+                    <Button
+                      variant="ghost"
+                      size="sm"
+                      onClick={() =>
+                        onChange(
+                          "voiceCommands",
+                          draft.voiceCommands.filter((_, i) => i !== index),
+                        )
+                      }
+                    >
+                      Remove
+                    </Button>
+                  </div>
+                );
+              })}
+              <div>
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  onClick={() =>
+                    onChange("voiceCommands", [
+                      ...draft.voiceCommands,
+                      { phrase: "", action: "keys", argument: "" },
+                    ])
+                  }
+                >
+                  Add command
+                </Button>
+              </div>
+            </>
+          )}
         </div>
       </div>
 
@@ -1540,6 +3324,25 @@ const GeneralSection = ({
             ]}
           />
         </label>
+        <label className="flex items-center justify-between gap-3">
+          <span>HUD accent</span>
+          <span className="flex items-center gap-2">
+            <input
+              type="color"
+              aria-label="HUD accent color"
+              value={draft.hudAccentColor ?? "#14aaff"}
+              onChange={(event) => onChange("hudAccentColor", event.target.value)}
+            />
+            <Button
+              variant="ghost"
+              size="sm"
+              disabled={draft.hudAccentColor === null}
+              onClick={() => onChange("hudAccentColor", null)}
+            >
+              Default
+            </Button>
+          </span>
+        </label>
       </div>
 
       <div className="grid gap-3">
@@ -1557,6 +3360,91 @@ const GeneralSection = ({
             onChange={(event) => onChange("showHudOverlay", event.target.checked)}
           />
         </label>
+        {draft.showHudOverlay && !gnomeHudExtensionStatus?.isGnomeWayland && (
+          <label className="flex items-center justify-between gap-3 rounded-vibe border border-border bg-surface2 p-3 text-sm">
+            <span className="text-muted">Show Cancel and Done buttons on the overlay</span>
+            <input
+              type="checkbox"
+              checked={draft.interactiveOverlay}
+              onChange={(event) => onChange("interactiveOverlay", event.target.checked)}
+            />
+          </label>
+        )}
+        {draft.showHudOverlay && !gnomeHudExtensionStatus?.isGnomeWayland && (
+          <label className="flex items-center justify-between gap-3 rounded-vibe border border-border bg-surface2 p-3 text-sm">
+            <span className="text-muted">
+              Hide the overlay over fullscreen apps and games (enable sound cues for feedback)
+            </span>
+            <input
+              type="checkbox"
+              checked={draft.suppressOverlayInFullscreen}
+              onChange={(event) => onChange("suppressOverlayInFullscreen", event.target.checked)}
+            />
+          </label>
+        )}
+        <label className="flex items-center justify-between gap-3 rounded-vibe border border-border bg-surface2 p-3 text-sm">
+          <span className="text-muted">
+            Show the pasted text after each dictation
+            {draft.interactiveOverlay ? " (click it to copy again)" : ""}
+          </span>
+          <input
+            type="checkbox"
+            checked={draft.transcriptToast}
+            onChange={(event) => onChange("transcriptToast", event.target.checked)}
+          />
+        </label>
+        <label className="flex items-center justify-between gap-3 rounded-vibe border border-border bg-surface2 p-3 text-sm">
+          <span className="text-muted">
+            Keep a searchable history of dictations on this device
+            {draft.strictPrivacy ? " (off in strict privacy)" : ""}
+          </span>
+          <input
+            type="checkbox"
+            checked={draft.historyEnabled && !draft.strictPrivacy}
+            disabled={draft.strictPrivacy}
+            onChange={(event) => onChange("historyEnabled", event.target.checked)}
+          />
+        </label>
+        <label className="flex items-center justify-between gap-3 rounded-vibe border border-border bg-surface2 p-3 text-sm">
+          <span className="text-muted">
+            Strict privacy: never keep transcripts or audio after a dictation (turns off debug
+            transcripts, trace export, history and transcript log lines)
+          </span>
+          <input
+            type="checkbox"
+            checked={draft.strictPrivacy}
+            onChange={(event) => onChange("strictPrivacy", event.target.checked)}
+          />
+        </label>
+        {draft.showHudOverlay && !gnomeHudExtensionStatus?.isGnomeWayland && (
+          <div className="grid gap-2 rounded-vibe border border-border bg-surface2 p-3 text-sm">
+            {(
+              [
+                ["overlayShowAnimationMs", "Entrance animation (ms)", 0, 2000],
+                ["overlayHideDelayMs", "Exit animation before hiding (ms)", 0, 2000],
+                ["hudToastMs", "Keep toasts visible (ms)", 500, 10000],
+              ] as const
+            ).map(([key, label, min, max]) => (
+              <label key={key} className="flex items-center justify-between gap-3">
+                <span className="text-muted">{label}</span>
+                <input
+                  type="number"
+                  min={min}
+                  max={max}
+                  step={20}
+                  className="w-24 rounded-vibe border border-border bg-surface px-2 py-1 text-right text-sm"
+                  value={draft[key]}
+                  onChange={(event) => {
+                    const parsed = Number(event.target.value);
+                    if (Number.isFinite(parsed)) {
+                      onChange(key, Math.min(max, Math.max(min, parsed)));
+                    }
+                  }}
+                />
+              </label>
+            ))}
+          </div>
+        )}
         {waylandSession && (
           <p className="text-xs text-muted">
             HUD may not work on Wayland or some tiling window managers.
@@ -1642,10 +3530,232 @@ const GeneralSection = ({
           </div>
         )}
       </div>
+
+      <div className="grid gap-3">
+        <div>
+          <div className="text-sm font-semibold text-fg">Sound Cues</div>
+          <div className="mt-0.5 text-xs text-muted">
+            Short sounds for dictation events, useful when the HUD is hidden.
+          </div>
+        </div>
+        <label className="flex items-center justify-between gap-3 rounded-vibe border border-border bg-surface2 p-3 text-sm">
+          <span className="text-muted">Play sound cues</span>
+          <input
+            type="checkbox"
+            checked={draft.soundCuesEnabled}
+            onChange={(event) => onChange("soundCuesEnabled", event.target.checked)}
+          />
+        </label>
+        {draft.soundCuesEnabled && (
+          <>
+            <label className="flex items-center justify-between gap-3">
+              <span>Theme</span>
+              <Select
+                width="md"
+                value={draft.soundCueTheme}
+                onChange={(v) => onChange("soundCueTheme", v as AppSettings["soundCueTheme"])}
+                options={[
+                  { value: "soft", label: "Soft" },
+                  { value: "click", label: "Click" },
+                  { value: "chime", label: "Chime" },
+                ]}
+              />
+            </label>
+            <label className="flex items-center gap-2 text-sm">
+              <input
+                type="checkbox"
+                checked={draft.soundCueStart}
+                onChange={(event) => onChange("soundCueStart", event.target.checked)}
+              />
+              Start listening
+            </label>
+            <label className="flex items-center gap-2 text-sm">
+              <input
+                type="checkbox"
+                checked={draft.soundCueStop}
+                onChange={(event) => onChange("soundCueStop", event.target.checked)}
+              />
+              Stop listening
+            </label>
+            <label className="flex items-center gap-2 text-sm">
+              <input
+                type="checkbox"
+                checked={draft.soundCuePaste}
+                onChange={(event) => onChange("soundCuePaste", event.target.checked)}
+              />
+              Paste succeeded
+            </label>
+            <label className="flex items-center gap-2 text-sm">
+              <input
+                type="checkbox"
+                checked={draft.soundCueError}
+                onChange={(event) => onChange("soundCueError", event.target.checked)}
+              />
+              Errors
+            </label>
+          </>
+        )}
+      </div>
+
+      <div className="grid gap-3">
+        <div>
+          <div className="text-sm font-semibold text-fg">Performance Mode</div>
+          <div className="mt-0.5 text-xs text-muted">
+            When transcription falls behind, OpenFlow trims VAD hangover and can suggest a lighter
+            model.
+          </div>
+        </div>
+        <Disclosure title="Thresholds" description="When performance mode turns on and off.">
+          <PerformancePolicyEditor
+            value={draft.performancePolicy}
+            onChange={(next) => onChange("performancePolicy", next)}
+          />
+        </Disclosure>
+        <label className="flex items-center justify-between gap-3">
+          <span>Switch to a lighter model</span>
+          <Select
+            width="md"
+            value={draft.modelDownshift}
+            onChange={(v) => onChange("modelDownshift", v as AppSettings["modelDownshift"])}
+            options={[
+              { value: "off", label: "Never" },
+              { value: "offer", label: "Ask first" },
+              { value: "auto", label: "Automatically" },
+            ]}
+          />
+        </label>
+        {draft.modelDownshift !== "off" && (
+          <label className="flex items-center justify-between gap-3">
+            <span>After sessions in performance mode</span>
+            <input
+              type="number"
+              min={1}
+              className="w-24 rounded-vibe border border-border bg-surface2 px-2 py-1 text-right text-sm"
+              value={draft.performancePolicy.downshiftAfterSessions}
+              onChange={(event) => {
+                const parsed = Number(event.target.value);
+                if (Number.isFinite(parsed)) {
+                  onChange("performancePolicy", {
+                    ...draft.performancePolicy,
+                    downshiftAfterSessions: Math.max(1, parsed),
+                  });
+                }
+              }}
+            />
+          </label>
+        )}
+      </div>
     </div>
   );
 };
 
+const SECRET_FIELDS: Array<{ id: string; label: string; hint: string }> = [
+  {
+    id: "hf-token",
+    label: "Hugging Face token",
+    hint: "Sent with model downloads from huggingface.co; needed for gated or private repos.",
+  },
+];
+
+const SecretsSection = ({
+  storedSecrets,
+  onStored,
+}: {
+  storedSecrets: string[];
+  onStored: (settings: AppSettings) => void;
+}) => {
+  const [available, setAvailable] = useState<boolean | null>(null);
+  const [values, setValues] = useState<Record<string, string>>({});
+  const [busy, setBusy] = useState<string | null>(null);
+  const [message, setMessage] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<boolean>("secret_store_available")
+      .then(setAvailable)
+      .catch(() => setAvailable(false));
+  }, []);
+
+  const submit = async (id: string, clear: boolean) => {
+    setBusy(id);
+    setMessage(null);
+    try {
+      const next = clear
+        ? await invoke<AppSettings>("clear_secret", { id })
+        : await invoke<AppSettings>("set_secret", { id, value: values[id] ?? "" });
+      onStored(next);
+      setValues((prev) => ({ ...prev, [id]: "" }));
+    } catch (error) {
+      setMessage(String(error));
+    } finally {
+      setBusy(null);
+    }
+  };
+
+  return (
+    <section>
+      <h3 className="text-lg font-medium text-fg">Secrets</h3>
+      <Card className="mt-3 space-y-3 p-4">
+        {available === false && (
+          <div className="rounded-vibe border border-warn/30 bg-warn/10 p-3 text-xs text-fg">
+            secret-tool was not found. Install libsecret-tools (and a keyring such as GNOME
+            Keyring or KWallet) to store secrets.
+          </div>
+        )}
+        {message && (
+          <div className="rounded-vibe border border-bad/30 bg-bad/10 p-3 text-xs text-fg">
+            {message}
+          </div>
+        )}
+        {SECRET_FIELDS.map((field) => {
+          const stored = storedSecrets.includes(field.id);
+          return (
+            <div key={field.id} className="space-y-1">
+              <div className="flex items-center justify-between gap-3 text-sm">
+                <span className="text-fg">{field.label}</span>
+                <Badge tone={stored ? "good" : "neutral"}>
+                  {stored ? "In keyring" : "Not set"}
+                </Badge>
+              </div>
+              <p className="text-xs text-muted">{field.hint}</p>
+              <div className="flex items-center gap-2">
+                <input
+                  type="password"
+                  autoComplete="off"
+                  placeholder={stored ? "Replace stored value" : "Paste value"}
+                  value={values[field.id] ?? ""}
+                  onChange={(event) =>
+                    setValues((prev) => ({ ...prev, [field.id]: event.target.value }))
+                  }
+                  className="flex-1 rounded-vibe border border-border bg-surface2 px-2 py-1 text-sm"
+                  disabled={available === false || busy !== null}
+                />
+                <Button
+                  variant="secondary"
+                  size="sm"
+                  onClick={() => void submit(field.id, false)}
+                  disabled={available === false || busy !== null || !(values[field.id] ?? "").trim()}
+                >
+                  Save
+                </Button>
+                {stored && (
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    onClick={() => void submit(field.id, true)}
+                    disabled={busy !== null}
+                  >
+                    Clear
+                  </Button>
+                )}
+              </div>
+            </div>
+          );
+        })}
+      </Card>
+    </section>
+  );
+};
+
 const LinuxSetupSection = ({
   status,
   busy,
@@ -1659,17 +3769,29 @@ const LinuxSetupSection = ({
   onEnable: () => Promise<void>;
   onRefresh: () => Promise<void>;
 }) => {
+  const [setupScript, setSetupScript] = useState<string | null>(null);
+  const [setupScriptError, setSetupScriptError] = useState<string | null>(null);
+
   if (!status?.supported) {
     return null;
   }
 
+  const loadSetupScript = async () => {
+    setSetupScriptError(null);
+    try {
+      setSetupScript(await invoke<string>("generate_setup_script"));
+    } catch (error) {
+      setSetupScriptError(String(error));
+    }
+  };
+
   const hotkeysReady = status.waylandSession
     ? status.evdevReadable
     : status.x11Session
       ? status.x11HotkeysAvailable
       : false;
   const injectionReady = status.waylandSession
-    ? status.uinputWritable
+    ? status.uinputWritable || status.virtualKeyboardInjection
     : status.x11Session
       ? status.x11XtestAvailable
       : false;
@@ -1752,6 +3874,23 @@ const LinuxSetupSection = ({
               {clipboardToolsReady ? "ready" : "missing"}
             </span>
           </div>
+          {status.compositor && (
+            <div className="flex items-center justify-between">
+              <span className="text-muted">Compositor</span>
+              <span
+                className="text-fg"
+                title={[
+                  `layer-shell: ${status.compositor.layerShell ? "yes" : "no"}`,
+                  `data-control: ${status.compositor.dataControl ? "yes" : "no"}`,
+                  `virtual keyboard: ${status.compositor.virtualKeyboard ? "yes" : "no"}`,
+                  `global shortcuts portal: ${status.compositor.globalShortcutsPortal ? "yes" : "no"}`,
+                ].join("\n")}
+              >
+                {status.compositor.family} · overlay {status.compositor.overlay} · paste{" "}
+                {status.compositor.injection} · hotkeys {status.compositor.hotkeys}
+              </span>
+            </div>
+          )}
           <div className="flex items-center justify-between">
             <span className="text-muted">One-click setup (polkit + acl)</span>
             <span
@@ -1846,6 +3985,54 @@ const LinuxSetupSection = ({
           </p>
         )}
 
+        {status.waylandSession && (
+          <div className="grid gap-2 rounded-vibe border border-border bg-surface2 p-3 text-xs">
+            <div className="flex items-center justify-between gap-3">
+              <div>
+                <div className="font-semibold text-fg">Manual setup (no polkit)</div>
+                <div className="mt-0.5 text-muted">
+                  Review the script, then run it with sudo. Refresh to check each step.
+                </div>
+              </div>
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => {
+                  if (setupScript) {
+                    void navigator.clipboard.writeText(setupScript);
+                  } else {
+                    void loadSetupScript();
+                  }
+                }}
+              >
+                {setupScript ? "Copy script" : "Show script"}
+              </Button>
+            </div>
+            {setupScriptError && <p className="text-warn">{setupScriptError}</p>}
+            {setupScript && (
+              <pre className="max-h-64 overflow-auto rounded-vibe border border-border bg-bg p-2 font-mono text-[11px] text-fg">
+                {setupScript}
+              </pre>
+            )}
+            {status.setupSteps.map((step) => (
+              <div key={step.id} className="flex justify-between gap-3">
+                <span className="text-muted">
+                  {step.id === "input-group"
+                    ? "User in 'input' group"
+                    : step.id === "uinput-rule"
+                      ? "udev rule for /dev/uinput"
+                      : step.id === "input-access"
+                        ? "Keyboard access (/dev/input)"
+                        : "Paste access (/dev/uinput)"}
+                </span>
+                <span className={step.done ? "text-good" : "text-warn"}>
+                  {step.done ? "done" : "pending"}
+                </span>
+              </div>
+            ))}
+          </div>
+        )}
+
         {!pasteReady && (
           <p className="text-xs text-muted">
             Paste to active app requires clipboard tooling and{status.waylandSession ? " /dev/uinput access" : " XTEST"}.
@@ -1856,7 +4043,54 @@ const LinuxSetupSection = ({
   );
 };
 
+const ReleaseNotesView = ({ version }: { version?: string }) => {
+  const [notes, setNotes] = useState<ReleaseNotes | null>(null);
+  const [loading, setLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const load = async () => {
+    setLoading(true);
+    setError(null);
+    try {
+      setNotes(await invoke<ReleaseNotes>("get_release_notes", { version: version ?? null }));
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  if (!notes) {
+    return (
+      <div className="space-y-1">
+        <Button variant="ghost" size="sm" onClick={load} disabled={loading}>
+          {loading ? "Loading…" : "What's new"}
+        </Button>
+        {error && <p className="text-xs text-warn">Release notes unavailable: {error}</p>}
+      </div>
+    );
+  }
+
+  return (
+    <div className="space-y-2 rounded-vibe border border-border bg-surface2 p-3 text-xs">
+      <div className="flex items-center justify-between">
+        <span className="font-medium text-fg">What's new in {notes.version}</span>
+        {notes.url && (
+          <a className="text-info underline" href={notes.url} target="_blank" rel="noreferrer">
+            Release page
+          </a>
+        )}
+      </div>
+      <pre className="max-h-64 overflow-auto whitespace-pre-wrap font-sans text-muted">
+        {notes.notes || "No release notes were published for this version."}
+      </pre>
+    </div>
+  );
+};
+
 const UpdatesSection = ({
+  draft,
+  onChange,
   linuxStatus,
   info,
   downloaded,
@@ -1871,6 +4105,8 @@ const UpdatesSection = ({
   onRestart,
   onQuit,
 }: {
+  draft: AppSettings;
+  onChange: <K extends keyof AppSettings>(key: K, value: AppSettings[K]) => void;
   linuxStatus: LinuxPermissionsStatus | null;
   info: UpdateCheckResult | null;
   downloaded: DownloadedUpdate | null;
@@ -1892,7 +4128,9 @@ const UpdatesSection = ({
 
   const updateAvailable = Boolean(info?.updateAvailable);
   const hasDownload = Boolean(downloaded?.tarballPath);
-  const pkexecReady = Boolean(linuxStatus?.pkexecAvailable);
+  // AppImages are user-owned and replaced without elevation.
+  const isAppImage = info?.installKind === "appimage";
+  const pkexecReady = isAppImage || Boolean(linuxStatus?.pkexecAvailable);
 
   return (
     <section>
@@ -1922,6 +4160,31 @@ const UpdatesSection = ({
           </div>
         )}
 
+        <div className="flex items-center justify-between gap-3">
+          <span className="text-sm text-muted">Check automatically</span>
+          <Select
+            width="md"
+            value={draft.updateCheckCadence}
+            onChange={(v) => onChange("updateCheckCadence", v as AppSettings["updateCheckCadence"])}
+            options={[
+              { value: "daily", label: "Daily" },
+              { value: "weekly", label: "Weekly" },
+              { value: "never", label: "Never" },
+            ]}
+          />
+        </div>
+        <label className="flex items-center gap-2 text-sm">
+          <input
+            type="checkbox"
+            checked={draft.preDownloadUpdates}
+            disabled={draft.updateCheckCadence === "never"}
+            onChange={(event) => onChange("preDownloadUpdates", event.target.checked)}
+          />
+          Download updates in the background (applying still asks first)
+        </label>
+
+        <ReleaseNotesView version={info?.currentVersion} />
+
         <div className="flex flex-wrap gap-2">
           <Button
             variant="secondary"
@@ -1946,9 +4209,15 @@ const UpdatesSection = ({
               variant="primary"
               onClick={onApply}
               disabled={busy || !pkexecReady}
-              title={!pkexecReady ? "pkexec not available" : "Requires admin approval"}
+              title={
+                isAppImage
+                  ? "Replaces the AppImage file"
+                  : !pkexecReady
+                    ? "pkexec not available"
+                    : "Requires admin approval"
+              }
             >
-              Apply (admin)
+              {isAppImage ? "Apply" : "Apply (admin)"}
             </Button>
           )}
 
@@ -1973,6 +4242,9 @@ const UpdatesSection = ({
               <span className="font-mono">
                 {formatBytes(progress.downloadedBytes)}
                 {progress.totalBytes ? ` / ${formatBytes(progress.totalBytes)}` : ""}
+                {progress.bytesPerSec
+                  ? ` · ${formatTransfer(progress.bytesPerSec, progress.etaSecs)}`
+                  : ""}
               </span>
             </div>
             {progress.totalBytes ? (
@@ -2015,7 +4287,14 @@ const UpdatesSection = ({
         )}
 
         <p className="text-xs text-muted">
-          Applying an update replaces files under <span className="font-mono">/opt/openflow</span>.
+          {isAppImage ? (
+            "Applying an update replaces the AppImage you launched OpenFlow from."
+          ) : (
+            <>
+              Applying an update replaces files under{" "}
+              <span className="font-mono">/opt/openflow</span>.
+            </>
+          )}
         </p>
       </Card>
     </section>
@@ -2277,7 +4556,7 @@ function renderModelRow({
   let statusDetail: string | undefined;
   let installLabel = "Install";
   let installDisabled = false;
-  let uninstallDisabled = status.state !== "installed";
+  let uninstallDisabled = status.state !== "installed" || Boolean(record?.systemProvided);
   let progressValue = 0;
   let downloadedBytes = 0;
   let totalBytes = 0;
@@ -2294,23 +4573,20 @@ function renderModelRow({
       downloadedBytes = status.downloadedBytes ?? 0;
       totalBytes = status.totalBytes ?? record?.sizeBytes ?? 0;
 
-      // Calculate download speed and ETA
-      if (status.startedAt && downloadedBytes > 0) {
+      // Prefer the backend's smoothed rate; fall back to the average since start.
+      if (status.bytesPerSec) {
+        downloadSpeed = `${formatBytes(status.bytesPerSec)}/s`;
+        if (status.etaSecs !== undefined) {
+          eta = formatRemaining(status.etaSecs);
+        }
+      } else if (status.startedAt && downloadedBytes > 0) {
         const elapsedSeconds = (Date.now() - status.startedAt) / 1000;
         if (elapsedSeconds > 0) {
           const bytesPerSecond = downloadedBytes / elapsedSeconds;
           downloadSpeed = `${formatBytes(bytesPerSecond)}/s`;
 
           if (totalBytes > downloadedBytes) {
-            const remainingBytes = totalBytes - downloadedBytes;
-            const remainingSeconds = remainingBytes / bytesPerSecond;
-            if (remainingSeconds < 60) {
-              eta = `${Math.ceil(remainingSeconds)}s remaining`;
-            } else if (remainingSeconds < 3600) {
-              eta = `${Math.ceil(remainingSeconds / 60)}m remaining`;
-            } else {
-              eta = `${Math.floor(remainingSeconds / 3600)}h ${Math.ceil((remainingSeconds % 3600) / 60)}m remaining`;
-            }
+            eta = formatRemaining((totalBytes - downloadedBytes) / bytesPerSecond);
           }
         }
       }
@@ -2503,6 +4779,31 @@ function formatBytes(bytes: number): string {
   return `${size.toFixed(unitIndex === 0 ? 0 : 1)} ${units[unitIndex]}`;
 }
 
+// "EN · 360 MB download · ~600 MB RAM · MIT"
+function describeModelInfo(info: NonNullable<RawModelAsset["info"]>): string {
+  const languages = info.multilingual ? "Multilingual" : info.languages.join(", ").toUpperCase();
+  return `${languages} · ${info.downloadMb} MB download · ~${info.ramMb} MB RAM · ${info.license}`;
+}
+
+function formatRemaining(seconds: number): string {
+  if (seconds < 60) {
+    return `${Math.ceil(seconds)}s remaining`;
+  }
+  if (seconds < 3600) {
+    return `${Math.ceil(seconds / 60)} min remaining`;
+  }
+  return `${Math.floor(seconds / 3600)}h ${Math.ceil((seconds % 3600) / 60)}m remaining`;
+}
+
+// "14.0 MB/s, 3 min remaining"; empty until the backend has a rate.
+function formatTransfer(bytesPerSec?: number | null, etaSecs?: number | null): string {
+  if (!bytesPerSec) {
+    return "";
+  }
+  const speed = `${formatBytes(bytesPerSec)}/s`;
+  return etaSecs === undefined || etaSecs === null ? speed : `${speed}, ${formatRemaining(etaSecs)}`;
+}
+
 // Keep legacy helper sections referenced to avoid unused warnings.
 void ModelSection;
 void renderModelRow;
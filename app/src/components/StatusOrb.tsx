@@ -1,22 +1,131 @@
+import { useEffect, useState } from "react";
 import { AnimatePresence, motion } from "framer-motion";
+import { invoke } from "@tauri-apps/api/core";
 import { useAppStore } from "../state/appStore";
 import PlasmaOrb from "./PlasmaOrb";
+import LevelWaveform from "./LevelWaveform";
+import { Button } from "../ui/primitives";
 
 const StatusOrb = () => {
   const hudState = useAppStore((state) => state.hudState);
+  const downloadProgress = useAppStore((state) => state.hudDownloadProgress);
+  const levels = useAppStore((state) => state.hudLevels);
+  const toast = useAppStore((state) => state.hudToast);
+  const transcriptToast = useAppStore((state) => state.transcriptToast);
+  const [copied, setCopied] = useState(false);
+
+  useEffect(() => setCopied(false), [transcriptToast]);
+  const interactive = useAppStore((state) => state.settings?.interactiveOverlay ?? false);
+  const accent = useAppStore((state) => state.settings?.hudAccentColor ?? null);
+  const showSeconds = useAppStore((state) => state.settings?.overlayShowAnimationMs ?? 420) / 1000;
+  const hideSeconds = useAppStore((state) => state.settings?.overlayHideDelayMs ?? 260) / 1000;
   const isVisible = hudState !== "idle";
 
   return (
-    <div className="pointer-events-none absolute inset-0 z-50 flex items-end justify-center pb-6">
+    <div className="pointer-events-none absolute inset-0 z-50 flex flex-col items-center justify-end pb-6">
+      <AnimatePresence>
+        {toast && (
+          <motion.p
+            key="hud-toast"
+            className="mb-2 rounded-full border border-border bg-surface px-3 py-1 text-center text-xs font-medium text-fg"
+            initial={{ opacity: 0, y: 6 }}
+            animate={{ opacity: 1, y: 0 }}
+            exit={{ opacity: 0, y: 4 }}
+            transition={{ duration: 0.24 }}
+          >
+            {toast}
+          </motion.p>
+        )}
+        {transcriptToast && (
+          <motion.button
+            key="transcript-toast"
+            type="button"
+            // Clicks only arrive when the overlay is interactive; otherwise it is click-through.
+            className={`mb-2 flex max-w-[208px] flex-col rounded-vibe border border-border bg-surface px-3 py-1.5 text-left text-xs text-fg ${
+              interactive ? "pointer-events-auto cursor-pointer" : ""
+            }`}
+            initial={{ opacity: 0, y: 6 }}
+            animate={{ opacity: 1, y: 0 }}
+            exit={{ opacity: 0, y: 4 }}
+            transition={{ duration: 0.24 }}
+            onMouseDown={(event) => event.preventDefault()}
+            onClick={() => {
+              invoke("recopy_last_transcript")
+                .then(() => setCopied(true))
+                .catch((error) => console.error("Failed to copy transcript", error));
+            }}
+            title={interactive ? "Click to copy" : undefined}
+          >
+            <span className="truncate font-medium">{transcriptToast.text}</span>
+            <span className="truncate text-[10px] text-muted">
+              {copied
+                ? "Copied to clipboard"
+                : [
+                    `${transcriptToast.words} words`,
+                    transcriptToast.shortcut,
+                    transcriptToast.windowClass,
+                  ]
+                    .filter(Boolean)
+                    .join(" · ")}
+            </span>
+          </motion.button>
+        )}
+      </AnimatePresence>
       <AnimatePresence>
         {isVisible && (
           <motion.div
             initial={{ opacity: 0, y: 10, scale: 0.9 }}
-            animate={{ opacity: 1, y: 0, scale: 1 }}
-            exit={{ opacity: 0, y: 8, scale: 0.84 }}
-            transition={{ duration: 0.42, ease: [0.22, 1, 0.36, 1] }}
+            animate={{
+              opacity: 1,
+              y: 0,
+              scale: 1,
+              transition: { duration: showSeconds, ease: [0.22, 1, 0.36, 1] },
+            }}
+            exit={{
+              opacity: 0,
+              y: 8,
+              scale: 0.84,
+              transition: { duration: hideSeconds, ease: [0.22, 1, 0.36, 1] },
+            }}
           >
-            <PlasmaOrb state={hudState} size={106} />
+            <PlasmaOrb state={hudState} size={106} accent={accent} />
+            {hudState === "listening" && levels && (
+              <LevelWaveform levels={levels} accent={accent} />
+            )}
+            {hudState === "downloading" && downloadProgress && (
+              <p className="mt-2 text-center text-xs font-medium text-muted">
+                Downloading model {Math.round(downloadProgress.progress * 100)}%
+              </p>
+            )}
+            {interactive && hudState === "listening" && (
+              // Only these buttons take pointer events; the rest of the overlay stays inert.
+              <div className="pointer-events-auto mt-2 flex justify-center gap-2">
+                <Button
+                  size="sm"
+                  variant="secondary"
+                  onMouseDown={(event) => event.preventDefault()}
+                  onClick={() => {
+                    invoke("cancel_dictation").catch((error) =>
+                      console.error("Failed to cancel dictation", error),
+                    );
+                  }}
+                >
+                  Cancel
+                </Button>
+                <Button
+                  size="sm"
+                  variant="primary"
+                  onMouseDown={(event) => event.preventDefault()}
+                  onClick={() => {
+                    invoke("complete_dictation").catch((error) =>
+                      console.error("Failed to complete dictation", error),
+                    );
+                  }}
+                >
+                  Done
+                </Button>
+              </div>
+            )}
           </motion.div>
         )}
       </AnimatePresence>
@@ -4,16 +4,48 @@ import { invoke } from "@tauri-apps/api/core";
 export type ModelKind =
   | "whisper-onnx"
   | "whisper-ct2"
+  | "whisper-cpp"
   | "parakeet"
   | "vad"
+  | "speaker"
+  | "gpu-runtime"
   | "unknown";
 
 type RawModelStatus =
   | "notInstalled"
   | "installed"
-  | { downloading: { progress: number; downloadedBytes?: number; totalBytes?: number | null } }
+  | {
+      downloading: {
+        progress: number;
+        downloadedBytes?: number;
+        totalBytes?: number | null;
+        bytesPerSec?: number | null;
+        etaSecs?: number | null;
+      };
+    }
   | { error: string };
 
+export type QualityTier = "basic" | "standard" | "high" | "best";
+
+// Catalog metadata for built-in assets.
+export interface ModelInfo {
+  languages: string[];
+  multilingual: boolean;
+  downloadMb: number;
+  ramMb: number;
+  license: string;
+  quality?: QualityTier | null;
+}
+
+export interface ModelQuery {
+  kind?: ModelKind;
+  language?: string;
+  maxRamMb?: number;
+  maxDownloadMb?: number;
+  minQuality?: QualityTier;
+  installedOnly?: boolean;
+}
+
 export interface RawModelAsset {
   name: string;
   kind: ModelKind;
@@ -21,6 +53,12 @@ export interface RawModelAsset {
   sizeBytes: number;
   checksum?: string | null;
   status: RawModelStatus;
+  systemProvided?: boolean;
+  installedRevision?: string | null;
+  latestRevision?: string | null;
+  previousRevision?: string | null;
+  linkedPath?: string | null;
+  info?: ModelInfo | null;
 }
 
 export interface ModelSnapshotPayload {
@@ -30,12 +68,27 @@ export interface ModelSnapshotPayload {
   sizeBytes: number;
   checksum?: string | null;
   status: RawModelStatus;
+  systemProvided?: boolean;
+  installedRevision?: string | null;
+  latestRevision?: string | null;
+  previousRevision?: string | null;
+  linkedPath?: string | null;
+  info?: ModelInfo | null;
 }
 
 export type ModelStateKind =
   | { state: "notInstalled" }
   | { state: "installed" }
-  | { state: "downloading"; progress: number; downloadedBytes?: number; totalBytes?: number; startedAt?: number }
+  | {
+      state: "downloading";
+      progress: number;
+      downloadedBytes?: number;
+      totalBytes?: number;
+      // Smoothed by the backend; absent until a few samples arrived.
+      bytesPerSec?: number;
+      etaSecs?: number;
+      startedAt?: number;
+    }
   | { state: "error"; message: string };
 
 export interface DownloadLogEntry {
@@ -53,25 +106,173 @@ export interface ModelRecord {
   sizeBytes: number;
   checksum: string | null;
   status: ModelStateKind;
+  systemProvided: boolean;
+  installedRevision: string | null;
+  latestRevision: string | null;
+  previousRevision: string | null;
+  linkedPath: string | null;
+  info: ModelInfo | null;
+  updateAvailable: boolean;
 }
 
 export type HudState =
   | "idle"
   | "warming"
+  | "downloading"
   | "listening"
   | "processing"
   | "performance-warning"
   | "secure-blocked"
   | "asr-error";
 
+export interface HudDownloadProgress {
+  assetName: string;
+  progress: number;
+}
+
+// Mic level history while listening, oldest first, values in 0..1.
+export interface HudLevels {
+  rms: number[];
+  peak: number[];
+}
+
+export type TimingStage =
+  | "capture"
+  | "vad"
+  | "queue"
+  | "trim"
+  | "speaker-gate"
+  | "asr"
+  | "clean"
+  | "inject"
+  | "clipboard"
+  | "key-inject";
+
+export interface LatencyBudget {
+  targetMs: number;
+  stageMs: Partial<Record<TimingStage, number>>;
+}
+
+export interface PerformancePolicy {
+  slowLatencyMs: number;
+  slowCpuPercent: number;
+  enterAfterSlowSessions: number;
+  exitAfterFastSessions: number;
+  recoverCpuPercent: number;
+  shortenVadHangover: boolean;
+  hangoverCapMs: number;
+  suggestModelDownshift: boolean;
+  downshiftAfterSessions: number;
+  latencyBudget: LatencyBudget;
+}
+
+export const DEFAULT_PERFORMANCE_POLICY: PerformancePolicy = {
+  slowLatencyMs: 2000,
+  slowCpuPercent: 75,
+  enterAfterSlowSessions: 2,
+  exitAfterFastSessions: 1,
+  recoverCpuPercent: 75,
+  shortenVadHangover: true,
+  hangoverCapMs: 200,
+  suggestModelDownshift: false,
+  downshiftAfterSessions: 3,
+  latencyBudget: { targetMs: 0, stageMs: {} },
+};
+
+export interface AsrSelection {
+  asrFamily: AppSettings["asrFamily"];
+  whisperBackend: AppSettings["whisperBackend"];
+  whisperModel: AppSettings["whisperModel"];
+  whisperModelLanguage: AppSettings["whisperModelLanguage"];
+  whisperPrecision: AppSettings["whisperPrecision"];
+}
+
+export interface TranscriptToast {
+  text: string;
+  chars: number;
+  words: number;
+  shortcut: string;
+  windowClass: string | null;
+}
+
+export interface TranscriptAlternatives {
+  text: string;
+  alternatives: string[];
+}
+
+export interface VoiceCommand {
+  phrase: string;
+  action:
+    | "keys"
+    | "text"
+    | "delete-last-sentence"
+    | "delete-last-utterance"
+    | "select-last-sentence"
+    | "replace"
+    | "launch";
+  argument: string;
+}
+
+export const DEFAULT_VOICE_COMMANDS: VoiceCommand[] = [
+  { phrase: "new line", action: "keys", argument: "Enter" },
+  { phrase: "new paragraph", action: "text", argument: "\n\n" },
+  { phrase: "delete last sentence", action: "delete-last-sentence", argument: "" },
+  { phrase: "undo", action: "keys", argument: "Ctrl+Z" },
+  { phrase: "select all", action: "keys", argument: "Ctrl+A" },
+  { phrase: "open terminal", action: "launch", argument: "x-terminal-emulator" },
+];
+
+export interface PauseThresholds {
+  commaMs: number;
+  periodMs: number;
+}
+
+export interface HistoryEntry {
+  id: number;
+  recordedAt: string;
+  text: string;
+  app: string | null;
+  wordCount: number;
+  outcome: "pasted" | "paste-failed" | "paste-unconfirmed" | "emitted";
+}
+
+export interface SettingsChange {
+  changedAt: string;
+  source:
+    | "ui"
+    | "migration"
+    | "language-cycle"
+    | "model-downshift"
+    | "warmup-fallback"
+    | "reset";
+  field: string;
+  old: unknown;
+  new: unknown;
+}
+
 export interface AppSettings {
-  hotkeyMode: "hold" | "toggle";
+  hotkeyMode: "hold" | "toggle" | "hybrid";
+  hybridHoldThresholdMs: number;
   pushToTalkHotkey: string;
   toggleToTalkHotkey: string;
+  recopyTranscriptHotkey: string;
+  commandHotkey: string;
+  continuousHotkey: string;
+  continuousEndSilenceMs: number;
+  voiceCommands: VoiceCommand[];
   hudTheme: "system" | "light" | "dark" | "high-contrast";
+  hudAccentColor: string | null;
   showHudOverlay: boolean;
+  suppressOverlayInFullscreen: boolean;
+  overlayShowAnimationMs: number;
+  overlayHideDelayMs: number;
+  hudToastMs: number;
+  transcriptToast: boolean;
+  strictPrivacy: boolean;
+  interactiveOverlay: boolean;
   asrFamily: "parakeet" | "whisper";
-  whisperBackend: "ct2" | "onnx";
+  // "cpp" needs a build with the asr-whispercpp feature.
+  whisperBackend: "ct2" | "onnx" | "cpp";
   whisperModel:
     | "tiny"
     | "base"
@@ -81,13 +282,72 @@ export interface AppSettings {
     | "large-v3-turbo";
   whisperModelLanguage: "en" | "multi";
   whisperPrecision: "int8" | "float";
-  pasteShortcut: "ctrl-v" | "ctrl-shift-v";
+  mmapModelWeights: boolean;
+  autoRevertAsr: boolean;
+  startupWarmup: "immediate" | "delay" | "idle" | "hotkey";
+  startupWarmupSecs: number;
+  asrDevice: "cpu" | "auto" | "cuda" | "rocm";
+  maxDictationSecs: number;
+  alwaysTranscribe: boolean;
+  pasteShortcut: string;
+  appPasteShortcuts: Record<string, string>;
+  pasteFallbackShiftInsert: boolean;
+  typeOut: boolean;
+  typeOutCharDelayMs: number;
+  // Paste multi-line text into terminals instead of typing it.
+  typeOutTerminalPaste: boolean;
+  confirmBeforePaste: boolean;
+  transcriptAlternatives: number;
+  redactionRules: string[];
+  customRedactions: string[];
+  redactBeforePaste: boolean;
   language: string;
   autoDetectLanguage: boolean;
-  autocleanMode: "off" | "fast";
+  secondaryLanguage: string;
+  languageCycleHotkey: string;
+  languageCycle: string[];
+  languageModels: Record<string, AsrSelection>;
+  customVocabulary: string[];
+  pausePunctuation: boolean;
+  pauseThresholds: Record<string, PauseThresholds>;
+  autocleanMode: "off" | "fast" | "polish";
+  // Local LLM server used by polish mode.
+  polishEndpoint: string;
+  polishApi: "ollama" | "openai";
+  polishModel: string;
+  // Empty uses the built-in prompt; `{text}` marks where the transcript goes.
+  polishPrompt: string;
+  polishTimeoutMs: number;
+  // Consent to send transcripts to a polish server on another machine.
+  polishAllowRemote: boolean;
+  formattingLocale: string;
+  messageLocale: string;
   debugTranscripts: boolean;
-  audioDeviceId: string | null;
+  keepFailedAudio: boolean;
+  failedAudioRetentionDays: number;
+  diagnosticsIntervalMs: number;
+  eventRateProfile: "normal" | "low-power";
+  eventMinIntervalMs: Record<string, number>;
+  exportSessionTraces: boolean;
+  historyEnabled: boolean;
+  performancePolicy: PerformancePolicy;
+  modelDownshift: "off" | "offer" | "auto";
+  soundCuesEnabled: boolean;
+  soundCueTheme: "soft" | "click" | "chime";
+  soundCueStart: boolean;
+  soundCueStop: boolean;
+  soundCuePaste: boolean;
+  soundCueError: boolean;
+  audioDevicePreferences: string[];
+  onDemandCapture: boolean;
+  bluetoothAutoProfile: boolean;
   vadSensitivity: "low" | "medium" | "high";
+  speakerGate: boolean;
+  speakerGateThreshold: number;
+  updateCheckCadence: "daily" | "weekly" | "never";
+  preDownloadUpdates: boolean;
+  // Ids of secrets held in the system keyring; written by set_secret/clear_secret only.
+  storedSecrets: string[];
 }
 
 export interface PerformanceMetrics {
@@ -95,6 +355,9 @@ export interface PerformanceMetrics {
   averageCpuPercent: number;
   consecutiveSlow: number;
   performanceMode: boolean;
+  // Decode threads are capped while the CPU thermal-throttles.
+  thermalThrottled: boolean;
+  cpuTempC: number | null;
 }
 
 export interface LinuxPermissionsStatus {
@@ -107,15 +370,32 @@ export interface LinuxPermissionsStatus {
   xdgRuntimeDirAvailable: boolean;
   evdevReadable: boolean;
   uinputWritable: boolean;
+  virtualKeyboardInjection: boolean;
   clipboardBackend: string;
   wlCopyAvailable: boolean;
   wlPasteAvailable: boolean;
   xclipAvailable: boolean;
   pkexecAvailable: boolean;
   setfaclAvailable: boolean;
+  inputGroupPendingRelogin: boolean;
+  accessWatch: "idle" | "waiting" | "granted" | "timed-out";
+  setupSteps: Array<{ id: string; done: boolean }>;
+  compositor: CompositorCapabilities | null;
   details: string[];
 }
 
+export interface CompositorCapabilities {
+  family: "gnome" | "kde" | "wlroots" | "other";
+  desktop: string;
+  layerShell: boolean;
+  dataControl: boolean;
+  virtualKeyboard: boolean;
+  globalShortcutsPortal: boolean;
+  overlay: "gnome-extension" | "layer-shell" | "window";
+  injection: "virtual-keyboard" | "uinput";
+  hotkeys: "portal" | "evdev";
+}
+
 export interface GnomeHudExtensionStatus {
   supported: boolean;
   isGnomeWayland: boolean;
@@ -127,27 +407,114 @@ export interface GnomeHudExtensionStatus {
   details: string[];
 }
 
+export type PreflightSeverity = "ok" | "info" | "warning" | "error";
+
+export interface PreflightCheck {
+  id: string;
+  label: string;
+  severity: PreflightSeverity;
+  message: string;
+  remedy?: string;
+  route?: string;
+}
+
+export interface PreflightReport {
+  generatedAtUnix: number;
+  appVersion: string;
+  sessionType: string;
+  overall: PreflightSeverity;
+  checks: PreflightCheck[];
+}
+
 export const DEFAULT_PUSH_TO_TALK_HOTKEY = "RightAlt";
 export const DEFAULT_TOGGLE_TO_TALK_HOTKEY = "RightAlt";
 
 export const DEFAULT_APP_SETTINGS: AppSettings = {
   hotkeyMode: "hold",
+  hybridHoldThresholdMs: 350,
   pushToTalkHotkey: DEFAULT_PUSH_TO_TALK_HOTKEY,
   toggleToTalkHotkey: DEFAULT_TOGGLE_TO_TALK_HOTKEY,
+  recopyTranscriptHotkey: "",
+  commandHotkey: "",
+  continuousHotkey: "",
+  continuousEndSilenceMs: 900,
+  voiceCommands: DEFAULT_VOICE_COMMANDS,
   hudTheme: "system",
+  hudAccentColor: null,
   showHudOverlay: false,
+  suppressOverlayInFullscreen: true,
+  overlayShowAnimationMs: 420,
+  overlayHideDelayMs: 260,
+  hudToastMs: 1800,
+  transcriptToast: false,
+  strictPrivacy: false,
+  interactiveOverlay: false,
   asrFamily: "parakeet",
   whisperBackend: "ct2",
   whisperModel: "small",
   whisperModelLanguage: "multi",
   whisperPrecision: "int8",
-  pasteShortcut: "ctrl-shift-v",
+  mmapModelWeights: true,
+  autoRevertAsr: true,
+  startupWarmup: "idle",
+  startupWarmupSecs: 5,
+  asrDevice: "cpu",
+  maxDictationSecs: 120,
+  alwaysTranscribe: false,
+  pasteShortcut: "Ctrl+Shift+V",
+  appPasteShortcuts: {},
+  pasteFallbackShiftInsert: false,
+  typeOut: false,
+  typeOutCharDelayMs: 5,
+  typeOutTerminalPaste: true,
+  confirmBeforePaste: false,
+  transcriptAlternatives: 0,
+  redactionRules: [],
+  customRedactions: [],
+  redactBeforePaste: false,
   language: "auto",
   autoDetectLanguage: true,
+  secondaryLanguage: "",
+  languageCycleHotkey: "",
+  languageCycle: [],
+  languageModels: {},
+  customVocabulary: [],
+  pausePunctuation: true,
+  pauseThresholds: { default: { commaMs: 450, periodMs: 1000 } },
   autocleanMode: "fast",
+  polishEndpoint: "http://127.0.0.1:11434",
+  polishApi: "ollama",
+  polishModel: "llama3.2:3b",
+  polishPrompt: "",
+  polishTimeoutMs: 4000,
+  polishAllowRemote: false,
+  formattingLocale: "off",
+  messageLocale: "system",
   debugTranscripts: false,
-  audioDeviceId: null,
+  keepFailedAudio: false,
+  failedAudioRetentionDays: 3,
+  diagnosticsIntervalMs: 250,
+  eventRateProfile: "normal",
+  eventMinIntervalMs: {},
+  exportSessionTraces: false,
+  historyEnabled: true,
+  performancePolicy: DEFAULT_PERFORMANCE_POLICY,
+  modelDownshift: "offer",
+  soundCuesEnabled: false,
+  soundCueTheme: "soft",
+  soundCueStart: true,
+  soundCueStop: true,
+  soundCuePaste: true,
+  soundCueError: true,
+  audioDevicePreferences: [],
+  onDemandCapture: false,
+  bluetoothAutoProfile: false,
   vadSensitivity: "medium",
+  speakerGate: false,
+  speakerGateThreshold: 0.5,
+  updateCheckCadence: "daily",
+  preDownloadUpdates: false,
+  storedSecrets: [],
 };
 
 interface AppState {
@@ -156,7 +523,22 @@ interface AppState {
   settings: AppSettings | null;
   initialize: () => Promise<void>;
   setHudState: (state: HudState) => void;
+  hudDownloadProgress: HudDownloadProgress | null;
+  setHudDownloadProgress: (progress: HudDownloadProgress | null) => void;
+  hudLevels: HudLevels | null;
+  setHudLevels: (levels: HudLevels | null) => void;
+  hudToast: string | null;
+  setHudToast: (message: string | null) => void;
+  transcriptToast: TranscriptToast | null;
+  setTranscriptToast: (toast: TranscriptToast | null) => void;
+  pendingTranscript: string | null;
+  setPendingTranscript: (text: string | null) => void;
+  transcriptAlternatives: TranscriptAlternatives | null;
+  setTranscriptAlternatives: (value: TranscriptAlternatives | null) => void;
   toggleSettings: (value?: boolean) => void;
+  settingsRoute: string | null;
+  openSettingsPage: (route: string) => void;
+  clearSettingsRoute: () => void;
   updateSettings: (settings: AppSettings) => Promise<void>;
   refreshSettings: () => Promise<void>;
   setSettingsState: (settings: AppSettings) => void;
@@ -173,6 +555,11 @@ interface AppState {
   setModelSnapshot: (snapshot: ModelSnapshotPayload) => void;
   installModelAsset: (name: string) => Promise<void>;
   uninstallModelAsset: (name: string) => Promise<void>;
+  cancelModelDownload: (name: string) => Promise<void>;
+  updateModelAsset: (name: string) => Promise<void>;
+  rollbackModelAsset: (name: string) => Promise<void>;
+  linkModelAsset: (name: string, path: string) => Promise<void>;
+  checkModelUpdates: () => Promise<void>;
   toasts: Toast[];
   notify: (toast: Omit<Toast, "id">) => void;
   dismissToast: (id: number) => void;
@@ -188,6 +575,8 @@ interface AppState {
   gnomeHudExtensionStatus: GnomeHudExtensionStatus | null;
   refreshGnomeHudExtensionStatus: () => Promise<void>;
   installGnomeHudExtension: () => Promise<void>;
+  preflight: PreflightReport | null;
+  runPreflight: () => Promise<PreflightReport | null>;
 }
 
 export interface AudioDevice {
@@ -209,6 +598,7 @@ export const useAppStore = create<AppState>((set, get) => ({
   downloadStartTimes: {},
   linuxPermissions: null,
   gnomeHudExtensionStatus: null,
+  preflight: null,
   initialize: async () => {
     await get().refreshSettings();
     await get().refreshModels();
@@ -218,11 +608,26 @@ export const useAppStore = create<AppState>((set, get) => ({
   },
   setHudState: (state) =>
     set((prev) => (prev.hudState === state ? prev : { hudState: state })),
+  hudDownloadProgress: null,
+  setHudDownloadProgress: (progress) => set({ hudDownloadProgress: progress }),
+  hudLevels: null,
+  setHudLevels: (levels) => set({ hudLevels: levels }),
+  hudToast: null,
+  setHudToast: (message) => set({ hudToast: message }),
+  transcriptToast: null,
+  setTranscriptToast: (toast) => set({ transcriptToast: toast }),
+  pendingTranscript: null,
+  setPendingTranscript: (text) => set({ pendingTranscript: text }),
+  transcriptAlternatives: null,
+  setTranscriptAlternatives: (value) => set({ transcriptAlternatives: value }),
   toggleSettings: (value) =>
     set((prev) => ({
       settingsVisible:
         value !== undefined ? value : !prev.settingsVisible,
     })),
+  settingsRoute: null,
+  openSettingsPage: (route) => set({ settingsVisible: true, settingsRoute: route }),
+  clearSettingsRoute: () => set({ settingsRoute: null }),
   updateSettings: async (settings) => {
     await invoke("update_settings", { settings });
     await get().refreshSettings();
@@ -383,6 +788,91 @@ export const useAppStore = create<AppState>((set, get) => ({
       });
     }
   },
+  cancelModelDownload: async (name: string) => {
+    try {
+      await invoke("cancel_model_download", { name });
+      get().notify({
+        title: "Download cancelled",
+        description: name,
+        variant: "info",
+      });
+    } catch (error) {
+      console.error("Failed to cancel model download", error);
+      get().notify({
+        title: "Cancel failed",
+        description: String(error),
+        variant: "error",
+      });
+    }
+  },
+  updateModelAsset: async (name: string) => {
+    try {
+      await invoke("update_model_asset", { name });
+      get().notify({
+        title: "Model update started",
+        description: name,
+        variant: "info",
+      });
+    } catch (error) {
+      console.error("Failed to start model update", error);
+      get().notify({
+        title: "Model update failed",
+        description: String(error),
+        variant: "error",
+      });
+    }
+  },
+  rollbackModelAsset: async (name: string) => {
+    try {
+      await invoke("rollback_model_asset", { name });
+      get().notify({
+        title: "Previous model version restored",
+        description: name,
+        variant: "info",
+      });
+    } catch (error) {
+      console.error("Failed to roll back model", error);
+      get().notify({
+        title: "Model rollback failed",
+        description: String(error),
+        variant: "error",
+      });
+    }
+  },
+  linkModelAsset: async (name: string, path: string) => {
+    try {
+      await invoke("link_model_asset", { name, path });
+      get().notify({
+        title: "Model linked",
+        description: `${name} now uses ${path}`,
+        variant: "info",
+      });
+    } catch (error) {
+      console.error("Failed to link model", error);
+      get().notify({
+        title: "Model link failed",
+        description: String(error),
+        variant: "error",
+      });
+    }
+  },
+  checkModelUpdates: async () => {
+    try {
+      const updates = await invoke<RawModelAsset[]>("check_model_updates");
+      get().notify({
+        title: updates.length > 0 ? "Model updates available" : "Models are up to date",
+        description: updates.map((asset) => asset.name).join(", ") || undefined,
+        variant: "info",
+      });
+    } catch (error) {
+      console.error("Failed to check model updates", error);
+      get().notify({
+        title: "Model update check failed",
+        description: String(error),
+        variant: "error",
+      });
+    }
+  },
   notify: (toast) =>
     set((state) => ({
       toasts: buildNextToastList(state.toasts, toast),
@@ -414,6 +904,16 @@ export const useAppStore = create<AppState>((set, get) => ({
   authenticateLinuxPermissions: async () => {
     await invoke("linux_enable_permissions");
   },
+  runPreflight: async () => {
+    try {
+      const report = await invoke<PreflightReport>("run_preflight");
+      set({ preflight: report });
+      return report;
+    } catch {
+      set({ preflight: null });
+      return null;
+    }
+  },
   refreshGnomeHudExtensionStatus: async () => {
     try {
       const status = await invoke<GnomeHudExtensionStatus>("gnome_hud_extension_status");
@@ -428,6 +928,28 @@ export const useAppStore = create<AppState>((set, get) => ({
   },
 }));
 
+// A setting update_settings rejected before saving; `field` is the AppSettings key.
+export interface SettingsFieldError {
+  field: keyof AppSettings;
+  message: string;
+}
+
+export type SettingsUpdateError =
+  | { kind: "invalid"; errors: SettingsFieldError[] }
+  | { kind: "failed"; message: string };
+
+// The field errors of a rejected update_settings call; null for any other failure.
+export function settingsFieldErrors(error: unknown): SettingsFieldError[] | null {
+  if (
+    typeof error === "object" &&
+    error !== null &&
+    (error as SettingsUpdateError).kind === "invalid"
+  ) {
+    return (error as { errors: SettingsFieldError[] }).errors;
+  }
+  return null;
+}
+
 export interface Toast {
   id: number;
   title: string;
@@ -498,6 +1020,15 @@ function normalizeModelRecord(raw: RawModelAsset): ModelRecord {
     sizeBytes: raw.sizeBytes ?? 0,
     checksum: raw.checksum ?? null,
     status: normalizeStatus(raw.status),
+    systemProvided: raw.systemProvided ?? false,
+    installedRevision: raw.installedRevision ?? null,
+    latestRevision: raw.latestRevision ?? null,
+    previousRevision: raw.previousRevision ?? null,
+    linkedPath: raw.linkedPath ?? null,
+    info: raw.info ?? null,
+    updateAvailable:
+      Boolean(raw.installedRevision && raw.latestRevision) &&
+      raw.installedRevision !== raw.latestRevision,
   };
 }
 
@@ -515,6 +1046,8 @@ function normalizeStatus(status: RawModelStatus): ModelStateKind {
       progress: status.downloading.progress ?? 0,
       downloadedBytes: status.downloading.downloadedBytes ?? 0,
       totalBytes: status.downloading.totalBytes ?? undefined,
+      bytesPerSec: status.downloading.bytesPerSec ?? undefined,
+      etaSecs: status.downloading.etaSecs ?? undefined,
     };
   } else if ("error" in status) {
     return { state: "error", message: status.error }; 
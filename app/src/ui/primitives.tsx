@@ -172,12 +172,14 @@ export function Select<T extends string>({
 }
 
 export function AccordionSection({
+  id,
   title,
   description,
   open,
   onToggle,
   children,
 }: {
+  id?: string;
   title: string;
   description?: string;
   open: boolean;
@@ -187,7 +189,7 @@ export function AccordionSection({
   const contentId = useId();
 
   return (
-    <div className="rounded-vibe border border-border bg-surface">
+    <div id={id} className="rounded-vibe border border-border bg-surface">
       <button
         type="button"
         className={cn(
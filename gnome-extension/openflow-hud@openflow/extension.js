@@ -3,6 +3,7 @@ import Gio from "gi://Gio";
 import GLib from "gi://GLib";
 import St from "gi://St";
 import * as Main from "resource:///org/gnome/shell/ui/main.js";
+import * as PanelMenu from "resource:///org/gnome/shell/ui/panelMenu.js";
 import { Extension } from "resource:///org/gnome/shell/extensions/extension.js";
 
 const HUD_SIZE = 104;
@@ -18,6 +19,8 @@ const TAU = Math.PI * 2;
 const LISTENING_PHASE_RATE = 0.046 / (ANIMATION_INTERVAL_MS / 1000);
 const PROCESSING_PHASE_RATE = 0.064 / (ANIMATION_INTERVAL_MS / 1000);
 const MAX_READ_FAILURES_BEFORE_HIDE = 3;
+const INDICATOR_ICON_IDLE = "audio-input-microphone-symbolic";
+const INDICATOR_ICON_ACTIVE = "media-record-symbolic";
 
 const STATE_COLORS = {
     listening: {
@@ -65,6 +68,65 @@ const STATE_COLORS = {
 };
 
 const DEFAULT_COLORS = STATE_COLORS.warming;
+const INTERFACE_SCHEMA = "org.gnome.desktop.interface";
+
+function parseRgba(color) {
+    const match = /rgba?\(([^)]+)\)/.exec(color);
+    if (!match) {
+        return null;
+    }
+    const parts = match[1].split(",").map((part) => Number.parseFloat(part.trim()));
+    if (parts.length < 3 || parts.some((part) => !Number.isFinite(part))) {
+        return null;
+    }
+    return [parts[0], parts[1], parts[2], parts[3] ?? 1];
+}
+
+function formatRgba([red, green, blue, alpha]) {
+    const channel = (value) => Math.round(Math.max(0, Math.min(255, value)));
+    return `rgba(${channel(red)}, ${channel(green)}, ${channel(blue)}, ${Math.max(0, Math.min(1, alpha)).toFixed(2)})`;
+}
+
+function mapPalette(palette, transform) {
+    return Object.fromEntries(
+        Object.entries(palette).map(([key, color]) => {
+            const rgba = parseRgba(color);
+            return [key, rgba ? formatRgba(transform(rgba)) : color];
+        })
+    );
+}
+
+// Listening palette built from an `#rrggbb` accent, with lighter tints inside.
+function accentPalette(hex) {
+    const match = /^#([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$/i.exec(hex ?? "");
+    if (!match) {
+        return null;
+    }
+    const base = match.slice(1).map((part) => Number.parseInt(part, 16));
+    const tint = (amount, alpha) =>
+        formatRgba([...base.map((c) => c + (255 - c) * amount), alpha]);
+    return {
+        halo: tint(0, 0.42),
+        ring: tint(0.4, 0.68),
+        arc: tint(0.55, 0.95),
+        arcSoft: tint(0.35, 0.52),
+        spark: tint(0.75, 0.8),
+    };
+}
+
+// Pastel strokes vanish on a light shell; darken them there, and make every
+// stroke opaque for high contrast.
+function paletteFor(state, theme, accent) {
+    const accented = state === "listening" ? accentPalette(accent) : null;
+    const palette = accented ?? STATE_COLORS[state] ?? DEFAULT_COLORS;
+    if (theme === "light") {
+        return mapPalette(palette, ([r, g, b, a]) => [r * 0.62, g * 0.62, b * 0.62, a * 1.15]);
+    }
+    if (theme === "high-contrast") {
+        return mapPalette(palette, ([r, g, b, a]) => [r, g, b, Math.min(1, a * 1.6)]);
+    }
+    return palette;
+}
 
 function colorToRgba(color, alphaScale = 1) {
     const match = /rgba?\(([^)]+)\)/.exec(color);
@@ -149,13 +211,18 @@ export default class OpenFlowHudExtension extends Extension {
         this._phaseOffsetA = Math.random() * TAU;
         this._phaseOffsetB = Math.random() * TAU;
         this._colors = DEFAULT_COLORS;
+        this._theme = "system";
+        this._accent = null;
         this._hideTimeoutId = null;
         this._enabledAtMicros = GLib.get_real_time();
         this._hasSeenPostEnableWrite = false;
         this._readFailureCount = 0;
         this._lastMonitorIndex = null;
         this._displayFocusChangedId = null;
+        this._fullscreenChangedId = null;
         this._workspaceChangedId = null;
+        this._reportedFullscreen = null;
+        this._reportedPid = null;
 
         this._container = new St.Widget({
             reactive: false,
@@ -194,7 +261,23 @@ export default class OpenFlowHudExtension extends Extension {
         this._container.add_child(this._drawingArea);
         Main.layoutManager.addChrome(this._container, { trackFullscreen: true });
 
+        this._interfaceSettings = null;
+        this._colorSchemeChangedId = null;
+        try {
+            this._interfaceSettings = new Gio.Settings({ schema_id: INTERFACE_SCHEMA });
+            this._colorSchemeChangedId = this._interfaceSettings.connect(
+                "changed::color-scheme",
+                () => {
+                    this._applyStateVisual(this._state);
+                    this._drawingArea?.queue_repaint();
+                }
+            );
+        } catch (_error) {
+            this._interfaceSettings = null;
+        }
+
         this._applyStateVisual("warming");
+        this._createIndicator();
 
         this._monitorsChangedId = Main.layoutManager.connect("monitors-changed", () => {
             this._syncPosition();
@@ -203,6 +286,10 @@ export default class OpenFlowHudExtension extends Extension {
         if (global.display?.connect) {
             this._displayFocusChangedId = global.display.connect("notify::focus-window", () => {
                 this._syncPosition();
+                this._syncFullscreen();
+            });
+            this._fullscreenChangedId = global.display.connect("in-fullscreen-changed", () => {
+                this._syncFullscreen();
             });
         }
 
@@ -235,6 +322,12 @@ export default class OpenFlowHudExtension extends Extension {
             this._hideTimeoutId = null;
         }
 
+        if (this._indicator) {
+            this._indicator.destroy();
+            this._indicator = null;
+            this._indicatorIcon = null;
+        }
+
         if (this._pollId) {
             GLib.Source.remove(this._pollId);
             this._pollId = null;
@@ -255,11 +348,24 @@ export default class OpenFlowHudExtension extends Extension {
             this._displayFocusChangedId = null;
         }
 
+        if (this._fullscreenChangedId && global.display?.disconnect) {
+            global.display.disconnect(this._fullscreenChangedId);
+            this._fullscreenChangedId = null;
+        }
+        this._reportedFullscreen = null;
+        this._reportedPid = null;
+
         if (this._workspaceChangedId && global.workspace_manager?.disconnect) {
             global.workspace_manager.disconnect(this._workspaceChangedId);
             this._workspaceChangedId = null;
         }
 
+        if (this._interfaceSettings && this._colorSchemeChangedId) {
+            this._interfaceSettings.disconnect(this._colorSchemeChangedId);
+            this._colorSchemeChangedId = null;
+        }
+        this._interfaceSettings = null;
+
         if (this._drawingArea && this._repaintId) {
             this._drawingArea.disconnect(this._repaintId);
             this._repaintId = null;
@@ -286,6 +392,7 @@ export default class OpenFlowHudExtension extends Extension {
     _refresh() {
         const path = this._statePath();
         if (!path) {
+            this._syncIndicator(null);
             this._hide();
             return;
         }
@@ -293,6 +400,7 @@ export default class OpenFlowHudExtension extends Extension {
         try {
             const [ok, bytes] = GLib.file_get_contents(path);
             if (!ok) {
+                this._syncIndicator(null);
                 this._hide();
                 return;
             }
@@ -302,7 +410,17 @@ export default class OpenFlowHudExtension extends Extension {
             const enabled = payload?.enabled === true;
             const state = typeof payload?.state === "string" ? payload.state : "idle";
             const pid = Number.isInteger(payload?.pid) ? payload.pid : null;
+            const running = pid !== null && this._isProcessAlive(pid);
+            this._syncIndicator(running ? state : null);
+            if (running && pid !== this._reportedPid) {
+                // A freshly started OpenFlow has not heard the current fullscreen state.
+                this._reportedPid = pid;
+                this._reportedFullscreen = null;
+                this._syncFullscreen();
+            }
             const sessionId = typeof payload?.session_id === "string" ? payload.session_id : null;
+            this._theme = typeof payload?.theme === "string" ? payload.theme : "system";
+            this._accent = typeof payload?.accent === "string" ? payload.accent : null;
             const modifiedMicros = this._readStateModifiedMicros(path);
 
             if (
@@ -326,7 +444,7 @@ export default class OpenFlowHudExtension extends Extension {
 
             this._cancelHideSchedule();
 
-            const signature = `${enabled ? "1" : "0"}:${state}:${pid ?? "none"}:${sessionId ?? "none"}`;
+            const signature = `${enabled ? "1" : "0"}:${state}:${pid ?? "none"}:${sessionId ?? "none"}:${this._theme}:${this._accent ?? "none"}`;
             if (signature === this._lastSignature) {
                 if (!this._container?.visible) {
                     this._state = state;
@@ -351,11 +469,71 @@ export default class OpenFlowHudExtension extends Extension {
         } catch (_error) {
             this._readFailureCount += 1;
             if (this._readFailureCount >= MAX_READ_FAILURES_BEFORE_HIDE) {
+                this._syncIndicator(null);
                 this._scheduleHide();
             }
         }
     }
 
+    _createIndicator() {
+        this._indicator = new PanelMenu.Button(0.0, "OpenFlow", true);
+        this._indicatorIcon = new St.Icon({
+            icon_name: INDICATOR_ICON_IDLE,
+            style_class: "system-status-icon",
+        });
+        this._indicator.add_child(this._indicatorIcon);
+        this._indicator.visible = false;
+        this._indicator.connect("button-press-event", () => {
+            this._sendCommand("toggle");
+            return true;
+        });
+        Main.panel.addToStatusArea(this.uuid, this._indicator);
+    }
+
+    // `state` is null while OpenFlow is not running; the indicator is hidden then.
+    _syncIndicator(state) {
+        if (!this._indicator) {
+            return;
+        }
+        this._indicator.visible = state !== null;
+        const active = state === "listening" || state === "processing";
+        this._indicatorIcon.icon_name = active ? INDICATOR_ICON_ACTIVE : INDICATOR_ICON_IDLE;
+    }
+
+    // Wayland hides window state from clients, so tell OpenFlow when the focused window
+    // goes fullscreen; it keeps its own overlay out of games and presentations.
+    _syncFullscreen() {
+        const fullscreen = global.display?.focus_window?.is_fullscreen?.() === true;
+        if (fullscreen === this._reportedFullscreen) {
+            return;
+        }
+        this._reportedFullscreen = fullscreen;
+        this._sendCommand(fullscreen ? "fullscreen on" : "fullscreen off");
+    }
+
+    _isProcessAlive(pid) {
+        return GLib.file_test(`/proc/${pid}`, GLib.FileTest.EXISTS);
+    }
+
+    _sendCommand(command) {
+        const path = this._controlPath();
+        if (!path) {
+            return;
+        }
+
+        const client = new Gio.SocketClient();
+        client.connect_async(Gio.UnixSocketAddress.new(path), null, (source, result) => {
+            try {
+                const connection = source.connect_finish(result);
+                const bytes = new TextEncoder().encode(`${command}\n`);
+                connection.get_output_stream().write_all(bytes, null);
+                connection.close(null);
+            } catch (error) {
+                console.warn(`OpenFlow HUD: failed to send ${command}: ${error}`);
+            }
+        });
+    }
+
     _readStateModifiedMicros(path) {
         try {
             const file = Gio.File.new_for_path(path);
@@ -398,8 +576,21 @@ export default class OpenFlowHudExtension extends Extension {
         this._hideTimeoutId = null;
     }
 
+    // "system" follows the shell: light only when the user prefers light.
+    _resolvedTheme() {
+        if (this._theme !== "system") {
+            return this._theme;
+        }
+        try {
+            const scheme = this._interfaceSettings?.get_string("color-scheme");
+            return scheme === "prefer-light" ? "light" : "dark";
+        } catch (_error) {
+            return "dark";
+        }
+    }
+
     _applyStateVisual(state) {
-        this._colors = STATE_COLORS[state] ?? DEFAULT_COLORS;
+        this._colors = paletteFor(state, this._resolvedTheme(), this._accent);
         this._halo.set_style(`border-radius: 999px; background-color: ${this._colors.halo};`);
     }
 
@@ -519,6 +710,14 @@ export default class OpenFlowHudExtension extends Extension {
         return GLib.build_filenamev([runtimeDir, "openflow", "hud-state.json"]);
     }
 
+    _controlPath() {
+        const runtimeDir = GLib.getenv("XDG_RUNTIME_DIR");
+        if (!runtimeDir) {
+            return null;
+        }
+        return GLib.build_filenamev([runtimeDir, "openflow", "control.sock"]);
+    }
+
     _syncPosition() {
         if (!this._container) {
             return;